@@ -85,6 +85,7 @@ use mz_sql_parser::ast::QualifiedReplica;
 use mz_stash::{DebugStashFactory, StashFactory};
 use mz_storage_types::connections::inline::{ConnectionResolver, InlinedConnection};
 use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::controller::TableLayout;
 use mz_storage_types::sources::Timeline;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::notice::OptimizerNotice;
@@ -469,9 +470,29 @@ impl Catalog {
             CatalogType::Timestamp => CatalogType::Timestamp,
             CatalogType::TimestampTz => CatalogType::TimestampTz,
             CatalogType::Uuid => CatalogType::Uuid,
+            CatalogType::Inet => CatalogType::Inet,
+            CatalogType::Cidr => CatalogType::Cidr,
+            CatalogType::TsVector => CatalogType::TsVector,
+            CatalogType::TsQuery => CatalogType::TsQuery,
+            CatalogType::JsonPath => CatalogType::JsonPath,
+            CatalogType::Hll => CatalogType::Hll,
             CatalogType::VarChar => CatalogType::VarChar,
             CatalogType::Int2Vector => CatalogType::Int2Vector,
             CatalogType::MzAclItem => CatalogType::MzAclItem,
+            CatalogType::Enum { labels } => CatalogType::Enum {
+                labels: labels.clone(),
+            },
+            CatalogType::Domain {
+                base_reference,
+                base_modifiers,
+                not_null,
+                checks,
+            } => CatalogType::Domain {
+                base_reference: name_to_id_map[base_reference],
+                base_modifiers: base_modifiers.clone(),
+                not_null: *not_null,
+                checks: checks.clone(),
+            },
         };
 
         BuiltinType {
@@ -941,6 +962,50 @@ impl Catalog {
         self.state.get_entry(id)
     }
 
+    /// Returns the PostgreSQL type of `typ`, including the labels of any enum
+    /// types within it, which are required to decode values of the type.
+    pub fn resolve_pg_type(&self, typ: &ScalarType) -> mz_pgrepr::Type {
+        let mut pg_typ = mz_pgrepr::Type::from(typ);
+        self.fill_enum_labels(typ, &mut pg_typ);
+        pg_typ
+    }
+
+    fn fill_enum_labels(&self, typ: &ScalarType, pg_typ: &mut mz_pgrepr::Type) {
+        match (typ, pg_typ) {
+            (ScalarType::Enum { custom_id, .. }, mz_pgrepr::Type::Enum { labels, .. }) => {
+                if let Some(CatalogTypeDetails {
+                    typ:
+                        CatalogType::Enum {
+                            labels: enum_labels,
+                        },
+                    ..
+                }) = self.get_entry(custom_id).type_details()
+                {
+                    *labels = Some(enum_labels.clone());
+                }
+            }
+            (ScalarType::Array(typ), mz_pgrepr::Type::Array(pg_typ))
+            | (
+                ScalarType::List {
+                    element_type: typ, ..
+                },
+                mz_pgrepr::Type::List(pg_typ),
+            )
+            | (
+                ScalarType::Map {
+                    value_type: typ, ..
+                },
+                mz_pgrepr::Type::Map { value_type: pg_typ },
+            ) => self.fill_enum_labels(typ, pg_typ),
+            (ScalarType::Record { fields, .. }, mz_pgrepr::Type::Record(pg_typs)) => {
+                for ((_, field), pg_typ) in fields.iter().zip(pg_typs) {
+                    self.fill_enum_labels(&field.scalar_type, pg_typ);
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub fn get_schema(
         &self,
         database_spec: &ResolvedDatabaseSpecifier,
@@ -1305,6 +1370,118 @@ impl Catalog {
 
                     info!("update role {name} ({id})");
                 }
+                Op::AlterType { id, create_sql } => {
+                    let name = state.get_entry(&id).name().clone();
+                    let to_item = state.reparse_item(id, create_sql)?;
+                    builtin_table_updates.extend(state.pack_item_update(id, -1));
+                    Self::update_item(state, builtin_table_updates, id, name, to_item)?;
+                    let entry = state.get_entry(&id);
+                    tx.update_item(id, entry.clone().into())?;
+
+                    let name = state.get_entry(&id).name().clone();
+                    let name = Self::full_name_detail(
+                        &state.resolve_full_name(&name, session.map(|session| session.conn_id())),
+                    );
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Alter,
+                        ObjectType::Type,
+                        EventDetails::UpdateItemV1(mz_audit_log::UpdateItemV1 {
+                            id: id.to_string(),
+                            name,
+                        }),
+                    )?;
+                }
+                Op::AlterTable {
+                    id,
+                    create_sql,
+                    layout,
+                    dropped_column,
+                    dependents,
+                } => {
+                    // Comments on the columns after a dropped column move up
+                    // by one position. Column positions are 1-based.
+                    if let Some(column) = dropped_column {
+                        let comment_id = state.get_comment_id(ObjectId::Item(id));
+                        let comments: Vec<_> = state
+                            .comments
+                            .get_object_comments(comment_id)
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|(col_pos, comment)| Some(((*col_pos)?, comment.clone())))
+                            .filter(|(col_pos, _)| *col_pos > column)
+                            .collect();
+                        for (col_pos, comment) in comments {
+                            let moves_to = (col_pos > column + 1).then(|| col_pos - 1);
+                            let mut updates = vec![(Some(col_pos), None)];
+                            if let Some(moves_to) = moves_to {
+                                updates.push((Some(moves_to), Some(comment.clone())));
+                            }
+                            for (col_pos, comment) in updates {
+                                tx.update_comment(comment_id, col_pos, comment.clone())?;
+                                let prev = state.comments.update_comment(
+                                    comment_id,
+                                    col_pos,
+                                    comment.clone(),
+                                );
+                                if let Some(prev) = prev {
+                                    builtin_table_updates.push(
+                                        state.pack_comment_update(comment_id, col_pos, &prev, -1),
+                                    );
+                                }
+                                if let Some(comment) = &comment {
+                                    builtin_table_updates.push(
+                                        state.pack_comment_update(comment_id, col_pos, comment, 1),
+                                    );
+                                }
+                            }
+                        }
+                    }
+
+                    // The table is re-planned first so that its dependents
+                    // observe its new columns.
+                    for (item_id, create_sql) in std::iter::once((id, create_sql)).chain(dependents)
+                    {
+                        let name = state.get_entry(&item_id).name().clone();
+                        let mut to_item = state.reparse_item(item_id, create_sql)?;
+                        // The table's `create_sql` does not describe the
+                        // columns stored in its shard.
+                        if let CatalogItem::Table(table) = &mut to_item {
+                            if item_id == id {
+                                table.layout = Some(layout.clone());
+                            }
+                        }
+                        let is_temporary = to_item.is_temporary();
+                        builtin_table_updates.extend(state.pack_item_update(item_id, -1));
+                        Self::update_item(state, builtin_table_updates, item_id, name, to_item)?;
+                        if !is_temporary {
+                            let entry = state.get_entry(&item_id);
+                            tx.update_item(item_id, entry.clone().into())?;
+                        }
+                    }
+
+                    let name = state.get_entry(&id).name().clone();
+                    let name = Self::full_name_detail(
+                        &state.resolve_full_name(&name, session.map(|session| session.conn_id())),
+                    );
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Alter,
+                        ObjectType::Table,
+                        EventDetails::UpdateItemV1(mz_audit_log::UpdateItemV1 {
+                            id: id.to_string(),
+                            name,
+                        }),
+                    )?;
+                }
                 Op::AlterSetCluster { id, cluster } => Self::transact_alter_set_cluster(
                     state,
                     tx,
@@ -1779,6 +1956,7 @@ impl Catalog {
                             serialized_item,
                             owner_id,
                             privileges.clone(),
+                            item.table_layout().cloned(),
                         )?;
                     }
 
@@ -3052,7 +3230,13 @@ impl Catalog {
             &old_entry.name().qualifiers.schema_spec,
             conn_id,
         );
-        schema.items.remove(&old_entry.name().item);
+        // Types live in their own namespace.
+        let names = if old_entry.item_type() == SqlCatalogItemType::Type {
+            &mut schema.types
+        } else {
+            &mut schema.items
+        };
+        names.remove(&old_entry.name().item);
 
         // Dropped deps
         let dropped_references: Vec<_> = old_entry
@@ -3087,7 +3271,7 @@ impl Catalog {
         new_entry.name = to_name;
         new_entry.item = to_item;
 
-        schema.items.insert(new_entry.name().item.clone(), id);
+        names.insert(new_entry.name().item.clone(), id);
 
         for u in dropped_references {
             // OK if we no longer have this entry because we are dropping our
@@ -3560,6 +3744,23 @@ impl From<UpdatePrivilegeVariant> for EventType {
 
 #[derive(Debug, Clone)]
 pub enum Op {
+    /// Replaces the definition of a type with `create_sql`.
+    AlterType {
+        id: GlobalId,
+        create_sql: String,
+    },
+    /// Replaces the definition of the table `id` with `create_sql` and the
+    /// columns stored in its shard with `layout`, which adds a column to the
+    /// table or drops the column at position `dropped_column`, and the
+    /// definitions of the items that depend on the table with the definitions
+    /// in `dependents`.
+    AlterTable {
+        id: GlobalId,
+        create_sql: String,
+        layout: TableLayout,
+        dropped_column: Option<usize>,
+        dependents: Vec<(GlobalId, String)>,
+    },
     AlterSetCluster {
         id: GlobalId,
         cluster: ClusterId,
@@ -3747,6 +3948,10 @@ impl ExprHumanizer for ConnCatalog<'_> {
             | Map {
                 custom_id: Some(global_id),
                 ..
+            }
+            | Enum {
+                custom_id: global_id,
+                ..
             } => {
                 let item = self.get_item(global_id);
                 self.minimal_qualification(item.name()).to_string()
@@ -5243,12 +5448,6 @@ mod tests {
                 "mz_panic",
                 "mz_sleep",
                 "pow",
-                "stddev_pop",
-                "stddev_samp",
-                "stddev",
-                "var_pop",
-                "var_samp",
-                "variance",
             ]);
 
             let fns = BUILTINS::funcs()
@@ -5543,7 +5742,9 @@ mod tests {
                         | typ @ ScalarType::MzTimestamp
                         | typ @ ScalarType::List { .. }
                         | typ @ ScalarType::Map { .. }
-                        | typ @ ScalarType::MzAclItem => {
+                        | typ @ ScalarType::MzAclItem
+                        | typ @ ScalarType::Hll
+                        | typ @ ScalarType::Enum { .. } => {
                             panic!("{typ:?} type found in {full_name}");
                         }
                         ScalarType::AclItem
@@ -5574,6 +5775,11 @@ mod tests {
                         | ScalarType::RegClass
                         | ScalarType::Int2Vector
                         | ScalarType::Range { .. }
+                        | ScalarType::Inet
+                        | ScalarType::Cidr
+                        | ScalarType::TsVector
+                        | ScalarType::TsQuery
+                        | ScalarType::JsonPath
                         | ScalarType::PgLegacyName => {}
                     }
                 }
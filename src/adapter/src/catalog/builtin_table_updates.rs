@@ -19,18 +19,18 @@ use mz_catalog::builtin::{
     MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_BASE_TYPES, MZ_CLUSTERS, MZ_CLUSTER_LINKS,
     MZ_CLUSTER_REPLICAS, MZ_CLUSTER_REPLICA_METRICS, MZ_CLUSTER_REPLICA_SIZES,
     MZ_CLUSTER_REPLICA_STATUSES, MZ_COLUMNS, MZ_COMMENTS, MZ_CONNECTIONS, MZ_DATABASES,
-    MZ_DEFAULT_PRIVILEGES, MZ_EGRESS_IPS, MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS,
-    MZ_INTERNAL_CLUSTER_REPLICAS, MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES,
-    MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS,
-    MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS, MZ_SECRETS,
-    MZ_SESSIONS, MZ_SINKS, MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD,
-    MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TYPES, MZ_TYPE_PG_METADATA, MZ_VIEWS,
-    MZ_WEBHOOKS_SOURCES,
+    MZ_DEFAULT_PRIVILEGES, MZ_DOMAIN_TYPES, MZ_EGRESS_IPS, MZ_ENUM_TYPES, MZ_FUNCTIONS, MZ_INDEXES,
+    MZ_INDEX_COLUMNS, MZ_INTERNAL_CLUSTER_REPLICAS, MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS,
+    MZ_KAFKA_SOURCES, MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_OBJECT_DEPENDENCIES,
+    MZ_OPERATORS, MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS,
+    MZ_SECRETS, MZ_SESSIONS, MZ_SINKS, MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS,
+    MZ_STORAGE_USAGE_BY_SHARD, MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TYPES,
+    MZ_TYPE_PG_METADATA, MZ_VIEWS, MZ_WEBHOOKS_SOURCES,
 };
 use mz_catalog::config::AwsPrincipalContext;
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
-    CatalogItem, ClusterVariant, Connection, DataSourceDesc, Database, Func, Index,
+    CatalogItem, ClusterVariant, Connection, DataSourceDesc, Database, Func, FuncInner, Index,
     MaterializedView, Sink, Table, Type, View,
 };
 use mz_catalog::SYSTEM_CONN_ID;
@@ -421,7 +421,7 @@ impl CatalogState {
                     self.pack_type_update(id, oid, schema_id, name, owner_id, privileges, ty, diff)
                 }
                 CatalogItem::Func(func) => {
-                    self.pack_func_update(id, schema_id, name, owner_id, func, diff)
+                    self.pack_func_update(id, oid, schema_id, name, owner_id, func, diff)
                 }
                 CatalogItem::Secret(_) => {
                     self.pack_secret_update(id, oid, schema_id, name, owner_id, privileges, diff)
@@ -942,6 +942,9 @@ impl CatalogState {
                     diff,
                 });
             }
+            StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::Webhook(_)
+            | StorageSinkConnection::Iceberg(_) => (),
         };
 
         let create_stmt = mz_sql::parse::parse(&sink.create_sql)
@@ -1077,6 +1080,15 @@ impl CatalogState {
                 .to_ast_string_redacted()
         });
 
+        // Domains belong to the category of their (ultimate) base type.
+        let mut category_type = &typ.details.typ;
+        while let CatalogType::Domain { base_reference, .. } = category_type {
+            match self.get_entry(base_reference).item() {
+                CatalogItem::Type(base) => category_type = &base.details.typ,
+                _ => unreachable!("domains are always over types"),
+            }
+        }
+
         out.push(BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_TYPES),
             row: Row::pack_slice(&[
@@ -1084,7 +1096,7 @@ impl CatalogState {
                 Datum::UInt32(oid),
                 Datum::String(&schema_id.to_string()),
                 Datum::String(name),
-                Datum::String(&TypeCategory::from_catalog_type(&typ.details.typ).to_string()),
+                Datum::String(&TypeCategory::from_catalog_type(category_type).to_string()),
                 Datum::String(&owner_id.to_string()),
                 privileges,
                 if let Some(create_sql) = &typ.create_sql {
@@ -1129,6 +1141,21 @@ impl CatalogState {
                 append_modifier(&mut packer, element_modifiers);
                 self.resolve_builtin_table(&MZ_LIST_TYPES)
             }
+            CatalogType::Enum { labels } => {
+                packer.push(Datum::String(&id.to_string()));
+                packer.push_list(labels.iter().map(|l| Datum::String(l)));
+                self.resolve_builtin_table(&MZ_ENUM_TYPES)
+            }
+            CatalogType::Domain {
+                base_reference: base_id,
+                not_null,
+                ..
+            } => {
+                packer.push(Datum::String(&id.to_string()));
+                packer.push(Datum::String(&base_id.to_string()));
+                packer.push(Datum::from(*not_null));
+                self.resolve_builtin_table(&MZ_DOMAIN_TYPES)
+            }
             CatalogType::Map {
                 key_reference: key_id,
                 value_reference: value_id,
@@ -1175,6 +1202,7 @@ impl CatalogState {
     fn pack_func_update(
         &self,
         id: GlobalId,
+        oid: u32,
         schema_id: &SchemaSpecifier,
         name: &str,
         owner_id: &RoleId,
@@ -1183,6 +1211,14 @@ impl CatalogState {
     ) -> Vec<BuiltinTableUpdate> {
         let mut updates = vec![];
         for func_impl_details in func.inner.func_impls() {
+            // Built-in functions assign each of their implementations an OID,
+            // while the single implementation of a user-defined function uses
+            // the OID of the function itself.
+            let oid = match &func.inner {
+                FuncInner::Builtin(_) => func_impl_details.oid,
+                FuncInner::User(_) => oid,
+            };
+
             let arg_type_ids = func_impl_details
                 .arg_typs
                 .iter()
@@ -1207,7 +1243,7 @@ impl CatalogState {
                 id: self.resolve_builtin_table(&MZ_FUNCTIONS),
                 row: Row::pack_slice(&[
                     Datum::String(&id.to_string()),
-                    Datum::UInt32(func_impl_details.oid),
+                    Datum::UInt32(oid),
                     Datum::String(&schema_id.to_string()),
                     Datum::String(name),
                     arg_type_ids,
@@ -1229,11 +1265,11 @@ impl CatalogState {
                 diff,
             });
 
-            if let mz_sql::func::Func::Aggregate(_) = func.inner {
+            if let mz_sql::func::Func::Aggregate(_) = &*func.inner {
                 updates.push(BuiltinTableUpdate {
                     id: self.resolve_builtin_table(&MZ_AGGREGATES),
                     row: Row::pack_slice(&[
-                        Datum::UInt32(func_impl_details.oid),
+                        Datum::UInt32(oid),
                         // TODO(materialize#3326): Support ordered-set aggregate functions.
                         Datum::String("n"),
                         Datum::Int16(0),
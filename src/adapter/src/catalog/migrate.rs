@@ -288,6 +288,9 @@ fn ast_rewrite_create_sink_into_kafka_options_0_80_0(
                         value: Some(WithOptionValue::Value(Value::Boolean(true))),
                     });
                 }
+                CreateSinkConnection::Postgres { .. }
+                | CreateSinkConnection::Webhook { .. }
+                | CreateSinkConnection::Iceberg { .. } => {}
             }
         }
     }
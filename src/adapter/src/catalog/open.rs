@@ -33,8 +33,8 @@ use mz_catalog::durable::objects::{
 use mz_catalog::durable::{Transaction, SYSTEM_CLUSTER_ID_ALLOC_KEY, SYSTEM_REPLICA_ID_ALLOC_KEY};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
-    CatalogEntry, CatalogItem, CommentsMap, DataSourceDesc, Database, DefaultPrivileges, Func, Log,
-    Role, Schema, Source, Table, Type,
+    CatalogEntry, CatalogItem, CommentsMap, DataSourceDesc, Database, DefaultPrivileges, Func,
+    FuncInner, Log, Role, Schema, Source, Table, Type,
 };
 use mz_catalog::SYSTEM_CONN_ID;
 use mz_cluster_client::ReplicaId;
@@ -65,6 +65,7 @@ use mz_sql::{plan, rbac};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::Expr;
 use mz_ssh_util::keys::SshKeyPairSet;
+use mz_storage_types::controller::TableLayout;
 use mz_storage_types::sources::Timeline;
 
 // DO NOT add any more imports from `crate` outside of `crate::catalog`.
@@ -128,6 +129,9 @@ pub enum CatalogItemRebuilder {
         sql: String,
         is_retained_metrics_object: bool,
         custom_logical_compaction_window: Option<CompactionWindow>,
+        /// The columns stored in the shard of the object, if it is a table,
+        /// which `sql` does not describe.
+        table_layout: Option<TableLayout>,
     },
 }
 
@@ -153,6 +157,7 @@ impl CatalogItemRebuilder {
                 sql: create_stmt.to_ast_string_stable(),
                 is_retained_metrics_object: entry.item().is_retained_metrics_object(),
                 custom_logical_compaction_window: entry.item().custom_logical_compaction_window(),
+                table_layout: entry.item().table_layout().cloned(),
             }
         }
     }
@@ -165,15 +170,24 @@ impl CatalogItemRebuilder {
                 sql,
                 is_retained_metrics_object,
                 custom_logical_compaction_window,
-            } => state
-                .parse_item(
-                    id,
-                    sql.clone(),
-                    None,
-                    is_retained_metrics_object,
-                    custom_logical_compaction_window,
-                )
-                .unwrap_or_else(|error| panic!("invalid persisted create sql ({error:?}): {sql}")),
+                table_layout,
+            } => {
+                let mut item = state
+                    .parse_item(
+                        id,
+                        sql.clone(),
+                        None,
+                        is_retained_metrics_object,
+                        custom_logical_compaction_window,
+                    )
+                    .unwrap_or_else(|error| {
+                        panic!("invalid persisted create sql ({error:?}): {sql}")
+                    });
+                if let CatalogItem::Table(table) = &mut item {
+                    table.layout = table_layout;
+                }
+                item
+            }
         }
     }
 }
@@ -529,6 +543,10 @@ impl Catalog {
                                     create_sql: None,
                                     desc: table.desc.clone(),
                                     defaults: vec![Expr::null(); table.desc.arity()],
+                                    domains: vec![None; table.desc.arity()],
+                                    collations: vec![None; table.desc.arity()],
+                                    unique_keys: vec![],
+                                    layout: None,
                                     conn_id: None,
                                     resolved_ids: ResolvedIds(BTreeSet::new()),
                                     custom_logical_compaction_window: table
@@ -587,7 +605,11 @@ impl Catalog {
                                 id,
                                 oid,
                                 name.clone(),
-                                CatalogItem::Func(Func { inner: func.inner }),
+                                CatalogItem::Func(Func {
+                                    create_sql: None,
+                                    inner: FuncInner::Builtin(func.inner),
+                                    resolved_ids: ResolvedIds(BTreeSet::new()),
+                                }),
                                 MZ_SYSTEM_ROLE_ID,
                                 PrivilegeMap::default(),
                             );
@@ -1465,6 +1487,7 @@ impl Catalog {
                 serialized_item,
                 entry.owner_id().clone(),
                 entry.privileges().all_values_owned().collect(),
+                item.table_layout().cloned(),
             )?;
         }
         txn.update_system_object_mappings(std::mem::take(
@@ -1506,6 +1529,7 @@ impl Catalog {
         let mut items: VecDeque<_> = tx.loaded_items().into_iter().collect();
         while let Some(item) = items.pop_front() {
             let d_c = item.create_sql.clone();
+            let table_layout = item.table_layout.clone();
             // TODO(benesch): a better way of detecting when a view has depended
             // upon a non-existent logging view. This is fine for now because
             // the only goal is to produce a nicer error message; we'll bail out
@@ -1513,7 +1537,7 @@ impl Catalog {
             static LOGGING_ERROR: Lazy<Regex> =
                 Lazy::new(|| Regex::new("mz_catalog.[^']*").expect("valid regex"));
 
-            let catalog_item = match state.deserialize_item(item.id, d_c) {
+            let catalog_item = match state.deserialize_item(item.id, d_c, table_layout) {
                 Ok(item) => item,
                 Err(AdapterError::Catalog(Error {
                     kind: ErrorKind::Sql(SqlCatalogError::UnknownItem(name)),
@@ -1604,6 +1628,7 @@ impl Catalog {
                 create_sql: _,
                 owner_id: _,
                 privileges: _,
+                table_layout: _,
             } = dependents.remove(0);
             let schema = state.find_non_temp_schema(&schema_id);
             let name = QualifiedItemName {
@@ -1632,6 +1657,7 @@ impl Catalog {
                 create_sql: _,
                 owner_id: _,
                 privileges: _,
+                table_layout: _,
             } = dependents.remove(0);
             let schema = state.find_non_temp_schema(&schema_id);
             let name = QualifiedItemName {
@@ -1867,6 +1893,10 @@ mod builtin_migration_tests {
                         .with_column("a", ScalarType::Int32.nullable(true))
                         .with_key(vec![0]),
                     defaults: vec![Expr::null(); 1],
+                    domains: vec![None; 1],
+                    collations: vec![None; 1],
+                    unique_keys: vec![vec![0]],
+                    layout: None,
                     conn_id: None,
                     resolved_ids: ResolvedIds(BTreeSet::new()),
                     custom_logical_compaction_window: None,
@@ -34,8 +34,8 @@ use mz_catalog::config::{AwsPrincipalContext, ClusterReplicaSizeMap};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, Cluster, ClusterConfig, ClusterReplica, ClusterReplicaProcessStatus,
-    CommentsMap, Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
-    Role, Schema, Secret, Sink, Source, Table, Type, View,
+    CommentsMap, Connection, DataSourceDesc, Database, DefaultPrivileges, Func, FuncInner, Index,
+    MaterializedView, Role, Schema, Secret, Sink, Source, Table, Type, View,
 };
 use mz_catalog::{LINKED_CLUSTER_REPLICA_NAME, SYSTEM_CONN_ID};
 use mz_controller::clusters::{
@@ -71,9 +71,9 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext,
+    CreateConnectionPlan, CreateFunctionPlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
+    CreateViewPlan, Params, Plan, PlanContext,
 };
 use mz_sql::rbac;
 use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
@@ -83,6 +83,7 @@ use mz_storage_types::connections::inline::{
     ConnectionResolver, InlinedConnection, IntoInlineConnection,
 };
 use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::controller::TableLayout;
 
 // DO NOT add any more imports from `crate` outside of `crate::catalog`.
 use crate::catalog::{BuiltinTableUpdate, ConnCatalog};
@@ -318,7 +319,8 @@ impl CatalogState {
             CatalogItem::Log(_) => out.push(id),
             item @ (CatalogItem::View(_)
             | CatalogItem::MaterializedView(_)
-            | CatalogItem::Connection(_)) => {
+            | CatalogItem::Connection(_)
+            | CatalogItem::Func(_)) => {
                 // TODO(jkosh44) Unclear if this table wants to include all uses or only references.
                 for id in &item.references().0 {
                     self.introspection_dependencies_inner(*id, out);
@@ -329,7 +331,6 @@ impl CatalogState {
             CatalogItem::Table(_)
             | CatalogItem::Source(_)
             | CatalogItem::Type(_)
-            | CatalogItem::Func(_)
             | CatalogItem::Secret(_) => (),
         }
     }
@@ -842,12 +843,19 @@ impl CatalogState {
         &self,
         id: GlobalId,
         create_sql: String,
+        table_layout: Option<TableLayout>,
     ) -> Result<CatalogItem, AdapterError> {
         // TODO - The `None` needs to be changed if we ever allow custom
         // logical compaction windows in user-defined objects.
         let mut pcx = PlanContext::zero();
         pcx.set_planning_id(id);
-        self.parse_item(id, create_sql, Some(&pcx), false, None)
+        let mut item = self.parse_item(id, create_sql, Some(&pcx), false, None)?;
+        // Tables that were stored without a layout have never been altered, so
+        // their `create_sql` describes the columns stored in their shards.
+        if let (CatalogItem::Table(table), Some(layout)) = (&mut item, table_layout) {
+            table.layout = Some(layout);
+        }
+        Ok(item)
     }
 
     /// Parses the given SQL string into a `CatalogItem`.
@@ -878,15 +886,22 @@ impl CatalogState {
         let plan =
             mz_sql::plan::plan(pcx, &session_catalog, stmt, &Params::empty(), &resolved_ids)?;
         Ok(match plan {
-            Plan::CreateTable(CreateTablePlan { table, .. }) => CatalogItem::Table(Table {
-                create_sql: Some(table.create_sql),
-                desc: table.desc,
-                defaults: table.defaults,
-                conn_id: None,
-                resolved_ids,
-                custom_logical_compaction_window,
-                is_retained_metrics_object,
-            }),
+            Plan::CreateTable(CreateTablePlan { table, .. }) => {
+                let layout = TableLayout::new(&table.desc);
+                CatalogItem::Table(Table {
+                    create_sql: Some(table.create_sql),
+                    desc: table.desc,
+                    defaults: table.defaults,
+                    domains: table.domains,
+                    collations: table.collations,
+                    unique_keys: table.unique_keys,
+                    layout: Some(layout),
+                    conn_id: None,
+                    resolved_ids,
+                    custom_logical_compaction_window,
+                    is_retained_metrics_object,
+                })
+            }
             Plan::CreateSource(CreateSourcePlan {
                 source,
                 timeline,
@@ -1013,6 +1028,11 @@ impl CatalogState {
                 },
                 resolved_ids,
             }),
+            Plan::CreateFunction(CreateFunctionPlan { func, .. }) => CatalogItem::Func(Func {
+                create_sql: Some(func.create_sql),
+                inner: FuncInner::User(Arc::new(func.inner)),
+                resolved_ids,
+            }),
             Plan::CreateSecret(CreateSecretPlan { secret, .. }) => CatalogItem::Secret(Secret {
                 create_sql: secret.create_sql,
             }),
@@ -1037,6 +1057,59 @@ impl CatalogState {
         })
     }
 
+    /// Parses the given SQL string into a new definition of the existing item
+    /// `id`.
+    ///
+    /// Unlike [`CatalogState::parse_item`], `create_sql` is permitted to
+    /// create an item with the name of the existing item.
+    pub(crate) fn reparse_item(
+        &mut self,
+        id: GlobalId,
+        create_sql: String,
+    ) -> Result<CatalogItem, AdapterError> {
+        let entry = self.get_entry(&id);
+        let name = entry.name().clone();
+        let conn_id = entry.item().conn_id().unwrap_or(&SYSTEM_CONN_ID).clone();
+        let item_type = entry.item_type();
+        let is_retained_metrics_object = entry.item().is_retained_metrics_object();
+        let custom_logical_compaction_window = entry.item().custom_logical_compaction_window();
+
+        // Hide the item's name while planning so that `create_sql` does not
+        // conflict with the item itself.
+        let schema = self.get_schema_mut(
+            &name.qualifiers.database_spec,
+            &name.qualifiers.schema_spec,
+            &conn_id,
+        );
+        let names = match item_type {
+            CatalogItemType::Type => &mut schema.types,
+            CatalogItemType::Func => &mut schema.functions,
+            _ => &mut schema.items,
+        };
+        names.remove(&name.item);
+
+        let item = self.parse_item(
+            id,
+            create_sql,
+            None,
+            is_retained_metrics_object,
+            custom_logical_compaction_window,
+        );
+
+        let schema = self.get_schema_mut(
+            &name.qualifiers.database_spec,
+            &name.qualifiers.schema_spec,
+            &conn_id,
+        );
+        let names = match item_type {
+            CatalogItemType::Type => &mut schema.types,
+            CatalogItemType::Func => &mut schema.functions,
+            _ => &mut schema.items,
+        };
+        names.insert(name.item, id);
+        item
+    }
+
     /// Returns all indexes on the given object and cluster known in the
     /// catalog.
     pub fn get_indexes_on(
@@ -1170,21 +1243,12 @@ impl CatalogState {
             &metadata.name().qualifiers.schema_spec,
             conn_id,
         );
-        if metadata.item_type() == CatalogItemType::Type {
-            schema
-                .types
-                .remove(&metadata.name().item)
-                .expect("catalog out of sync");
-        } else {
-            // Functions would need special handling, but we don't yet support
-            // dropping functions.
-            assert_ne!(metadata.item_type(), CatalogItemType::Func);
-
-            schema
-                .items
-                .remove(&metadata.name().item)
-                .expect("catalog out of sync");
-        };
+        match metadata.item_type() {
+            CatalogItemType::Type => schema.types.remove(&metadata.name().item),
+            CatalogItemType::Func => schema.functions.remove(&metadata.name().item),
+            _ => schema.items.remove(&metadata.name().item),
+        }
+        .expect("catalog out of sync");
 
         if !id.is_system() {
             if let Some(cluster_id) = metadata.item().cluster_id() {
@@ -2299,6 +2363,10 @@ impl ExprHumanizer for CatalogState {
             | Map {
                 custom_id: Some(global_id),
                 ..
+            }
+            | Enum {
+                custom_id: global_id,
+                ..
             } => {
                 let item = self.get_entry(global_id);
                 self.resolve_full_name(item.name(), None).to_string()
@@ -142,7 +142,7 @@ use crate::catalog::{BuiltinMigrationMetadata, BuiltinTableUpdate, Catalog};
 use crate::client::{Client, Handle};
 use crate::command::{Canceled, Command, ExecuteResponse};
 use crate::config::{SynchronizedParameters, SystemParameterFrontend, SystemParameterSyncConfig};
-use crate::coord::appends::{Deferred, GroupCommitPermit, PendingWriteTxn};
+use crate::coord::appends::{Deferred, GroupCommitPermit, PendingWriteTxn, UniqueKeyCheck};
 use crate::coord::catalog_oracle::CatalogTimestampPersistence;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::peek::PendingPeek;
@@ -210,6 +210,9 @@ pub enum Message<T = mz_repr::Timestamp> {
         /// Permit which limits how many group commits we run at once.
         Option<GroupCommitPermit>,
     ),
+    /// Commits the writes to user tables of a group commit once they have
+    /// been checked against the unique keys of the tables.
+    GroupCommitUniqueKeysChecked(UniqueKeyCheck),
     AdvanceTimelines,
     ClusterEvent(ClusterEvent),
     RemovePendingPeeks {
@@ -290,6 +293,7 @@ impl Message {
             Message::WriteLockGrant(_) => "write_lock_grant",
             Message::GroupCommitInitiate(..) => "group_commit_initiate",
             Message::GroupCommitApply(..) => "group_commit_apply",
+            Message::GroupCommitUniqueKeysChecked(_) => "group_commit_unique_keys_checked",
             Message::AdvanceTimelines => "advance_timelines",
             Message::ClusterEvent(_) => "cluster_event",
             Message::RemovePendingPeeks { .. } => "remove_pending_peeks",
@@ -887,6 +891,20 @@ impl ConnMeta {
     }
 }
 
+/// An in-progress `COPY ... TO <expr>` statement, which is retired once the
+/// compute controller reports the outcome of its sink.
+#[derive(Debug)]
+pub struct ActiveCopyTo {
+    /// The connection that issued the copy.
+    conn_id: ConnectionId,
+    /// Context used to send a response back to the client.
+    ctx: ExecuteContext,
+    /// The cluster on which the sink is running.
+    cluster_id: ComputeInstanceId,
+    /// Objects on which the copy depends.
+    depends_on: BTreeSet<GlobalId>,
+}
+
 #[derive(Debug)]
 /// A pending transaction waiting to be committed.
 pub struct PendingTxn {
@@ -1259,6 +1277,9 @@ pub struct Coordinator {
 
     /// A map from active subscribes to the subscribe description.
     active_subscribes: BTreeMap<GlobalId, ActiveSubscribe>,
+    /// A map from the sinks of in-progress `COPY ... TO <expr>` statements to
+    /// the context needed to respond to the client.
+    active_copies: BTreeMap<GlobalId, ActiveCopyTo>,
     /// A map from active webhooks to their invalidation handle.
     active_webhooks: BTreeMap<GlobalId, WebhookAppenderInvalidator>,
 
@@ -1880,6 +1901,7 @@ impl Coordinator {
                 data_source,
                 since: None,
                 status_collection_id,
+                table_layout: None,
             }
         };
 
@@ -1890,10 +1912,13 @@ impl Coordinator {
                 match entry.item() {
                     CatalogItem::Source(source) => Some((id, source_desc(source))),
                     CatalogItem::Table(table) => {
-                        let collection_desc = CollectionDescription::from_desc(
-                            table.desc.clone(),
-                            DataSourceOther::TableWrites,
-                        );
+                        let collection_desc = CollectionDescription {
+                            table_layout: table.layout.clone(),
+                            ..CollectionDescription::from_desc(
+                                table.desc.clone(),
+                                DataSourceOther::TableWrites,
+                            )
+                        };
                         Some((id, collection_desc))
                     }
                     CatalogItem::MaterializedView(mv) => {
@@ -2843,6 +2868,7 @@ pub fn serve(
                     client_pending_peeks: BTreeMap::new(),
                     pending_real_time_recency_timestamp: BTreeMap::new(),
                     active_subscribes: BTreeMap::new(),
+                    active_copies: BTreeMap::new(),
                     active_webhooks: BTreeMap::new(),
                     write_lock: Arc::new(tokio::sync::Mutex::new(())),
                     write_lock_wait_group: VecDeque::new(),
@@ -9,17 +9,21 @@
 
 //! Logic and types for all appends executed by the [`Coordinator`].
 
-use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 use std::time::Duration;
 
 use derivative::Derivative;
 use futures::future::{BoxFuture, FutureExt};
+use mz_catalog::memory::objects::{CatalogItem, Table};
 use mz_ore::metrics::MetricsFutureExt;
 use mz_ore::task;
 use mz_ore::vec::VecExt;
-use mz_repr::{Diff, GlobalId, Row, Timestamp};
-use mz_sql::plan::Plan;
+use mz_repr::{
+    ColumnName, Datum, DatumVec, Diff, GlobalId, RelationDesc, Row, RowArena, Timestamp,
+};
+use mz_sql::plan::{MergeActionPlan, MergeWhenPlan, OnConflictAction, OnConflictPlan, Plan};
 use mz_storage_client::client::TimestamplessUpdate;
 use mz_timestamp_oracle::WriteTimestamp;
 use tokio::sync::{oneshot, Notify, OwnedMutexGuard, OwnedSemaphorePermit, Semaphore};
@@ -27,9 +31,9 @@ use tracing::{warn, Instrument, Span};
 
 use crate::catalog::BuiltinTableUpdate;
 use crate::coord::{Coordinator, Message, PendingTxn, PlanValidity};
-use crate::session::{Session, WriteOp};
+use crate::session::{EndTransactionAction, Session, WriteOp};
 use crate::util::{CompletedClientTransmitter, ResultExt};
-use crate::ExecuteContext;
+use crate::{AdapterError, ExecuteContext};
 
 /// An operation that is deferred while waiting for a lock.
 #[derive(Debug)]
@@ -209,6 +213,10 @@ impl Coordinator {
     /// All applicable pending writes will be combined into a single Append command and sent to
     /// STORAGE as a single batch. All applicable writes will happen at the same timestamp and all
     /// involved tables will be advanced to some timestamp larger than the timestamp of the write.
+    ///
+    /// If some of the writes to user tables are to tables with unique keys, then all writes to
+    /// user tables are instead committed by a later group commit, once they have been checked
+    /// against the keys. See [`Coordinator::check_unique_keys`].
     #[tracing::instrument(level = "debug", skip_all)]
     pub(crate) async fn group_commit_initiate(
         &mut self,
@@ -258,6 +266,32 @@ impl Coordinator {
             (None, pending_writes)
         };
 
+        let (write_lock_guard, pending_writes) = if self.writes_unique_keys(&pending_writes) {
+            let write_lock_guard =
+                write_lock_guard.expect("writes to user tables hold the write lock");
+            let (user_writes, system_writes) = pending_writes
+                .into_iter()
+                .partition(|write| matches!(write, PendingWriteTxn::User { .. }));
+            self.check_unique_keys(user_writes, write_lock_guard).await;
+            (None, system_writes)
+        } else {
+            (write_lock_guard, pending_writes)
+        };
+
+        self.group_commit(write_lock_guard, pending_writes, permit)
+            .await;
+    }
+
+    /// Commits `pending_writes` at the same timestamp.
+    ///
+    /// If `pending_writes` contains writes to user tables, then `write_lock_guard` must hold the
+    /// write lock.
+    async fn group_commit(
+        &mut self,
+        write_lock_guard: Option<OwnedMutexGuard<()>>,
+        pending_writes: Vec<PendingWriteTxn>,
+        permit: Option<GroupCommitPermit>,
+    ) {
         // The value returned here still might be ahead of `now()` if `now()` has gone backwards at
         // any point during this method or if this was triggered from DDL. We will still commit the
         // write without waiting for `now()` to advance. This is ok because the next batch of writes
@@ -507,6 +541,468 @@ impl Coordinator {
             session.grant_write_lock(p);
         })
     }
+
+    /// Resolves the rows proposed by an `INSERT ... ON CONFLICT` against the
+    /// existing rows of the table described by `desc`, returning the updates
+    /// to append.
+    ///
+    /// Each of `rows` is a proposed row, followed by the existing row that
+    /// shares its key (or `NULL`s), followed by a boolean that is `true` iff
+    /// such an existing row was found. For the result to be correct, no other
+    /// write to the table may occur between the timestamp at which the
+    /// existing rows were read and the timestamp at which the updates are
+    /// appended, which holding the write lock across both guarantees.
+    pub(crate) fn resolve_insert_conflicts(
+        desc: &RelationDesc,
+        on_conflict: &OnConflictPlan,
+        rows: Vec<Row>,
+    ) -> Result<Vec<(Row, Diff)>, AdapterError> {
+        let arity = desc.arity();
+        let arena = RowArena::new();
+        let mut datum_vec = DatumVec::new();
+        let mut diffs = Vec::with_capacity(rows.len() * 2);
+        // The keys of all rows that this statement has inserted or updated,
+        // along with the existing row with each key, if any.
+        let mut seen = BTreeMap::new();
+        for row in rows {
+            let datums = datum_vec.borrow_with(&row);
+            let (proposed, rest) = datums.split_at(arity);
+            let (existing, copies) = rest.split_at(arity);
+            // As in PostgreSQL, proposed rows must meet the table's
+            // constraints even if they conflict.
+            for (idx, datum) in proposed.iter().enumerate() {
+                desc.constraints_met(idx, datum)?;
+            }
+            let matched = !copies[0].is_null();
+            let existing_row = matched.then(|| Row::pack_slice(existing));
+            let key = Row::pack(on_conflict.key.iter().map(|k| proposed[*k]));
+            let first = match seen.entry(key) {
+                Entry::Vacant(entry) => {
+                    entry.insert(existing_row);
+                    true
+                }
+                Entry::Occupied(entry) => {
+                    // A proposed row only matches several existing rows, or
+                    // copies of one, if the table already violates its key.
+                    // Updating them would leave the table in violation.
+                    if matches!(on_conflict.action, OnConflictAction::DoUpdate { .. })
+                        && *entry.get() != existing_row
+                    {
+                        return Err(AdapterError::OnConflictKeyNotUnique);
+                    }
+                    false
+                }
+            };
+            if matched
+                && copies[0].unwrap_int64() > 1
+                && matches!(on_conflict.action, OnConflictAction::DoUpdate { .. })
+            {
+                return Err(AdapterError::OnConflictKeyNotUnique);
+            }
+            match (&on_conflict.action, matched, first) {
+                // The proposed row conflicts with no row.
+                (_, false, true) => diffs.push((Row::pack_slice(proposed), 1)),
+                (OnConflictAction::DoNothing, _, _) => {}
+                // The proposed row conflicts with a row inserted or updated by
+                // an earlier proposed row.
+                (OnConflictAction::DoUpdate { .. }, _, false) => {
+                    return Err(AdapterError::OnConflictRowAffectedTwice);
+                }
+                (
+                    OnConflictAction::DoUpdate {
+                        assignments,
+                        selection,
+                    },
+                    true,
+                    true,
+                ) => {
+                    let mut datums = existing.to_vec();
+                    datums.extend_from_slice(proposed);
+                    if let Some(selection) = selection {
+                        if selection.eval(&datums, &arena)? != Datum::True {
+                            continue;
+                        }
+                    }
+                    let mut updated = existing.to_vec();
+                    for (idx, expr) in assignments {
+                        updated[*idx] = expr.eval(&datums, &arena)?;
+                    }
+                    for (idx, datum) in updated.iter().enumerate() {
+                        desc.constraints_met(idx, datum)?;
+                    }
+                    diffs.push((Row::pack_slice(existing), -1));
+                    diffs.push((Row::pack_slice(&updated), 1));
+                }
+            }
+        }
+        Ok(diffs)
+    }
+
+    /// Resolves the `WHEN` clauses of a `MERGE` into the table described by
+    /// `desc`, returning the updates to append along with the number of rows
+    /// inserted, updated, or deleted.
+    ///
+    /// Each of `rows` is a target row (or `NULL`s), followed by a source row
+    /// that matched it, followed by the number of copies of the target row in
+    /// the table (or `NULL`, if the source row matched no target row). As for
+    /// [`Coordinator::resolve_insert_conflicts`], the write lock must be held
+    /// from the read of `rows` until the updates are appended.
+    pub(crate) fn resolve_merge(
+        desc: &RelationDesc,
+        when_clauses: &[MergeWhenPlan],
+        rows: Vec<Row>,
+    ) -> Result<(Vec<(Row, Diff)>, usize), AdapterError> {
+        let arity = desc.arity();
+        let arena = RowArena::new();
+        let mut datum_vec = DatumVec::new();
+        let mut diffs = Vec::with_capacity(rows.len() * 2);
+        let mut affected_rows: Diff = 0;
+        // The target rows that this statement has updated or deleted.
+        let mut seen = BTreeSet::new();
+        for row in rows {
+            let datums = datum_vec.borrow_with(&row);
+            let (rest, count) = datums.split_at(datums.len() - 1);
+            let (target, source) = rest.split_at(arity);
+            // Expressions of `WHEN MATCHED` clauses are evaluated over the
+            // target row followed by the source row, and those of `WHEN NOT
+            // MATCHED` clauses over the source row alone.
+            let (matched, copies, datums) = match count[0] {
+                Datum::Int64(copies) => (true, copies, rest),
+                _ => (false, 1, source),
+            };
+            // As in PostgreSQL, only the first clause whose condition holds
+            // acts on the source row.
+            for clause in when_clauses.iter().filter(|c| c.matched == matched) {
+                if let Some(condition) = &clause.condition {
+                    if condition.eval(datums, &arena)? != Datum::True {
+                        continue;
+                    }
+                }
+                match &clause.action {
+                    MergeActionPlan::Update(assignments) => {
+                        if !seen.insert(Row::pack_slice(target)) {
+                            return Err(AdapterError::MergeRowAffectedTwice);
+                        }
+                        let mut updated = target.to_vec();
+                        for (idx, expr) in assignments {
+                            updated[*idx] = expr.eval(datums, &arena)?;
+                        }
+                        for (idx, datum) in updated.iter().enumerate() {
+                            desc.constraints_met(idx, datum)?;
+                        }
+                        diffs.push((Row::pack_slice(target), -copies));
+                        diffs.push((Row::pack_slice(&updated), copies));
+                        affected_rows += copies;
+                    }
+                    MergeActionPlan::Delete => {
+                        if !seen.insert(Row::pack_slice(target)) {
+                            return Err(AdapterError::MergeRowAffectedTwice);
+                        }
+                        diffs.push((Row::pack_slice(target), -copies));
+                        affected_rows += copies;
+                    }
+                    MergeActionPlan::Insert(values) => {
+                        let inserted = values
+                            .iter()
+                            .map(|expr| expr.eval(datums, &arena))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        for (idx, datum) in inserted.iter().enumerate() {
+                            desc.constraints_met(idx, datum)?;
+                        }
+                        diffs.push((Row::pack_slice(&inserted), 1));
+                        affected_rows += 1;
+                    }
+                    MergeActionPlan::DoNothing => {}
+                }
+                break;
+            }
+        }
+        let affected_rows = usize::try_from(affected_rows).expect("positive isize must fit");
+        Ok((diffs, affected_rows))
+    }
+
+    /// Returns whether some of `pending_writes` write to a table with
+    /// `PRIMARY KEY` or `UNIQUE` constraints.
+    fn writes_unique_keys(&self, pending_writes: &[PendingWriteTxn]) -> bool {
+        pending_writes
+            .iter()
+            .any(|pending_write_txn| match pending_write_txn {
+                PendingWriteTxn::User { writes, .. } => {
+                    writes.iter().any(|WriteOp { id, .. }| {
+                        matches!(
+                            self.catalog().try_get_entry(id).map(|entry| entry.item()),
+                            Some(CatalogItem::Table(table)) if !table.unique_keys.is_empty()
+                        )
+                    })
+                }
+                PendingWriteTxn::System { .. } => false,
+            })
+    }
+
+    /// Checks the writes to user tables `pending_writes` against the unique
+    /// keys of the tables that they write to, without blocking the
+    /// coordinator. Once the check completes, the transactions whose writes
+    /// satisfy the keys are committed by
+    /// [`Coordinator::group_commit_unique_keys_checked`].
+    ///
+    /// The only rows of the tables that are read are those that share their
+    /// value for one of the keys with a row that the writes insert, and they
+    /// are read from persist as of the current read timestamp.
+    /// `write_lock_guard` is held until the writes are committed, so the
+    /// tables' contents at that timestamp are also their contents at the
+    /// timestamp at which the writes are committed.
+    async fn check_unique_keys(
+        &mut self,
+        pending_writes: Vec<PendingWriteTxn>,
+        write_lock_guard: OwnedMutexGuard<()>,
+    ) {
+        let mut keys: BTreeMap<GlobalId, Vec<UniqueKey>> = BTreeMap::new();
+        for pending_write_txn in &pending_writes {
+            let PendingWriteTxn::User { writes, .. } = pending_write_txn else {
+                continue;
+            };
+            for WriteOp { id, rows } in writes {
+                let Some(CatalogItem::Table(table)) =
+                    self.catalog().try_get_entry(id).map(|entry| entry.item())
+                else {
+                    continue;
+                };
+                if table.unique_keys.is_empty() {
+                    continue;
+                }
+                let keys = keys.entry(*id).or_insert_with(|| UniqueKey::of(table));
+                for key in keys {
+                    key.insert_values(rows);
+                }
+            }
+        }
+        let keys: BTreeMap<_, _> = keys
+            .into_iter()
+            .map(|(id, keys)| (id, Arc::new(keys)))
+            .collect();
+
+        let read_ts = self.get_local_read_ts().await;
+        let mut snapshots = Vec::with_capacity(keys.len());
+        for (id, keys) in &keys {
+            let filter_keys = Arc::clone(keys);
+            let mut datum_vec = DatumVec::new();
+            let mut value = Row::default();
+            let filter = Box::new(move |row: &Row| {
+                let datums = datum_vec.borrow_with(row);
+                filter_keys
+                    .iter()
+                    .any(|key| key.pack(&datums, &mut value) && key.values.contains(&value))
+            });
+            let snapshot = self
+                .controller
+                .storage
+                .snapshot_filtered(*id, read_ts, filter)
+                .unwrap_or_terminate("cannot fail to fetch snapshot");
+            snapshots.push((*id, Arc::clone(keys), snapshot));
+        }
+
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(
+            || "group_commit_check_unique_keys",
+            async move {
+                let mut counts = BTreeMap::new();
+                for (id, keys, snapshot) in snapshots {
+                    let existing = snapshot
+                        .await
+                        .unwrap_or_terminate("cannot fail to fetch snapshot");
+                    counts.insert(id, UniqueKey::count(&keys, &existing));
+                }
+                let check = UniqueKeyCheck {
+                    pending_writes,
+                    write_lock_guard,
+                    keys,
+                    counts,
+                };
+                // It is not an error for this task to be running after `internal_cmd_rx` is dropped.
+                let result = internal_cmd_tx.send(Message::GroupCommitUniqueKeysChecked(check));
+                if let Err(e) = result {
+                    warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                }
+            }
+            .instrument(Span::current()),
+        );
+    }
+
+    /// Commits the transactions of a completed [`UniqueKeyCheck`] whose
+    /// writes satisfy the unique keys of the tables that they write to, and
+    /// rolls back the others.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub(crate) async fn group_commit_unique_keys_checked(&mut self, check: UniqueKeyCheck) {
+        let UniqueKeyCheck {
+            pending_writes,
+            write_lock_guard,
+            keys,
+            mut counts,
+        } = check;
+        let mut checked_writes = Vec::with_capacity(pending_writes.len());
+        for pending_write_txn in pending_writes {
+            let PendingWriteTxn::User { writes, .. } = &pending_write_txn else {
+                checked_writes.push(pending_write_txn);
+                continue;
+            };
+            match UniqueKey::check(&keys, &mut counts, writes) {
+                Ok(()) => checked_writes.push(pending_write_txn),
+                // A transaction whose writes would violate a unique key is
+                // rolled back, and none of its writes are applied.
+                Err(err) => {
+                    let PendingWriteTxn::User {
+                        pending_txn: PendingTxn { ctx, .. },
+                        ..
+                    } = pending_write_txn
+                    else {
+                        unreachable!("matched above");
+                    };
+                    let response = CompletedClientTransmitter::new(
+                        ctx,
+                        Err(err),
+                        EndTransactionAction::Rollback,
+                    );
+                    let (ctx, result) = response.finalize();
+                    ctx.retire(result);
+                }
+            }
+        }
+        self.group_commit(Some(write_lock_guard), checked_writes, None)
+            .await;
+    }
+}
+
+/// The writes to user tables of a group commit, which wait for a check against
+/// the unique keys of the tables that they write to.
+#[derive(Debug)]
+pub(crate) struct UniqueKeyCheck {
+    /// The writes to check.
+    pending_writes: Vec<PendingWriteTxn>,
+    /// Holds the coordinator's write lock from the read of the tables until
+    /// the writes are committed.
+    write_lock_guard: OwnedMutexGuard<()>,
+    /// The unique keys of each table with unique keys that the writes write
+    /// to.
+    keys: BTreeMap<GlobalId, Arc<Vec<UniqueKey>>>,
+    /// For each table and key, the number of rows of the table with each of
+    /// the key's values that the writes insert.
+    counts: BTreeMap<GlobalId, Vec<BTreeMap<Row, Diff>>>,
+}
+
+/// A `PRIMARY KEY` or `UNIQUE` constraint of a table.
+#[derive(Debug)]
+struct UniqueKey {
+    /// The positions of the key's columns.
+    columns: Vec<usize>,
+    /// The names of the key's columns.
+    names: Vec<ColumnName>,
+    /// Whether rows with `NULL` in one of the key's columns never conflict.
+    nulls_distinct: bool,
+    /// The values of the key that the checked writes insert.
+    values: BTreeSet<Row>,
+}
+
+impl UniqueKey {
+    /// Returns the unique keys of `table`, with no values.
+    fn of(table: &Table) -> Vec<UniqueKey> {
+        table
+            .unique_keys
+            .iter()
+            .map(|columns| UniqueKey {
+                columns: columns.clone(),
+                names: columns
+                    .iter()
+                    .map(|i| table.desc.get_name(*i).clone())
+                    .collect(),
+                // Only the keys of the table's relation type, whose columns
+                // are `NOT NULL` or which were declared `NULLS NOT DISTINCT`,
+                // consider `NULL`s equal.
+                nulls_distinct: !table.desc.typ().keys.contains(columns),
+                values: BTreeSet::new(),
+            })
+            .collect()
+    }
+
+    /// Packs the value of the key in the row `datums` into `value`, and
+    /// returns whether the value can conflict with that of another row.
+    fn pack(&self, datums: &[Datum], value: &mut Row) -> bool {
+        value
+            .packer()
+            .extend(self.columns.iter().map(|i| datums[*i]));
+        !self.nulls_distinct || self.columns.iter().all(|i| !datums[*i].is_null())
+    }
+
+    /// Records the values of the key in the rows that `updates` insert.
+    fn insert_values(&mut self, updates: &[(Row, Diff)]) {
+        let mut datum_vec = DatumVec::new();
+        let mut value = Row::default();
+        for (row, diff) in updates {
+            let datums = datum_vec.borrow_with(row);
+            if *diff > 0 && self.pack(&datums, &mut value) {
+                self.values.insert(value.clone());
+            }
+        }
+    }
+
+    /// Returns, for each of `keys`, the number of rows of `updates` with each
+    /// of the key's recorded values.
+    fn count(keys: &[UniqueKey], updates: &[(Row, Diff)]) -> Vec<BTreeMap<Row, Diff>> {
+        let mut datum_vec = DatumVec::new();
+        let mut value = Row::default();
+        let mut counts = vec![BTreeMap::new(); keys.len()];
+        for (row, diff) in updates {
+            let datums = datum_vec.borrow_with(row);
+            for (key, counts) in keys.iter().zip(&mut counts) {
+                if key.pack(&datums, &mut value) && key.values.contains(&value) {
+                    *counts.entry(value.clone()).or_default() += diff;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Checks that applying `writes` after the rows counted in `counts`
+    /// leaves at most one row with each value of each of `keys`, and if so,
+    /// counts the rows that `writes` insert and delete.
+    fn check(
+        keys: &BTreeMap<GlobalId, Arc<Vec<UniqueKey>>>,
+        counts: &mut BTreeMap<GlobalId, Vec<BTreeMap<Row, Diff>>>,
+        writes: &[WriteOp],
+    ) -> Result<(), AdapterError> {
+        let mut deltas = Vec::new();
+        for (id, keys) in keys {
+            let mut updates: Vec<_> = writes
+                .iter()
+                .filter(|write| write.id == *id)
+                .flat_map(|write| write.rows.iter().cloned())
+                .collect();
+            if updates.is_empty() {
+                continue;
+            }
+            differential_dataflow::consolidation::consolidate(&mut updates);
+            let delta = UniqueKey::count(keys, &updates);
+            for ((key, key_counts), delta) in keys.iter().zip(&counts[id]).zip(&delta) {
+                for (value, diff) in delta {
+                    if key_counts.get(value).copied().unwrap_or(0) + diff > 1 {
+                        return Err(AdapterError::UniqueViolation {
+                            columns: key.names.clone(),
+                            value: value.clone(),
+                        });
+                    }
+                }
+            }
+            deltas.push((*id, delta));
+        }
+        for (id, delta) in deltas {
+            let counts = counts.get_mut(&id).expect("counts exist");
+            for (key_counts, delta) in counts.iter_mut().zip(delta) {
+                for (value, diff) in delta {
+                    *key_counts.entry(value).or_default() += diff;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Helper struct to run a builtin table append.
@@ -555,6 +555,8 @@ impl Coordinator {
                     | Statement::AlterOwner(_)
                     | Statement::AlterRole(_)
                     | Statement::AlterSecret(_)
+                    | Statement::AlterType(_)
+                    | Statement::AlterTable(_)
                     | Statement::AlterSink(_)
                     | Statement::AlterSource(_)
                     | Statement::AlterSystemReset(_)
@@ -574,6 +576,7 @@ impl Coordinator {
                     | Statement::CreateSubsource(_)
                     | Statement::CreateTable(_)
                     | Statement::CreateType(_)
+                    | Statement::CreateFunction(_)
                     | Statement::CreateView(_)
                     | Statement::CreateWebhookSource(_)
                     | Statement::Delete(_)
@@ -582,6 +585,7 @@ impl Coordinator {
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
                     | Statement::Insert(_)
+                    | Statement::Merge(_)
                     | Statement::ReassignOwned(_)
                     | Statement::RevokePrivileges(_)
                     | Statement::RevokeRole(_)
@@ -898,6 +902,8 @@ impl Coordinator {
             // Inform the target session (if it asks) about the cancellation.
             let _ = conn_meta.cancel_tx.send(Canceled::Canceled);
 
+            self.cancel_active_copies(&conn_id);
+
             for PendingPeek {
                 sender: rows_tx,
                 conn_id: _,
@@ -432,6 +432,25 @@ impl Coordinator {
             }
         }
 
+        // Clean up any active copies that rely on dropped relations or clusters.
+        let mut copies_to_drop = vec![];
+        for (sink_id, active_copy) in &self.active_copies {
+            if let Some(id) = active_copy
+                .depends_on
+                .iter()
+                .find(|id| relations_to_drop.contains(id))
+            {
+                let entry = self.catalog().get_entry(id);
+                let name = self
+                    .catalog()
+                    .resolve_full_name(entry.name(), Some(&active_copy.conn_id));
+                copies_to_drop.push((format!("relation {}", name.to_string().quoted()), *sink_id));
+            } else if clusters_to_drop.contains(&active_copy.cluster_id) {
+                let name = self.catalog().get_cluster(active_copy.cluster_id).name();
+                copies_to_drop.push((format!("cluster {}", name.quoted()), *sink_id));
+            }
+        }
+
         let storage_ids_to_drop = sources_to_drop
             .iter()
             .chain(storage_sinks_to_drop.iter())
@@ -565,6 +584,12 @@ impl Coordinator {
                     }
                 }
             }
+            for (dropped_name, sink_id) in copies_to_drop {
+                let error = AdapterError::Unstructured(anyhow::anyhow!(
+                    "copy could not complete because {dropped_name} was dropped"
+                ));
+                self.retire_copy_to(sink_id, Err(error));
+            }
             if !indexes_to_drop.is_empty() {
                 self.drop_indexes(indexes_to_drop);
             }
@@ -1223,6 +1248,8 @@ impl Coordinator {
                 },
                 Op::AlterRole { .. }
                 | Op::AlterSetCluster { .. }
+                | Op::AlterType { .. }
+                | Op::AlterTable { .. }
                 | Op::UpdatePrivilege { .. }
                 | Op::UpdateDefaultPrivilege { .. }
                 | Op::GrantRole { .. }
@@ -18,6 +18,7 @@ use futures::FutureExt;
 use mz_adapter_types::connection::ConnectionId;
 use mz_controller::clusters::ClusterEvent;
 use mz_controller::ControllerResponse;
+use mz_ore::cast::CastFrom;
 use mz_ore::now::EpochMillis;
 use mz_ore::task;
 use mz_persist_client::usage::ShardsUsageReferenced;
@@ -30,13 +31,14 @@ use rand::{rngs, Rng, SeedableRng};
 use tracing::{event, warn, Instrument, Level};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use crate::command::Command;
+use crate::command::{Command, ExecuteResponse};
 use crate::coord::appends::Deferred;
 use crate::coord::statement_logging::StatementLoggingId;
 use crate::coord::{
     Coordinator, CreateConnectionValidationReady, Message, PeekStage, PeekStageFinish,
     PendingReadTxn, PlanValidity, PurifiedStatementReady, RealTimeRecencyContext,
 };
+use crate::error::AdapterError;
 use crate::session::Session;
 use crate::statement_logging::StatementLifecycleEvent;
 use crate::util::{ComputeSinkId, ResultExt};
@@ -100,6 +102,9 @@ impl Coordinator {
                     self.group_commit_apply(timestamp, responses, write_lock_guard, permit)
                         .await;
                 }
+                Message::GroupCommitUniqueKeysChecked(check) => {
+                    self.group_commit_unique_keys_checked(check).await;
+                }
                 Message::AdvanceTimelines => {
                     self.advance_timelines().await;
                 }
@@ -239,6 +244,7 @@ impl Coordinator {
                     persist_location: _,
                     relation_desc: _,
                     txns_shard: _,
+                    table_layout: _,
                 } = &collection.collection_metadata;
                 [*remap_shard, *status_shard, Some(*data_shard)].into_iter()
             })
@@ -367,6 +373,14 @@ impl Coordinator {
                     }
                 }
             }
+            ControllerResponse::CopyToResponse(sink_id, response) => {
+                // The copy could have been canceled already, in which case
+                // `retire_copy_to` is a no-op.
+                let response = response
+                    .map(|count| ExecuteResponse::Copied(usize::cast_from(count)))
+                    .map_err(AdapterError::Unstructured);
+                self.retire_copy_to(sink_id, response);
+            }
             ControllerResponse::ComputeReplicaMetrics(replica_id, new) => {
                 let m = match self
                     .transient_replica_metadata
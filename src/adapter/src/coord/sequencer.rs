@@ -214,6 +214,12 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::CreateFunction(plan) => {
+                    let result = self
+                        .sequence_create_function(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::Comment(plan) => {
                     let result = self.sequence_comment_on(ctx.session(), plan).await;
                     ctx.retire(result);
@@ -320,6 +326,9 @@ impl Coordinator {
                         session,
                     );
                 }
+                Plan::CopyTo(plan) => {
+                    self.sequence_copy_to(ctx, plan, target_cluster).await;
+                }
                 Plan::ExplainPlan(plan) => {
                     self.sequence_explain_plan(ctx, plan, target_cluster).await;
                 }
@@ -408,6 +417,13 @@ impl Coordinator {
                     let result = self.sequence_alter_secret(ctx.session(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterType(plan) => {
+                    let result = self.sequence_alter_type(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
+                Plan::AlterTable(plan) => {
+                    self.sequence_alter_table(ctx, plan).await;
+                }
                 Plan::PurifiedAlterSource {
                     alter_source,
                     subsources,
@@ -745,6 +761,7 @@ impl Coordinator {
                     kind: MutationKind::Insert,
                     returning: Vec::new(),
                     max_result_size: catalog.system_config().max_result_size(),
+                    affected_rows: None,
                 };
                 Self::send_diffs(session, diffs_plan)
             }
@@ -760,7 +777,20 @@ impl Coordinator {
         session: &mut Session,
         mut plan: plan::SendDiffsPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
-        let affected_rows = {
+        let affected_rows = if let Some(affected_rows) = plan.affected_rows {
+            affected_rows
+        } else if let MutationKind::Insert = plan.kind {
+            // Inserts only retract rows when `ON CONFLICT DO UPDATE` updates
+            // them. As in PostgreSQL, each row inserted or updated counts
+            // once.
+            let affected_rows: Diff = plan
+                .updates
+                .iter()
+                .map(|(_, diff)| *diff)
+                .filter(|diff| *diff > 0)
+                .sum();
+            usize::try_from(affected_rows).expect("positive isize must fit")
+        } else {
             let mut affected_rows = Diff::from(0);
             let mut all_positive_diffs = true;
             // If all diffs are positive, the number of affected rows is just the
@@ -821,6 +851,7 @@ impl Coordinator {
             MutationKind::Delete => ExecuteResponse::Deleted(affected_rows),
             MutationKind::Insert => ExecuteResponse::Inserted(affected_rows),
             MutationKind::Update => ExecuteResponse::Updated(affected_rows / 2),
+            MutationKind::Merge => ExecuteResponse::Merged(affected_rows),
         })
     }
 }
@@ -35,7 +35,9 @@ use mz_repr::explain::{
     ExplainFormat, ExprHumanizer, ExprHumanizerExt, TransientItem, UsedIndexes,
 };
 use mz_repr::role_id::RoleId;
-use mz_repr::{ColumnName, Datum, Diff, GlobalId, RelationDesc, Row, RowArena, Timestamp};
+use mz_repr::{
+    ColumnName, Datum, Diff, GlobalId, NotNullViolation, RelationDesc, Row, RowArena, Timestamp,
+};
 use mz_sql::ast::{ExplainStage, IndexOptionName};
 use mz_sql::catalog::{
     CatalogCluster, CatalogClusterReplica, CatalogDatabase, CatalogError,
@@ -49,7 +51,8 @@ use mz_sql::names::{
 // Import `plan` module, but only import select elements to avoid merge conflicts on use statements.
 use mz_adapter_types::connection::ConnectionId;
 use mz_catalog::memory::objects::{
-    CatalogItem, Cluster, Connection, DataSourceDesc, Secret, Sink, Source, Table, Type,
+    CatalogItem, Cluster, Connection, DataSourceDesc, Func, FuncInner, Secret, Sink, Source, Table,
+    Type,
 };
 use mz_sql::plan::{
     AlterConnectionAction, AlterConnectionPlan, ExplainSinkSchemaPlan, Explainee, Index,
@@ -71,7 +74,7 @@ use mz_sql_parser::ast::{
 use mz_ssh_util::keys::SshKeyPairSet;
 use mz_storage_client::controller::{CollectionDescription, DataSource, DataSourceOther};
 use mz_storage_types::connections::inline::IntoInlineConnection;
-use mz_storage_types::controller::StorageError;
+use mz_storage_types::controller::{StorageError, TableLayout};
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::notice::{OptimizerNoticeApi, OptimizerNoticeKind, RawOptimizerNotice};
 use mz_transform::EmptyStatisticsOracle;
@@ -99,12 +102,15 @@ use crate::error::AdapterError;
 use crate::explain::explain_dataflow;
 use crate::explain::optimizer_trace::OptimizerTrace;
 use crate::notice::{AdapterNotice, DroppedInUseIndex};
-use crate::optimize::dataflows::{prep_scalar_expr, EvalTime, ExprPrepStyle};
+use crate::optimize::dataflows::{
+    dataflow_import_id_bundle, prep_scalar_expr, EvalTime, ExprPrepStyle,
+};
 use crate::optimize::{self, Optimize, OptimizerConfig};
 use crate::session::{EndTransactionAction, Session, TransactionOps, TransactionStatus, WriteOp};
 use crate::util::{viewable_variables, ClientTransmitter, ResultExt};
 use crate::{guard_write_critical_section, PeekResponseUnary, TimestampExplanation};
 
+mod copy_to;
 mod create_index;
 mod create_materialized_view;
 mod create_view;
@@ -286,6 +292,7 @@ impl Coordinator {
                                     data_source,
                                     since: None,
                                     status_collection_id,
+                                    table_layout: None,
                                 },
                             )],
                         )
@@ -559,10 +566,15 @@ impl Coordinator {
             None
         };
         let table_id = self.catalog_mut().allocate_user_id().await?;
+        let layout = TableLayout::new(&table.desc);
         let table = Table {
             create_sql: Some(table.create_sql),
             desc: table.desc,
             defaults: table.defaults,
+            domains: table.domains,
+            collations: table.collations,
+            unique_keys: table.unique_keys,
+            layout: Some(layout),
             conn_id: conn_id.cloned(),
             resolved_ids,
             custom_logical_compaction_window: None,
@@ -585,10 +597,13 @@ impl Coordinator {
                     coord.set_statement_execution_timestamp(id, register_ts);
                 }
 
-                let collection_desc = CollectionDescription::from_desc(
-                    table.desc.clone(),
-                    DataSourceOther::TableWrites,
-                );
+                let collection_desc = CollectionDescription {
+                    table_layout: table.layout.clone(),
+                    ..CollectionDescription::from_desc(
+                        table.desc.clone(),
+                        DataSourceOther::TableWrites,
+                    )
+                };
                 coord
                     .controller
                     .storage
@@ -874,6 +889,33 @@ impl Coordinator {
         }
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_create_function(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateFunctionPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let func = Func {
+            create_sql: Some(plan.func.create_sql),
+            inner: FuncInner::User(Arc::new(plan.func.inner)),
+            resolved_ids,
+        };
+        let id = self.catalog_mut().allocate_user_id().await?;
+        let oid = self.catalog_mut().allocate_oid()?;
+        let op = catalog::Op::CreateItem {
+            id,
+            oid,
+            name: plan.name,
+            item: CatalogItem::Func(func),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedFunction),
+            Err(err) => Err(err),
+        }
+    }
+
     pub(super) async fn sequence_comment_on(
         &mut self,
         session: &Session,
@@ -1720,15 +1762,28 @@ impl Coordinator {
             if let (Some(mut ops), write_lock_guard) = txn.into_ops_and_lock_guard() {
                 match &mut ops {
                     TransactionOps::Writes(writes) => {
-                        for WriteOp { id, .. } in &mut writes.iter() {
+                        for WriteOp { id, rows } in &mut writes.iter() {
                             // Re-verify this id exists.
-                            let _ = self.catalog().try_get_entry(id).ok_or_else(|| {
+                            let entry = self.catalog().try_get_entry(id).ok_or_else(|| {
                                 AdapterError::Catalog(mz_catalog::memory::error::Error {
                                     kind: mz_catalog::memory::error::ErrorKind::Sql(
                                         CatalogError::UnknownItem(id.to_string()),
                                     ),
                                 })
                             })?;
+                            // Re-verify the table's columns haven't changed. Each
+                            // statement's rows have the same columns, so it
+                            // suffices to check one of them.
+                            if let CatalogItem::Table(table) = entry.item() {
+                                let arity = table.desc.arity();
+                                let first = rows.first();
+                                if first.map_or(false, |(row, _)| row.iter().count() != arity) {
+                                    return Err(AdapterError::ChangedPlan(format!(
+                                        "table {} was altered while the transaction was in progress",
+                                        self.catalog().resolve_full_name(entry.name(), None)
+                                    )));
+                                }
+                            }
                         }
 
                         // `rows` can be empty if, say, a DELETE's WHERE clause had 0 results.
@@ -2886,6 +2941,8 @@ impl Coordinator {
                     assignments: BTreeMap::new(),
                     kind: MutationKind::Insert,
                     returning: plan.returning,
+                    on_conflict: None,
+                    when_clauses: Vec::new(),
                 };
 
                 self.sequence_read_then_write(ctx, read_then_write_plan)
@@ -2914,6 +2971,8 @@ impl Coordinator {
             assignments,
             finishing,
             returning,
+            on_conflict,
+            when_clauses,
         } = plan;
 
         // Read then writes can be queued, so re-verify the id exists.
@@ -3047,7 +3106,18 @@ impl Coordinator {
                 timeout_dur = Duration::MAX;
             }
 
-            let make_diffs = move |rows: Vec<Row>| -> Result<Vec<(Row, Diff)>, AdapterError> {
+            // Unlike for other mutations, the number of rows a `MERGE` affects
+            // is not determined by its diffs, so `make_diffs` reports it here.
+            let mut merged_rows = None;
+            let mut make_diffs = |rows: Vec<Row>| -> Result<Vec<(Row, Diff)>, AdapterError> {
+                if let Some(on_conflict) = &on_conflict {
+                    return Self::resolve_insert_conflicts(&desc, on_conflict, rows);
+                }
+                if let MutationKind::Merge = kind {
+                    let (diffs, affected_rows) = Self::resolve_merge(&desc, &when_clauses, rows)?;
+                    merged_rows = Some(affected_rows);
+                    return Ok(diffs);
+                }
                 let arena = RowArena::new();
                 // Use 2x row len incase there's some assignments.
                 let mut diffs = Vec::with_capacity(rows.len() * 2);
@@ -3079,6 +3149,7 @@ impl Coordinator {
                         // updated value.
                         MutationKind::Update | MutationKind::Delete => diffs.push((row, -1)),
                         MutationKind::Insert => diffs.push((row, 1)),
+                        MutationKind::Merge => unreachable!("resolved above"),
                     }
                 }
                 for (row, diff) in &diffs {
@@ -3229,6 +3300,7 @@ impl Coordinator {
                             kind,
                             returning: returning_rows,
                             max_result_size,
+                            affected_rows: merged_rows,
                         },
                     );
                     ctx.retire(result);
@@ -3464,6 +3536,219 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Secret))
     }
 
+    pub(super) async fn sequence_alter_type(
+        &mut self,
+        session: &Session,
+        plan::AlterTypePlan { id, create_sql }: plan::AlterTypePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let ops = vec![catalog::Op::AlterType { id, create_sql }];
+        self.catalog_transact(Some(session), ops).await?;
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Type))
+    }
+
+    pub(super) async fn sequence_alter_table(
+        &mut self,
+        mut ctx: ExecuteContext,
+        plan: plan::AlterTablePlan,
+    ) {
+        // Altering the table's columns must not interleave with other writes
+        // to it, so hold the write lock for the duration of the alteration.
+        let id = plan.id;
+        guard_write_critical_section!(self, ctx, Plan::AlterTable(plan), btreeset! {id});
+
+        let result = self.sequence_alter_table_inner(&mut ctx, plan).await;
+        ctx.retire(result);
+    }
+
+    async fn sequence_alter_table_inner(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan::AlterTablePlan {
+            id,
+            create_sql,
+            action,
+            layout,
+            dependents,
+            indexes,
+        }: plan::AlterTablePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        // The table's existing rows are not rewritten, so a `NOT NULL` column
+        // without a default can only be added to an empty table.
+        if let plan::AlterTableAction::AddColumn {
+            name,
+            typ,
+            fill: None,
+        } = &action
+        {
+            if !typ.nullable {
+                let read_ts = self.get_local_read_ts().await;
+                let contents = self
+                    .controller
+                    .storage
+                    .snapshot(id, read_ts)
+                    .await
+                    .unwrap_or_terminate("cannot fail to fetch snapshot");
+                let has_pending_writes = self.pending_writes.iter().any(|write| match write {
+                    PendingWriteTxn::User { writes, .. } => {
+                        writes.iter().any(|write| write.id == id)
+                    }
+                    PendingWriteTxn::System { .. } => false,
+                });
+                if !contents.is_empty() || has_pending_writes {
+                    return Err(AdapterError::ConstraintViolation(NotNullViolation(
+                        name.clone(),
+                    )));
+                }
+            }
+        }
+
+        // Indexes on the table are replaced by indexes over the table's new
+        // columns.
+        let mut ops = vec![];
+        let mut rebuilt_indexes = vec![];
+        for index_id in indexes {
+            let entry = self.catalog().get_entry(&index_id);
+            let mut index = entry.index().expect("dependent is an index").clone();
+            if let plan::AlterTableAction::DropColumn { column } = &action {
+                let arity = self
+                    .catalog()
+                    .get_entry(&id)
+                    .desc_opt()
+                    .expect("table has a desc")
+                    .arity();
+                let permutation: Vec<_> = (0..arity)
+                    .map(|c| if c > *column { c - 1 } else { c })
+                    .collect();
+                for key in &mut index.keys {
+                    key.permute(&permutation);
+                }
+            }
+            let name = entry.name().clone();
+            let owner_id = *entry.owner_id();
+            let new_id = self.catalog_mut().allocate_user_id().await?;
+            let oid = self.catalog_mut().allocate_oid()?;
+            ops.push(catalog::Op::DropObject(ObjectId::Item(index_id)));
+            rebuilt_indexes.push(catalog::Op::CreateItem {
+                id: new_id,
+                oid,
+                name,
+                item: CatalogItem::Index(index),
+                owner_id,
+            });
+        }
+        let new_index_ids: Vec<_> = rebuilt_indexes
+            .iter()
+            .map(|op| match op {
+                catalog::Op::CreateItem { id, .. } => *id,
+                _ => unreachable!("rebuilt indexes are created"),
+            })
+            .collect();
+        let dropped_column = match &action {
+            plan::AlterTableAction::AddColumn { .. } => None,
+            plan::AlterTableAction::DropColumn { column } => Some(*column),
+        };
+        ops.push(catalog::Op::AlterTable {
+            id,
+            create_sql,
+            layout,
+            dropped_column,
+            dependents,
+        });
+        ops.extend(rebuilt_indexes);
+
+        self.catalog_transact_with_side_effects(Some(ctx.session()), ops, |coord| async {
+            let (desc, layout) = match coord.catalog().get_entry(&id).item() {
+                CatalogItem::Table(table) => (
+                    table.desc.clone(),
+                    table.layout.clone().expect("user tables have a layout"),
+                ),
+                _ => unreachable!("altered item must be a table"),
+            };
+            let register_ts = coord.get_local_write_ts().await.timestamp;
+            coord
+                .controller
+                .storage
+                .alter_table_desc(id, desc, layout, register_ts)
+                .await
+                .unwrap_or_terminate("cannot fail to alter table");
+            coord.apply_local_write(register_ts).await;
+
+            // Writes that were planned against the old columns but not yet
+            // applied must be adjusted, too.
+            for write in &mut coord.pending_writes {
+                if let PendingWriteTxn::User { writes, .. } = write {
+                    for write in writes.iter_mut().filter(|write| write.id == id) {
+                        for (row, _) in &mut write.rows {
+                            *row = match &action {
+                                plan::AlterTableAction::AddColumn { fill, .. } => {
+                                    let fill = fill.as_ref().map_or(Datum::Null, Row::unpack_first);
+                                    Row::pack(row.iter().chain(iter::once(fill)))
+                                }
+                                plan::AlterTableAction::DropColumn { column } => Row::pack(
+                                    row.iter()
+                                        .enumerate()
+                                        .filter(|(i, _)| i != column)
+                                        .map(|(_, datum)| datum),
+                                ),
+                            };
+                        }
+                    }
+                }
+            }
+
+            for index_id in new_index_ids {
+                coord.ship_rebuilt_index(index_id).await;
+            }
+            coord.trigger_group_commit();
+        })
+        .await?;
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
+    /// Builds and ships the dataflow of the index `id`, which `ALTER TABLE`
+    /// created to replace an index over the table's old columns.
+    async fn ship_rebuilt_index(&mut self, id: GlobalId) {
+        let entry = self.catalog().get_entry(&id);
+        let index = entry.index().expect("rebuilt item is an index").clone();
+        let name = entry.name().clone();
+        let compute_instance = self
+            .instance_snapshot(index.cluster_id)
+            .expect("compute instance exists");
+        let optimizer_config = optimize::OptimizerConfig::from(self.catalog().system_config());
+        let mut optimizer = optimize::index::Optimizer::new(
+            self.owned_catalog(),
+            compute_instance,
+            id,
+            optimizer_config,
+        );
+
+        // MIR ⇒ MIR optimization (global)
+        let index_plan = optimize::index::Index::new(&name, &index.on, &index.keys);
+        let global_mir_plan = optimizer
+            .optimize(index_plan)
+            .expect("cannot fail to optimize index");
+        // MIR ⇒ LIR lowering and LIR ⇒ LIR optimization (global)
+        let global_lir_plan = optimizer
+            .optimize(global_mir_plan.clone())
+            .expect("cannot fail to optimize index");
+
+        let catalog = self.catalog_mut();
+        catalog.set_optimized_plan(id, global_mir_plan.df_desc().clone());
+        catalog.set_physical_plan(id, global_lir_plan.df_desc().clone());
+
+        let (mut df_desc, df_meta) = global_lir_plan.unapply();
+        let id_bundle = dataflow_import_id_bundle(&df_desc, index.cluster_id);
+        let since = self.least_valid_read(&id_bundle);
+        df_desc.set_as_of(since);
+        let df_meta = self.catalog().render_notices(df_meta, Some(id));
+        self.catalog_mut().set_dataflow_metainfo(id, df_meta);
+
+        self.ship_dataflow(df_desc, index.cluster_id).await;
+        if let Some(window) = index.custom_logical_compaction_window {
+            self.update_compute_base_read_policy(index.cluster_id, id, window.into());
+        }
+    }
+
     pub(super) async fn sequence_alter_connection(
         &mut self,
         ctx: ExecuteContext,
@@ -3860,37 +4145,49 @@ impl Coordinator {
 
                 referenced_subsources.sort();
 
-                // Remove dropped references from text columns.
+                // Remove dropped references from text and enum columns.
                 match &mut create_source_stmt.connection {
                     CreateSourceConnection::Postgres { options, .. } => {
                         options.retain_mut(|option| {
-                            if option.name != PgConfigOptionName::TextColumns {
+                            if option.name != PgConfigOptionName::TextColumns
+                                && option.name != PgConfigOptionName::EnumColumns
+                            {
                                 return true;
                             }
 
-                            // We know this is text_cols
+                            // We know this is text_cols or enum_cols
                             match &mut option.value {
                                 Some(WithOptionValue::Sequence(names)) => {
-                                    names.retain(|name| match name {
-                                        WithOptionValue::UnresolvedItemName(
-                                            column_qualified_reference,
-                                        ) => {
-                                            mz_ore::soft_assert_eq_or_log!(
-                                                column_qualified_reference.0.len(), 4
-                                            );
-                                            if column_qualified_reference.0.len() == 4 {
-                                                let mut table = column_qualified_reference.clone();
-                                                table.0.truncate(3);
-                                                !dropped_references.contains(&table)
-                                            } else {
-                                                tracing::warn!(
-                                                    "PgConfigOptionName::TextColumns had unexpected value {:?}; should have 4 components",
-                                                    column_qualified_reference
-                                                );
-                                                true
+                                    names.retain(|name| {
+                                        let column_qualified_reference = match name {
+                                            WithOptionValue::UnresolvedItemName(column) => column,
+                                            // Enum columns pair each column
+                                            // with its type.
+                                            WithOptionValue::Sequence(pair) => {
+                                                match pair.as_slice() {
+                                                    [WithOptionValue::UnresolvedItemName(column), _] => {
+                                                        column
+                                                    }
+                                                    _ => return true,
+                                                }
                                             }
+                                            _ => return true,
+                                        };
+                                        mz_ore::soft_assert_eq_or_log!(
+                                            column_qualified_reference.0.len(), 4
+                                        );
+                                        if column_qualified_reference.0.len() == 4 {
+                                            let mut table = column_qualified_reference.clone();
+                                            table.0.truncate(3);
+                                            !dropped_references.contains(&table)
+                                        } else {
+                                            tracing::warn!(
+                                                "{} had unexpected value {:?}; should have 4 components",
+                                                option.name,
+                                                column_qualified_reference
+                                            );
+                                            true
                                         }
-                                        _ => true,
                                     });
 
                                     names.sort();
@@ -4071,6 +4368,32 @@ impl Coordinator {
                     value: details,
                 });
 
+                // Merge enum columns
+                let new_enum_columns = options
+                    .iter()
+                    .find(|option| option.name == AlterSourceAddSubsourceOptionName::EnumColumns)
+                    .and_then(|option| option.value.clone());
+
+                if let Some(WithOptionValue::Sequence(new)) = new_enum_columns {
+                    match curr_options
+                        .iter_mut()
+                        .find(|option| option.name == PgConfigOptionName::EnumColumns)
+                    {
+                        Some(curr) => {
+                            let curr = match curr.value {
+                                Some(WithOptionValue::Sequence(ref mut curr)) => curr,
+                                _ => unreachable!(),
+                            };
+                            curr.extend(new);
+                            curr.sort();
+                        }
+                        None => curr_options.push(PgConfigOption {
+                            name: PgConfigOptionName::EnumColumns,
+                            value: Some(WithOptionValue::Sequence(new)),
+                        }),
+                    }
+                }
+
                 // Merge text columns
                 let curr_text_columns = curr_options
                     .iter_mut()
@@ -4233,6 +4556,7 @@ impl Coordinator {
                                     data_source,
                                     since: None,
                                     status_collection_id,
+                                    table_layout: None,
                                 },
                             )],
                         )
@@ -0,0 +1,233 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::BTreeSet;
+
+use mz_adapter_types::connection::ConnectionId;
+use mz_compute_types::ComputeInstanceId;
+use mz_expr::CollectionPlan;
+use mz_repr::{Datum, GlobalId, RowArena};
+use mz_sql::plan;
+use mz_storage_types::sinks::S3UploadInfo;
+
+use crate::command::ExecuteResponse;
+use crate::coord::sequencer::inner::check_log_reads;
+use crate::coord::{ActiveCopyTo, Coordinator, TargetCluster};
+use crate::error::AdapterError;
+use crate::optimize::copy_to::CopyToContext;
+use crate::optimize::dataflows::{prep_scalar_expr, EvalTime, ExprPrepStyle};
+use crate::optimize::{self, Optimize};
+use crate::session::TransactionStatus;
+use crate::util::ResultExt;
+use crate::{ExecuteContext, TimelineContext};
+
+impl Coordinator {
+    /// Starts a `COPY ... TO <expr>`.
+    ///
+    /// The statement is retired once the compute controller reports the
+    /// outcome of the copy (see [`Coordinator::retire_copy_to`]).
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn sequence_copy_to(
+        &mut self,
+        mut ctx: ExecuteContext,
+        plan: plan::CopyToPlan,
+        target_cluster: TargetCluster,
+    ) {
+        match self.copy_to_ship(&mut ctx, plan, target_cluster).await {
+            Ok((sink_id, cluster_id, depends_on)) => {
+                let active_copy = ActiveCopyTo {
+                    conn_id: ctx.session().conn_id().clone(),
+                    ctx,
+                    cluster_id,
+                    depends_on,
+                };
+                self.active_copies.insert(sink_id, active_copy);
+            }
+            Err(e) => ctx.retire(Err(e)),
+        }
+    }
+
+    /// Optimizes the copy and ships its dataflow, returning the ID of the
+    /// sink, the cluster it runs on, and the objects it depends on.
+    async fn copy_to_ship(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan::CopyToPlan {
+            select_plan,
+            desc,
+            to,
+            connection,
+            connection_id,
+            format,
+            max_file_size,
+        }: plan::CopyToPlan,
+        target_cluster: TargetCluster,
+    ) -> Result<(GlobalId, ComputeInstanceId, BTreeSet<GlobalId>), AdapterError> {
+        // The copy has side effects outside of Materialize, which we cannot
+        // undo if the surrounding transaction is rolled back.
+        if !matches!(ctx.session().transaction(), TransactionStatus::Started(_)) {
+            return Err(AdapterError::OperationProhibitsTransaction(
+                "COPY ... TO <expr>".into(),
+            ));
+        }
+
+        let cluster = self
+            .catalog()
+            .resolve_target_cluster(target_cluster, ctx.session())?;
+        let cluster_id = cluster.id;
+        if cluster.replicas().next().is_none() {
+            return Err(AdapterError::NoClusterReplicasAvailable(
+                cluster.name.clone(),
+            ));
+        }
+
+        let depends_on = select_plan.source.depends_on();
+
+        // The sink runs on every replica, so reads of introspection sources
+        // are only allowed on clusters with a single replica.
+        let notices = check_log_reads(
+            self.catalog(),
+            cluster,
+            &depends_on,
+            &mut None,
+            ctx.session().vars(),
+        )?;
+        ctx.session_mut().add_notices(notices);
+
+        // Determine timeline.
+        let mut timeline = self.validate_timeline_context(depends_on.clone())?;
+        if matches!(timeline, TimelineContext::TimestampIndependent)
+            && select_plan.source.contains_temporal()
+        {
+            // If the from IDs are timestamp independent but the query contains temporal functions
+            // then the timeline context needs to be upgraded to timestamp dependent.
+            timeline = TimelineContext::TimestampDependent;
+        }
+
+        // Evaluate the destination of the copy.
+        let uri = {
+            let mut to = to;
+            prep_scalar_expr(
+                &mut to,
+                ExprPrepStyle::OneShot {
+                    logical_time: EvalTime::NotAvailable,
+                    session: ctx.session(),
+                    catalog_state: self.catalog().state(),
+                },
+            )?;
+            let temp_storage = RowArena::new();
+            match to.eval(&[], &temp_storage)? {
+                Datum::Null => coord_bail!("COPY TO target value can not be null"),
+                datum => datum.unwrap_str().to_string(),
+            }
+        };
+        S3UploadInfo::parse_uri(&uri)?;
+
+        // Collect optimizer parameters.
+        let compute_instance = self
+            .instance_snapshot(cluster_id)
+            .expect("compute instance does not exist");
+        let select_id = self.allocate_transient_id()?;
+        let sink_id = self.allocate_transient_id()?;
+        let copy_to_context = CopyToContext {
+            desc,
+            uri,
+            connection,
+            connection_id,
+            format,
+            max_file_size,
+        };
+        let optimizer_config = optimize::OptimizerConfig::from(self.catalog().system_config());
+
+        // Build an optimizer for this COPY TO.
+        let mut optimizer = optimize::copy_to::Optimizer::new(
+            self.owned_catalog(),
+            compute_instance,
+            select_id,
+            sink_id,
+            copy_to_context,
+            optimizer_config,
+        );
+
+        // HIR ⇒ MIR lowering and MIR ⇒ MIR optimization (local and global)
+        let global_mir_plan =
+            optimizer.catch_unwind_optimize((select_plan.source, select_plan.finishing.project))?;
+
+        // Timestamp selection
+        let oracle_read_ts = self
+            .oracle_read_ts(ctx.session(), &timeline, &select_plan.when)
+            .await;
+        let as_of = self
+            .determine_timestamp(
+                ctx.session(),
+                &global_mir_plan.id_bundle(optimizer.cluster_id()),
+                &select_plan.when,
+                optimizer.cluster_id(),
+                &timeline,
+                oracle_read_ts,
+                None,
+            )
+            .await?
+            .timestamp_context
+            .timestamp_or_default();
+        if let Some(id) = ctx.extra().contents() {
+            self.set_statement_execution_timestamp(id, as_of);
+        }
+
+        // MIR ⇒ LIR lowering and LIR ⇒ LIR optimization (global)
+        let global_mir_plan = global_mir_plan.resolve(as_of, ctx.session());
+        let global_lir_plan = optimizer.catch_unwind_optimize(global_mir_plan)?;
+
+        let (df_desc, df_meta) = global_lir_plan.unapply();
+        // Emit notices.
+        self.emit_optimizer_notices(ctx.session(), &df_meta.optimizer_notices);
+
+        // Ship dataflow.
+        self.ship_dataflow(df_desc, cluster_id).await;
+
+        Ok((sink_id, cluster_id, depends_on))
+    }
+
+    /// Retires the `COPY ... TO <expr>` with the given sink, if it is still
+    /// active, and drops its dataflow.
+    pub(crate) fn retire_copy_to(
+        &mut self,
+        sink_id: GlobalId,
+        result: Result<ExecuteResponse, AdapterError>,
+    ) {
+        let Some(active_copy) = self.active_copies.remove(&sink_id) else {
+            return;
+        };
+        if self.drop_compute_read_policy(&sink_id) {
+            let mut compute = self.controller.active_compute();
+            // A cluster could have been dropped, so verify it exists.
+            if compute.instance_exists(active_copy.cluster_id) {
+                compute
+                    .drop_collections(active_copy.cluster_id, vec![sink_id])
+                    .unwrap_or_terminate("cannot fail to drop collections");
+            }
+        } else {
+            tracing::error!("Instructed to drop a compute sink that isn't one");
+        }
+        active_copy.ctx.retire(result);
+    }
+
+    /// Cancels all in-progress copies issued by `conn_id`.
+    pub(crate) fn cancel_active_copies(&mut self, conn_id: &ConnectionId) {
+        let sink_ids: Vec<_> = self
+            .active_copies
+            .iter()
+            .filter(|(_, active_copy)| &active_copy.conn_id == conn_id)
+            .map(|(sink_id, _)| *sink_id)
+            .collect();
+        for sink_id in sink_ids {
+            self.retire_copy_to(sink_id, Ok(ExecuteResponse::Canceled));
+        }
+    }
+}
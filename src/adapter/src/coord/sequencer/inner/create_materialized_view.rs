@@ -518,6 +518,7 @@ impl Coordinator {
                                 data_source: DataSource::Other(DataSourceOther::Compute),
                                 since: Some(as_of),
                                 status_collection_id: None,
+                                table_layout: None,
                             },
                         )],
                     )
@@ -22,7 +22,7 @@ use mz_ore::str::StrExt;
 use mz_pgwire_common::{ErrorResponse, Severity};
 use mz_repr::adt::timestamp::TimestampError;
 use mz_repr::explain::ExplainError;
-use mz_repr::{NotNullViolation, Timestamp};
+use mz_repr::{ColumnName, NotNullViolation, Row, Timestamp};
 use mz_sql::plan::PlanError;
 use mz_sql::rbac;
 use mz_sql::session::vars::VarError;
@@ -92,6 +92,22 @@ pub enum AdapterError {
     InvalidTableMutationSelection,
     /// Expression violated a column's constraint
     ConstraintViolation(NotNullViolation),
+    /// An `INSERT ... ON CONFLICT DO UPDATE` proposed more than one row with
+    /// the same key.
+    OnConflictRowAffectedTwice,
+    /// An `INSERT ... ON CONFLICT DO UPDATE` proposed a row that conflicts
+    /// with more than one existing row.
+    OnConflictKeyNotUnique,
+    /// A `MERGE` matched a target row with more than one source row.
+    MergeRowAffectedTwice,
+    /// A write would have produced more than one row of a table with the same
+    /// value for one of the table's `PRIMARY KEY` or `UNIQUE` constraints.
+    UniqueViolation {
+        /// The names of the constraint's columns.
+        columns: Vec<ColumnName>,
+        /// The duplicated value of the constraint's columns.
+        value: Row,
+    },
     /// Transaction cluster was dropped in the middle of a transaction.
     ConcurrentClusterDrop,
     /// Target cluster has no replicas to service query.
@@ -244,6 +260,11 @@ impl AdapterError {
             },
             AdapterError::Catalog(c) => c.detail(),
             AdapterError::Eval(e) => e.detail(),
+            AdapterError::UniqueViolation { columns, value } => Some(format!(
+                "Key ({})=({}) already exists.",
+                columns.iter().join(", "),
+                value.iter().join(", ")
+            )),
             AdapterError::RelationOutsideTimeDomain { relations, names } => Some(format!(
                 "The following relations in the query are outside the transaction's time domain:\n{}\n{}",
                 relations
@@ -322,6 +343,17 @@ impl AdapterError {
             ),
             AdapterError::Catalog(c) => c.hint(),
             AdapterError::Eval(e) => e.hint(),
+            AdapterError::OnConflictRowAffectedTwice => Some(
+                "Ensure that no rows proposed for insertion within the same command have \
+                duplicate constrained values."
+                    .into(),
+            ),
+            AdapterError::OnConflictKeyNotUnique => Some(
+                "Remove the existing rows with duplicate constrained values from the table.".into(),
+            ),
+            AdapterError::MergeRowAffectedTwice => {
+                Some("Ensure that not more than one source row matches any one target row.".into())
+            }
             AdapterError::InvalidClusterReplicaAz { expected, az: _ } => {
                 Some(if expected.is_empty() {
                     "No availability zones configured; do not specify AVAILABILITY ZONE".into()
@@ -415,6 +447,10 @@ impl AdapterError {
             AdapterError::SourceOrSinkSizeRequired { .. } => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::InvalidTableMutationSelection => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ConstraintViolation(NotNullViolation(_)) => SqlState::NOT_NULL_VIOLATION,
+            AdapterError::OnConflictRowAffectedTwice => SqlState::CARDINALITY_VIOLATION,
+            AdapterError::OnConflictKeyNotUnique => SqlState::CARDINALITY_VIOLATION,
+            AdapterError::MergeRowAffectedTwice => SqlState::CARDINALITY_VIOLATION,
+            AdapterError::UniqueViolation { .. } => SqlState::UNIQUE_VIOLATION,
             AdapterError::ConcurrentClusterDrop => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::NoClusterReplicasAvailable(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::OperationProhibitsTransaction(_) => SqlState::ACTIVE_SQL_TRANSACTION,
@@ -548,6 +584,18 @@ impl fmt::Display for AdapterError {
             AdapterError::ConstraintViolation(not_null_violation) => {
                 write!(f, "{}", not_null_violation)
             }
+            AdapterError::OnConflictRowAffectedTwice => {
+                f.write_str("ON CONFLICT DO UPDATE command cannot affect row a second time")
+            }
+            AdapterError::OnConflictKeyNotUnique => f.write_str(
+                "ON CONFLICT DO UPDATE command matched more than one existing row with the same key",
+            ),
+            AdapterError::MergeRowAffectedTwice => {
+                f.write_str("MERGE command cannot affect row a second time")
+            }
+            AdapterError::UniqueViolation { .. } => {
+                f.write_str("duplicate key value violates unique constraint")
+            }
             AdapterError::ConcurrentClusterDrop => {
                 write!(f, "the transaction's active cluster has been dropped")
             }
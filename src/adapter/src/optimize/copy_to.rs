@@ -0,0 +1,335 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Optimizer implementation for `COPY ... TO <expr>` statements.
+
+use std::sync::Arc;
+
+use mz_compute_types::plan::Plan;
+use mz_compute_types::sinks::{
+    ComputeSinkConnection, ComputeSinkDesc, CopyToS3OneshotSinkConnection,
+};
+use mz_compute_types::ComputeInstanceId;
+use mz_ore::collections::CollectionExt;
+use mz_repr::explain::trace_plan;
+use mz_repr::{GlobalId, RelationDesc, Timestamp};
+use mz_sql::plan::HirRelationExpr;
+use mz_storage_types::connections::aws::AwsConnection;
+use mz_storage_types::sinks::{S3SinkFormat, S3UploadInfo};
+use mz_transform::dataflow::DataflowMetainfo;
+use mz_transform::normalize_lets::normalize_lets;
+use mz_transform::typecheck::{empty_context, SharedContext as TypecheckContext};
+use mz_transform::Optimizer as TransformOptimizer;
+use timely::progress::Antichain;
+use tracing::{span, Level};
+
+use crate::catalog::Catalog;
+use crate::optimize::dataflows::{
+    dataflow_import_id_bundle, prep_relation_expr, prep_scalar_expr, ComputeInstanceSnapshot,
+    DataflowBuilder, EvalTime, ExprPrepStyle,
+};
+use crate::optimize::{
+    LirDataflowDescription, MirDataflowDescription, Optimize, OptimizerConfig, OptimizerError,
+};
+use crate::session::Session;
+use crate::CollectionIdBundle;
+
+pub struct Optimizer {
+    /// A typechecking context to use throughout the optimizer pipeline.
+    typecheck_ctx: TypecheckContext,
+    /// A snapshot of the catalog state.
+    catalog: Arc<Catalog>,
+    /// A snapshot of the cluster that will run the dataflows.
+    compute_instance: ComputeInstanceSnapshot,
+    /// A transient GlobalId to be used for the view of the copied query.
+    select_id: GlobalId,
+    /// A transient GlobalId to be used for the exported sink.
+    sink_id: GlobalId,
+    /// The destination of the copy.
+    copy_to_context: CopyToContext,
+    // Optimizer config.
+    config: OptimizerConfig,
+}
+
+/// The parameters of a `COPY ... TO <expr>` that are required to build its sink.
+#[derive(Debug, Clone)]
+pub struct CopyToContext {
+    /// The description of the copied relation.
+    pub desc: RelationDesc,
+    /// The evaluated destination URI.
+    pub uri: String,
+    /// The AWS connection used to upload the files.
+    pub connection: AwsConnection,
+    /// The ID of the AWS connection.
+    pub connection_id: GlobalId,
+    /// The encoding of the uploaded files.
+    pub format: S3SinkFormat,
+    /// The maximum size in bytes of each uploaded file.
+    pub max_file_size: u64,
+}
+
+// A bogey `Debug` implementation that hides fields. This is needed to make the
+// `event!` call in `sequence_peek_stage` not emit a lot of data.
+//
+// For now, we skip almost all fields, but we might revisit that bit if it turns
+// out that we really need those for debugging purposes.
+impl std::fmt::Debug for Optimizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Optimizer")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl Optimizer {
+    pub fn new(
+        catalog: Arc<Catalog>,
+        compute_instance: ComputeInstanceSnapshot,
+        select_id: GlobalId,
+        sink_id: GlobalId,
+        copy_to_context: CopyToContext,
+        config: OptimizerConfig,
+    ) -> Self {
+        Self {
+            typecheck_ctx: empty_context(),
+            catalog,
+            compute_instance,
+            select_id,
+            sink_id,
+            copy_to_context,
+            config,
+        }
+    }
+
+    pub fn cluster_id(&self) -> ComputeInstanceId {
+        self.compute_instance.instance_id()
+    }
+}
+
+/// The (sealed intermediate) result after:
+///
+/// 1. HIR ⇒ MIR lowering and decorrelation,
+/// 2. embedding the resulting plan into a [`MirDataflowDescription`] that
+///    exports a `CopyToS3Oneshot` sink,
+/// 3. transitively inlining referenced views, and
+/// 4. jointly optimizing the `MIR` plans in the [`MirDataflowDescription`].
+#[derive(Clone)]
+pub struct GlobalMirPlan<T: Clone> {
+    df_desc: MirDataflowDescription,
+    df_meta: DataflowMetainfo,
+    context: T,
+}
+
+impl std::fmt::Debug for GlobalMirPlan<Unresolved> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GlobalMirPlan")
+            .field("df_desc", &self.df_desc)
+            .field("df_meta", &self.df_meta)
+            .finish()
+    }
+}
+
+impl<T: Clone> GlobalMirPlan<T> {
+    /// Computes the [`CollectionIdBundle`] of the wrapped dataflow.
+    pub fn id_bundle(&self, compute_instance_id: ComputeInstanceId) -> CollectionIdBundle {
+        dataflow_import_id_bundle(&self.df_desc, compute_instance_id)
+    }
+}
+
+/// The (final) result after MIR ⇒ LIR lowering and optimizing the resulting
+/// `DataflowDescription` with `LIR` plans.
+#[derive(Clone, Debug)]
+pub struct GlobalLirPlan {
+    df_desc: LirDataflowDescription,
+    df_meta: DataflowMetainfo,
+}
+
+impl GlobalLirPlan {
+    pub fn sink_id(&self) -> GlobalId {
+        let sink_exports = &self.df_desc.sink_exports;
+        let sink_id = sink_exports.keys().next().expect("valid sink");
+        *sink_id
+    }
+}
+
+/// Marker type for [`GlobalMirPlan`] structs representing an optimization
+/// result without a resolved timestamp.
+#[derive(Clone, Debug)]
+pub struct Unresolved;
+
+/// Marker type for [`GlobalMirPlan`] structs representing an optimization
+/// result with a resolved timestamp.
+///
+/// The actual timestamp value is set in the [`MirDataflowDescription`] of the
+/// surrounding [`GlobalMirPlan`] when we call `resolve()`. The session is
+/// needed to resolve unmaterializable function calls in the final stage.
+#[derive(Clone)]
+pub struct Resolved<'s> {
+    session: &'s Session,
+}
+
+impl Optimize<(HirRelationExpr, Vec<usize>)> for Optimizer {
+    type To = GlobalMirPlan<Unresolved>;
+
+    fn optimize(
+        &mut self,
+        (expr, project): (HirRelationExpr, Vec<usize>),
+    ) -> Result<Self::To, OptimizerError> {
+        // HIR ⇒ MIR lowering and decorrelation
+        let expr = expr.lower(&self.config)?.project(project);
+
+        // MIR ⇒ MIR optimization (local)
+        let expr = span!(target: "optimizer", Level::DEBUG, "local").in_scope(|| {
+            #[allow(deprecated)]
+            let optimizer = TransformOptimizer::logical_optimizer(&self.typecheck_ctx);
+            let expr = optimizer.optimize(expr)?;
+
+            // Trace the result of this phase.
+            trace_plan(&expr);
+
+            Ok::<_, OptimizerError>(expr)
+        })?;
+
+        let from_desc = RelationDesc::new(expr.typ(), self.copy_to_context.desc.iter_names());
+
+        // Make SinkDesc
+        let CopyToContext {
+            uri,
+            connection,
+            connection_id,
+            format,
+            max_file_size,
+            ..
+        } = self.copy_to_context.clone();
+        let sink_desc = ComputeSinkDesc {
+            from: self.select_id,
+            from_desc: from_desc.clone(),
+            connection: ComputeSinkConnection::CopyToS3Oneshot(CopyToS3OneshotSinkConnection {
+                upload_info: S3UploadInfo {
+                    uri,
+                    max_file_size,
+                    desc: from_desc,
+                    format,
+                },
+                aws_connection: connection,
+                connection_id,
+            }),
+            with_snapshot: true,
+            // The `up_to` is set once the timestamp has been resolved.
+            up_to: Antichain::new(),
+            // No `FORCE NOT NULL` for copy tos
+            non_null_assertions: vec![],
+            // No `REFRESH` for copy tos
+            refresh_schedule: None,
+        };
+
+        let mut df_builder =
+            DataflowBuilder::new(self.catalog.state(), self.compute_instance.clone());
+
+        let debug_name = format!("copy-to-{}", self.sink_id);
+        let mut df_desc = MirDataflowDescription::new(debug_name);
+
+        df_builder.import_view_into_dataflow(&self.select_id, &expr, &mut df_desc)?;
+        df_builder.reoptimize_imported_views(&mut df_desc, &self.config)?;
+        df_desc.export_sink(self.sink_id, sink_desc);
+
+        // Unmaterializable function calls are resolved in the final stage, once
+        // we know the timestamp of the copy.
+        let df_meta = mz_transform::optimize_dataflow(
+            &mut df_desc,
+            &df_builder,
+            &mz_transform::EmptyStatisticsOracle,
+        )?;
+
+        // Return the (sealed) plan at the end of this optimization step.
+        Ok(GlobalMirPlan {
+            df_desc,
+            df_meta,
+            context: Unresolved,
+        })
+    }
+}
+
+impl GlobalMirPlan<Unresolved> {
+    /// Produces the [`GlobalMirPlan`] with [`Resolved`] timestamp.
+    ///
+    /// The copy reads a single snapshot at `as_of`, so the sink's `up_to` and
+    /// the dataflow's `until` are both set to the time immediately after it.
+    pub fn resolve(mut self, as_of: Timestamp, session: &Session) -> GlobalMirPlan<Resolved> {
+        self.df_desc.set_as_of(Antichain::from_elem(as_of));
+
+        let up_to = Antichain::from_elem(as_of.step_forward());
+        for (_, sink) in self.df_desc.sink_exports.iter_mut() {
+            sink.up_to = up_to.clone();
+        }
+        self.df_desc.until = up_to;
+
+        GlobalMirPlan {
+            df_desc: self.df_desc,
+            df_meta: self.df_meta,
+            context: Resolved { session },
+        }
+    }
+}
+
+impl<'s> Optimize<GlobalMirPlan<Resolved<'s>>> for Optimizer {
+    type To = GlobalLirPlan;
+
+    fn optimize(&mut self, plan: GlobalMirPlan<Resolved<'s>>) -> Result<Self::To, OptimizerError> {
+        let GlobalMirPlan {
+            mut df_desc,
+            df_meta,
+            context: Resolved { session },
+        } = plan;
+
+        // Get the single timestamp representing the `as_of` time.
+        let as_of = df_desc
+            .as_of
+            .clone()
+            .expect("as_of antichain")
+            .into_element();
+
+        // Resolve all unmaterializable function calls including mz_now().
+        let style = ExprPrepStyle::OneShot {
+            logical_time: EvalTime::Time(as_of),
+            session,
+            catalog_state: self.catalog.state(),
+        };
+        df_desc.visit_children(
+            |r| prep_relation_expr(r, style),
+            |s| prep_scalar_expr(s, style),
+        )?;
+
+        // Ensure all expressions are normalized before finalizing.
+        for build in df_desc.objects_to_build.iter_mut() {
+            normalize_lets(&mut build.plan.0)?
+        }
+
+        // Finalize the dataflow. This includes:
+        // - MIR ⇒ LIR lowering
+        // - LIR ⇒ LIR transforms
+        let df_desc = Plan::finalize_dataflow(
+            df_desc,
+            self.config.enable_consolidate_after_union_negate,
+            self.config.enable_specialized_arrangements,
+            self.config.enable_reduce_mfp_fusion,
+        )
+        .map_err(OptimizerError::Internal)?;
+
+        // Return the plan at the end of this `optimize` step.
+        Ok(GlobalLirPlan { df_desc, df_meta })
+    }
+}
+
+impl GlobalLirPlan {
+    /// Unwraps the parts of the final result of the optimization pipeline.
+    pub fn unapply(self) -> (LirDataflowDescription, DataflowMetainfo) {
+        (self.df_desc, self.df_meta)
+    }
+}
@@ -128,6 +128,8 @@ pub fn analyze_audited_statement(
         StatementKind::AlterIndex => Some((StatementAction::Alter, ObjectType::Index)),
         StatementKind::AlterRole => Some((StatementAction::Alter, ObjectType::Role)),
         StatementKind::AlterSecret => Some((StatementAction::Alter, ObjectType::Secret)),
+        StatementKind::AlterType => Some((StatementAction::Alter, ObjectType::Type)),
+        StatementKind::AlterTable => Some((StatementAction::Alter, ObjectType::Table)),
         StatementKind::AlterSource => Some((StatementAction::Alter, ObjectType::Source)),
         StatementKind::CreateCluster => Some((StatementAction::Create, ObjectType::Cluster)),
         StatementKind::CreateClusterReplica => {
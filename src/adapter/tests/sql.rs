@@ -97,6 +97,9 @@ async fn datadriven() {
                                             )),
                                             desc: RelationDesc::empty(),
                                             defaults: vec![Expr::null(); 0],
+                                            domains: vec![],
+                                            collations: vec![],
+                                            unique_keys: vec![],
                                             conn_id: None,
                                             resolved_ids: ResolvedIds(BTreeSet::new()),
                                             custom_logical_compaction_window: None,
@@ -141,7 +141,6 @@ fn main() -> anyhow::Result<()> {
     // the compile block for them as well.
     prost_build::Config::new()
         .btree_map(["."])
-        .bytes(["."])
         .message_attribute(".", ATTR)
         // Note(parkmycar): This is annoying, but we need to manually specify each oneof so we can
         // get them to implement Eq, PartialEq, and Ord. If you define a new oneof you should add
@@ -171,6 +170,13 @@ fn main() -> anyhow::Result<()> {
         //
         // [^1]: https://serde.rs/enum-representations.html#internally-tagged
         .enum_attribute("StateUpdateKind.kind", "#[serde(tag = \"kind\")]")
+        // Items that are not tables, and the tables that were stored before
+        // their layouts were, must serialize the same as they did before
+        // `ItemValue.table_layout` existed.
+        .field_attribute(
+            "ItemValue.table_layout",
+            "#[serde(default, skip_serializing_if = \"Option::is_none\")]",
+        )
         // We derive Arbitrary for all protobuf types for wire compatibility testing.
         .message_attribute(".", ARBITRARY_ATTR)
         .enum_attribute(".", ARBITRARY_ATTR)
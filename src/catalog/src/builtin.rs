@@ -917,6 +917,20 @@ pub const TYPE_ANYELEMENT: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYENUM: BuiltinType<NameReference> = BuiltinType {
+    name: "anyenum",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYENUM_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 3504,
+            typreceive_oid: 0,
+        }),
+    },
+};
+
 pub const TYPE_ANYNONARRAY: BuiltinType<NameReference> = BuiltinType {
     name: "anynonarray",
     schema: PG_CATALOG_SCHEMA,
@@ -1630,6 +1644,180 @@ pub const TYPE_ACL_ITEM_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_INET: BuiltinType<NameReference> = BuiltinType {
+    name: "inet",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INET_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Inet,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 910,
+            typreceive_oid: 2496,
+        }),
+    },
+};
+
+pub const TYPE_INET_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_inet",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INET_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INET.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_CIDR: BuiltinType<NameReference> = BuiltinType {
+    name: "cidr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_CIDR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Cidr,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 1267,
+            typreceive_oid: 2498,
+        }),
+    },
+};
+
+pub const TYPE_CIDR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_cidr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_CIDR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_CIDR.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_TSVECTOR: BuiltinType<NameReference> = BuiltinType {
+    name: "tsvector",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSVECTOR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::TsVector,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 3610,
+            typreceive_oid: 3639,
+        }),
+    },
+};
+
+pub const TYPE_TSVECTOR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tsvector",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSVECTOR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TSVECTOR.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_TSQUERY: BuiltinType<NameReference> = BuiltinType {
+    name: "tsquery",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSQUERY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::TsQuery,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 3612,
+            typreceive_oid: 3641,
+        }),
+    },
+};
+
+pub const TYPE_TSQUERY_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tsquery",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSQUERY_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TSQUERY.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_JSONPATH: BuiltinType<NameReference> = BuiltinType {
+    name: "jsonpath",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_JSONPATH_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::JsonPath,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4001,
+            typreceive_oid: 4002,
+        }),
+    },
+};
+
+pub const TYPE_JSONPATH_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_jsonpath",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_JSONPATH_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_JSONPATH.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_HLL: BuiltinType<NameReference> = BuiltinType {
+    name: "hll",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: oid::TYPE_HLL_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Hll,
+        array_id: None,
+        pg_metadata: None,
+    },
+};
+
+pub const TYPE_HLL_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_hll",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: oid::TYPE_HLL_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_HLL.name,
+        },
+        array_id: None,
+        pg_metadata: None,
+    },
+};
+
 pub const TYPE_INTERNAL: BuiltinType<NameReference> = BuiltinType {
     name: "internal",
     schema: PG_CATALOG_SCHEMA,
@@ -2214,6 +2402,32 @@ pub static MZ_LIST_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     is_retained_metrics_object: false,
     access: vec![PUBLIC_SELECT],
 });
+pub static MZ_ENUM_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_enum_types",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column(
+            "labels",
+            ScalarType::List {
+                element_type: Box::new(ScalarType::String),
+                custom_id: None,
+            }
+            .nullable(false),
+        ),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+pub static MZ_DOMAIN_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_domain_types",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("base_type_id", ScalarType::String.nullable(false))
+        .with_column("not_null", ScalarType::Bool.nullable(false)),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
 pub static MZ_MAP_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_map_types",
     schema: MZ_CATALOG_SCHEMA,
@@ -3651,8 +3865,23 @@ pub static PG_TYPE: Lazy<BuiltinView> = Lazy::new(|| {
         AS typarray,
     mz_internal.mz_type_pg_metadata.typinput::pg_catalog.regproc AS typinput,
     COALESCE(mz_internal.mz_type_pg_metadata.typreceive, 0) AS typreceive,
-    false::pg_catalog.bool AS typnotnull,
-    0::pg_catalog.oid AS typbasetype,
+    coalesce(
+        (
+            SELECT dt.not_null
+            FROM mz_catalog.mz_domain_types AS dt
+            WHERE dt.id = mz_types.id
+        ),
+        false
+    ) AS typnotnull,
+    coalesce(
+        (
+            SELECT t.oid
+            FROM mz_catalog.mz_domain_types AS dt
+            JOIN mz_catalog.mz_types AS t ON dt.base_type_id = t.id
+            WHERE dt.id = mz_types.id
+        ),
+        0
+    ) AS typbasetype,
     -1::pg_catalog.int4 AS typtypmod,
     -- MZ doesn't support COLLATE so typcollation is filled with 0
     0::pg_catalog.oid AS typcollation,
@@ -3666,6 +3895,8 @@ FROM
             -- converted to the correct value above.
             SELECT id, 'a' AS mztype FROM mz_catalog.mz_array_types
             UNION ALL SELECT id, 'b' FROM mz_catalog.mz_base_types
+            UNION ALL SELECT id, 'd' FROM mz_catalog.mz_domain_types
+            UNION ALL SELECT id, 'e' FROM mz_catalog.mz_enum_types
             UNION ALL SELECT id, 'l' FROM mz_catalog.mz_list_types
             UNION ALL SELECT id, 'm' FROM mz_catalog.mz_map_types
             UNION ALL SELECT id, 'p' FROM mz_catalog.mz_pseudo_types
@@ -3782,10 +4013,12 @@ pub static PG_ENUM: Lazy<BuiltinView> = Lazy::new(|| BuiltinView {
     column_defs: None,
     sql: "SELECT
     NULL::pg_catalog.oid AS oid,
-    NULL::pg_catalog.oid AS enumtypid,
-    NULL::pg_catalog.float4 AS enumsortorder,
-    NULL::pg_catalog.text AS enumlabel
-WHERE false",
+    mz_types.oid AS enumtypid,
+    labels.ordinality::pg_catalog.float4 AS enumsortorder,
+    labels.label AS enumlabel
+FROM mz_catalog.mz_enum_types
+JOIN mz_catalog.mz_types ON mz_types.id = mz_enum_types.id,
+unnest(mz_enum_types.labels) WITH ORDINALITY AS labels(label, ordinality)",
     access: vec![PUBLIC_SELECT],
 });
 
@@ -6337,6 +6570,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Type(&TYPE_ANY),
         Builtin::Type(&TYPE_ANYARRAY),
         Builtin::Type(&TYPE_ANYELEMENT),
+        Builtin::Type(&TYPE_ANYENUM),
         Builtin::Type(&TYPE_ANYNONARRAY),
         Builtin::Type(&TYPE_ANYRANGE),
         Builtin::Type(&TYPE_BOOL),
@@ -6423,6 +6657,18 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Type(&TYPE_MZ_ACL_ITEM_ARRAY),
         Builtin::Type(&TYPE_ACL_ITEM),
         Builtin::Type(&TYPE_ACL_ITEM_ARRAY),
+        Builtin::Type(&TYPE_INET),
+        Builtin::Type(&TYPE_INET_ARRAY),
+        Builtin::Type(&TYPE_CIDR),
+        Builtin::Type(&TYPE_CIDR_ARRAY),
+        Builtin::Type(&TYPE_TSVECTOR),
+        Builtin::Type(&TYPE_TSVECTOR_ARRAY),
+        Builtin::Type(&TYPE_TSQUERY),
+        Builtin::Type(&TYPE_TSQUERY_ARRAY),
+        Builtin::Type(&TYPE_JSONPATH),
+        Builtin::Type(&TYPE_JSONPATH_ARRAY),
+        Builtin::Type(&TYPE_HLL),
+        Builtin::Type(&TYPE_HLL_ARRAY),
         Builtin::Type(&TYPE_INTERNAL),
     ];
     for (schema, funcs) in &[
@@ -6493,6 +6739,8 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_ARRAY_TYPES),
         Builtin::Table(&MZ_BASE_TYPES),
         Builtin::Table(&MZ_LIST_TYPES),
+        Builtin::Table(&MZ_ENUM_TYPES),
+        Builtin::Table(&MZ_DOMAIN_TYPES),
         Builtin::Table(&MZ_MAP_TYPES),
         Builtin::Table(&MZ_ROLES),
         Builtin::Table(&MZ_ROLE_MEMBERS),
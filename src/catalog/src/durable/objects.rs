@@ -22,6 +22,7 @@ use mz_sql::catalog::{
     RoleMembership, RoleVars,
 };
 use mz_sql::names::{CommentObjectId, DatabaseId, SchemaId};
+use mz_storage_types::controller::TableLayout;
 use mz_storage_types::sources::Timeline;
 use proptest_derive::Arbitrary;
 
@@ -380,6 +381,9 @@ pub struct Item {
     pub create_sql: String,
     pub owner_id: RoleId,
     pub privileges: Vec<MzAclItem>,
+    /// The columns stored in the shard of a table, which `create_sql` does not
+    /// describe once the table's columns have been altered.
+    pub table_layout: Option<TableLayout>,
 }
 
 impl DurableType<ItemKey, ItemValue> for Item {
@@ -392,6 +396,7 @@ impl DurableType<ItemKey, ItemValue> for Item {
                 create_sql: self.create_sql,
                 owner_id: self.owner_id,
                 privileges: self.privileges,
+                table_layout: self.table_layout,
             },
         )
     }
@@ -404,6 +409,7 @@ impl DurableType<ItemKey, ItemValue> for Item {
             create_sql: value.create_sql,
             owner_id: value.owner_id,
             privileges: value.privileges,
+            table_layout: value.table_layout,
         }
     }
 }
@@ -867,6 +873,7 @@ pub struct ItemValue {
     pub(crate) create_sql: String,
     pub(crate) owner_id: RoleId,
     pub(crate) privileges: Vec<MzAclItem>,
+    pub(crate) table_layout: Option<TableLayout>,
 }
 
 impl ItemValue {
@@ -29,7 +29,9 @@ use mz_sql::names::{
     CommentObjectId, DatabaseId, ResolvedDatabaseSpecifier, SchemaId, SchemaSpecifier,
 };
 use mz_sql::session::vars::OwnedVarInput;
+use mz_storage_types::controller::ProtoTableLayout;
 use mz_storage_types::instances::StorageInstanceId;
+use prost::Message;
 use std::time::Duration;
 
 use crate::durable::impls::persist::state_update::StateUpdateKindRaw;
@@ -497,6 +499,10 @@ impl RustType<proto::ItemValue> for ItemValue {
             definition: Some(definition),
             owner_id: Some(self.owner_id.into_proto()),
             privileges: self.privileges.into_proto(),
+            table_layout: self
+                .table_layout
+                .as_ref()
+                .map(|layout| layout.into_proto().encode_to_vec()),
         }
     }
 
@@ -509,12 +515,17 @@ impl RustType<proto::ItemValue> for ItemValue {
         let create_sql = match create_sql_value {
             proto::catalog_item::Value::V1(c) => c.create_sql,
         };
+        let table_layout = match proto.table_layout {
+            Some(layout) => Some(ProtoTableLayout::decode(layout.as_slice())?.into_rust()?),
+            None => None,
+        };
         Ok(ItemValue {
             schema_id: proto.schema_id.into_rust_if_some("ItemValue::schema_id")?,
             name: proto.name,
             create_sql,
             owner_id: proto.owner_id.into_rust_if_some("ItemValue::owner_id")?,
             privileges: proto.privileges.into_rust()?,
+            table_layout,
         })
     }
 }
@@ -26,7 +26,7 @@ use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
 use mz_sql::session::vars::CatalogKind;
 use mz_sql_parser::ast::QualifiedReplica;
 use mz_stash::TableTransaction;
-use mz_storage_types::controller::PersistTxnTablesImpl;
+use mz_storage_types::controller::{PersistTxnTablesImpl, TableLayout};
 use mz_storage_types::sources::Timeline;
 use std::collections::{BTreeMap, BTreeSet};
 use std::time::Duration;
@@ -115,11 +115,16 @@ impl<'a> Transaction<'a> {
             items: TableTransaction::new(items, |a: &ItemValue, b| {
                 let a_type = a.item_type();
                 let b_type = b.item_type();
-                a.schema_id == b.schema_id
-                    && a.name == b.name
-                    && ((a_type != CatalogItemType::Type && b_type != CatalogItemType::Type)
-                        || (a_type == CatalogItemType::Type && b_type.conflicts_with_type())
-                        || (b_type == CatalogItemType::Type && a_type.conflicts_with_type()))
+                // Functions live in a namespace of their own.
+                let namespaces_overlap =
+                    if a_type == CatalogItemType::Func || b_type == CatalogItemType::Func {
+                        a_type == b_type
+                    } else {
+                        (a_type != CatalogItemType::Type && b_type != CatalogItemType::Type)
+                            || (a_type == CatalogItemType::Type && b_type.conflicts_with_type())
+                            || (b_type == CatalogItemType::Type && a_type.conflicts_with_type())
+                    };
+                a.schema_id == b.schema_id && a.name == b.name && namespaces_overlap
             })?,
             comments: TableTransaction::new(comments, |_a, _b| false)?,
             roles: TableTransaction::new(roles, |a: &RoleValue, b| a.name == b.name)?,
@@ -514,6 +519,7 @@ impl<'a> Transaction<'a> {
         create_sql: String,
         owner_id: RoleId,
         privileges: Vec<MzAclItem>,
+        table_layout: Option<TableLayout>,
     ) -> Result<(), CatalogError> {
         match self.items.insert(
             ItemKey { gid: id },
@@ -523,6 +529,7 @@ impl<'a> Transaction<'a> {
                 create_sql,
                 owner_id,
                 privileges,
+                table_layout,
             },
         ) {
             Ok(_) => Ok(()),
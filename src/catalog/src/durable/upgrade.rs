@@ -160,14 +160,14 @@ macro_rules! objects {
     }
 }
 
-objects!(v42, v43, v44, v45);
+objects!(v42, v43, v44, v45, v46);
 
 /// The current version of the `Catalog`.
 ///
 /// We will initialize new `Catalog`es with this version, and migrate existing `Catalog`es to this
 /// version. Whenever the `Catalog` changes, e.g. the protobufs we serialize in the `Catalog`
 /// change, we need to bump this version.
-pub(crate) const CATALOG_VERSION: u64 = 45;
+pub(crate) const CATALOG_VERSION: u64 = 46;
 
 /// The minimum `Catalog` version number that we support migrating from.
 ///
@@ -194,6 +194,7 @@ pub(crate) mod stash {
     mod v42_to_v43;
     mod v43_to_v44;
     mod v44_to_v45;
+    mod v45_to_v46;
 
     #[tracing::instrument(name = "stash::upgrade", level = "debug", skip_all)]
     pub(crate) async fn upgrade(stash: &mut Stash) -> Result<(), StashError> {
@@ -220,6 +221,7 @@ pub(crate) mod stash {
                             42 => v42_to_v43::upgrade(),
                             43 => v43_to_v44::upgrade(),
                             44 => v44_to_v45::upgrade(&tx).await?,
+                            45 => v45_to_v46::upgrade(),
 
                             // Up-to-date, no migration needed!
                             CATALOG_VERSION => return Ok(CATALOG_VERSION),
@@ -295,6 +297,7 @@ pub(crate) mod persist {
     mod v42_to_v43;
     mod v43_to_v44;
     mod v44_to_v45;
+    mod v45_to_v46;
 
     /// Describes a single action to take during a migration from `V1` to `V2`.
     #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -416,6 +419,15 @@ pub(crate) mod persist {
                     )
                     .await
                 }
+                45 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v45_to_v46::upgrade,
+                    )
+                    .await
+                }
 
                 // Up-to-date, no migration needed!
                 CATALOG_VERSION => Ok((CATALOG_VERSION, upper)),
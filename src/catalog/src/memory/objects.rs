@@ -12,6 +12,7 @@
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use mz_adapter_types::compaction::CompactionWindow;
@@ -29,6 +30,7 @@ use mz_controller_types::{ClusterId, ReplicaId};
 use mz_expr::refresh_schedule::RefreshSchedule;
 use mz_expr::{CollectionPlan, MirScalarExpr, OptimizedMirRelationExpr};
 use mz_ore::collections::CollectionExt;
+use mz_repr::adt::collation::Collation;
 use mz_repr::adt::mz_acl_item::{AclMode, PrivilegeMap};
 use mz_repr::role_id::RoleId;
 use mz_repr::{GlobalId, RelationDesc};
@@ -52,6 +54,7 @@ use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
 use mz_storage_client::controller::IntrospectionType;
 use mz_storage_types::connections::inline::ReferencedConnection;
+use mz_storage_types::controller::TableLayout;
 use mz_storage_types::sinks::{KafkaSinkFormat, SinkEnvelope, StorageSinkConnection};
 use mz_storage_types::sources::{
     IngestionDescription, SourceConnection, SourceDesc, SourceEnvelope, SourceExport, Timeline,
@@ -350,6 +353,7 @@ impl From<CatalogEntry> for durable::Item {
             id: entry.id,
             schema_id: entry.name.qualifiers.schema_spec.into(),
             name: entry.name.item,
+            table_layout: entry.item.table_layout().cloned(),
             create_sql: entry.item.into_serialized(),
             owner_id: entry.owner_id,
             privileges: entry.privileges.into_all_values().collect(),
@@ -363,6 +367,19 @@ pub struct Table {
     pub desc: RelationDesc,
     #[serde(skip)]
     pub defaults: Vec<Expr<Aug>>,
+    /// The domain that each column was declared as, if any.
+    #[serde(skip)]
+    pub domains: Vec<Option<GlobalId>>,
+    /// The collation that each column was declared with, if any.
+    pub collations: Vec<Option<Collation>>,
+    /// The columns of each of the table's `PRIMARY KEY` and `UNIQUE`
+    /// constraints, which writes to the table must not violate.
+    #[serde(skip)]
+    pub unique_keys: Vec<Vec<usize>>,
+    /// The columns stored in the table's shard, which include the columns
+    /// that have been dropped from the table. Builtin tables have none.
+    #[serde(skip)]
+    pub layout: Option<TableLayout>,
     #[serde(skip)]
     pub conn_id: Option<ConnectionId>,
     pub resolved_ids: ResolvedIds,
@@ -662,10 +679,18 @@ impl Sink {
 
     /// Output format of the sink.
     pub fn format(&self) -> &str {
-        let StorageSinkConnection::Kafka(connection) = &self.connection;
-        match &connection.format {
-            KafkaSinkFormat::Avro { .. } => "avro",
-            KafkaSinkFormat::Json => "json",
+        match &self.connection {
+            StorageSinkConnection::Kafka(connection) => match &connection.format {
+                KafkaSinkFormat::Avro { .. } => "avro",
+                KafkaSinkFormat::Json => "json",
+                KafkaSinkFormat::Text => "text",
+                KafkaSinkFormat::Bytes => "bytes",
+            },
+            // Rows are written upstream using PostgreSQL's text representation
+            // of each column.
+            StorageSinkConnection::Postgres(_) => "text",
+            StorageSinkConnection::Webhook(_) => "json",
+            StorageSinkConnection::Iceberg(_) => "parquet",
         }
     }
 
@@ -720,8 +745,32 @@ pub struct Type {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Func {
+    /// The `CREATE FUNCTION` statement of a user-defined function, or `None`
+    /// for a built-in function.
+    pub create_sql: Option<String>,
     #[serde(skip)]
-    pub inner: &'static mz_sql::func::Func,
+    pub inner: FuncInner,
+    pub resolved_ids: ResolvedIds,
+}
+
+/// The implementation of a [`Func`].
+#[derive(Debug, Clone)]
+pub enum FuncInner {
+    /// A built-in function.
+    Builtin(&'static mz_sql::func::Func),
+    /// A function defined by a `CREATE FUNCTION` statement.
+    User(Arc<mz_sql::func::Func>),
+}
+
+impl Deref for FuncInner {
+    type Target = mz_sql::func::Func;
+
+    fn deref(&self) -> &mz_sql::func::Func {
+        match self {
+            FuncInner::Builtin(func) => func,
+            FuncInner::User(func) => func,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -777,12 +826,9 @@ impl CatalogItem {
         }
     }
 
-    pub fn func(
-        &self,
-        entry: &CatalogEntry,
-    ) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    pub fn func(&self, entry: &CatalogEntry) -> Result<&mz_sql::func::Func, SqlCatalogError> {
         match &self {
-            CatalogItem::Func(func) => Ok(func.inner),
+            CatalogItem::Func(func) => Ok(&func.inner),
             _ => Err(SqlCatalogError::UnexpectedType {
                 name: entry.name().item.to_string(),
                 actual_type: entry.item_type(),
@@ -827,7 +873,7 @@ impl CatalogItem {
     pub fn references(&self) -> &ResolvedIds {
         static EMPTY: Lazy<ResolvedIds> = Lazy::new(|| ResolvedIds(BTreeSet::new()));
         match self {
-            CatalogItem::Func(_) => &*EMPTY,
+            CatalogItem::Func(func) => &func.resolved_ids,
             CatalogItem::Index(idx) => &idx.resolved_ids,
             CatalogItem::Sink(sink) => &sink.resolved_ids,
             CatalogItem::Source(source) => &source.resolved_ids,
@@ -959,7 +1005,11 @@ impl CatalogItem {
                 i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
                 Ok(CatalogItem::Type(i))
             }
-            CatalogItem::Func(i) => Ok(CatalogItem::Func(i.clone())),
+            CatalogItem::Func(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
+                Ok(CatalogItem::Func(i))
+            }
         }
     }
 
@@ -1022,7 +1072,12 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
-            CatalogItem::Func(_) | CatalogItem::Type(_) => {
+            CatalogItem::Func(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
+                Ok(CatalogItem::Func(i))
+            }
+            CatalogItem::Type(_) => {
                 unreachable!("{}s cannot be renamed", self.typ())
             }
             CatalogItem::Connection(i) => {
@@ -1142,6 +1197,15 @@ impl CatalogItem {
         }
     }
 
+    /// Returns the columns stored in the shard of the item, if it is a user
+    /// table.
+    pub fn table_layout(&self) -> Option<&TableLayout> {
+        match self {
+            CatalogItem::Table(table) => table.layout.as_ref(),
+            _ => None,
+        }
+    }
+
     pub fn to_serialized(&self) -> String {
         match self {
             CatalogItem::Table(table) => table
@@ -1172,7 +1236,11 @@ impl CatalogItem {
                 .clone(),
             CatalogItem::Secret(secret) => secret.create_sql.clone(),
             CatalogItem::Connection(connection) => connection.create_sql.clone(),
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Func(func) => func
+                .create_sql
+                .as_ref()
+                .expect("builtin functions cannot be serialized")
+                .clone(),
         }
     }
 
@@ -1198,7 +1266,9 @@ impl CatalogItem {
             CatalogItem::Type(typ) => typ.create_sql.expect("builtin types cannot be serialized"),
             CatalogItem::Secret(secret) => secret.create_sql,
             CatalogItem::Connection(connection) => connection.create_sql,
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Func(func) => func
+                .create_sql
+                .expect("builtin functions cannot be serialized"),
         }
     }
 }
@@ -1222,7 +1292,7 @@ impl CatalogEntry {
     }
 
     /// Returns the [`mz_sql::func::Func`] associated with this `CatalogEntry`.
-    pub fn func(&self) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    pub fn func(&self) -> Result<&mz_sql::func::Func, SqlCatalogError> {
         self.item.func(self)
     }
 
@@ -1996,7 +2066,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         self.desc(name)
     }
 
-    fn func(&self) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    fn func(&self) -> Result<&mz_sql::func::Func, SqlCatalogError> {
         self.func()
     }
 
@@ -2028,7 +2098,9 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
             }
             CatalogItem::Secret(Secret { create_sql, .. }) => create_sql,
             CatalogItem::Connection(Connection { create_sql, .. }) => create_sql,
-            CatalogItem::Func(_) => "<builtin>",
+            CatalogItem::Func(Func { create_sql, .. }) => {
+                create_sql.as_deref().unwrap_or("<builtin>")
+            }
             CatalogItem::Log(_) => "<builtin>",
         }
     }
@@ -2045,14 +2117,29 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
-    fn table_details(&self) -> Option<&[Expr<Aug>]> {
-        if let CatalogItem::Table(Table { defaults, .. }) = self.item() {
-            Some(defaults)
+    fn table_details(&self) -> Option<(&[Expr<Aug>], &[Option<GlobalId>])> {
+        if let CatalogItem::Table(Table {
+            defaults, domains, ..
+        }) = self.item()
+        {
+            Some((defaults, domains))
+        } else {
+            None
+        }
+    }
+
+    fn table_collations(&self) -> Option<&[Option<Collation>]> {
+        if let CatalogItem::Table(Table { collations, .. }) = self.item() {
+            Some(collations)
         } else {
             None
         }
     }
 
+    fn table_layout(&self) -> Option<&TableLayout> {
+        self.item().table_layout()
+    }
+
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>> {
         if let CatalogItem::Type(Type { details, .. }) = self.item() {
             Some(details)
@@ -24,9 +24,10 @@ use mz_ore::task::JoinHandleExt;
 use mz_persist_client::PersistClient;
 use mz_proto::RustType;
 use mz_repr::role_id::RoleId;
-use mz_repr::GlobalId;
+use mz_repr::{GlobalId, RelationDesc, ScalarType};
 use mz_sql::names::SchemaId;
 use mz_stash::DebugStashFactory;
+use mz_storage_types::controller::TableLayout;
 use mz_storage_types::sources::Timeline;
 use std::collections::BTreeMap;
 use std::time::Duration;
@@ -426,6 +427,7 @@ async fn test_items(openable_state: impl OpenableDurableCatalogState) {
             create_sql: "CREATE VIEW v AS SELECT 1".to_string(),
             owner_id: RoleId::User(1),
             privileges: vec![],
+            table_layout: None,
         },
         Item {
             id: GlobalId::User(200),
@@ -434,6 +436,18 @@ async fn test_items(openable_state: impl OpenableDurableCatalogState) {
             create_sql: "CREATE MATERIALIZED VIEW mv AS SELECT 2".to_string(),
             owner_id: RoleId::User(2),
             privileges: vec![],
+            table_layout: None,
+        },
+        Item {
+            id: GlobalId::User(300),
+            schema_id: SchemaId::User(1),
+            name: "baz".to_string(),
+            create_sql: "CREATE TABLE t (a int)".to_string(),
+            owner_id: RoleId::User(1),
+            privileges: vec![],
+            table_layout: Some(TableLayout::new(
+                &RelationDesc::empty().with_column("a", ScalarType::Int32.nullable(true)),
+            )),
         },
     ];
 
@@ -450,6 +464,7 @@ async fn test_items(openable_state: impl OpenableDurableCatalogState) {
             item.create_sql.clone(),
             item.owner_id,
             item.privileges.clone(),
+            item.table_layout.clone(),
         )
         .unwrap();
     }
@@ -499,6 +514,7 @@ async fn test_set_catalog(openable_state: impl OpenableDurableCatalogState) {
             create_sql: "CREATE VIEW v AS SELECT 1".to_string(),
             owner_id: RoleId::User(1),
             privileges: vec![],
+            table_layout: None,
         },
         Item {
             id: GlobalId::User(200),
@@ -507,6 +523,7 @@ async fn test_set_catalog(openable_state: impl OpenableDurableCatalogState) {
             create_sql: "CREATE MATERIALIZED VIEW mv AS SELECT 2".to_string(),
             owner_id: RoleId::User(2),
             privileges: vec![],
+            table_layout: None,
         },
     ];
     let new_items: BTreeMap<_, _> = [
@@ -517,6 +534,7 @@ async fn test_set_catalog(openable_state: impl OpenableDurableCatalogState) {
             create_sql: "CREATE VIEW v4 AS SELECT 10".to_string(),
             owner_id: RoleId::User(1),
             privileges: vec![],
+            table_layout: None,
         },
         Item {
             id: GlobalId::User(400),
@@ -525,6 +543,7 @@ async fn test_set_catalog(openable_state: impl OpenableDurableCatalogState) {
             create_sql: "CREATE MATERIALIZED VIEW mv4 AS SELECT 20".to_string(),
             owner_id: RoleId::User(2),
             privileges: vec![],
+            table_layout: None,
         },
     ]
     .into_iter()
@@ -565,6 +584,7 @@ async fn test_set_catalog(openable_state: impl OpenableDurableCatalogState) {
             item.create_sql.clone(),
             item.owner_id,
             item.privileges.clone(),
+            item.table_layout.clone(),
         )
         .unwrap();
     }
@@ -82,6 +82,10 @@ pub enum ComputeControllerResponse<T> {
     SubscribeResponse(GlobalId, SubscribeResponse<T>),
     /// See [`ComputeResponse::FrontierUpper`]
     FrontierUpper { id: GlobalId, upper: Antichain<T> },
+    /// See [`ComputeResponse::CopyToResponse`].
+    ///
+    /// Reports the number of rows uploaded, or the error that caused the copy to fail.
+    CopyToResponse(GlobalId, Result<u64, anyhow::Error>),
 }
 
 /// Replica configuration
@@ -45,7 +45,9 @@ use crate::protocol::command::{
     ComputeCommand, ComputeParameters, InstanceConfig, Peek, PeekTarget,
 };
 use crate::protocol::history::ComputeCommandHistory;
-use crate::protocol::response::{ComputeResponse, PeekResponse, SubscribeBatch, SubscribeResponse};
+use crate::protocol::response::{
+    ComputeResponse, CopyToResponse, PeekResponse, SubscribeBatch, SubscribeResponse,
+};
 use crate::service::{ComputeClient, ComputeGrpcClient};
 
 #[derive(Error, Debug)]
@@ -147,6 +149,14 @@ pub(super) struct Instance<T> {
     /// on the subscribe's input. `subscribes` is only used to track which updates have been
     /// emitted, to decide if new ones should be emitted or suppressed.
     subscribes: BTreeMap<GlobalId, ActiveSubscribe<T>>,
+    /// Tracks all in-progress COPY TOs.
+    ///
+    /// New entries are added for all s3 oneshot sinks (corresponding to a COPY TO) exported from
+    /// dataflows created through [`ActiveInstance::create_dataflow`].
+    ///
+    /// The entry for a copy to is removed once at least one replica has finished
+    /// or the exporting collection is dropped.
+    copy_tos: BTreeSet<GlobalId>,
     /// The command history, used when introducing new replicas or restarting existing replicas.
     history: ComputeCommandHistory<UIntGauge, T>,
     /// IDs of replicas that have failed and require rehydration.
@@ -345,6 +355,7 @@ where
             log_sources: arranged_logs,
             peeks: Default::default(),
             subscribes: Default::default(),
+            copy_tos: Default::default(),
             history,
             failed_replicas: Default::default(),
             response_tx,
@@ -780,6 +791,11 @@ where
                 .insert(subscribe_id, ActiveSubscribe::new());
         }
 
+        // Initialize tracking of copy tos.
+        for copy_to_id in dataflow.copy_to_ids() {
+            self.compute.copy_tos.insert(copy_to_id);
+        }
+
         // Here we augment all imported sources and all exported sinks with with the appropriate
         // storage metadata needed by the compute instance.
         let mut source_imports = BTreeMap::new();
@@ -813,6 +829,9 @@ where
                     ComputeSinkConnection::Persist(conn)
                 }
                 ComputeSinkConnection::Subscribe(conn) => ComputeSinkConnection::Subscribe(conn),
+                ComputeSinkConnection::CopyToS3Oneshot(conn) => {
+                    ComputeSinkConnection::CopyToS3Oneshot(conn)
+                }
             };
             let desc = ComputeSinkDesc {
                 from: se.from,
@@ -870,6 +889,11 @@ where
         // Validate that the ids exist.
         self.validate_ids(ids.iter().cloned())?;
 
+        // Dropped copy tos will not produce a response that we need to pass on.
+        for id in &ids {
+            self.compute.copy_tos.remove(id);
+        }
+
         let policies = ids
             .into_iter()
             .map(|id| (id, ReadPolicy::ValidFrom(Antichain::new())));
@@ -1278,6 +1302,9 @@ where
             ComputeResponse::SubscribeResponse(id, response) => {
                 self.handle_subscribe_response(id, response, replica_id)
             }
+            ComputeResponse::CopyToResponse(id, response) => {
+                self.handle_copy_to_response(id, response, replica_id)
+            }
         }
     }
 
@@ -1438,6 +1465,45 @@ where
             }
         }
     }
+
+    fn handle_copy_to_response(
+        &mut self,
+        sink_id: GlobalId,
+        response: CopyToResponse,
+        replica_id: ReplicaId,
+    ) -> Option<ComputeControllerResponse<T>> {
+        if !self.compute.collections.contains_key(&sink_id) {
+            tracing::warn!(?replica_id, "Response for unknown copy to {sink_id}",);
+            tracing::error!("Replica sent a response for an unknown copy to");
+            return None;
+        }
+
+        // A copy to reports its response only once it has stopped reading from its inputs, so
+        // we can advance the replica's write frontier to the empty frontier.
+        self.update_write_frontiers(replica_id, &[(sink_id, Antichain::new())]);
+
+        // Only pass on the first response for each copy to.
+        if !self.compute.copy_tos.remove(&sink_id) {
+            return None;
+        }
+
+        let result = match response {
+            CopyToResponse::RowCount(count) => Ok(count),
+            CopyToResponse::Error(error) => Err(anyhow::anyhow!(error)),
+            // We should never get here: Replicas only drop copy to sinks in response to the
+            // controller allowing them to do so, and when the controller drops a copy to it
+            // also removes it from the list of tracked copy_tos (see `drop_collections`).
+            CopyToResponse::Dropped => {
+                tracing::error!(
+                    %sink_id, %replica_id,
+                    "received `Dropped` response for a tracked copy to",
+                );
+                return None;
+            }
+        };
+
+        Some(ComputeControllerResponse::CopyToResponse(sink_id, result))
+    }
 }
 
 #[derive(Debug)]
@@ -127,6 +127,17 @@ pub enum ComputeResponse<T = mz_repr::Timestamp> {
     /// [`CreateDataflow` command]: super::command::ComputeCommand::CreateDataflow
     /// [`AllowCompaction` command]: super::command::ComputeCommand::AllowCompaction
     SubscribeResponse(GlobalId, SubscribeResponse<T>),
+
+    /// `CopyToResponse` reports the completion of an S3-oneshot sink.
+    ///
+    /// The replica must send exactly one `CopyToResponse` for every S3-oneshot sink previously
+    /// created by a [`CreateDataflow` command].
+    ///
+    /// The replica must not send `CopyToResponse`s for S3-oneshot sinks that were not previously
+    /// created by a [`CreateDataflow` command].
+    ///
+    /// [`CreateDataflow` command]: super::command::ComputeCommand::CreateDataflow
+    CopyToResponse(GlobalId, CopyToResponse),
 }
 
 impl RustType<ProtoComputeResponse> for ComputeResponse<mz_repr::Timestamp> {
@@ -152,6 +163,12 @@ impl RustType<ProtoComputeResponse> for ComputeResponse<mz_repr::Timestamp> {
                         resp: Some(resp.into_proto()),
                     })
                 }
+                ComputeResponse::CopyToResponse(id, resp) => {
+                    CopyToResponse(ProtoCopyToResponseKind {
+                        id: Some(id.into_proto()),
+                        resp: Some(resp.into_proto()),
+                    })
+                }
             }),
         }
     }
@@ -174,6 +191,11 @@ impl RustType<ProtoComputeResponse> for ComputeResponse<mz_repr::Timestamp> {
                 resp.resp
                     .into_rust_if_some("ProtoSubscribeResponseKind::resp")?,
             )),
+            Some(CopyToResponse(resp)) => Ok(ComputeResponse::CopyToResponse(
+                resp.id.into_rust_if_some("ProtoCopyToResponseKind::id")?,
+                resp.resp
+                    .into_rust_if_some("ProtoCopyToResponseKind::resp")?,
+            )),
             None => Err(TryFromProtoError::missing_field(
                 "ProtoComputeResponse::kind",
             )),
@@ -198,6 +220,9 @@ impl Arbitrary for ComputeResponse<mz_repr::Timestamp> {
             (any::<GlobalId>(), any::<SubscribeResponse>())
                 .prop_map(|(id, resp)| ComputeResponse::SubscribeResponse(id, resp))
                 .boxed(),
+            (any::<GlobalId>(), any::<CopyToResponse>())
+                .prop_map(|(id, resp)| ComputeResponse::CopyToResponse(id, resp))
+                .boxed(),
         ])
     }
 }
@@ -290,6 +315,55 @@ impl Arbitrary for PeekResponse {
     }
 }
 
+/// The response from a `CopyToS3Oneshot` sink.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CopyToResponse {
+    /// The sink finished successfully, uploading the given number of rows.
+    RowCount(u64),
+    /// Error of an unsuccessful sink.
+    Error(String),
+    /// The sink was dropped before it could complete.
+    Dropped,
+}
+
+impl RustType<ProtoCopyToResponse> for CopyToResponse {
+    fn into_proto(&self) -> ProtoCopyToResponse {
+        use proto_copy_to_response::Kind::*;
+        ProtoCopyToResponse {
+            kind: Some(match self {
+                CopyToResponse::RowCount(count) => Rows(*count),
+                CopyToResponse::Error(err) => Error(err.clone()),
+                CopyToResponse::Dropped => Dropped(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoCopyToResponse) -> Result<Self, TryFromProtoError> {
+        use proto_copy_to_response::Kind::*;
+        match proto.kind {
+            Some(Rows(count)) => Ok(CopyToResponse::RowCount(count)),
+            Some(Error(err)) => Ok(CopyToResponse::Error(err)),
+            Some(Dropped(())) => Ok(CopyToResponse::Dropped),
+            None => Err(TryFromProtoError::missing_field(
+                "ProtoCopyToResponse::kind",
+            )),
+        }
+    }
+}
+
+impl Arbitrary for CopyToResponse {
+    type Strategy = Union<BoxedStrategy<Self>>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Union::new(vec![
+            any::<u64>().prop_map(CopyToResponse::RowCount).boxed(),
+            ".*".prop_map(CopyToResponse::Error).boxed(),
+            Just(CopyToResponse::Dropped).boxed(),
+        ])
+    }
+}
+
 /// Various responses that can be communicated about the progress of a SUBSCRIBE command.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum SubscribeResponse<T = mz_repr::Timestamp> {
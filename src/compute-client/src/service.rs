@@ -30,7 +30,8 @@ use uuid::Uuid;
 use crate::metrics::ReplicaMetrics;
 use crate::protocol::command::{ComputeCommand, ProtoComputeCommand};
 use crate::protocol::response::{
-    ComputeResponse, PeekResponse, ProtoComputeResponse, SubscribeBatch, SubscribeResponse,
+    ComputeResponse, CopyToResponse, PeekResponse, ProtoComputeResponse, SubscribeBatch,
+    SubscribeResponse,
 };
 use crate::service::proto_compute_server::ProtoCompute;
 
@@ -91,6 +92,8 @@ where
 ///     individual workers.
 ///   * It emits `PeekResponse`s and `SubscribeResponse`s reporting the union of the responses
 ///     received from the workers.
+///   * It emits `CopyToResponse`s reporting the total row count of the responses received from
+///     the workers, or the first error encountered.
 ///
 /// In the compute communication stack, this client is instantiated several times:
 ///
@@ -156,6 +159,14 @@ pub struct PartitionedComputeState<T> {
     /// the tracking state maintained for it and b) we won't re-initialize tracking for a subscribe
     /// we have already dropped.
     pending_subscribes: BTreeMap<GlobalId, PendingSubscribe<T>>,
+    /// Pending responses for a copy to; returnable once all are available.
+    ///
+    /// Tracking of responses for a copy to is initialized when the first `CopyToResponse` for
+    /// that sink is received. Once all shards have provided a `CopyToResponse`, a unified response
+    /// is emitted and the tracking state is dropped again.
+    ///
+    /// The compute protocol requires that exactly one response is emitted for each copy to sink.
+    copy_to_responses: BTreeMap<GlobalId, BTreeMap<usize, CopyToResponse>>,
 }
 
 impl<T> Partitionable<ComputeCommand<T>, ComputeResponse<T>>
@@ -171,6 +182,7 @@ where
             uppers: BTreeMap::new(),
             peek_responses: BTreeMap::new(),
             pending_subscribes: BTreeMap::new(),
+            copy_to_responses: BTreeMap::new(),
         }
     }
 }
@@ -185,10 +197,12 @@ where
             uppers,
             peek_responses,
             pending_subscribes,
+            copy_to_responses,
         } = self;
         uppers.clear();
         peek_responses.clear();
         pending_subscribes.clear();
+        copy_to_responses.clear();
     }
 
     /// Observes commands that move past, and prepares state for responses.
@@ -411,6 +425,34 @@ where
 
                 emit_response
             }
+            ComputeResponse::CopyToResponse(id, response) => {
+                // Incorporate new copy to responses; awaiting all responses.
+                let entry = self
+                    .copy_to_responses
+                    .entry(id)
+                    .or_insert_with(Default::default);
+                let novel = entry.insert(shard_id, response);
+                assert!(novel.is_none(), "Duplicate copy to response");
+                // We may be ready to respond.
+                if entry.len() == self.parts {
+                    let mut response = CopyToResponse::RowCount(0);
+                    for (_part, r) in std::mem::take(entry).into_iter() {
+                        response = match (response, r) {
+                            (_, CopyToResponse::Dropped) => CopyToResponse::Dropped,
+                            (CopyToResponse::Dropped, _) => CopyToResponse::Dropped,
+                            (_, CopyToResponse::Error(e)) => CopyToResponse::Error(e),
+                            (CopyToResponse::Error(e), _) => CopyToResponse::Error(e),
+                            (CopyToResponse::RowCount(r1), CopyToResponse::RowCount(r2)) => {
+                                CopyToResponse::RowCount(r1 + r2)
+                            }
+                        };
+                    }
+                    self.copy_to_responses.remove(&id);
+                    Some(Ok(ComputeResponse::CopyToResponse(id, response)))
+                } else {
+                    None
+                }
+            }
         }
     }
 }
@@ -291,6 +291,16 @@ where
             })
     }
 
+    /// Identifiers of exported copy to sinks.
+    pub fn copy_to_ids(&self) -> impl Iterator<Item = GlobalId> + '_ {
+        self.sink_exports
+            .iter()
+            .filter_map(|(id, desc)| match desc.connection {
+                ComputeSinkConnection::CopyToS3Oneshot(_) => Some(*id),
+                _ => None,
+            })
+    }
+
     /// Produce a `Display`able value containing the import IDs of this dataflow.
     pub fn display_import_ids(&self) -> impl fmt::Display + '_ {
         use mz_ore::str::{bracketed, separated};
@@ -599,7 +599,7 @@ impl ReducePlan {
         // We need to make sure that each list of aggregates by type forms
         // a subsequence of the overall sequence of aggregates.
         for index in 0..aggregates.len() {
-            let typ = reduction_type(&aggregates[index].func);
+            let typ = reduction_type_under(&aggregates[index].func, monotonic);
             let aggregates_list = reduction_types.entry(typ).or_insert_with(Vec::new);
             aggregates_list.push((index, aggregates[index].clone()));
         }
@@ -630,7 +630,7 @@ impl ReducePlan {
         // use to reconstruct the output in the correct order.
         let aggregate_types = aggregates
             .iter()
-            .map(|a| reduction_type(&a.func))
+            .map(|a| reduction_type_under(&a.func, monotonic))
             .collect::<Vec<_>>();
 
         collation.aggregate_types = aggregate_types;
@@ -950,6 +950,9 @@ pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::Count
         | AggregateFunc::Any
         | AggregateFunc::All
+        | AggregateFunc::VarianceNumeric { .. }
+        | AggregateFunc::VarianceFloat64 { .. }
+        | AggregateFunc::Regression { .. }
         | AggregateFunc::Dummy => ReductionType::Accumulable,
         AggregateFunc::MaxNumeric
         | AggregateFunc::MaxInt16
@@ -988,16 +991,43 @@ pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::ArrayConcat { .. }
         | AggregateFunc::ListConcat { .. }
         | AggregateFunc::StringAgg { .. }
+        // Collations do not order strings like their datums, which
+        // hierarchical reductions rely on.
+        | AggregateFunc::MaxStringCollated { .. }
+        | AggregateFunc::MinStringCollated { .. }
+        | AggregateFunc::PercentileCont { .. }
+        | AggregateFunc::PercentileContMulti { .. }
+        | AggregateFunc::PercentileDisc { .. }
+        | AggregateFunc::PercentileDiscMulti { .. }
+        | AggregateFunc::Mode { .. }
+        | AggregateFunc::HllMerge
         | AggregateFunc::RowNumber { .. }
         | AggregateFunc::Rank { .. }
         | AggregateFunc::DenseRank { .. }
+        | AggregateFunc::PercentRank { .. }
+        | AggregateFunc::CumeDist { .. }
+        | AggregateFunc::Ntile { .. }
         | AggregateFunc::LagLead { .. }
         | AggregateFunc::FirstValue { .. }
         | AggregateFunc::LastValue { .. }
+        | AggregateFunc::NthValue { .. }
         | AggregateFunc::WindowAggregate { .. } => ReductionType::Basic,
     }
 }
 
+/// Determines the [`ReductionType`] of `func` for inputs that are `monotonic`
+/// or not.
+///
+/// Merging the sketches of monotonic inputs only ever raises their registers,
+/// so `hll_merge` then only needs the largest rank of each register, which a
+/// single merged sketch per key holds, like `max` does for its values.
+fn reduction_type_under(func: &AggregateFunc, monotonic: bool) -> ReductionType {
+    match func {
+        AggregateFunc::HllMerge if monotonic => ReductionType::Hierarchical,
+        func => reduction_type(func),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mz_proto::protobuf_roundtrip;
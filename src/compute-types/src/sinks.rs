@@ -12,7 +12,9 @@
 use mz_expr::refresh_schedule::RefreshSchedule;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::{GlobalId, RelationDesc, Timestamp};
+use mz_storage_types::connections::aws::AwsConnection;
 use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::sinks::S3UploadInfo;
 use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
@@ -107,6 +109,7 @@ impl RustType<ProtoComputeSinkDesc> for ComputeSinkDesc<CollectionMetadata, Time
 pub enum ComputeSinkConnection<S: 'static = ()> {
     Subscribe(SubscribeSinkConnection),
     Persist(PersistSinkConnection<S>),
+    CopyToS3Oneshot(CopyToS3OneshotSinkConnection),
 }
 
 impl<S> ComputeSinkConnection<S> {
@@ -115,6 +118,7 @@ impl<S> ComputeSinkConnection<S> {
         match self {
             ComputeSinkConnection::Subscribe(_) => "subscribe",
             ComputeSinkConnection::Persist(_) => "persist",
+            ComputeSinkConnection::CopyToS3Oneshot(_) => "copy_to_s3_oneshot",
         }
     }
 
@@ -126,6 +130,12 @@ impl<S> ComputeSinkConnection<S> {
             false
         }
     }
+
+    /// True if the sink is a one-shot `COPY ... TO` export, which runs to
+    /// completion at a single timestamp rather than being maintained.
+    pub fn is_copy_to(&self) -> bool {
+        matches!(self, ComputeSinkConnection::CopyToS3Oneshot(_))
+    }
 }
 
 impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMetadata> {
@@ -135,6 +145,9 @@ impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMe
             kind: Some(match self {
                 ComputeSinkConnection::Subscribe(_) => Kind::Subscribe(()),
                 ComputeSinkConnection::Persist(persist) => Kind::Persist(persist.into_proto()),
+                ComputeSinkConnection::CopyToS3Oneshot(s3) => {
+                    Kind::CopyToS3Oneshot(s3.into_proto())
+                }
             }),
         }
     }
@@ -147,6 +160,7 @@ impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMe
         Ok(match kind {
             Kind::Subscribe(_) => ComputeSinkConnection::Subscribe(SubscribeSinkConnection {}),
             Kind::Persist(persist) => ComputeSinkConnection::Persist(persist.into_rust()?),
+            Kind::CopyToS3Oneshot(s3) => ComputeSinkConnection::CopyToS3Oneshot(s3.into_rust()?),
         })
     }
 }
@@ -179,3 +193,39 @@ impl RustType<ProtoPersistSinkConnection> for PersistSinkConnection<CollectionMe
         })
     }
 }
+
+/// A one-shot sink that writes a snapshot of its input to S3, as issued by
+/// `COPY ... TO 's3://...'`.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CopyToS3OneshotSinkConnection {
+    /// Where and how the snapshot is written.
+    pub upload_info: S3UploadInfo,
+    /// The AWS connection used to authenticate against S3.
+    pub aws_connection: AwsConnection,
+    /// The ID of the AWS connection, required to assume its role.
+    pub connection_id: GlobalId,
+}
+
+impl RustType<ProtoCopyToS3OneshotSinkConnection> for CopyToS3OneshotSinkConnection {
+    fn into_proto(&self) -> ProtoCopyToS3OneshotSinkConnection {
+        ProtoCopyToS3OneshotSinkConnection {
+            upload_info: Some(self.upload_info.into_proto()),
+            aws_connection: Some(self.aws_connection.into_proto()),
+            connection_id: Some(self.connection_id.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoCopyToS3OneshotSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(CopyToS3OneshotSinkConnection {
+            upload_info: proto
+                .upload_info
+                .into_rust_if_some("ProtoCopyToS3OneshotSinkConnection::upload_info")?,
+            aws_connection: proto
+                .aws_connection
+                .into_rust_if_some("ProtoCopyToS3OneshotSinkConnection::aws_connection")?,
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoCopyToS3OneshotSinkConnection::connection_id")?,
+        })
+    }
+}
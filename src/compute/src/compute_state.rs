@@ -23,7 +23,9 @@ use mz_compute_client::protocol::command::{
     ComputeCommand, ComputeParameters, InstanceConfig, Peek, PeekTarget,
 };
 use mz_compute_client::protocol::history::ComputeCommandHistory;
-use mz_compute_client::protocol::response::{ComputeResponse, PeekResponse, SubscribeResponse};
+use mz_compute_client::protocol::response::{
+    ComputeResponse, CopyToResponse, PeekResponse, SubscribeResponse,
+};
 use mz_compute_types::dataflows::DataflowDescription;
 use mz_compute_types::plan::Plan;
 use mz_expr::SafeMfpPlan;
@@ -71,6 +73,7 @@ pub struct ComputeState {
     ///    `TraceManager`.
     ///  * Persist sinks store their current frontier in `CollectionState::sink_write_frontier`.
     ///  * Subscribes report their frontiers through the `subscribe_response_buffer`.
+    ///  * Copy tos report their completion through the `copy_to_response_buffer`.
     pub collections: BTreeMap<GlobalId, CollectionState>,
     /// Collections that were recently dropped and whose removal needs to be reported.
     pub dropped_collections: Vec<GlobalId>,
@@ -81,6 +84,11 @@ pub struct ComputeState {
     /// The entries are pairs of sink identifier (to identify the subscribe instance)
     /// and the response itself.
     pub subscribe_response_buffer: Rc<RefCell<Vec<(GlobalId, SubscribeResponse)>>>,
+    /// Shared buffer with S3 oneshot operator instances by which they can respond.
+    ///
+    /// The entries are pairs of sink identifier (to identify the s3 oneshot instance)
+    /// and the response itself.
+    pub copy_to_response_buffer: Rc<RefCell<Vec<(GlobalId, CopyToResponse)>>>,
     /// Peek commands that are awaiting fulfillment.
     pub pending_peeks: BTreeMap<Uuid, PendingPeek>,
     /// The logger, from Timely's logging framework, if logs are enabled.
@@ -123,6 +131,7 @@ impl ComputeState {
             dropped_collections: Default::default(),
             traces,
             subscribe_response_buffer: Default::default(),
+            copy_to_response_buffer: Default::default(),
             pending_peeks: Default::default(),
             compute_logger: None,
             persist_clients,
@@ -409,10 +418,12 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
         // unless:
         //  * The collection is a subscribe, in which case we will emit a
         //    `SubscribeResponse::Dropped` independently.
+        //  * The collection is a copy to, in which case we will emit a
+        //    `CopyToResponse::Dropped` independently.
         //  * The collection has already advanced to the empty frontier, in which case
         //    the final `FrontierUpper` response already serves the purpose of reporting
         //    the end of the dataflow.
-        if !collection.is_subscribe() && !collection.reported_frontier.is_empty() {
+        if !collection.is_subscribe_or_copy() && !collection.reported_frontier.is_empty() {
             self.compute_state.dropped_collections.push(id);
         }
     }
@@ -474,8 +485,9 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
             } else if let Some(frontier) = &collection.sink_write_frontier {
                 new_frontier.clone_from(&frontier.borrow());
             } else {
-                // Subscribe frontiers are reported in `process_subscribes` instead.
-                if !collection.is_subscribe() {
+                // Subscribe frontiers are reported in `process_subscribes` and copy to frontiers
+                // in `process_copy_tos` instead.
+                if !collection.is_subscribe_or_copy() {
                     error!(id = ?id, "collection without frontier");
                 }
                 continue;
@@ -650,6 +662,32 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
         }
     }
 
+    /// Scan the shared copy to response buffer, and forward results along.
+    pub fn process_copy_tos(&mut self) {
+        let mut copy_to_responses = self.compute_state.copy_to_response_buffer.borrow_mut();
+        for (sink_id, response) in copy_to_responses.drain(..) {
+            // Update frontier logging for this copy to. A copy to is done once it has responded.
+            if let Some(collection) = self.compute_state.collections.get_mut(&sink_id) {
+                if let Some(logger) = self.compute_state.compute_logger.as_mut() {
+                    if let Some(time) = collection.reported_frontier.logging_time() {
+                        logger.log(ComputeEvent::Frontier {
+                            id: sink_id,
+                            time,
+                            diff: -1,
+                        });
+                    }
+                }
+
+                collection.reported_frontier = ReportedFrontier::Reported(Antichain::new());
+            } else {
+                // Presumably tracking state for this copy to was already dropped by
+                // `drop_collection`. There is nothing left to do for logging.
+            }
+
+            self.send_compute_response(ComputeResponse::CopyToResponse(sink_id, response));
+        }
+    }
+
     /// Send a response to the coordinator.
     fn send_compute_response(&self, response: ComputeResponse) {
         // Ignore send errors because the coordinator is free to ignore our
@@ -874,7 +912,14 @@ impl PersistPeek {
                 let Some(count) = NonZeroUsize::new(count) else {
                     continue;
                 };
-                let mut datum_local = datum_vec.borrow_with(&row);
+                let mut datum_local = match &metadata.table_layout {
+                    Some(layout) => {
+                        let mut datum_local = datum_vec.borrow();
+                        datum_local.extend(layout.datums(&row));
+                        datum_local
+                    }
+                    None => datum_vec.borrow_with(&row),
+                };
                 let eval_result = mfp_plan
                     .evaluate_into(&mut datum_local, &arena, &mut row_builder)
                     .map_err(|e| e.to_string())?;
@@ -1261,7 +1306,7 @@ pub struct CollectionState {
     pub sink_token: Option<SinkToken>,
     /// Frontier of sink writes.
     ///
-    /// Only `Some` if the collection is a sink and *not* a subscribe.
+    /// Only `Some` if the collection is a sink and *not* a subscribe or copy to.
     pub sink_write_frontier: Option<Rc<RefCell<Antichain<Timestamp>>>>,
 }
 
@@ -1274,7 +1319,9 @@ impl CollectionState {
         }
     }
 
-    fn is_subscribe(&self) -> bool {
+    /// Whether this collection is a subscribe or copy to, which report their frontiers through
+    /// dedicated response buffers.
+    fn is_subscribe_or_copy(&self) -> bool {
         self.sink_token.is_some() && self.sink_write_frontier.is_none()
     }
 }
@@ -71,7 +71,7 @@ impl Default for LinearJoinSpec {
 
 impl LinearJoinSpec {
     /// Render a join operator according to this specification.
-    fn render<G, Tr1, Tr2, L, I>(
+    pub(crate) fn render<G, Tr1, Tr2, L, I>(
         &self,
         arranged1: &Arranged<G, Tr1>,
         arranged2: &Arranged<G, Tr2>,
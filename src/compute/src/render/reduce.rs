@@ -11,8 +11,10 @@
 //!
 //! Consult [ReducePlan] documentation for details.
 
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 
+use chrono::Timelike;
 use dec::OrderedDecimal;
 use differential_dataflow::collection::AsCollection;
 use differential_dataflow::difference::{Multiply, Semigroup};
@@ -27,8 +29,11 @@ use mz_compute_types::plan::reduce::{
     MonotonicPlan, ReducePlan, ReductionType,
 };
 use mz_expr::{
-    AggregateExpr, AggregateFunc, EvalError, MapFilterProject, MirScalarExpr, SafeMfpPlan,
+    compare_columns, AggregateExpr, AggregateFunc, ColumnOrder, EvalError, MapFilterProject,
+    MirScalarExpr, SafeMfpPlan, StatisticalMoments,
 };
+use mz_ore::cast::{CastFrom, ReinterpretCast};
+use mz_repr::adt::hll::Hll;
 use mz_repr::adt::numeric::{self, Numeric, NumericAgg};
 use mz_repr::fixed_length::IntoRowByTypes;
 use mz_repr::{Datum, DatumList, DatumVec, Diff, Row, RowArena, SharedRow};
@@ -743,6 +748,12 @@ where
             (key, row_builder.clone())
         });
 
+        // Merging sketches is insensitive to duplicates, so we ignore `distinct`
+        // and maintain the registers of the merged sketch individually.
+        if let AggregateFunc::HllMerge = func {
+            return self.build_hll_aggregate(partial, validating, mfp_after);
+        }
+
         let mut err_output = None;
 
         // If `distinct` is set, we restrict ourselves to the distinct `(key, val)`.
@@ -767,6 +778,21 @@ where
             }
         }
 
+        // Ordered-set aggregates need their values in sorted order, which we
+        // maintain incrementally rather than by sorting each group from scratch.
+        if let Some(order_by) = func.ordered_set_order_by() {
+            let order_by = order_by.to_vec();
+            // Validation has already happened if we applied distinctness.
+            let validating = validating && err_output.is_none();
+            let (oks, errs) =
+                self.build_ordered_set_aggregate(partial, func, order_by, validating, mfp_after);
+            let err_output = match (err_output, errs) {
+                (Some(e1), Some(e2)) => Some(e1.concat(&e2)),
+                (e1, e2) => e1.or(e2),
+            };
+            return (oks, err_output);
+        }
+
         // Allocations for the two closures.
         let mut datums1 = DatumVec::new();
         let mut datums2 = DatumVec::new();
@@ -872,6 +898,509 @@ where
         (oks, err_output)
     }
 
+    /// Build the dataflow to compute a single ordered-set aggregation, such as
+    /// `percentile_cont`, whose input values are `(direct_arg, value)` records.
+    ///
+    /// Rather than sorting all values of a key on each change, the values of
+    /// each key are arranged in a tree that is ordered by value. Each value is
+    /// placed in a leaf according to its [`ordered_set_sort_prefix`], and the
+    /// nodes at depth `d` collect the values whose prefixes agree in their top
+    /// `d` bytes. Every node summarizes each of its at most 256 children by the
+    /// number of values below it and the largest count of a distinct value
+    /// below it, so a change to a value only updates the nodes on the path from
+    /// its leaf to the root.
+    ///
+    /// Each key then determines, from the summary at the root, the positions in
+    /// the sort order of the values it needs, or, for `mode`, the count of its
+    /// most frequent value. Each of these is looked up by descending from the
+    /// root along the one path that contains it.
+    fn build_ordered_set_aggregate<S>(
+        &self,
+        input: Collection<S, (Row, Row), Diff>,
+        func: AggregateFunc,
+        order_by: Vec<ColumnOrder>,
+        validating: bool,
+        mfp_after: Option<SafeMfpPlan>,
+    ) -> (
+        RowRowArrangement<S>,
+        Option<Collection<S, DataflowError, Diff>>,
+    )
+    where
+        S: Scope<Timestamp = G::Timestamp>,
+    {
+        // Records are null when excluded by a `FILTER` clause, and null values
+        // are ignored. We cannot discard their direct arguments, as every key
+        // must produce an output.
+        let direct_args = input.map(|(key, row)| {
+            let direct_arg = match row.unpack_first() {
+                Datum::Null => Datum::Null,
+                record => record.unwrap_list().iter().next().unwrap(),
+            };
+            let value = Row::pack_slice(&[Datum::UInt64(ORDERED_SET_DIRECT_ARG), direct_arg]);
+            (key, value)
+        });
+
+        // Append the sort prefix of the value to the key of each leaf.
+        let order_by1 = order_by.clone();
+        let values = input.flat_map(move |(key, row)| {
+            let value = match row.unpack_first() {
+                Datum::Null => return None,
+                record => record.unwrap_list().iter().nth(1).unwrap(),
+            };
+            if value.is_null() {
+                return None;
+            }
+            let prefix = ordered_set_sort_prefix(order_by1.first(), value);
+            let binding = SharedRow::get();
+            let mut row_builder = binding.borrow_mut();
+            let mut packer = row_builder.packer();
+            packer.extend(key.iter());
+            packer.push(Datum::UInt64(prefix));
+            let node = row_builder.clone();
+            Some((node, Row::pack_slice(&[value])))
+        });
+
+        // Reduce each leaf to its distinct values in sort order.
+        let order_by2 = order_by.clone();
+        let arranged = values.mz_arrange::<RowRowSpine<_, _>>("Arranged ReduceOrderedSet");
+        let leaves =
+            arranged.mz_reduce_abelian::<_, RowRowSpine<_, _>>("ReduceOrderedSet Leaves", {
+                move |_key, source, target| {
+                    // Non-positive counts are reported by the error check below.
+                    let mut entries: Vec<_> = source
+                        .iter()
+                        .filter(|(_value, count)| count.is_positive())
+                        .map(|(mut value, count)| (value.next().unwrap(), *count, *count))
+                        .collect();
+                    if entries.is_empty() {
+                        return;
+                    }
+                    entries.sort_by(|(left, _, _), (right, _, _)| {
+                        compare_ordered_set_values(&order_by2, *left, *right)
+                    });
+                    target.push((pack_ordered_set_node(&entries), 1));
+                }
+            });
+
+        let mut err_output = None;
+        if validating {
+            let error_logger = self.error_logger();
+            let errs = arranged
+                .mz_reduce_abelian::<_, RowErrSpine<_, _>>(
+                    "ReduceOrderedSet Error Check",
+                    move |_key, source, target| {
+                        // Negative counts would be surprising, but until we are 100% certain we won't
+                        // see them, we should report when we do.
+                        for (value, count) in source.iter() {
+                            if count.is_positive() {
+                                continue;
+                            }
+                            let value = value.into_owned();
+                            let message = "Non-positive accumulation in ReduceOrderedSet";
+                            error_logger.log(message, &format!("value={value:?}, count={count}"));
+                            target.push((EvalError::Internal(message.to_string()).into(), 1));
+                            return;
+                        }
+                    },
+                )
+                .as_collection(|_, v| v.into_owned());
+            err_output = Some(errs);
+        }
+
+        // Summarize the children of each node, from the leaves up to the root.
+        // `levels[d]` holds the nodes at depth `d`.
+        let mut levels = vec![leaves];
+        for _depth in (0..ORDERED_SET_DEPTH).rev() {
+            let children = levels.last().unwrap();
+            let summaries = children
+                .as_collection(|node, mut summary| {
+                    let count = summary.next().unwrap();
+                    let max = summary.next().unwrap();
+                    let (node, prefix) = split_ordered_set_node(node);
+                    let binding = SharedRow::get();
+                    let mut row_builder = binding.borrow_mut();
+                    let mut packer = row_builder.packer();
+                    packer.extend(node.iter());
+                    packer.push(Datum::UInt64(prefix >> 8));
+                    let parent = row_builder.clone();
+                    let child = Row::pack_slice(&[Datum::UInt64(prefix & 0xff), count, max]);
+                    (parent, child)
+                })
+                .mz_arrange::<RowRowSpine<_, _>>("Arranged ReduceOrderedSet Node")
+                .mz_reduce_abelian::<_, RowRowSpine<_, _>>("ReduceOrderedSet Node", {
+                    move |_key, source, target| {
+                        let mut entries: Vec<_> = source
+                            .iter()
+                            .map(|(mut child, _count)| {
+                                let byte = child.next().unwrap();
+                                let count = child.next().unwrap().unwrap_int64();
+                                let max = child.next().unwrap().unwrap_int64();
+                                (byte, count, max)
+                            })
+                            .collect();
+                        entries.sort_by_key(|(byte, _, _)| byte.unwrap_uint64());
+                        target.push((pack_ordered_set_node(&entries), 1));
+                    }
+                });
+            levels.push(summaries);
+        }
+        levels.reverse();
+
+        // Combine the direct argument of each key with the summary at its
+        // root, which is absent if the key has no values.
+        let roots = levels[0].as_collection(|node, mut summary| {
+            let count = summary.next().unwrap();
+            let max = summary.next().unwrap();
+            let (key, _prefix) = split_ordered_set_node(node);
+            let root = Row::pack_slice(&[Datum::UInt64(ORDERED_SET_ROOT), count, max]);
+            (key, root)
+        });
+        let headers = direct_args
+            .concat(&roots)
+            .mz_arrange::<RowRowSpine<_, _>>("Arranged ReduceOrderedSet Header")
+            .mz_reduce_abelian::<_, RowRowSpine<_, _>>("ReduceOrderedSet Header", {
+                move |_key, source, target| {
+                    let mut direct_arg = Datum::Null;
+                    let (mut total, mut max) = (Datum::Int64(0), Datum::Int64(0));
+                    for (mut entry, count) in source.iter() {
+                        if !count.is_positive() {
+                            continue;
+                        }
+                        match entry.next().unwrap().unwrap_uint64() {
+                            ORDERED_SET_ROOT => {
+                                total = entry.next().unwrap();
+                                max = entry.next().unwrap();
+                            }
+                            _ => {
+                                let datum = entry.next().unwrap();
+                                if !datum.is_null() {
+                                    direct_arg = datum;
+                                }
+                            }
+                        }
+                    }
+                    let header = Row::pack_slice(&[
+                        Datum::UInt64(ORDERED_SET_HEADER),
+                        direct_arg,
+                        total,
+                        max,
+                    ]);
+                    target.push((header, 1));
+                }
+            });
+
+        // Request the values that each key needs from its root.
+        let func1 = func.clone();
+        let mut requests = headers
+            .as_collection(|key, header| (key.into_owned(), header.into_owned()))
+            .flat_map(move |(key, header)| {
+                let mut header = header.iter().skip(1);
+                let direct_arg = header.next().unwrap();
+                let total = header.next().unwrap().unwrap_int64();
+                let max = header.next().unwrap().unwrap_int64();
+                let mut requests: Vec<_> = func1
+                    .ordered_set_positions(direct_arg, usize::try_from(total).unwrap_or(0))
+                    .into_iter()
+                    .map(|position| {
+                        let target = i64::try_from(position).expect("position fits in i64");
+                        (u64::cast_from(position), target)
+                    })
+                    .collect();
+                if matches!(func1, AggregateFunc::Mode { .. }) && total > 0 {
+                    requests.push((ORDERED_SET_MODE, max));
+                }
+                requests.sort();
+                requests.dedup();
+
+                let binding = SharedRow::get();
+                let mut row_builder = binding.borrow_mut();
+                let mut packer = row_builder.packer();
+                packer.extend(key.iter());
+                packer.push(Datum::UInt64(0));
+                let root = row_builder.clone();
+                requests.into_iter().map(move |(id, target)| {
+                    let request = Row::pack_slice(&[Datum::UInt64(id), Datum::Int64(target)]);
+                    (root.clone(), request)
+                })
+            });
+
+        // Descend the tree one level at a time, following each request to the
+        // child that contains the value it asks for.
+        for level in &levels[..ORDERED_SET_DEPTH] {
+            let arranged =
+                requests.mz_arrange::<RowRowSpine<_, _>>("Arranged ReduceOrderedSet Requests");
+            requests = self.linear_join_spec.render(
+                &arranged,
+                level,
+                self.shutdown_token.clone(),
+                |node, mut request, mut summary| {
+                    let id = request.next().unwrap().unwrap_uint64();
+                    let target = request.next().unwrap().unwrap_int64();
+                    let children = summary.nth(2).unwrap().unwrap_list();
+                    let (byte, target) = descend_ordered_set_node(id, target, children)?;
+                    let (node, prefix) = split_ordered_set_node(node);
+                    let binding = SharedRow::get();
+                    let mut row_builder = binding.borrow_mut();
+                    let mut packer = row_builder.packer();
+                    packer.extend(node.iter());
+                    packer.push(Datum::UInt64((prefix << 8) | byte.unwrap_uint64()));
+                    let child = row_builder.clone();
+                    let request = Row::pack_slice(&[Datum::UInt64(id), Datum::Int64(target)]);
+                    Some((child, request))
+                },
+            );
+        }
+
+        // Look up the requested values in the leaves.
+        let arranged =
+            requests.mz_arrange::<RowRowSpine<_, _>>("Arranged ReduceOrderedSet Requests");
+        let selected = self.linear_join_spec.render(
+            &arranged,
+            &levels[ORDERED_SET_DEPTH],
+            self.shutdown_token.clone(),
+            |node, mut request, mut summary| {
+                let id = request.next().unwrap().unwrap_uint64();
+                let target = request.next().unwrap().unwrap_int64();
+                let entries = summary.nth(2).unwrap().unwrap_list();
+                let (value, _target) = descend_ordered_set_node(id, target, entries)?;
+                let (key, _prefix) = split_ordered_set_node(node);
+                let selected = Row::pack_slice(&[
+                    Datum::UInt64(ORDERED_SET_SELECTED),
+                    Datum::UInt64(id),
+                    value,
+                ]);
+                Some((key, selected))
+            },
+        );
+
+        // Allocations for the two closures.
+        let mut datums1 = DatumVec::new();
+        let mut datums2 = DatumVec::new();
+        let mfp_after1 = mfp_after.clone();
+        let mfp_after2 = mfp_after.filter(|mfp| mfp.could_error());
+        let func2 = func.clone();
+
+        let headers = headers.as_collection(|key, header| (key.into_owned(), header.into_owned()));
+        let arranged = headers
+            .concat(&selected)
+            .mz_arrange::<RowRowSpine<_, _>>("Arranged ReduceOrderedSet Selected");
+        let oks = arranged.mz_reduce_abelian::<_, RowRowSpine<_, _>>("ReduceOrderedSet", {
+            move |key, source, target| {
+                let temp_storage = RowArena::new();
+                let entries = source.iter().map(|(entry, _count)| *entry);
+                let Some(result) = eval_ordered_set_selected(&func, entries, &temp_storage) else {
+                    return;
+                };
+                let datum_iter = key.into_datum_iter(None);
+                let mut datums_local = datums1.borrow();
+                datums_local.extend(datum_iter);
+                let key_len = datums_local.len();
+                datums_local.push(result);
+
+                if let Some(row) =
+                    evaluate_mfp_after(&mfp_after1, &mut datums_local, &temp_storage, key_len)
+                {
+                    target.push((row, 1));
+                }
+            }
+        });
+
+        if let Some(mfp) = mfp_after2 {
+            let errs = arranged
+                .mz_reduce_abelian::<_, RowErrSpine<_, _>>(
+                    "ReduceOrderedSet MFP Error Check",
+                    move |key, source, target| {
+                        let temp_storage = RowArena::new();
+                        let entries = source.iter().map(|(entry, _count)| *entry);
+                        let Some(result) =
+                            eval_ordered_set_selected(&func2, entries, &temp_storage)
+                        else {
+                            return;
+                        };
+                        let datum_iter = key.into_datum_iter(None);
+                        let mut datums_local = datums2.borrow();
+                        datums_local.extend(datum_iter);
+                        datums_local.push(result);
+                        if let Result::Err(e) = mfp.evaluate_inner(&mut datums_local, &temp_storage)
+                        {
+                            target.push((e.into(), 1));
+                        }
+                    },
+                )
+                .as_collection(|_, v| v.into_owned());
+            err_output = match err_output {
+                Some(e) => Some(e.concat(&errs)),
+                None => Some(errs),
+            };
+        }
+        (oks, err_output)
+    }
+
+    /// Build the dataflow to compute a single `hll_merge` aggregation.
+    ///
+    /// Each register of a merged sketch holds the largest rank of that register
+    /// among the merged sketches. Rather than merging all sketches of a key on
+    /// each change, which would require arranging every sketch, the sketches
+    /// are split into their non-zero registers, and each register of each key
+    /// keeps a count of its inputs per rank, from which its largest rank is
+    /// maintained. A retraction then only revisits the registers it affects,
+    /// and the merged sketch of each key is assembled from at most one rank per
+    /// register.
+    ///
+    /// Monotonic inputs are instead reduced hierarchically, keeping only the
+    /// merged sketch of each key, as their registers can only ever grow.
+    fn build_hll_aggregate<S>(
+        &self,
+        input: Collection<S, (Row, Row), Diff>,
+        validating: bool,
+        mfp_after: Option<SafeMfpPlan>,
+    ) -> (
+        RowRowArrangement<S>,
+        Option<Collection<S, DataflowError, Diff>>,
+    )
+    where
+        S: Scope<Timestamp = G::Timestamp>,
+    {
+        // Every key produces an output, which is null if all of its sketches
+        // are, so we record whether each input has a sketch.
+        let presence = input.map(|(key, row)| {
+            let present = Datum::from(!row.unpack_first().is_null());
+            (
+                key,
+                Row::pack_slice(&[Datum::UInt64(HLL_PRESENCE), present]),
+            )
+        });
+
+        // Append the index of each non-zero register to the key, and count the
+        // inputs with each rank of the register in the difference, at the
+        // position of the rank.
+        let ranks = input
+            .flat_map(|(key, row)| {
+                let sketch = match row.unpack_first() {
+                    Datum::Null => None,
+                    datum => Some(Hll::decode(datum.unwrap_bytes()).expect("valid hll sketch")),
+                };
+                sketch
+                    .into_iter()
+                    .flat_map(|sketch| sketch.ranks().collect::<Vec<_>>())
+                    .map(move |(index, rank)| {
+                        let binding = SharedRow::get();
+                        let mut row_builder = binding.borrow_mut();
+                        let mut packer = row_builder.packer();
+                        packer.extend(key.iter());
+                        packer.push(Datum::UInt64(u64::cast_from(index)));
+                        (row_builder.clone(), rank)
+                    })
+            })
+            .explode_one(|(register, rank)| {
+                let mut counts = vec![0; usize::from(rank) + 1];
+                counts[usize::from(rank)] = 1;
+                ((register, ()), counts)
+            });
+
+        // Reduce each register to its largest rank with a positive count.
+        let arranged = ranks.mz_arrange::<RowSpine<_, Vec<Diff>>>("Arranged ReduceHll");
+        let registers =
+            arranged.mz_reduce_abelian::<_, RowRowSpine<_, _>>("ReduceHll Registers", {
+                move |_key, source, target| {
+                    // Non-positive counts are reported by the error check below.
+                    let counts = &source[0].1;
+                    let rank = counts.iter().rposition(|count| count.is_positive());
+                    if let Some(rank) = rank {
+                        let rank = u64::cast_from(rank);
+                        target.push((Row::pack_slice(&[Datum::UInt64(rank)]), 1));
+                    }
+                }
+            });
+
+        let mut err_output = None;
+        if validating {
+            let error_logger = self.error_logger();
+            let errs = arranged
+                .mz_reduce_abelian::<_, RowErrSpine<_, _>>(
+                    "ReduceHll Error Check",
+                    move |_key, source, target| {
+                        // Negative counts would be surprising, but until we are 100% certain we won't
+                        // see them, we should report when we do.
+                        let counts = &source[0].1;
+                        if counts.iter().any(|count| count.is_negative()) {
+                            let message = "Non-positive accumulation in ReduceHll";
+                            error_logger.log(message, &format!("counts={counts:?}"));
+                            target.push((EvalError::Internal(message.to_string()).into(), 1));
+                        }
+                    },
+                )
+                .as_collection(|_, v| v.into_owned());
+            err_output = Some(errs);
+        }
+
+        let registers = registers.as_collection(|register, mut rank| {
+            let mut datums: Vec<_> = register.into_iter().collect();
+            let index = datums.pop().unwrap();
+            let rank = rank.next().unwrap();
+            let entry = Row::pack_slice(&[Datum::UInt64(HLL_REGISTER), index, rank]);
+            (Row::pack_slice(&datums), entry)
+        });
+
+        // Allocations for the two closures.
+        let mut datums1 = DatumVec::new();
+        let mut datums2 = DatumVec::new();
+        let mfp_after1 = mfp_after.clone();
+        let mfp_after2 = mfp_after.filter(|mfp| mfp.could_error());
+
+        let arranged = presence
+            .concat(&registers)
+            .mz_arrange::<RowRowSpine<_, _>>("Arranged ReduceHll Sketch");
+        let oks = arranged.mz_reduce_abelian::<_, RowRowSpine<_, _>>("ReduceHll", {
+            move |key, source, target| {
+                let temp_storage = RowArena::new();
+                let entries = source.iter().map(|(entry, count)| (*entry, *count));
+                let Some(result) = eval_hll_registers(entries, &temp_storage) else {
+                    return;
+                };
+                let datum_iter = key.into_datum_iter(None);
+                let mut datums_local = datums1.borrow();
+                datums_local.extend(datum_iter);
+                let key_len = datums_local.len();
+                datums_local.push(result);
+
+                if let Some(row) =
+                    evaluate_mfp_after(&mfp_after1, &mut datums_local, &temp_storage, key_len)
+                {
+                    target.push((row, 1));
+                }
+            }
+        });
+
+        if let Some(mfp) = mfp_after2 {
+            let errs = arranged
+                .mz_reduce_abelian::<_, RowErrSpine<_, _>>(
+                    "ReduceHll MFP Error Check",
+                    move |key, source, target| {
+                        let temp_storage = RowArena::new();
+                        let entries = source.iter().map(|(entry, count)| (*entry, *count));
+                        let Some(result) = eval_hll_registers(entries, &temp_storage) else {
+                            return;
+                        };
+                        let datum_iter = key.into_datum_iter(None);
+                        let mut datums_local = datums2.borrow();
+                        datums_local.extend(datum_iter);
+                        datums_local.push(result);
+                        if let Result::Err(e) = mfp.evaluate_inner(&mut datums_local, &temp_storage)
+                        {
+                            target.push((e.into(), 1));
+                        }
+                    },
+                )
+                .as_collection(|_, v| v.into_owned());
+            err_output = match err_output {
+                Some(e) => Some(e.concat(&errs)),
+                None => Some(errs),
+            };
+        }
+        (oks, err_output)
+    }
+
     fn build_reduce_inaccumulable_distinct<S, Tr>(
         &self,
         input: Collection<S, (Row, Row), Diff>,
@@ -1329,16 +1858,26 @@ where
         // Other aggregations can be directly moved in to the `diff` field.
         //
         // In each case, the resulting collection should have `data` shaped as `(key, ())`
-        // and a `diff` that is a vector with length `3 * aggrs.len()`. The three values are
-        // generally the count, and then two aggregation-specific values. The size could be
-        // reduced if we want to specialize for the aggregations.
+        // and a `diff` that is a vector with one accumulator per aggregation, except for
+        // statistical aggregations, which spread their sums over several consecutive
+        // accumulators.
+
+        // The position of each aggregation's first accumulator in the diff vector.
+        let offsets: Vec<usize> = full_aggrs
+            .iter()
+            .scan(0, |offset, aggr| {
+                let start = *offset;
+                *offset += accumulable_width(&aggr.func);
+                Some(start)
+            })
+            .collect();
 
         // Instantiate a default vector for diffs with the correct types at each
         // position.
         let zero_diffs: (Vec<_>, Diff) = (
             full_aggrs
                 .iter()
-                .map(|f| accumulable_zero(&f.func))
+                .flat_map(|f| accumulable_zeros(&f.func))
                 .collect(),
             0,
         );
@@ -1348,6 +1887,7 @@ where
             // First, collect all non-distinct aggregations in one pass.
             let easy_cases = collection.explode_one({
                 let zero_diffs = zero_diffs.clone();
+                let offsets = offsets.clone();
                 move |(key, row)| {
                     let mut diffs = zero_diffs.clone();
                     // Try to unpack only the datums we need. Unfortunately, since we
@@ -1362,7 +1902,8 @@ where
                             datum = row_iter.next().unwrap();
                         }
                         let datum = datum.1;
-                        diffs.0[*accumulable_index] = datum_to_accumulator(&aggr.func, datum);
+                        let offset = offsets[*accumulable_index];
+                        datum_to_accumulators(&aggr.func, datum, &mut diffs.0[offset..]);
                         diffs.1 = 1;
                     }
                     ((key, ()), diffs)
@@ -1373,6 +1914,7 @@ where
 
         // Next, collect all aggregations that require distinctness.
         for (accumulable_index, datum_index, aggr) in distinct_aggrs.into_iter() {
+            let offset = offsets[accumulable_index];
             let collection = collection
                 .map(move |(key, row)| {
                     let binding = SharedRow::get();
@@ -1395,7 +1937,7 @@ where
                     move |(key, row)| {
                         let datum = row.iter().next().unwrap();
                         let mut diffs = zero_diffs.clone();
-                        diffs.0[accumulable_index] = datum_to_accumulator(&aggr.func, datum);
+                        datum_to_accumulators(&aggr.func, datum, &mut diffs.0[offset..]);
                         diffs.1 = 1;
                         ((key, ()), diffs)
                     }
@@ -1416,6 +1958,8 @@ where
         let mfp_after1 = mfp_after.clone();
         let mfp_after2 = mfp_after.filter(|mfp| mfp.could_error());
         let full_aggrs2 = full_aggrs.clone();
+        let offsets1 = offsets.clone();
+        let offsets2 = offsets;
 
         let error_logger = self.error_logger();
         let err_full_aggrs = full_aggrs.clone();
@@ -1433,8 +1977,12 @@ where
                         let mut datums_local = datums1.borrow();
                         datums_local.extend(datum_iter);
                         let key_len = datums_local.len();
-                        for (aggr, accum) in full_aggrs.iter().zip(accums) {
-                            datums_local.push(finalize_accum(&aggr.func, accum, total));
+                        for (aggr, offset) in full_aggrs.iter().zip(&offsets1) {
+                            datums_local.push(finalize_accums(
+                                &aggr.func,
+                                &accums[*offset..],
+                                total,
+                            ));
                         }
 
                         if let Some(row) = evaluate_mfp_after(
@@ -1449,13 +1997,14 @@ where
                 },
                 move |key, input, output| {
                     let (ref accums, total) = input[0].1;
-                    for (aggr, accum) in err_full_aggrs.iter().zip(accums) {
+                    for (aggr, offset) in err_full_aggrs.iter().zip(&offsets2) {
+                        let aggr_accums = &accums[*offset..*offset + accumulable_width(&aggr.func)];
                         // We first test here if inputs without net-positive records are present,
                         // producing an error to the logs and to the query output if that is the case.
-                        if total == 0 && !accum.is_zero() {
+                        if total == 0 && aggr_accums.iter().any(|accum| !accum.is_zero()) {
                             error_logger.log(
                                 "Net-zero records with non-zero accumulation in ReduceAccumulable",
-                                &format!("aggr={aggr:?}, accum={accum:?}"),
+                                &format!("aggr={aggr:?}, accums={aggr_accums:?}"),
                             );
                             let key = key.into_owned();
                             let message = format!(
@@ -1464,7 +2013,7 @@ where
                             );
                             output.push((EvalError::Internal(message).into(), 1));
                         }
-                        match (&aggr.func, &accum) {
+                        match (&aggr.func, &aggr_accums[0]) {
                             (AggregateFunc::SumUInt16, Accum::SimpleNumber { accum, .. })
                             | (AggregateFunc::SumUInt32, Accum::SimpleNumber { accum, .. })
                             | (AggregateFunc::SumUInt64, Accum::SimpleNumber { accum, .. }) => {
@@ -1491,8 +2040,8 @@ where
                     let datum_iter = key.into_datum_iter(None);
                     let mut datums_local = datums2.borrow();
                     datums_local.extend(datum_iter);
-                    for (aggr, accum) in full_aggrs2.iter().zip(accums) {
-                        datums_local.push(finalize_accum(&aggr.func, accum, total));
+                    for (aggr, offset) in full_aggrs2.iter().zip(&offsets2) {
+                        datums_local.push(finalize_accums(&aggr.func, &accums[*offset..], total));
                     }
 
                     if let Result::Err(e) = mfp.evaluate_inner(&mut datums_local, &temp_storage) {
@@ -1507,6 +2056,404 @@ where
     }
 }
 
+/// The depth of the trees in which the values of ordered-set aggregates are
+/// arranged. Each level below the root consumes one byte of the sort prefix of
+/// a value.
+const ORDERED_SET_DEPTH: usize = 8;
+
+/// Tags the direct arguments and the root summaries that are combined into
+/// the header of each key of an ordered-set aggregate.
+const ORDERED_SET_DIRECT_ARG: u64 = 0;
+const ORDERED_SET_ROOT: u64 = 1;
+
+/// Tags the header of each key of an ordered-set aggregate and the values
+/// selected for it.
+const ORDERED_SET_HEADER: u64 = 0;
+const ORDERED_SET_SELECTED: u64 = 1;
+
+/// The ID of the request for the most frequent value of a key, which `mode`
+/// makes instead of requesting values by position.
+const ORDERED_SET_MODE: u64 = u64::MAX;
+
+/// Tags whether the inputs of a key of an `hll_merge` aggregation have a
+/// sketch, and the largest ranks of the registers of its merged sketch.
+const HLL_PRESENCE: u64 = 0;
+const HLL_REGISTER: u64 = 1;
+
+/// Evaluates an `hll_merge` aggregation from the presence and register entries
+/// of a key, or returns `None` if the key has no inputs.
+fn eval_hll_registers<'a, 'b, I, J>(entries: I, temp_storage: &'a RowArena) -> Option<Datum<'a>>
+where
+    I: IntoIterator<Item = (J, Diff)>,
+    J: IntoIterator<Item = Datum<'b>>,
+{
+    let mut present = None;
+    let mut ranks = Vec::new();
+    for (entry, count) in entries {
+        // Non-positive counts are reported by the error check on the registers.
+        if !count.is_positive() {
+            continue;
+        }
+        let mut entry = entry.into_iter();
+        match entry.next().unwrap().unwrap_uint64() {
+            HLL_PRESENCE => {
+                let has_sketch = entry.next().unwrap().unwrap_bool();
+                present = Some(present.unwrap_or(false) || has_sketch);
+            }
+            _ => {
+                let index = entry.next().unwrap().unwrap_uint64();
+                let rank = entry.next().unwrap().unwrap_uint64();
+                let index = usize::cast_from(index);
+                ranks.push((index, u8::try_from(rank).expect("valid rank")));
+            }
+        }
+    }
+    match present? {
+        true => {
+            let sketch = Hll::from_ranks(ranks).encode();
+            Some(Datum::Bytes(temp_storage.push_bytes(sketch)))
+        }
+        false => Some(Datum::Null),
+    }
+}
+
+/// Compares two values of an ordered-set aggregate.
+fn compare_ordered_set_values(order_by: &[ColumnOrder], left: Datum, right: Datum) -> Ordering {
+    compare_columns(order_by, &[left], &[right], || left.cmp(&right))
+}
+
+/// Returns a prefix of the sort key of the non-null `value` under `order`:
+/// whenever a value sorts before another, its prefix is not larger. The prefix
+/// is made of the leading bytes of an order-preserving encoding of the value.
+fn ordered_set_sort_prefix(order: Option<&ColumnOrder>, value: Datum) -> u64 {
+    let Some(order) = order else {
+        return 0;
+    };
+    let mut key = Vec::new();
+    match (order.collation, value) {
+        (Some(collation), Datum::String(s)) => {
+            key.extend(collation.sort_prefix(s).to_be_bytes());
+        }
+        _ => {
+            push_sort_key(value, &mut key);
+        }
+    }
+    // Padding with zeros preserves the order of the encodings, as none of them
+    // is a proper prefix of another.
+    let mut prefix = [0; 8];
+    let len = key.len().min(prefix.len());
+    prefix[..len].copy_from_slice(&key[..len]);
+    let prefix = u64::from_be_bytes(prefix);
+    if order.desc {
+        !prefix
+    } else {
+        prefix
+    }
+}
+
+/// Appends an order-preserving encoding of `datum` to `key`, stopping early
+/// once `key` holds the bytes that [`ordered_set_sort_prefix`] uses: whenever
+/// a datum
+/// sorts before another, its encoding is not larger, and no encoding is a
+/// proper prefix of another. Returns whether the encoding is exact, that is,
+/// whether it differs between datums that do not compare equal, which is
+/// required of the encodings of all but the last datum in a sequence.
+fn push_sort_key(datum: Datum, key: &mut Vec<u8>) -> bool {
+    /// Appends `bytes` so that no encoding is a proper prefix of another, by
+    /// escaping zero bytes and terminating with two zero bytes.
+    fn push_escaped(bytes: &[u8], key: &mut Vec<u8>) {
+        for byte in bytes {
+            if key.len() >= 8 {
+                return;
+            }
+            key.push(*byte);
+            if *byte == 0 {
+                key.push(0xff);
+            }
+        }
+        key.extend([0, 0]);
+    }
+    fn signed(i: i64) -> [u8; 8] {
+        (u64::reinterpret_cast(i) ^ (1 << 63)).to_be_bytes()
+    }
+    fn float(f: f64) -> [u8; 8] {
+        // NaNs sort last, and negative and positive zero are equal.
+        if f.is_nan() {
+            return [0xff; 8];
+        }
+        let bits = if f == 0.0 { 0 } else { f.to_bits() };
+        if bits >> 63 == 1 {
+            (!bits).to_be_bytes()
+        } else {
+            (bits | (1 << 63)).to_be_bytes()
+        }
+    }
+    fn numeric(n: &Numeric, key: &mut Vec<u8>) {
+        // NaN sorts last, and equal values with different exponents encode
+        // alike, as the trailing zeros of their coefficients are dropped.
+        if n.is_nan() {
+            key.push(3);
+        } else if n.is_zero() {
+            key.push(1);
+        } else {
+            let start = key.len();
+            key.push(2);
+            let mut digits = n.coefficient_digits();
+            let adjusted_exponent = n.exponent() + i32::try_from(digits.len()).expect("few digits");
+            key.extend((u32::reinterpret_cast(adjusted_exponent) ^ (1 << 31)).to_be_bytes());
+            while digits.last() == Some(&0) {
+                digits.pop();
+            }
+            key.extend(digits.iter().map(|digit| digit + 1));
+            key.push(0);
+            // Negative values sort before zero, in the reverse order of their
+            // magnitudes.
+            if n.is_negative() {
+                key[start] = 0;
+                for byte in &mut key[start + 1..] {
+                    *byte = !*byte;
+                }
+            }
+        }
+    }
+
+    // Datums of different variants compare by the order in which the variants
+    // are declared, which the first byte of their encoding follows.
+    match datum {
+        Datum::False => key.push(0),
+        Datum::True => key.push(1),
+        Datum::Int16(i) => {
+            key.push(2);
+            key.extend(signed(i.into()));
+        }
+        Datum::Int32(i) => {
+            key.push(3);
+            key.extend(signed(i.into()));
+        }
+        Datum::Int64(i) => {
+            key.push(4);
+            key.extend(signed(i));
+        }
+        Datum::UInt8(u) => key.extend([5, u]),
+        Datum::UInt16(u) => {
+            key.push(6);
+            key.extend(u.to_be_bytes());
+        }
+        Datum::UInt32(u) => {
+            key.push(7);
+            key.extend(u.to_be_bytes());
+        }
+        Datum::UInt64(u) => {
+            key.push(8);
+            key.extend(u.to_be_bytes());
+        }
+        Datum::Float32(f) => {
+            key.push(9);
+            key.extend(float(f.into_inner().into()));
+        }
+        Datum::Float64(f) => {
+            key.push(10);
+            key.extend(float(f.into_inner()));
+        }
+        Datum::Date(d) => {
+            key.push(11);
+            key.extend(signed(d.unix_epoch_days().into()));
+        }
+        Datum::Time(t) => {
+            key.push(12);
+            key.extend(t.num_seconds_from_midnight().to_be_bytes());
+            key.extend(t.nanosecond().to_be_bytes());
+        }
+        Datum::Timestamp(ts) => {
+            key.push(13);
+            key.extend(signed(ts.to_naive().timestamp()));
+            key.extend(ts.to_naive().timestamp_subsec_nanos().to_be_bytes());
+        }
+        Datum::TimestampTz(ts) => {
+            key.push(14);
+            key.extend(signed(ts.to_naive().timestamp()));
+            key.extend(ts.to_naive().timestamp_subsec_nanos().to_be_bytes());
+        }
+        Datum::Interval(i) => {
+            key.push(15);
+            key.extend(signed(i.months.into()));
+            key.extend(signed(i.days.into()));
+            key.extend(signed(i.micros));
+        }
+        Datum::Bytes(b) => {
+            key.push(16);
+            push_escaped(b, key);
+        }
+        Datum::String(s) => {
+            key.push(17);
+            push_escaped(s.as_bytes(), key);
+        }
+        Datum::List(list) => {
+            // Lists compare element by element, and a list sorts before the
+            // lists it is a proper prefix of.
+            key.push(19);
+            for element in list.iter() {
+                if key.len() >= 8 {
+                    return true;
+                }
+                key.push(1);
+                if !push_sort_key(element, key) {
+                    return false;
+                }
+            }
+            key.push(0);
+        }
+        Datum::Numeric(n) => {
+            key.push(21);
+            numeric(&n.0, key);
+        }
+        Datum::JsonNull => key.push(22),
+        Datum::Uuid(u) => {
+            key.push(23);
+            key.extend(u.as_bytes());
+        }
+        Datum::MzTimestamp(ts) => {
+            key.push(24);
+            key.extend(u64::from(ts).to_be_bytes());
+        }
+        Datum::Null => key.push(u8::MAX),
+        // The encodings of the remaining variants do not distinguish their
+        // values, which then only sort within the leaves of the tree.
+        Datum::Array(_) => {
+            key.push(18);
+            return false;
+        }
+        Datum::Map(_) => {
+            key.push(20);
+            return false;
+        }
+        Datum::Range(_) => {
+            key.push(25);
+            return false;
+        }
+        Datum::MzAclItem(_) => {
+            key.push(26);
+            return false;
+        }
+        Datum::AclItem(_) => {
+            key.push(27);
+            return false;
+        }
+        Datum::Inet(_) => {
+            key.push(28);
+            return false;
+        }
+        Datum::Dummy => {
+            key.push(29);
+            return false;
+        }
+    }
+    true
+}
+
+/// Splits the key of a node of the tree of an ordered-set aggregate into the
+/// key of the aggregation and the node's sort prefix.
+fn split_ordered_set_node<'a, I>(node: I) -> (Row, u64)
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut datums: Vec<_> = node.into_iter().collect();
+    let prefix = datums.pop().unwrap().unwrap_uint64();
+    (Row::pack_slice(&datums), prefix)
+}
+
+/// Packs the summary of a node of the tree of an ordered-set aggregate, given
+/// its `(child, count, max)` entries in sort order. The children of a leaf are
+/// its distinct values, and the children of other nodes are the last bytes of
+/// the sort prefixes of their children. `count` is the number of values below
+/// the child, and `max` the largest count of a distinct value below it.
+fn pack_ordered_set_node(entries: &[(Datum, Diff, Diff)]) -> Row {
+    let count: Diff = entries.iter().map(|(_child, count, _max)| count).sum();
+    let max = entries.iter().map(|(_child, _count, max)| *max).max();
+    let binding = SharedRow::get();
+    let mut row_builder = binding.borrow_mut();
+    let mut packer = row_builder.packer();
+    packer.push(Datum::Int64(count));
+    packer.push(Datum::Int64(max.unwrap_or(0)));
+    packer.push_list_with(|packer| {
+        for (child, count, max) in entries {
+            packer.push_list([*child, Datum::Int64(*count), Datum::Int64(*max)]);
+        }
+    });
+    row_builder.clone()
+}
+
+/// Finds the child of a node of the tree of an ordered-set aggregate that
+/// contains the value requested by the request with ID `id`, given the
+/// entries of the node's summary. The request is for the value at position
+/// `target` among the values of the node or, for [`ORDERED_SET_MODE`], for
+/// the first value whose count is `target`. Returns the child and the target
+/// of the request within it.
+fn descend_ordered_set_node(id: u64, mut target: i64, entries: DatumList) -> Option<(Datum, i64)> {
+    for entry in entries.iter() {
+        let mut fields = entry.unwrap_list().iter();
+        let child = fields.next().unwrap();
+        let count = fields.next().unwrap().unwrap_int64();
+        let max = fields.next().unwrap().unwrap_int64();
+        if id == ORDERED_SET_MODE {
+            if max == target {
+                return Some((child, target));
+            }
+        } else if target < count {
+            return Some((child, target));
+        } else {
+            target -= count;
+        }
+    }
+    None
+}
+
+/// Evaluates an ordered-set aggregate for a key, given the key's header and
+/// the values selected for its requests. Returns `None` if a requested value
+/// is missing, which only happens if the input has non-positive counts, which
+/// the error check reports.
+fn eval_ordered_set_selected<'a, I, J>(
+    func: &AggregateFunc,
+    entries: I,
+    temp_storage: &'a RowArena,
+) -> Option<Datum<'a>>
+where
+    I: IntoIterator<Item = J>,
+    J: IntoIterator<Item = Datum<'a>>,
+{
+    let mut direct_arg = Datum::Null;
+    let mut total = 0;
+    let mut selected = BTreeMap::new();
+    for entry in entries {
+        let mut entry = entry.into_iter();
+        match entry.next().unwrap().unwrap_uint64() {
+            ORDERED_SET_HEADER => {
+                direct_arg = entry.next().unwrap();
+                total = usize::try_from(entry.next().unwrap().unwrap_int64()).unwrap_or(0);
+            }
+            _ => {
+                let id = entry.next().unwrap().unwrap_uint64();
+                selected.insert(id, entry.next().unwrap());
+            }
+        }
+    }
+    let positions = func.ordered_set_positions(direct_arg, total);
+    if !positions
+        .iter()
+        .all(|position| selected.contains_key(&u64::cast_from(*position)))
+    {
+        return None;
+    }
+    let nth = |position: usize| selected[&u64::cast_from(position)];
+    let mode = || {
+        selected
+            .get(&ORDERED_SET_MODE)
+            .copied()
+            .unwrap_or(Datum::Null)
+    };
+    Some(func.eval_ordered_set_with(direct_arg, total, nth, mode, temp_storage))
+}
+
 /// Evaluates the fused MFP, if one exists, on a reconstructed `DatumVecBorrow`
 /// containing key and aggregate values, then returns a result `Row` or `None`
 /// if the MFP filters the result out.
@@ -1563,8 +2510,46 @@ fn accumulable_zero(aggr_func: &AggregateFunc) -> Accum {
     }
 }
 
+/// Returns the number of consecutive accumulators that `aggr_func` uses.
+fn accumulable_width(aggr_func: &AggregateFunc) -> usize {
+    StatisticalMoments::width(aggr_func).unwrap_or(1)
+}
+
+/// Returns the zero values of the accumulators that `aggr_func` uses.
+fn accumulable_zeros(aggr_func: &AggregateFunc) -> Vec<Accum> {
+    match StatisticalMoments::width(aggr_func) {
+        Some(width) => vec![
+            Accum::Moment {
+                accum: OrderedDecimal(NumericAgg::zero()),
+                non_finite: 0,
+                non_nulls: 0,
+            };
+            width
+        ],
+        None => vec![accumulable_zero(aggr_func)],
+    }
+}
+
 static FLOAT_SCALE: Lazy<f64> = Lazy::new(|| f64::from(1 << 24));
 
+/// Writes the accumulators of `datum` to the first [`accumulable_width`]
+/// elements of `accums`.
+fn datum_to_accumulators(aggregate_func: &AggregateFunc, datum: Datum, accums: &mut [Accum]) {
+    match StatisticalMoments::width(aggregate_func) {
+        Some(width) => {
+            let moments = StatisticalMoments::from_datum(aggregate_func, datum);
+            for (accum, sum) in accums[..width].iter_mut().zip(moments.sums) {
+                *accum = Accum::Moment {
+                    accum: OrderedDecimal(sum),
+                    non_finite: moments.non_finite,
+                    non_nulls: moments.count,
+                };
+            }
+        }
+        None => accums[0] = datum_to_accumulator(aggregate_func, datum),
+    }
+}
+
 fn datum_to_accumulator(aggregate_func: &AggregateFunc, datum: Datum) -> Accum {
     match aggregate_func {
         AggregateFunc::Count => Accum::SimpleNumber {
@@ -1869,6 +2854,39 @@ fn finalize_accum<'a>(aggr_func: &'a AggregateFunc, accum: &'a Accum, total: Dif
     }
 }
 
+/// Finalizes the aggregation whose accumulators start at the beginning of
+/// `accums`.
+fn finalize_accums<'a>(
+    aggr_func: &'a AggregateFunc,
+    accums: &'a [Accum],
+    total: Diff,
+) -> Datum<'a> {
+    match StatisticalMoments::width(aggr_func) {
+        Some(width) => {
+            let mut moments = StatisticalMoments::zero();
+            for (sum, accum) in moments.sums.iter_mut().zip(&accums[..width]) {
+                match accum {
+                    Accum::Moment {
+                        accum,
+                        non_finite,
+                        non_nulls,
+                    } => {
+                        *sum = accum.0;
+                        moments.non_finite = *non_finite;
+                        moments.count = *non_nulls;
+                    }
+                    _ => panic!(
+                        "Unexpected accumulation (aggr={:?}, accum={accum:?})",
+                        aggr_func
+                    ),
+                }
+            }
+            moments.finalize(aggr_func)
+        }
+        None => finalize_accum(aggr_func, &accums[0], total),
+    }
+}
+
 /// Accumulates values for the various types of accumulable aggregations.
 ///
 /// We assume that there are not more than 2^32 elements for the aggregation.
@@ -1922,6 +2940,15 @@ enum Accum {
         /// Counts non-NULL values
         non_nulls: Diff,
     },
+    /// Accumulates one of the sums of a statistical aggregation.
+    Moment {
+        /// Accumulates the sum over non-special values
+        accum: OrderedDecimal<NumericAgg>,
+        /// Counts infinite and NaN values
+        non_finite: Diff,
+        /// Counts non-NULL values
+        non_nulls: Diff,
+    },
 }
 
 impl Semigroup for Accum {
@@ -1955,6 +2982,11 @@ impl Semigroup for Accum {
                     && nans.is_zero()
                     && non_nulls.is_zero()
             }
+            Accum::Moment {
+                accum,
+                non_finite,
+                non_nulls,
+            } => accum.0.is_zero() && non_finite.is_zero() && non_nulls.is_zero(),
         }
     }
 
@@ -2053,6 +3085,34 @@ impl Semigroup for Accum {
                 *nans += other_nans;
                 *non_nulls += other_non_nulls;
             }
+            (
+                Accum::Moment {
+                    accum,
+                    non_finite,
+                    non_nulls,
+                },
+                Accum::Moment {
+                    accum: other_accum,
+                    non_finite: other_non_finite,
+                    non_nulls: other_non_nulls,
+                },
+            ) => {
+                let mut cx_agg = numeric::cx_agg();
+                cx_agg.add(&mut accum.0, &other_accum.0);
+                // Sums of squares and products can legitimately exceed the
+                // aggregator's precision when inputs span many orders of
+                // magnitude. Unlike `Accum::Numeric`, which sums user values
+                // directly, we only lose accuracy here, so warn instead of
+                // panicking.
+                if cx_agg.status().rounded() {
+                    warn!("Moment accumulator rounded. Incorrect results possible");
+                }
+                // Reduce to reclaim unused decimal precision, see
+                // `Accum::Numeric`.
+                cx_agg.reduce(&mut accum.0);
+                *non_finite += other_non_finite;
+                *non_nulls += other_non_nulls;
+            }
             (l, r) => unreachable!(
                 "Accumulator::plus_equals called with non-matching variants: {l:?} vs {r:?}"
             ),
@@ -2117,6 +3177,23 @@ impl Multiply<Diff> for Accum {
                     non_nulls: non_nulls * factor,
                 }
             }
+            Accum::Moment {
+                accum,
+                non_finite,
+                non_nulls,
+            } => {
+                let mut cx = numeric::cx_agg();
+                let mut f = NumericAgg::from(factor);
+                cx.mul(&mut f, &accum.0);
+                if cx.status().rounded() {
+                    warn!("Moment accumulator rounded. Incorrect results possible");
+                }
+                Accum::Moment {
+                    accum: OrderedDecimal(f),
+                    non_finite: non_finite * factor,
+                    non_nulls: non_nulls * factor,
+                }
+            }
         }
     }
 }
@@ -2146,6 +3223,7 @@ mod monoids {
     use differential_dataflow::difference::{Multiply, Semigroup};
     use mz_expr::AggregateFunc;
     use mz_ore::soft_panic_or_log;
+    use mz_repr::adt::hll::Hll;
     use mz_repr::{Datum, Diff, Row};
     use serde::{Deserialize, Serialize};
     use timely::container::columnation::{Columnation, Region};
@@ -2155,13 +3233,14 @@ mod monoids {
     pub enum ReductionMonoid {
         Min(Row),
         Max(Row),
+        HllMerge(Row),
     }
 
     impl ReductionMonoid {
         pub fn finalize(&self) -> &Row {
             use ReductionMonoid::*;
             match self {
-                Min(row) | Max(row) => row,
+                Min(row) | Max(row) | HllMerge(row) => row,
             }
         }
     }
@@ -2212,6 +3291,23 @@ mod monoids {
                         lhs.clone_from(rhs);
                     }
                 }
+                (ReductionMonoid::HllMerge(lhs), ReductionMonoid::HllMerge(rhs)) => {
+                    let rhs_val = rhs.unpack_first();
+                    // Datum::Null is the identity, the sketch of no values.
+                    if !rhs_val.is_null() {
+                        let lhs_val = lhs.unpack_first();
+                        if lhs_val.is_null() {
+                            lhs.clone_from(rhs);
+                        } else {
+                            let mut merged =
+                                Hll::decode(lhs_val.unwrap_bytes()).expect("valid hll sketch");
+                            merged.merge(
+                                &Hll::decode(rhs_val.unwrap_bytes()).expect("valid hll sketch"),
+                            );
+                            lhs.packer().push(Datum::Bytes(&merged.encode()));
+                        }
+                    }
+                }
                 (lhs, rhs) => {
                     soft_panic_or_log!(
                         "Mismatched monoid variants in reduction! lhs: {lhs:?} rhs: {rhs:?}"
@@ -2233,7 +3329,7 @@ mod monoids {
         type InnerRegion = ReductionMonoidRegion;
     }
 
-    /// Region for [`ReductionMonoid`]. This region is special in that it stores both enum variants
+    /// Region for [`ReductionMonoid`]. This region is special in that it stores all enum variants
     /// in the same backing region. Alternatively, it could store it in two regions, but we select
     /// the former for simplicity reasons.
     #[derive(Default)]
@@ -2249,6 +3345,7 @@ mod monoids {
             match item {
                 Min(row) => Min(self.inner.copy(row)),
                 Max(row) => Max(self.inner.copy(row)),
+                HllMerge(row) => HllMerge(self.inner.copy(row)),
             }
         }
 
@@ -2314,6 +3411,7 @@ mod monoids {
             | AggregateFunc::MinTimestamp
             | AggregateFunc::MinTimestampTz
             | AggregateFunc::MinInterval => Some(ReductionMonoid::Min(row)),
+            AggregateFunc::HllMerge => Some(ReductionMonoid::HllMerge(row)),
             AggregateFunc::SumInt16
             | AggregateFunc::SumInt32
             | AggregateFunc::SumInt64
@@ -2326,18 +3424,32 @@ mod monoids {
             | AggregateFunc::Count
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::VarianceNumeric { .. }
+            | AggregateFunc::VarianceFloat64 { .. }
+            | AggregateFunc::Regression { .. }
             | AggregateFunc::Dummy
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::ArrayConcat { .. }
             | AggregateFunc::ListConcat { .. }
             | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::MaxStringCollated { .. }
+            | AggregateFunc::MinStringCollated { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileContMulti { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::PercentileDiscMulti { .. }
+            | AggregateFunc::Mode { .. }
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. } => None,
         }
     }
@@ -2391,7 +3503,7 @@ mod window_agg_helpers {
 
     pub struct AccumulableOneByOneAggr {
         aggr_func: AggregateFunc,
-        accum: Accum,
+        accums: Vec<Accum>,
         total: Diff,
     }
 
@@ -2399,20 +3511,23 @@ mod window_agg_helpers {
         fn new(aggr_func: &AggregateFunc) -> Self {
             AccumulableOneByOneAggr {
                 aggr_func: aggr_func.clone(),
-                accum: accumulable_zero(aggr_func),
+                accums: accumulable_zeros(aggr_func),
                 total: 0,
             }
         }
 
         fn give(&mut self, d: &Datum) {
-            self.accum
-                .plus_equals(&datum_to_accumulator(&self.aggr_func, d.clone()));
+            let mut accums = accumulable_zeros(&self.aggr_func);
+            datum_to_accumulators(&self.aggr_func, d.clone(), &mut accums);
+            for (accum, other) in self.accums.iter_mut().zip(&accums) {
+                accum.plus_equals(other);
+            }
             self.total += 1;
         }
 
         fn get_current_aggregate<'a>(&self, temp_storage: &'a RowArena) -> Datum<'a> {
             temp_storage.make_datum(|packer| {
-                packer.push(finalize_accum(&self.aggr_func, &self.accum, self.total));
+                packer.push(finalize_accums(&self.aggr_func, &self.accums, self.total));
             })
         }
     }
@@ -29,6 +29,7 @@ use mz_ore::cast::CastFrom;
 use mz_ore::halt;
 use mz_ore::tracing::TracingHandle;
 use mz_persist_client::cache::PersistClientCache;
+use mz_storage_types::connections::ConnectionContext;
 use timely::communication::Allocate;
 use timely::dataflow::channels::pact::Exchange;
 use timely::dataflow::operators::generic::source;
@@ -51,6 +52,9 @@ pub struct ComputeInstanceContext {
     pub scratch_directory: Option<PathBuf>,
     /// Whether to set core affinity for Timely workers.
     pub worker_core_affinity: bool,
+    /// Context required to connect to an external sink from compute,
+    /// like the `CopyToS3OneshotSink` compute sink.
+    pub connection_context: ConnectionContext,
 }
 
 /// Configures the server with compute-specific metrics.
@@ -472,6 +476,7 @@ impl<'w, A: Allocate + 'static> Worker<'w, A> {
             if let Some(mut compute_state) = self.activate_compute(&mut response_tx) {
                 compute_state.process_peeks();
                 compute_state.process_subscribes();
+                compute_state.process_copy_tos();
             }
 
             self.metrics
@@ -622,12 +627,12 @@ impl<'w, A: Allocate + 'static> Worker<'w, A> {
                                         dataflow.as_of.as_ref().unwrap(),
                                     )
                                 });
-                            // We cannot reconcile subscriptions at the moment, because the response buffer is shared,
-                            // and to a first approximation must be completely reformed.
-                            let subscribe_free = dataflow
-                                .sink_exports
-                                .iter()
-                                .all(|(_id, sink)| !sink.connection.is_subscribe());
+                            // We cannot reconcile subscriptions or copy tos at the moment, because
+                            // their response buffers are shared, and to a first approximation must
+                            // be completely reformed.
+                            let subscribe_free = dataflow.sink_exports.iter().all(|(_id, sink)| {
+                                !sink.connection.is_subscribe() && !sink.connection.is_copy_to()
+                            });
                             if compatible && uncompacted && subscribe_free {
                                 // Match found; remove the match from the deletion queue,
                                 // and compact its outputs to the dataflow's `as_of`.
@@ -755,6 +760,8 @@ impl<'w, A: Allocate + 'static> Worker<'w, A> {
             // We must drop the subscribe response buffer as it is global across all subscribes.
             // If it were broken out by `GlobalId` then we could drop only those of dataflows we drop.
             compute_state.subscribe_response_buffer = Rc::new(RefCell::new(Vec::new()));
+            // The same is true for the copy to response buffer.
+            compute_state.copy_to_response_buffer = Rc::new(RefCell::new(Vec::new()));
         } else {
             todo_commands = new_commands.clone();
         }
@@ -0,0 +1,116 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use differential_dataflow::Collection;
+use mz_compute_client::protocol::response::CopyToResponse;
+use mz_compute_types::sinks::{ComputeSinkDesc, CopyToS3OneshotSinkConnection};
+use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_timely_util::operator::CollectionExt;
+use timely::dataflow::Scope;
+use timely::progress::Antichain;
+
+use crate::render::sinks::SinkRender;
+use crate::typedefs::KeyBatcher;
+
+impl<G> SinkRender<G> for CopyToS3OneshotSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn render_continuous_sink(
+        &self,
+        compute_state: &mut crate::compute_state::ComputeState,
+        sink: &ComputeSinkDesc<CollectionMetadata>,
+        sink_id: GlobalId,
+        _as_of: Antichain<Timestamp>,
+        sinked_collection: Collection<G, Row, Diff>,
+        err_collection: Collection<G, DataflowError, Diff>,
+    ) -> Option<Rc<dyn Any>>
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        // An encapsulation of the copy to response protocol.
+        // Used to send the result of the upload, and to alert if the
+        // dataflow was dropped before completing.
+        let response_protocol_handle = Rc::new(RefCell::new(Some(ResponseProtocol {
+            sink_id,
+            response_buffer: Some(Rc::clone(&compute_state.copy_to_response_buffer)),
+        })));
+        let response_protocol_weak = Rc::downgrade(&response_protocol_handle);
+
+        // Consolidating the input here lets the sink upload rows as soon as they arrive, rather
+        // than holding on to all of them until it has seen every diff.
+        let sinked_collection = sinked_collection
+            .consolidate_named::<KeyBatcher<_, _, _>>("Consolidated CopyToS3OneshotSink input");
+
+        let token = mz_storage_operators::s3_oneshot_sink::copy_to(
+            sinked_collection,
+            err_collection,
+            sink.up_to.clone(),
+            self.upload_info.clone(),
+            compute_state.context.connection_context.clone(),
+            self.aws_connection.clone(),
+            sink_id,
+            self.connection_id,
+            move |result| {
+                if let Some(protocol) = response_protocol_handle.borrow_mut().as_mut() {
+                    protocol.send(result);
+                }
+            },
+        );
+
+        // Inform the coordinator that we have been dropped, and destroy the response protocol so
+        // the sink operator can't send spurious messages while shutting down.
+        Some(Rc::new(scopeguard::guard(token, move |_| {
+            if let Some(protocol_handle) = response_protocol_weak.upgrade() {
+                std::mem::drop(protocol_handle.borrow_mut().take())
+            }
+        })))
+    }
+}
+
+/// A type that guides the transmission of the copy to result back to the controller.
+///
+/// A protocol instance may `send` a single response. If no response was sent before the
+/// protocol is dropped, the `Drop` implementation sends an indication that the copy to was
+/// dropped without completing.
+struct ResponseProtocol {
+    pub sink_id: GlobalId,
+    pub response_buffer: Option<Rc<RefCell<Vec<(GlobalId, CopyToResponse)>>>>,
+}
+
+impl ResponseProtocol {
+    /// Send the result of the copy to, consuming the protocol's ability to respond.
+    fn send(&mut self, result: Result<u64, String>) {
+        let buffer = self
+            .response_buffer
+            .take()
+            .expect("expecting response buffer");
+        let response = match result {
+            Ok(count) => CopyToResponse::RowCount(count),
+            Err(error) => CopyToResponse::Error(error),
+        };
+        buffer.borrow_mut().push((self.sink_id, response));
+    }
+}
+
+impl Drop for ResponseProtocol {
+    fn drop(&mut self) {
+        if let Some(buffer) = self.response_buffer.take() {
+            buffer
+                .borrow_mut()
+                .push((self.sink_id, CopyToResponse::Dropped));
+        }
+    }
+}
@@ -110,6 +110,11 @@ pub enum ControllerResponse<T = mz_repr::Timestamp> {
     PeekResponse(Uuid, PeekResponse, OpenTelemetryContext),
     /// The worker's next response to a specified subscribe.
     SubscribeResponse(GlobalId, SubscribeResponse<T>),
+    /// The response from a dataflow containing a `CopyToS3Oneshot` sink.
+    ///
+    /// The `GlobalId` identifies the sink. The `Result` carries the number of rows uploaded, or
+    /// the error that occurred while copying.
+    CopyToResponse(GlobalId, Result<u64, anyhow::Error>),
     /// Notification that new resource usage metrics are available for a given replica.
     ComputeReplicaMetrics(ReplicaId, Vec<ServiceProcessMetrics>),
     WatchSetFinished(Vec<Box<dyn Any>>),
@@ -315,6 +320,9 @@ where
                     ComputeControllerResponse::SubscribeResponse(id, tail) => {
                         Some(ControllerResponse::SubscribeResponse(id, tail))
                     }
+                    ComputeControllerResponse::CopyToResponse(id, response) => {
+                        Some(ControllerResponse::CopyToResponse(id, response))
+                    }
                     ComputeControllerResponse::FrontierUpper { id, upper } => {
                         self.handle_frontier_updates(&[(id, upper)])
                     }
@@ -1056,10 +1056,25 @@ async fn execute_stmt<S: ResultSender>(
         return Ok(SqlResult::err(client, Error::Unstructured(message)).into());
     }
 
+    // Decoding enum values requires the labels of their types, which live in
+    // the catalog.
+    let catalog = if param_types.iter().any(|typ| typ.contains_enum()) {
+        Some(client.catalog_snapshot().await)
+    } else {
+        None
+    };
+    let prep_stmt = client
+        .session()
+        .get_prepared_statement_unverified(EMPTY_PORTAL)
+        .expect("must exist");
+    let param_types = &prep_stmt.desc().param_types;
     let buf = RowArena::new();
     let mut params = vec![];
     for (raw_param, mz_typ) in izip!(raw_params, param_types) {
-        let pg_typ = mz_pgrepr::Type::from(mz_typ);
+        let pg_typ = match &catalog {
+            Some(catalog) => catalog.resolve_pg_type(mz_typ),
+            None => mz_pgrepr::Type::from(mz_typ),
+        };
         let datum = match raw_param {
             None => Datum::Null,
             Some(raw_param) => {
@@ -1140,7 +1155,9 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedViews { .. }
         | ExecuteResponse::CreatedMaterializedView { .. }
         | ExecuteResponse::CreatedType
+        | ExecuteResponse::CreatedFunction
         | ExecuteResponse::Comment
+        | ExecuteResponse::Copied(_)
         | ExecuteResponse::Deleted(_)
         | ExecuteResponse::DiscardedTemp
         | ExecuteResponse::DiscardedAll
@@ -1150,6 +1167,7 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::GrantedRole
         | ExecuteResponse::Inserted(_)
+        | ExecuteResponse::Merged(_)
         | ExecuteResponse::Raised
         | ExecuteResponse::ReassignOwned
         | ExecuteResponse::RevokedPrivilege
@@ -506,6 +506,7 @@ fn test_pgtest_mz() {
         dir,
         &[
             "enable_raise_statement",
+            "enable_table_keys",
             "enable_unmanaged_cluster_replicas",
             "enable_unsafe_functions",
         ],
@@ -920,6 +920,7 @@ mod scalar {
             column,
             desc,
             nulls_last,
+            collation: None,
         })
     }
 
@@ -36,8 +36,9 @@ pub use linear::{
     memoize_expr, MapFilterProject, ProtoMapFilterProject, ProtoMfpPlan, ProtoSafeMfpPlan,
 };
 pub use relation::func::{
-    AggregateFunc, AnalyzedRegex, CaptureGroupDesc, LagLeadType, NaiveOneByOneAggr, OneByOneAggr,
-    TableFunc,
+    AggregateFunc, AnalyzedRegex, CaptureGroupDesc, JsonTableBehavior, JsonTableColumn,
+    JsonTablePlan, LagLeadType, NaiveOneByOneAggr, OneByOneAggr, RegressionFunc,
+    StatisticalMoments, TableFunc, VarianceFunc,
 };
 pub use relation::join_input_mapper::JoinInputMapper;
 pub use relation::{
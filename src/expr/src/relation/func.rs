@@ -9,48 +9,54 @@
 
 #![allow(missing_docs)]
 
-use std::cmp::{max, min};
+use std::cmp::{max, min, Ordering};
+use std::collections::{BTreeMap, BTreeSet};
 use std::iter::Sum;
 use std::ops::Deref;
 use std::{fmt, iter};
 
-use chrono::{DateTime, NaiveDateTime, Utc};
-use dec::OrderedDecimal;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use dec::{Context, OrderedDecimal};
 use itertools::Itertools;
 use mz_lowertest::MzReflect;
-use mz_ore::cast::CastFrom;
+use mz_ore::cast::{CastFrom, CastLossy};
 
-use mz_ore::soft_assert_or_log;
 use mz_ore::str::separated;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::collation::Collation;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::hll::Hll;
 use mz_repr::adt::interval::Interval;
-use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
+use mz_repr::adt::jsonpath::{any_jsonpath, JsonPath};
+use mz_repr::adt::numeric::{self, Numeric, NumericAgg, NumericMaxScale};
 use mz_repr::adt::regex::Regex as ReprRegex;
 use mz_repr::adt::timestamp::{CheckedTimestamp, TimestampLike};
 use mz_repr::{ColumnName, ColumnType, Datum, Diff, RelationType, Row, RowArena, ScalarType};
-use num::{CheckedAdd, Integer, Signed, ToPrimitive};
+use num::{CheckedAdd, CheckedNeg, Integer, Signed, ToPrimitive};
 use ordered_float::OrderedFloat;
-use proptest::prelude::{Arbitrary, Just};
+use proptest::prelude::{any, Arbitrary, Just};
 use proptest::strategy::{BoxedStrategy, Strategy, Union};
 use proptest_derive::Arbitrary;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::explain::{HumanizedExpr, HumanizerMode};
-use crate::relation::proto_aggregate_func::{self, ProtoColumnOrders};
+use crate::relation::proto_aggregate_func::{
+    self, ProtoColumnOrders, ProtoRegressionFunc, ProtoVarianceFunc,
+};
 use crate::relation::proto_table_func::ProtoTabletizedScalar;
 use crate::relation::{
     compare_columns, proto_table_func, ColumnOrder, ProtoAggregateFunc, ProtoTableFunc,
     WindowFrame, WindowFrameBound, WindowFrameUnits,
 };
-use crate::scalar::func::{add_timestamp_months, jsonb_stringify};
-use crate::EvalError;
+use crate::scalar::func::{add_timestamp_months, jsonb_path_query_inner, jsonb_stringify};
 use crate::WindowFrameBound::{
     CurrentRow, OffsetFollowing, OffsetPreceding, UnboundedFollowing, UnboundedPreceding,
+    ValueFollowing, ValuePreceding,
 };
 use crate::WindowFrameUnits::{Groups, Range, Rows};
+use crate::{EvalError, MirScalarExpr};
 
 include!(concat!(env!("OUT_DIR"), "/mz_expr.relation.func.rs"));
 
@@ -71,6 +77,20 @@ where
     }
 }
 
+fn max_string_collated<'a, I>(datums: I, collation: Collation) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    match datums
+        .into_iter()
+        .filter(|d| !d.is_null())
+        .max_by(|a, b| collation.compare(a.unwrap_str(), b.unwrap_str()))
+    {
+        Some(datum) => datum,
+        None => Datum::Null,
+    }
+}
+
 fn max_datum<'a, I, DatumType>(datums: I) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -117,6 +137,20 @@ where
     }
 }
 
+fn min_string_collated<'a, I>(datums: I, collation: Collation) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    match datums
+        .into_iter()
+        .filter(|d| !d.is_null())
+        .min_by(|a, b| collation.compare(a.unwrap_str(), b.unwrap_str()))
+    {
+        Some(datum) => datum,
+        None => Datum::Null,
+    }
+}
+
 fn sum_datum<'a, I, DatumType, ResultType>(datums: I) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -154,6 +188,252 @@ where
     }
 }
 
+/// The sums that the variance and regression aggregates are computed from.
+///
+/// Inputs are accumulated as exact decimal sums of `x`, `x²`, `y`, `y²` and
+/// `xy`. Because the sums are exact, the statistics derived from them do not
+/// suffer from the catastrophic cancellation of the textbook floating point
+/// formulas, and retracting an input subtracts exactly what adding it added,
+/// which lets the sums be maintained incrementally.
+#[derive(Clone, Debug)]
+pub struct StatisticalMoments {
+    /// The number of non-NULL inputs, including non-finite ones.
+    pub count: i64,
+    /// The number of inputs that were infinite or NaN. These do not
+    /// contribute to `sums`.
+    pub non_finite: i64,
+    /// The sums of `x`, `x²`, `y`, `y²` and `xy`, in that order. Only the
+    /// first [`StatisticalMoments::width`] sums are used.
+    pub sums: [NumericAgg; 5],
+}
+
+impl StatisticalMoments {
+    const SUM_X: usize = 0;
+    const SUM_XX: usize = 1;
+    const SUM_Y: usize = 2;
+    const SUM_YY: usize = 3;
+    const SUM_XY: usize = 4;
+
+    /// Returns the number of sums that `func` is computed from, or `None` if
+    /// `func` is not a statistical aggregate.
+    pub fn width(func: &AggregateFunc) -> Option<usize> {
+        match func {
+            AggregateFunc::VarianceNumeric { .. } | AggregateFunc::VarianceFloat64 { .. } => {
+                Some(2)
+            }
+            AggregateFunc::Regression { .. } => Some(5),
+            _ => None,
+        }
+    }
+
+    pub fn zero() -> Self {
+        StatisticalMoments {
+            count: 0,
+            non_finite: 0,
+            sums: [NumericAgg::zero(); 5],
+        }
+    }
+
+    /// Returns the moments of the single input `datum` to `func`.
+    pub fn from_datum(func: &AggregateFunc, datum: Datum) -> Self {
+        let mut moments = Self::zero();
+        let (x, y) = match (func, datum) {
+            (_, Datum::Null) => return moments,
+            (AggregateFunc::Regression { .. }, datum) => {
+                let mut fields = datum.unwrap_list().iter();
+                let (y, x) = (fields.next().unwrap(), fields.next().unwrap());
+                if x.is_null() || y.is_null() {
+                    return moments;
+                }
+                (x, Some(y))
+            }
+            (_, datum) => (datum, None),
+        };
+        moments.count = 1;
+        let x = moment_value(x);
+        let y = y.map(moment_value);
+        if !x.is_finite() || y.map_or(false, |y| !y.is_finite()) {
+            moments.non_finite = 1;
+            return moments;
+        }
+        let mut cx = numeric::cx_agg();
+        moments.sums[Self::SUM_X] = x;
+        moments.sums[Self::SUM_XX] = mul_agg(&mut cx, x, &x);
+        if let Some(y) = y {
+            moments.sums[Self::SUM_Y] = y;
+            moments.sums[Self::SUM_YY] = mul_agg(&mut cx, y, &y);
+            moments.sums[Self::SUM_XY] = mul_agg(&mut cx, x, &y);
+        }
+        moments
+    }
+
+    pub fn plus_equals(&mut self, other: &Self) {
+        let mut cx = numeric::cx_agg();
+        for (sum, other_sum) in self.sums.iter_mut().zip(other.sums.iter()) {
+            cx.add(sum, other_sum);
+        }
+        self.count += other.count;
+        self.non_finite += other.non_finite;
+    }
+
+    /// Computes the result of `func` from the accumulated sums.
+    pub fn finalize(&self, func: &AggregateFunc) -> Datum<'static> {
+        let result = match func {
+            AggregateFunc::VarianceNumeric { func } => {
+                return match self.variance(*func) {
+                    Some(variance) => {
+                        let mut cx = numeric::cx_datum();
+                        let mut variance = cx.to_width(variance);
+                        numeric::munge_numeric(&mut variance)
+                            .expect("value was rounded to numeric's max precision");
+                        Datum::from(variance)
+                    }
+                    None => Datum::Null,
+                };
+            }
+            AggregateFunc::VarianceFloat64 { func } => self.variance(*func),
+            AggregateFunc::Regression {
+                func: RegressionFunc::RegrCount,
+            } => return Datum::Int64(self.count),
+            AggregateFunc::Regression { func } => self.regression(*func),
+            _ => panic!("{func:?} is not a statistical aggregate"),
+        };
+        match result {
+            Some(result) => Datum::from(
+                result
+                    .to_string()
+                    .parse::<f64>()
+                    .expect("decimals format as valid floats"),
+            ),
+            None => Datum::Null,
+        }
+    }
+
+    /// Returns `count` times the sum of `ab` minus the product of the sums of
+    /// `a` and `b`, i.e., `count²` times the (co)variance of `a` and `b`.
+    fn co_moment(&self, cx: &mut Context<NumericAgg>, a: usize, b: usize, ab: usize) -> NumericAgg {
+        let mut co_moment = mul_agg(cx, NumericAgg::from(self.count), &self.sums[ab]);
+        let product = mul_agg(cx, self.sums[a], &self.sums[b]);
+        cx.sub(&mut co_moment, &product);
+        co_moment
+    }
+
+    fn variance(&self, func: VarianceFunc) -> Option<NumericAgg> {
+        let sample = matches!(func, VarianceFunc::VarSamp | VarianceFunc::StddevSamp);
+        if self.count == 0 || (sample && self.count == 1) {
+            return None;
+        }
+        if self.non_finite > 0 {
+            return Some(NumericAgg::nan());
+        }
+        let mut cx = numeric::cx_agg();
+        let mut variance = self.co_moment(&mut cx, Self::SUM_X, Self::SUM_X, Self::SUM_XX);
+        // The sums are exact unless they exceeded the aggregator's precision,
+        // in which case rounding might push a zero variance below zero.
+        if variance.is_negative() {
+            variance = NumericAgg::zero();
+        }
+        cx.div(&mut variance, &self.denominator(&mut cx, sample));
+        if matches!(func, VarianceFunc::StddevPop | VarianceFunc::StddevSamp) {
+            cx.sqrt(&mut variance);
+        }
+        Some(variance)
+    }
+
+    fn regression(&self, func: RegressionFunc) -> Option<NumericAgg> {
+        if self.count == 0 || (func == RegressionFunc::CovarSamp && self.count == 1) {
+            return None;
+        }
+        if self.non_finite > 0 {
+            return Some(NumericAgg::nan());
+        }
+        let mut cx = numeric::cx_agg();
+        let n = NumericAgg::from(self.count);
+        let (sum_x, sum_y) = (self.sums[Self::SUM_X], self.sums[Self::SUM_Y]);
+        let sxx = self.co_moment(&mut cx, Self::SUM_X, Self::SUM_X, Self::SUM_XX);
+        let syy = self.co_moment(&mut cx, Self::SUM_Y, Self::SUM_Y, Self::SUM_YY);
+        let sxy = self.co_moment(&mut cx, Self::SUM_X, Self::SUM_Y, Self::SUM_XY);
+        let (numerator, denominator) = match func {
+            RegressionFunc::Corr => {
+                if sxx.is_zero() || syy.is_zero() {
+                    return None;
+                }
+                let mut denominator = mul_agg(&mut cx, sxx, &syy);
+                cx.sqrt(&mut denominator);
+                (sxy, denominator)
+            }
+            RegressionFunc::CovarPop => (sxy, self.denominator(&mut cx, false)),
+            RegressionFunc::CovarSamp => (sxy, self.denominator(&mut cx, true)),
+            RegressionFunc::RegrAvgX => (sum_x, n),
+            RegressionFunc::RegrAvgY => (sum_y, n),
+            RegressionFunc::RegrCount => unreachable!("regr_count does not need the sums"),
+            RegressionFunc::RegrIntercept => {
+                if sxx.is_zero() {
+                    return None;
+                }
+                let mut numerator = mul_agg(&mut cx, sum_y, &sxx);
+                let product = mul_agg(&mut cx, sum_x, &sxy);
+                cx.sub(&mut numerator, &product);
+                (numerator, mul_agg(&mut cx, n, &sxx))
+            }
+            RegressionFunc::RegrR2 => {
+                if sxx.is_zero() {
+                    return None;
+                }
+                if syy.is_zero() {
+                    return Some(NumericAgg::from(1));
+                }
+                (mul_agg(&mut cx, sxy, &sxy), mul_agg(&mut cx, sxx, &syy))
+            }
+            RegressionFunc::RegrSlope => {
+                if sxx.is_zero() {
+                    return None;
+                }
+                (sxy, sxx)
+            }
+            RegressionFunc::RegrSxx => (sxx, n),
+            RegressionFunc::RegrSxy => (sxy, n),
+            RegressionFunc::RegrSyy => (syy, n),
+        };
+        let mut result = numerator;
+        cx.div(&mut result, &denominator);
+        Some(result)
+    }
+
+    /// Returns `count²` for population statistics, and `count * (count - 1)`
+    /// for sample statistics.
+    fn denominator(&self, cx: &mut Context<NumericAgg>, sample: bool) -> NumericAgg {
+        let n = NumericAgg::from(self.count);
+        let other = if sample {
+            NumericAgg::from(self.count - 1)
+        } else {
+            n
+        };
+        mul_agg(cx, n, &other)
+    }
+}
+
+fn mul_agg(cx: &mut Context<NumericAgg>, mut a: NumericAgg, b: &NumericAgg) -> NumericAgg {
+    cx.mul(&mut a, b);
+    a
+}
+
+/// Converts an input to a statistical aggregate into a wide decimal.
+fn moment_value(datum: Datum) -> NumericAgg {
+    match datum {
+        Datum::Int16(i) => NumericAgg::from(i32::from(i)),
+        Datum::Int32(i) => NumericAgg::from(i),
+        Datum::Int64(i) => NumericAgg::from(i),
+        Datum::UInt16(u) => NumericAgg::from(u32::from(u)),
+        Datum::UInt32(u) => NumericAgg::from(u),
+        Datum::UInt64(u) => NumericAgg::from(u),
+        Datum::Float32(f) => NumericAgg::from(f64::from(*f)),
+        Datum::Float64(f) => NumericAgg::from(*f),
+        Datum::Numeric(n) => numeric::cx_agg().to_width(n.0),
+        x => panic!("Invalid argument to statistical aggregate: {x:?}"),
+    }
+}
+
 // TODO(benesch): remove potentially dangerous usage of `as`.
 #[allow(clippy::as_conversions)]
 fn count<'a, I>(datums: I) -> Datum<'a>
@@ -191,6 +471,27 @@ where
         })
 }
 
+fn hll_merge<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut merged: Option<Hll> = None;
+    for datum in datums {
+        if datum.is_null() {
+            continue;
+        }
+        let sketch = Hll::decode(datum.unwrap_bytes()).expect("valid hll sketch");
+        match &mut merged {
+            Some(merged) => merged.merge(&sketch),
+            None => merged = Some(sketch),
+        }
+    }
+    match merged {
+        Some(merged) => Datum::Bytes(temp_storage.push_bytes(merged.encode())),
+        None => Datum::Null,
+    }
+}
+
 fn string_agg<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -351,6 +652,108 @@ where
     })
 }
 
+// Assuming datums are `(direct_arg, value)` records of an ordered-set aggregate,
+// return the direct argument along with the non-null values, sorted by
+// `order_by` and paired with their multiplicities.
+fn order_ordered_set_datums<'a, I>(
+    datums: I,
+    order_by: &[ColumnOrder],
+) -> (Datum<'a>, Vec<(Datum<'a>, usize)>)
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut direct_arg = Datum::Null;
+    let mut values = vec![];
+    // Records are NULL when they were excluded by a FILTER clause.
+    for d in datums.into_iter().filter(|d| !d.is_null()) {
+        let mut fields = d.unwrap_list().iter();
+        direct_arg = fields.next().unwrap();
+        let value = fields.next().unwrap();
+        if !value.is_null() {
+            values.push(value);
+        }
+    }
+    values
+        .sort_by(|left, right| compare_columns(order_by, &[*left], &[*right], || left.cmp(right)));
+    let values = values
+        .into_iter()
+        .dedup_with_count()
+        .map(|(count, value)| (value, count))
+        .collect();
+    (direct_arg, values)
+}
+
+// Computes a continuous percentile of sorted float values, interpolating
+// linearly between the two nearest values.
+fn percentile_cont<'a>(fraction: f64, nth: &impl Fn(usize) -> Datum<'a>, total: usize) -> f64 {
+    let (pos, lo, hi) = percentile_cont_positions(fraction, total);
+    let lo_value = nth(lo).unwrap_float64();
+    if lo == hi {
+        return lo_value;
+    }
+    let hi_value = nth(hi).unwrap_float64();
+    lo_value + (pos - f64::cast_lossy(lo)) * (hi_value - lo_value)
+}
+
+// Returns the fractional position of a continuous percentile in the sort
+// order, and the positions of the two values it interpolates between.
+fn percentile_cont_positions(fraction: f64, total: usize) -> (f64, usize, usize) {
+    let pos = fraction * f64::cast_lossy(total - 1);
+    let (lo, hi) = (pos.floor(), pos.ceil());
+    (pos, usize::cast_lossy(lo), usize::cast_lossy(hi))
+}
+
+// Computes a discrete percentile, i.e. the first value whose position in the
+// sort order is at least `fraction` of the total.
+fn percentile_disc<'a>(
+    fraction: f64,
+    nth: &impl Fn(usize) -> Datum<'a>,
+    total: usize,
+) -> Datum<'a> {
+    nth(percentile_disc_position(fraction, total))
+}
+
+// Returns the position of a discrete percentile in the sort order.
+fn percentile_disc_position(fraction: f64, total: usize) -> usize {
+    let row = usize::cast_lossy((fraction * f64::cast_lossy(total)).ceil());
+    max(row, 1) - 1
+}
+
+// Applies `percentile` to each fraction in `fractions`, preserving the
+// array's shape. NULL fractions produce NULL percentiles.
+fn percentile_multi<'a, F>(
+    fractions: Datum<'a>,
+    temp_storage: &'a RowArena,
+    percentile: F,
+) -> Datum<'a>
+where
+    F: Fn(f64) -> Datum<'a>,
+{
+    let fractions = fractions.unwrap_array();
+    let dims: Vec<_> = fractions.dims().into_iter().collect();
+    let percentiles = fractions.elements().iter().map(|fraction| match fraction {
+        Datum::Null => Datum::Null,
+        fraction => percentile(fraction.unwrap_float64()),
+    });
+    temp_storage.make_datum(|packer| {
+        packer.push_array(&dims, percentiles).unwrap();
+    })
+}
+
+// Returns the most frequent of the sorted values, preferring the value that
+// sorts first among equally frequent values.
+fn mode<'a>(sorted_values: &[(Datum<'a>, usize)]) -> Datum<'a> {
+    let mut best = (Datum::Null, 0);
+    // Equal values are adjacent, but may have been supplied as separate runs.
+    for (value, group) in &sorted_values.iter().group_by(|(value, _count)| *value) {
+        let count = group.map(|(_value, count)| count).sum();
+        if count > best.1 {
+            best = (value, count);
+        }
+    }
+    best.0
+}
+
 fn row_number<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -457,6 +860,369 @@ where
     })
 }
 
+// Returns the boundaries of the peer groups of `rows`, which must be sorted by their order-by
+// rows. Each peer group is returned as a pair of the index of its first row and the index just
+// after its last row.
+fn peer_groups<T, F>(rows: &[T], order_by_row: F) -> Vec<(usize, usize)>
+where
+    F: Fn(&T) -> &Row,
+{
+    let mut groups = Vec::new();
+    let mut peer_group_start = 0;
+    while peer_group_start < rows.len() {
+        let mut peer_group_end = peer_group_start + 1;
+        while peer_group_end < rows.len()
+            && order_by_row(&rows[peer_group_start]) == order_by_row(&rows[peer_group_end])
+        {
+            peer_group_end += 1;
+        }
+        groups.push((peer_group_start, peer_group_end));
+        peer_group_start = peer_group_end;
+    }
+    groups
+}
+
+// Computes the frame of each row of `rows`, which must be sorted by their order-by rows. Each frame
+// is returned as a pair of the index of its first row and the index just after its last row. A
+// frame is empty if the former is not smaller than the latter.
+fn window_frame_bounds<T, F>(
+    rows: &[T],
+    order_by_row: F,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Vec<(usize, usize)>
+where
+    F: Fn(&T) -> &Row,
+{
+    let length = rows.len();
+    let peer_groups = peer_groups(rows, &order_by_row);
+
+    // In RANGE mode, a value offset shifts the ORDER BY value of the current row, and the frame
+    // boundary is the position of this shifted value among the rows. The planning makes sure that
+    // there is exactly one ORDER BY column in this case. Like in Postgres, the frame boundary of a
+    // row with a null ORDER BY value is the boundary of its peer group.
+    //
+    // The shifted values follow the order of the rows, so the boundaries never move backwards, and
+    // each boundary slides forward from its position for the previous row in its `cursor`.
+    let value_offset_bound =
+        |cursor: &mut usize, current: &T, offset: &Row, preceding: bool, is_start: bool| {
+            let order = &order_by[0];
+            let base = order_by_row(current).iter().nth(order.column).unwrap();
+            if base.is_null() {
+                return None;
+            }
+            // PRECEDING moves towards the start of the partition, which has the smaller values if the
+            // order is ascending.
+            let subtract = preceding != order.desc;
+            let position = |row: &T| match order_by_row(row).iter().nth(order.column).unwrap() {
+                Datum::Null if order.nulls_last => Ordering::Greater,
+                Datum::Null => Ordering::Less,
+                value => {
+                    let ordering = cmp_to_shifted(value, base, offset.unpack_first(), subtract);
+                    if order.desc {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                }
+            };
+            let in_frame_or_before = |row: &T| {
+                if is_start {
+                    position(row) == Ordering::Less
+                } else {
+                    position(row) != Ordering::Greater
+                }
+            };
+            while *cursor < length && in_frame_or_before(&rows[*cursor]) {
+                *cursor += 1;
+            }
+            Some(*cursor)
+        };
+
+    let mut start_cursor = 0;
+    let mut end_cursor = 0;
+    let mut frames = Vec::with_capacity(length);
+    for (group_idx, (peer_group_start, peer_group_end)) in peer_groups.iter().enumerate() {
+        let peer_group = rows
+            .iter()
+            .enumerate()
+            .take(*peer_group_end)
+            .skip(*peer_group_start);
+        for (idx, row) in peer_group {
+            let frame_start = match (&window_frame.units, &window_frame.start_bound) {
+                (_, UnboundedPreceding) => 0,
+                (Rows, OffsetPreceding(offset)) => idx.saturating_sub(usize::cast_from(*offset)),
+                (Rows, CurrentRow) => idx,
+                (Rows, OffsetFollowing(offset)) => {
+                    min(idx.saturating_add(usize::cast_from(*offset)), length)
+                }
+                // In RANGE and GROUPS mode, the current row stands for its whole peer group
+                (Range | Groups, CurrentRow) => *peer_group_start,
+                (Groups, OffsetPreceding(offset)) => {
+                    peer_groups[group_idx.saturating_sub(usize::cast_from(*offset))].0
+                }
+                (Groups, OffsetFollowing(offset)) => peer_groups
+                    .get(group_idx.saturating_add(usize::cast_from(*offset)))
+                    .map_or(length, |(start, _)| *start),
+                (Range, ValuePreceding(offset)) => {
+                    value_offset_bound(&mut start_cursor, row, offset, true, true)
+                        .unwrap_or(*peer_group_start)
+                }
+                (Range, ValueFollowing(offset)) => {
+                    value_offset_bound(&mut start_cursor, row, offset, false, true)
+                        .unwrap_or(*peer_group_start)
+                }
+                // Forbidden during planning
+                (_, UnboundedFollowing)
+                | (Range, OffsetPreceding(_) | OffsetFollowing(_))
+                | (Rows | Groups, ValuePreceding(_) | ValueFollowing(_)) => unreachable!(),
+            };
+            let frame_end = match (&window_frame.units, &window_frame.end_bound) {
+                (_, UnboundedFollowing) => length,
+                (Rows, OffsetPreceding(offset)) => {
+                    (idx + 1).saturating_sub(usize::cast_from(*offset))
+                }
+                (Rows, CurrentRow) => idx + 1,
+                (Rows, OffsetFollowing(offset)) => min(
+                    idx.saturating_add(usize::cast_from(*offset))
+                        .saturating_add(1),
+                    length,
+                ),
+                (Range | Groups, CurrentRow) => *peer_group_end,
+                (Groups, OffsetPreceding(offset)) => group_idx
+                    .checked_sub(usize::cast_from(*offset))
+                    .map_or(0, |group| peer_groups[group].1),
+                (Groups, OffsetFollowing(offset)) => {
+                    let group = group_idx.saturating_add(usize::cast_from(*offset));
+                    peer_groups[min(group, peer_groups.len() - 1)].1
+                }
+                (Range, ValuePreceding(offset)) => {
+                    value_offset_bound(&mut end_cursor, row, offset, true, false)
+                        .unwrap_or(*peer_group_end)
+                }
+                (Range, ValueFollowing(offset)) => {
+                    value_offset_bound(&mut end_cursor, row, offset, false, false)
+                        .unwrap_or(*peer_group_end)
+                }
+                // Forbidden during planning
+                (_, UnboundedPreceding)
+                | (Range, OffsetPreceding(_) | OffsetFollowing(_))
+                | (Rows | Groups, ValuePreceding(_) | ValueFollowing(_)) => unreachable!(),
+            };
+            frames.push((frame_start, frame_end));
+        }
+    }
+    frames
+}
+
+// Compares `value` with `base + offset`, or with `base - offset` if `subtract` is set. A shifted
+// base that is out of the range of its type is further away from `base` than any value.
+fn cmp_to_shifted(value: Datum, base: Datum, offset: Datum, subtract: bool) -> Ordering {
+    let out_of_range = if subtract {
+        Ordering::Greater
+    } else {
+        Ordering::Less
+    };
+    match (value, offset) {
+        (Datum::Int16(_) | Datum::Int32(_) | Datum::Int64(_), Datum::Int64(offset)) => {
+            let as_i128 = |d: Datum| match d {
+                Datum::Int16(i) => i128::from(i),
+                Datum::Int32(i) => i128::from(i),
+                Datum::Int64(i) => i128::from(i),
+                _ => unreachable!(),
+            };
+            let shifted = if subtract {
+                as_i128(base) - i128::from(offset)
+            } else {
+                as_i128(base) + i128::from(offset)
+            };
+            as_i128(value).cmp(&shifted)
+        }
+        (Datum::Float32(_) | Datum::Float64(_), Datum::Float64(offset)) => {
+            let as_f64 = |d: Datum| match d {
+                Datum::Float32(f) => f64::from(f.into_inner()),
+                Datum::Float64(f) => f.into_inner(),
+                _ => unreachable!(),
+            };
+            let shifted = if subtract {
+                as_f64(base) - offset.into_inner()
+            } else {
+                as_f64(base) + offset.into_inner()
+            };
+            OrderedFloat(as_f64(value)).cmp(&OrderedFloat(shifted))
+        }
+        (Datum::Numeric(value), Datum::Numeric(offset)) => {
+            let mut cx = numeric::cx_datum();
+            let mut shifted = base.unwrap_numeric().0;
+            if subtract {
+                cx.sub(&mut shifted, &offset.0);
+            } else {
+                cx.add(&mut shifted, &offset.0);
+            }
+            value.cmp(&OrderedDecimal(shifted))
+        }
+        (Datum::Interval(value), Datum::Interval(offset)) => {
+            let offset = if subtract {
+                offset.checked_neg()
+            } else {
+                Some(offset)
+            };
+            match offset.and_then(|offset| base.unwrap_interval().checked_add(&offset)) {
+                Some(shifted) => value.cmp(&shifted),
+                None => out_of_range,
+            }
+        }
+        (value, Datum::Interval(offset)) => {
+            let as_date_time = |d: Datum| match d {
+                Datum::Date(date) => NaiveDate::from(date).and_hms_opt(0, 0, 0).unwrap(),
+                Datum::Timestamp(ts) => ts.date_time(),
+                Datum::TimestampTz(ts) => ts.date_time(),
+                _ => unreachable!(),
+            };
+            let offset = if subtract {
+                offset.checked_neg()
+            } else {
+                Some(offset)
+            };
+            let shifted = offset.and_then(|offset| {
+                add_timestamp_months(&as_date_time(base), offset.months)
+                    .ok()?
+                    .checked_add_signed(offset.duration_as_chrono())
+            });
+            match shifted {
+                Some(shifted) => as_date_time(value).cmp(&shifted),
+                None => out_of_range,
+            }
+        }
+        // The planning only allows the above combinations of types
+        _ => unreachable!(),
+    }
+}
+
+// Computes a floating point rank for each peer group of the sorted input, given the number of
+// rows in the window partition and the boundaries of the peer group.
+fn distribution_rank<'a, I, F>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+    rank_of_peer_group: F,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+    F: Fn(usize, usize, usize) -> f64,
+{
+    // Keep the row used for ordering around, as it is used to determine the peer groups
+    let datums = order_aggregate_datums_with_rank(datums, order_by)
+        .map(|(d0, order_row)| {
+            d0.unwrap_list()
+                .iter()
+                .map(move |d1| (d1, order_row.clone()))
+        })
+        .flatten()
+        .collect_vec();
+
+    let total = datums.len();
+    let mut result: Vec<(Datum, f64)> = Vec::with_capacity(total);
+    for (peer_group_start, peer_group_end) in peer_groups(&datums, |(_, order_row)| order_row) {
+        // All rows of a peer group get the same rank
+        let rank = rank_of_peer_group(total, peer_group_start, peer_group_end);
+        for (datum, _) in &datums[peer_group_start..peer_group_end] {
+            result.push((*datum, rank));
+        }
+    }
+
+    let result = result.into_iter().map(|(datum, rank)| {
+        temp_storage.make_datum(|packer| {
+            packer.push_list(vec![Datum::Float64(rank.into()), datum]);
+        })
+    });
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(result);
+    })
+}
+
+fn percent_rank<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // (rank - 1) / (total rows - 1), where the rank of a peer group is the row number of its
+    // first row.
+    distribution_rank(datums, temp_storage, order_by, |total, start, _end| {
+        if total > 1 {
+            f64::cast_lossy(start) / f64::cast_lossy(total - 1)
+        } else {
+            0.0
+        }
+    })
+}
+
+fn cume_dist<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // (rows preceding or peer with the current row) / (total rows)
+    distribution_rank(datums, temp_storage, order_by, |total, _start, end| {
+        f64::cast_lossy(end) / f64::cast_lossy(total)
+    })
+}
+
+// The expected input is in the format of [((OriginalRow, NumBuckets), OrderByExprs...)]
+fn ntile<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the (OriginalRow, NumBuckets) record
+    let datums = order_aggregate_datums(datums, order_by);
+
+    // Decode the input (OriginalRow, NumBuckets) into separate datums
+    let datums = datums
+        .into_iter()
+        .map(|d| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let num_buckets = iter.next().unwrap();
+
+            (num_buckets, original_row)
+        })
+        .collect_vec();
+
+    // Like Postgres, we take the number of buckets from the first row of the partition. The
+    // planning makes sure that it is positive.
+    let length = datums.len();
+    let num_buckets = datums
+        .first()
+        .and_then(|(num_buckets, _)| match num_buckets {
+            Datum::Null => None,
+            num_buckets => usize::try_from(num_buckets.unwrap_int32()).ok(),
+        })
+        .filter(|num_buckets| *num_buckets > 0);
+
+    // The first `length % num_buckets` buckets get one more row than the others.
+    let result = datums.iter().enumerate().map(|(idx, (_, original_row))| {
+        let bucket = match num_buckets {
+            None => Datum::Null,
+            Some(num_buckets) => {
+                let small_bucket_size = length / num_buckets;
+                let large_bucket_rows = (length % num_buckets) * (small_bucket_size + 1);
+                let bucket = if idx < large_bucket_rows {
+                    idx / (small_bucket_size + 1)
+                } else {
+                    length % num_buckets + (idx - large_bucket_rows) / small_bucket_size
+                };
+                Datum::Int32(i32::try_from(bucket + 1).expect("at most num_buckets buckets"))
+            }
+        };
+        temp_storage.make_datum(|packer| {
+            packer.push_list(vec![bucket, *original_row]);
+        })
+    });
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(result);
+    })
+}
+
 // The expected input is in the format of [((OriginalRow, EncodedArgs), OrderByExprs...)]
 fn lag_lead<'a, I>(
     datums: I,
@@ -570,24 +1336,45 @@ fn first_value<'a, I>(
 where
     I: IntoIterator<Item = Datum<'a>>,
 {
-    // Sort the datums according to the ORDER BY expressions and return the (OriginalRow, InputValue) record
-    let datums = order_aggregate_datums(datums, order_by);
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, InputValue), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the frames in RANGE and GROUPS mode
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
 
-    // Decode the input (OriginalRow, InputValue) into separate datums
+    // Decode the input (OriginalRow, InputValue) into separate datums, while keeping the OrderByRow
     let datums = datums
         .into_iter()
-        .map(|d| {
+        .map(|(d, order_by_row)| {
             let mut iter = d.unwrap_list().iter();
             let original_row = iter.next().unwrap();
             let input_value = iter.next().unwrap();
 
-            (input_value, original_row)
+            (input_value, original_row, order_by_row)
         })
         .collect_vec();
 
+    if window_frame.units != Rows {
+        let frames = window_frame_bounds(&datums, |(_, _, row)| row, order_by, window_frame);
+        let result = datums
+            .iter()
+            .zip_eq(frames)
+            .map(|((_, original_row, _), frame)| {
+                let first_value = match frame {
+                    (frame_start, frame_end) if frame_start < frame_end => datums[frame_start].0,
+                    // The frame is empty
+                    _ => Datum::Null,
+                };
+                temp_storage.make_datum(|packer| {
+                    packer.push_list(vec![first_value, *original_row]);
+                })
+            });
+        return temp_storage.make_datum(|packer| {
+            packer.push_list(result);
+        });
+    }
+
     let length = datums.len();
     let mut result: Vec<(Datum, Datum)> = Vec::with_capacity(length);
-    for (idx, (current_datum, original_row)) in datums.iter().enumerate() {
+    for (idx, (current_datum, original_row, _)) in datums.iter().enumerate() {
         let first_value = match &window_frame.start_bound {
             // Always return the current value
             WindowFrameBound::CurrentRow => *current_datum,
@@ -636,7 +1423,9 @@ where
                 }
             }
             // Forbidden during planning
-            WindowFrameBound::UnboundedFollowing => unreachable!(),
+            WindowFrameBound::UnboundedFollowing
+            | WindowFrameBound::ValuePreceding(_)
+            | WindowFrameBound::ValueFollowing(_) => unreachable!(),
         };
 
         result.push((first_value, *original_row));
@@ -679,34 +1468,38 @@ where
         })
         .collect_vec();
 
+    if window_frame.units != Rows {
+        let frames = window_frame_bounds(&datums, |(_, _, row)| row, order_by, window_frame);
+        let result = datums
+            .iter()
+            .zip_eq(frames)
+            .map(|((_, original_row, _), frame)| {
+                let last_value = match frame {
+                    (frame_start, frame_end) if frame_start < frame_end => datums[frame_end - 1].0,
+                    // The frame is empty
+                    _ => Datum::Null,
+                };
+                temp_storage.make_datum(|packer| {
+                    packer.push_list(vec![last_value, *original_row]);
+                })
+            });
+        return temp_storage.make_datum(|packer| {
+            packer.push_list(result);
+        });
+    }
+
     let length = datums.len();
     let mut result: Vec<(Datum, Datum)> = Vec::with_capacity(length);
-    for (idx, (current_datum, original_row, order_by_row)) in datums.iter().enumerate() {
+    for (idx, (current_datum, original_row, _)) in datums.iter().enumerate() {
         let last_value = match &window_frame.end_bound {
-            WindowFrameBound::CurrentRow => match &window_frame.units {
-                // Always return the current value when in ROWS mode
-                WindowFrameUnits::Rows => *current_datum,
-                WindowFrameUnits::Range => {
-                    // When in RANGE mode, return the last value of the peer group
-                    // The peer group is the group of rows with the same ORDER BY value
-                    // Note: Range is only supported for the default window frame (RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW),
-                    // which is why it does not appear in the other branches
-                    datums[idx..]
-                        .iter()
-                        .take_while(|(_, _, row)| row == order_by_row)
-                        .last()
-                        .unwrap()
-                        .0
-                }
-                // GROUPS is not supported, and forbidden during planning
-                WindowFrameUnits::Groups => unreachable!(),
-            },
+            // Always return the current value when in ROWS mode
+            WindowFrameBound::CurrentRow => *current_datum,
             WindowFrameBound::UnboundedFollowing => {
                 if let WindowFrameBound::OffsetFollowing(start_offset) = &window_frame.start_bound {
                     let start_offset = usize::cast_from(*start_offset);
 
                     // If the frame starts after the last row of the window, return null
-                    if idx + start_offset > length - 1 {
+                    if idx.saturating_add(start_offset) > length - 1 {
                         Datum::Null
                     } else {
                         datums[length - 1].0
@@ -759,21 +1552,146 @@ where
                 }
             }
             // Forbidden during planning
-            WindowFrameBound::UnboundedPreceding => unreachable!(),
+            WindowFrameBound::UnboundedPreceding
+            | WindowFrameBound::ValuePreceding(_)
+            | WindowFrameBound::ValueFollowing(_) => unreachable!(),
+        };
+
+        result.push((last_value, *original_row));
+    }
+
+    let result = result.into_iter().map(|(result_value, original_row)| {
+        temp_storage.make_datum(|packer| {
+            packer.push_list(vec![result_value, original_row]);
+        })
+    });
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(result);
+    })
+}
+
+// The expected input is in the format of [((OriginalRow, (InputValue, N)), OrderByExprs...)]
+fn nth_value<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, EncodedArgs), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the peer groups in RANGE mode
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
+
+    // Decode the input (OriginalRow, (InputValue, N)) into separate datums, while keeping the OrderByRow
+    let datums = datums
+        .into_iter()
+        .map(|(d, order_by_row)| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let mut encoded_args = iter.next().unwrap().unwrap_list().iter();
+            let (input_value, n) = (encoded_args.next().unwrap(), encoded_args.next().unwrap());
+
+            (input_value, n, original_row, order_by_row)
+        })
+        .collect_vec();
+
+    let frames = window_frame_bounds(&datums, |(_, _, _, row)| row, order_by, window_frame);
+    let mut result: Vec<(Datum, Datum)> = Vec::with_capacity(datums.len());
+    for ((_, n, original_row, _), (frame_start, frame_end)) in datums.iter().zip_eq(frames) {
+        // Null values of N always return null. The planning makes sure that N is positive.
+        let nth_value = match n {
+            Datum::Null => Datum::Null,
+            n => match usize::try_from(n.unwrap_int32()) {
+                Ok(n) if n > 0 && frame_start.saturating_add(n - 1) < frame_end => {
+                    datums[frame_start + n - 1].0
+                }
+                _ => Datum::Null,
+            },
         };
 
-        result.push((last_value, *original_row));
+        result.push((nth_value, *original_row));
+    }
+
+    let result = result.into_iter().map(|(result_value, original_row)| {
+        temp_storage.make_datum(|packer| {
+            packer.push_list(vec![result_value, original_row]);
+        })
+    });
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(result);
+    })
+}
+
+/// Filters out repeated values if `distinct` is set, keeping the first occurrence of each value
+/// and preserving the order of `values`.
+fn distinct_values<'a, I>(values: I, distinct: bool) -> impl Iterator<Item = Datum<'a>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut seen = BTreeSet::new();
+    values
+        .into_iter()
+        .filter(move |value| !distinct || seen.insert(*value))
+}
+
+/// The distinct input values of a window frame that slides forward over a window partition,
+/// together with how many times each of them occurs in the frame.
+///
+/// Neither bound of the frame may move backwards, so every value enters and leaves the frame at
+/// most once, instead of deduplicating each frame from scratch.
+struct SlidingDistinctValues<'a> {
+    counts: BTreeMap<Datum<'a>, usize>,
+    /// The rows `start..end` of the window partition are counted in `counts`.
+    start: usize,
+    end: usize,
+}
+
+impl<'a> SlidingDistinctValues<'a> {
+    fn new() -> Self {
+        SlidingDistinctValues {
+            counts: BTreeMap::new(),
+            start: 0,
+            end: 0,
+        }
     }
 
-    let result = result.into_iter().map(|(result_value, original_row)| {
-        temp_storage.make_datum(|packer| {
-            packer.push_list(vec![result_value, original_row]);
-        })
-    });
+    /// Moves the frame to the rows `start..end` of `input_datums`.
+    fn slide_to<'b>(
+        &mut self,
+        input_datums: &[(Datum<'a>, Datum<'b>, Row)],
+        start: usize,
+        end: usize,
+    ) {
+        let start = max(self.start, start);
+        let end = max(self.end, end);
+        for (input_value, _original_row, _order_by_row) in
+            &input_datums[self.start..min(self.end, start)]
+        {
+            let count = self
+                .counts
+                .get_mut(input_value)
+                .expect("value in the frame must be counted");
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(input_value);
+            }
+        }
+        for (input_value, _original_row, _order_by_row) in &input_datums[max(self.end, start)..end]
+        {
+            *self.counts.entry(*input_value).or_insert(0) += 1;
+        }
+        self.start = start;
+        self.end = end;
+    }
 
-    temp_storage.make_datum(|packer| {
-        packer.push_list(result);
-    })
+    /// The distinct values of the current frame.
+    fn values(&self) -> impl Iterator<Item = Datum<'a>> + '_ {
+        self.counts.keys().copied()
+    }
 }
 
 // The expected input is in the format of [((OriginalRow, InputValue), OrderByExprs...)]
@@ -786,6 +1704,8 @@ fn window_aggr<'a, I, A>(
     // see the comment on `WindowExprType`.
     order_by: &[ColumnOrder],
     window_frame: &WindowFrame,
+    // Whether the wrapped aggregate should see each distinct input value only once per frame.
+    distinct: bool,
 ) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -815,18 +1735,6 @@ where
     let length = input_datums.len();
     let mut result: Vec<(Datum, Datum)> = Vec::with_capacity(length);
 
-    // In this degenerate case, all results would be `wrapped_aggregate.default()` (usually null).
-    // However, this currently can't happen, because
-    // - Groups frame mode is currently not supported;
-    // - Range frame mode is currently supported only for the default frame, which includes the
-    //   current row.
-    soft_assert_or_log!(
-        !((matches!(window_frame.units, WindowFrameUnits::Groups)
-            || matches!(window_frame.units, WindowFrameUnits::Range))
-            && !window_frame.includes_current_row()),
-        "window frame without current row"
-    );
-
     if (matches!(
         window_frame.start_bound,
         WindowFrameBound::UnboundedPreceding
@@ -842,10 +1750,13 @@ where
         //    (The current peer group will be the whole partition if there is no ORDER BY.)
         // We simply need to compute the aggregate once, on the entire partition, and each input
         // row will get this one aggregate value as result.
-        let input_values = input_datums
-            .iter()
-            .map(|(input_value, _original_row, _order_by_row)| input_value.clone())
-            .collect_vec(); // I'm open to suggestions on how to remove this `collect_vec()`...
+        let input_values = distinct_values(
+            input_datums
+                .iter()
+                .map(|(input_value, _original_row, _order_by_row)| input_value.clone()),
+            distinct,
+        )
+        .collect_vec(); // I'm open to suggestions on how to remove this `collect_vec()`...
         let result_value = wrapped_aggregate.eval(input_values, &temp_storage);
         // Every row will get the above aggregate as result.
         for (_current_datum, original_row, _order_by_row) in input_datums.iter() {
@@ -857,11 +1768,16 @@ where
             result: &mut Vec<(Datum<'a>, Datum<'b>)>,
             mut one_by_one_aggr: A,
             temp_storage: &'a RowArena,
+            distinct: bool,
         ) where
             A: OneByOneAggr,
         {
+            // With DISTINCT, we only give each value to the aggregation the first time we see it.
+            let mut seen = BTreeSet::new();
             for (current_datum, original_row, _order_by_row) in input_datums.into_iter() {
-                one_by_one_aggr.give(&current_datum);
+                if !distinct || seen.insert(current_datum) {
+                    one_by_one_aggr.give(&current_datum);
+                }
                 let result_value = one_by_one_aggr.get_current_aggregate(temp_storage);
                 result.push((result_value, original_row));
             }
@@ -872,9 +1788,11 @@ where
             result: &mut Vec<(Datum<'a>, Datum<'b>)>,
             mut one_by_one_aggr: A,
             temp_storage: &'a RowArena,
+            distinct: bool,
         ) where
             A: OneByOneAggr,
         {
+            let mut seen = BTreeSet::new();
             let mut peer_group_start = 0;
             while peer_group_start < input_datums.len() {
                 // Find the boundaries of the current peer group.
@@ -892,7 +1810,9 @@ where
                 for (current_datum, _original_row, _order_by_row) in
                     input_datums[peer_group_start..peer_group_end].iter()
                 {
-                    one_by_one_aggr.give(current_datum);
+                    if !distinct || seen.insert(*current_datum) {
+                        one_by_one_aggr.give(current_datum);
+                    }
                 }
                 let agg_for_peer_group = one_by_one_aggr.get_current_aggregate(temp_storage);
                 // Put the above aggregate into each record in the peer group.
@@ -913,23 +1833,25 @@ where
             temp_storage: &'a RowArena,
             offset_start: i64,
             offset_end: i64,
+            distinct: bool,
         ) {
             let len = input_datums
                 .len()
                 .to_i64()
                 .expect("window partition's len should fit into i64");
+            let mut distinct_frame = SlidingDistinctValues::new();
             for (i, (_current_datum, original_row, _order_by_row)) in
                 input_datums.iter().enumerate()
             {
                 let i = i.to_i64().expect("window partition shouldn't be super big");
                 // Trim the start of the frame to make it not reach over the start of the window
                 // partition.
-                let frame_start = max(i + offset_start, 0)
+                let frame_start = max(i.saturating_add(offset_start), 0)
                     .to_usize()
                     .expect("The max made sure it's not negative");
                 // Trim the end of the frame to make it not reach over the end of the window
                 // partition.
-                let frame_end = min(i + offset_end, len - 1).to_usize();
+                let frame_end = min(i.saturating_add(offset_end), len - 1).to_usize();
                 match frame_end {
                     Some(frame_end) => {
                         if frame_start <= frame_end {
@@ -943,10 +1865,17 @@ where
                             //    the fixed size of the window, or that we are not retracting
                             //    arbitrary elements but doing queue operations. E.g., see
                             //    http://codercareer.blogspot.com/2012/02/no-33-maximums-in-sliding-windows.html
-                            let frame_values = input_datums[frame_start..=frame_end].iter().map(
-                                |(input_value, _original_row, _order_by_row)| input_value.clone(),
-                            );
-                            let result_value = wrapped_aggregate.eval(frame_values, temp_storage);
+                            let result_value = if distinct {
+                                distinct_frame.slide_to(&input_datums, frame_start, frame_end + 1);
+                                wrapped_aggregate.eval(distinct_frame.values(), temp_storage)
+                            } else {
+                                let frame_values = input_datums[frame_start..=frame_end]
+                                    .iter()
+                                    .map(|(input_value, _original_row, _order_by_row)| {
+                                        input_value.clone()
+                                    });
+                                wrapped_aggregate.eval(frame_values, temp_storage)
+                            };
                             result.push((result_value, original_row.clone()));
                         } else {
                             // frame_start > frame_end, so this is an empty frame.
@@ -963,6 +1892,145 @@ where
             }
         }
 
+        /// Computes the aggregate for frames that all start at the beginning of the window
+        /// partition, given the end of each frame. The ends never move backwards, so the values
+        /// are given to the aggregation one by one as the frames grow.
+        fn unbounded_preceding_frames<'a, 'b, A, I>(
+            input_datums: &[(Datum<'a>, Datum<'b>, Row)],
+            frame_ends: I,
+            result: &mut Vec<(Datum<'a>, Datum<'b>)>,
+            wrapped_aggregate: &AggregateFunc,
+            mut one_by_one_aggr: A,
+            temp_storage: &'a RowArena,
+            distinct: bool,
+        ) where
+            A: OneByOneAggr,
+            I: IntoIterator<Item = usize>,
+        {
+            let mut seen = BTreeSet::new();
+            let mut given = 0;
+            for ((_current_datum, original_row, _order_by_row), frame_end) in
+                input_datums.iter().zip_eq(frame_ends)
+            {
+                while given < frame_end {
+                    let value = input_datums[given].0;
+                    if !distinct || seen.insert(value) {
+                        one_by_one_aggr.give(&value);
+                    }
+                    given += 1;
+                }
+                let result_value = if frame_end > 0 {
+                    one_by_one_aggr.get_current_aggregate(temp_storage)
+                } else {
+                    wrapped_aggregate.default()
+                };
+                result.push((result_value, *original_row));
+            }
+        }
+
+        fn unbounded_preceding_and_bound<'a, 'b, A>(
+            input_datums: Vec<(Datum<'a>, Datum<'b>, Row)>,
+            result: &mut Vec<(Datum<'a>, Datum<'b>)>,
+            wrapped_aggregate: &AggregateFunc,
+            temp_storage: &'a RowArena,
+            order_by: &[ColumnOrder],
+            window_frame: &WindowFrame,
+            distinct: bool,
+        ) where
+            A: OneByOneAggr,
+        {
+            let frames = window_frame_bounds(
+                &input_datums,
+                |(_input_value, _original_row, order_by_row)| order_by_row,
+                order_by,
+                window_frame,
+            );
+            unbounded_preceding_frames(
+                &input_datums,
+                frames
+                    .into_iter()
+                    .map(|(_frame_start, frame_end)| frame_end),
+                result,
+                wrapped_aggregate,
+                A::new(wrapped_aggregate, false),
+                temp_storage,
+                distinct,
+            );
+        }
+
+        fn bound_and_unbounded_following<'a, 'b, A>(
+            mut input_datums: Vec<(Datum<'a>, Datum<'b>, Row)>,
+            result: &mut Vec<(Datum<'a>, Datum<'b>)>,
+            wrapped_aggregate: &AggregateFunc,
+            temp_storage: &'a RowArena,
+            order_by: &[ColumnOrder],
+            window_frame: &WindowFrame,
+            distinct: bool,
+        ) where
+            A: OneByOneAggr,
+        {
+            let frames = window_frame_bounds(
+                &input_datums,
+                |(_input_value, _original_row, order_by_row)| order_by_row,
+                order_by,
+                window_frame,
+            );
+            // Same as above, but reverse: each frame starts at the end of the reversed partition.
+            let length = input_datums.len();
+            input_datums.reverse();
+            unbounded_preceding_frames(
+                &input_datums,
+                frames
+                    .into_iter()
+                    .rev()
+                    .map(|(frame_start, _frame_end)| length - frame_start),
+                result,
+                wrapped_aggregate,
+                A::new(wrapped_aggregate, true),
+                temp_storage,
+                distinct,
+            );
+            result.reverse();
+        }
+
+        fn general_frames<'a, 'b>(
+            input_datums: Vec<(Datum<'a>, Datum<'b>, Row)>,
+            result: &mut Vec<(Datum<'a>, Datum<'b>)>,
+            wrapped_aggregate: &AggregateFunc,
+            temp_storage: &'a RowArena,
+            order_by: &[ColumnOrder],
+            window_frame: &WindowFrame,
+            distinct: bool,
+        ) {
+            let frames = window_frame_bounds(
+                &input_datums,
+                |(_input_value, _original_row, order_by_row)| order_by_row,
+                order_by,
+                window_frame,
+            );
+            let mut distinct_frame = SlidingDistinctValues::new();
+            for ((_current_datum, original_row, _order_by_row), (frame_start, frame_end)) in
+                input_datums.iter().zip_eq(frames)
+            {
+                // Like in `rows_between_offset_and_offset`, we compute the aggregate on each frame
+                // from scratch.
+                let result_value = if frame_start < frame_end {
+                    if distinct {
+                        distinct_frame.slide_to(&input_datums, frame_start, frame_end);
+                        wrapped_aggregate.eval(distinct_frame.values(), temp_storage)
+                    } else {
+                        let frame_values = input_datums[frame_start..frame_end]
+                            .iter()
+                            .map(|(input_value, _original_row, _order_by_row)| input_value.clone());
+                        wrapped_aggregate.eval(frame_values, temp_storage)
+                    }
+                } else {
+                    wrapped_aggregate.default()
+                };
+                result.push((result_value, original_row.clone()));
+            }
+        }
+
         match (
             &window_frame.units,
             &window_frame.start_bound,
@@ -978,6 +2046,7 @@ where
                     &mut result,
                     A::new(wrapped_aggregate, false),
                     &temp_storage,
+                    distinct,
                 );
             }
             (Rows, CurrentRow, UnboundedFollowing) => {
@@ -988,6 +2057,7 @@ where
                     &mut result,
                     A::new(wrapped_aggregate, true),
                     &temp_storage,
+                    distinct,
                 );
                 result.reverse();
             }
@@ -999,18 +2069,16 @@ where
                     &mut result,
                     A::new(wrapped_aggregate, false),
                     &temp_storage,
+                    distinct,
                 );
             }
             // The next several cases all call `rows_between_offset_and_offset`. Note that the
             // offset passed to `rows_between_offset_and_offset` should be negated when it's
-            // PRECEDING.
+            // PRECEDING. Offsets that don't fit into an i64 are larger than any window partition,
+            // so we can clamp them to i64::MAX.
             (Rows, OffsetPreceding(start_prec), OffsetPreceding(end_prec)) => {
-                let start_prec = start_prec.to_i64().expect(
-                    "window frame start OFFSET shouldn't be super big (the planning ensured this)",
-                );
-                let end_prec = end_prec.to_i64().expect(
-                    "window frame end OFFSET shouldn't be super big (the planning ensured this)",
-                );
+                let start_prec = i64::try_from(*start_prec).unwrap_or(i64::MAX);
+                let end_prec = i64::try_from(*end_prec).unwrap_or(i64::MAX);
                 rows_between_offset_and_offset(
                     input_datums,
                     &mut result,
@@ -1018,15 +2086,12 @@ where
                     &temp_storage,
                     -start_prec,
                     -end_prec,
+                    distinct,
                 );
             }
             (Rows, OffsetPreceding(start_prec), OffsetFollowing(end_fol)) => {
-                let start_prec = start_prec.to_i64().expect(
-                    "window frame start OFFSET shouldn't be super big (the planning ensured this)",
-                );
-                let end_fol = end_fol.to_i64().expect(
-                    "window frame end OFFSET shouldn't be super big (the planning ensured this)",
-                );
+                let start_prec = i64::try_from(*start_prec).unwrap_or(i64::MAX);
+                let end_fol = i64::try_from(*end_fol).unwrap_or(i64::MAX);
                 rows_between_offset_and_offset(
                     input_datums,
                     &mut result,
@@ -1034,15 +2099,12 @@ where
                     &temp_storage,
                     -start_prec,
                     end_fol,
+                    distinct,
                 );
             }
             (Rows, OffsetFollowing(start_fol), OffsetFollowing(end_fol)) => {
-                let start_fol = start_fol.to_i64().expect(
-                    "window frame start OFFSET shouldn't be super big (the planning ensured this)",
-                );
-                let end_fol = end_fol.to_i64().expect(
-                    "window frame end OFFSET shouldn't be super big (the planning ensured this)",
-                );
+                let start_fol = i64::try_from(*start_fol).unwrap_or(i64::MAX);
+                let end_fol = i64::try_from(*end_fol).unwrap_or(i64::MAX);
                 rows_between_offset_and_offset(
                     input_datums,
                     &mut result,
@@ -1050,15 +2112,14 @@ where
                     &temp_storage,
                     start_fol,
                     end_fol,
+                    distinct,
                 );
             }
             (Rows, OffsetFollowing(_), OffsetPreceding(_)) => {
                 unreachable!() // The planning ensured that this nonsensical case can't happen
             }
             (Rows, OffsetPreceding(start_prec), CurrentRow) => {
-                let start_prec = start_prec.to_i64().expect(
-                    "window frame start OFFSET shouldn't be super big (the planning ensured this)",
-                );
+                let start_prec = i64::try_from(*start_prec).unwrap_or(i64::MAX);
                 let end_fol = 0;
                 rows_between_offset_and_offset(
                     input_datums,
@@ -1067,13 +2128,12 @@ where
                     &temp_storage,
                     -start_prec,
                     end_fol,
+                    distinct,
                 );
             }
             (Rows, CurrentRow, OffsetFollowing(end_fol)) => {
                 let start_fol = 0;
-                let end_fol = end_fol.to_i64().expect(
-                    "window frame end OFFSET shouldn't be super big (the planning ensured this)",
-                );
+                let end_fol = i64::try_from(*end_fol).unwrap_or(i64::MAX);
                 rows_between_offset_and_offset(
                     input_datums,
                     &mut result,
@@ -1081,6 +2141,7 @@ where
                     &temp_storage,
                     start_fol,
                     end_fol,
+                    distinct,
                 );
             }
             (Rows, CurrentRow, CurrentRow) => {
@@ -1095,6 +2156,7 @@ where
                     &temp_storage,
                     start_fol,
                     end_fol,
+                    distinct,
                 );
             }
             (Rows, CurrentRow, OffsetPreceding(_))
@@ -1108,28 +2170,48 @@ where
                 // function.
                 unreachable!()
             }
-            (Rows, UnboundedPreceding, OffsetPreceding(_))
-            | (Rows, UnboundedPreceding, OffsetFollowing(_))
-            | (Rows, OffsetPreceding(..), UnboundedFollowing)
-            | (Rows, OffsetFollowing(..), UnboundedFollowing) => {
-                // Unsupported. Bail in the planner.
-                // https://github.com/MaterializeInc/materialize/issues/22268
-                unreachable!()
+            (Rows, UnboundedPreceding, OffsetPreceding(_) | OffsetFollowing(_))
+            | (Range | Groups, UnboundedPreceding, _) => {
+                // Mixed unbounded - offset frames, and RANGE and GROUPS frames that start at the
+                // beginning of the window partition.
+                unbounded_preceding_and_bound::<A>(
+                    input_datums,
+                    &mut result,
+                    wrapped_aggregate,
+                    &temp_storage,
+                    order_by,
+                    window_frame,
+                    distinct,
+                );
             }
-            (Range, _, _) => {
-                // Unsupported.
-                // The planner doesn't allow Range frame mode for now (except for the default
-                // frame), see https://github.com/MaterializeInc/materialize/issues/21934
-                // Note that it would be easy to handle (Range, CurrentRow, UnboundedFollowing):
-                // it would be similar to (Rows, CurrentRow, UnboundedFollowing), but would call
-                // groups_between_unbounded_preceding_current_row.
-                unreachable!()
+            (Rows, OffsetPreceding(_) | OffsetFollowing(_), UnboundedFollowing)
+            | (Range | Groups, _, UnboundedFollowing) => {
+                // Same as above, but for frames that end at the end of the window partition.
+                bound_and_unbounded_following::<A>(
+                    input_datums,
+                    &mut result,
+                    wrapped_aggregate,
+                    &temp_storage,
+                    order_by,
+                    window_frame,
+                    distinct,
+                );
             }
-            (Groups, _, _) => {
-                // Unsupported.
-                // The planner doesn't allow Groups frame mode for now, see
-                // https://github.com/MaterializeInc/materialize/issues/21940
-                unreachable!()
+            (Range, _, _) | (Groups, _, _) => {
+                // RANGE and GROUPS frames that are bounded at both ends.
+                general_frames(
+                    input_datums,
+                    &mut result,
+                    wrapped_aggregate,
+                    &temp_storage,
+                    order_by,
+                    window_frame,
+                    distinct,
+                );
+            }
+            (Rows, ValuePreceding(_) | ValueFollowing(_), _)
+            | (Rows, _, ValuePreceding(_) | ValueFollowing(_)) => {
+                unreachable!() // The planning uses value offsets only in RANGE mode
             }
         }
     }
@@ -1212,6 +2294,92 @@ pub enum LagLeadType {
     Lead,
 }
 
+/// Identifies which statistic a variance aggregate computes. The variance
+/// aggregates share an implementation and only differ in how they finish the
+/// accumulated sums.
+#[derive(
+    Arbitrary,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect,
+)]
+pub enum VarianceFunc {
+    VarPop,
+    VarSamp,
+    StddevPop,
+    StddevSamp,
+}
+
+impl VarianceFunc {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VarianceFunc::VarPop => "var_pop",
+            VarianceFunc::VarSamp => "var_samp",
+            VarianceFunc::StddevPop => "stddev_pop",
+            VarianceFunc::StddevSamp => "stddev_samp",
+        }
+    }
+}
+
+/// Identifies which statistic a two-argument regression aggregate computes.
+/// The regression aggregates share an implementation and only differ in how
+/// they finish the accumulated sums.
+#[derive(
+    Arbitrary,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect,
+)]
+pub enum RegressionFunc {
+    Corr,
+    CovarPop,
+    CovarSamp,
+    RegrAvgX,
+    RegrAvgY,
+    RegrCount,
+    RegrIntercept,
+    RegrR2,
+    RegrSlope,
+    RegrSxx,
+    RegrSxy,
+    RegrSyy,
+}
+
+impl RegressionFunc {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RegressionFunc::Corr => "corr",
+            RegressionFunc::CovarPop => "covar_pop",
+            RegressionFunc::CovarSamp => "covar_samp",
+            RegressionFunc::RegrAvgX => "regr_avgx",
+            RegressionFunc::RegrAvgY => "regr_avgy",
+            RegressionFunc::RegrCount => "regr_count",
+            RegressionFunc::RegrIntercept => "regr_intercept",
+            RegressionFunc::RegrR2 => "regr_r2",
+            RegressionFunc::RegrSlope => "regr_slope",
+            RegressionFunc::RegrSxx => "regr_sxx",
+            RegressionFunc::RegrSxy => "regr_sxy",
+            RegressionFunc::RegrSyy => "regr_syy",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, MzReflect)]
 pub enum AggregateFunc {
     MaxNumeric,
@@ -1226,6 +2394,9 @@ pub enum AggregateFunc {
     MaxFloat64,
     MaxBool,
     MaxString,
+    MaxStringCollated {
+        collation: Collation,
+    },
     MaxDate,
     MaxTimestamp,
     MaxTimestampTz,
@@ -1242,6 +2413,9 @@ pub enum AggregateFunc {
     MinFloat64,
     MinBool,
     MinString,
+    MinStringCollated {
+        collation: Collation,
+    },
     MinDate,
     MinTimestamp,
     MinTimestampTz,
@@ -1258,6 +2432,25 @@ pub enum AggregateFunc {
     Count,
     Any,
     All,
+    /// Computes a variance or standard deviation of integer or numeric values,
+    /// producing a numeric result.
+    VarianceNumeric {
+        func: VarianceFunc,
+    },
+    /// Computes a variance or standard deviation of float values, producing a
+    /// float result.
+    VarianceFloat64 {
+        func: VarianceFunc,
+    },
+    /// Computes a regression statistic over `Datum::List`s whose first field
+    /// is the `Datum::Float64` dependent variable `y` and whose second field
+    /// is the `Datum::Float64` independent variable `x`. Inputs where either
+    /// field is NULL are ignored.
+    Regression {
+        func: RegressionFunc,
+    },
+    /// Merges `hll` sketches into the sketch of their union.
+    HllMerge,
     /// Accumulates `Datum::List`s whose first element is a JSON-typed `Datum`s
     /// into a JSON list. The other elements are columns used by `order_by`.
     ///
@@ -1289,6 +2482,34 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Computes a continuous percentile over `Datum::List`s whose first field
+    /// is the `Datum::Float64` fraction and whose second field is the
+    /// `Datum::Float64` value being aggregated. `order_by` sorts the values.
+    PercentileCont {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Like `PercentileCont`, but the first field is an array of fractions,
+    /// and the result is an array holding one percentile per fraction.
+    PercentileContMulti {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes a discrete percentile over `Datum::List`s whose first field
+    /// is the `Datum::Float64` fraction and whose second field is the value
+    /// being aggregated. `order_by` sorts the values.
+    PercentileDisc {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Like `PercentileDisc`, but the first field is an array of fractions,
+    /// and the result is an array holding one percentile per fraction.
+    PercentileDiscMulti {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the most frequent value over `Datum::List`s whose second field
+    /// is the value being aggregated. The first field is ignored. Ties are
+    /// broken in favor of the value that sorts first according to `order_by`.
+    Mode {
+        order_by: Vec<ColumnOrder>,
+    },
     RowNumber {
         order_by: Vec<ColumnOrder>,
     },
@@ -1298,6 +2519,17 @@ pub enum AggregateFunc {
     DenseRank {
         order_by: Vec<ColumnOrder>,
     },
+    PercentRank {
+        order_by: Vec<ColumnOrder>,
+    },
+    CumeDist {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Splits each window partition into as equal as possible buckets. The
+    /// number of buckets is taken from the first row of the partition.
+    Ntile {
+        order_by: Vec<ColumnOrder>,
+    },
     LagLead {
         order_by: Vec<ColumnOrder>,
         lag_lead: LagLeadType,
@@ -1311,10 +2543,16 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    NthValue {
+        order_by: Vec<ColumnOrder>,
+        window_frame: WindowFrame,
+    },
     WindowAggregate {
         wrapped_aggregate: Box<AggregateFunc>,
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
+        /// Whether the wrapped aggregate should only see the distinct values of each frame.
+        distinct: bool,
     },
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
@@ -1350,6 +2588,9 @@ impl Arbitrary for AggregateFunc {
             Just(AggregateFunc::MaxFloat64).boxed(),
             Just(AggregateFunc::MaxBool).boxed(),
             Just(AggregateFunc::MaxString).boxed(),
+            Collation::arbitrary()
+                .prop_map(|collation| AggregateFunc::MaxStringCollated { collation })
+                .boxed(),
             Just(AggregateFunc::MaxTimestamp).boxed(),
             Just(AggregateFunc::MaxDate).boxed(),
             Just(AggregateFunc::MaxTimestampTz).boxed(),
@@ -1366,6 +2607,9 @@ impl Arbitrary for AggregateFunc {
             Just(AggregateFunc::MinFloat64).boxed(),
             Just(AggregateFunc::MinBool).boxed(),
             Just(AggregateFunc::MinString).boxed(),
+            Collation::arbitrary()
+                .prop_map(|collation| AggregateFunc::MinStringCollated { collation })
+                .boxed(),
             Just(AggregateFunc::MinDate).boxed(),
             Just(AggregateFunc::MinTimestamp).boxed(),
             Just(AggregateFunc::MinTimestampTz).boxed(),
@@ -1382,6 +2626,16 @@ impl Arbitrary for AggregateFunc {
             Just(AggregateFunc::Count).boxed(),
             Just(AggregateFunc::Any).boxed(),
             Just(AggregateFunc::All).boxed(),
+            Just(AggregateFunc::HllMerge).boxed(),
+            proptest_any::<VarianceFunc>()
+                .prop_map(|func| AggregateFunc::VarianceNumeric { func })
+                .boxed(),
+            proptest_any::<VarianceFunc>()
+                .prop_map(|func| AggregateFunc::VarianceFloat64 { func })
+                .boxed(),
+            proptest_any::<RegressionFunc>()
+                .prop_map(|func| AggregateFunc::Regression { func })
+                .boxed(),
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::JsonbAgg { order_by })
                 .boxed(),
@@ -1397,12 +2651,36 @@ impl Arbitrary for AggregateFunc {
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::StringAgg { order_by })
                 .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::PercentileCont { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::PercentileContMulti { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::PercentileDisc { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::PercentileDiscMulti { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::Mode { order_by })
+                .boxed(),
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::RowNumber { order_by })
                 .boxed(),
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::DenseRank { order_by })
                 .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::PercentRank { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::CumeDist { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::Ntile { order_by })
+                .boxed(),
             (
                 vec(proptest_any::<ColumnOrder>(), 1..4),
                 proptest_any::<LagLeadType>(),
@@ -1434,6 +2712,15 @@ impl Arbitrary for AggregateFunc {
                     window_frame,
                 })
                 .boxed(),
+            (
+                vec(proptest_any::<ColumnOrder>(), 1..4),
+                proptest_any::<WindowFrame>(),
+            )
+                .prop_map(|(order_by, window_frame)| AggregateFunc::NthValue {
+                    order_by,
+                    window_frame,
+                })
+                .boxed(),
             Just(AggregateFunc::Dummy).boxed(),
         ])
     }
@@ -1451,6 +2738,76 @@ impl RustType<ProtoColumnOrders> for Vec<ColumnOrder> {
     }
 }
 
+impl RustType<ProtoVarianceFunc> for VarianceFunc {
+    fn into_proto(&self) -> ProtoVarianceFunc {
+        use proto_aggregate_func::proto_variance_func::Kind;
+        ProtoVarianceFunc {
+            kind: Some(match self {
+                VarianceFunc::VarPop => Kind::VarPop(()),
+                VarianceFunc::VarSamp => Kind::VarSamp(()),
+                VarianceFunc::StddevPop => Kind::StddevPop(()),
+                VarianceFunc::StddevSamp => Kind::StddevSamp(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoVarianceFunc) -> Result<Self, TryFromProtoError> {
+        use proto_aggregate_func::proto_variance_func::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoVarianceFunc::kind"))?;
+        Ok(match kind {
+            Kind::VarPop(()) => VarianceFunc::VarPop,
+            Kind::VarSamp(()) => VarianceFunc::VarSamp,
+            Kind::StddevPop(()) => VarianceFunc::StddevPop,
+            Kind::StddevSamp(()) => VarianceFunc::StddevSamp,
+        })
+    }
+}
+
+impl RustType<ProtoRegressionFunc> for RegressionFunc {
+    fn into_proto(&self) -> ProtoRegressionFunc {
+        use proto_aggregate_func::proto_regression_func::Kind;
+        ProtoRegressionFunc {
+            kind: Some(match self {
+                RegressionFunc::Corr => Kind::Corr(()),
+                RegressionFunc::CovarPop => Kind::CovarPop(()),
+                RegressionFunc::CovarSamp => Kind::CovarSamp(()),
+                RegressionFunc::RegrAvgX => Kind::RegrAvgX(()),
+                RegressionFunc::RegrAvgY => Kind::RegrAvgY(()),
+                RegressionFunc::RegrCount => Kind::RegrCount(()),
+                RegressionFunc::RegrIntercept => Kind::RegrIntercept(()),
+                RegressionFunc::RegrR2 => Kind::RegrR2(()),
+                RegressionFunc::RegrSlope => Kind::RegrSlope(()),
+                RegressionFunc::RegrSxx => Kind::RegrSxx(()),
+                RegressionFunc::RegrSxy => Kind::RegrSxy(()),
+                RegressionFunc::RegrSyy => Kind::RegrSyy(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoRegressionFunc) -> Result<Self, TryFromProtoError> {
+        use proto_aggregate_func::proto_regression_func::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoRegressionFunc::kind"))?;
+        Ok(match kind {
+            Kind::Corr(()) => RegressionFunc::Corr,
+            Kind::CovarPop(()) => RegressionFunc::CovarPop,
+            Kind::CovarSamp(()) => RegressionFunc::CovarSamp,
+            Kind::RegrAvgX(()) => RegressionFunc::RegrAvgX,
+            Kind::RegrAvgY(()) => RegressionFunc::RegrAvgY,
+            Kind::RegrCount(()) => RegressionFunc::RegrCount,
+            Kind::RegrIntercept(()) => RegressionFunc::RegrIntercept,
+            Kind::RegrR2(()) => RegressionFunc::RegrR2,
+            Kind::RegrSlope(()) => RegressionFunc::RegrSlope,
+            Kind::RegrSxx(()) => RegressionFunc::RegrSxx,
+            Kind::RegrSxy(()) => RegressionFunc::RegrSxy,
+            Kind::RegrSyy(()) => RegressionFunc::RegrSyy,
+        })
+    }
+}
+
 impl RustType<ProtoAggregateFunc> for AggregateFunc {
     fn into_proto(&self) -> ProtoAggregateFunc {
         use proto_aggregate_func::Kind;
@@ -1468,6 +2825,9 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                 AggregateFunc::MaxFloat64 => Kind::MaxFloat64(()),
                 AggregateFunc::MaxBool => Kind::MaxBool(()),
                 AggregateFunc::MaxString => Kind::MaxString(()),
+                AggregateFunc::MaxStringCollated { collation } => {
+                    Kind::MaxStringCollated(collation.into_proto())
+                }
                 AggregateFunc::MaxDate => Kind::MaxDate(()),
                 AggregateFunc::MaxTimestamp => Kind::MaxTimestamp(()),
                 AggregateFunc::MaxTimestampTz => Kind::MaxTimestampTz(()),
@@ -1484,6 +2844,9 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                 AggregateFunc::MinFloat64 => Kind::MinFloat64(()),
                 AggregateFunc::MinBool => Kind::MinBool(()),
                 AggregateFunc::MinString => Kind::MinString(()),
+                AggregateFunc::MinStringCollated { collation } => {
+                    Kind::MinStringCollated(collation.into_proto())
+                }
                 AggregateFunc::MinDate => Kind::MinDate(()),
                 AggregateFunc::MinTimestamp => Kind::MinTimestamp(()),
                 AggregateFunc::MinTimestampTz => Kind::MinTimestampTz(()),
@@ -1500,6 +2863,10 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                 AggregateFunc::Count => Kind::Count(()),
                 AggregateFunc::Any => Kind::Any(()),
                 AggregateFunc::All => Kind::All(()),
+                AggregateFunc::VarianceNumeric { func } => Kind::VarianceNumeric(func.into_proto()),
+                AggregateFunc::VarianceFloat64 { func } => Kind::VarianceFloat64(func.into_proto()),
+                AggregateFunc::Regression { func } => Kind::Regression(func.into_proto()),
+                AggregateFunc::HllMerge => Kind::HllMerge(()),
                 AggregateFunc::JsonbAgg { order_by } => Kind::JsonbAgg(order_by.into_proto()),
                 AggregateFunc::JsonbObjectAgg { order_by } => {
                     Kind::JsonbObjectAgg(order_by.into_proto())
@@ -1507,9 +2874,25 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                 AggregateFunc::ArrayConcat { order_by } => Kind::ArrayConcat(order_by.into_proto()),
                 AggregateFunc::ListConcat { order_by } => Kind::ListConcat(order_by.into_proto()),
                 AggregateFunc::StringAgg { order_by } => Kind::StringAgg(order_by.into_proto()),
+                AggregateFunc::PercentileCont { order_by } => {
+                    Kind::PercentileCont(order_by.into_proto())
+                }
+                AggregateFunc::PercentileContMulti { order_by } => {
+                    Kind::PercentileContMulti(order_by.into_proto())
+                }
+                AggregateFunc::PercentileDisc { order_by } => {
+                    Kind::PercentileDisc(order_by.into_proto())
+                }
+                AggregateFunc::PercentileDiscMulti { order_by } => {
+                    Kind::PercentileDiscMulti(order_by.into_proto())
+                }
+                AggregateFunc::Mode { order_by } => Kind::Mode(order_by.into_proto()),
                 AggregateFunc::RowNumber { order_by } => Kind::RowNumber(order_by.into_proto()),
                 AggregateFunc::Rank { order_by } => Kind::Rank(order_by.into_proto()),
                 AggregateFunc::DenseRank { order_by } => Kind::DenseRank(order_by.into_proto()),
+                AggregateFunc::PercentRank { order_by } => Kind::PercentRank(order_by.into_proto()),
+                AggregateFunc::CumeDist { order_by } => Kind::CumeDist(order_by.into_proto()),
+                AggregateFunc::Ntile { order_by } => Kind::Ntile(order_by.into_proto()),
                 AggregateFunc::LagLead {
                     order_by,
                     lag_lead,
@@ -1538,14 +2921,23 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     order_by: Some(order_by.into_proto()),
                     window_frame: Some(window_frame.into_proto()),
                 }),
+                AggregateFunc::NthValue {
+                    order_by,
+                    window_frame,
+                } => Kind::NthValue(proto_aggregate_func::ProtoFramedWindowFunc {
+                    order_by: Some(order_by.into_proto()),
+                    window_frame: Some(window_frame.into_proto()),
+                }),
                 AggregateFunc::WindowAggregate {
                     wrapped_aggregate,
                     order_by,
                     window_frame,
+                    distinct,
                 } => Kind::WindowAggregate(Box::new(proto_aggregate_func::ProtoWindowAggregate {
                     wrapped_aggregate: Some(wrapped_aggregate.into_proto()),
                     order_by: Some(order_by.into_proto()),
                     window_frame: Some(window_frame.into_proto()),
+                    distinct: *distinct,
                 })),
                 AggregateFunc::Dummy => Kind::Dummy(()),
             }),
@@ -1570,6 +2962,9 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
             Kind::MaxFloat64(()) => AggregateFunc::MaxFloat64,
             Kind::MaxBool(()) => AggregateFunc::MaxBool,
             Kind::MaxString(()) => AggregateFunc::MaxString,
+            Kind::MaxStringCollated(collation) => AggregateFunc::MaxStringCollated {
+                collation: collation.into_rust()?,
+            },
             Kind::MaxDate(()) => AggregateFunc::MaxDate,
             Kind::MaxTimestamp(()) => AggregateFunc::MaxTimestamp,
             Kind::MaxTimestampTz(()) => AggregateFunc::MaxTimestampTz,
@@ -1586,6 +2981,9 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
             Kind::MinFloat64(()) => AggregateFunc::MinFloat64,
             Kind::MinBool(()) => AggregateFunc::MinBool,
             Kind::MinString(()) => AggregateFunc::MinString,
+            Kind::MinStringCollated(collation) => AggregateFunc::MinStringCollated {
+                collation: collation.into_rust()?,
+            },
             Kind::MinDate(()) => AggregateFunc::MinDate,
             Kind::MinTimestamp(()) => AggregateFunc::MinTimestamp,
             Kind::MinTimestampTz(()) => AggregateFunc::MinTimestampTz,
@@ -1602,6 +3000,16 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
             Kind::Count(()) => AggregateFunc::Count,
             Kind::Any(()) => AggregateFunc::Any,
             Kind::All(()) => AggregateFunc::All,
+            Kind::HllMerge(()) => AggregateFunc::HllMerge,
+            Kind::VarianceNumeric(func) => AggregateFunc::VarianceNumeric {
+                func: func.into_rust()?,
+            },
+            Kind::VarianceFloat64(func) => AggregateFunc::VarianceFloat64 {
+                func: func.into_rust()?,
+            },
+            Kind::Regression(func) => AggregateFunc::Regression {
+                func: func.into_rust()?,
+            },
             Kind::JsonbAgg(order_by) => AggregateFunc::JsonbAgg {
                 order_by: order_by.into_rust()?,
             },
@@ -1617,6 +3025,21 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
             Kind::StringAgg(order_by) => AggregateFunc::StringAgg {
                 order_by: order_by.into_rust()?,
             },
+            Kind::PercentileCont(order_by) => AggregateFunc::PercentileCont {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::PercentileContMulti(order_by) => AggregateFunc::PercentileContMulti {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::PercentileDisc(order_by) => AggregateFunc::PercentileDisc {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::PercentileDiscMulti(order_by) => AggregateFunc::PercentileDiscMulti {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::Mode(order_by) => AggregateFunc::Mode {
+                order_by: order_by.into_rust()?,
+            },
             Kind::RowNumber(order_by) => AggregateFunc::RowNumber {
                 order_by: order_by.into_rust()?,
             },
@@ -1626,6 +3049,15 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
             Kind::DenseRank(order_by) => AggregateFunc::DenseRank {
                 order_by: order_by.into_rust()?,
             },
+            Kind::PercentRank(order_by) => AggregateFunc::PercentRank {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::CumeDist(order_by) => AggregateFunc::CumeDist {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::Ntile(order_by) => AggregateFunc::Ntile {
+                order_by: order_by.into_rust()?,
+            },
             Kind::LagLead(pll) => AggregateFunc::LagLead {
                 order_by: pll.order_by.into_rust_if_some("ProtoLagLead::order_by")?,
                 lag_lead: match pll.lag_lead {
@@ -1659,6 +3091,14 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     .window_frame
                     .into_rust_if_some("ProtoFramedWindowFunc::window_frame")?,
             },
+            Kind::NthValue(pfv) => AggregateFunc::NthValue {
+                order_by: pfv
+                    .order_by
+                    .into_rust_if_some("ProtoFramedWindowFunc::order_by")?,
+                window_frame: pfv
+                    .window_frame
+                    .into_rust_if_some("ProtoFramedWindowFunc::window_frame")?,
+            },
             Kind::WindowAggregate(paf) => AggregateFunc::WindowAggregate {
                 wrapped_aggregate: paf
                     .wrapped_aggregate
@@ -1669,6 +3109,7 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                 window_frame: paf
                     .window_frame
                     .into_rust_if_some("ProtoWindowAggregate::window_frame")?,
+                distinct: paf.distinct,
             },
             Kind::Dummy(()) => AggregateFunc::Dummy,
         })
@@ -1695,6 +3136,9 @@ impl AggregateFunc {
             AggregateFunc::MaxFloat64 => max_datum::<'a, I, OrderedFloat<f64>>(datums),
             AggregateFunc::MaxBool => max_datum::<'a, I, bool>(datums),
             AggregateFunc::MaxString => max_string(datums),
+            AggregateFunc::MaxStringCollated { collation } => {
+                max_string_collated(datums, *collation)
+            }
             AggregateFunc::MaxDate => max_datum::<'a, I, Date>(datums),
             AggregateFunc::MaxTimestamp => {
                 max_datum::<'a, I, CheckedTimestamp<NaiveDateTime>>(datums)
@@ -1717,6 +3161,9 @@ impl AggregateFunc {
             AggregateFunc::MinFloat64 => min_datum::<'a, I, OrderedFloat<f64>>(datums),
             AggregateFunc::MinBool => min_datum::<'a, I, bool>(datums),
             AggregateFunc::MinString => min_string(datums),
+            AggregateFunc::MinStringCollated { collation } => {
+                min_string_collated(datums, *collation)
+            }
             AggregateFunc::MinDate => min_datum::<'a, I, Date>(datums),
             AggregateFunc::MinTimestamp => {
                 min_datum::<'a, I, CheckedTimestamp<NaiveDateTime>>(datums)
@@ -1737,6 +3184,16 @@ impl AggregateFunc {
             AggregateFunc::Count => count(datums),
             AggregateFunc::Any => any(datums),
             AggregateFunc::All => all(datums),
+            AggregateFunc::VarianceNumeric { .. }
+            | AggregateFunc::VarianceFloat64 { .. }
+            | AggregateFunc::Regression { .. } => {
+                let mut moments = StatisticalMoments::zero();
+                for datum in datums {
+                    moments.plus_equals(&StatisticalMoments::from_datum(self, datum));
+                }
+                moments.finalize(self)
+            }
+            AggregateFunc::HllMerge => hll_merge(datums, temp_storage),
             AggregateFunc::JsonbAgg { order_by } => jsonb_agg(datums, temp_storage, order_by),
             AggregateFunc::JsonbObjectAgg { order_by } => {
                 jsonb_object_agg(datums, temp_storage, order_by)
@@ -1744,9 +3201,20 @@ impl AggregateFunc {
             AggregateFunc::ArrayConcat { order_by } => array_concat(datums, temp_storage, order_by),
             AggregateFunc::ListConcat { order_by } => list_concat(datums, temp_storage, order_by),
             AggregateFunc::StringAgg { order_by } => string_agg(datums, temp_storage, order_by),
+            AggregateFunc::PercentileCont { order_by }
+            | AggregateFunc::PercentileContMulti { order_by }
+            | AggregateFunc::PercentileDisc { order_by }
+            | AggregateFunc::PercentileDiscMulti { order_by }
+            | AggregateFunc::Mode { order_by } => {
+                let (direct_arg, sorted_values) = order_ordered_set_datums(datums, order_by);
+                self.eval_ordered_set(direct_arg, &sorted_values, temp_storage)
+            }
             AggregateFunc::RowNumber { order_by } => row_number(datums, temp_storage, order_by),
             AggregateFunc::Rank { order_by } => rank(datums, temp_storage, order_by),
             AggregateFunc::DenseRank { order_by } => dense_rank(datums, temp_storage, order_by),
+            AggregateFunc::PercentRank { order_by } => percent_rank(datums, temp_storage, order_by),
+            AggregateFunc::CumeDist { order_by } => cume_dist(datums, temp_storage, order_by),
+            AggregateFunc::Ntile { order_by } => ntile(datums, temp_storage, order_by),
             AggregateFunc::LagLead {
                 order_by,
                 lag_lead: lag_lead_type,
@@ -1760,21 +3228,151 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => nth_value(datums, temp_storage, order_by, window_frame),
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate,
                 order_by,
                 window_frame,
+                distinct,
             } => window_aggr::<_, NaiveOneByOneAggr>(
                 datums,
                 temp_storage,
                 wrapped_aggregate,
                 order_by,
                 window_frame,
+                *distinct,
             ),
             AggregateFunc::Dummy => Datum::Dummy,
         }
     }
 
+    /// If `self` is an ordered-set aggregate, returns the ordering of the
+    /// values it aggregates. The input of an ordered-set aggregate is a
+    /// `(direct_arg, value)` record, and the ordering refers to `value` as
+    /// column 0.
+    pub fn ordered_set_order_by(&self) -> Option<&[ColumnOrder]> {
+        match self {
+            AggregateFunc::PercentileCont { order_by }
+            | AggregateFunc::PercentileContMulti { order_by }
+            | AggregateFunc::PercentileDisc { order_by }
+            | AggregateFunc::PercentileDiscMulti { order_by }
+            | AggregateFunc::Mode { order_by } => Some(order_by),
+            _ => None,
+        }
+    }
+
+    /// Evaluates an ordered-set aggregate, given its direct argument and its
+    /// non-null values, sorted according to `ordered_set_order_by` and paired
+    /// with their (positive) multiplicities. Equal values must be adjacent,
+    /// but need not be merged.
+    ///
+    /// Panics if `self` is not an ordered-set aggregate.
+    pub fn eval_ordered_set<'a>(
+        &self,
+        direct_arg: Datum<'a>,
+        sorted_values: &[(Datum<'a>, usize)],
+        temp_storage: &'a RowArena,
+    ) -> Datum<'a> {
+        let total: usize = sorted_values.iter().map(|(_value, count)| count).sum();
+        // `ends[i]` is the number of values up to and including `sorted_values[i]`.
+        let ends: Vec<usize> = sorted_values
+            .iter()
+            .scan(0, |end, (_value, count)| {
+                *end += count;
+                Some(*end)
+            })
+            .collect();
+        let nth = |n: usize| sorted_values[ends.partition_point(|end| *end <= n)].0;
+        self.eval_ordered_set_with(direct_arg, total, nth, || mode(sorted_values), temp_storage)
+    }
+
+    /// Returns the positions in the sort order of the values that an
+    /// ordered-set aggregate over `total` values needs to know to produce its
+    /// result, given its direct argument. `mode` needs no positions, but the
+    /// most frequent value instead.
+    ///
+    /// Panics if `self` is not an ordered-set aggregate.
+    pub fn ordered_set_positions(&self, direct_arg: Datum, total: usize) -> Vec<usize> {
+        if total == 0 || direct_arg.is_null() {
+            return vec![];
+        }
+        let fractions = || {
+            direct_arg
+                .unwrap_array()
+                .elements()
+                .iter()
+                .filter(|fraction| !fraction.is_null())
+                .map(|fraction| fraction.unwrap_float64())
+        };
+        match self {
+            AggregateFunc::PercentileCont { .. } => {
+                let (_pos, lo, hi) = percentile_cont_positions(direct_arg.unwrap_float64(), total);
+                vec![lo, hi]
+            }
+            AggregateFunc::PercentileContMulti { .. } => fractions()
+                .flat_map(|fraction| {
+                    let (_pos, lo, hi) = percentile_cont_positions(fraction, total);
+                    [lo, hi]
+                })
+                .collect(),
+            AggregateFunc::PercentileDisc { .. } => {
+                vec![percentile_disc_position(direct_arg.unwrap_float64(), total)]
+            }
+            AggregateFunc::PercentileDiscMulti { .. } => fractions()
+                .map(|fraction| percentile_disc_position(fraction, total))
+                .collect(),
+            AggregateFunc::Mode { .. } => vec![],
+            _ => panic!("{self:?} is not an ordered-set aggregate"),
+        }
+    }
+
+    /// Evaluates an ordered-set aggregate over `total` values, given its
+    /// direct argument, `nth`, which returns the value at one of the
+    /// positions returned by `ordered_set_positions`, and `mode`, which
+    /// returns the most frequent value, preferring the value that sorts first
+    /// among equally frequent values.
+    ///
+    /// Panics if `self` is not an ordered-set aggregate.
+    pub fn eval_ordered_set_with<'a, N, M>(
+        &self,
+        direct_arg: Datum<'a>,
+        total: usize,
+        nth: N,
+        mode: M,
+        temp_storage: &'a RowArena,
+    ) -> Datum<'a>
+    where
+        N: Fn(usize) -> Datum<'a>,
+        M: FnOnce() -> Datum<'a>,
+    {
+        if total == 0 || direct_arg.is_null() {
+            return Datum::Null;
+        }
+        match self {
+            AggregateFunc::PercentileCont { .. } => {
+                Datum::from(percentile_cont(direct_arg.unwrap_float64(), &nth, total))
+            }
+            AggregateFunc::PercentileContMulti { .. } => {
+                percentile_multi(direct_arg, temp_storage, |fraction| {
+                    Datum::from(percentile_cont(fraction, &nth, total))
+                })
+            }
+            AggregateFunc::PercentileDisc { .. } => {
+                percentile_disc(direct_arg.unwrap_float64(), &nth, total)
+            }
+            AggregateFunc::PercentileDiscMulti { .. } => {
+                percentile_multi(direct_arg, temp_storage, |fraction| {
+                    percentile_disc(fraction, &nth, total)
+                })
+            }
+            AggregateFunc::Mode { .. } => mode(),
+            _ => panic!("{self:?} is not an ordered-set aggregate"),
+        }
+    }
+
     /// Like `eval`, but it's given a [OneByOneAggr]. If `self` is a `WindowAggregate`, then
     /// the given [OneByOneAggr] will be used to evaluate the wrapped aggregate inside the
     /// `WindowAggregate`. If `self` is not a `WindowAggregate`, then it simply calls `eval`.
@@ -1788,12 +3386,14 @@ impl AggregateFunc {
                 wrapped_aggregate,
                 order_by,
                 window_frame,
+                distinct,
             } => window_aggr::<_, W>(
                 datums,
                 temp_storage,
                 wrapped_aggregate,
                 order_by,
                 window_frame,
+                *distinct,
             ),
             _ => self.eval(datums, temp_storage),
         }
@@ -1804,6 +3404,9 @@ impl AggregateFunc {
     pub fn default(&self) -> Datum<'static> {
         match self {
             AggregateFunc::Count => Datum::Int64(0),
+            AggregateFunc::Regression {
+                func: RegressionFunc::RegrCount,
+            } => Datum::Int64(0),
             AggregateFunc::Any => Datum::False,
             AggregateFunc::All => Datum::True,
             AggregateFunc::Dummy => Datum::Dummy,
@@ -1823,9 +3426,13 @@ impl AggregateFunc {
             AggregateFunc::RowNumber { .. } => Datum::empty_list(),
             AggregateFunc::Rank { .. } => Datum::empty_list(),
             AggregateFunc::DenseRank { .. } => Datum::empty_list(),
+            AggregateFunc::PercentRank { .. } => Datum::empty_list(),
+            AggregateFunc::CumeDist { .. } => Datum::empty_list(),
+            AggregateFunc::Ntile { .. } => Datum::empty_list(),
             AggregateFunc::LagLead { .. } => Datum::empty_list(),
             AggregateFunc::FirstValue { .. } => Datum::empty_list(),
             AggregateFunc::LastValue { .. } => Datum::empty_list(),
+            AggregateFunc::NthValue { .. } => Datum::empty_list(),
             AggregateFunc::WindowAggregate { .. } => Datum::empty_list(),
             _ => Datum::Null,
         }
@@ -1841,6 +3448,13 @@ impl AggregateFunc {
             AggregateFunc::Count => ScalarType::Int64,
             AggregateFunc::Any => ScalarType::Bool,
             AggregateFunc::All => ScalarType::Bool,
+            AggregateFunc::VarianceNumeric { .. } => ScalarType::Numeric { max_scale: None },
+            AggregateFunc::VarianceFloat64 { .. } => ScalarType::Float64,
+            AggregateFunc::Regression {
+                func: RegressionFunc::RegrCount,
+            } => ScalarType::Int64,
+            AggregateFunc::Regression { .. } => ScalarType::Float64,
+            AggregateFunc::HllMerge => ScalarType::Hll,
             AggregateFunc::JsonbAgg { .. } => ScalarType::Jsonb,
             AggregateFunc::JsonbObjectAgg { .. } => ScalarType::Jsonb,
             AggregateFunc::SumInt16 => ScalarType::Int64,
@@ -1861,14 +3475,62 @@ impl AggregateFunc {
                 }
             }
             AggregateFunc::StringAgg { .. } => ScalarType::String,
-            AggregateFunc::RowNumber { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?row_number?")
+            AggregateFunc::PercentileCont { .. } => ScalarType::Float64,
+            AggregateFunc::PercentileContMulti { .. } => {
+                ScalarType::Array(Box::new(ScalarType::Float64))
             }
-            AggregateFunc::Rank { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?rank?")
+            // The input is a `(direct_arg, value)` record, so extract the value.
+            AggregateFunc::PercentileDisc { .. } | AggregateFunc::Mode { .. } => {
+                input_type.scalar_type.unwrap_record_element_type()[1].clone()
             }
-            AggregateFunc::DenseRank { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?dense_rank?")
+            AggregateFunc::PercentileDiscMulti { .. } => ScalarType::Array(Box::new(
+                input_type.scalar_type.unwrap_record_element_type()[1].clone(),
+            )),
+            AggregateFunc::RowNumber { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?row_number?",
+                ScalarType::Int64,
+            ),
+            AggregateFunc::Rank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?rank?",
+                ScalarType::Int64,
+            ),
+            AggregateFunc::DenseRank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?dense_rank?",
+                ScalarType::Int64,
+            ),
+            AggregateFunc::PercentRank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?percent_rank?",
+                ScalarType::Float64,
+            ),
+            AggregateFunc::CumeDist { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?cume_dist?",
+                ScalarType::Float64,
+            ),
+            AggregateFunc::Ntile { .. } => {
+                // The input type for Ntile is ((OriginalRow, NumBuckets), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Record {
+                        fields: vec![
+                            (
+                                ColumnName::from("?ntile?"),
+                                ScalarType::Int32.nullable(true),
+                            ),
+                            (ColumnName::from("?record?"), original_row_type),
+                        ],
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
             }
             AggregateFunc::LagLead { lag_lead, .. } => {
                 // The input type for Lag is a ((OriginalRow, EncodedArgs), OrderByExprs...)
@@ -1938,6 +3600,28 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::NthValue { .. } => {
+                // The input type for NthValue is ((OriginalRow, (InputValue, N)), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let value_type = fields[0].unwrap_record_element_type()[1]
+                    .unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(true);
+
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Record {
+                        fields: vec![
+                            (ColumnName::from("?nth_value?"), value_type),
+                            (ColumnName::from("?record?"), original_row_type),
+                        ],
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate, ..
             } => {
@@ -1970,7 +3654,22 @@ impl AggregateFunc {
         // Count never produces null, and other aggregations only produce
         // null in the presence of null inputs.
         let nullable = match self {
-            AggregateFunc::Count => false,
+            AggregateFunc::Count
+            | AggregateFunc::Regression {
+                func: RegressionFunc::RegrCount,
+            } => false,
+            // Statistical aggregates produce null when there are too few
+            // inputs to compute the statistic from.
+            AggregateFunc::VarianceNumeric { .. }
+            | AggregateFunc::VarianceFloat64 { .. }
+            | AggregateFunc::Regression { .. } => true,
+            // Ordered-set aggregates produce null when all their values are
+            // null, even though the records wrapping the values are not null.
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileContMulti { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::PercentileDiscMulti { .. }
+            | AggregateFunc::Mode { .. } => true,
             // Use the nullability of the underlying column being aggregated, not the Records wrapping it
             AggregateFunc::StringAgg { .. } => match input_type.scalar_type {
                 // The outer Record wraps the input in the first position, and any ORDER BY expressions afterwards
@@ -1986,16 +3685,17 @@ impl AggregateFunc {
         scalar_type.nullable(nullable)
     }
 
-    /// Compute output type for ROW_NUMBER, RANK, DENSE_RANK
-    fn output_type_ranking_window_funcs(input_type: &ColumnType, col_name: &str) -> ScalarType {
+    /// Compute output type for ROW_NUMBER, RANK, DENSE_RANK, PERCENT_RANK, CUME_DIST
+    fn output_type_ranking_window_funcs(
+        input_type: &ColumnType,
+        col_name: &str,
+        rank_type: ScalarType,
+    ) -> ScalarType {
         match input_type.scalar_type {
             ScalarType::Record { ref fields, .. } => ScalarType::List {
                 element_type: Box::new(ScalarType::Record {
                     fields: vec![
-                        (
-                            ColumnName::from(col_name),
-                            ScalarType::Int64.nullable(false),
-                        ),
+                        (ColumnName::from(col_name), rank_type.nullable(false)),
                         (ColumnName::from("?record?"), {
                             let inner = match &fields[0].1.scalar_type {
                                 ScalarType::List { element_type, .. } => element_type.clone(),
@@ -2030,6 +3730,7 @@ impl AggregateFunc {
             | AggregateFunc::MaxFloat64
             | AggregateFunc::MaxBool
             | AggregateFunc::MaxString
+            | AggregateFunc::MaxStringCollated { .. }
             | AggregateFunc::MaxDate
             | AggregateFunc::MaxTimestamp
             | AggregateFunc::MaxTimestampTz
@@ -2045,6 +3746,7 @@ impl AggregateFunc {
             | AggregateFunc::MinFloat64
             | AggregateFunc::MinBool
             | AggregateFunc::MinString
+            | AggregateFunc::MinStringCollated { .. }
             | AggregateFunc::MinDate
             | AggregateFunc::MinTimestamp
             | AggregateFunc::MinTimestampTz
@@ -2057,6 +3759,8 @@ impl AggregateFunc {
             | AggregateFunc::SumFloat32
             | AggregateFunc::SumFloat64
             | AggregateFunc::SumNumeric
+            | AggregateFunc::VarianceNumeric { .. }
+            | AggregateFunc::VarianceFloat64 { .. }
             | AggregateFunc::StringAgg { .. } => true,
             // Count is never null
             AggregateFunc::Count => false,
@@ -2265,6 +3969,9 @@ where
             AggregateFunc::MaxFloat64 => f.write_str("max"),
             AggregateFunc::MaxBool => f.write_str("max"),
             AggregateFunc::MaxString => f.write_str("max"),
+            AggregateFunc::MaxStringCollated { collation } => {
+                write!(f, "max[collation={}]", collation)
+            }
             AggregateFunc::MaxDate => f.write_str("max"),
             AggregateFunc::MaxTimestamp => f.write_str("max"),
             AggregateFunc::MaxTimestampTz => f.write_str("max"),
@@ -2281,6 +3988,9 @@ where
             AggregateFunc::MinFloat64 => f.write_str("min"),
             AggregateFunc::MinBool => f.write_str("min"),
             AggregateFunc::MinString => f.write_str("min"),
+            AggregateFunc::MinStringCollated { collation } => {
+                write!(f, "min[collation={}]", collation)
+            }
             AggregateFunc::MinDate => f.write_str("min"),
             AggregateFunc::MinTimestamp => f.write_str("min"),
             AggregateFunc::MinTimestampTz => f.write_str("min"),
@@ -2297,6 +4007,11 @@ where
             AggregateFunc::Count => f.write_str("count"),
             AggregateFunc::Any => f.write_str("any"),
             AggregateFunc::All => f.write_str("all"),
+            AggregateFunc::VarianceNumeric { func } | AggregateFunc::VarianceFloat64 { func } => {
+                f.write_str(func.name())
+            }
+            AggregateFunc::Regression { func } => f.write_str(func.name()),
+            AggregateFunc::HllMerge => f.write_str("hll_merge"),
             AggregateFunc::JsonbAgg { order_by } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 write!(f, "jsonb_agg[order_by=[{}]]", separated(", ", order_by))
@@ -2321,6 +4036,42 @@ where
                 let order_by = order_by.iter().map(|col| self.child(col));
                 write!(f, "string_agg[order_by=[{}]]", separated(", ", order_by))
             }
+            AggregateFunc::PercentileCont { order_by } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                write!(
+                    f,
+                    "percentile_cont[order_by=[{}]]",
+                    separated(", ", order_by)
+                )
+            }
+            AggregateFunc::PercentileContMulti { order_by } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                write!(
+                    f,
+                    "percentile_cont_multi[order_by=[{}]]",
+                    separated(", ", order_by)
+                )
+            }
+            AggregateFunc::PercentileDisc { order_by } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                write!(
+                    f,
+                    "percentile_disc[order_by=[{}]]",
+                    separated(", ", order_by)
+                )
+            }
+            AggregateFunc::PercentileDiscMulti { order_by } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                write!(
+                    f,
+                    "percentile_disc_multi[order_by=[{}]]",
+                    separated(", ", order_by)
+                )
+            }
+            AggregateFunc::Mode { order_by } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                write!(f, "mode[order_by=[{}]]", separated(", ", order_by))
+            }
             AggregateFunc::RowNumber { order_by } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 write!(f, "row_number[order_by=[{}]]", separated(", ", order_by))
@@ -2333,6 +4084,18 @@ where
                 let order_by = order_by.iter().map(|col| self.child(col));
                 write!(f, "dense_rank[order_by=[{}]]", separated(", ", order_by))
             }
+            AggregateFunc::PercentRank { order_by } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                write!(f, "percent_rank[order_by=[{}]]", separated(", ", order_by))
+            }
+            AggregateFunc::CumeDist { order_by } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                write!(f, "cume_dist[order_by=[{}]]", separated(", ", order_by))
+            }
+            AggregateFunc::Ntile { order_by } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                write!(f, "ntile[order_by=[{}]]", separated(", ", order_by))
+            }
             AggregateFunc::LagLead {
                 lag_lead: LagLeadType::Lag,
                 ignore_nulls,
@@ -2387,15 +4150,32 @@ where
                 }
                 f.write_str("]")
             }
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => {
+                let order_by = order_by.iter().map(|col| self.child(col));
+                f.write_str("nth_value")?;
+                f.write_str("[")?;
+                write!(f, "order_by=[{}]", separated(", ", order_by))?;
+                if *window_frame != WindowFrame::default() {
+                    write!(f, " {}", window_frame)?;
+                }
+                f.write_str("]")
+            }
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate,
                 order_by,
                 window_frame,
+                distinct,
             } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 let wrapped_aggregate = self.child(wrapped_aggregate.deref());
                 f.write_str("window_agg")?;
                 f.write_str("[")?;
+                if *distinct {
+                    f.write_str("distinct ")?;
+                }
                 write!(f, "{} ", wrapped_aggregate)?;
                 write!(f, "order_by=[{}]", separated(", ", order_by))?;
                 if *window_frame != WindowFrame::default() {
@@ -2571,6 +4351,393 @@ fn mz_acl_explode<'a>(
     Ok(res.into_iter())
 }
 
+/// The plan for a `JSON_TABLE` call, or for one of its `NESTED PATH` clauses.
+///
+/// Each item that `path` selects produces a row containing the value of each
+/// of `columns` for that item.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, MzReflect)]
+pub struct JsonTablePlan {
+    pub path: JsonPath,
+    pub columns: Vec<JsonTableColumn>,
+}
+
+impl Arbitrary for JsonTablePlan {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<JsonTablePlan>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let leaf = Union::new(vec![
+            Just(JsonTableColumn::Ordinality).boxed(),
+            (
+                any::<String>(),
+                any_jsonpath(),
+                any::<ScalarType>(),
+                any::<MirScalarExpr>(),
+                any::<JsonTableBehavior>(),
+                any::<JsonTableBehavior>(),
+            )
+                .prop_map(|(name, path, typ, cast_expr, on_empty, on_error)| {
+                    JsonTableColumn::Regular {
+                        name,
+                        path,
+                        typ,
+                        cast_expr: Box::new(cast_expr),
+                        on_empty,
+                        on_error,
+                    }
+                })
+                .boxed(),
+            (
+                any_jsonpath(),
+                any::<ScalarType>(),
+                any::<MirScalarExpr>(),
+                any::<JsonTableBehavior>(),
+            )
+                .prop_map(|(path, typ, cast_expr, on_error)| JsonTableColumn::Exists {
+                    path,
+                    typ,
+                    cast_expr: Box::new(cast_expr),
+                    on_error,
+                })
+                .boxed(),
+        ]);
+        let column = leaf.prop_recursive(2, 8, 4, |inner| {
+            (any_jsonpath(), proptest::collection::vec(inner, 1..4))
+                .prop_map(|(path, columns)| {
+                    JsonTableColumn::Nested(JsonTablePlan { path, columns })
+                })
+                .boxed()
+        });
+        (any_jsonpath(), proptest::collection::vec(column, 1..4))
+            .prop_map(|(path, columns)| JsonTablePlan { path, columns })
+            .boxed()
+    }
+}
+
+impl RustType<ProtoJsonTablePlan> for JsonTablePlan {
+    fn into_proto(&self) -> ProtoJsonTablePlan {
+        ProtoJsonTablePlan {
+            path: Some(self.path.into_proto()),
+            columns: self.columns.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoJsonTablePlan) -> Result<Self, TryFromProtoError> {
+        Ok(JsonTablePlan {
+            path: proto.path.into_rust_if_some("ProtoJsonTablePlan::path")?,
+            columns: proto.columns.into_rust()?,
+        })
+    }
+}
+
+impl JsonTablePlan {
+    /// Returns the number of columns the plan produces, including those of
+    /// its nested plans.
+    pub fn arity(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|column| match column {
+                JsonTableColumn::Nested(plan) => plan.arity(),
+                _ => 1,
+            })
+            .sum()
+    }
+
+    /// Appends the types of the columns the plan produces to `types`.
+    fn push_column_types(&self, types: &mut Vec<ColumnType>) {
+        for column in &self.columns {
+            match column {
+                JsonTableColumn::Ordinality => types.push(ScalarType::Int32.nullable(true)),
+                JsonTableColumn::Regular { typ, .. } | JsonTableColumn::Exists { typ, .. } => {
+                    types.push(typ.clone().nullable(true))
+                }
+                JsonTableColumn::Nested(plan) => plan.push_column_types(types),
+            }
+        }
+    }
+
+    /// Returns the rows the plan produces for `target`.
+    ///
+    /// Failing to evaluate `path` produces no rows unless `error_on_error` is
+    /// set.
+    fn eval<'a>(
+        &'a self,
+        target: Datum<'a>,
+        vars: Datum<'a>,
+        error_on_error: bool,
+        temp_storage: &'a RowArena,
+    ) -> Result<Vec<Vec<Datum<'a>>>, EvalError> {
+        let items = match jsonb_path_query_inner(target, &self.path, vars, temp_storage) {
+            Ok(items) => items,
+            Err(_) if !error_on_error => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+        let mut rows = vec![];
+        for (i, item) in items.into_iter().enumerate() {
+            let ordinality = i32::try_from(i + 1)
+                .map_err(|_| EvalError::Int32OutOfRange((i + 1).to_string()))?;
+            let mut row = Vec::with_capacity(self.arity());
+            let mut nested = vec![];
+            for column in &self.columns {
+                match column {
+                    JsonTableColumn::Ordinality => row.push(Datum::Int32(ordinality)),
+                    JsonTableColumn::Regular { .. } | JsonTableColumn::Exists { .. } => {
+                        row.push(column.eval(item, vars, temp_storage)?)
+                    }
+                    JsonTableColumn::Nested(plan) => {
+                        nested.push((row.len(), plan));
+                        row.extend(iter::repeat(Datum::Null).take(plan.arity()));
+                    }
+                }
+            }
+            // The rows of sibling nested plans are unioned, with `NULL`s in
+            // the columns of the other siblings. As in an outer join, the item
+            // still produces a row if its nested plans produce none.
+            let n = rows.len();
+            for (offset, plan) in nested {
+                for nested_row in plan.eval(item, vars, error_on_error, temp_storage)? {
+                    let mut row = row.clone();
+                    row[offset..offset + nested_row.len()].copy_from_slice(&nested_row);
+                    rows.push(row);
+                }
+            }
+            if rows.len() == n {
+                rows.push(row);
+            }
+        }
+        Ok(rows)
+    }
+}
+
+/// A column of a [`JsonTablePlan`].
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, MzReflect)]
+pub enum JsonTableColumn {
+    /// The position of the item among those selected by the plan's path,
+    /// starting from 1.
+    Ordinality,
+    /// The single item that `path` selects from the row's item, cast to `typ`.
+    Regular {
+        name: String,
+        path: JsonPath,
+        typ: ScalarType,
+        /// Casts the item to `typ` from `jsonb`, if `typ` is `jsonb`, or
+        /// otherwise from its text representation.
+        cast_expr: Box<MirScalarExpr>,
+        on_empty: JsonTableBehavior,
+        on_error: JsonTableBehavior,
+    },
+    /// Whether `path` selects any items from the row's item, cast to `typ`.
+    Exists {
+        path: JsonPath,
+        typ: ScalarType,
+        /// Casts the result, a `bool`, to `typ`.
+        cast_expr: Box<MirScalarExpr>,
+        /// Produces the `bool` to cast if evaluating `path` fails.
+        on_error: JsonTableBehavior,
+    },
+    /// The columns of a plan evaluated against the row's item.
+    Nested(JsonTablePlan),
+}
+
+impl RustType<ProtoJsonTableColumn> for JsonTableColumn {
+    fn into_proto(&self) -> ProtoJsonTableColumn {
+        use proto_json_table_column::{Kind, ProtoExists, ProtoRegular};
+
+        ProtoJsonTableColumn {
+            kind: Some(match self {
+                JsonTableColumn::Ordinality => Kind::Ordinality(()),
+                JsonTableColumn::Regular {
+                    name,
+                    path,
+                    typ,
+                    cast_expr,
+                    on_empty,
+                    on_error,
+                } => Kind::Regular(ProtoRegular {
+                    name: name.into_proto(),
+                    path: Some(path.into_proto()),
+                    typ: Some(typ.into_proto()),
+                    cast_expr: Some((**cast_expr).into_proto()),
+                    on_empty: Some(on_empty.into_proto()),
+                    on_error: Some(on_error.into_proto()),
+                }),
+                JsonTableColumn::Exists {
+                    path,
+                    typ,
+                    cast_expr,
+                    on_error,
+                } => Kind::Exists(ProtoExists {
+                    path: Some(path.into_proto()),
+                    typ: Some(typ.into_proto()),
+                    cast_expr: Some((**cast_expr).into_proto()),
+                    on_error: Some(on_error.into_proto()),
+                }),
+                JsonTableColumn::Nested(plan) => Kind::Nested(Box::new(plan.into_proto())),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoJsonTableColumn) -> Result<Self, TryFromProtoError> {
+        use proto_json_table_column::Kind;
+
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoJsonTableColumn::kind"))?;
+        Ok(match kind {
+            Kind::Ordinality(()) => JsonTableColumn::Ordinality,
+            Kind::Regular(x) => JsonTableColumn::Regular {
+                name: x.name,
+                path: x.path.into_rust_if_some("ProtoRegular::path")?,
+                typ: x.typ.into_rust_if_some("ProtoRegular::typ")?,
+                cast_expr: Box::new(x.cast_expr.into_rust_if_some("ProtoRegular::cast_expr")?),
+                on_empty: x.on_empty.into_rust_if_some("ProtoRegular::on_empty")?,
+                on_error: x.on_error.into_rust_if_some("ProtoRegular::on_error")?,
+            },
+            Kind::Exists(x) => JsonTableColumn::Exists {
+                path: x.path.into_rust_if_some("ProtoExists::path")?,
+                typ: x.typ.into_rust_if_some("ProtoExists::typ")?,
+                cast_expr: Box::new(x.cast_expr.into_rust_if_some("ProtoExists::cast_expr")?),
+                on_error: x.on_error.into_rust_if_some("ProtoExists::on_error")?,
+            },
+            Kind::Nested(plan) => JsonTableColumn::Nested((*plan).into_rust()?),
+        })
+    }
+}
+
+impl JsonTableColumn {
+    /// Evaluates a regular or `EXISTS` column against the row's item.
+    fn eval<'a>(
+        &'a self,
+        item: Datum<'a>,
+        vars: Datum<'a>,
+        temp_storage: &'a RowArena,
+    ) -> Result<Datum<'a>, EvalError> {
+        match self {
+            JsonTableColumn::Regular {
+                name,
+                path,
+                typ,
+                cast_expr,
+                on_empty,
+                on_error,
+            } => match json_table_value(name, path, typ, cast_expr, item, vars, temp_storage) {
+                Ok(Some(datum)) => Ok(datum),
+                Ok(None) => on_empty.eval(temp_storage, || {
+                    EvalError::JsonPath(format!(
+                        "no SQL/JSON item found for specified path of column \"{}\"",
+                        name
+                    ))
+                }),
+                Err(e) => on_error.eval(temp_storage, || e),
+            },
+            JsonTableColumn::Exists {
+                path,
+                cast_expr,
+                on_error,
+                ..
+            } => {
+                let exists = match jsonb_path_query_inner(item, path, vars, temp_storage) {
+                    Ok(items) => Datum::from(!items.is_empty()),
+                    Err(e) => on_error.eval(temp_storage, || e)?,
+                };
+                cast_expr.eval(&[exists], temp_storage)
+            }
+            JsonTableColumn::Ordinality | JsonTableColumn::Nested(_) => {
+                unreachable!("not evaluated per column")
+            }
+        }
+    }
+}
+
+/// Returns the value of a regular `JSON_TABLE` column, or `None` if `path`
+/// selects no items.
+fn json_table_value<'a>(
+    name: &str,
+    path: &JsonPath,
+    typ: &ScalarType,
+    cast_expr: &'a MirScalarExpr,
+    item: Datum<'a>,
+    vars: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Option<Datum<'a>>, EvalError> {
+    let values = jsonb_path_query_inner(item, path, vars, temp_storage)?;
+    let value = match values[..] {
+        [] => return Ok(None),
+        [value] => value,
+        _ => {
+            return Err(EvalError::JsonPath(format!(
+                "JSON path expression for column \"{}\" must return single item when no wrapper is requested",
+                name
+            )))
+        }
+    };
+    let value = match (typ, value) {
+        (ScalarType::Jsonb, _) => value,
+        (_, Datum::List(_) | Datum::Map(_)) => {
+            return Err(EvalError::JsonPath(format!(
+                "JSON path expression for column \"{}\" must return single scalar item",
+                name
+            )))
+        }
+        _ => jsonb_stringify(value, temp_storage),
+    };
+    cast_expr.eval(&[value], temp_storage).map(Some)
+}
+
+/// What a `JSON_TABLE` column produces when its path selects no items or
+/// fails.
+#[derive(
+    Arbitrary, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub enum JsonTableBehavior {
+    Null,
+    Error,
+    Default(MirScalarExpr),
+}
+
+impl RustType<ProtoJsonTableBehavior> for JsonTableBehavior {
+    fn into_proto(&self) -> ProtoJsonTableBehavior {
+        use proto_json_table_behavior::Kind;
+
+        ProtoJsonTableBehavior {
+            kind: Some(match self {
+                JsonTableBehavior::Null => Kind::Null(()),
+                JsonTableBehavior::Error => Kind::Error(()),
+                JsonTableBehavior::Default(expr) => Kind::DefaultExpr(expr.into_proto()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoJsonTableBehavior) -> Result<Self, TryFromProtoError> {
+        use proto_json_table_behavior::Kind;
+
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoJsonTableBehavior::kind"))?;
+        Ok(match kind {
+            Kind::Null(()) => JsonTableBehavior::Null,
+            Kind::Error(()) => JsonTableBehavior::Error,
+            Kind::DefaultExpr(expr) => JsonTableBehavior::Default(expr.into_rust()?),
+        })
+    }
+}
+
+impl JsonTableBehavior {
+    /// Returns the value this behavior produces, where `error` is the error to
+    /// raise for [`JsonTableBehavior::Error`].
+    fn eval<'a>(
+        &'a self,
+        temp_storage: &'a RowArena,
+        error: impl FnOnce() -> EvalError,
+    ) -> Result<Datum<'a>, EvalError> {
+        match self {
+            JsonTableBehavior::Null => Ok(Datum::Null),
+            JsonTableBehavior::Error => Err(error()),
+            JsonTableBehavior::Default(expr) => expr.eval(&[], temp_storage),
+        }
+    }
+}
+
 #[derive(
     Arbitrary, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, MzReflect,
 )]
@@ -2612,11 +4779,19 @@ pub enum TableFunc {
         name: String,
         relation: RelationType,
     },
+    /// Evaluates a `JSON_TABLE` call against a `jsonb` document and a `jsonb`
+    /// object of path variables.
+    JsonTable {
+        plan: JsonTablePlan,
+        /// Whether failing to evaluate the row path raises an error, rather
+        /// than producing no rows.
+        error_on_error: bool,
+    },
 }
 
 impl RustType<ProtoTableFunc> for TableFunc {
     fn into_proto(&self) -> ProtoTableFunc {
-        use proto_table_func::{Kind, ProtoWrap};
+        use proto_table_func::{Kind, ProtoJsonTable, ProtoWrap};
 
         ProtoTableFunc {
             kind: Some(match self {
@@ -2645,6 +4820,13 @@ impl RustType<ProtoTableFunc> for TableFunc {
                         relation: Some(relation.into_proto()),
                     })
                 }
+                TableFunc::JsonTable {
+                    plan,
+                    error_on_error,
+                } => Kind::JsonTable(ProtoJsonTable {
+                    plan: Some(plan.into_proto()),
+                    error_on_error: *error_on_error,
+                }),
             }),
         }
     }
@@ -2686,6 +4868,10 @@ impl RustType<ProtoTableFunc> for TableFunc {
                     .relation
                     .into_rust_if_some("ProtoTabletizedScalar::relation")?,
             },
+            Kind::JsonTable(v) => TableFunc::JsonTable {
+                plan: v.plan.into_rust_if_some("ProtoJsonTable::plan")?,
+                error_on_error: v.error_on_error,
+            },
         })
     }
 }
@@ -2764,6 +4950,15 @@ impl TableFunc {
                 let r = Row::pack_slice(datums);
                 Ok(Box::new(std::iter::once((r, 1))))
             }
+            TableFunc::JsonTable {
+                plan,
+                error_on_error,
+            } => {
+                let rows = plan.eval(datums[0], datums[1], *error_on_error, temp_storage)?;
+                Ok(Box::new(
+                    rows.into_iter().map(|row| (Row::pack_slice(&row), 1)),
+                ))
+            }
         }
     }
 
@@ -2884,6 +5079,12 @@ impl TableFunc {
             TableFunc::TabletizedScalar { relation, .. } => {
                 return relation.clone();
             }
+            TableFunc::JsonTable { plan, .. } => {
+                let mut column_types = vec![];
+                plan.push_column_types(&mut column_types);
+                let keys = vec![];
+                (column_types, keys)
+            }
         };
 
         if !keys.is_empty() {
@@ -2912,6 +5113,7 @@ impl TableFunc {
             TableFunc::UnnestList { .. } => 1,
             TableFunc::Wrap { width, .. } => *width,
             TableFunc::TabletizedScalar { relation, .. } => relation.column_types.len(),
+            TableFunc::JsonTable { plan, .. } => plan.arity(),
         }
     }
 
@@ -2922,6 +5124,7 @@ impl TableFunc {
             | TableFunc::JsonbEach { .. }
             | TableFunc::JsonbObjectKeys
             | TableFunc::JsonbArrayElements { .. }
+            | TableFunc::JsonTable { .. }
             | TableFunc::GenerateSeriesInt32
             | TableFunc::GenerateSeriesInt64
             | TableFunc::GenerateSeriesTimestamp
@@ -2959,6 +5162,7 @@ impl TableFunc {
             TableFunc::UnnestList { .. } => true,
             TableFunc::Wrap { .. } => true,
             TableFunc::TabletizedScalar { .. } => true,
+            TableFunc::JsonTable { .. } => true,
         }
     }
 }
@@ -2983,6 +5187,7 @@ impl fmt::Display for TableFunc {
             TableFunc::UnnestList { .. } => f.write_str("unnest_list"),
             TableFunc::Wrap { width, .. } => write!(f, "wrap{}", width),
             TableFunc::TabletizedScalar { name, .. } => f.write_str(name),
+            TableFunc::JsonTable { .. } => f.write_str("json_table"),
         }
     }
 }
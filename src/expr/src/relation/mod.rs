@@ -25,6 +25,8 @@ use mz_ore::num::NonNeg;
 use mz_ore::stack::RecursionLimitError;
 use mz_ore::str::Indent;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
+use mz_repr::adt::collation::Collation;
+use mz_repr::adt::interval::Interval;
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::explain::text::text_string_at;
 use mz_repr::explain::{
@@ -2270,6 +2272,10 @@ pub struct ColumnOrder {
     /// Whether to sort nulls last.
     #[serde(default)]
     pub nulls_last: bool,
+    /// The collation with which to compare strings, if they are not to be
+    /// compared bytewise.
+    #[serde(default)]
+    pub collation: Option<Collation>,
 }
 
 impl Columnation for ColumnOrder {
@@ -2282,6 +2288,7 @@ impl RustType<ProtoColumnOrder> for ColumnOrder {
             column: self.column.into_proto(),
             desc: self.desc,
             nulls_last: self.nulls_last,
+            collation: self.collation.into_proto(),
         }
     }
 
@@ -2290,6 +2297,7 @@ impl RustType<ProtoColumnOrder> for ColumnOrder {
             column: proto.column.into_rust()?,
             desc: proto.desc,
             nulls_last: proto.nulls_last,
+            collation: proto.collation.into_rust()?,
         })
     }
 }
@@ -2310,7 +2318,11 @@ where
             } else {
                 "nulls_first"
             },
-        )
+        )?;
+        if let Some(collation) = &self.expr.collation {
+            write!(f, " collate {}", collation)?;
+        }
+        Ok(())
     }
 }
 
@@ -2366,6 +2378,7 @@ impl AggregateExpr {
             | AggregateFunc::MaxFloat64
             | AggregateFunc::MaxBool
             | AggregateFunc::MaxString
+            | AggregateFunc::MaxStringCollated { .. }
             | AggregateFunc::MaxDate
             | AggregateFunc::MaxTimestamp
             | AggregateFunc::MaxTimestampTz
@@ -2380,11 +2393,13 @@ impl AggregateExpr {
             | AggregateFunc::MinFloat64
             | AggregateFunc::MinBool
             | AggregateFunc::MinString
+            | AggregateFunc::MinStringCollated { .. }
             | AggregateFunc::MinDate
             | AggregateFunc::MinTimestamp
             | AggregateFunc::MinTimestampTz
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::HllMerge
             | AggregateFunc::Dummy => self.expr.is_literal(),
             AggregateFunc::Count => self.expr.is_literal_null(),
             _ => self.expr.is_literal_err(),
@@ -2472,19 +2487,117 @@ impl AggregateExpr {
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)))
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
 
+            // Ordered-set aggregates take a `(direct_arg, value)` record, and
+            // over a single value all of them produce that value, unless the
+            // record was filtered out or the direct argument is null.
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => {
+                let direct_arg = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+                let value = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+                direct_arg.call_is_null().if_then_else(
+                    MirScalarExpr::literal_null(self.typ(input_type).scalar_type),
+                    value,
+                )
+            }
+
+            // The multi-fraction ordered-set aggregates and the statistical
+            // aggregates have no scalar equivalent; `ReduceElision` leaves
+            // them alone.
+            AggregateFunc::PercentileContMulti { .. }
+            | AggregateFunc::PercentileDiscMulti { .. }
+            | AggregateFunc::VarianceNumeric { .. }
+            | AggregateFunc::VarianceFloat64 { .. }
+            | AggregateFunc::Regression { .. } => MirScalarExpr::literal(
+                Err(EvalError::Internal(format!(
+                    "{:?} cannot be evaluated on a unique input",
+                    self.func
+                ))),
+                self.typ(input_type).scalar_type,
+            ),
+
             // ListConcat and ArrayConcat take a single level of records and output a list containing exactly 1 element
             AggregateFunc::ListConcat { .. } | AggregateFunc::ArrayConcat { .. } => self
                 .expr
                 .clone()
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
 
-            // RowNumber, Rank, DenseRank take a list of records and output a list containing exactly 1 element
-            AggregateFunc::RowNumber { .. } => {
-                self.on_unique_ranking_window_funcs(input_type, "?row_number?")
-            }
-            AggregateFunc::Rank { .. } => self.on_unique_ranking_window_funcs(input_type, "?rank?"),
-            AggregateFunc::DenseRank { .. } => {
-                self.on_unique_ranking_window_funcs(input_type, "?dense_rank?")
+            // RowNumber, Rank, DenseRank, PercentRank, CumeDist take a list of records and output
+            // a list containing exactly 1 element
+            AggregateFunc::RowNumber { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?row_number?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ScalarType::Int64),
+            ),
+            AggregateFunc::Rank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?rank?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ScalarType::Int64),
+            ),
+            AggregateFunc::DenseRank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?dense_rank?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ScalarType::Int64),
+            ),
+            AggregateFunc::PercentRank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?percent_rank?",
+                MirScalarExpr::literal_ok(Datum::Float64(0.0.into()), ScalarType::Float64),
+            ),
+            AggregateFunc::CumeDist { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?cume_dist?",
+                MirScalarExpr::literal_ok(Datum::Float64(1.0.into()), ScalarType::Float64),
+            ),
+
+            // The input type for Ntile is a ((OriginalRow, NumBuckets), OrderByExprs...)
+            AggregateFunc::Ntile { .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the number of buckets
+                let num_buckets = tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                // A single row always goes into the first bucket, unless the number of buckets is null
+                let value = num_buckets.call_is_null().if_then_else(
+                    MirScalarExpr::literal_null(ScalarType::Int32),
+                    MirScalarExpr::literal_ok(Datum::Int32(1), ScalarType::Int32),
+                );
+
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ListCreate {
+                        elem_type: return_type,
+                    },
+                    exprs: vec![MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::RecordCreate {
+                            field_names: vec![
+                                ColumnName::from("?ntile?"),
+                                ColumnName::from("?record?"),
+                            ],
+                        },
+                        exprs: vec![value, original_row],
+                    }],
+                }
             }
 
             // The input type for LagLead is a ((OriginalRow, (InputValue, Offset, Default)), OrderByExprs...)
@@ -2645,6 +2758,62 @@ impl AggregateExpr {
                 }
             }
 
+            // The input type for NthValue is a ((OriginalRow, (InputValue, N)), OrderByExprs...)
+            AggregateFunc::NthValue { window_frame, .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+                let nth_value_return_type = return_type.unwrap_record_element_type()[0].clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the encoded args
+                let encoded_args =
+                    tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+                let expr = encoded_args
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+                let n = encoded_args.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                // If the window frame includes the current (single) row, it is the first row of
+                // the frame, so return its value if N is 1, null otherwise
+                let value = if window_frame.includes_current_row() {
+                    n.call_binary(
+                        MirScalarExpr::literal_ok(Datum::Int32(1), ScalarType::Int32),
+                        crate::BinaryFunc::Eq,
+                    )
+                    .if_then_else(expr, MirScalarExpr::literal_null(nth_value_return_type))
+                } else {
+                    MirScalarExpr::literal_null(nth_value_return_type)
+                };
+
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ListCreate {
+                        elem_type: return_type,
+                    },
+                    exprs: vec![MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::RecordCreate {
+                            field_names: vec![
+                                ColumnName::from("?nth_value?"),
+                                ColumnName::from("?record?"),
+                            ],
+                        },
+                        exprs: vec![value, original_row],
+                    }],
+                }
+            }
+
             // The input type for window aggs is a ((OriginalRow, InputValue), OrderByExprs...)
             // See an example MIR in `window_func_applied_to`.
             AggregateFunc::WindowAggregate {
@@ -2717,6 +2886,7 @@ impl AggregateExpr {
             | AggregateFunc::MaxFloat64
             | AggregateFunc::MaxBool
             | AggregateFunc::MaxString
+            | AggregateFunc::MaxStringCollated { .. }
             | AggregateFunc::MaxDate
             | AggregateFunc::MaxTimestamp
             | AggregateFunc::MaxTimestampTz
@@ -2733,6 +2903,7 @@ impl AggregateExpr {
             | AggregateFunc::MinFloat64
             | AggregateFunc::MinBool
             | AggregateFunc::MinString
+            | AggregateFunc::MinStringCollated { .. }
             | AggregateFunc::MinDate
             | AggregateFunc::MinTimestamp
             | AggregateFunc::MinTimestampTz
@@ -2742,15 +2913,17 @@ impl AggregateExpr {
             | AggregateFunc::SumNumeric
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::HllMerge
             | AggregateFunc::Dummy => self.expr.clone(),
         }
     }
 
-    /// `on_unique` for ROW_NUMBER, RANK, DENSE_RANK
+    /// `on_unique` for ROW_NUMBER, RANK, DENSE_RANK, PERCENT_RANK, CUME_DIST
     pub fn on_unique_ranking_window_funcs(
         &self,
         input_type: &[ColumnType],
         col_name: &str,
+        rank: MirScalarExpr,
     ) -> MirScalarExpr {
         let list = self
             .expr
@@ -2779,10 +2952,7 @@ impl AggregateExpr {
                 func: VariadicFunc::RecordCreate {
                     field_names: vec![ColumnName::from(col_name), ColumnName::from("?record?")],
                 },
-                exprs: vec![
-                    MirScalarExpr::literal_ok(Datum::Int64(1), ScalarType::Int64),
-                    record,
-                ],
+                exprs: vec![rank, record],
             }],
         }
     }
@@ -3154,10 +3324,16 @@ where
                 }
             }
             (lval, rval) => {
+                let cmp = match (order.collation, lval, rval) {
+                    (Some(collation), Datum::String(l), Datum::String(r)) => {
+                        collation.compare(l, r)
+                    }
+                    _ => lval.cmp(rval),
+                };
                 if order.desc {
-                    rval.cmp(lval)
+                    cmp.reverse()
                 } else {
-                    lval.cmp(rval)
+                    cmp
                 }
             }
         };
@@ -3207,16 +3383,20 @@ impl WindowFrame {
 
     fn includes_current_row(&self) -> bool {
         use WindowFrameBound::*;
-        match self.start_bound {
-            UnboundedPreceding => match self.end_bound {
+        // In RANGE mode, a value offset includes the current row exactly when a row offset of the
+        // same magnitude would, so we only need to know whether the offset is zero.
+        let end_bound = self.end_bound.value_offset_as_row_offset();
+        match self.start_bound.value_offset_as_row_offset() {
+            UnboundedPreceding => match end_bound {
                 UnboundedPreceding => false,
                 OffsetPreceding(0) => true,
                 OffsetPreceding(_) => false,
                 CurrentRow => true,
                 OffsetFollowing(_) => true,
                 UnboundedFollowing => true,
+                ValuePreceding(_) | ValueFollowing(_) => unreachable!(),
             },
-            OffsetPreceding(0) => match self.end_bound {
+            OffsetPreceding(0) => match end_bound {
                 UnboundedPreceding => unreachable!(),
                 OffsetPreceding(0) => true,
                 // Any nonzero offsets here will create an empty window
@@ -3224,8 +3404,9 @@ impl WindowFrame {
                 CurrentRow => true,
                 OffsetFollowing(_) => true,
                 UnboundedFollowing => true,
+                ValuePreceding(_) | ValueFollowing(_) => unreachable!(),
             },
-            OffsetPreceding(_) => match self.end_bound {
+            OffsetPreceding(_) => match end_bound {
                 UnboundedPreceding => unreachable!(),
                 // Window ends at the current row
                 OffsetPreceding(0) => true,
@@ -3233,23 +3414,27 @@ impl WindowFrame {
                 CurrentRow => true,
                 OffsetFollowing(_) => true,
                 UnboundedFollowing => true,
+                ValuePreceding(_) | ValueFollowing(_) => unreachable!(),
             },
             CurrentRow => true,
-            OffsetFollowing(0) => match self.end_bound {
+            OffsetFollowing(0) => match end_bound {
                 UnboundedPreceding => unreachable!(),
                 OffsetPreceding(_) => unreachable!(),
                 CurrentRow => unreachable!(),
                 OffsetFollowing(_) => true,
                 UnboundedFollowing => true,
+                ValuePreceding(_) | ValueFollowing(_) => unreachable!(),
             },
-            OffsetFollowing(_) => match self.end_bound {
+            OffsetFollowing(_) => match end_bound {
                 UnboundedPreceding => unreachable!(),
                 OffsetPreceding(_) => unreachable!(),
                 CurrentRow => unreachable!(),
                 OffsetFollowing(_) => false,
                 UnboundedFollowing => false,
+                ValuePreceding(_) | ValueFollowing(_) => unreachable!(),
             },
             UnboundedFollowing => false,
+            ValuePreceding(_) | ValueFollowing(_) => unreachable!(),
         }
     }
 }
@@ -3286,8 +3471,8 @@ pub enum WindowFrameUnits {
     /// Each peer group is treated as the unit of work for bounds,
     /// and offset-based bounds use the value of the ORDER BY expression
     Range,
-    /// Each peer group is treated as the unit of work for bounds.
-    /// Groups is currently not supported, and it is rejected during planning.
+    /// Each peer group is treated as the unit of work for bounds,
+    /// and offset-based bounds count peer groups
     Groups,
 }
 
@@ -3342,21 +3527,58 @@ pub enum WindowFrameBound {
     UnboundedPreceding,
     /// `<N> PRECEDING`
     OffsetPreceding(u64),
+    /// `<offset> PRECEDING` in RANGE mode, where the offset is subtracted from the value of the
+    /// (single) ORDER BY expression. The row holds exactly one datum.
+    ValuePreceding(Row),
     /// `CURRENT ROW`
     CurrentRow,
     /// `<N> FOLLOWING`
     OffsetFollowing(u64),
+    /// `<offset> FOLLOWING` in RANGE mode, where the offset is added to the value of the
+    /// (single) ORDER BY expression. The row holds exactly one datum.
+    ValueFollowing(Row),
     /// `UNBOUNDED FOLLOWING`.
     UnboundedFollowing,
 }
 
+impl WindowFrameBound {
+    /// Replaces a RANGE value offset by a row offset that is 0 if the value offset is zero, and 1
+    /// otherwise.
+    fn value_offset_as_row_offset(&self) -> WindowFrameBound {
+        fn is_zero(offset: &Row) -> bool {
+            match offset.unpack_first() {
+                Datum::Int64(i) => i == 0,
+                Datum::Float64(f) => f.into_inner() == 0.0,
+                Datum::Numeric(n) => n.0.is_zero(),
+                Datum::Interval(i) => i == Interval::default(),
+                _ => false,
+            }
+        }
+        match self {
+            WindowFrameBound::ValuePreceding(offset) => {
+                WindowFrameBound::OffsetPreceding(if is_zero(offset) { 0 } else { 1 })
+            }
+            WindowFrameBound::ValueFollowing(offset) => {
+                WindowFrameBound::OffsetFollowing(if is_zero(offset) { 0 } else { 1 })
+            }
+            bound => bound.clone(),
+        }
+    }
+}
+
 impl Display for WindowFrameBound {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             WindowFrameBound::UnboundedPreceding => write!(f, "unbounded preceding"),
             WindowFrameBound::OffsetPreceding(offset) => write!(f, "{} preceding", offset),
+            WindowFrameBound::ValuePreceding(offset) => {
+                write!(f, "{} preceding", offset.unpack_first())
+            }
             WindowFrameBound::CurrentRow => write!(f, "current row"),
             WindowFrameBound::OffsetFollowing(offset) => write!(f, "{} following", offset),
+            WindowFrameBound::ValueFollowing(offset) => {
+                write!(f, "{} following", offset.unpack_first())
+            }
             WindowFrameBound::UnboundedFollowing => write!(f, "unbounded following"),
         }
     }
@@ -3369,8 +3591,10 @@ impl RustType<proto_window_frame::ProtoWindowFrameBound> for WindowFrameBound {
             kind: Some(match self {
                 WindowFrameBound::UnboundedPreceding => UnboundedPreceding(()),
                 WindowFrameBound::OffsetPreceding(offset) => OffsetPreceding(*offset),
+                WindowFrameBound::ValuePreceding(offset) => ValuePreceding(offset.into_proto()),
                 WindowFrameBound::CurrentRow => CurrentRow(()),
                 WindowFrameBound::OffsetFollowing(offset) => OffsetFollowing(*offset),
+                WindowFrameBound::ValueFollowing(offset) => ValueFollowing(offset.into_proto()),
                 WindowFrameBound::UnboundedFollowing => UnboundedFollowing(()),
             }),
         }
@@ -3381,8 +3605,10 @@ impl RustType<proto_window_frame::ProtoWindowFrameBound> for WindowFrameBound {
         Ok(match x.kind {
             Some(UnboundedPreceding(())) => WindowFrameBound::UnboundedPreceding,
             Some(OffsetPreceding(offset)) => WindowFrameBound::OffsetPreceding(offset),
+            Some(ValuePreceding(offset)) => WindowFrameBound::ValuePreceding(offset.into_rust()?),
             Some(CurrentRow(())) => WindowFrameBound::CurrentRow,
             Some(OffsetFollowing(offset)) => WindowFrameBound::OffsetFollowing(offset),
+            Some(ValueFollowing(offset)) => WindowFrameBound::ValueFollowing(offset.into_rust()?),
             Some(UnboundedFollowing(())) => WindowFrameBound::UnboundedFollowing,
             None => {
                 return Err(TryFromProtoError::missing_field(
@@ -3507,6 +3733,7 @@ mod tests {
                 column: 4,
                 desc: true,
                 nulls_last: true,
+                collation: None,
             }],
             limit: Some(NonNeg::try_from(7).unwrap()),
             offset: Default::default(),
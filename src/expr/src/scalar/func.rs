@@ -38,10 +38,13 @@ use mz_pgrepr::Type;
 use mz_pgtz::timezone::{Timezone, TimezoneSpec};
 use mz_proto::chrono::any_naive_datetime;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
-use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::array::{Array, ArrayDimension};
+use mz_repr::adt::collation::Collation;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::enum_type::EnumValue;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::jsonpath::{any_jsonpath, JsonPath};
 use mz_repr::adt::mz_acl_item::{AclItem, AclMode, MzAclItem};
 use mz_repr::adt::numeric::{self, DecimalLike, Numeric, NumericMaxScale};
 use mz_repr::adt::range::{self, Range, RangeBound, RangeOps};
@@ -1319,6 +1322,134 @@ fn uuid_generate_v5<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::Uuid(res)
 }
 
+fn inet_contains_inet<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(a.unwrap_inet().contains(&b.unwrap_inet()))
+}
+
+fn inet_contains_or_equals_inet<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(a.unwrap_inet().contains_or_equals(&b.unwrap_inet()))
+}
+
+fn inet_overlaps<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(a.unwrap_inet().overlaps(&b.unwrap_inet()))
+}
+
+fn set_masklen_inet<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let inet = a
+        .unwrap_inet()
+        .with_prefix_len(b.unwrap_int32())
+        .map_err(|e| EvalError::InvalidParameterValue(e.to_string()))?;
+    Ok(Datum::Inet(inet))
+}
+
+fn set_masklen_cidr<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    // Unlike an `inet`, a `cidr` must not have any bits set to the right of
+    // its new prefix.
+    let inet = a
+        .unwrap_inet()
+        .with_prefix_len(b.unwrap_int32())
+        .map_err(|e| EvalError::InvalidParameterValue(e.to_string()))?;
+    Ok(Datum::Inet(inet.network()))
+}
+
+fn to_tsvector<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let vector = to_tsvector_inner(a.unwrap_str(), b.unwrap_str())?;
+    Ok(Datum::String(temp_storage.push_string(vector.to_string())))
+}
+
+fn to_tsquery<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let query = to_tsquery_inner(a.unwrap_str(), b.unwrap_str())?;
+    Ok(Datum::String(temp_storage.push_string(query.to_string())))
+}
+
+fn plainto_tsquery<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let query = plainto_tsquery_inner(a.unwrap_str(), b.unwrap_str())?;
+    Ok(Datum::String(temp_storage.push_string(query.to_string())))
+}
+
+fn phraseto_tsquery<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let query = phraseto_tsquery_inner(a.unwrap_str(), b.unwrap_str())?;
+    Ok(Datum::String(temp_storage.push_string(query.to_string())))
+}
+
+fn websearch_to_tsquery<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let query = websearch_to_tsquery_inner(a.unwrap_str(), b.unwrap_str())?;
+    Ok(Datum::String(temp_storage.push_string(query.to_string())))
+}
+
+fn ts_match<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let vector = strconv::parse_tsvector(a.unwrap_str())?;
+    let query = strconv::parse_tsquery(b.unwrap_str())?;
+    Ok(Datum::from(vector.matches(&query)))
+}
+
+fn ts_rank<'a>(
+    weights: Option<Array<'a>>,
+    vector: Datum<'a>,
+    query: Datum<'a>,
+    normalization: Datum<'a>,
+) -> Result<Datum<'a>, EvalError> {
+    let vector = strconv::parse_tsvector(vector.unwrap_str())?;
+    let query = strconv::parse_tsquery(query.unwrap_str())?;
+    let rank = ts_rank_inner(weights, &vector, &query, normalization.unwrap_int32())?;
+    Ok(Datum::from(rank))
+}
+
+/// Evaluates `jsonb_set_lax`, which, unlike `jsonb_set`, accepts a NULL new
+/// value and consults `null_value_treatment` to decide what to do with it.
+fn jsonb_set_lax<'a>(ds: &[Datum<'a>], temp_storage: &'a RowArena) -> Result<Datum<'a>, EvalError> {
+    if ds[0].is_null() || ds[1].is_null() || ds[3].is_null() {
+        return Ok(Datum::Null);
+    }
+    // As in PostgreSQL, `null_value_treatment` is only consulted, and thus
+    // only validated, when the new value is NULL.
+    if !ds[2].is_null() {
+        return jsonb_set_inner(ds[0], ds[1], ds[2], ds[3].unwrap_bool(), temp_storage);
+    }
+    jsonb_set_lax_inner(
+        ds[0],
+        ds[1],
+        ds[2],
+        ds[3].unwrap_bool(),
+        NullValueTreatment::from_datum(ds[4])?,
+        temp_storage,
+    )
+}
+
+/// Evaluates a `jsonb_path_*` function whose path was not known ahead of
+/// time, parsing the path on each call.
+fn jsonb_path_func<'a, F>(
+    f: F,
+    ds: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError>
+where
+    F: FnOnce(Datum<'a>, &JsonPath, Datum<'a>, bool, &'a RowArena) -> Result<Datum<'a>, EvalError>,
+{
+    let path = strconv::parse_jsonpath(ds[1].unwrap_str())?;
+    f(ds[0], &path, ds[2], ds[3].unwrap_bool(), temp_storage)
+}
+
 fn power_numeric<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let mut a = a.unwrap_numeric().0;
     let b = b.unwrap_numeric().0;
@@ -1460,6 +1591,22 @@ fn gte<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::from(a >= b)
 }
 
+fn lt_collated<'a>(a: Datum<'a>, b: Datum<'a>, collation: Collation) -> Datum<'a> {
+    Datum::from(collation.compare(a.unwrap_str(), b.unwrap_str()).is_lt())
+}
+
+fn lte_collated<'a>(a: Datum<'a>, b: Datum<'a>, collation: Collation) -> Datum<'a> {
+    Datum::from(collation.compare(a.unwrap_str(), b.unwrap_str()).is_le())
+}
+
+fn gt_collated<'a>(a: Datum<'a>, b: Datum<'a>, collation: Collation) -> Datum<'a> {
+    Datum::from(collation.compare(a.unwrap_str(), b.unwrap_str()).is_gt())
+}
+
+fn gte_collated<'a>(a: Datum<'a>, b: Datum<'a>, collation: Collation) -> Datum<'a> {
+    Datum::from(collation.compare(a.unwrap_str(), b.unwrap_str()).is_ge())
+}
+
 fn to_char_timestamplike<'a, T>(ts: &T, format: &str, temp_storage: &'a RowArena) -> Datum<'a>
 where
     T: TimestampLike,
@@ -2254,6 +2401,10 @@ pub enum BinaryFunc {
     Lte,
     Gt,
     Gte,
+    LtCollated { collation: Collation },
+    LteCollated { collation: Collation },
+    GtCollated { collation: Collation },
+    GteCollated { collation: Collation },
     LikeEscape,
     IsLikeMatch { case_insensitive: bool },
     IsRegexpMatch { case_insensitive: bool },
@@ -2288,6 +2439,7 @@ pub enum BinaryFunc {
     JsonbContainsJsonb,
     JsonbDeleteInt64,
     JsonbDeleteString,
+    JsonbDeletePath,
     MapContainsKey,
     MapGetValue,
     MapContainsAllKeys,
@@ -2339,6 +2491,17 @@ pub enum BinaryFunc {
     MzAclItemContainsPrivilege,
     ParseIdent,
     PrettySql,
+    InetContainsInet { rev: bool },
+    InetContainsOrEqualsInet { rev: bool },
+    InetOverlaps,
+    SetMasklenInet,
+    SetMasklenCidr,
+    ToTsVector,
+    ToTsQuery,
+    PlainToTsQuery,
+    PhraseToTsQuery,
+    WebsearchToTsQuery,
+    TsMatch,
 }
 
 impl BinaryFunc {
@@ -2461,6 +2624,10 @@ impl BinaryFunc {
             BinaryFunc::Lte => Ok(lte(a, b)),
             BinaryFunc::Gt => Ok(gt(a, b)),
             BinaryFunc::Gte => Ok(gte(a, b)),
+            BinaryFunc::LtCollated { collation } => Ok(lt_collated(a, b, *collation)),
+            BinaryFunc::LteCollated { collation } => Ok(lte_collated(a, b, *collation)),
+            BinaryFunc::GtCollated { collation } => Ok(gt_collated(a, b, *collation)),
+            BinaryFunc::GteCollated { collation } => Ok(gte_collated(a, b, *collation)),
             BinaryFunc::LikeEscape => like_escape(a, b, temp_storage),
             BinaryFunc::IsLikeMatch { case_insensitive } => {
                 is_like_match_dynamic(a, b, *case_insensitive)
@@ -2540,6 +2707,7 @@ impl BinaryFunc {
             BinaryFunc::JsonbContainsJsonb => Ok(jsonb_contains_jsonb(a, b)),
             BinaryFunc::JsonbDeleteInt64 => Ok(jsonb_delete_int64(a, b, temp_storage)),
             BinaryFunc::JsonbDeleteString => Ok(jsonb_delete_string(a, b, temp_storage)),
+            BinaryFunc::JsonbDeletePath => jsonb_delete_path_inner(a, b, temp_storage),
             BinaryFunc::MapContainsKey => Ok(map_contains_key(a, b)),
             BinaryFunc::MapGetValue => Ok(map_get_value(a, b)),
             BinaryFunc::MapContainsAllKeys => Ok(map_contains_all_keys(a, b)),
@@ -2604,6 +2772,19 @@ impl BinaryFunc {
             BinaryFunc::MzAclItemContainsPrivilege => mz_acl_item_contains_privilege(a, b),
             BinaryFunc::ParseIdent => parse_ident(a, b, temp_storage),
             BinaryFunc::PrettySql => pretty_sql(a, b, temp_storage),
+            BinaryFunc::InetContainsInet { rev: _ } => Ok(inet_contains_inet(a, b)),
+            BinaryFunc::InetContainsOrEqualsInet { rev: _ } => {
+                Ok(inet_contains_or_equals_inet(a, b))
+            }
+            BinaryFunc::InetOverlaps => Ok(inet_overlaps(a, b)),
+            BinaryFunc::SetMasklenInet => set_masklen_inet(a, b),
+            BinaryFunc::SetMasklenCidr => set_masklen_cidr(a, b),
+            BinaryFunc::ToTsVector => to_tsvector(a, b, temp_storage),
+            BinaryFunc::ToTsQuery => to_tsquery(a, b, temp_storage),
+            BinaryFunc::PlainToTsQuery => plainto_tsquery(a, b, temp_storage),
+            BinaryFunc::PhraseToTsQuery => phraseto_tsquery(a, b, temp_storage),
+            BinaryFunc::WebsearchToTsQuery => websearch_to_tsquery(a, b, temp_storage),
+            BinaryFunc::TsMatch => ts_match(a, b),
         }
     }
 
@@ -2617,6 +2798,10 @@ impl BinaryFunc {
             | Lte
             | Gt
             | Gte
+            | LtCollated { .. }
+            | LteCollated { .. }
+            | GtCollated { .. }
+            | GteCollated { .. }
             | ArrayContains
             // like and regexp produce errors on invalid like-strings or regexes
             | IsLikeMatch { .. }
@@ -2732,6 +2917,8 @@ impl BinaryFunc {
             | JsonbDeleteInt64
             | JsonbDeleteString => ScalarType::Jsonb.nullable(true),
 
+            JsonbDeletePath => ScalarType::Jsonb.nullable(in_nullable),
+
             JsonbContainsString | JsonbContainsJsonb | MapContainsKey | MapContainsAllKeys
             | MapContainsAnyKeys | MapContainsMap => ScalarType::Bool.nullable(in_nullable),
 
@@ -2792,6 +2979,17 @@ impl BinaryFunc {
 
             ParseIdent => ScalarType::Array(Box::new(ScalarType::String)).nullable(in_nullable),
             PrettySql => ScalarType::String.nullable(in_nullable),
+
+            InetContainsInet { .. } | InetContainsOrEqualsInet { .. } | InetOverlaps => {
+                ScalarType::Bool.nullable(in_nullable)
+            }
+            SetMasklenInet => ScalarType::Inet.nullable(in_nullable),
+            SetMasklenCidr => ScalarType::Cidr.nullable(in_nullable),
+            ToTsVector => ScalarType::TsVector.nullable(in_nullable),
+            ToTsQuery | PlainToTsQuery | PhraseToTsQuery | WebsearchToTsQuery => {
+                ScalarType::TsQuery.nullable(in_nullable)
+            }
+            TsMatch => ScalarType::Bool.nullable(in_nullable),
         }
     }
 
@@ -2919,6 +3117,10 @@ impl BinaryFunc {
             | Lte
             | Gt
             | Gte
+            | LtCollated { .. }
+            | LteCollated { .. }
+            | GtCollated { .. }
+            | GteCollated { .. }
             | LikeEscape
             | IsLikeMatch { .. }
             | IsRegexpMatch { .. }
@@ -2992,7 +3194,19 @@ impl BinaryFunc {
             | UuidGenerateV5
             | MzAclItemContainsPrivilege
             | ParseIdent
-            | PrettySql => false,
+            | PrettySql
+            | InetContainsInet { .. }
+            | InetContainsOrEqualsInet { .. }
+            | InetOverlaps
+            | SetMasklenInet
+            | SetMasklenCidr
+            | ToTsVector
+            | ToTsQuery
+            | PlainToTsQuery
+            | PhraseToTsQuery
+            | WebsearchToTsQuery
+            | TsMatch
+            | JsonbDeletePath => false,
 
             JsonbGetInt64 { .. }
             | JsonbGetString { .. }
@@ -3117,6 +3331,7 @@ impl BinaryFunc {
             | JsonbContainsString
             | JsonbDeleteInt64
             | JsonbDeleteString
+            | JsonbDeletePath
             | MapContainsKey
             | MapGetValue
             | MapContainsAllKeys
@@ -3143,7 +3358,11 @@ impl BinaryFunc {
             | RangeAdjacent
             | RangeUnion
             | RangeIntersection
-            | RangeDifference => true,
+            | RangeDifference
+            | InetContainsInet { .. }
+            | InetContainsOrEqualsInet { .. }
+            | InetOverlaps
+            | TsMatch => true,
             ToCharTimestamp
             | ToCharTimestampTz
             | AgeTimestamp
@@ -3196,7 +3415,18 @@ impl BinaryFunc {
             | ConstantTimeEqBytes
             | ConstantTimeEqString
             | ParseIdent
-            | PrettySql => false,
+            | PrettySql
+            | SetMasklenInet
+            | SetMasklenCidr
+            | ToTsVector
+            | ToTsQuery
+            | PlainToTsQuery
+            | PhraseToTsQuery
+            | WebsearchToTsQuery
+            | LtCollated { .. }
+            | LteCollated { .. }
+            | GtCollated { .. }
+            | GteCollated { .. } => false,
         }
     }
 
@@ -3209,6 +3439,18 @@ impl BinaryFunc {
             BinaryFunc::Gte => Some(BinaryFunc::Lt),
             BinaryFunc::Gt => Some(BinaryFunc::Lte),
             BinaryFunc::Lte => Some(BinaryFunc::Gt),
+            BinaryFunc::LtCollated { collation } => Some(BinaryFunc::GteCollated {
+                collation: *collation,
+            }),
+            BinaryFunc::GteCollated { collation } => Some(BinaryFunc::LtCollated {
+                collation: *collation,
+            }),
+            BinaryFunc::GtCollated { collation } => Some(BinaryFunc::LteCollated {
+                collation: *collation,
+            }),
+            BinaryFunc::LteCollated { collation } => Some(BinaryFunc::GtCollated {
+                collation: *collation,
+            }),
             _ => None,
         }
     }
@@ -3221,7 +3463,11 @@ impl BinaryFunc {
             | BinaryFunc::Lt
             | BinaryFunc::Gte
             | BinaryFunc::Gt
-            | BinaryFunc::Lte => false,
+            | BinaryFunc::Lte
+            | BinaryFunc::LtCollated { .. }
+            | BinaryFunc::GteCollated { .. }
+            | BinaryFunc::GtCollated { .. }
+            | BinaryFunc::LteCollated { .. } => false,
             _ => true,
         }
     }
@@ -3336,6 +3582,11 @@ impl BinaryFunc {
             BinaryFunc::RoundNumeric => (true, false),
             BinaryFunc::Eq | BinaryFunc::NotEq => (false, false),
             BinaryFunc::Lt | BinaryFunc::Lte | BinaryFunc::Gt | BinaryFunc::Gte => (true, true),
+            // Collations do not order strings bytewise, as their datums are.
+            BinaryFunc::LtCollated { .. }
+            | BinaryFunc::LteCollated { .. }
+            | BinaryFunc::GtCollated { .. }
+            | BinaryFunc::GteCollated { .. } => (false, false),
             BinaryFunc::LikeEscape
             | BinaryFunc::IsLikeMatch { .. }
             | BinaryFunc::IsRegexpMatch { .. } => (false, false),
@@ -3371,6 +3622,7 @@ impl BinaryFunc {
             | BinaryFunc::JsonbContainsJsonb
             | BinaryFunc::JsonbDeleteInt64
             | BinaryFunc::JsonbDeleteString
+            | BinaryFunc::JsonbDeletePath
             | BinaryFunc::MapContainsKey
             | BinaryFunc::MapGetValue
             | BinaryFunc::MapContainsAllKeys
@@ -3420,6 +3672,17 @@ impl BinaryFunc {
             BinaryFunc::ParseIdent => (false, false),
             BinaryFunc::ConstantTimeEqBytes | BinaryFunc::ConstantTimeEqString => (false, false),
             BinaryFunc::PrettySql => (false, false),
+            BinaryFunc::InetContainsInet { .. }
+            | BinaryFunc::InetContainsOrEqualsInet { .. }
+            | BinaryFunc::InetOverlaps
+            | BinaryFunc::SetMasklenInet
+            | BinaryFunc::SetMasklenCidr => (false, false),
+            BinaryFunc::ToTsVector
+            | BinaryFunc::ToTsQuery
+            | BinaryFunc::PlainToTsQuery
+            | BinaryFunc::PhraseToTsQuery
+            | BinaryFunc::WebsearchToTsQuery
+            | BinaryFunc::TsMatch => (false, false),
         }
     }
 }
@@ -3527,6 +3790,10 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::Lte => f.write_str("<="),
             BinaryFunc::Gt => f.write_str(">"),
             BinaryFunc::Gte => f.write_str(">="),
+            BinaryFunc::LtCollated { collation } => write!(f, "lt_collated[{}]", collation),
+            BinaryFunc::LteCollated { collation } => write!(f, "lte_collated[{}]", collation),
+            BinaryFunc::GtCollated { collation } => write!(f, "gt_collated[{}]", collation),
+            BinaryFunc::GteCollated { collation } => write!(f, "gte_collated[{}]", collation),
             BinaryFunc::LikeEscape => f.write_str("like_escape"),
             BinaryFunc::IsLikeMatch {
                 case_insensitive: false,
@@ -3574,6 +3841,7 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::JsonbContainsJsonb | BinaryFunc::MapContainsMap => f.write_str("@>"),
             BinaryFunc::JsonbDeleteInt64 => f.write_str("-"),
             BinaryFunc::JsonbDeleteString => f.write_str("-"),
+            BinaryFunc::JsonbDeletePath => f.write_str("#-"),
             BinaryFunc::MapGetValue => f.write_str("->"),
             BinaryFunc::MapContainsAllKeys => f.write_str("?&"),
             BinaryFunc::MapContainsAnyKeys => f.write_str("?|"),
@@ -3627,6 +3895,18 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::MzAclItemContainsPrivilege => f.write_str("mz_aclitem_contains_privilege"),
             BinaryFunc::ParseIdent => f.write_str("parse_ident"),
             BinaryFunc::PrettySql => f.write_str("pretty_sql"),
+            BinaryFunc::InetContainsInet { rev } => f.write_str(if *rev { "<<" } else { ">>" }),
+            BinaryFunc::InetContainsOrEqualsInet { rev } => {
+                f.write_str(if *rev { "<<=" } else { ">>=" })
+            }
+            BinaryFunc::InetOverlaps => f.write_str("&&"),
+            BinaryFunc::SetMasklenInet | BinaryFunc::SetMasklenCidr => f.write_str("set_masklen"),
+            BinaryFunc::ToTsVector => f.write_str("to_tsvector"),
+            BinaryFunc::ToTsQuery => f.write_str("to_tsquery"),
+            BinaryFunc::PlainToTsQuery => f.write_str("plainto_tsquery"),
+            BinaryFunc::PhraseToTsQuery => f.write_str("phraseto_tsquery"),
+            BinaryFunc::WebsearchToTsQuery => f.write_str("websearch_to_tsquery"),
+            BinaryFunc::TsMatch => f.write_str("@@"),
         }
     }
 }
@@ -3745,6 +4025,18 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::Lte).boxed(),
             Just(BinaryFunc::Gt).boxed(),
             Just(BinaryFunc::Gte).boxed(),
+            Collation::arbitrary()
+                .prop_map(|collation| BinaryFunc::LtCollated { collation })
+                .boxed(),
+            Collation::arbitrary()
+                .prop_map(|collation| BinaryFunc::LteCollated { collation })
+                .boxed(),
+            Collation::arbitrary()
+                .prop_map(|collation| BinaryFunc::GtCollated { collation })
+                .boxed(),
+            Collation::arbitrary()
+                .prop_map(|collation| BinaryFunc::GteCollated { collation })
+                .boxed(),
             Just(BinaryFunc::LikeEscape).boxed(),
             bool::arbitrary()
                 .prop_map(|case_insensitive| BinaryFunc::IsLikeMatch { case_insensitive })
@@ -3789,6 +4081,7 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::JsonbContainsJsonb).boxed(),
             Just(BinaryFunc::JsonbDeleteInt64).boxed(),
             Just(BinaryFunc::JsonbDeleteString).boxed(),
+            Just(BinaryFunc::JsonbDeletePath).boxed(),
             Just(BinaryFunc::MapContainsKey).boxed(),
             Just(BinaryFunc::MapGetValue).boxed(),
             Just(BinaryFunc::MapContainsAllKeys).boxed(),
@@ -3840,6 +4133,21 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::RangeIntersection).boxed(),
             Just(BinaryFunc::RangeDifference).boxed(),
             Just(BinaryFunc::ParseIdent).boxed(),
+            bool::arbitrary()
+                .prop_map(|rev| BinaryFunc::InetContainsInet { rev })
+                .boxed(),
+            bool::arbitrary()
+                .prop_map(|rev| BinaryFunc::InetContainsOrEqualsInet { rev })
+                .boxed(),
+            Just(BinaryFunc::InetOverlaps).boxed(),
+            Just(BinaryFunc::SetMasklenInet).boxed(),
+            Just(BinaryFunc::SetMasklenCidr).boxed(),
+            Just(BinaryFunc::ToTsVector).boxed(),
+            Just(BinaryFunc::ToTsQuery).boxed(),
+            Just(BinaryFunc::PlainToTsQuery).boxed(),
+            Just(BinaryFunc::PhraseToTsQuery).boxed(),
+            Just(BinaryFunc::WebsearchToTsQuery).boxed(),
+            Just(BinaryFunc::TsMatch).boxed(),
         ])
     }
 }
@@ -3949,6 +4257,10 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::Lte => Lte(()),
             BinaryFunc::Gt => Gt(()),
             BinaryFunc::Gte => Gte(()),
+            BinaryFunc::LtCollated { collation } => LtCollated(collation.into_proto()),
+            BinaryFunc::LteCollated { collation } => LteCollated(collation.into_proto()),
+            BinaryFunc::GtCollated { collation } => GtCollated(collation.into_proto()),
+            BinaryFunc::GteCollated { collation } => GteCollated(collation.into_proto()),
             BinaryFunc::LikeEscape => LikeEscape(()),
             BinaryFunc::IsLikeMatch { case_insensitive } => IsLikeMatch(*case_insensitive),
             BinaryFunc::IsRegexpMatch { case_insensitive } => IsRegexpMatch(*case_insensitive),
@@ -3983,6 +4295,7 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::JsonbContainsJsonb => JsonbContainsJsonb(()),
             BinaryFunc::JsonbDeleteInt64 => JsonbDeleteInt64(()),
             BinaryFunc::JsonbDeleteString => JsonbDeleteString(()),
+            BinaryFunc::JsonbDeletePath => JsonbDeletePath(()),
             BinaryFunc::MapContainsKey => MapContainsKey(()),
             BinaryFunc::MapGetValue => MapGetValue(()),
             BinaryFunc::MapContainsAllKeys => MapContainsAllKeys(()),
@@ -4039,6 +4352,17 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::ConstantTimeEqBytes => ConstantTimeEqBytes(()),
             BinaryFunc::ConstantTimeEqString => ConstantTimeEqString(()),
             BinaryFunc::PrettySql => PrettySql(()),
+            BinaryFunc::InetContainsInet { rev } => InetContainsInet(*rev),
+            BinaryFunc::InetContainsOrEqualsInet { rev } => InetContainsOrEqualsInet(*rev),
+            BinaryFunc::InetOverlaps => InetOverlaps(()),
+            BinaryFunc::SetMasklenInet => SetMasklenInet(()),
+            BinaryFunc::SetMasklenCidr => SetMasklenCidr(()),
+            BinaryFunc::ToTsVector => ToTsVector(()),
+            BinaryFunc::ToTsQuery => ToTsQuery(()),
+            BinaryFunc::PlainToTsQuery => PlainToTsQuery(()),
+            BinaryFunc::PhraseToTsQuery => PhraseToTsQuery(()),
+            BinaryFunc::WebsearchToTsQuery => WebsearchToTsQuery(()),
+            BinaryFunc::TsMatch => TsMatch(()),
         };
         ProtoBinaryFunc { kind: Some(kind) }
     }
@@ -4148,6 +4472,18 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 Lte(()) => Ok(BinaryFunc::Lte),
                 Gt(()) => Ok(BinaryFunc::Gt),
                 Gte(()) => Ok(BinaryFunc::Gte),
+                LtCollated(collation) => Ok(BinaryFunc::LtCollated {
+                    collation: collation.into_rust()?,
+                }),
+                LteCollated(collation) => Ok(BinaryFunc::LteCollated {
+                    collation: collation.into_rust()?,
+                }),
+                GtCollated(collation) => Ok(BinaryFunc::GtCollated {
+                    collation: collation.into_rust()?,
+                }),
+                GteCollated(collation) => Ok(BinaryFunc::GteCollated {
+                    collation: collation.into_rust()?,
+                }),
                 LikeEscape(()) => Ok(BinaryFunc::LikeEscape),
                 IsLikeMatch(case_insensitive) => Ok(BinaryFunc::IsLikeMatch { case_insensitive }),
                 IsRegexpMatch(case_insensitive) => {
@@ -4184,6 +4520,7 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 JsonbContainsJsonb(()) => Ok(BinaryFunc::JsonbContainsJsonb),
                 JsonbDeleteInt64(()) => Ok(BinaryFunc::JsonbDeleteInt64),
                 JsonbDeleteString(()) => Ok(BinaryFunc::JsonbDeleteString),
+                JsonbDeletePath(()) => Ok(BinaryFunc::JsonbDeletePath),
                 MapContainsKey(()) => Ok(BinaryFunc::MapContainsKey),
                 MapGetValue(()) => Ok(BinaryFunc::MapGetValue),
                 MapContainsAllKeys(()) => Ok(BinaryFunc::MapContainsAllKeys),
@@ -4242,6 +4579,17 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 ConstantTimeEqBytes(()) => Ok(BinaryFunc::ConstantTimeEqBytes),
                 ConstantTimeEqString(()) => Ok(BinaryFunc::ConstantTimeEqString),
                 PrettySql(()) => Ok(BinaryFunc::PrettySql),
+                InetContainsInet(rev) => Ok(BinaryFunc::InetContainsInet { rev }),
+                InetContainsOrEqualsInet(rev) => Ok(BinaryFunc::InetContainsOrEqualsInet { rev }),
+                InetOverlaps(()) => Ok(BinaryFunc::InetOverlaps),
+                SetMasklenInet(()) => Ok(BinaryFunc::SetMasklenInet),
+                SetMasklenCidr(()) => Ok(BinaryFunc::SetMasklenCidr),
+                ToTsVector(()) => Ok(BinaryFunc::ToTsVector),
+                ToTsQuery(()) => Ok(BinaryFunc::ToTsQuery),
+                PlainToTsQuery(()) => Ok(BinaryFunc::PlainToTsQuery),
+                PhraseToTsQuery(()) => Ok(BinaryFunc::PhraseToTsQuery),
+                WebsearchToTsQuery(()) => Ok(BinaryFunc::WebsearchToTsQuery),
+                TsMatch(()) => Ok(BinaryFunc::TsMatch),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoBinaryFunc::kind"))
@@ -4433,9 +4781,19 @@ derive_unary!(
     CbrtFloat64,
     AbsInt16,
     AbsInt32,
+    CheckNtileArgument,
+    CheckNthValueArgument,
+    CastStringToHll,
+    CastHllToString,
+    CastBytesToHll,
+    CastHllToBytes,
+    HllSingleton,
+    HllEstimate,
     AbsInt64,
     AbsFloat32,
     AbsFloat64,
+    CheckPercentileFraction,
+    CheckPercentileFractions,
     AbsNumeric,
     CastBoolToString,
     CastBoolToStringNonstandard,
@@ -4619,6 +4977,28 @@ derive_unary!(
     CastMapToString,
     CastInt2VectorToString,
     CastRangeToString,
+    CastStringToEnum,
+    CastEnumToString,
+    CastStringToInet,
+    CastStringToCidr,
+    CastInetToString,
+    CastCidrToString,
+    CastCidrToInet,
+    CastInetToCidr,
+    InetHost,
+    InetMasklen,
+    InetNetwork,
+    InetFamily,
+    CastStringToTsVector,
+    CastStringToTsQuery,
+    CastTsVectorToString,
+    CastTsQueryToString,
+    CastStringToJsonPath,
+    CastJsonPathToString,
+    JsonbPathExists,
+    JsonbPathMatch,
+    JsonbPathQueryArray,
+    JsonbPathQueryFirst,
     CeilFloat32,
     CeilFloat64,
     CeilNumeric,
@@ -4746,6 +5126,12 @@ impl Arbitrary for UnaryFunc {
     type Strategy = Union<BoxedStrategy<Self>>;
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        fn empty_jsonb_object() -> Row {
+            let mut row = Row::default();
+            row.packer().push_dict(Vec::<(&str, Datum)>::new());
+            row
+        }
+
         Union::new(vec![
             Not::arbitrary().prop_map_into().boxed(),
             IsNull::arbitrary().prop_map_into().boxed(),
@@ -4769,9 +5155,21 @@ impl Arbitrary for UnaryFunc {
             CbrtFloat64::arbitrary().prop_map_into().boxed(),
             AbsInt16::arbitrary().prop_map_into().boxed(),
             AbsInt32::arbitrary().prop_map_into().boxed(),
+            CheckNtileArgument::arbitrary().prop_map_into().boxed(),
+            CheckNthValueArgument::arbitrary().prop_map_into().boxed(),
+            CastStringToHll::arbitrary().prop_map_into().boxed(),
+            CastHllToString::arbitrary().prop_map_into().boxed(),
+            CastBytesToHll::arbitrary().prop_map_into().boxed(),
+            CastHllToBytes::arbitrary().prop_map_into().boxed(),
+            HllSingleton::arbitrary().prop_map_into().boxed(),
+            HllEstimate::arbitrary().prop_map_into().boxed(),
             AbsInt64::arbitrary().prop_map_into().boxed(),
             AbsFloat32::arbitrary().prop_map_into().boxed(),
             AbsFloat64::arbitrary().prop_map_into().boxed(),
+            CheckPercentileFraction::arbitrary().prop_map_into().boxed(),
+            CheckPercentileFractions::arbitrary()
+                .prop_map_into()
+                .boxed(),
             AbsNumeric::arbitrary().prop_map_into().boxed(),
             CastBoolToString::arbitrary().prop_map_into().boxed(),
             CastBoolToStringNonstandard::arbitrary()
@@ -5016,6 +5414,60 @@ impl Arbitrary for UnaryFunc {
             CastMapToString::arbitrary().prop_map_into().boxed(),
             CastInt2VectorToString::arbitrary().prop_map_into().boxed(),
             CastRangeToString::arbitrary().prop_map_into().boxed(),
+            CastStringToEnum::arbitrary().prop_map_into().boxed(),
+            CastEnumToString::arbitrary().prop_map_into().boxed(),
+            CastStringToInet::arbitrary().prop_map_into().boxed(),
+            CastStringToCidr::arbitrary().prop_map_into().boxed(),
+            CastInetToString::arbitrary().prop_map_into().boxed(),
+            CastCidrToString::arbitrary().prop_map_into().boxed(),
+            CastCidrToInet::arbitrary().prop_map_into().boxed(),
+            CastInetToCidr::arbitrary().prop_map_into().boxed(),
+            InetHost::arbitrary().prop_map_into().boxed(),
+            InetMasklen::arbitrary().prop_map_into().boxed(),
+            InetNetwork::arbitrary().prop_map_into().boxed(),
+            InetFamily::arbitrary().prop_map_into().boxed(),
+            CastStringToTsVector::arbitrary().prop_map_into().boxed(),
+            CastStringToTsQuery::arbitrary().prop_map_into().boxed(),
+            CastTsVectorToString::arbitrary().prop_map_into().boxed(),
+            CastTsQueryToString::arbitrary().prop_map_into().boxed(),
+            CastStringToJsonPath::arbitrary().prop_map_into().boxed(),
+            CastJsonPathToString::arbitrary().prop_map_into().boxed(),
+            (any_jsonpath(), any::<bool>())
+                .prop_map(|(path, silent)| {
+                    UnaryFunc::JsonbPathExists(JsonbPathExists {
+                        path,
+                        vars: empty_jsonb_object(),
+                        silent,
+                    })
+                })
+                .boxed(),
+            (any_jsonpath(), any::<bool>())
+                .prop_map(|(path, silent)| {
+                    UnaryFunc::JsonbPathMatch(JsonbPathMatch {
+                        path,
+                        vars: empty_jsonb_object(),
+                        silent,
+                    })
+                })
+                .boxed(),
+            (any_jsonpath(), any::<bool>())
+                .prop_map(|(path, silent)| {
+                    UnaryFunc::JsonbPathQueryArray(JsonbPathQueryArray {
+                        path,
+                        vars: empty_jsonb_object(),
+                        silent,
+                    })
+                })
+                .boxed(),
+            (any_jsonpath(), any::<bool>())
+                .prop_map(|(path, silent)| {
+                    UnaryFunc::JsonbPathQueryFirst(JsonbPathQueryFirst {
+                        path,
+                        vars: empty_jsonb_object(),
+                        silent,
+                    })
+                })
+                .boxed(),
             CeilFloat32::arbitrary().prop_map_into().boxed(),
             CeilFloat64::arbitrary().prop_map_into().boxed(),
             CeilNumeric::arbitrary().prop_map_into().boxed(),
@@ -5152,9 +5604,19 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CbrtFloat64(_) => CbrtFloat64(()),
             UnaryFunc::AbsInt16(_) => AbsInt16(()),
             UnaryFunc::AbsInt32(_) => AbsInt32(()),
+            UnaryFunc::CheckNtileArgument(_) => CheckNtileArgument(()),
+            UnaryFunc::CheckNthValueArgument(_) => CheckNthValueArgument(()),
+            UnaryFunc::CastStringToHll(_) => CastStringToHll(()),
+            UnaryFunc::CastHllToString(_) => CastHllToString(()),
+            UnaryFunc::CastBytesToHll(_) => CastBytesToHll(()),
+            UnaryFunc::CastHllToBytes(_) => CastHllToBytes(()),
+            UnaryFunc::HllSingleton(_) => HllSingleton(()),
+            UnaryFunc::HllEstimate(_) => HllEstimate(()),
             UnaryFunc::AbsInt64(_) => AbsInt64(()),
             UnaryFunc::AbsFloat32(_) => AbsFloat32(()),
             UnaryFunc::AbsFloat64(_) => AbsFloat64(()),
+            UnaryFunc::CheckPercentileFraction(_) => CheckPercentileFraction(()),
+            UnaryFunc::CheckPercentileFractions(_) => CheckPercentileFractions(()),
             UnaryFunc::AbsNumeric(_) => AbsNumeric(()),
             UnaryFunc::CastBoolToString(_) => CastBoolToString(()),
             UnaryFunc::CastBoolToStringNonstandard(_) => CastBoolToStringNonstandard(()),
@@ -5396,6 +5858,47 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CastMapToString(func) => CastMapToString(func.ty.into_proto()),
             UnaryFunc::CastInt2VectorToString(_) => CastInt2VectorToString(()),
             UnaryFunc::CastRangeToString(func) => CastRangeToString(func.ty.into_proto()),
+            UnaryFunc::CastStringToEnum(func) => CastStringToEnum(ProtoCastStringToEnum {
+                return_ty: Some(func.return_ty.into_proto()),
+                labels: func.labels.clone(),
+            }),
+            UnaryFunc::CastEnumToString(func) => CastEnumToString(func.ty.into_proto()),
+            UnaryFunc::CastStringToInet(_) => CastStringToInet(()),
+            UnaryFunc::CastStringToCidr(_) => CastStringToCidr(()),
+            UnaryFunc::CastInetToString(_) => CastInetToString(()),
+            UnaryFunc::CastCidrToString(_) => CastCidrToString(()),
+            UnaryFunc::CastCidrToInet(_) => CastCidrToInet(()),
+            UnaryFunc::CastInetToCidr(_) => CastInetToCidr(()),
+            UnaryFunc::InetHost(_) => InetHost(()),
+            UnaryFunc::InetMasklen(_) => InetMasklen(()),
+            UnaryFunc::InetNetwork(_) => InetNetwork(()),
+            UnaryFunc::InetFamily(_) => InetFamily(()),
+            UnaryFunc::CastStringToTsVector(_) => CastStringToTsVector(()),
+            UnaryFunc::CastStringToTsQuery(_) => CastStringToTsQuery(()),
+            UnaryFunc::CastTsVectorToString(_) => CastTsVectorToString(()),
+            UnaryFunc::CastTsQueryToString(_) => CastTsQueryToString(()),
+            UnaryFunc::CastStringToJsonPath(_) => CastStringToJsonPath(()),
+            UnaryFunc::CastJsonPathToString(_) => CastJsonPathToString(()),
+            UnaryFunc::JsonbPathExists(func) => JsonbPathExists(ProtoJsonbPathFunc {
+                path: Some(func.path.into_proto()),
+                vars: Some(func.vars.into_proto()),
+                silent: func.silent,
+            }),
+            UnaryFunc::JsonbPathMatch(func) => JsonbPathMatch(ProtoJsonbPathFunc {
+                path: Some(func.path.into_proto()),
+                vars: Some(func.vars.into_proto()),
+                silent: func.silent,
+            }),
+            UnaryFunc::JsonbPathQueryArray(func) => JsonbPathQueryArray(ProtoJsonbPathFunc {
+                path: Some(func.path.into_proto()),
+                vars: Some(func.vars.into_proto()),
+                silent: func.silent,
+            }),
+            UnaryFunc::JsonbPathQueryFirst(func) => JsonbPathQueryFirst(ProtoJsonbPathFunc {
+                path: Some(func.path.into_proto()),
+                vars: Some(func.vars.into_proto()),
+                silent: func.silent,
+            }),
             UnaryFunc::CeilFloat32(_) => CeilFloat32(()),
             UnaryFunc::CeilFloat64(_) => CeilFloat64(()),
             UnaryFunc::CeilNumeric(_) => CeilNumeric(()),
@@ -5539,9 +6042,19 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 CbrtFloat64(()) => Ok(impls::CbrtFloat64.into()),
                 AbsInt16(()) => Ok(impls::AbsInt16.into()),
                 AbsInt32(()) => Ok(impls::AbsInt32.into()),
+                CheckNtileArgument(()) => Ok(impls::CheckNtileArgument.into()),
+                CheckNthValueArgument(()) => Ok(impls::CheckNthValueArgument.into()),
+                CastStringToHll(()) => Ok(impls::CastStringToHll.into()),
+                CastHllToString(()) => Ok(impls::CastHllToString.into()),
+                CastBytesToHll(()) => Ok(impls::CastBytesToHll.into()),
+                CastHllToBytes(()) => Ok(impls::CastHllToBytes.into()),
+                HllSingleton(()) => Ok(impls::HllSingleton.into()),
+                HllEstimate(()) => Ok(impls::HllEstimate.into()),
                 AbsInt64(()) => Ok(impls::AbsInt64.into()),
                 AbsFloat32(()) => Ok(impls::AbsFloat32.into()),
                 AbsFloat64(()) => Ok(impls::AbsFloat64.into()),
+                CheckPercentileFraction(()) => Ok(impls::CheckPercentileFraction.into()),
+                CheckPercentileFractions(()) => Ok(impls::CheckPercentileFractions.into()),
                 AbsNumeric(()) => Ok(impls::AbsNumeric.into()),
                 CastBoolToString(()) => Ok(impls::CastBoolToString.into()),
                 CastBoolToStringNonstandard(()) => Ok(impls::CastBoolToStringNonstandard.into()),
@@ -5840,6 +6353,57 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                     ty: ty.into_rust()?,
                 }
                 .into()),
+                CastStringToEnum(func) => Ok(impls::CastStringToEnum {
+                    return_ty: func
+                        .return_ty
+                        .into_rust_if_some("ProtoCastStringToEnum::return_ty")?,
+                    labels: func.labels,
+                }
+                .into()),
+                CastEnumToString(ty) => Ok(impls::CastEnumToString {
+                    ty: ty.into_rust()?,
+                }
+                .into()),
+                CastStringToInet(_) => Ok(impls::CastStringToInet.into()),
+                CastStringToCidr(_) => Ok(impls::CastStringToCidr.into()),
+                CastInetToString(_) => Ok(impls::CastInetToString.into()),
+                CastCidrToString(_) => Ok(impls::CastCidrToString.into()),
+                CastCidrToInet(_) => Ok(impls::CastCidrToInet.into()),
+                CastInetToCidr(_) => Ok(impls::CastInetToCidr.into()),
+                InetHost(_) => Ok(impls::InetHost.into()),
+                InetMasklen(_) => Ok(impls::InetMasklen.into()),
+                InetNetwork(_) => Ok(impls::InetNetwork.into()),
+                InetFamily(_) => Ok(impls::InetFamily.into()),
+                CastStringToTsVector(_) => Ok(impls::CastStringToTsVector.into()),
+                CastStringToTsQuery(_) => Ok(impls::CastStringToTsQuery.into()),
+                CastTsVectorToString(_) => Ok(impls::CastTsVectorToString.into()),
+                CastTsQueryToString(_) => Ok(impls::CastTsQueryToString.into()),
+                CastStringToJsonPath(_) => Ok(impls::CastStringToJsonPath.into()),
+                CastJsonPathToString(_) => Ok(impls::CastJsonPathToString.into()),
+                JsonbPathExists(func) => Ok(impls::JsonbPathExists {
+                    path: func.path.into_rust_if_some("ProtoJsonbPathFunc::path")?,
+                    vars: func.vars.into_rust_if_some("ProtoJsonbPathFunc::vars")?,
+                    silent: func.silent,
+                }
+                .into()),
+                JsonbPathMatch(func) => Ok(impls::JsonbPathMatch {
+                    path: func.path.into_rust_if_some("ProtoJsonbPathFunc::path")?,
+                    vars: func.vars.into_rust_if_some("ProtoJsonbPathFunc::vars")?,
+                    silent: func.silent,
+                }
+                .into()),
+                JsonbPathQueryArray(func) => Ok(impls::JsonbPathQueryArray {
+                    path: func.path.into_rust_if_some("ProtoJsonbPathFunc::path")?,
+                    vars: func.vars.into_rust_if_some("ProtoJsonbPathFunc::vars")?,
+                    silent: func.silent,
+                }
+                .into()),
+                JsonbPathQueryFirst(func) => Ok(impls::JsonbPathQueryFirst {
+                    path: func.path.into_rust_if_some("ProtoJsonbPathFunc::path")?,
+                    vars: func.vars.into_rust_if_some("ProtoJsonbPathFunc::vars")?,
+                    silent: func.silent,
+                }
+                .into()),
                 CeilFloat32(_) => Ok(impls::CeilFloat32.into()),
                 CeilFloat64(_) => Ok(impls::CeilFloat64.into()),
                 CeilNumeric(_) => Ok(impls::CeilNumeric.into()),
@@ -6560,8 +7124,10 @@ where
         Timestamp { .. } => Ok(strconv::format_timestamp(buf, &d.unwrap_timestamp())),
         TimestampTz { .. } => Ok(strconv::format_timestamptz(buf, &d.unwrap_timestamptz())),
         Interval => Ok(strconv::format_interval(buf, d.unwrap_interval())),
-        Bytes => Ok(strconv::format_bytes(buf, d.unwrap_bytes())),
-        String | VarChar { .. } | PgLegacyName => Ok(strconv::format_string(buf, d.unwrap_str())),
+        Bytes | Hll => Ok(strconv::format_bytes(buf, d.unwrap_bytes())),
+        String | VarChar { .. } | PgLegacyName | TsVector | TsQuery | JsonPath => {
+            Ok(strconv::format_string(buf, d.unwrap_str()))
+        }
         Char { length } => Ok(strconv::format_string(
             buf,
             &mz_repr::adt::char::format_str_pad(d.unwrap_str(), *length),
@@ -6572,6 +7138,12 @@ where
         }
         Jsonb => Ok(strconv::format_jsonb(buf, JsonbRef::from_datum(d))),
         Uuid => Ok(strconv::format_uuid(buf, d.unwrap_uuid())),
+        Inet => Ok(strconv::format_inet(buf, d.unwrap_inet())),
+        Cidr => Ok(strconv::format_cidr(
+            buf,
+            mz_repr::adt::inet::Cidr(d.unwrap_inet()),
+        )),
+        Enum { .. } => Ok(strconv::format_string(buf, EnumValue::from_datum(d).label)),
         Record { fields, .. } => {
             let mut fields = fields.iter();
             strconv::format_record(buf, &d.unwrap_list(), |buf, d| {
@@ -7464,6 +8036,14 @@ pub enum VariadicFunc {
     TimezoneTime,
     RegexpSplitToArray,
     RegexpReplace,
+    TsRank,
+    JsonbPathExists,
+    JsonbPathMatch,
+    JsonbPathQueryArray,
+    JsonbPathQueryFirst,
+    JsonbSet,
+    JsonbInsert,
+    JsonbSetLax,
 }
 
 impl VariadicFunc {
@@ -7573,6 +8153,34 @@ impl VariadicFunc {
                 };
                 regexp_replace(ds[0], ds[1], ds[2], flags, temp_storage)
             }
+            VariadicFunc::TsRank => {
+                // The weights are optional and come first when present.
+                let (weights, ds) = if ds.len() == 4 {
+                    (Some(ds[0].unwrap_array()), &ds[1..])
+                } else {
+                    (None, &ds[..])
+                };
+                ts_rank(weights, ds[0], ds[1], ds[2])
+            }
+            VariadicFunc::JsonbPathExists => {
+                jsonb_path_func(jsonb_path_exists_inner, &ds, temp_storage)
+            }
+            VariadicFunc::JsonbPathMatch => {
+                jsonb_path_func(jsonb_path_match_inner, &ds, temp_storage)
+            }
+            VariadicFunc::JsonbPathQueryArray => {
+                jsonb_path_func(jsonb_path_query_array_inner, &ds, temp_storage)
+            }
+            VariadicFunc::JsonbPathQueryFirst => {
+                jsonb_path_func(jsonb_path_query_first_inner, &ds, temp_storage)
+            }
+            VariadicFunc::JsonbSet => {
+                jsonb_set_inner(ds[0], ds[1], ds[2], ds[3].unwrap_bool(), temp_storage)
+            }
+            VariadicFunc::JsonbInsert => {
+                jsonb_insert_inner(ds[0], ds[1], ds[2], ds[3].unwrap_bool(), temp_storage)
+            }
+            VariadicFunc::JsonbSetLax => jsonb_set_lax(&ds, temp_storage),
         }
     }
 
@@ -7618,7 +8226,15 @@ impl VariadicFunc {
             | VariadicFunc::ArrayFill { .. }
             | VariadicFunc::TimezoneTime
             | VariadicFunc::RegexpSplitToArray
-            | VariadicFunc::RegexpReplace => false,
+            | VariadicFunc::RegexpReplace
+            | VariadicFunc::TsRank
+            | VariadicFunc::JsonbPathExists
+            | VariadicFunc::JsonbPathMatch
+            | VariadicFunc::JsonbPathQueryArray
+            | VariadicFunc::JsonbPathQueryFirst
+            | VariadicFunc::JsonbSet
+            | VariadicFunc::JsonbInsert
+            | VariadicFunc::JsonbSetLax => false,
         }
     }
 
@@ -7718,6 +8334,11 @@ impl VariadicFunc {
                 ScalarType::Array(Box::new(ScalarType::String)).nullable(in_nullable)
             }
             RegexpReplace => ScalarType::String.nullable(in_nullable),
+            TsRank => ScalarType::Float32.nullable(in_nullable),
+            JsonbPathExists | JsonbPathMatch => ScalarType::Bool.nullable(true),
+            JsonbPathQueryArray => ScalarType::Jsonb.nullable(in_nullable),
+            JsonbPathQueryFirst => ScalarType::Jsonb.nullable(true),
+            JsonbSet | JsonbInsert | JsonbSetLax => ScalarType::Jsonb.nullable(in_nullable),
         }
     }
 
@@ -7744,6 +8365,7 @@ impl VariadicFunc {
                 | VariadicFunc::RangeCreate { .. }
                 | VariadicFunc::ArrayPosition
                 | VariadicFunc::ArrayFill { .. }
+                | VariadicFunc::JsonbSetLax
         )
     }
 
@@ -7787,14 +8409,22 @@ impl VariadicFunc {
             | ArrayFill { .. }
             | TimezoneTime
             | RegexpSplitToArray
-            | RegexpReplace => false,
+            | RegexpReplace
+            | TsRank
+            | JsonbPathQueryArray
+            | JsonbSet
+            | JsonbInsert
+            | JsonbSetLax => false,
             Coalesce
             | Greatest
             | Least
             | MakeTimestamp
             | ArrayIndex { .. }
             | ListIndex
-            | RegexpMatch => true,
+            | RegexpMatch
+            | JsonbPathExists
+            | JsonbPathMatch
+            | JsonbPathQueryFirst => true,
         }
     }
 
@@ -7892,7 +8522,15 @@ impl VariadicFunc {
             | VariadicFunc::DateDiffTime
             | VariadicFunc::TimezoneTime
             | VariadicFunc::RegexpSplitToArray
-            | VariadicFunc::RegexpReplace => false,
+            | VariadicFunc::RegexpReplace
+            | VariadicFunc::TsRank
+            | VariadicFunc::JsonbPathExists
+            | VariadicFunc::JsonbPathMatch
+            | VariadicFunc::JsonbPathQueryArray
+            | VariadicFunc::JsonbPathQueryFirst
+            | VariadicFunc::JsonbSet
+            | VariadicFunc::JsonbInsert
+            | VariadicFunc::JsonbSetLax => false,
         }
     }
 }
@@ -7949,6 +8587,14 @@ impl fmt::Display for VariadicFunc {
             VariadicFunc::TimezoneTime => f.write_str("timezonet"),
             VariadicFunc::RegexpSplitToArray => f.write_str("regexp_split_to_array"),
             VariadicFunc::RegexpReplace => f.write_str("regexp_replace"),
+            VariadicFunc::TsRank => f.write_str("ts_rank"),
+            VariadicFunc::JsonbPathExists => f.write_str("jsonb_path_exists"),
+            VariadicFunc::JsonbPathMatch => f.write_str("jsonb_path_match"),
+            VariadicFunc::JsonbPathQueryArray => f.write_str("jsonb_path_query_array"),
+            VariadicFunc::JsonbPathQueryFirst => f.write_str("jsonb_path_query_first"),
+            VariadicFunc::JsonbSet => f.write_str("jsonb_set"),
+            VariadicFunc::JsonbInsert => f.write_str("jsonb_insert"),
+            VariadicFunc::JsonbSetLax => f.write_str("jsonb_set_lax"),
         }
     }
 }
@@ -8067,6 +8713,14 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::TimezoneTime => TimezoneTime(()),
             VariadicFunc::RegexpSplitToArray => RegexpSplitToArray(()),
             VariadicFunc::RegexpReplace => RegexpReplace(()),
+            VariadicFunc::TsRank => TsRank(()),
+            VariadicFunc::JsonbPathExists => JsonbPathExists(()),
+            VariadicFunc::JsonbPathMatch => JsonbPathMatch(()),
+            VariadicFunc::JsonbPathQueryArray => JsonbPathQueryArray(()),
+            VariadicFunc::JsonbPathQueryFirst => JsonbPathQueryFirst(()),
+            VariadicFunc::JsonbSet => JsonbSet(()),
+            VariadicFunc::JsonbInsert => JsonbInsert(()),
+            VariadicFunc::JsonbSetLax => JsonbSetLax(()),
         };
         ProtoVariadicFunc { kind: Some(kind) }
     }
@@ -8130,6 +8784,14 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                 TimezoneTime(()) => Ok(VariadicFunc::TimezoneTime),
                 RegexpSplitToArray(()) => Ok(VariadicFunc::RegexpSplitToArray),
                 RegexpReplace(()) => Ok(VariadicFunc::RegexpReplace),
+                TsRank(()) => Ok(VariadicFunc::TsRank),
+                JsonbPathExists(()) => Ok(VariadicFunc::JsonbPathExists),
+                JsonbPathMatch(()) => Ok(VariadicFunc::JsonbPathMatch),
+                JsonbPathQueryArray(()) => Ok(VariadicFunc::JsonbPathQueryArray),
+                JsonbPathQueryFirst(()) => Ok(VariadicFunc::JsonbPathQueryFirst),
+                JsonbSet(()) => Ok(VariadicFunc::JsonbSet),
+                JsonbInsert(()) => Ok(VariadicFunc::JsonbInsert),
+                JsonbSetLax(()) => Ok(VariadicFunc::JsonbSetLax),
             }
         } else {
             Err(TryFromProtoError::missing_field(
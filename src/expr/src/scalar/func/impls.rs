@@ -13,14 +13,18 @@ mod byte;
 mod char;
 mod date;
 mod datum;
+mod enum_type;
 mod float32;
 mod float64;
+mod hll;
+mod inet;
 mod int16;
 mod int2vector;
 mod int32;
 mod int64;
 mod interval;
 mod jsonb;
+mod jsonpath;
 mod list;
 mod map;
 mod mz_acl_item;
@@ -32,6 +36,7 @@ mod range;
 mod record;
 mod regproc;
 mod string;
+mod text_search;
 mod time;
 mod timestamp;
 mod uint16;
@@ -46,14 +51,18 @@ pub use crate::scalar::func::impls::byte::*;
 pub use crate::scalar::func::impls::char::*;
 pub use crate::scalar::func::impls::date::*;
 pub use crate::scalar::func::impls::datum::*;
+pub use crate::scalar::func::impls::enum_type::*;
 pub use crate::scalar::func::impls::float32::*;
 pub use crate::scalar::func::impls::float64::*;
+pub use crate::scalar::func::impls::hll::*;
+pub use crate::scalar::func::impls::inet::*;
 pub use crate::scalar::func::impls::int16::*;
 pub use crate::scalar::func::impls::int2vector::*;
 pub use crate::scalar::func::impls::int32::*;
 pub use crate::scalar::func::impls::int64::*;
 pub use crate::scalar::func::impls::interval::*;
 pub use crate::scalar::func::impls::jsonb::*;
+pub use crate::scalar::func::impls::jsonpath::*;
 pub use crate::scalar::func::impls::list::*;
 pub use crate::scalar::func::impls::map::*;
 pub use crate::scalar::func::impls::mz_acl_item::*;
@@ -65,6 +74,7 @@ pub use crate::scalar::func::impls::range::*;
 pub use crate::scalar::func::impls::record::*;
 pub use crate::scalar::func::impls::regproc::*;
 pub use crate::scalar::func::impls::string::*;
+pub use crate::scalar::func::impls::text_search::*;
 pub use crate::scalar::func::impls::time::*;
 pub use crate::scalar::func::impls::timestamp::*;
 pub use crate::scalar::func::impls::uint16::*;
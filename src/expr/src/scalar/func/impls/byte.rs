@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use mz_repr::adt::hll::Hll;
 use mz_repr::strconv;
 
 use crate::EvalError;
@@ -37,3 +38,13 @@ sqlfunc!(
         i32::try_from(val).or(Err(EvalError::Int32OutOfRange(val.to_string())))
     }
 );
+
+sqlfunc!(
+    #[sqlname = "bytea_to_hll"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastHllToBytes)]
+    fn cast_bytes_to_hll<'a>(a: &'a [u8]) -> Result<Hll, EvalError> {
+        Hll::decode(a)
+            .map_err(|e| EvalError::InvalidParameterValue(format!("invalid hll sketch: {e}")))
+    }
+);
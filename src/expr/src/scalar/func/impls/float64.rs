@@ -14,7 +14,8 @@ use mz_lowertest::MzReflect;
 use mz_ore::cast::TryCastFrom;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
 use mz_repr::adt::timestamp::CheckedTimestamp;
-use mz_repr::{strconv, ColumnType, ScalarType};
+use mz_repr::{strconv, ColumnType, Datum, ScalarType};
+use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
 use crate::scalar::func::EagerUnaryFunc;
@@ -494,3 +495,63 @@ sqlfunc!(
         }
     }
 );
+
+sqlfunc!(
+    #[sqlname = "check_percentile_fraction"]
+    #[preserves_uniqueness = true]
+    fn check_percentile_fraction(a: f64) -> Result<f64, EvalError> {
+        if !(0.0..=1.0).contains(&a) {
+            return Err(EvalError::InvalidParameterValue(format!(
+                "percentile value {} is not between 0 and 1",
+                a
+            )));
+        }
+        Ok(a)
+    }
+);
+
+/// Validates each non-null element of an array of percentile fractions, like
+/// [`check_percentile_fraction`] does for a single fraction.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CheckPercentileFractions;
+
+impl<'a> EagerUnaryFunc<'a> for CheckPercentileFractions {
+    type Input = Datum<'a>;
+    type Output = Result<Datum<'a>, EvalError>;
+
+    fn call(&self, a: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+        if a.is_null() {
+            return Ok(a);
+        }
+        for fraction in a.unwrap_array().elements().iter() {
+            if !fraction.is_null() {
+                check_percentile_fraction(fraction.unwrap_float64())?;
+            }
+        }
+        Ok(a)
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        input
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for CheckPercentileFractions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("check_percentile_fractions")
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_repr::adt::hll::Hll;
+use mz_repr::{Datum, Row};
+
+sqlfunc!(
+    #[sqlname = "hll_to_text"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastStringToHll)]
+    fn cast_hll_to_string(a: Hll) -> String {
+        a.to_string()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "hll_to_bytea"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastBytesToHll)]
+    fn cast_hll_to_bytes(a: Hll) -> Vec<u8> {
+        a.encode()
+    }
+);
+
+sqlfunc!(
+    // The sketch of a single value, which `hll_merge` combines into the
+    // sketch of a group. Values are hashed by their row encoding, so equal
+    // values of the same type always land in the same register.
+    #[sqlname = "hll_singleton"]
+    fn hll_singleton<'a>(a: Datum<'a>) -> Option<Hll> {
+        if a.is_null() {
+            return None;
+        }
+        let row = Row::pack_slice(&[a]);
+        Some(Hll::from_hash(seahash::hash(row.data())))
+    }
+);
+
+sqlfunc!(
+    fn hll_estimate(a: Hll) -> i64 {
+        // Float-to-integer `as` conversions saturate, and the estimate is
+        // non-negative and far below `i64::MAX` in any case.
+        #[allow(clippy::as_conversions)]
+        {
+            a.estimate().round() as i64
+        }
+    }
+);
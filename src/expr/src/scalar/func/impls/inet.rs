@@ -0,0 +1,81 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_repr::adt::inet::{Cidr, Inet};
+use mz_repr::strconv;
+
+sqlfunc!(
+    #[sqlname = "inet_to_text"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastStringToInet)]
+    fn cast_inet_to_string(a: Inet) -> String {
+        // Unlike the output function, the cast to text always includes the
+        // prefix length, as in PostgreSQL.
+        let mut buf = String::new();
+        strconv::format_cidr(&mut buf, Cidr(a));
+        buf
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "cidr_to_text"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastStringToCidr)]
+    fn cast_cidr_to_string(a: Cidr) -> String {
+        let mut buf = String::new();
+        strconv::format_cidr(&mut buf, a);
+        buf
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "cidr_to_inet"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastInetToCidr)]
+    fn cast_cidr_to_inet(a: Cidr) -> Inet {
+        a.0
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "inet_to_cidr"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastCidrToInet)]
+    fn cast_inet_to_cidr(a: Inet) -> Cidr {
+        Cidr(a.network())
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "host"]
+    fn inet_host(a: Inet) -> String {
+        a.addr().to_string()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "masklen"]
+    fn inet_masklen(a: Inet) -> i32 {
+        i32::from(a.prefix_len())
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "network"]
+    fn inet_network(a: Inet) -> Cidr {
+        Cidr(a.network())
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "family"]
+    fn inet_family(a: Inet) -> i32 {
+        a.family()
+    }
+);
@@ -47,6 +47,32 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "check_ntile_argument"]
+    #[preserves_uniqueness = true]
+    fn check_ntile_argument(a: i32) -> Result<i32, EvalError> {
+        if a <= 0 {
+            return Err(EvalError::InvalidParameterValue(
+                "argument of ntile must be greater than zero".into(),
+            ));
+        }
+        Ok(a)
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "check_nth_value_argument"]
+    #[preserves_uniqueness = true]
+    fn check_nth_value_argument(a: i32) -> Result<i32, EvalError> {
+        if a <= 0 {
+            return Err(EvalError::InvalidParameterValue(
+                "argument of nth_value must be greater than zero".into(),
+            ));
+        }
+        Ok(a)
+    }
+);
+
 sqlfunc!(
     #[sqlname = "integer_to_boolean"]
     #[preserves_uniqueness = false]
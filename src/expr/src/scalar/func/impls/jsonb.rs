@@ -10,9 +10,10 @@
 use std::fmt;
 
 use mz_lowertest::MzReflect;
+use mz_ore::cast::CastFrom;
 use mz_repr::adt::jsonb::{Jsonb, JsonbRef};
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
-use mz_repr::{strconv, ColumnType, Datum, Row, RowPacker, ScalarType};
+use mz_repr::{strconv, ColumnType, Datum, Row, RowArena, RowPacker, ScalarType};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
@@ -246,3 +247,231 @@ sqlfunc!(
         buf
     }
 );
+
+/// The modification applied at the end of a path by [`jsonb_modify_path`].
+#[derive(Clone, Copy)]
+enum PathOp<'a> {
+    /// Replaces the value at the path. If only the last path element is
+    /// missing and `create_if_missing` is set, the value is added instead.
+    Set {
+        value: Datum<'a>,
+        create_if_missing: bool,
+    },
+    /// Inserts the value into the array before (or after) the path's last
+    /// element, or adds it to the object under the path's last key.
+    Insert { value: Datum<'a>, after: bool },
+    /// Removes the value at the path.
+    Delete,
+}
+
+/// Applies `op` at `path` within `target`, leaving `target` unchanged if
+/// any but the last path element does not exist.
+fn jsonb_modify_path<'a>(
+    target: Datum<'a>,
+    path: Datum<'a>,
+    op: PathOp<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    if !matches!(target, Datum::Map(_) | Datum::List(_)) {
+        let msg = match op {
+            PathOp::Delete => "cannot delete path in scalar",
+            PathOp::Set { .. } | PathOp::Insert { .. } => "cannot set path in scalar",
+        };
+        return Err(EvalError::InvalidParameterValue(msg.into()));
+    }
+    let path = path.unwrap_array().elements().iter().collect::<Vec<_>>();
+    if path.is_empty() {
+        return Ok(target);
+    }
+    modify_path(target, &path, 0, op, temp_storage)
+}
+
+fn modify_path<'a>(
+    json: Datum<'a>,
+    path: &[Datum<'a>],
+    level: usize,
+    op: PathOp<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let key = match path[level] {
+        Datum::String(key) => key,
+        Datum::Null => {
+            return Err(EvalError::InvalidParameterValue(format!(
+                "path element at position {} is null",
+                level + 1
+            )))
+        }
+        _ => unreachable!("jsonb paths known to be text arrays"),
+    };
+    let last = level + 1 == path.len();
+    match json {
+        Datum::Map(dict) => {
+            let existing = dict.iter().find(|(k, _v)| *k == key).map(|(_k, v)| v);
+            let new = match (existing, op) {
+                (Some(v), _) if !last => Some(modify_path(v, path, level + 1, op, temp_storage)?),
+                (Some(_), PathOp::Set { value, .. }) => Some(value),
+                (Some(_), PathOp::Insert { .. }) => {
+                    return Err(EvalError::InvalidParameterValue(
+                        "cannot replace existing key".into(),
+                    ))
+                }
+                (Some(_), PathOp::Delete) => None,
+                (
+                    None,
+                    PathOp::Set {
+                        value,
+                        create_if_missing: true,
+                    }
+                    | PathOp::Insert { value, .. },
+                ) if last => Some(value),
+                (None, _) => return Ok(json),
+            };
+            let mut pairs = dict.iter().filter(|(k, _v)| *k != key).collect::<Vec<_>>();
+            if let Some(v) = new {
+                pairs.push((key, v));
+                pairs.sort_by(|(k1, _v1), (k2, _v2)| k1.cmp(k2));
+            }
+            Ok(temp_storage.make_datum(|packer| packer.push_dict(pairs)))
+        }
+        Datum::List(list) => {
+            let index = strconv::parse_int32(key).map_err(|_| {
+                EvalError::InvalidParameterValue(format!(
+                    "path element at position {} is not an integer: \"{}\"",
+                    level + 1,
+                    key
+                ))
+            })?;
+            let mut elems = list.iter().collect::<Vec<_>>();
+            // Negative indices count back from the end of the array; `None`
+            // means the index lies before the start of the array.
+            let index = if index >= 0 {
+                Some(usize::cast_from(index.unsigned_abs()))
+            } else {
+                elems
+                    .len()
+                    .checked_sub(usize::cast_from(index.unsigned_abs()))
+            };
+            match index.filter(|i| *i < elems.len()) {
+                Some(i) if !last => {
+                    elems[i] = modify_path(elems[i], path, level + 1, op, temp_storage)?
+                }
+                None if !last => return Ok(json),
+                Some(i) => match op {
+                    PathOp::Set { value, .. } => elems[i] = value,
+                    PathOp::Insert { value, after } => {
+                        elems.insert(if after { i + 1 } else { i }, value)
+                    }
+                    PathOp::Delete => {
+                        elems.remove(i);
+                    }
+                },
+                // Out-of-range indices add the value to the start or end of
+                // the array.
+                None => match op {
+                    PathOp::Set {
+                        value,
+                        create_if_missing: true,
+                    }
+                    | PathOp::Insert { value, .. } => match index {
+                        None => elems.insert(0, value),
+                        Some(_) => elems.push(value),
+                    },
+                    PathOp::Set { .. } | PathOp::Delete => return Ok(json),
+                },
+            }
+            Ok(temp_storage.make_datum(|packer| packer.push_list(elems)))
+        }
+        // Paths that run into a scalar before their end do not exist.
+        _ => Ok(json),
+    }
+}
+
+/// Replaces the value at `path` within `target` with `new_value`, i.e.
+/// `jsonb_set`.
+pub fn jsonb_set_inner<'a>(
+    target: Datum<'a>,
+    path: Datum<'a>,
+    new_value: Datum<'a>,
+    create_if_missing: bool,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let op = PathOp::Set {
+        value: new_value,
+        create_if_missing,
+    };
+    jsonb_modify_path(target, path, op, temp_storage)
+}
+
+/// Inserts `new_value` at `path` within `target`, i.e. `jsonb_insert`.
+pub fn jsonb_insert_inner<'a>(
+    target: Datum<'a>,
+    path: Datum<'a>,
+    new_value: Datum<'a>,
+    insert_after: bool,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let op = PathOp::Insert {
+        value: new_value,
+        after: insert_after,
+    };
+    jsonb_modify_path(target, path, op, temp_storage)
+}
+
+/// Removes the value at `path` within `target`, i.e. the `#-` operator.
+pub fn jsonb_delete_path_inner<'a>(
+    target: Datum<'a>,
+    path: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    jsonb_modify_path(target, path, PathOp::Delete, temp_storage)
+}
+
+/// How `jsonb_set_lax` handles a NULL new value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NullValueTreatment {
+    RaiseException,
+    UseJsonNull,
+    DeleteKey,
+    ReturnTarget,
+}
+
+impl NullValueTreatment {
+    pub fn from_datum(d: Datum) -> Result<Self, EvalError> {
+        match d {
+            Datum::String("raise_exception") => Ok(NullValueTreatment::RaiseException),
+            Datum::String("use_json_null") => Ok(NullValueTreatment::UseJsonNull),
+            Datum::String("delete_key") => Ok(NullValueTreatment::DeleteKey),
+            Datum::String("return_target") => Ok(NullValueTreatment::ReturnTarget),
+            _ => Err(EvalError::InvalidParameterValue(
+                "null_value_treatment must be \"delete_key\", \"return_target\", \"use_json_null\", or \"raise_exception\"".into(),
+            )),
+        }
+    }
+}
+
+/// Like [`jsonb_set_inner`], but handles an SQL NULL `new_value` according to
+/// `null_value_treatment`, i.e. `jsonb_set_lax`.
+pub fn jsonb_set_lax_inner<'a>(
+    target: Datum<'a>,
+    path: Datum<'a>,
+    new_value: Datum<'a>,
+    create_if_missing: bool,
+    null_value_treatment: NullValueTreatment,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    if !new_value.is_null() {
+        return jsonb_set_inner(target, path, new_value, create_if_missing, temp_storage);
+    }
+    match null_value_treatment {
+        NullValueTreatment::RaiseException => Err(EvalError::MustNotBeNull("JSON value".into())),
+        NullValueTreatment::UseJsonNull => jsonb_set_inner(
+            target,
+            path,
+            Datum::JsonNull,
+            create_if_missing,
+            temp_storage,
+        ),
+        NullValueTreatment::DeleteKey => jsonb_delete_path_inner(target, path, temp_storage),
+        NullValueTreatment::ReturnTarget => Ok(target),
+    }
+}
@@ -0,0 +1,1025 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Evaluation of SQL/JSON paths against `jsonb` values.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use dec::Rounding;
+use mz_lowertest::MzReflect;
+use mz_ore::cast::CastFrom;
+use mz_repr::adt::jsonpath::{
+    JsonPath, JsonPathAccessor, JsonPathArithmeticOp, JsonPathComparisonOp, JsonPathExpr,
+    JsonPathLiteral, JsonPathMethod, JsonPathUnaryOp,
+};
+use mz_repr::adt::numeric::{self, Numeric};
+use mz_repr::{ColumnType, Datum, Row, RowArena, ScalarType};
+use serde::{Deserialize, Serialize};
+
+use crate::scalar::func::LazyUnaryFunc;
+use crate::{EvalError, MirScalarExpr};
+
+sqlfunc!(
+    #[sqlname = "jsonpath_to_text"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastStringToJsonPath)]
+    fn cast_json_path_to_string(a: JsonPath) -> String {
+        a.to_string()
+    }
+);
+
+/// An error that occurred while evaluating a path.
+#[derive(Debug)]
+enum PathError {
+    /// An error that is suppressed when the `silent` argument of a
+    /// `jsonb_path_*` function is true, and that makes a predicate unknown.
+    Soft(EvalError),
+    /// An error that is never suppressed, like a reference to a variable that
+    /// does not exist.
+    Hard(EvalError),
+}
+
+fn soft<T>(message: String) -> Result<T, PathError> {
+    Err(PathError::Soft(EvalError::JsonPath(message)))
+}
+
+/// An item produced by a path: either a JSON value or a date/time value
+/// produced by `.datetime()`.
+#[derive(Debug, Clone, Copy)]
+enum Item<'a> {
+    Json(Datum<'a>),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    TimeTz(NaiveTime, FixedOffset),
+    Timestamp(NaiveDateTime),
+    TimestampTz(DateTime<FixedOffset>),
+}
+
+impl<'a> Item<'a> {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Item::Json(Datum::JsonNull) => "null",
+            Item::Json(Datum::True | Datum::False) => "boolean",
+            Item::Json(Datum::Numeric(_)) => "number",
+            Item::Json(Datum::String(_)) => "string",
+            Item::Json(Datum::List(_)) => "array",
+            Item::Json(Datum::Map(_)) => "object",
+            Item::Json(d) => unreachable!("{d:?} is not a jsonb datum"),
+            Item::Date(_) => "date",
+            Item::Time(_) => "time without time zone",
+            Item::TimeTz(..) => "time with time zone",
+            Item::Timestamp(_) => "timestamp without time zone",
+            Item::TimestampTz(_) => "timestamp with time zone",
+        }
+    }
+
+    fn numeric(&self) -> Option<Numeric> {
+        match self {
+            Item::Json(Datum::Numeric(n)) => Some(n.0),
+            _ => None,
+        }
+    }
+
+    /// Converts the item into a `jsonb` datum. Date/time values are converted
+    /// into strings in ISO 8601 format.
+    fn into_datum(self, temp_storage: &'a RowArena) -> Datum<'a> {
+        let s = match self {
+            Item::Json(d) => return d,
+            Item::Date(d) => d.format("%Y-%m-%d").to_string(),
+            Item::Time(t) => format_time(t),
+            Item::TimeTz(t, offset) => format!("{}{offset}", format_time(t)),
+            Item::Timestamp(ts) => format!("{}T{}", ts.format("%Y-%m-%d"), format_time(ts.time())),
+            Item::TimestampTz(ts) => format!(
+                "{}T{}{}",
+                ts.format("%Y-%m-%d"),
+                format_time(ts.time()),
+                ts.offset()
+            ),
+        };
+        Datum::String(temp_storage.push_string(s))
+    }
+}
+
+/// Formats a time with as many fractional digits as required.
+fn format_time(t: NaiveTime) -> String {
+    let mut s = t.format("%H:%M:%S").to_string();
+    let micros = t.nanosecond() / 1_000;
+    if micros > 0 {
+        let fraction = format!(".{micros:06}");
+        s.push_str(fraction.trim_end_matches('0'));
+    }
+    s
+}
+
+/// The result of a predicate, which may be unknown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tri {
+    True,
+    False,
+    Unknown,
+}
+
+impl From<bool> for Tri {
+    fn from(b: bool) -> Tri {
+        if b {
+            Tri::True
+        } else {
+            Tri::False
+        }
+    }
+}
+
+/// Evaluates a [`JsonPath`] against a single `jsonb` document.
+struct Executor<'a> {
+    strict: bool,
+    root: Datum<'a>,
+    vars: Datum<'a>,
+    temp_storage: &'a RowArena,
+    /// The next ID to assign to an object passed to `.keyvalue()`.
+    next_keyvalue_id: u64,
+}
+
+impl<'a> Executor<'a> {
+    fn new(
+        path: &JsonPath,
+        root: Datum<'a>,
+        vars: Datum<'a>,
+        temp_storage: &'a RowArena,
+    ) -> Result<Executor<'a>, EvalError> {
+        if !matches!(vars, Datum::Map(_)) {
+            return Err(EvalError::JsonPath(
+                "\"vars\" argument is not an object".into(),
+            ));
+        }
+        Ok(Executor {
+            strict: path.strict,
+            root,
+            vars,
+            temp_storage,
+            next_keyvalue_id: 0,
+        })
+    }
+
+    /// Evaluates `expr`, where `current` is the item being filtered, if any,
+    /// and `last` is the last subscript of the array being accessed, if any.
+    fn exec(
+        &mut self,
+        expr: &JsonPathExpr,
+        current: Option<Item<'a>>,
+        last: Option<i64>,
+    ) -> Result<Vec<Item<'a>>, PathError> {
+        let item = match expr {
+            JsonPathExpr::Root => Item::Json(self.root),
+            JsonPathExpr::Current => current.expect("@ is only allowed in filters"),
+            JsonPathExpr::Variable(name) => {
+                let value = self
+                    .vars
+                    .unwrap_map()
+                    .iter()
+                    .find(|(k, _)| k == name)
+                    .map(|(_, v)| v);
+                match value {
+                    Some(value) => Item::Json(value),
+                    None => {
+                        return Err(PathError::Hard(EvalError::JsonPath(format!(
+                            "could not find jsonpath variable \"{name}\""
+                        ))))
+                    }
+                }
+            }
+            JsonPathExpr::Last => {
+                let last = last.expect("last is only allowed in array subscripts");
+                Item::Json(Datum::from(Numeric::from(last)))
+            }
+            JsonPathExpr::Literal(literal) => Item::Json(match literal {
+                JsonPathLiteral::Null => Datum::JsonNull,
+                JsonPathLiteral::Bool(b) => Datum::from(*b),
+                JsonPathLiteral::Number(n) => Datum::from(*n),
+                JsonPathLiteral::String(s) => {
+                    Datum::String(self.temp_storage.push_string(s.clone()))
+                }
+            }),
+            JsonPathExpr::Accessor(base, accessor) => {
+                let mut out = vec![];
+                for item in self.exec(base, current, last)? {
+                    self.apply_accessor(item, accessor, current, last, true, &mut out)?;
+                }
+                return Ok(out);
+            }
+            JsonPathExpr::Unary(op, expr) => {
+                let mut out = vec![];
+                for item in self.exec_unwrapped(expr, current, last)? {
+                    let Some(mut n) = item.numeric() else {
+                        return soft(format!(
+                            "operand of unary jsonpath operator {op} is not a numeric value"
+                        ));
+                    };
+                    if *op == JsonPathUnaryOp::Minus {
+                        numeric::cx_datum().neg(&mut n);
+                    }
+                    out.push(Item::Json(Datum::from(n)));
+                }
+                return Ok(out);
+            }
+            JsonPathExpr::Arithmetic(op, left, right) => {
+                let a = self.arithmetic_operand(*op, left, "left", current, last)?;
+                let b = self.arithmetic_operand(*op, right, "right", current, last)?;
+                let n = arithmetic(*op, a, b).map_err(PathError::Soft)?;
+                Item::Json(Datum::from(n))
+            }
+            JsonPathExpr::Comparison(..)
+            | JsonPathExpr::And(..)
+            | JsonPathExpr::Or(..)
+            | JsonPathExpr::Not(..)
+            | JsonPathExpr::IsUnknown(..)
+            | JsonPathExpr::Exists(..)
+            | JsonPathExpr::LikeRegex { .. }
+            | JsonPathExpr::StartsWith(..) => Item::Json(match self.predicate(expr, current)? {
+                Tri::True => Datum::True,
+                Tri::False => Datum::False,
+                Tri::Unknown => Datum::JsonNull,
+            }),
+        };
+        Ok(vec![item])
+    }
+
+    /// Evaluates an operand of a binary arithmetic operator, which must be a
+    /// single number.
+    fn arithmetic_operand(
+        &mut self,
+        op: JsonPathArithmeticOp,
+        expr: &JsonPathExpr,
+        side: &str,
+        current: Option<Item<'a>>,
+        last: Option<i64>,
+    ) -> Result<Numeric, PathError> {
+        let n = match self.exec_unwrapped(expr, current, last)?.as_slice() {
+            [item] => item.numeric(),
+            _ => None,
+        };
+        match n {
+            Some(n) => Ok(n),
+            None => soft(format!(
+                "{side} operand of jsonpath operator {op} is not a single numeric value"
+            )),
+        }
+    }
+
+    /// Like [`Executor::exec`], but in lax mode unwraps any arrays among the
+    /// resulting items.
+    fn exec_unwrapped(
+        &mut self,
+        expr: &JsonPathExpr,
+        current: Option<Item<'a>>,
+        last: Option<i64>,
+    ) -> Result<Vec<Item<'a>>, PathError> {
+        let items = self.exec(expr, current, last)?;
+        if self.strict {
+            return Ok(items);
+        }
+        let mut out = vec![];
+        for item in items {
+            match item {
+                Item::Json(Datum::List(list)) => out.extend(list.iter().map(Item::Json)),
+                item => out.push(item),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Applies `accessor` to `item`, appending the resulting items to `out`.
+    ///
+    /// If `unwrap` is true and the path is in lax mode, accessors that expect
+    /// a non-array item are applied to each element of an array instead.
+    fn apply_accessor(
+        &mut self,
+        item: Item<'a>,
+        accessor: &JsonPathAccessor,
+        current: Option<Item<'a>>,
+        last: Option<i64>,
+        unwrap: bool,
+        out: &mut Vec<Item<'a>>,
+    ) -> Result<(), PathError> {
+        let unwraps = match accessor {
+            JsonPathAccessor::Member(_)
+            | JsonPathAccessor::MemberWildcard
+            | JsonPathAccessor::Filter(_) => true,
+            JsonPathAccessor::Method(method) => {
+                !matches!(method, JsonPathMethod::Type | JsonPathMethod::Size)
+            }
+            JsonPathAccessor::Any { .. }
+            | JsonPathAccessor::ArrayWildcard
+            | JsonPathAccessor::Subscripts(_) => false,
+        };
+        if let Item::Json(Datum::List(list)) = item {
+            if unwraps && unwrap && !self.strict {
+                for elem in list.iter() {
+                    self.apply_accessor(Item::Json(elem), accessor, current, last, false, out)?;
+                }
+                return Ok(());
+            }
+        }
+        match accessor {
+            JsonPathAccessor::Member(key) => match item {
+                Item::Json(Datum::Map(map)) => match map.iter().find(|(k, _)| k == key) {
+                    Some((_, value)) => out.push(Item::Json(value)),
+                    None if self.strict => {
+                        return soft(format!("JSON object does not contain key \"{key}\""))
+                    }
+                    None => (),
+                },
+                _ if self.strict => {
+                    return soft("jsonpath member accessor can only be applied to an object".into())
+                }
+                _ => (),
+            },
+            JsonPathAccessor::MemberWildcard => match item {
+                Item::Json(Datum::Map(map)) => out.extend(map.iter().map(|(_, v)| Item::Json(v))),
+                _ if self.strict => {
+                    return soft(
+                        "jsonpath wildcard member accessor can only be applied to an object".into(),
+                    )
+                }
+                _ => (),
+            },
+            JsonPathAccessor::Any { first, last } => {
+                if *first == 0 {
+                    out.push(item);
+                }
+                if let Item::Json(datum) = item {
+                    any_descendants(datum, 1, *first, *last, out);
+                }
+            }
+            JsonPathAccessor::ArrayWildcard => match item {
+                Item::Json(Datum::List(list)) => out.extend(list.iter().map(Item::Json)),
+                _ if self.strict => {
+                    return soft(
+                        "jsonpath wildcard array accessor can only be applied to an array".into(),
+                    )
+                }
+                item => out.push(item),
+            },
+            JsonPathAccessor::Subscripts(subscripts) => {
+                let elems: Vec<Item<'a>> = match item {
+                    Item::Json(Datum::List(list)) => list.iter().map(Item::Json).collect(),
+                    _ if self.strict => {
+                        return soft(
+                            "jsonpath array accessor can only be applied to an array".into(),
+                        )
+                    }
+                    item => vec![item],
+                };
+                let len = i64::try_from(elems.len()).expect("array length fits in i64");
+                for (from, to) in subscripts {
+                    let from = self.subscript(from, current, len - 1)?;
+                    let to = match to {
+                        Some(to) => self.subscript(to, current, len - 1)?,
+                        None => from,
+                    };
+                    if self.strict && (from < 0 || from > to || to >= len) {
+                        return soft("jsonpath array subscript is out of bounds".into());
+                    }
+                    let from = from.max(0);
+                    let to = to.min(len - 1);
+                    for i in from..=to {
+                        let i = usize::try_from(i).expect("subscript is in bounds");
+                        out.push(elems[i]);
+                    }
+                }
+            }
+            JsonPathAccessor::Filter(predicate) => {
+                if self.predicate(predicate, Some(item))? == Tri::True {
+                    out.push(item);
+                }
+            }
+            JsonPathAccessor::Method(method) => self.apply_method(item, *method, out)?,
+        }
+        Ok(())
+    }
+
+    /// Evaluates an array subscript, which must be a single number.
+    fn subscript(
+        &mut self,
+        expr: &JsonPathExpr,
+        current: Option<Item<'a>>,
+        last: i64,
+    ) -> Result<i64, PathError> {
+        let items = self.exec(expr, current, Some(last))?;
+        let n = match items.as_slice() {
+            [item] => item.numeric(),
+            _ => None,
+        };
+        let Some(mut n) = n else {
+            return soft("jsonpath array subscript is not a single numeric value".into());
+        };
+        let mut cx = numeric::cx_datum();
+        cx.set_rounding(Rounding::Down);
+        cx.round(&mut n);
+        match cx.try_into_i32(n) {
+            Ok(i) => Ok(i64::from(i)),
+            Err(_) => soft("jsonpath array subscript is out of integer range".into()),
+        }
+    }
+
+    /// Applies an item method to `item`, appending the resulting items to
+    /// `out`.
+    fn apply_method(
+        &mut self,
+        item: Item<'a>,
+        method: JsonPathMethod,
+        out: &mut Vec<Item<'a>>,
+    ) -> Result<(), PathError> {
+        let name = method.name();
+        let datum = match method {
+            JsonPathMethod::Type => Datum::String(item.type_name()),
+            JsonPathMethod::Size => match item {
+                Item::Json(Datum::List(list)) => {
+                    Datum::from(Numeric::from(u64::cast_from(list.iter().count())))
+                }
+                _ if self.strict => {
+                    return soft(format!(
+                        "jsonpath item method .{name}() can only be applied to an array"
+                    ))
+                }
+                _ => Datum::from(Numeric::from(1)),
+            },
+            JsonPathMethod::Double => {
+                let f = match item {
+                    Item::Json(Datum::Numeric(n)) => n.to_string().parse::<f64>().ok(),
+                    Item::Json(Datum::String(s)) => match s.trim().parse::<f64>() {
+                        Ok(f) => Some(f),
+                        Err(_) => {
+                            return soft(format!(
+                                "string argument of jsonpath item method .{name}() is not a \
+                                 valid representation of a double precision number"
+                            ))
+                        }
+                    },
+                    _ => {
+                        return soft(format!(
+                            "jsonpath item method .{name}() can only be applied to a string or \
+                             numeric value"
+                        ))
+                    }
+                };
+                match f.filter(|f| f.is_finite()) {
+                    Some(f) => Datum::from(Numeric::from(f)),
+                    None => {
+                        return soft(format!(
+                            "NaN or Infinity is not allowed for jsonpath item method .{name}()"
+                        ))
+                    }
+                }
+            }
+            JsonPathMethod::Ceiling | JsonPathMethod::Floor | JsonPathMethod::Abs => {
+                let Some(mut n) = item.numeric() else {
+                    return soft(format!(
+                        "jsonpath item method .{name}() can only be applied to a numeric value"
+                    ));
+                };
+                let mut cx = numeric::cx_datum();
+                match method {
+                    JsonPathMethod::Abs => cx.abs(&mut n),
+                    _ if n.exponent() >= 0 => (),
+                    _ => {
+                        cx.set_rounding(if method == JsonPathMethod::Ceiling {
+                            Rounding::Ceiling
+                        } else {
+                            Rounding::Floor
+                        });
+                        cx.round(&mut n);
+                    }
+                }
+                numeric::munge_numeric(&mut n).expect("rounding cannot overflow");
+                Datum::from(n)
+            }
+            JsonPathMethod::Datetime => match item {
+                Item::Json(Datum::String(s)) => {
+                    out.push(parse_datetime(s)?);
+                    return Ok(());
+                }
+                _ => {
+                    return soft(format!(
+                        "jsonpath item method .{name}() can only be applied to a string"
+                    ))
+                }
+            },
+            JsonPathMethod::KeyValue => match item {
+                Item::Json(Datum::Map(map)) => {
+                    let id = self.next_keyvalue_id;
+                    self.next_keyvalue_id += 1;
+                    let id = Datum::from(Numeric::from(id));
+                    for (k, v) in map.iter() {
+                        out.push(Item::Json(self.temp_storage.make_datum(|packer| {
+                            packer.push_dict([("id", id), ("key", Datum::String(k)), ("value", v)])
+                        })));
+                    }
+                    return Ok(());
+                }
+                _ => {
+                    return soft(format!(
+                        "jsonpath item method .{name}() can only be applied to an object"
+                    ))
+                }
+            },
+        };
+        out.push(Item::Json(datum));
+        Ok(())
+    }
+
+    /// Evaluates a predicate.
+    fn predicate(
+        &mut self,
+        expr: &JsonPathExpr,
+        current: Option<Item<'a>>,
+    ) -> Result<Tri, PathError> {
+        match expr {
+            JsonPathExpr::Comparison(op, left, right) => {
+                let Some(lefts) = self.operands(left, current)? else {
+                    return Ok(Tri::Unknown);
+                };
+                let Some(rights) = self.operands(right, current)? else {
+                    return Ok(Tri::Unknown);
+                };
+                Ok(self.any(
+                    lefts
+                        .iter()
+                        .flat_map(|l| rights.iter().map(move |r| compare(*op, l, r))),
+                ))
+            }
+            JsonPathExpr::And(left, right) => match self.predicate(left, current)? {
+                Tri::False => Ok(Tri::False),
+                Tri::True => self.predicate(right, current),
+                Tri::Unknown => match self.predicate(right, current)? {
+                    Tri::False => Ok(Tri::False),
+                    _ => Ok(Tri::Unknown),
+                },
+            },
+            JsonPathExpr::Or(left, right) => match self.predicate(left, current)? {
+                Tri::True => Ok(Tri::True),
+                Tri::False => self.predicate(right, current),
+                Tri::Unknown => match self.predicate(right, current)? {
+                    Tri::True => Ok(Tri::True),
+                    _ => Ok(Tri::Unknown),
+                },
+            },
+            JsonPathExpr::Not(expr) => Ok(match self.predicate(expr, current)? {
+                Tri::True => Tri::False,
+                Tri::False => Tri::True,
+                Tri::Unknown => Tri::Unknown,
+            }),
+            JsonPathExpr::IsUnknown(expr) => {
+                Ok(Tri::from(self.predicate(expr, current)? == Tri::Unknown))
+            }
+            JsonPathExpr::Exists(expr) => match self.exec(expr, current, None) {
+                Ok(items) => Ok(Tri::from(!items.is_empty())),
+                Err(PathError::Soft(_)) => Ok(Tri::Unknown),
+                Err(e) => Err(e),
+            },
+            JsonPathExpr::LikeRegex { expr, regex, .. } => {
+                let Some(items) = self.operands(expr, current)? else {
+                    return Ok(Tri::Unknown);
+                };
+                Ok(self.any(items.iter().map(|item| match item {
+                    Item::Json(Datum::String(s)) => Tri::from(regex.is_match(s)),
+                    _ => Tri::Unknown,
+                })))
+            }
+            JsonPathExpr::StartsWith(left, right) => {
+                let Some(lefts) = self.operands(left, current)? else {
+                    return Ok(Tri::Unknown);
+                };
+                let prefix = match self.operands(right, current)?.as_deref() {
+                    Some([Item::Json(Datum::String(prefix))]) => *prefix,
+                    _ => return Ok(Tri::Unknown),
+                };
+                Ok(self.any(lefts.iter().map(|item| match item {
+                    Item::Json(Datum::String(s)) => Tri::from(s.starts_with(prefix)),
+                    _ => Tri::Unknown,
+                })))
+            }
+            _ => unreachable!("{expr:?} is not a predicate"),
+        }
+    }
+
+    /// Evaluates the operand of a predicate, returning `None` if evaluation
+    /// raised an error that makes the predicate unknown.
+    fn operands(
+        &mut self,
+        expr: &JsonPathExpr,
+        current: Option<Item<'a>>,
+    ) -> Result<Option<Vec<Item<'a>>>, PathError> {
+        match self.exec_unwrapped(expr, current, None) {
+            Ok(items) => Ok(Some(items)),
+            Err(PathError::Soft(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Combines the results of applying a predicate to each of its operands.
+    ///
+    /// The predicate is true if any result is true. In strict mode, it is
+    /// unknown if any result is unknown; in lax mode, only if no result is
+    /// true.
+    fn any(&self, results: impl Iterator<Item = Tri>) -> Tri {
+        let mut found = false;
+        let mut unknown = false;
+        for result in results {
+            match result {
+                Tri::True if !self.strict => return Tri::True,
+                Tri::True => found = true,
+                Tri::Unknown if self.strict => return Tri::Unknown,
+                Tri::Unknown => unknown = true,
+                Tri::False => (),
+            }
+        }
+        if found {
+            Tri::True
+        } else if unknown {
+            Tri::Unknown
+        } else {
+            Tri::False
+        }
+    }
+}
+
+/// Appends the values nested in `datum` at levels `first` through `last` to
+/// `out`, in pre-order. The children of `datum` are at level `level`.
+fn any_descendants<'a>(
+    datum: Datum<'a>,
+    level: u32,
+    first: u32,
+    last: u32,
+    out: &mut Vec<Item<'a>>,
+) {
+    if level > last {
+        return;
+    }
+    let children: Vec<Datum<'a>> = match datum {
+        Datum::List(list) => list.iter().collect(),
+        Datum::Map(map) => map.iter().map(|(_, v)| v).collect(),
+        _ => return,
+    };
+    for child in children {
+        if level >= first {
+            out.push(Item::Json(child));
+        }
+        if level < last {
+            any_descendants(child, level + 1, first, last, out);
+        }
+    }
+}
+
+fn arithmetic(op: JsonPathArithmeticOp, mut a: Numeric, b: Numeric) -> Result<Numeric, EvalError> {
+    let mut cx = numeric::cx_datum();
+    match op {
+        JsonPathArithmeticOp::Add => cx.add(&mut a, &b),
+        JsonPathArithmeticOp::Sub => cx.sub(&mut a, &b),
+        JsonPathArithmeticOp::Mul => cx.mul(&mut a, &b),
+        JsonPathArithmeticOp::Div | JsonPathArithmeticOp::Mod if b.is_zero() => {
+            return Err(EvalError::DivisionByZero)
+        }
+        JsonPathArithmeticOp::Div => cx.div(&mut a, &b),
+        JsonPathArithmeticOp::Mod => cx.rem(&mut a, &b),
+    }
+    let status = cx.status();
+    if status.overflow() {
+        Err(EvalError::FloatOverflow)
+    } else if status.subnormal() {
+        Err(EvalError::FloatUnderflow)
+    } else {
+        numeric::munge_numeric(&mut a).map_err(|_| EvalError::FloatOverflow)?;
+        Ok(a)
+    }
+}
+
+/// Compares two items.
+///
+/// Items of different types are incomparable, except that `null` is not equal
+/// to anything but `null`. Arrays and objects are incomparable.
+fn compare(op: JsonPathComparisonOp, a: &Item, b: &Item) -> Tri {
+    let ordering = match (a, b) {
+        (Item::Json(a), Item::Json(b)) => match (a, b) {
+            (Datum::JsonNull, Datum::JsonNull) => Some(Ordering::Equal),
+            (Datum::JsonNull, _) | (_, Datum::JsonNull) => {
+                return Tri::from(op == JsonPathComparisonOp::NotEq)
+            }
+            (Datum::True | Datum::False, Datum::True | Datum::False) => Some(a.cmp(b)),
+            (Datum::Numeric(a), Datum::Numeric(b)) => Some(a.cmp(b)),
+            (Datum::String(a), Datum::String(b)) => Some(a.cmp(b)),
+            _ => None,
+        },
+        (Item::Json(Datum::JsonNull), _) | (_, Item::Json(Datum::JsonNull)) => {
+            return Tri::from(op == JsonPathComparisonOp::NotEq)
+        }
+        (Item::Json(_), _) | (_, Item::Json(_)) => None,
+        (a, b) => compare_datetimes(a, b),
+    };
+    match ordering {
+        None => Tri::Unknown,
+        Some(ordering) => Tri::from(match op {
+            JsonPathComparisonOp::Eq => ordering == Ordering::Equal,
+            JsonPathComparisonOp::NotEq => ordering != Ordering::Equal,
+            JsonPathComparisonOp::Lt => ordering == Ordering::Less,
+            JsonPathComparisonOp::Lte => ordering != Ordering::Greater,
+            JsonPathComparisonOp::Gt => ordering == Ordering::Greater,
+            JsonPathComparisonOp::Gte => ordering != Ordering::Less,
+        }),
+    }
+}
+
+/// Compares two date/time items.
+///
+/// Dates and timestamps without time zones are comparable with each other,
+/// as are times and timestamps with time zones with values of the same type.
+/// Comparing values with and without time zones would require a session time
+/// zone, so such values are incomparable.
+fn compare_datetimes(a: &Item, b: &Item) -> Option<Ordering> {
+    let naive = |item: &Item| match item {
+        Item::Date(d) => Some(d.and_time(NaiveTime::MIN)),
+        Item::Timestamp(ts) => Some(*ts),
+        _ => None,
+    };
+    match (a, b) {
+        (Item::Time(a), Item::Time(b)) => Some(a.cmp(b)),
+        (Item::TimeTz(a, a_offset), Item::TimeTz(b, b_offset)) => {
+            let a = *a - chrono::Duration::seconds(i64::from(a_offset.local_minus_utc()));
+            let b = *b - chrono::Duration::seconds(i64::from(b_offset.local_minus_utc()));
+            Some(a.cmp(&b))
+        }
+        (Item::TimestampTz(a), Item::TimestampTz(b)) => Some(a.cmp(b)),
+        (a, b) => Some(naive(a)?.cmp(&naive(b)?)),
+    }
+}
+
+/// Parses a string into a date/time item, i.e. `.datetime()`.
+///
+/// The string must be in one of the ISO 8601 formats for dates, times, or
+/// timestamps, with or without time zone offsets.
+fn parse_datetime<'a>(s: &str) -> Result<Item<'a>, PathError> {
+    for sep in [" ", "T"] {
+        for tz in ["%:z", "%#z"] {
+            let format = format!("%Y-%m-%d{sep}%H:%M:%S%.f{tz}");
+            if let Ok(ts) = DateTime::parse_from_str(s, &format) {
+                return Ok(Item::TimestampTz(ts));
+            }
+        }
+        let format = format!("%Y-%m-%d{sep}%H:%M:%S%.f");
+        if let Ok(ts) = NaiveDateTime::parse_from_str(s, &format) {
+            return Ok(Item::Timestamp(ts));
+        }
+    }
+    if let Ok(d) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(Item::Date(d));
+    }
+    // chrono cannot parse a time and offset without a date, so split off the
+    // offset.
+    if let Some(i) = s.rfind(['+', '-']) {
+        let (time, offset) = s.split_at(i);
+        if let (Ok(time), Some(offset)) = (
+            NaiveTime::parse_from_str(time, "%H:%M:%S%.f"),
+            parse_offset(offset),
+        ) {
+            return Ok(Item::TimeTz(time, offset));
+        }
+    }
+    if let Ok(t) = NaiveTime::parse_from_str(s, "%H:%M:%S%.f") {
+        return Ok(Item::Time(t));
+    }
+    soft(format!("datetime format is not recognized: \"{s}\""))
+}
+
+/// Parses a time zone offset of the form `+HH` or `+HH:MM`.
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, s) = match s.split_at(1) {
+        ("+", s) => (1, s),
+        ("-", s) => (-1, s),
+        _ => return None,
+    };
+    let (hours, minutes) = match s.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None => (s, "0"),
+    };
+    if hours.len() != 2
+        || !hours
+            .chars()
+            .chain(minutes.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Evaluates `path` against `target`, returning the resulting items or `None`
+/// if evaluation failed and `silent` is true.
+fn exec_path<'a>(
+    target: Datum<'a>,
+    path: &JsonPath,
+    vars: Datum<'a>,
+    silent: bool,
+    temp_storage: &'a RowArena,
+) -> Result<Option<Vec<Datum<'a>>>, EvalError> {
+    let mut executor = Executor::new(path, target, vars, temp_storage)?;
+    match executor.exec(&path.expr, None, None) {
+        Ok(items) => Ok(Some(
+            items
+                .into_iter()
+                .map(|item| item.into_datum(temp_storage))
+                .collect(),
+        )),
+        Err(PathError::Soft(_)) if silent => Ok(None),
+        Err(PathError::Soft(e) | PathError::Hard(e)) => Err(e),
+    }
+}
+
+/// Reports whether `path` returns any items for `target`, i.e.
+/// `jsonb_path_exists`.
+pub fn jsonb_path_exists_inner<'a>(
+    target: Datum<'a>,
+    path: &JsonPath,
+    vars: Datum<'a>,
+    silent: bool,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    Ok(match exec_path(target, path, vars, silent, temp_storage)? {
+        Some(items) => Datum::from(!items.is_empty()),
+        None => Datum::Null,
+    })
+}
+
+/// Returns the result of the predicate `path` for `target`, i.e.
+/// `jsonb_path_match`.
+pub fn jsonb_path_match_inner<'a>(
+    target: Datum<'a>,
+    path: &JsonPath,
+    vars: Datum<'a>,
+    silent: bool,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    match exec_path(target, path, vars, silent, temp_storage)?.as_deref() {
+        Some([Datum::True]) => Ok(Datum::True),
+        Some([Datum::False]) => Ok(Datum::False),
+        Some([Datum::JsonNull]) | None => Ok(Datum::Null),
+        Some(_) if silent => Ok(Datum::Null),
+        Some(_) => Err(EvalError::JsonPath(
+            "single boolean result is expected".into(),
+        )),
+    }
+}
+
+/// Returns the items `path` returns for `target` as an array, i.e.
+/// `jsonb_path_query_array`.
+pub fn jsonb_path_query_array_inner<'a>(
+    target: Datum<'a>,
+    path: &JsonPath,
+    vars: Datum<'a>,
+    silent: bool,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let items = exec_path(target, path, vars, silent, temp_storage)?.unwrap_or_default();
+    Ok(temp_storage.make_datum(|packer| packer.push_list(items)))
+}
+
+/// Returns the first item `path` returns for `target`, i.e.
+/// `jsonb_path_query_first`.
+pub fn jsonb_path_query_first_inner<'a>(
+    target: Datum<'a>,
+    path: &JsonPath,
+    vars: Datum<'a>,
+    silent: bool,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let items = exec_path(target, path, vars, silent, temp_storage)?;
+    Ok(items
+        .and_then(|items| items.into_iter().next())
+        .unwrap_or(Datum::Null))
+}
+
+/// Returns the items `path` returns for `target`, failing on any error.
+pub fn jsonb_path_query_inner<'a>(
+    target: Datum<'a>,
+    path: &JsonPath,
+    vars: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Vec<Datum<'a>>, EvalError> {
+    Ok(exec_path(target, path, vars, false, temp_storage)?.unwrap_or_default())
+}
+
+macro_rules! jsonb_path_func {
+    (
+        $name:ident,
+        $sql_name:literal,
+        $inner:ident,
+        |$input_type:pat_param| $output_type:expr,
+        $introduces_nulls:expr
+    ) => {
+        /// A `jsonb_path_*` function whose path, variables, and `silent` flag
+        /// are known ahead of time, so that the path is only parsed once.
+        #[derive(
+            Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+        )]
+        pub struct $name {
+            pub path: JsonPath,
+            /// A row containing the `vars` argument.
+            #[mzreflect(ignore)]
+            pub vars: Row,
+            pub silent: bool,
+        }
+
+        impl LazyUnaryFunc for $name {
+            fn eval<'a>(
+                &'a self,
+                datums: &[Datum<'a>],
+                temp_storage: &'a RowArena,
+                a: &'a MirScalarExpr,
+            ) -> Result<Datum<'a>, EvalError> {
+                let target = a.eval(datums, temp_storage)?;
+                if target.is_null() {
+                    return Ok(Datum::Null);
+                }
+                let vars = self.vars.unpack_first();
+                $inner(target, &self.path, vars, self.silent, temp_storage)
+            }
+
+            fn output_type(&self, $input_type: ColumnType) -> ColumnType {
+                $output_type
+            }
+
+            fn propagates_nulls(&self) -> bool {
+                true
+            }
+
+            fn introduces_nulls(&self) -> bool {
+                $introduces_nulls
+            }
+
+            fn preserves_uniqueness(&self) -> bool {
+                false
+            }
+
+            fn inverse(&self) -> Option<crate::UnaryFunc> {
+                None
+            }
+
+            fn is_monotone(&self) -> bool {
+                false
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "{}[{}, vars={}, silent={}]",
+                    $sql_name,
+                    mz_ore::str::StrExt::quoted(self.path.to_string().as_str()),
+                    self.vars.unpack_first(),
+                    self.silent
+                )
+            }
+        }
+    };
+}
+
+jsonb_path_func!(
+    JsonbPathExists,
+    "jsonb_path_exists",
+    jsonb_path_exists_inner,
+    |_| ScalarType::Bool.nullable(true),
+    true
+);
+
+jsonb_path_func!(
+    JsonbPathMatch,
+    "jsonb_path_match",
+    jsonb_path_match_inner,
+    |_| ScalarType::Bool.nullable(true),
+    true
+);
+
+jsonb_path_func!(
+    JsonbPathQueryArray,
+    "jsonb_path_query_array",
+    jsonb_path_query_array_inner,
+    |input_type| ScalarType::Jsonb.nullable(input_type.nullable),
+    false
+);
+
+jsonb_path_func!(
+    JsonbPathQueryFirst,
+    "jsonb_path_query_first",
+    jsonb_path_query_first_inner,
+    |_| ScalarType::Jsonb.nullable(true),
+    true
+);
@@ -17,12 +17,17 @@ use mz_ore::result::ResultExt;
 use mz_ore::str::StrExt;
 use mz_repr::adt::char::{format_str_trim, Char};
 use mz_repr::adt::date::Date;
+use mz_repr::adt::enum_type::EnumValue;
+use mz_repr::adt::hll::Hll;
+use mz_repr::adt::inet::{Cidr, Inet};
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::Jsonb;
+use mz_repr::adt::jsonpath::JsonPath;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
 use mz_repr::adt::pg_legacy_name::PgLegacyName;
 use mz_repr::adt::regex::Regex;
 use mz_repr::adt::system::{Oid, PgLegacyChar};
+use mz_repr::adt::text_search::{TsQuery, TsVector};
 use mz_repr::adt::timestamp::{CheckedTimestamp, TimestampPrecision};
 use mz_repr::adt::varchar::{VarChar, VarCharMaxLength};
 use mz_repr::{strconv, ColumnType, Datum, RowArena, ScalarType};
@@ -300,6 +305,60 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "text_to_inet"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastInetToString)]
+    fn cast_string_to_inet<'a>(a: &'a str) -> Result<Inet, EvalError> {
+        strconv::parse_inet(a).err_into()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "text_to_cidr"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastCidrToString)]
+    fn cast_string_to_cidr<'a>(a: &'a str) -> Result<Cidr, EvalError> {
+        strconv::parse_cidr(a).err_into()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "text_to_tsvector"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastTsVectorToString)]
+    fn cast_string_to_ts_vector<'a>(a: &'a str) -> Result<TsVector, EvalError> {
+        strconv::parse_tsvector(a).err_into()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "text_to_tsquery"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastTsQueryToString)]
+    fn cast_string_to_ts_query<'a>(a: &'a str) -> Result<TsQuery, EvalError> {
+        strconv::parse_tsquery(a).err_into()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "text_to_jsonpath"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastJsonPathToString)]
+    fn cast_string_to_json_path<'a>(a: &'a str) -> Result<JsonPath, EvalError> {
+        strconv::parse_jsonpath(a).err_into()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "text_to_hll"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastHllToString)]
+    fn cast_string_to_hll<'a>(a: &'a str) -> Result<Hll, EvalError> {
+        strconv::parse_hll(a).err_into()
+    }
+);
+
 #[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
 pub struct CastStringToArray {
     // Target array's type.
@@ -577,6 +636,49 @@ impl fmt::Display for CastStringToChar {
     }
 }
 
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToEnum {
+    // Target enum's type
+    pub return_ty: ScalarType,
+    // The labels of the enum type when the cast was planned. Labels are only
+    // ever appended to a type, so these remain valid, but labels added later
+    // are not recognized.
+    pub labels: Vec<String>,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToEnum {
+    type Input = &'a str;
+    type Output = Result<EnumValue<&'a str>, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<EnumValue<&'a str>, EvalError> {
+        strconv::parse_enum(&self.labels, a)
+            .err_into()
+            .map(|position| EnumValue { position, label: a })
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        self.return_ty.clone().nullable(input.nullable)
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastEnumToString {
+            ty: self.return_ty.clone(),
+        })
+    }
+}
+
+impl fmt::Display for CastStringToEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("strtoenum")
+    }
+}
+
 #[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
 pub struct CastStringToRange {
     // Target range's type
@@ -0,0 +1,849 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_ore::cast::CastLossy;
+use mz_repr::adt::array::Array;
+use mz_repr::adt::text_search::{
+    TsLexeme, TsOperand, TsPosition, TsQuery, TsQueryNode, TsVector, TsWeight, MAX_POSITION,
+};
+use mz_repr::strconv;
+
+use crate::EvalError;
+
+sqlfunc!(
+    #[sqlname = "tsvector_to_text"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastStringToTsVector)]
+    fn cast_ts_vector_to_string(a: TsVector) -> String {
+        a.to_string()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "tsquery_to_text"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastStringToTsQuery)]
+    fn cast_ts_query_to_string(a: TsQuery) -> String {
+        a.to_string()
+    }
+);
+
+/// A text search configuration, which determines how a document is broken
+/// into words and how those words are normalized into lexemes.
+///
+/// Documents are broken into words at every character that is not
+/// alphanumeric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextSearchConfig {
+    /// Lowercases words.
+    Simple,
+    /// Lowercases words, discards English stop words, and reduces the
+    /// remaining words to their stems with the Snowball English stemmer.
+    English,
+}
+
+impl TextSearchConfig {
+    fn from_name(name: &str) -> Result<TextSearchConfig, EvalError> {
+        match name.to_lowercase().as_str() {
+            "simple" | "pg_catalog.simple" => Ok(TextSearchConfig::Simple),
+            "english" | "pg_catalog.english" => Ok(TextSearchConfig::English),
+            _ => Err(EvalError::InvalidParameterValue(format!(
+                "text search configuration \"{name}\" does not exist"
+            ))),
+        }
+    }
+
+    /// Returns the lexemes of `text` and their 1-based positions. Stop words
+    /// are discarded, but still occupy a position.
+    fn lexemes(&self, text: &str) -> Vec<(String, u16)> {
+        let mut lexemes = vec![];
+        let mut position: u16 = 0;
+        for word in text.split(|c: char| !c.is_alphanumeric()) {
+            if word.is_empty() {
+                continue;
+            }
+            position = position.saturating_add(1).min(MAX_POSITION);
+            if let Some(lexeme) = self.normalize(word) {
+                lexemes.push((lexeme, position));
+            }
+        }
+        lexemes
+    }
+
+    fn normalize(&self, word: &str) -> Option<String> {
+        let word = word.to_lowercase();
+        match self {
+            TextSearchConfig::Simple => Some(word),
+            // As in PostgreSQL, words that contain digits are neither stop
+            // words nor stemmed.
+            TextSearchConfig::English if word.chars().any(|c| c.is_numeric()) => Some(word),
+            TextSearchConfig::English if ENGLISH_STOP_WORDS.binary_search(&&*word).is_ok() => None,
+            TextSearchConfig::English => Some(stem_english(&word)),
+        }
+    }
+}
+
+/// Converts `text` into a [`TsVector`] of its lexemes using the text search
+/// configuration `config`, i.e. `to_tsvector`.
+pub fn to_tsvector_inner(config: &str, text: &str) -> Result<TsVector, EvalError> {
+    let config = TextSearchConfig::from_name(config)?;
+    let lexemes = config
+        .lexemes(text)
+        .into_iter()
+        .map(|(word, position)| TsLexeme {
+            word,
+            positions: vec![TsPosition {
+                position,
+                weight: TsWeight::D,
+            }],
+        })
+        .collect();
+    Ok(TsVector::new(lexemes))
+}
+
+/// Parses `text` as a [`TsQuery`], normalizing each operand with the text
+/// search configuration `config`, i.e. `to_tsquery`.
+pub fn to_tsquery_inner(config: &str, text: &str) -> Result<TsQuery, EvalError> {
+    let config = TextSearchConfig::from_name(config)?;
+    Ok(strconv::parse_tsquery_with(text, |operand| {
+        config.lexemes(operand)
+    })?)
+}
+
+/// Converts `text` into a [`TsQuery`] that matches documents that contain all
+/// of its lexemes, i.e. `plainto_tsquery`.
+pub fn plainto_tsquery_inner(config: &str, text: &str) -> Result<TsQuery, EvalError> {
+    let config = TextSearchConfig::from_name(config)?;
+    let root = config
+        .lexemes(text)
+        .into_iter()
+        .map(|(word, _)| TsQueryNode::Operand(TsOperand::new(word)))
+        .reduce(|l, r| TsQueryNode::And(Box::new(l), Box::new(r)));
+    Ok(TsQuery { root })
+}
+
+/// Converts `text` into a [`TsQuery`] that matches documents that contain its
+/// lexemes in the same order and at the same distances from one another, i.e.
+/// `phraseto_tsquery`.
+pub fn phraseto_tsquery_inner(config: &str, text: &str) -> Result<TsQuery, EvalError> {
+    let config = TextSearchConfig::from_name(config)?;
+    Ok(TsQuery {
+        root: phrase(config.lexemes(text)),
+    })
+}
+
+/// Converts `text`, written in the syntax of web search engines, into a
+/// [`TsQuery`], i.e. `websearch_to_tsquery`.
+///
+/// Quoted text matches as a phrase, the word `or` separates alternatives, a
+/// leading `-` negates a word or phrase, and all other words must match.
+pub fn websearch_to_tsquery_inner(config: &str, text: &str) -> Result<TsQuery, EvalError> {
+    let config = TextSearchConfig::from_name(config)?;
+
+    // The terms of the alternative being built, and the alternatives that
+    // have been completed.
+    let mut terms: Option<TsQueryNode> = None;
+    let mut alternatives: Option<TsQueryNode> = None;
+    let mut chars = text.chars().peekable();
+    let mut negated = false;
+    let mut term_start = true;
+    while let Some(c) = chars.next() {
+        let node = if c == '"' {
+            let mut quoted = String::new();
+            while let Some(c) = chars.next_if(|c| *c != '"') {
+                quoted.push(c);
+            }
+            chars.next();
+            phrase(config.lexemes(&quoted))
+        } else if c.is_alphanumeric() {
+            let mut word = String::from(c);
+            while let Some(c) = chars.next_if(|c| c.is_alphanumeric()) {
+                word.push(c);
+            }
+            if word.eq_ignore_ascii_case("or") && !negated {
+                if let Some(terms) = terms.take() {
+                    alternatives = Some(match alternatives {
+                        None => terms,
+                        Some(alts) => TsQueryNode::Or(Box::new(alts), Box::new(terms)),
+                    });
+                }
+                continue;
+            }
+            config
+                .normalize(&word)
+                .map(|word| TsQueryNode::Operand(TsOperand::new(word)))
+        } else {
+            // A `-` negates the following term only if it begins a term.
+            negated = c == '-' && term_start;
+            term_start = c.is_whitespace();
+            continue;
+        };
+        term_start = false;
+        if let Some(mut node) = node {
+            if negated {
+                node = TsQueryNode::Not(Box::new(node));
+            }
+            terms = Some(match terms {
+                None => node,
+                Some(terms) => TsQueryNode::And(Box::new(terms), Box::new(node)),
+            });
+        }
+        negated = false;
+    }
+    let root = match (alternatives, terms) {
+        (Some(alts), Some(terms)) => Some(TsQueryNode::Or(Box::new(alts), Box::new(terms))),
+        (alts, terms) => alts.or(terms),
+    };
+    Ok(TsQuery { root })
+}
+
+/// Builds a phrase of the given lexemes, separating each pair of lexemes by
+/// the difference of their positions.
+fn phrase(lexemes: Vec<(String, u16)>) -> Option<TsQueryNode> {
+    let mut node: Option<TsQueryNode> = None;
+    let mut last_position = 0;
+    for (word, position) in lexemes {
+        let operand = TsQueryNode::Operand(TsOperand::new(word));
+        node = Some(match node {
+            None => operand,
+            Some(node) => {
+                TsQueryNode::Phrase(Box::new(node), Box::new(operand), position - last_position)
+            }
+        });
+        last_position = position;
+    }
+    node
+}
+
+/// The weights of positions of weight `D`, `C`, `B`, and `A`, respectively,
+/// used by `ts_rank` when no weights are given.
+const DEFAULT_WEIGHTS: [f32; 4] = [0.1, 0.2, 0.4, 1.0];
+
+/// The sum of `1 / i^2` for all positive integers `i`, i.e. `pi^2 / 6`.
+const SUM_OF_INVERSE_SQUARES: f64 = 1.64493406685;
+
+/// The normalization options of `ts_rank`, which may be combined.
+const RANK_NORM_LOGLENGTH: i32 = 0x01;
+const RANK_NORM_LENGTH: i32 = 0x02;
+const RANK_NORM_UNIQ: i32 = 0x08;
+const RANK_NORM_LOGUNIQ: i32 = 0x10;
+const RANK_NORM_RDIVRPLUS1: i32 = 0x20;
+
+/// Scores how relevant the document `vector` is to `query`, i.e. `ts_rank`.
+///
+/// The score weights the matching positions of the document by `weights`, the
+/// weights of positions of weight `D`, `C`, `B`, and `A`, respectively, and is
+/// then normalized according to the bits of `normalization`.
+///
+/// The algorithm matches PostgreSQL's, including its use of single precision
+/// arithmetic.
+pub fn ts_rank_inner(
+    weights: Option<Array>,
+    vector: &TsVector,
+    query: &TsQuery,
+    normalization: i32,
+) -> Result<f32, EvalError> {
+    let weights = match weights {
+        None => DEFAULT_WEIGHTS,
+        Some(array) => {
+            if array.dims().len() != 1 {
+                return Err(EvalError::InvalidParameterValue(
+                    "array of weight must be one-dimensional".into(),
+                ));
+            }
+            let elements = array.elements();
+            if elements.iter().count() < 4 {
+                return Err(EvalError::InvalidParameterValue(
+                    "array of weight is too short".into(),
+                ));
+            }
+            let mut weights = DEFAULT_WEIGHTS;
+            for (weight, datum) in weights.iter_mut().zip(elements.iter()) {
+                if datum.is_null() {
+                    return Err(EvalError::InvalidParameterValue(
+                        "array of weight must not contain nulls".into(),
+                    ));
+                }
+                let w = datum.unwrap_float32();
+                if w > 1.0 {
+                    return Err(EvalError::InvalidParameterValue(
+                        "weight out of range".into(),
+                    ));
+                }
+                // Negative weights are replaced by the default weight.
+                if w >= 0.0 {
+                    *weight = w;
+                }
+            }
+            weights
+        }
+    };
+
+    let Some(root) = &query.root else {
+        return Ok(0.0);
+    };
+    if vector.lexemes().is_empty() {
+        return Ok(0.0);
+    }
+
+    let mut res = match root {
+        TsQueryNode::And(..) | TsQueryNode::Phrase(..) => rank_and(&weights, vector, query),
+        _ => rank_or(&weights, vector, query),
+    };
+    if res < 0.0 {
+        res = 1e-20;
+    }
+
+    // The number of words in the document, counting lexemes without positions
+    // as a single word.
+    let length = || -> usize {
+        vector
+            .lexemes()
+            .iter()
+            .map(|l| l.positions.len().max(1))
+            .sum()
+    };
+    let unique = vector.lexemes().len();
+    if normalization & RANK_NORM_LOGLENGTH != 0 {
+        res = round_to_f32(f64::from(res) / (f64::cast_lossy(length() + 1).log2()));
+    }
+    if normalization & RANK_NORM_LENGTH != 0 {
+        res /= round_to_f32(f64::cast_lossy(length()));
+    }
+    if normalization & RANK_NORM_UNIQ != 0 {
+        res /= round_to_f32(f64::cast_lossy(unique));
+    }
+    if normalization & RANK_NORM_LOGUNIQ != 0 {
+        res = round_to_f32(f64::from(res) / (f64::cast_lossy(unique + 1).log2()));
+    }
+    if normalization & RANK_NORM_RDIVRPLUS1 != 0 {
+        res /= res + 1.0;
+    }
+    Ok(res)
+}
+
+/// The positions of a lexeme, as `(position, weight index)` pairs. Lexemes
+/// without positions are treated as having a single position of weight `D`
+/// at the largest possible position.
+fn rank_positions(lexeme: &TsLexeme) -> Vec<(u16, usize)> {
+    if lexeme.positions.is_empty() {
+        vec![(MAX_POSITION, TsWeight::D.index())]
+    } else {
+        lexeme
+            .positions
+            .iter()
+            .map(|p| (p.position, p.weight.index()))
+            .collect()
+    }
+}
+
+/// Ranks a query whose operands are alternatives, by the number of
+/// occurrences of each operand and the weights of those occurrences.
+fn rank_or(weights: &[f32; 4], vector: &TsVector, query: &TsQuery) -> f32 {
+    let operands = query.operands();
+    let mut res: f32 = 0.0;
+    for operand in &operands {
+        for lexeme in vector.find(operand) {
+            let mut resj: f32 = 0.0;
+            let mut wjm: f32 = -1.0;
+            let mut jm: usize = 0;
+            for (j, (_, weight)) in rank_positions(lexeme).into_iter().enumerate() {
+                let w = weights[weight];
+                resj += w / round_to_f32(f64::cast_lossy((j + 1) * (j + 1)));
+                if w > wjm {
+                    wjm = w;
+                    jm = j;
+                }
+            }
+            let occurrences = wjm + resj - wjm / round_to_f32(f64::cast_lossy((jm + 1) * (jm + 1)));
+            res = round_to_f32(f64::from(res) + f64::from(occurrences) / SUM_OF_INVERSE_SQUARES);
+        }
+    }
+    if !operands.is_empty() {
+        res /= round_to_f32(f64::cast_lossy(operands.len()));
+    }
+    res
+}
+
+/// Ranks a query whose operands must all match, by the proximity of the
+/// occurrences of each pair of operands.
+fn rank_and(weights: &[f32; 4], vector: &TsVector, query: &TsQuery) -> f32 {
+    let operands = query.operands();
+    if operands.len() < 2 {
+        return rank_or(weights, vector, query);
+    }
+    // The positions of the last lexeme that matched each operand, and
+    // whether that lexeme lacked positions.
+    let mut positions: Vec<Option<(Vec<(u16, usize)>, bool)>> = vec![None; operands.len()];
+    let mut res: f32 = -1.0;
+    for (i, operand) in operands.iter().enumerate() {
+        for lexeme in vector.find(operand) {
+            let unpositioned = lexeme.positions.is_empty();
+            let current = rank_positions(lexeme);
+            for (other, other_unpositioned) in positions[..i].iter().flatten() {
+                for (pos, weight) in &current {
+                    for (other_pos, other_weight) in other {
+                        let mut dist = i32::from(*pos) - i32::from(*other_pos);
+                        dist = dist.abs();
+                        if dist != 0 || unpositioned || *other_unpositioned {
+                            if dist == 0 {
+                                dist = i32::from(MAX_POSITION) + 1;
+                            }
+                            let product =
+                                weights[*weight] * weights[*other_weight] * word_distance(dist);
+                            let curw = round_to_f32(f64::from(product).sqrt());
+                            res = if res < 0.0 {
+                                curw
+                            } else {
+                                round_to_f32(1.0 - (1.0 - f64::from(res)) * (1.0 - f64::from(curw)))
+                            };
+                        }
+                    }
+                }
+            }
+            positions[i] = Some((current, unpositioned));
+        }
+    }
+    res
+}
+
+/// Scores the proximity of two positions `dist` apart.
+fn word_distance(dist: i32) -> f32 {
+    if dist > 100 {
+        return 1e-30;
+    }
+    round_to_f32(1.0 / (1.005 + 0.05 * (f64::from(dist) / 1.5 - 2.0).exp()))
+}
+
+/// Rounds `f` to single precision, as PostgreSQL's ranking arithmetic does.
+#[allow(clippy::as_conversions)]
+fn round_to_f32(f: f64) -> f32 {
+    f as f32
+}
+
+/// The stop words of the `english` configuration, which are the same as
+/// PostgreSQL's, in sorted order.
+const ENGLISH_STOP_WORDS: &[&str] = &[
+    "a",
+    "about",
+    "above",
+    "after",
+    "again",
+    "against",
+    "all",
+    "am",
+    "an",
+    "and",
+    "any",
+    "are",
+    "as",
+    "at",
+    "be",
+    "because",
+    "been",
+    "before",
+    "being",
+    "below",
+    "between",
+    "both",
+    "but",
+    "by",
+    "can",
+    "did",
+    "do",
+    "does",
+    "doing",
+    "don",
+    "down",
+    "during",
+    "each",
+    "few",
+    "for",
+    "from",
+    "further",
+    "had",
+    "has",
+    "have",
+    "having",
+    "he",
+    "her",
+    "here",
+    "hers",
+    "herself",
+    "him",
+    "himself",
+    "his",
+    "how",
+    "i",
+    "if",
+    "in",
+    "into",
+    "is",
+    "it",
+    "its",
+    "itself",
+    "just",
+    "me",
+    "more",
+    "most",
+    "my",
+    "myself",
+    "no",
+    "nor",
+    "not",
+    "now",
+    "of",
+    "off",
+    "on",
+    "once",
+    "only",
+    "or",
+    "other",
+    "our",
+    "ours",
+    "ourselves",
+    "out",
+    "over",
+    "own",
+    "s",
+    "same",
+    "she",
+    "should",
+    "so",
+    "some",
+    "such",
+    "t",
+    "than",
+    "that",
+    "the",
+    "their",
+    "theirs",
+    "them",
+    "themselves",
+    "then",
+    "there",
+    "these",
+    "they",
+    "this",
+    "those",
+    "through",
+    "to",
+    "too",
+    "under",
+    "until",
+    "up",
+    "very",
+    "was",
+    "we",
+    "were",
+    "what",
+    "when",
+    "where",
+    "which",
+    "while",
+    "who",
+    "whom",
+    "why",
+    "will",
+    "with",
+    "you",
+    "your",
+    "yours",
+    "yourself",
+    "yourselves",
+];
+
+/// Reduces a lowercase English word to its stem with the Snowball English
+/// ("Porter2") stemming algorithm.
+///
+/// See: <https://snowballstem.org/algorithms/english/stemmer.html>
+fn stem_english(word: &str) -> String {
+    if word.chars().count() <= 2 {
+        return word.to_string();
+    }
+    match word {
+        "skis" => return "ski".into(),
+        "skies" => return "sky".into(),
+        "dying" => return "die".into(),
+        "lying" => return "lie".into(),
+        "tying" => return "tie".into(),
+        "idly" => return "idl".into(),
+        "gently" => return "gentl".into(),
+        "ugly" => return "ugli".into(),
+        "early" => return "earli".into(),
+        "only" => return "onli".into(),
+        "singly" => return "singl".into(),
+        "sky" | "news" | "howe" | "atlas" | "cosmos" | "bias" | "andes" => return word.into(),
+        _ => (),
+    }
+
+    let mut w: Vec<char> = word.chars().collect();
+    // Mark each `y` that acts as a consonant as `Y`.
+    for i in 0..w.len() {
+        if w[i] == 'y' && (i == 0 || is_vowel(w[i - 1])) {
+            w[i] = 'Y';
+        }
+    }
+    let r1 = ["gener", "commun", "arsen"]
+        .iter()
+        .find(|prefix| word.starts_with(*prefix))
+        .map(|prefix| prefix.len())
+        .unwrap_or_else(|| region_start(&w, 0));
+    let r2 = region_start(&w, r1);
+
+    // Step 1a.
+    if ends_with(&w, "sses") {
+        replace_suffix(&mut w, 4, "ss");
+    } else if ends_with(&w, "ied") || ends_with(&w, "ies") {
+        let replacement = if w.len() > 4 { "i" } else { "ie" };
+        replace_suffix(&mut w, 3, replacement);
+    } else if ends_with(&w, "us") || ends_with(&w, "ss") {
+        // Unchanged.
+    } else if ends_with(&w, "s") && w[..w.len() - 2].iter().any(|c| is_vowel(*c)) {
+        w.pop();
+    }
+
+    let stemmed: String = w.iter().collect();
+    if matches!(
+        stemmed.as_str(),
+        "inning" | "outing" | "canning" | "herring" | "earring" | "proceed" | "exceed" | "succeed"
+    ) {
+        return stemmed;
+    }
+
+    // Step 1b.
+    if let Some(suffix) = longest_suffix(&w, &["eedly", "ingly", "edly", "eed", "ing", "ed"]) {
+        let start = w.len() - suffix.len();
+        if suffix.starts_with("ee") {
+            if start >= r1 {
+                replace_suffix(&mut w, suffix.len(), "ee");
+            }
+        } else if w[..start].iter().any(|c| is_vowel(*c)) {
+            w.truncate(start);
+            if ends_with(&w, "at") || ends_with(&w, "bl") || ends_with(&w, "iz") {
+                w.push('e');
+            } else if ends_with_double(&w) {
+                w.pop();
+            } else if r1 >= w.len() && ends_with_short_syllable(&w) {
+                w.push('e');
+            }
+        }
+    }
+
+    // Step 1c.
+    if w.len() > 2 && matches!(w[w.len() - 1], 'y' | 'Y') && !is_vowel(w[w.len() - 2]) {
+        let last = w.len() - 1;
+        w[last] = 'i';
+    }
+
+    // Step 2.
+    const STEP_2: &[(&str, &str)] = &[
+        ("ization", "ize"),
+        ("ational", "ate"),
+        ("fulness", "ful"),
+        ("ousness", "ous"),
+        ("iveness", "ive"),
+        ("tional", "tion"),
+        ("biliti", "ble"),
+        ("lessli", "less"),
+        ("entli", "ent"),
+        ("ation", "ate"),
+        ("alism", "al"),
+        ("aliti", "al"),
+        ("ousli", "ous"),
+        ("iviti", "ive"),
+        ("fulli", "ful"),
+        ("enci", "ence"),
+        ("anci", "ance"),
+        ("abli", "able"),
+        ("izer", "ize"),
+        ("ator", "ate"),
+        ("alli", "al"),
+        ("bli", "ble"),
+        ("ogi", "og"),
+        ("li", ""),
+    ];
+    if let Some((suffix, replacement)) = STEP_2.iter().find(|(s, _)| ends_with(&w, s)) {
+        let start = w.len() - suffix.len();
+        let valid = match *suffix {
+            "ogi" => start > 0 && w[start - 1] == 'l',
+            "li" => start > 0 && "cdeghkmnrt".contains(w[start - 1]),
+            _ => true,
+        };
+        if start >= r1 && valid {
+            replace_suffix(&mut w, suffix.len(), replacement);
+        }
+    }
+
+    // Step 3.
+    const STEP_3: &[(&str, &str)] = &[
+        ("ational", "ate"),
+        ("tional", "tion"),
+        ("alize", "al"),
+        ("icate", "ic"),
+        ("iciti", "ic"),
+        ("ative", ""),
+        ("ical", "ic"),
+        ("ness", ""),
+        ("ful", ""),
+    ];
+    if let Some((suffix, replacement)) = STEP_3.iter().find(|(s, _)| ends_with(&w, s)) {
+        let start = w.len() - suffix.len();
+        if start >= r1 && (*suffix != "ative" || start >= r2) {
+            replace_suffix(&mut w, suffix.len(), replacement);
+        }
+    }
+
+    // Step 4.
+    const STEP_4: &[&str] = &[
+        "ement", "ance", "ence", "able", "ible", "ment", "ant", "ent", "ism", "ate", "iti", "ous",
+        "ive", "ize", "ion", "al", "er", "ic",
+    ];
+    if let Some(suffix) = longest_suffix(&w, STEP_4) {
+        let start = w.len() - suffix.len();
+        let valid = suffix != "ion" || (start > 0 && matches!(w[start - 1], 's' | 't'));
+        if start >= r2 && valid {
+            w.truncate(start);
+        }
+    }
+
+    // Step 5.
+    if let Some(&last) = w.last() {
+        let start = w.len() - 1;
+        if last == 'e' && (start >= r2 || (start >= r1 && !ends_with_short_syllable(&w[..start]))) {
+            w.pop();
+        } else if last == 'l' && start >= r2 && start > 0 && w[start - 1] == 'l' {
+            w.pop();
+        }
+    }
+
+    w.into_iter()
+        .map(|c| if c == 'Y' { 'y' } else { c })
+        .collect()
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+/// Returns the start of the region after the first non-vowel that follows a
+/// vowel at or after `from`, or the length of the word if there is none.
+fn region_start(w: &[char], from: usize) -> usize {
+    (from + 1..w.len())
+        .find(|i| !is_vowel(w[*i]) && is_vowel(w[*i - 1]))
+        .map(|i| i + 1)
+        .unwrap_or(w.len())
+}
+
+fn ends_with(w: &[char], suffix: &str) -> bool {
+    let n = suffix.chars().count();
+    w.len() >= n && w[w.len() - n..].iter().copied().eq(suffix.chars())
+}
+
+fn longest_suffix<'a>(w: &[char], suffixes: &[&'a str]) -> Option<&'a str> {
+    suffixes
+        .iter()
+        .filter(|s| ends_with(w, s))
+        .max_by_key(|s| s.len())
+        .copied()
+}
+
+fn replace_suffix(w: &mut Vec<char>, len: usize, replacement: &str) {
+    w.truncate(w.len() - len);
+    w.extend(replacement.chars());
+}
+
+fn ends_with_double(w: &[char]) -> bool {
+    const DOUBLES: &[&str] = &["bb", "dd", "ff", "gg", "mm", "nn", "pp", "rr", "tt"];
+    DOUBLES.iter().any(|d| ends_with(w, d))
+}
+
+/// Reports whether `w` ends in a short syllable: a vowel followed by a
+/// non-vowel other than `w`, `x`, or `Y` and preceded by a non-vowel, or a
+/// vowel at the start of the word followed by a non-vowel.
+fn ends_with_short_syllable(w: &[char]) -> bool {
+    match w {
+        [a, b] => is_vowel(*a) && !is_vowel(*b),
+        [.., a, b, c] => {
+            !is_vowel(*a) && is_vowel(*b) && !is_vowel(*c) && !matches!(c, 'w' | 'x' | 'Y')
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_stem_english() {
+        for (word, stem) in [
+            ("running", "run"),
+            ("hoping", "hope"),
+            ("hopping", "hop"),
+            ("supernovae", "supernova"),
+            ("stars", "star"),
+            ("foxes", "fox"),
+            ("lazy", "lazi"),
+            ("happiness", "happi"),
+            ("connection", "connect"),
+            ("generously", "generous"),
+            ("relational", "relat"),
+            ("cries", "cri"),
+            ("ties", "tie"),
+            ("gas", "gas"),
+            ("caresses", "caress"),
+            ("agreed", "agre"),
+            ("controllable", "control"),
+            ("skies", "sky"),
+            ("news", "news"),
+            ("succeeded", "succeed"),
+        ] {
+            assert_eq!(stem_english(word), stem, "{word}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_to_tsvector() {
+        let v = to_tsvector_inner("english", "The Fat Rats ate 2 fat cats").unwrap();
+        assert_eq!(v.to_string(), "'2':5 'ate':4 'cat':7 'fat':2,6 'rat':3");
+        let v = to_tsvector_inner("simple", "The Fat Rats").unwrap();
+        assert_eq!(v.to_string(), "'fat':2 'rats':3 'the':1");
+        assert!(to_tsvector_inner("klingon", "").is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_to_tsquery() {
+        let q = to_tsquery_inner("english", "The & (Fat | Rats) & supernovae:*").unwrap();
+        assert_eq!(q.to_string(), "( 'fat' | 'rat' ) & 'supernova':*");
+        let q = plainto_tsquery_inner("english", "The Fat Rats").unwrap();
+        assert_eq!(q.to_string(), "'fat' & 'rat'");
+        let q = phraseto_tsquery_inner("english", "The Cat and Rats").unwrap();
+        assert_eq!(q.to_string(), "'cat' <2> 'rat'");
+        let q = websearch_to_tsquery_inner("english", "\"supernovae stars\" -crab").unwrap();
+        assert_eq!(q.to_string(), "'supernova' <-> 'star' & !'crab'");
+        let q = websearch_to_tsquery_inner("english", "\"sad cat\" or \"fat rat\"").unwrap();
+        assert_eq!(q.to_string(), "'sad' <-> 'cat' | 'fat' <-> 'rat'");
+        let q = websearch_to_tsquery_inner("english", "the").unwrap();
+        assert_eq!(q.to_string(), "");
+    }
+
+    #[mz_ore::test]
+    fn test_ts_rank() {
+        let v = to_tsvector_inner("english", "a fat cat sat on a mat and ate a fat rat").unwrap();
+        let q = to_tsquery_inner("english", "cat").unwrap();
+        let rank = ts_rank_inner(None, &v, &q, 0).unwrap();
+        assert!((rank - 0.0607927).abs() < 1e-6, "{rank}");
+        let q = to_tsquery_inner("english", "fat & rat").unwrap();
+        let rank = ts_rank_inner(None, &v, &q, 0).unwrap();
+        assert!(rank > 0.0, "{rank}");
+        let q = to_tsquery_inner("english", "dog").unwrap();
+        assert_eq!(ts_rank_inner(None, &v, &q, 0).unwrap(), 0.0);
+    }
+}
@@ -1202,6 +1202,56 @@ impl MirScalarExpr {
                                     e.typ(column_types).scalar_type,
                                 ),
                             };
+                        } else if matches!(
+                            func,
+                            VariadicFunc::JsonbPathExists
+                                | VariadicFunc::JsonbPathMatch
+                                | VariadicFunc::JsonbPathQueryArray
+                                | VariadicFunc::JsonbPathQueryFirst
+                        ) && exprs[1..].iter().all(|e| e.is_literal())
+                        {
+                            // Literal nulls and errors were handled above, so
+                            // the path, variables, and `silent` flag are all
+                            // known values.
+                            let path = exprs[1].as_literal_str().unwrap();
+                            let vars = Row::pack_slice(&[exprs[2].as_literal().unwrap().unwrap()]);
+                            let silent = exprs[3].as_literal().unwrap().unwrap().unwrap_bool();
+                            *e = match mz_repr::strconv::parse_jsonpath(path) {
+                                Ok(path) => {
+                                    let func = match func {
+                                        VariadicFunc::JsonbPathExists => {
+                                            UnaryFunc::JsonbPathExists(func::JsonbPathExists {
+                                                path,
+                                                vars,
+                                                silent,
+                                            })
+                                        }
+                                        VariadicFunc::JsonbPathMatch => {
+                                            UnaryFunc::JsonbPathMatch(func::JsonbPathMatch {
+                                                path,
+                                                vars,
+                                                silent,
+                                            })
+                                        }
+                                        VariadicFunc::JsonbPathQueryArray => {
+                                            UnaryFunc::JsonbPathQueryArray(
+                                                func::JsonbPathQueryArray { path, vars, silent },
+                                            )
+                                        }
+                                        VariadicFunc::JsonbPathQueryFirst => {
+                                            UnaryFunc::JsonbPathQueryFirst(
+                                                func::JsonbPathQueryFirst { path, vars, silent },
+                                            )
+                                        }
+                                        _ => unreachable!(),
+                                    };
+                                    mem::take(exprs).into_first().call_unary(func)
+                                }
+                                Err(err) => MirScalarExpr::literal(
+                                    Err(err.into()),
+                                    e.typ(column_types).scalar_type,
+                                ),
+                            };
                         } else if *func == VariadicFunc::ListIndex && is_list_create_call(&exprs[0])
                         {
                             // We are looking for ListIndex(ListCreate, literal), and eliminate
@@ -2463,6 +2513,7 @@ pub enum EvalError {
     AclArrayNullElement,
     MzAclArrayNullElement,
     PrettyError(String),
+    JsonPath(String),
 }
 
 impl fmt::Display for EvalError {
@@ -2564,6 +2615,7 @@ impl fmt::Display for EvalError {
             }
             EvalError::Parse(e) => e.fmt(f),
             EvalError::PrettyError(e) => e.fmt(f),
+            EvalError::JsonPath(e) => f.write_str(e),
             EvalError::ParseHex(e) => e.fmt(f),
             EvalError::Internal(s) => write!(f, "internal error: {}", s),
             EvalError::InfinityOutOfDomain(s) => {
@@ -2858,6 +2910,7 @@ impl RustType<ProtoEvalError> for EvalError {
             EvalError::UnterminatedLikeEscapeSequence => UnterminatedLikeEscapeSequence(()),
             EvalError::Parse(error) => Parse(error.into_proto()),
             EvalError::PrettyError(error) => PrettyError(error.into_proto()),
+            EvalError::JsonPath(error) => JsonPath(error.into_proto()),
             EvalError::ParseHex(error) => ParseHex(error.into_proto()),
             EvalError::Internal(v) => Internal(v.clone()),
             EvalError::InfinityOutOfDomain(v) => InfinityOutOfDomain(v.clone()),
@@ -3037,6 +3090,7 @@ impl RustType<ProtoEvalError> for EvalError {
                 MzAclArrayNullElement(()) => Ok(EvalError::MzAclArrayNullElement),
                 InvalidIanaTimezoneId(s) => Ok(EvalError::InvalidIanaTimezoneId(s)),
                 PrettyError(s) => Ok(EvalError::PrettyError(s)),
+                JsonPath(s) => Ok(EvalError::JsonPath(s)),
             },
             None => Err(TryFromProtoError::missing_field("ProtoEvalError::kind")),
         }
@@ -17,6 +17,8 @@ use itertools::Itertools;
 use mz_avro::types::{DecimalValue, ToAvro, Value};
 use mz_avro::Schema;
 use mz_ore::cast::CastFrom;
+use mz_repr::adt::enum_type::EnumValue;
+use mz_repr::adt::inet::Cidr;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{self, NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, Row, ScalarType};
@@ -421,7 +423,9 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                     debug_assert_eq!(buf.len(), 16);
                     buf
                 }),
-                ScalarType::Bytes => Value::Bytes(Vec::from(datum.unwrap_bytes())),
+                ScalarType::Bytes | ScalarType::Hll => {
+                    Value::Bytes(Vec::from(datum.unwrap_bytes()))
+                }
                 ScalarType::String | ScalarType::VarChar { .. } | ScalarType::PgLegacyName => {
                     Value::String(datum.unwrap_str().to_owned())
                 }
@@ -490,6 +494,14 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                 ScalarType::MzTimestamp => Value::String(datum.unwrap_mz_timestamp().to_string()),
                 ScalarType::Range { .. } => Value::String(datum.unwrap_range().to_string()),
                 ScalarType::MzAclItem => Value::String(datum.unwrap_mz_acl_item().to_string()),
+                ScalarType::Enum { .. } => {
+                    Value::String(EnumValue::from_datum(datum).label.to_owned())
+                }
+                ScalarType::Inet => Value::String(datum.unwrap_inet().to_string()),
+                ScalarType::Cidr => Value::String(Cidr(datum.unwrap_inet()).to_string()),
+                ScalarType::TsVector | ScalarType::TsQuery | ScalarType::JsonPath => {
+                    Value::String(datum.unwrap_str().to_owned())
+                }
             };
             if typ.nullable {
                 val = Value::Union {
@@ -11,6 +11,8 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 use mz_repr::adt::char;
+use mz_repr::adt::enum_type::EnumValue;
+use mz_repr::adt::inet::Cidr;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, ScalarType};
@@ -52,6 +54,19 @@ impl JsonEncoder {
         let value = encode_datums_as_json(row.iter(), names_types);
         value.to_string().into_bytes()
     }
+
+    /// Encodes a key row as a JSON object, without serializing it.
+    pub fn key_to_json(&self, row: &mz_repr::Row) -> serde_json::Value {
+        encode_datums_as_json(
+            row.iter(),
+            self.key_columns.as_ref().expect("key schema must exist"),
+        )
+    }
+
+    /// Encodes a value row as a JSON object, without serializing it.
+    pub fn value_to_json(&self, row: &mz_repr::Row) -> serde_json::Value {
+        encode_datums_as_json(row.iter(), &self.value_columns)
+    }
 }
 
 impl Encode for JsonEncoder {
@@ -168,7 +183,7 @@ impl ToJson for TypedDatum<'_> {
             ScalarType::Interval => {
                 serde_json::Value::String(format!("{}", datum.unwrap_interval()))
             }
-            ScalarType::Bytes => json!(datum.unwrap_bytes()),
+            ScalarType::Bytes | ScalarType::Hll => json!(datum.unwrap_bytes()),
             ScalarType::String | ScalarType::VarChar { .. } | ScalarType::PgLegacyName => {
                 json!(datum.unwrap_str())
             }
@@ -241,6 +256,12 @@ impl ToJson for TypedDatum<'_> {
                 json!(datum.unwrap_range().to_string())
             }
             ScalarType::MzAclItem => json!(datum.unwrap_mz_acl_item().to_string()),
+            ScalarType::Enum { .. } => json!(EnumValue::from_datum(datum).label),
+            ScalarType::Inet => json!(datum.unwrap_inet().to_string()),
+            ScalarType::Cidr => json!(Cidr(datum.unwrap_inet()).to_string()),
+            ScalarType::TsVector | ScalarType::TsQuery | ScalarType::JsonPath => {
+                json!(datum.unwrap_str())
+            }
         };
         // We don't need to recurse into map or object here because those already recursively call
         // .json() with the number policy to generate the member Values.
@@ -297,7 +318,7 @@ fn build_row_schema_field_type(
             },
         }),
         ScalarType::Interval => type_namer.interval_type(),
-        ScalarType::Bytes => json!("bytes"),
+        ScalarType::Bytes | ScalarType::Hll => json!("bytes"),
         ScalarType::String
         | ScalarType::Char { .. }
         | ScalarType::VarChar { .. }
@@ -391,6 +412,11 @@ fn build_row_schema_field_type(
         // https://debezium.io/documentation/reference/stable/connectors/postgresql.html
         ScalarType::Range { .. } => json!("string"),
         ScalarType::MzAclItem => json!("string"),
+        // Enum labels need not be valid Avro symbols, so they are encoded as
+        // strings rather than as Avro enums.
+        ScalarType::Enum { .. } => json!("string"),
+        ScalarType::Inet | ScalarType::Cidr => json!("string"),
+        ScalarType::TsVector | ScalarType::TsQuery | ScalarType::JsonPath => json!("string"),
     };
     if typ.nullable {
         // Should be revisited if we ever support a different kind of union scheme.
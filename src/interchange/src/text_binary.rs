@@ -0,0 +1,186 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Encoders for `FORMAT TEXT` and `FORMAT BYTES` sinks, which write the only
+//! column of each key and value as is, rather than wrapping it in a record.
+//!
+//! Value rows contain the key columns too, so the value column is the only
+//! column of the value that is not part of the key.
+
+use bytes::BytesMut;
+use mz_repr::{Datum, RelationDesc, Row, ScalarType};
+
+use crate::encode::Encode;
+
+/// Returns the type of the only column of `desc`.
+fn single_column_type(desc: &RelationDesc) -> ScalarType {
+    assert_eq!(desc.arity(), 1, "validated during planning");
+    desc.typ().column_types[0].scalar_type.clone()
+}
+
+/// Returns the index of the only column of `value_desc` that is not one of the
+/// `key_indices`.
+fn value_column(value_desc: &RelationDesc, key_indices: Option<&[usize]>) -> usize {
+    let key_indices = key_indices.unwrap_or_default();
+    let mut columns = (0..value_desc.arity()).filter(|i| !key_indices.contains(i));
+    let column = columns.next().expect("validated during planning");
+    assert!(columns.next().is_none(), "validated during planning");
+    column
+}
+
+/// Returns the datum at `index` in `row`.
+fn datum_at(row: &Row, index: usize) -> Datum {
+    row.iter()
+        .nth(index)
+        .expect("row has the arity of its relation")
+}
+
+/// Encodes the only column of a row in its PostgreSQL text representation.
+#[derive(Debug)]
+pub struct TextEncoder {
+    key_type: Option<ScalarType>,
+    value_column: usize,
+    value_type: ScalarType,
+}
+
+impl TextEncoder {
+    pub fn new(
+        key_desc: Option<RelationDesc>,
+        value_desc: RelationDesc,
+        key_indices: Option<&[usize]>,
+    ) -> Self {
+        let value_column = value_column(&value_desc, key_indices);
+        TextEncoder {
+            key_type: key_desc.as_ref().map(single_column_type),
+            value_column,
+            value_type: value_desc.typ().column_types[value_column]
+                .scalar_type
+                .clone(),
+        }
+    }
+
+    fn encode_datum(datum: Datum, typ: &ScalarType) -> Vec<u8> {
+        let mut buf = BytesMut::new();
+        mz_pgrepr::Value::from_datum(datum, typ)
+            .expect("column validated to be non-nullable during planning")
+            .encode_text(&mut buf);
+        buf.to_vec()
+    }
+}
+
+impl Encode for TextEncoder {
+    fn get_format_name(&self) -> &str {
+        "text"
+    }
+
+    fn encode_key_unchecked(&self, row: Row) -> Vec<u8> {
+        let typ = self.key_type.as_ref().expect("key type must exist");
+        Self::encode_datum(row.unpack_first(), typ)
+    }
+
+    fn encode_value_unchecked(&self, row: Row) -> Vec<u8> {
+        Self::encode_datum(datum_at(&row, self.value_column), &self.value_type)
+    }
+}
+
+/// Encodes the only column of a row, which must be of type `bytea`, as its raw
+/// bytes.
+#[derive(Debug)]
+pub struct BinaryEncoder {
+    value_column: usize,
+}
+
+impl BinaryEncoder {
+    pub fn new(value_desc: &RelationDesc, key_indices: Option<&[usize]>) -> Self {
+        BinaryEncoder {
+            value_column: value_column(value_desc, key_indices),
+        }
+    }
+}
+
+impl Encode for BinaryEncoder {
+    fn get_format_name(&self) -> &str {
+        "bytes"
+    }
+
+    fn encode_key_unchecked(&self, row: Row) -> Vec<u8> {
+        row.unpack_first().unwrap_bytes().to_vec()
+    }
+
+    fn encode_value_unchecked(&self, row: Row) -> Vec<u8> {
+        datum_at(&row, self.value_column).unwrap_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use mz_repr::adt::jsonb::Jsonb;
+    use mz_repr::{ColumnType, RelationType};
+
+    use super::*;
+
+    fn desc(types: &[ScalarType]) -> RelationDesc {
+        RelationDesc::new(
+            RelationType::new(
+                types
+                    .iter()
+                    .map(|typ| ColumnType {
+                        scalar_type: typ.clone(),
+                        nullable: false,
+                    })
+                    .collect(),
+            ),
+            (0..types.len()).map(|i| format!("c{i}")),
+        )
+    }
+
+    #[mz_ore::test]
+    fn text_encoding() {
+        let encoder = TextEncoder::new(
+            Some(desc(&[ScalarType::Int32])),
+            desc(&[ScalarType::Jsonb]),
+            None,
+        );
+
+        let key = Row::pack_slice(&[Datum::Int32(42)]);
+        assert_eq!(encoder.encode_key_unchecked(key), b"42");
+
+        let value = Jsonb::from_str(r#"{"a": 1}"#).unwrap();
+        assert_eq!(
+            encoder.encode_value_unchecked(value.into_row()),
+            br#"{"a":1}"#
+        );
+    }
+
+    #[mz_ore::test]
+    fn text_encoding_skips_key_columns() {
+        let encoder = TextEncoder::new(
+            Some(desc(&[ScalarType::Int32])),
+            desc(&[ScalarType::String, ScalarType::Int32]),
+            Some(&[1]),
+        );
+
+        let value = Row::pack_slice(&[Datum::String("v"), Datum::Int32(42)]);
+        assert_eq!(encoder.encode_value_unchecked(value), b"v");
+    }
+
+    #[mz_ore::test]
+    fn binary_encoding() {
+        let encoder =
+            BinaryEncoder::new(&desc(&[ScalarType::Bytes, ScalarType::Bytes]), Some(&[0]));
+
+        let key = Row::pack_slice(&[Datum::Bytes(b"key")]);
+        assert_eq!(encoder.encode_key_unchecked(key), b"key");
+
+        let value = Row::pack_slice(&[Datum::Bytes(b"key"), Datum::Bytes(b"\x00\xffraw")]);
+        assert_eq!(encoder.encode_value_unchecked(value), b"\x00\xffraw");
+    }
+}
@@ -93,6 +93,54 @@ pub fn encode_copy_row_text(
     Ok(())
 }
 
+/// Encodes a row in the PostgreSQL `COPY` CSV format with the default
+/// parameters: a `,` delimiter, `"` as both the quote and escape character,
+/// and `NULL` represented as an unquoted empty string.
+pub fn encode_copy_row_csv(
+    row: Row,
+    typ: &RelationType,
+    out: &mut Vec<u8>,
+) -> Result<(), io::Error> {
+    let delim = b',';
+    let quote = b'"';
+    let mut buf = BytesMut::new();
+    for (idx, field) in mz_pgrepr::values_from_row(row, typ).into_iter().enumerate() {
+        if idx > 0 {
+            out.push(delim);
+        }
+        match field {
+            None => (),
+            Some(field) => {
+                buf.clear();
+                field.encode_text(&mut buf);
+                // Like PostgreSQL, quote values that would otherwise be
+                // misread: the empty string (which would read back as NULL),
+                // the end of copy marker, and anything containing the
+                // delimiter, the quote character or a line break.
+                let needs_quotes = buf.is_empty()
+                    || &buf[..] == END_OF_COPY_MARKER
+                    || buf
+                        .iter()
+                        .any(|b| *b == delim || *b == quote || *b == b'\n' || *b == b'\r');
+                if needs_quotes {
+                    out.push(quote);
+                    for b in &buf {
+                        if *b == quote {
+                            out.push(quote);
+                        }
+                        out.push(*b);
+                    }
+                    out.push(quote);
+                } else {
+                    out.extend(&buf);
+                }
+            }
+        }
+    }
+    out.push(b'\n');
+    Ok(())
+}
+
 pub struct CopyTextFormatParser<'a> {
     data: &'a [u8],
     position: usize,
@@ -511,6 +559,8 @@ pub fn decode_copy_format_csv(
 
 #[cfg(test)]
 mod tests {
+    use mz_repr::ScalarType;
+
     use super::*;
 
     #[mz_ore::test]
@@ -674,4 +724,47 @@ mod tests {
             assert!(parser.is_eof());
         }
     }
+
+    #[mz_ore::test]
+    fn test_copy_format_csv_round_trip() {
+        let typ = RelationType::new(vec![
+            ScalarType::Int32.nullable(true),
+            ScalarType::String.nullable(true),
+        ]);
+        let rows = vec![
+            Row::pack_slice(&[Datum::Int32(1), Datum::String("plain")]),
+            Row::pack_slice(&[Datum::Int32(2), Datum::String("")]),
+            Row::pack_slice(&[Datum::Null, Datum::Null]),
+            Row::pack_slice(&[Datum::Int32(3), Datum::String("a,\"b\"\nc")]),
+            Row::pack_slice(&[Datum::Int32(4), Datum::String("\\.")]),
+        ];
+
+        let mut out = Vec::new();
+        for row in &rows {
+            encode_copy_row_csv(row.clone(), &typ, &mut out).unwrap();
+        }
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "1,plain\n2,\"\"\n,\n3,\"a,\"\"b\"\"\nc\"\n4,\"\\.\"\n",
+        );
+
+        let column_types = typ
+            .column_types
+            .iter()
+            .map(|t| mz_pgrepr::Type::from(&t.scalar_type))
+            .collect::<Vec<_>>();
+        let decoded = decode_copy_format_csv(
+            &out,
+            &column_types,
+            CopyCsvFormatParams {
+                delimiter: b',',
+                quote: b'"',
+                escape: b'"',
+                header: false,
+                null: "".into(),
+            },
+        )
+        .unwrap();
+        assert_eq!(decoded, rows);
+    }
 }
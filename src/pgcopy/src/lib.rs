@@ -16,6 +16,6 @@
 mod copy;
 
 pub use copy::{
-    decode_copy_format, encode_copy_row_binary, encode_copy_row_text, CopyCsvFormatParams,
-    CopyFormatParams, CopyTextFormatParams, CopyTextFormatParser,
+    decode_copy_format, encode_copy_row_binary, encode_copy_row_csv, encode_copy_row_text,
+    CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams, CopyTextFormatParser,
 };
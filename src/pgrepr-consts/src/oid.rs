@@ -18,6 +18,7 @@ pub const TYPE_ANYCOMPATIBLE_OID: u32 = 5077;
 pub const TYPE_ANYCOMPATIBLEARRAY_OID: u32 = 5078;
 pub const TYPE_ANYCOMPATIBLENONARRAY_OID: u32 = 5079;
 pub const TYPE_ANYELEMENT_OID: u32 = 2283;
+pub const TYPE_ANYENUM_OID: u32 = 3500;
 pub const TYPE_ANYNONARRAY_OID: u32 = 2776;
 pub const TYPE_BOOL_ARRAY_OID: u32 = 1000;
 pub const TYPE_BOOL_OID: u32 = 16;
@@ -86,6 +87,16 @@ pub const TYPE_TSRANGE_OID: u32 = 3908;
 pub const TYPE_TSRANGE_ARRAY_OID: u32 = 3909;
 pub const TYPE_TSTZRANGE_OID: u32 = 3910;
 pub const TYPE_TSTZRANGE_ARRAY_OID: u32 = 3911;
+pub const TYPE_INET_OID: u32 = 869;
+pub const TYPE_INET_ARRAY_OID: u32 = 1041;
+pub const TYPE_CIDR_OID: u32 = 650;
+pub const TYPE_CIDR_ARRAY_OID: u32 = 651;
+pub const TYPE_TSVECTOR_OID: u32 = 3614;
+pub const TYPE_TSVECTOR_ARRAY_OID: u32 = 3643;
+pub const TYPE_TSQUERY_OID: u32 = 3615;
+pub const TYPE_TSQUERY_ARRAY_OID: u32 = 3645;
+pub const TYPE_JSONPATH_OID: u32 = 4072;
+pub const TYPE_JSONPATH_ARRAY_OID: u32 = 4073;
 
 /// The first OID in PostgreSQL's system catalog that is not pinned during
 /// bootstrapping.
@@ -368,3 +379,23 @@ pub const FUNC_CONSTANT_TIME_EQ_STRING_OID: u32 = 16_645;
 pub const FUNC_TIMEZONE_OFFSET: u32 = 16_646;
 pub const FUNC_PRETTY_SQL: u32 = 16_647;
 pub const FUNC_PRETTY_SQL_NOWIDTH: u32 = 16_648;
+pub const FUNC_JSONB_PATH_EXISTS_NOVARS_OID: u32 = 16_649;
+pub const FUNC_JSONB_PATH_EXISTS_NOSILENT_OID: u32 = 16_650;
+pub const FUNC_JSONB_PATH_MATCH_NOVARS_OID: u32 = 16_651;
+pub const FUNC_JSONB_PATH_MATCH_NOSILENT_OID: u32 = 16_652;
+pub const FUNC_JSONB_PATH_QUERY_NOVARS_OID: u32 = 16_653;
+pub const FUNC_JSONB_PATH_QUERY_NOSILENT_OID: u32 = 16_654;
+pub const FUNC_JSONB_PATH_QUERY_ARRAY_NOVARS_OID: u32 = 16_655;
+pub const FUNC_JSONB_PATH_QUERY_ARRAY_NOSILENT_OID: u32 = 16_656;
+pub const FUNC_JSONB_PATH_QUERY_FIRST_NOVARS_OID: u32 = 16_657;
+pub const FUNC_JSONB_PATH_QUERY_FIRST_NOSILENT_OID: u32 = 16_658;
+pub const FUNC_JSONB_SET_NOCREATE_OID: u32 = 16_659;
+pub const FUNC_JSONB_INSERT_NOAFTER_OID: u32 = 16_660;
+pub const FUNC_JSONB_SET_LAX_NOCREATE_OID: u32 = 16_661;
+pub const FUNC_JSONB_SET_LAX_NOTREATMENT_OID: u32 = 16_662;
+pub const TYPE_HLL_OID: u32 = 16_663;
+pub const TYPE_HLL_ARRAY_OID: u32 = 16_664;
+pub const FUNC_HLL_SKETCH_OID: u32 = 16_665;
+pub const FUNC_HLL_MERGE_OID: u32 = 16_666;
+pub const FUNC_HLL_ESTIMATE_OID: u32 = 16_667;
+pub const FUNC_APPROX_COUNT_DISTINCT_OID: u32 = 16_668;
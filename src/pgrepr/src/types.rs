@@ -62,6 +62,17 @@ pub enum Type {
     Char,
     /// A date.
     Date,
+    /// A user-defined enumerated type.
+    Enum {
+        /// The OID of the enum type.
+        oid: u32,
+        /// The labels of the enum, in sort order, if known.
+        ///
+        /// The labels of an enum live in the catalog rather than in its
+        /// [`ScalarType`], so they are unknown to types converted from a
+        /// [`ScalarType`]. They are only required to decode values.
+        labels: Option<Vec<String>>,
+    },
     /// A 4-byte floating point number.
     Float4,
     /// An 8-byte floating point number.
@@ -163,6 +174,18 @@ pub enum Type {
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem,
+    /// An IPv4 or IPv6 host address and, optionally, its network.
+    Inet,
+    /// An IPv4 or IPv6 network.
+    Cidr,
+    /// A document optimized for text search.
+    TsVector,
+    /// A text search query.
+    TsQuery,
+    /// A SQL/JSON path.
+    JsonPath,
+    /// A HyperLogLog sketch.
+    Hll,
 }
 
 /// An unpacked [`typmod`](Type::typmod) for a [`Type`].
@@ -528,6 +551,26 @@ pub static MZ_ACL_ITEM_ARRAY: Lazy<postgres_types::Type> = Lazy::new(|| {
     )
 });
 
+/// An anonymous [`Type::Hll`], akin to [`postgres_types::Type::BYTEA`].
+pub static HLL: Lazy<postgres_types::Type> = Lazy::new(|| {
+    postgres_types::Type::new(
+        "hll".to_owned(),
+        oid::TYPE_HLL_OID,
+        postgres_types::Kind::Pseudo,
+        MZ_CATALOG_SCHEMA.to_owned(),
+    )
+});
+
+/// An anonymous [`Type::Array`], akin to [`postgres_types::Type::BYTEA_ARRAY`].
+pub static HLL_ARRAY: Lazy<postgres_types::Type> = Lazy::new(|| {
+    postgres_types::Type::new(
+        "_hll".to_owned(),
+        oid::TYPE_HLL_ARRAY_OID,
+        postgres_types::Kind::Pseudo,
+        MZ_CATALOG_SCHEMA.to_owned(),
+    )
+});
+
 impl Type {
     /// Returns the type corresponding to the provided OID, if the OID is known.
     pub fn from_oid(oid: u32) -> Result<Type, TypeFromOidError> {
@@ -569,6 +612,11 @@ impl Type {
             postgres_types::Type::TIMESTAMP => Type::Timestamp { precision: None },
             postgres_types::Type::TIMESTAMPTZ => Type::TimestampTz { precision: None },
             postgres_types::Type::UUID => Type::Uuid,
+            postgres_types::Type::INET => Type::Inet,
+            postgres_types::Type::CIDR => Type::Cidr,
+            postgres_types::Type::TS_VECTOR => Type::TsVector,
+            postgres_types::Type::TSQUERY => Type::TsQuery,
+            postgres_types::Type::JSONPATH => Type::JsonPath,
             postgres_types::Type::REGCLASS => Type::RegClass,
             postgres_types::Type::REGPROC => Type::RegProc,
             postgres_types::Type::REGTYPE => Type::RegType,
@@ -606,6 +654,11 @@ impl Type {
                 Type::Array(Box::new(Type::TimestampTz { precision: None }))
             }
             postgres_types::Type::UUID_ARRAY => Type::Array(Box::new(Type::Uuid)),
+            postgres_types::Type::INET_ARRAY => Type::Array(Box::new(Type::Inet)),
+            postgres_types::Type::CIDR_ARRAY => Type::Array(Box::new(Type::Cidr)),
+            postgres_types::Type::TS_VECTOR_ARRAY => Type::Array(Box::new(Type::TsVector)),
+            postgres_types::Type::TSQUERY_ARRAY => Type::Array(Box::new(Type::TsQuery)),
+            postgres_types::Type::JSONPATH_ARRAY => Type::Array(Box::new(Type::JsonPath)),
             postgres_types::Type::VARCHAR_ARRAY => {
                 Type::Array(Box::new(Type::VarChar { max_length: None }))
             }
@@ -706,6 +759,7 @@ impl Type {
                 Type::Bytea => &postgres_types::Type::BYTEA_ARRAY,
                 Type::Char => &postgres_types::Type::CHAR_ARRAY,
                 Type::Date => &postgres_types::Type::DATE_ARRAY,
+                Type::Enum { .. } => unreachable!(),
                 Type::Float4 => &postgres_types::Type::FLOAT4_ARRAY,
                 Type::Float8 => &postgres_types::Type::FLOAT8_ARRAY,
                 Type::Int2 => &postgres_types::Type::INT2_ARRAY,
@@ -731,6 +785,11 @@ impl Type {
                 Type::Timestamp { .. } => &postgres_types::Type::TIMESTAMP_ARRAY,
                 Type::TimestampTz { .. } => &postgres_types::Type::TIMESTAMPTZ_ARRAY,
                 Type::Uuid => &postgres_types::Type::UUID_ARRAY,
+                Type::Inet => &postgres_types::Type::INET_ARRAY,
+                Type::Cidr => &postgres_types::Type::CIDR_ARRAY,
+                Type::TsVector => &postgres_types::Type::TS_VECTOR_ARRAY,
+                Type::TsQuery => &postgres_types::Type::TSQUERY_ARRAY,
+                Type::JsonPath => &postgres_types::Type::JSONPATH_ARRAY,
                 Type::RegClass => &postgres_types::Type::REGCLASS_ARRAY,
                 Type::RegProc => &postgres_types::Type::REGPROC_ARRAY,
                 Type::RegType => &postgres_types::Type::REGTYPE_ARRAY,
@@ -746,11 +805,16 @@ impl Type {
                     _ => unreachable!(),
                 },
                 Type::MzAclItem => &MZ_ACL_ITEM_ARRAY,
+                Type::Hll => &HLL_ARRAY,
             },
             Type::Bool => &postgres_types::Type::BOOL,
             Type::Bytea => &postgres_types::Type::BYTEA,
             Type::Char => &postgres_types::Type::CHAR,
             Type::Date => &postgres_types::Type::DATE,
+            // Enum types are user defined, so there is no static type that
+            // describes them. Callers that need the enum's OID must use
+            // `Type::oid`.
+            Type::Enum { .. } => &postgres_types::Type::ANYENUM,
             Type::Float4 => &postgres_types::Type::FLOAT4,
             Type::Float8 => &postgres_types::Type::FLOAT8,
             Type::Int2 => &postgres_types::Type::INT2,
@@ -776,6 +840,11 @@ impl Type {
             Type::Timestamp { .. } => &postgres_types::Type::TIMESTAMP,
             Type::TimestampTz { .. } => &postgres_types::Type::TIMESTAMPTZ,
             Type::Uuid => &postgres_types::Type::UUID,
+            Type::Inet => &postgres_types::Type::INET,
+            Type::Cidr => &postgres_types::Type::CIDR,
+            Type::TsVector => &postgres_types::Type::TS_VECTOR,
+            Type::TsQuery => &postgres_types::Type::TSQUERY,
+            Type::JsonPath => &postgres_types::Type::JSONPATH,
             Type::RegClass => &postgres_types::Type::REGCLASS,
             Type::RegProc => &postgres_types::Type::REGPROC,
             Type::RegType => &postgres_types::Type::REGTYPE,
@@ -791,6 +860,7 @@ impl Type {
                 t => unreachable!("{t:?} is not a range element type"),
             },
             Type::MzAclItem => &MZ_ACL_ITEM,
+            Type::Hll => &HLL,
         }
     }
 
@@ -825,6 +895,11 @@ impl Type {
             &postgres_types::Type::TIMESTAMP_ARRAY => "timestamp without time zone[]",
             &postgres_types::Type::TIMESTAMPTZ_ARRAY => "timestamp with time zone[]",
             &postgres_types::Type::UUID_ARRAY => "uuid[]",
+            &postgres_types::Type::INET_ARRAY => "inet[]",
+            &postgres_types::Type::CIDR_ARRAY => "cidr[]",
+            &postgres_types::Type::TS_VECTOR_ARRAY => "tsvector[]",
+            &postgres_types::Type::TSQUERY_ARRAY => "tsquery[]",
+            &postgres_types::Type::JSONPATH_ARRAY => "jsonpath[]",
             &postgres_types::Type::VARCHAR_ARRAY => "character varying[]",
             &postgres_types::Type::BOOL => "boolean",
             &postgres_types::Type::BPCHAR => "character",
@@ -846,6 +921,7 @@ impl Type {
                 oid::TYPE_UINT8_ARRAY_OID => "uint8[]",
                 oid::TYPE_MZ_TIMESTAMP_ARRAY_OID => "mz_timestamp[]",
                 oid::TYPE_MZ_ACL_ITEM_ARRAY_OID => "mz_aclitem[]",
+                oid::TYPE_HLL_ARRAY_OID => "hll[]",
                 _ => other.name(),
             },
         }
@@ -855,7 +931,10 @@ impl Type {
     ///
     /// [OID]: https://www.postgresql.org/docs/current/datatype-oid.html
     pub fn oid(&self) -> u32 {
-        self.inner().oid()
+        match self {
+            Type::Enum { oid, .. } => *oid,
+            _ => self.inner().oid(),
+        }
     }
 
     /// Returns the constraint on the type, if any.
@@ -892,6 +971,7 @@ impl Type {
             | Type::BpChar { length: None }
             | Type::Char
             | Type::Date
+            | Type::Enum { .. }
             | Type::Float4
             | Type::Float8
             | Type::Int2
@@ -919,6 +999,12 @@ impl Type {
             | Type::Timestamp { precision: None }
             | Type::TimestampTz { precision: None }
             | Type::Uuid
+            | Type::Inet
+            | Type::Cidr
+            | Type::TsVector
+            | Type::TsQuery
+            | Type::JsonPath
+            | Type::Hll
             | Type::MzTimestamp
             | Type::VarChar { max_length: None }
             | Type::Range { .. }
@@ -935,6 +1021,7 @@ impl Type {
             Type::Bytea => -1,
             Type::Char => 1,
             Type::Date => 4,
+            Type::Enum { .. } => -1,
             Type::Float4 => 4,
             Type::Float8 => 8,
             Type::Int2 => 2,
@@ -960,6 +1047,12 @@ impl Type {
             Type::Timestamp { .. } => 8,
             Type::TimestampTz { .. } => 8,
             Type::Uuid => 16,
+            Type::Inet => -1,
+            Type::Cidr => -1,
+            Type::TsVector => -1,
+            Type::TsQuery => -1,
+            Type::JsonPath => -1,
+            Type::Hll => -1,
             Type::RegClass => 4,
             Type::RegProc => 4,
             Type::RegType => 4,
@@ -1012,6 +1105,8 @@ impl TryFrom<&Type> for ScalarType {
             Type::Bytea => Ok(ScalarType::Bytes),
             Type::Char => Ok(ScalarType::PgLegacyChar),
             Type::Date => Ok(ScalarType::Date),
+            // The enum's catalog ID is not known to this crate.
+            Type::Enum { .. } => Err(TypeConversionError::UnsupportedType(typ.clone())),
             Type::Float4 => Ok(ScalarType::Float32),
             Type::Float8 => Ok(ScalarType::Float64),
             Type::Int2 => Ok(ScalarType::Int16),
@@ -1095,6 +1190,12 @@ impl TryFrom<&Type> for ScalarType {
                 },
             }),
             Type::Uuid => Ok(ScalarType::Uuid),
+            Type::Inet => Ok(ScalarType::Inet),
+            Type::Cidr => Ok(ScalarType::Cidr),
+            Type::TsVector => Ok(ScalarType::TsVector),
+            Type::TsQuery => Ok(ScalarType::TsQuery),
+            Type::JsonPath => Ok(ScalarType::JsonPath),
+            Type::Hll => Ok(ScalarType::Hll),
             Type::RegClass => Ok(ScalarType::RegClass),
             Type::RegProc => Ok(ScalarType::RegProc),
             Type::RegType => Ok(ScalarType::RegType),
@@ -1254,6 +1355,12 @@ impl From<&ScalarType> for Type {
                 precision: (*precision).map(TimestampPrecision::from),
             },
             ScalarType::Uuid => Type::Uuid,
+            ScalarType::Inet => Type::Inet,
+            ScalarType::Cidr => Type::Cidr,
+            ScalarType::TsVector => Type::TsVector,
+            ScalarType::TsQuery => Type::TsQuery,
+            ScalarType::JsonPath => Type::JsonPath,
+            ScalarType::Hll => Type::Hll,
             ScalarType::Numeric { max_scale } => Type::Numeric {
                 constraints: Some(NumericConstraints {
                     max_precision: i32::from(NUMERIC_DATUM_MAX_PRECISION),
@@ -1272,6 +1379,10 @@ impl From<&ScalarType> for Type {
                 element_type: Box::new(From::from(&**element_type)),
             },
             ScalarType::MzAclItem => Type::MzAclItem,
+            ScalarType::Enum { custom_oid, .. } => Type::Enum {
+                oid: *custom_oid,
+                labels: None,
+            },
         }
     }
 }
@@ -18,6 +18,9 @@ use mz_pgwire_common::Format;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::enum_type::EnumValue;
+use mz_repr::adt::hll::Hll;
+use mz_repr::adt::inet::Cidr;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::mz_acl_item::{AclItem, MzAclItem};
 use mz_repr::adt::pg_legacy_name::NAME_MAX_BYTES;
@@ -29,12 +32,17 @@ use postgres_types::{FromSql, IsNull, ToSql, Type as PgType};
 use uuid::Uuid;
 
 use crate::types::{UINT2, UINT4, UINT8};
-use crate::{Interval, Jsonb, Numeric, Type, UInt2, UInt4, UInt8};
+use crate::{
+    Inet, Interval, JsonPath, Jsonb, Numeric, TsQuery, TsVector, Type, UInt2, UInt4, UInt8,
+};
 
+pub mod inet;
 pub mod interval;
 pub mod jsonb;
+pub mod jsonpath;
 pub mod numeric;
 pub mod record;
+pub mod text_search;
 pub mod unsigned;
 
 /// A PostgreSQL datum.
@@ -55,6 +63,8 @@ pub enum Value {
     Char(u8),
     /// A date.
     Date(Date),
+    /// A label of a user-defined enumerated type.
+    Enum(String),
     /// A 4-byte floating point number.
     Float4(f32),
     /// An 8-byte floating point number.
@@ -116,6 +126,18 @@ pub enum Value {
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem(AclItem),
+    /// An IPv4 or IPv6 host address and, optionally, its network.
+    Inet(Inet),
+    /// An IPv4 or IPv6 network.
+    Cidr(Inet),
+    /// A document optimized for text search.
+    TsVector(TsVector),
+    /// A text search query.
+    TsQuery(TsQuery),
+    /// A SQL/JSON path.
+    JsonPath(JsonPath),
+    /// A HyperLogLog sketch.
+    Hll(Hll),
 }
 
 impl Value {
@@ -146,6 +168,9 @@ impl Value {
             (Datum::MzAclItem(mai), ScalarType::MzAclItem) => Some(Value::MzAclItem(mai)),
             (Datum::AclItem(ai), ScalarType::AclItem) => Some(Value::AclItem(ai)),
             (Datum::Date(d), ScalarType::Date) => Some(Value::Date(d)),
+            (d @ Datum::List(_), ScalarType::Enum { .. }) => {
+                Some(Value::Enum(EnumValue::from_datum(d).label.to_owned()))
+            }
             (Datum::Time(t), ScalarType::Time) => Some(Value::Time(t)),
             (Datum::Timestamp(ts), ScalarType::Timestamp { .. }) => Some(Value::Timestamp(ts)),
             (Datum::TimestampTz(ts), ScalarType::TimestampTz { .. }) => {
@@ -163,6 +188,20 @@ impl Value {
                 Some(Value::Jsonb(Jsonb(JsonbRef::from_datum(datum).to_owned())))
             }
             (Datum::Uuid(u), ScalarType::Uuid) => Some(Value::Uuid(u)),
+            (Datum::Inet(i), ScalarType::Inet) => Some(Value::Inet(Inet(i))),
+            (Datum::Inet(i), ScalarType::Cidr) => Some(Value::Cidr(Inet(i))),
+            (Datum::String(s), ScalarType::TsVector) => Some(Value::TsVector(TsVector(
+                s.parse().expect("tsvector datums are canonical"),
+            ))),
+            (Datum::String(s), ScalarType::TsQuery) => Some(Value::TsQuery(TsQuery(
+                s.parse().expect("tsquery datums are canonical"),
+            ))),
+            (Datum::String(s), ScalarType::JsonPath) => Some(Value::JsonPath(JsonPath(
+                s.parse().expect("jsonpath datums are canonical"),
+            ))),
+            (Datum::Bytes(b), ScalarType::Hll) => Some(Value::Hll(
+                Hll::decode(b).expect("hll datums are canonical"),
+            )),
             (Datum::Array(array), ScalarType::Array(elem_type)) => {
                 let dims = array.dims().into_iter().collect();
                 let elements = array
@@ -247,6 +286,21 @@ impl Value {
             Value::Bytea(b) => Datum::Bytes(buf.push_bytes(b)),
             Value::Char(c) => Datum::UInt8(c),
             Value::Date(d) => Datum::Date(d),
+            Value::Enum(label) => {
+                let labels = match typ {
+                    Type::Enum {
+                        labels: Some(labels),
+                        ..
+                    } => labels,
+                    _ => panic!(
+                        "Value::Enum should have type Type::Enum with labels. Found {:?}",
+                        typ
+                    ),
+                };
+                // Values are only ever decoded from labels of their type.
+                let position = strconv::parse_enum(labels, &label).expect("valid enum label");
+                buf.make_datum(|packer| EnumValue { position, label }.push(packer))
+            }
             Value::Float4(f) => Datum::Float32(f.into()),
             Value::Float8(f) => Datum::Float64(f.into()),
             Value::Int2(i) => Datum::Int16(i),
@@ -300,6 +354,11 @@ impl Value {
             }
             Value::BpChar(s) => Datum::String(buf.push_string(s.trim_end().into())),
             Value::Uuid(u) => Datum::Uuid(u),
+            Value::Inet(i) | Value::Cidr(i) => Datum::Inet(i.0),
+            Value::TsVector(v) => Datum::String(buf.push_string(v.0.to_string())),
+            Value::TsQuery(q) => Datum::String(buf.push_string(q.0.to_string())),
+            Value::JsonPath(p) => Datum::String(buf.push_string(p.0.to_string())),
+            Value::Hll(h) => Datum::Bytes(buf.push_bytes(h.encode())),
             Value::Numeric(n) => Datum::Numeric(n.0),
             Value::MzTimestamp(t) => Datum::MzTimestamp(t),
             Value::Range(range) => {
@@ -355,6 +414,7 @@ impl Value {
                 Nestable::MayNeedEscaping
             }
             Value::Date(d) => strconv::format_date(buf, *d),
+            Value::Enum(label) => strconv::format_string(buf, label),
             Value::Int2(i) => strconv::format_int16(buf, *i),
             Value::Int4(i) => strconv::format_int32(buf, *i),
             Value::Int8(i) => strconv::format_int64(buf, *i),
@@ -388,6 +448,12 @@ impl Value {
             Value::Timestamp(ts) => strconv::format_timestamp(buf, ts),
             Value::TimestampTz(ts) => strconv::format_timestamptz(buf, ts),
             Value::Uuid(u) => strconv::format_uuid(buf, *u),
+            Value::Inet(i) => strconv::format_inet(buf, i.0),
+            Value::Cidr(i) => strconv::format_cidr(buf, Cidr(i.0)),
+            Value::TsVector(v) => strconv::format_tsvector(buf, &v.0),
+            Value::TsQuery(q) => strconv::format_tsquery(buf, &q.0),
+            Value::JsonPath(p) => strconv::format_jsonpath(buf, &p.0),
+            Value::Hll(h) => strconv::format_hll(buf, h),
             Value::Numeric(d) => strconv::format_numeric(buf, &d.0),
             Value::MzTimestamp(t) => strconv::format_mz_timestamp(buf, *t),
             Value::Range(range) => strconv::format_range(buf, range, |buf, elem| match elem {
@@ -436,6 +502,8 @@ impl Value {
             Value::Bytea(b) => b.to_sql(&PgType::BYTEA, buf),
             Value::Char(c) => i8::reinterpret_cast(*c).to_sql(&PgType::CHAR, buf),
             Value::Date(d) => d.pg_epoch_days().to_sql(&PgType::DATE, buf),
+            // The binary representation of an enum is its label's text.
+            Value::Enum(label) => label.to_sql(&PgType::TEXT, buf),
             Value::Float4(f) => f.to_sql(&PgType::FLOAT4, buf),
             Value::Float8(f) => f.to_sql(&PgType::FLOAT8, buf),
             Value::Int2(i) => i.to_sql(&PgType::INT2, buf),
@@ -507,6 +575,12 @@ impl Value {
             Value::Timestamp(ts) => ts.to_sql(&PgType::TIMESTAMP, buf),
             Value::TimestampTz(ts) => ts.to_sql(&PgType::TIMESTAMPTZ, buf),
             Value::Uuid(u) => u.to_sql(&PgType::UUID, buf),
+            Value::Inet(i) => i.to_sql(&PgType::INET, buf),
+            Value::Cidr(i) => i.to_sql(&PgType::CIDR, buf),
+            Value::TsVector(v) => v.to_sql(&PgType::TS_VECTOR, buf),
+            Value::TsQuery(q) => q.to_sql(&PgType::TSQUERY, buf),
+            Value::JsonPath(p) => p.to_sql(&PgType::JSONPATH, buf),
+            Value::Hll(h) => h.encode().to_sql(&PgType::BYTEA, buf),
             Value::Numeric(a) => a.to_sql(&PgType::NUMERIC, buf),
             Value::MzTimestamp(t) => t.to_string().to_sql(&PgType::TEXT, buf),
             Value::Range(range) => {
@@ -579,6 +653,10 @@ impl Value {
             Type::Bytea => Value::Bytea(strconv::parse_bytes(s)?),
             Type::Char => Value::Char(raw.get(0).copied().unwrap_or(0)),
             Type::Date => Value::Date(strconv::parse_date(s)?),
+            Type::Enum { labels, .. } => {
+                strconv::parse_enum(enum_labels(labels)?, s)?;
+                Value::Enum(s.to_owned())
+            }
             Type::Float4 => Value::Float4(strconv::parse_float32(s)?),
             Type::Float8 => Value::Float8(strconv::parse_float64(s)?),
             Type::Int2 => Value::Int2(strconv::parse_int16(s)?),
@@ -621,6 +699,12 @@ impl Value {
             Type::Timestamp { .. } => Value::Timestamp(strconv::parse_timestamp(s)?),
             Type::TimestampTz { .. } => Value::TimestampTz(strconv::parse_timestamptz(s)?),
             Type::Uuid => Value::Uuid(Uuid::parse_str(s)?),
+            Type::Inet => Value::Inet(Inet(strconv::parse_inet(s)?)),
+            Type::Cidr => Value::Cidr(Inet(strconv::parse_cidr(s)?.0)),
+            Type::TsVector => Value::TsVector(TsVector(strconv::parse_tsvector(s)?)),
+            Type::TsQuery => Value::TsQuery(TsQuery(strconv::parse_tsquery(s)?)),
+            Type::JsonPath => Value::JsonPath(JsonPath(strconv::parse_jsonpath(s)?)),
+            Type::Hll => Value::Hll(strconv::parse_hll(s)?),
             Type::MzTimestamp => Value::MzTimestamp(strconv::parse_mz_timestamp(s)?),
             Type::Range { element_type } => Value::Range(strconv::parse_range(s, |elem_text| {
                 Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
@@ -645,6 +729,11 @@ impl Value {
                 let days = i32::from_sql(ty.inner(), raw)?;
                 Ok(Value::Date(Date::from_pg_epoch(days)?))
             }
+            Type::Enum { labels, .. } => {
+                let s = String::from_sql(&PgType::TEXT, raw)?;
+                strconv::parse_enum(enum_labels(labels)?, &s)?;
+                Ok(Value::Enum(s))
+            }
             Type::Float4 => f32::from_sql(ty.inner(), raw).map(Value::Float4),
             Type::Float8 => f64::from_sql(ty.inner(), raw).map(Value::Float8),
             Type::Int2 => i16::from_sql(ty.inner(), raw).map(Value::Int2),
@@ -686,6 +775,12 @@ impl Value {
                 )?))
             }
             Type::Uuid => Uuid::from_sql(ty.inner(), raw).map(Value::Uuid),
+            Type::Inet => Inet::from_sql(ty.inner(), raw).map(Value::Inet),
+            Type::Cidr => Inet::from_sql(ty.inner(), raw).map(Value::Cidr),
+            Type::TsVector => TsVector::from_sql(ty.inner(), raw).map(Value::TsVector),
+            Type::TsQuery => TsQuery::from_sql(ty.inner(), raw).map(Value::TsQuery),
+            Type::JsonPath => JsonPath::from_sql(ty.inner(), raw).map(Value::JsonPath),
+            Type::Hll => Ok(Value::Hll(Hll::decode(raw)?)),
             Type::MzTimestamp => {
                 let s = String::from_sql(ty.inner(), raw)?;
                 let t: mz_repr::Timestamp = s.parse()?;
@@ -715,6 +810,14 @@ fn encode_element(buf: &mut BytesMut, elem: Option<&Value>, ty: &Type) -> Result
     Ok(())
 }
 
+/// Returns the labels of an enum type, which are required to decode its values.
+fn enum_labels(labels: &Option<Vec<String>>) -> Result<&[String], Box<dyn Error + Sync + Send>> {
+    match labels {
+        Some(labels) => Ok(labels),
+        None => Err("enum values cannot be decoded without the labels of their type".into()),
+    }
+}
+
 fn pg_len(what: &str, len: usize) -> Result<i32, io::Error> {
     len.try_into().map_err(|_| {
         io::Error::new(
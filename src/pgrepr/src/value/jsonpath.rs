@@ -0,0 +1,58 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::error::Error;
+use std::fmt;
+use std::str;
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+/// A wrapper for the `repr` crate's
+/// [`JsonPath`](mz_repr::adt::jsonpath::JsonPath) type that can be serialized
+/// to and deserialized from the PostgreSQL binary format.
+#[derive(Debug, Clone)]
+pub struct JsonPath(pub mz_repr::adt::jsonpath::JsonPath);
+
+impl ToSql for JsonPath {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + 'static + Send + Sync>> {
+        out.put_u8(1); // version
+        out.put_slice(self.0.to_string().as_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::JSONPATH)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for JsonPath {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<JsonPath, Box<dyn Error + Sync + Send>> {
+        if raw.len() < 1 || raw[0] != 1 {
+            return Err("unsupported jsonpath encoding version".into());
+        }
+        Ok(JsonPath(str::from_utf8(&raw[1..])?.parse()?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::JSONPATH)
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::error::Error;
+use std::fmt;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+use bytes::{BufMut, BytesMut};
+use mz_repr::adt::text_search::{
+    TsLexeme, TsOperand, TsPosition, TsQuery as ReprTsQuery, TsQueryNode, TsVector as ReprTsVector,
+    TsWeight, MAX_PHRASE_DISTANCE, MAX_POSITION,
+};
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+// The item types and operators of the PostgreSQL binary format of `tsquery`.
+const QI_VAL: u8 = 1;
+const QI_OPR: u8 = 2;
+const OP_NOT: u8 = 1;
+const OP_AND: u8 = 2;
+const OP_OR: u8 = 3;
+const OP_PHRASE: u8 = 4;
+
+/// A wrapper for the `repr` crate's
+/// [`TsVector`](mz_repr::adt::text_search::TsVector) type that can be
+/// serialized to and deserialized from the PostgreSQL binary format.
+#[derive(Debug, Clone)]
+pub struct TsVector(pub ReprTsVector);
+
+impl fmt::Display for TsVector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ToSql for TsVector {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + 'static + Send + Sync>> {
+        // Postgres represents a vector as the number of lexemes followed by,
+        // for each lexeme, the null-terminated word, the number of positions,
+        // and the positions, each of which packs its weight into its two
+        // highest bits.
+        //
+        // Postgres implementation: https://github.com/postgres/postgres/blob/REL_16_0/src/backend/utils/adt/tsvector.c#L367-L396
+        out.put_u32(u32::try_from(self.0.lexemes().len())?);
+        for lexeme in self.0.lexemes() {
+            put_cstring(out, &lexeme.word)?;
+            out.put_u16(u16::try_from(lexeme.positions.len())?);
+            for position in &lexeme.positions {
+                let weight = u16::try_from(position.weight.index())?;
+                out.put_u16(weight << 14 | position.position);
+            }
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::TS_VECTOR)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for TsVector {
+    fn from_sql(_: &Type, mut raw: &'a [u8]) -> Result<TsVector, Box<dyn Error + Sync + Send>> {
+        let count = raw.read_u32::<NetworkEndian>()?;
+        let mut lexemes = vec![];
+        for _ in 0..count {
+            let word = read_cstring(&mut raw)?;
+            let npos = raw.read_u16::<NetworkEndian>()?;
+            let mut positions = vec![];
+            for _ in 0..npos {
+                let pos = raw.read_u16::<NetworkEndian>()?;
+                let position = pos & MAX_POSITION;
+                if position == 0 {
+                    return Err("invalid position in external \"tsvector\" value".into());
+                }
+                let weight = TsWeight::from_index(u8::try_from(pos >> 14)?)
+                    .expect("two bits are a valid weight");
+                positions.push(TsPosition { position, weight });
+            }
+            lexemes.push(TsLexeme { word, positions });
+        }
+        Ok(TsVector(ReprTsVector::new(lexemes)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::TS_VECTOR)
+    }
+}
+
+/// A wrapper for the `repr` crate's
+/// [`TsQuery`](mz_repr::adt::text_search::TsQuery) type that can be
+/// serialized to and deserialized from the PostgreSQL binary format.
+#[derive(Debug, Clone)]
+pub struct TsQuery(pub ReprTsQuery);
+
+impl fmt::Display for TsQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ToSql for TsQuery {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + 'static + Send + Sync>> {
+        // Postgres represents a query as the number of items followed by the
+        // items in prefix order, where each operator is followed by its right
+        // operand and then its left operand.
+        //
+        // Postgres implementation: https://github.com/postgres/postgres/blob/REL_16_0/src/backend/utils/adt/tsquery.c#L1154-L1196
+        fn count(node: &TsQueryNode) -> u32 {
+            match node {
+                TsQueryNode::Operand(_) => 1,
+                TsQueryNode::Not(node) => 1 + count(node),
+                TsQueryNode::And(left, right)
+                | TsQueryNode::Or(left, right)
+                | TsQueryNode::Phrase(left, right, _) => 1 + count(left) + count(right),
+            }
+        }
+
+        fn put_node(
+            out: &mut BytesMut,
+            node: &TsQueryNode,
+        ) -> Result<(), Box<dyn Error + 'static + Send + Sync>> {
+            match node {
+                TsQueryNode::Operand(operand) => {
+                    out.put_u8(QI_VAL);
+                    out.put_u8(operand.weights);
+                    out.put_u8(u8::from(operand.prefix));
+                    put_cstring(out, &operand.word)?;
+                }
+                TsQueryNode::Not(node) => {
+                    out.put_u8(QI_OPR);
+                    out.put_u8(OP_NOT);
+                    put_node(out, node)?;
+                }
+                TsQueryNode::And(left, right)
+                | TsQueryNode::Or(left, right)
+                | TsQueryNode::Phrase(left, right, _) => {
+                    out.put_u8(QI_OPR);
+                    match node {
+                        TsQueryNode::And(..) => out.put_u8(OP_AND),
+                        TsQueryNode::Or(..) => out.put_u8(OP_OR),
+                        TsQueryNode::Phrase(_, _, distance) => {
+                            out.put_u8(OP_PHRASE);
+                            out.put_u16(*distance);
+                        }
+                        _ => unreachable!(),
+                    }
+                    put_node(out, right)?;
+                    put_node(out, left)?;
+                }
+            }
+            Ok(())
+        }
+
+        match &self.0.root {
+            None => out.put_u32(0),
+            Some(root) => {
+                out.put_u32(count(root));
+                put_node(out, root)?;
+            }
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::TSQUERY)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for TsQuery {
+    fn from_sql(_: &Type, mut raw: &'a [u8]) -> Result<TsQuery, Box<dyn Error + Sync + Send>> {
+        fn read_node(raw: &mut &[u8]) -> Result<TsQueryNode, Box<dyn Error + Sync + Send>> {
+            match raw.read_u8()? {
+                QI_VAL => {
+                    let weights = raw.read_u8()?;
+                    let prefix = raw.read_u8()? != 0;
+                    let word = read_cstring(raw)?;
+                    Ok(TsQueryNode::Operand(TsOperand {
+                        word,
+                        prefix,
+                        weights: weights & 0b1111,
+                    }))
+                }
+                QI_OPR => match raw.read_u8()? {
+                    OP_NOT => Ok(TsQueryNode::Not(Box::new(read_node(raw)?))),
+                    oper @ (OP_AND | OP_OR | OP_PHRASE) => {
+                        let distance = if oper == OP_PHRASE {
+                            let distance = raw.read_u16::<NetworkEndian>()?;
+                            if distance > MAX_PHRASE_DISTANCE {
+                                return Err(
+                                    "invalid phrase distance in external \"tsquery\" value".into(),
+                                );
+                            }
+                            Some(distance)
+                        } else {
+                            None
+                        };
+                        let right = Box::new(read_node(raw)?);
+                        let left = Box::new(read_node(raw)?);
+                        Ok(match (oper, distance) {
+                            (OP_AND, _) => TsQueryNode::And(left, right),
+                            (OP_OR, _) => TsQueryNode::Or(left, right),
+                            (_, Some(distance)) => TsQueryNode::Phrase(left, right, distance),
+                            _ => unreachable!(),
+                        })
+                    }
+                    _ => Err("invalid operator in external \"tsquery\" value".into()),
+                },
+                _ => Err("invalid item type in external \"tsquery\" value".into()),
+            }
+        }
+
+        let count = raw.read_u32::<NetworkEndian>()?;
+        let root = if count == 0 {
+            None
+        } else {
+            Some(read_node(&mut raw)?)
+        };
+        if !raw.is_empty() {
+            return Err("invalid external \"tsquery\" value".into());
+        }
+        Ok(TsQuery(ReprTsQuery { root }))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::TSQUERY)
+    }
+}
+
+fn put_cstring(out: &mut BytesMut, s: &str) -> Result<(), Box<dyn Error + 'static + Send + Sync>> {
+    if s.contains('\0') {
+        return Err("null character not permitted".into());
+    }
+    out.put_slice(s.as_bytes());
+    out.put_u8(0);
+    Ok(())
+}
+
+fn read_cstring(raw: &mut &[u8]) -> Result<String, Box<dyn Error + Sync + Send>> {
+    let len = raw
+        .iter()
+        .position(|b| *b == 0)
+        .ok_or("missing null terminator")?;
+    let s = std::str::from_utf8(&raw[..len])?.to_owned();
+    *raw = &raw[len + 1..];
+    Ok(s)
+}
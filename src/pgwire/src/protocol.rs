@@ -894,10 +894,26 @@ where
         if aborted_txn && !is_txn_exit_stmt(stmt.stmt()) {
             return self.aborted_txn_error().await;
         }
+        // Decoding enum values requires the labels of their types, which live
+        // in the catalog.
+        let catalog = if param_types.iter().any(|typ| typ.contains_enum()) {
+            Some(self.adapter_client.catalog_snapshot().await)
+        } else {
+            None
+        };
+        let stmt = self
+            .adapter_client
+            .session()
+            .get_prepared_statement_unverified(&statement_name)
+            .expect("must exist");
+        let param_types = &stmt.desc().param_types;
         let buf = RowArena::new();
         let mut params = vec![];
         for (raw_param, mz_typ, format) in izip!(raw_params, param_types, param_formats) {
-            let pg_typ = mz_pgrepr::Type::from(mz_typ);
+            let pg_typ = match &catalog {
+                Some(catalog) => catalog.resolve_pg_type(mz_typ),
+                None => mz_pgrepr::Type::from(mz_typ),
+            };
             let datum = match raw_param {
                 None => Datum::Null,
                 Some(bytes) => match mz_pgrepr::Value::decode(format, &pg_typ, &bytes) {
@@ -1717,9 +1733,11 @@ where
             | ExecuteResponse::CreatedSource { .. }
             | ExecuteResponse::CreatedTable { .. }
             | ExecuteResponse::CreatedType
+            | ExecuteResponse::CreatedFunction
             | ExecuteResponse::CreatedView { .. }
             | ExecuteResponse::CreatedViews { .. }
             | ExecuteResponse::Comment
+            | ExecuteResponse::Copied(..)
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::Deleted(..)
             | ExecuteResponse::DiscardedAll
@@ -1729,6 +1747,7 @@ where
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
+            | ExecuteResponse::Merged(..)
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
@@ -2167,11 +2186,24 @@ where
             }
         }
 
+        // Decoding enum values requires the labels of their types, which live
+        // in the catalog.
+        let catalog = if typ
+            .column_types
+            .iter()
+            .any(|x| x.scalar_type.contains_enum())
+        {
+            Some(self.adapter_client.catalog_snapshot().await)
+        } else {
+            None
+        };
         let column_types = typ
             .column_types
             .iter()
-            .map(|x| &x.scalar_type)
-            .map(mz_pgrepr::Type::from)
+            .map(|x| match &catalog {
+                Some(catalog) => catalog.resolve_pg_type(&x.scalar_type),
+                None => mz_pgrepr::Type::from(&x.scalar_type),
+            })
             .collect::<Vec<mz_pgrepr::Type>>();
 
         let rows = match mz_pgcopy::decode_copy_format(&data, &column_types, params) {
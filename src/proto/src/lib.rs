@@ -48,6 +48,8 @@ pub enum TryFromProtoError {
     /// A JSON deserialization failed.
     /// TODO: Remove this when we have complete coverage for source and sink structs.
     DeserializationError(serde_json::Error),
+    /// A Protobuf message that is stored encoded in a field failed to decode.
+    DecodeError(prost::DecodeError),
     /// Indicates an `Option<U>` field in the `Proto$T` that should be set,
     /// but for some reason it is not. In practice this should never occur.
     MissingField(String),
@@ -73,6 +75,8 @@ pub enum TryFromProtoError {
     InvalidBitFlags(String),
     /// Failed to deserialize a LIKE/ILIKE pattern.
     LikePatternDeserializationError(String),
+    /// Failed to parse a jsonpath.
+    JsonPathDeserializationError(String),
 }
 
 impl TryFromProtoError {
@@ -120,6 +124,12 @@ impl From<serde_json::Error> for TryFromProtoError {
     }
 }
 
+impl From<prost::DecodeError> for TryFromProtoError {
+    fn from(error: prost::DecodeError) -> Self {
+        TryFromProtoError::DecodeError(error)
+    }
+}
+
 impl From<http::uri::InvalidUri> for TryFromProtoError {
     fn from(error: http::uri::InvalidUri) -> Self {
         TryFromProtoError::InvalidUri(error)
@@ -148,6 +158,7 @@ impl std::fmt::Display for TryFromProtoError {
             DateConversionError(msg) => write!(f, "Date conversion failed: `{}`", msg),
             RegexError(error) => error.fmt(f),
             DeserializationError(error) => error.fmt(f),
+            DecodeError(error) => error.fmt(f),
             RowConversionError(msg) => write!(f, "Row packing failed: `{}`", msg),
             MissingField(field) => write!(f, "Missing value for `{}`", field),
             UnknownEnumVariant(field) => write!(f, "Unknown enum value for `{}`", field),
@@ -164,6 +175,11 @@ impl std::fmt::Display for TryFromProtoError {
                 "Protobuf deserialization failed for a LIKE/ILIKE pattern: `{}`",
                 inner_error
             ),
+            JsonPathDeserializationError(inner_error) => write!(
+                f,
+                "Protobuf deserialization failed for a jsonpath: `{}`",
+                inner_error
+            ),
         }
     }
 }
@@ -185,6 +201,7 @@ impl std::error::Error for TryFromProtoError {
             CharTryFromError(error) => Some(error),
             RegexError(error) => Some(error),
             DeserializationError(error) => Some(error),
+            DecodeError(error) => Some(error),
             DateConversionError(_) => None,
             RowConversionError(_) => None,
             MissingField(_) => None,
@@ -198,6 +215,7 @@ impl std::error::Error for TryFromProtoError {
             InvalidUrl(error) => Some(error),
             InvalidBitFlags(_) => None,
             LikePatternDeserializationError(_) => None,
+            JsonPathDeserializationError(_) => None,
         }
     }
 }
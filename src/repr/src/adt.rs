@@ -19,15 +19,21 @@
 
 pub mod array;
 pub mod char;
+pub mod collation;
 pub mod date;
 pub mod datetime;
+pub mod enum_type;
+pub mod hll;
+pub mod inet;
 pub mod interval;
 pub mod jsonb;
+pub mod jsonpath;
 pub mod mz_acl_item;
 pub mod numeric;
 pub mod pg_legacy_name;
 pub mod range;
 pub mod regex;
 pub mod system;
+pub mod text_search;
 pub mod timestamp;
 pub mod varchar;
@@ -0,0 +1,208 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! ICU collations.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str;
+
+use icu_collator::{Collator, CollatorOptions};
+use icu_locid::LanguageIdentifier;
+use mz_lowertest::MzReflect;
+use mz_proto::{RustType, TryFromProtoError};
+use proptest::prelude::{Arbitrary, BoxedStrategy, Strategy};
+use proptest::sample::select;
+use serde::{Deserialize, Serialize};
+
+/// The maximum length of the language tag of a [`Collation`]'s locale.
+const MAX_TAG_LEN: usize = 24;
+
+/// The suffix that distinguishes the names of ICU collations, as in
+/// PostgreSQL.
+const ICU_SUFFIX: &str = "-x-icu";
+
+/// An ICU collation, which orders strings according to the conventions of a
+/// locale.
+///
+/// Collations are named like in PostgreSQL, by the BCP 47 language tag of
+/// their locale followed by `-x-icu`. For example, `und-x-icu` names the
+/// collation of the root locale, and `de-DE-x-icu` names the collation of
+/// German as spoken in Germany.
+///
+/// Like PostgreSQL's collations, and unlike ICU's, collations are
+/// deterministic: strings that ICU considers equal are ordered bytewise, so
+/// that two strings compare as equal only if they are identical.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, MzReflect,
+)]
+#[serde(into = "String", try_from = "String")]
+pub struct Collation {
+    /// The canonical language tag of the locale, padded with zero bytes.
+    tag: [u8; MAX_TAG_LEN],
+}
+
+thread_local! {
+    /// The collators of the collations that this thread has compared strings
+    /// with, which are expensive to construct.
+    static COLLATORS: RefCell<BTreeMap<Collation, Collator>> = RefCell::new(BTreeMap::new());
+
+    /// The pivots of the collations that this thread has computed sort
+    /// prefixes with, in the order of each collation.
+    static PIVOTS: RefCell<BTreeMap<Collation, Vec<String>>> = RefCell::new(BTreeMap::new());
+}
+
+impl Collation {
+    /// Returns the collation with the given name, if it names an ICU
+    /// collation.
+    pub fn from_name(name: &str) -> Option<Collation> {
+        let locale = name.strip_suffix(ICU_SUFFIX)?;
+        let locale: LanguageIdentifier = locale.parse().ok()?;
+        let locale = locale.to_string();
+        if locale.len() > MAX_TAG_LEN {
+            return None;
+        }
+        let mut tag = [0; MAX_TAG_LEN];
+        tag[..locale.len()].copy_from_slice(locale.as_bytes());
+        Some(Collation { tag })
+    }
+
+    /// Returns the language tag of the collation's locale.
+    pub fn locale(&self) -> &str {
+        let len = self.tag.iter().position(|b| *b == 0).unwrap_or(MAX_TAG_LEN);
+        str::from_utf8(&self.tag[..len]).expect("language tags are ASCII")
+    }
+
+    /// Compares two strings according to the collation.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        COLLATORS.with(|collators| {
+            let mut collators = collators.borrow_mut();
+            let collator = collators.entry(*self).or_insert_with(|| {
+                let locale: LanguageIdentifier =
+                    self.locale().parse().expect("validated on construction");
+                // Locales without collation data of their own fall back to
+                // that of their parent locales, and ultimately to the root
+                // locale's.
+                Collator::try_new(&locale.into(), CollatorOptions::new())
+                    .expect("collation data is compiled in")
+            });
+            collator.compare(a, b).then_with(|| a.cmp(b))
+        })
+    }
+
+    /// Returns a prefix of the sort key of `s` under the collation: whenever a
+    /// string sorts before another, its prefix is not larger.
+    ///
+    /// ICU4X does not expose sort keys, so the prefix is the number of pivots,
+    /// the strings of one or two printable ASCII characters, that sort before
+    /// `s`.
+    pub fn sort_prefix(&self, s: &str) -> u16 {
+        PIVOTS.with(|pivots| {
+            let mut pivots = pivots.borrow_mut();
+            let pivots = pivots.entry(*self).or_insert_with(|| {
+                let chars = || (' '..='~').map(String::from);
+                let mut pivots: Vec<_> = chars()
+                    .chain(chars().flat_map(|a| chars().map(move |b| format!("{a}{b}"))))
+                    .collect();
+                pivots.sort_by(|a, b| self.compare(a, b));
+                pivots
+            });
+            let rank = pivots.partition_point(|pivot| self.compare(pivot, s) == Ordering::Less);
+            u16::try_from(rank).expect("fewer than u16::MAX pivots")
+        })
+    }
+}
+
+impl fmt::Display for Collation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.locale(), ICU_SUFFIX)
+    }
+}
+
+impl From<Collation> for String {
+    fn from(collation: Collation) -> String {
+        collation.to_string()
+    }
+}
+
+impl TryFrom<String> for Collation {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Collation, String> {
+        Collation::from_name(&name).ok_or_else(|| format!("unknown collation: {}", name))
+    }
+}
+
+impl RustType<String> for Collation {
+    fn into_proto(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_proto(proto: String) -> Result<Self, TryFromProtoError> {
+        Collation::try_from(proto).map_err(TryFromProtoError::unknown_enum_variant)
+    }
+}
+
+impl Arbitrary for Collation {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Collation>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(&["und-x-icu", "de-DE-x-icu", "en-US-x-icu", "sv-x-icu"][..])
+            .prop_map(|name| Collation::from_name(name).expect("valid collation name"))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_from_name() {
+        let collation = Collation::from_name("de-DE-x-icu").unwrap();
+        assert_eq!(collation.locale(), "de-DE");
+        assert_eq!(collation.to_string(), "de-DE-x-icu");
+        assert_eq!(Collation::from_name("und-x-icu").unwrap().locale(), "und");
+        assert_eq!(Collation::from_name("de-DE"), None);
+        assert_eq!(Collation::from_name("not a locale-x-icu"), None);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn test_sort_prefix() {
+        let collation = Collation::from_name("sv-x-icu").unwrap();
+        let mut strings = vec!["", "a", "B", "z", "å", "ö", "é", "ab", "aB", "zz", "~", "0"];
+        strings.sort_by(|a, b| collation.compare(a, b));
+        for pair in strings.windows(2) {
+            assert!(
+                collation.sort_prefix(pair[0]) <= collation.sort_prefix(pair[1]),
+                "{pair:?}"
+            );
+        }
+        assert!(collation.sort_prefix("a") < collation.sort_prefix("z"));
+    }
+
+    #[mz_ore::test]
+    fn test_compare() {
+        let collation = Collation::from_name("und-x-icu").unwrap();
+        assert_eq!(collation.compare("a", "B"), Ordering::Less);
+        assert_eq!(collation.compare("B", "c"), Ordering::Less);
+        assert_eq!(collation.compare("é", "f"), Ordering::Less);
+        assert_eq!(collation.compare("a", "a"), Ordering::Equal);
+        // Strings that ICU considers equal are ordered bytewise.
+        assert_ne!(collation.compare("e\u{301}", "é"), Ordering::Equal);
+
+        let swedish = Collation::from_name("sv-x-icu").unwrap();
+        assert_eq!(swedish.compare("ö", "z"), Ordering::Greater);
+        assert_eq!(collation.compare("ö", "z"), Ordering::Less);
+    }
+}
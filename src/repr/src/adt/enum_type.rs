@@ -0,0 +1,66 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! User-defined enumerated types.
+//!
+//! An enum type is identified by its ID, and its labels live in the catalog.
+//! A value of an enum type is a [`Datum::List`] holding the position of its
+//! label among the labels of the type, as a [`Datum::UInt32`], followed by the
+//! label itself, as a [`Datum::String`]. Labels are only ever appended to a
+//! type, so the position of a label never changes and values order by the
+//! declaration order of their labels. Values carry their label so that they
+//! can be formatted without looking up the labels of their type, which may
+//! have grown since the value was produced.
+
+use crate::{Datum, RowPacker};
+
+/// A value of an enum type.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EnumValue<S> {
+    /// The position of the label among the labels of the type.
+    pub position: u32,
+    /// The label of the value.
+    pub label: S,
+}
+
+impl<'a> EnumValue<&'a str> {
+    /// Returns the enum value stored in `datum`, or `None` if `datum` does not
+    /// have the shape of an enum value.
+    pub fn try_from_datum(datum: Datum<'a>) -> Option<Self> {
+        let Datum::List(list) = datum else {
+            return None;
+        };
+        let mut fields = list.iter();
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(Datum::UInt32(position)), Some(Datum::String(label)), None) => {
+                Some(EnumValue { position, label })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the enum value stored in `datum`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `datum` is not an enum value.
+    pub fn from_datum(datum: Datum<'a>) -> Self {
+        Self::try_from_datum(datum).unwrap_or_else(|| panic!("{datum:?} is not an enum value"))
+    }
+}
+
+impl<S: AsRef<str>> EnumValue<S> {
+    /// Pushes this value onto `packer`.
+    pub fn push(&self, packer: &mut RowPacker) {
+        packer.push_list([
+            Datum::UInt32(self.position),
+            Datum::String(self.label.as_ref()),
+        ]);
+    }
+}
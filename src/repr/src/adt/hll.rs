@@ -0,0 +1,312 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! HyperLogLog sketches for approximate distinct counting.
+//!
+//! A sketch is stored as a [`Datum::Bytes`](crate::Datum::Bytes) containing
+//! its canonical binary encoding. The encoding is a function of the sketch's
+//! registers alone, so sketches that have observed the same set of hashes are
+//! equal, and merging a sketch into itself leaves it unchanged.
+//!
+//! The encoding begins with a header of two bytes: the sketch's precision and
+//! the layout of the registers that follow. Sketches with few non-zero
+//! registers use a sparse layout of `(index, rank)` entries, each a big-endian
+//! `u16` index followed by a `u8` rank, in increasing order of index. All
+//! other sketches use a dense layout of one byte per register.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error};
+
+use crate::strconv;
+
+/// The number of bits of a hash that select a register.
+///
+/// A precision of 12 yields 4096 registers, for a dense sketch of 4 KiB and a
+/// standard error of roughly 1.6%.
+pub const PRECISION: u8 = 12;
+
+/// The number of registers in a sketch.
+pub const NUM_REGISTERS: usize = 1 << PRECISION;
+
+/// The largest rank that a register can hold.
+const MAX_RANK: u8 = 64 - PRECISION + 1;
+
+const HEADER_LEN: usize = 2;
+const SPARSE_ENTRY_LEN: usize = 3;
+const SPARSE_LAYOUT: u8 = 1;
+const DENSE_LAYOUT: u8 = 2;
+
+/// A HyperLogLog sketch of a multiset of 64-bit hashes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hll {
+    registers: Vec<u8>,
+}
+
+impl Default for Hll {
+    fn default() -> Self {
+        Hll {
+            registers: vec![0; NUM_REGISTERS],
+        }
+    }
+}
+
+impl Hll {
+    /// Returns a sketch that has observed only `hash`.
+    pub fn from_hash(hash: u64) -> Hll {
+        let mut hll = Hll::default();
+        hll.insert_hash(hash);
+        hll
+    }
+
+    /// Records the observation of `hash`.
+    ///
+    /// The top [`PRECISION`] bits of the hash select a register, which keeps
+    /// the largest rank, i.e. one more than the number of leading zeros, of
+    /// the remaining bits it has observed.
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = usize::try_from(hash >> (64 - PRECISION)).expect("fits in usize");
+        // Setting the lowest bit bounds the rank by `MAX_RANK`.
+        let rest = (hash << PRECISION) | 1;
+        let rank = u8::try_from(rest.leading_zeros() + 1).expect("at most 64");
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank);
+    }
+
+    /// Merges `other` into this sketch, so that it reflects the union of the
+    /// hashes observed by both.
+    pub fn merge(&mut self, other: &Hll) {
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    /// Returns the index and rank of each non-zero register, in increasing
+    /// order of index.
+    pub fn ranks(&self) -> impl Iterator<Item = (usize, u8)> + '_ {
+        self.registers
+            .iter()
+            .enumerate()
+            .filter(|(_index, rank)| **rank != 0)
+            .map(|(index, rank)| (index, *rank))
+    }
+
+    /// Returns the sketch whose registers hold the largest of the given ranks
+    /// for each index, and zero for indexes that are absent.
+    ///
+    /// Panics if an index or rank is out of range.
+    pub fn from_ranks<I>(ranks: I) -> Hll
+    where
+        I: IntoIterator<Item = (usize, u8)>,
+    {
+        let mut hll = Hll::default();
+        for (index, rank) in ranks {
+            assert!(rank <= MAX_RANK, "register rank {rank} is out of range");
+            let register = &mut hll.registers[index];
+            *register = (*register).max(rank);
+        }
+        hll
+    }
+
+    /// Reports whether the sketch has not observed any hashes.
+    pub fn is_empty(&self) -> bool {
+        self.registers.iter().all(|r| *r == 0)
+    }
+
+    /// Estimates the number of distinct hashes that the sketch has observed.
+    pub fn estimate(&self) -> f64 {
+        let m = f64::from(u32::try_from(NUM_REGISTERS).expect("fits in u32"));
+        let mut sum = 0.0;
+        let mut zeros = 0u32;
+        for rank in &self.registers {
+            sum += 2f64.powi(-i32::from(*rank));
+            if *rank == 0 {
+                zeros += 1;
+            }
+        }
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let raw = alpha * m * m / sum;
+        // The raw estimate is biased for small cardinalities, which linear
+        // counting of the empty registers estimates more accurately. With
+        // 64-bit hashes, no correction is needed for large cardinalities.
+        if raw <= 2.5 * m && zeros > 0 {
+            m * (m / f64::from(zeros)).ln()
+        } else {
+            raw
+        }
+    }
+
+    /// Returns the canonical binary encoding of the sketch.
+    pub fn encode(&self) -> Vec<u8> {
+        let non_zero = self.registers.iter().filter(|r| **r != 0).count();
+        if non_zero * SPARSE_ENTRY_LEN < NUM_REGISTERS {
+            let mut buf = Vec::with_capacity(HEADER_LEN + non_zero * SPARSE_ENTRY_LEN);
+            buf.extend([PRECISION, SPARSE_LAYOUT]);
+            for (index, rank) in self.registers.iter().enumerate() {
+                if *rank != 0 {
+                    let index = u16::try_from(index).expect("fits in u16");
+                    buf.extend(index.to_be_bytes());
+                    buf.push(*rank);
+                }
+            }
+            buf
+        } else {
+            let mut buf = Vec::with_capacity(HEADER_LEN + NUM_REGISTERS);
+            buf.extend([PRECISION, DENSE_LAYOUT]);
+            buf.extend(&self.registers);
+            buf
+        }
+    }
+
+    /// Decodes a sketch from its binary encoding, rejecting encodings that are
+    /// malformed or not canonical.
+    pub fn decode(buf: &[u8]) -> Result<Hll, Error> {
+        if buf.len() < HEADER_LEN {
+            bail!("sketch is too short");
+        }
+        let (precision, layout, body) = (buf[0], buf[1], &buf[HEADER_LEN..]);
+        if precision != PRECISION {
+            bail!("unsupported precision {precision}");
+        }
+        let check_rank = |rank: u8| {
+            if rank > MAX_RANK {
+                Err(anyhow!("register rank {rank} is out of range"))
+            } else {
+                Ok(rank)
+            }
+        };
+        let mut hll = Hll::default();
+        match layout {
+            SPARSE_LAYOUT => {
+                if body.len() % SPARSE_ENTRY_LEN != 0 {
+                    bail!("sparse sketch has a truncated entry");
+                }
+                if body.len() >= NUM_REGISTERS {
+                    bail!("sparse sketch has too many entries");
+                }
+                let mut prev = None;
+                for entry in body.chunks_exact(SPARSE_ENTRY_LEN) {
+                    let index = usize::from(u16::from_be_bytes([entry[0], entry[1]]));
+                    if index >= NUM_REGISTERS || prev.map_or(false, |prev| index <= prev) {
+                        bail!("sparse sketch entries are out of order");
+                    }
+                    if entry[2] == 0 {
+                        bail!("sparse sketch has an empty entry");
+                    }
+                    hll.registers[index] = check_rank(entry[2])?;
+                    prev = Some(index);
+                }
+            }
+            DENSE_LAYOUT => {
+                if body.len() != NUM_REGISTERS {
+                    bail!("dense sketch has {} registers", body.len());
+                }
+                for (register, rank) in hll.registers.iter_mut().zip(body) {
+                    *register = check_rank(*rank)?;
+                }
+                let non_zero = hll.registers.iter().filter(|r| **r != 0).count();
+                if non_zero * SPARSE_ENTRY_LEN < NUM_REGISTERS {
+                    bail!("dense sketch has too few registers set");
+                }
+            }
+            layout => bail!("unknown layout {layout}"),
+        }
+        Ok(hll)
+    }
+}
+
+/// Displays the sketch's binary encoding in the hex format of `bytea`.
+impl fmt::Display for Hll {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\\x{}", hex::encode(self.encode()))
+    }
+}
+
+impl FromStr for Hll {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Hll, Error> {
+        let Some(hex) = s.trim().strip_prefix(r"\x") else {
+            bail!("sketch must be hex encoded");
+        };
+        let buf = strconv::parse_bytes_hex(hex)?;
+        Hll::decode(&buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_encoding_round_trips() {
+        let mut hll = Hll::default();
+        assert_eq!(Hll::decode(&hll.encode()).unwrap(), hll);
+        assert_eq!(hll.encode(), vec![PRECISION, SPARSE_LAYOUT]);
+
+        for i in 0..100_000u64 {
+            hll.insert_hash(i.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+            if i % 997 == 0 {
+                let encoded = hll.encode();
+                assert_eq!(Hll::decode(&encoded).unwrap(), hll);
+                assert_eq!(hll.to_string().parse::<Hll>().unwrap(), hll);
+            }
+        }
+        assert_eq!(hll.encode()[1], DENSE_LAYOUT);
+    }
+
+    #[mz_ore::test]
+    fn test_decode_rejects_non_canonical() {
+        // Out of order sparse entries.
+        assert!(Hll::decode(&[PRECISION, SPARSE_LAYOUT, 0, 2, 1, 0, 1, 1]).is_err());
+        // Empty sparse entry.
+        assert!(Hll::decode(&[PRECISION, SPARSE_LAYOUT, 0, 1, 0]).is_err());
+        // Dense sketch that should be sparse.
+        let mut dense = vec![PRECISION, DENSE_LAYOUT];
+        dense.extend(vec![0; NUM_REGISTERS]);
+        assert!(Hll::decode(&dense).is_err());
+        // Unsupported precision.
+        assert!(Hll::decode(&[PRECISION + 1, SPARSE_LAYOUT]).is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_merge_and_estimate() {
+        let mut a = Hll::default();
+        let mut b = Hll::default();
+        for i in 0..20_000u64 {
+            let hash = i.wrapping_mul(0x9e37_79b9_7f4a_7c15).rotate_left(17);
+            if i < 15_000 {
+                a.insert_hash(hash);
+            }
+            if i >= 5_000 {
+                b.insert_hash(hash);
+            }
+        }
+        let mut union = a.clone();
+        union.merge(&b);
+        let estimate = union.estimate();
+        assert!((estimate - 20_000.0).abs() < 20_000.0 * 0.05, "{estimate}");
+
+        // Merging is idempotent and commutative.
+        let mut again = union.clone();
+        again.merge(&a);
+        assert_eq!(again, union);
+        b.merge(&a);
+        assert_eq!(b, union);
+
+        // Sketches are determined by their non-zero registers.
+        assert_eq!(Hll::from_ranks(union.ranks()), union);
+        assert_eq!(Hll::from_ranks(a.ranks().chain(b.ranks())), union);
+        assert_eq!(Hll::from_ranks([]), Hll::default());
+
+        assert_eq!(Hll::default().estimate(), 0.0);
+        assert_eq!(Hll::from_hash(42).estimate().round(), 1.0);
+    }
+}
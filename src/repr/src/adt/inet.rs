@@ -0,0 +1,375 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Network address types.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error};
+use mz_proto::{RustType, TryFromProtoError};
+use serde::{Deserialize, Serialize};
+
+include!(concat!(env!("OUT_DIR"), "/mz_repr.adt.inet.rs"));
+
+/// An IPv4 or IPv6 host address with the length of the prefix that identifies
+/// its network.
+///
+/// This is the representation of both the PostgreSQL `inet` type, which may
+/// have bits set to the right of its prefix, and the `cidr` type, which may
+/// not.
+///
+/// See: <https://www.postgresql.org/docs/current/datatype-net-types.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Inet {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl Inet {
+    /// Constructs a new address with the given prefix length, which must not
+    /// exceed the number of bits in the address.
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<Inet, Error> {
+        let max_prefix_len = max_prefix_len(&addr);
+        if prefix_len > max_prefix_len {
+            bail!("invalid mask length: {prefix_len}");
+        }
+        Ok(Inet { addr, prefix_len })
+    }
+
+    /// Returns the address, including any bits to the right of the prefix.
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the length of the network prefix in bits.
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the number of bits in the address, which is the maximum prefix
+    /// length.
+    pub fn max_prefix_len(&self) -> u8 {
+        max_prefix_len(&self.addr)
+    }
+
+    /// Returns the address family: 4 for IPv4 and 6 for IPv6.
+    pub fn family(&self) -> i32 {
+        match self.addr {
+            IpAddr::V4(_) => 4,
+            IpAddr::V6(_) => 6,
+        }
+    }
+
+    /// Reports whether no bits are set to the right of the prefix, i.e.
+    /// whether this is a valid `cidr` value.
+    pub fn is_network(&self) -> bool {
+        self.bits() == self.masked_bits(self.prefix_len)
+    }
+
+    /// Returns the network of the address, i.e. the address with all bits to
+    /// the right of the prefix cleared.
+    pub fn network(&self) -> Inet {
+        Inet {
+            addr: self.addr_from_bits(self.masked_bits(self.prefix_len)),
+            prefix_len: self.prefix_len,
+        }
+    }
+
+    /// Returns the address with its prefix length set to `prefix_len`, or to
+    /// the number of bits in the address if `prefix_len` is -1.
+    pub fn with_prefix_len(&self, prefix_len: i32) -> Result<Inet, Error> {
+        let prefix_len = match prefix_len {
+            -1 => self.max_prefix_len(),
+            _ => u8::try_from(prefix_len)
+                .ok()
+                .filter(|len| *len <= self.max_prefix_len())
+                .ok_or_else(|| anyhow!("invalid mask length: {prefix_len}"))?,
+        };
+        Ok(Inet {
+            addr: self.addr,
+            prefix_len,
+        })
+    }
+
+    /// Reports whether the network of `self` strictly contains the network of
+    /// `other`, i.e. the `>>` operator.
+    pub fn contains(&self, other: &Inet) -> bool {
+        self.prefix_len < other.prefix_len && self.contains_or_equals(other)
+    }
+
+    /// Reports whether the network of `self` contains or is equal to the
+    /// network of `other`, i.e. the `>>=` operator.
+    pub fn contains_or_equals(&self, other: &Inet) -> bool {
+        self.family() == other.family()
+            && self.prefix_len <= other.prefix_len
+            && self.masked_bits(self.prefix_len) == other.masked_bits(self.prefix_len)
+    }
+
+    /// Reports whether either network contains or is equal to the other,
+    /// i.e. the `&&` operator.
+    pub fn overlaps(&self, other: &Inet) -> bool {
+        self.contains_or_equals(other) || other.contains_or_equals(self)
+    }
+
+    /// Returns the address as an integer, in host byte order.
+    fn bits(&self) -> u128 {
+        match self.addr {
+            IpAddr::V4(addr) => u128::from(u32::from(addr)),
+            IpAddr::V6(addr) => u128::from(addr),
+        }
+    }
+
+    /// Returns the address as an integer with all but its leftmost
+    /// `prefix_len` bits cleared.
+    fn masked_bits(&self, prefix_len: u8) -> u128 {
+        let width = u32::from(self.max_prefix_len());
+        let host_bits = width - u32::from(prefix_len);
+        if host_bits == 128 {
+            0
+        } else {
+            (self.bits() >> host_bits) << host_bits
+        }
+    }
+
+    fn addr_from_bits(&self, bits: u128) -> IpAddr {
+        match self.addr {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(
+                u32::try_from(bits).expect("IPv4 addresses have 32 bits"),
+            )),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(bits)),
+        }
+    }
+}
+
+/// A network address, i.e. an [`Inet`] without any bits set to the right of
+/// its prefix.
+///
+/// This is the native Rust type of the PostgreSQL `cidr` type, whose values
+/// share their representation with `inet` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Cidr(pub Inet);
+
+/// Formats the network like the PostgreSQL `cidr` type, which, unlike `inet`,
+/// always includes the prefix length.
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.0.addr, self.0.prefix_len)
+    }
+}
+
+fn max_prefix_len(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+// The binary encoding used to pack an `Inet` into a row. IPv4 addresses are
+// padded to the size of IPv6 addresses so that the encoding has a fixed size.
+impl Inet {
+    pub fn encode_binary(&self) -> [u8; Self::binary_size()] {
+        let mut res = [0; Self::binary_size()];
+        match self.addr {
+            IpAddr::V4(addr) => {
+                res[0] = 4;
+                res[1..5].copy_from_slice(&addr.octets());
+            }
+            IpAddr::V6(addr) => {
+                res[0] = 6;
+                res[1..17].copy_from_slice(&addr.octets());
+            }
+        }
+        res[17] = self.prefix_len;
+        res
+    }
+
+    pub fn decode_binary(raw: &[u8]) -> Result<Inet, Error> {
+        if raw.len() != Inet::binary_size() {
+            bail!(
+                "invalid binary size, expecting {}, found {}",
+                Inet::binary_size(),
+                raw.len()
+            );
+        }
+        let addr = match raw[0] {
+            4 => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(&raw[1..5])?)),
+            6 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(&raw[1..17])?)),
+            family => bail!("invalid address family: {family}"),
+        };
+        Inet::new(addr, raw[17])
+    }
+
+    pub const fn binary_size() -> usize {
+        1 + 16 + 1
+    }
+}
+
+/// Orders addresses like PostgreSQL: IPv4 addresses sort before IPv6
+/// addresses, then addresses sort by their common prefix, then by prefix
+/// length, and finally by the remaining bits of the address.
+///
+/// See: <https://github.com/postgres/postgres/blob/REL_16_0/src/backend/utils/adt/network.c#L386-L407>
+impl Ord for Inet {
+    fn cmp(&self, other: &Inet) -> Ordering {
+        self.family()
+            .cmp(&other.family())
+            .then_with(|| {
+                let common = self.prefix_len.min(other.prefix_len);
+                self.masked_bits(common).cmp(&other.masked_bits(common))
+            })
+            .then_with(|| self.prefix_len.cmp(&other.prefix_len))
+            .then_with(|| self.bits().cmp(&other.bits()))
+    }
+}
+
+impl PartialOrd for Inet {
+    fn partial_cmp(&self, other: &Inet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Inet {
+    type Err = Error;
+
+    /// Parses an address of the form `address[/prefix_len]`. If the prefix
+    /// length is omitted, it is the number of bits in the address.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (s, None),
+        };
+        let addr: IpAddr = addr.parse()?;
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len
+                .parse()
+                .map_err(|_| anyhow!("invalid mask length: {prefix_len}"))?,
+            None => max_prefix_len(&addr),
+        };
+        Inet::new(addr, prefix_len)
+    }
+}
+
+/// Formats the address like the PostgreSQL `inet` type, which omits the prefix
+/// length if it is the number of bits in the address.
+impl fmt::Display for Inet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.addr)?;
+        if self.prefix_len != self.max_prefix_len() {
+            write!(f, "/{}", self.prefix_len)?;
+        }
+        Ok(())
+    }
+}
+
+impl RustType<ProtoInet> for Inet {
+    fn into_proto(&self) -> ProtoInet {
+        let addr = match self.addr {
+            IpAddr::V4(addr) => addr.octets().to_vec(),
+            IpAddr::V6(addr) => addr.octets().to_vec(),
+        };
+        ProtoInet {
+            addr,
+            prefix_len: u32::from(self.prefix_len),
+        }
+    }
+
+    fn from_proto(proto: ProtoInet) -> Result<Self, TryFromProtoError> {
+        let addr = if let Ok(octets) = <[u8; 4]>::try_from(proto.addr.as_slice()) {
+            IpAddr::V4(Ipv4Addr::from(octets))
+        } else if let Ok(octets) = <[u8; 16]>::try_from(proto.addr.as_slice()) {
+            IpAddr::V6(Ipv6Addr::from(octets))
+        } else {
+            return Err(TryFromProtoError::RowConversionError(format!(
+                "invalid ProtoInet::addr length: {}",
+                proto.addr.len()
+            )));
+        };
+        let prefix_len = u8::try_from(proto.prefix_len)?;
+        Inet::new(addr, prefix_len)
+            .map_err(|e| TryFromProtoError::RowConversionError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inet(s: &str) -> Inet {
+        s.parse().unwrap()
+    }
+
+    #[mz_ore::test]
+    fn test_parse_display() {
+        for (input, output) in [
+            ("192.168.1.5", "192.168.1.5"),
+            ("192.168.1.5/32", "192.168.1.5"),
+            ("192.168.1.5/24", "192.168.1.5/24"),
+            ("::1", "::1"),
+            ("2001:db8::/32", "2001:db8::/32"),
+        ] {
+            assert_eq!(inet(input).to_string(), output);
+        }
+        for input in ["", "192.168.1", "192.168.1.5/33", "192.168.1.5/", "::1/129"] {
+            assert!(input.parse::<Inet>().is_err(), "{input} parsed");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_network() {
+        assert_eq!(inet("192.168.1.5/24").network(), inet("192.168.1.0/24"));
+        assert_eq!(inet("192.168.1.5/0").network(), inet("0.0.0.0/0"));
+        assert_eq!(inet("2001:db8::1/32").network(), inet("2001:db8::/32"));
+        assert!(inet("192.168.1.0/24").is_network());
+        assert!(!inet("192.168.1.5/24").is_network());
+    }
+
+    #[mz_ore::test]
+    fn test_containment() {
+        let net = inet("192.168.1.0/24");
+        assert!(net.contains(&inet("192.168.1.5")));
+        assert!(!net.contains(&net));
+        assert!(net.contains_or_equals(&net));
+        assert!(!net.contains(&inet("192.168.2.5")));
+        assert!(!net.contains(&inet("::1")));
+        assert!(!inet("192.168.1.5").contains(&net));
+        assert!(net.overlaps(&inet("192.168.0.0/16")));
+        assert!(!net.overlaps(&inet("192.168.2.0/24")));
+    }
+
+    #[mz_ore::test]
+    fn test_ord() {
+        // Ordering matches PostgreSQL's.
+        let sorted = [
+            "10.0.0.0/8",
+            "10.2.0.0/8",
+            "10.1.0.0/16",
+            "10.1.0.0",
+            "192.168.1.0/24",
+            "192.168.1.5/24",
+            "192.168.1.5",
+            "::/0",
+            "::1",
+        ];
+        for pair in sorted.windows(2) {
+            assert!(inet(pair[0]) < inet(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_binary_roundtrip() {
+        for input in ["0.0.0.0/0", "192.168.1.5/24", "::1", "2001:db8::/32"] {
+            let inet = inet(input);
+            assert_eq!(Inet::decode_binary(&inet.encode_binary()).unwrap(), inet);
+            assert_eq!(Inet::from_proto(inet.into_proto()).unwrap(), inet);
+        }
+    }
+}
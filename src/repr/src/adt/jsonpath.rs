@@ -0,0 +1,1345 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The SQL/JSON path language.
+//!
+//! A [`JsonPath`] selects items from a JSON document, e.g.
+//! `$.events[*] ? (@.kind == "click").target`. This module contains the
+//! syntax tree of paths, their parser, and their canonical text
+//! representation, which matches PostgreSQL's. Paths are stored as
+//! [`Datum::String`](crate::Datum::String)s containing that representation.
+//!
+//! Paths are evaluated against `jsonb` values by the `jsonb_path_*` functions
+//! in `mz_expr`.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error};
+use mz_lowertest::MzReflect;
+use mz_proto::{RustType, TryFromProtoError};
+use proptest::sample::select;
+use proptest::strategy::Strategy;
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::adt::numeric::{self, Numeric};
+use crate::adt::regex::Regex;
+
+include!(concat!(env!("OUT_DIR"), "/mz_repr.adt.jsonpath.rs"));
+
+/// The bound of a `.**{first to last}` accessor that is written as `last`,
+/// i.e. the deepest level of the document.
+pub const LAST_LEVEL: u32 = u32::MAX;
+
+/// A parsed SQL/JSON path.
+///
+/// Two paths are equal iff their canonical text representations are equal.
+/// The [`PartialOrd`], [`Ord`], [`Hash`], and serialization implementations
+/// are similarly based upon the text representation.
+#[derive(Debug, Clone, MzReflect)]
+pub struct JsonPath {
+    /// Whether the path is evaluated in strict mode rather than lax mode.
+    ///
+    /// In lax mode, arrays are automatically unwrapped and wrapped to suit
+    /// the accessors applied to them, and structural errors, like accessing a
+    /// key that does not exist, produce no items rather than an error.
+    pub strict: bool,
+    /// The expression to evaluate.
+    #[mzreflect(ignore)]
+    pub expr: JsonPathExpr,
+}
+
+impl PartialEq for JsonPath {
+    fn eq(&self, other: &JsonPath) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for JsonPath {}
+
+impl PartialOrd for JsonPath {
+    fn partial_cmp(&self, other: &JsonPath) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonPath {
+    fn cmp(&self, other: &JsonPath) -> Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+impl Hash for JsonPath {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.to_string().hash(hasher);
+    }
+}
+
+impl Serialize for JsonPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let path = String::deserialize(deserializer)?;
+        path.parse().map_err(|err| {
+            D::Error::custom(format!(
+                "Unable to recreate jsonpath during deserialization: {}",
+                err
+            ))
+        })
+    }
+}
+
+impl RustType<ProtoJsonPath> for JsonPath {
+    fn into_proto(&self) -> ProtoJsonPath {
+        ProtoJsonPath {
+            path: self.to_string(),
+        }
+    }
+
+    fn from_proto(proto: ProtoJsonPath) -> Result<Self, TryFromProtoError> {
+        proto
+            .path
+            .parse()
+            .map_err(|err: Error| TryFromProtoError::JsonPathDeserializationError(err.to_string()))
+    }
+}
+
+/// An expression of a [`JsonPath`], which evaluates to a sequence of items.
+///
+/// Predicates, like comparisons, evaluate to a single `true`, `false`, or
+/// `null` item, the last of which represents an unknown result.
+#[derive(Debug, Clone)]
+pub enum JsonPathExpr {
+    /// `$`, the document being queried.
+    Root,
+    /// `@`, the item being filtered.
+    Current,
+    /// `$name`, a variable passed alongside the path.
+    Variable(String),
+    /// `last`, the last subscript of the array being accessed.
+    Last,
+    /// A literal scalar value.
+    Literal(JsonPathLiteral),
+    /// An accessor applied to each item of an expression, e.g. `$.a`.
+    Accessor(Box<JsonPathExpr>, JsonPathAccessor),
+    /// `+a` or `-a`.
+    Unary(JsonPathUnaryOp, Box<JsonPathExpr>),
+    /// An arithmetic operation, e.g. `a + b`.
+    Arithmetic(JsonPathArithmeticOp, Box<JsonPathExpr>, Box<JsonPathExpr>),
+    /// A comparison, e.g. `a == b`.
+    Comparison(JsonPathComparisonOp, Box<JsonPathExpr>, Box<JsonPathExpr>),
+    /// `a && b`.
+    And(Box<JsonPathExpr>, Box<JsonPathExpr>),
+    /// `a || b`.
+    Or(Box<JsonPathExpr>, Box<JsonPathExpr>),
+    /// `!(a)`.
+    Not(Box<JsonPathExpr>),
+    /// `(a) is unknown`.
+    IsUnknown(Box<JsonPathExpr>),
+    /// `exists (a)`.
+    Exists(Box<JsonPathExpr>),
+    /// `a like_regex "pattern" flag "flags"`.
+    LikeRegex {
+        expr: Box<JsonPathExpr>,
+        pattern: String,
+        /// The flags in canonical order.
+        flags: String,
+        /// The compiled `pattern`, adjusted for `flags`.
+        regex: Regex,
+    },
+    /// `a starts with b`.
+    StartsWith(Box<JsonPathExpr>, Box<JsonPathExpr>),
+}
+
+impl JsonPathExpr {
+    /// Reports whether the expression is a predicate.
+    pub fn is_predicate(&self) -> bool {
+        matches!(
+            self,
+            JsonPathExpr::Comparison(..)
+                | JsonPathExpr::And(..)
+                | JsonPathExpr::Or(..)
+                | JsonPathExpr::Not(..)
+                | JsonPathExpr::IsUnknown(..)
+                | JsonPathExpr::Exists(..)
+                | JsonPathExpr::LikeRegex { .. }
+                | JsonPathExpr::StartsWith(..)
+        )
+    }
+
+    /// The binding priority of the expression, which determines where
+    /// parentheses are required in its text representation.
+    fn priority(&self) -> u8 {
+        match self {
+            JsonPathExpr::Or(..) => 0,
+            JsonPathExpr::And(..) => 1,
+            JsonPathExpr::Comparison(..) | JsonPathExpr::StartsWith(..) => 2,
+            JsonPathExpr::Arithmetic(JsonPathArithmeticOp::Add | JsonPathArithmeticOp::Sub, ..) => {
+                3
+            }
+            JsonPathExpr::Arithmetic(..) => 4,
+            JsonPathExpr::Unary(..) => 5,
+            _ => 6,
+        }
+    }
+
+    /// Reports whether the expression is an operator whose text
+    /// representation may need parentheses.
+    fn is_operator(&self) -> bool {
+        matches!(
+            self,
+            JsonPathExpr::Unary(..)
+                | JsonPathExpr::Arithmetic(..)
+                | JsonPathExpr::Comparison(..)
+                | JsonPathExpr::And(..)
+                | JsonPathExpr::Or(..)
+                | JsonPathExpr::LikeRegex { .. }
+                | JsonPathExpr::StartsWith(..)
+        )
+    }
+
+    fn fmt_with_parens(&self, f: &mut fmt::Formatter, parens: bool) -> fmt::Result {
+        let parens = parens && self.is_operator();
+        if parens {
+            f.write_str("(")?;
+        }
+        let priority = self.priority();
+        let operand = |f: &mut fmt::Formatter, e: &JsonPathExpr| {
+            e.fmt_with_parens(f, e.priority() <= priority)
+        };
+        match self {
+            JsonPathExpr::Root => f.write_str("$")?,
+            JsonPathExpr::Current => f.write_str("@")?,
+            JsonPathExpr::Variable(name) => {
+                f.write_str("$")?;
+                write_quoted(f, name)?;
+            }
+            JsonPathExpr::Last => f.write_str("last")?,
+            JsonPathExpr::Literal(literal) => write!(f, "{literal}")?,
+            JsonPathExpr::Accessor(expr, accessor) => {
+                // An accessor binds more tightly than any operator.
+                expr.fmt_with_parens(f, true)?;
+                write!(f, "{accessor}")?;
+            }
+            JsonPathExpr::Unary(op, expr) => {
+                write!(f, "{op}")?;
+                operand(f, expr)?;
+            }
+            JsonPathExpr::Arithmetic(op, left, right) => {
+                operand(f, left)?;
+                write!(f, " {op} ")?;
+                operand(f, right)?;
+            }
+            JsonPathExpr::Comparison(op, left, right) => {
+                operand(f, left)?;
+                write!(f, " {op} ")?;
+                operand(f, right)?;
+            }
+            JsonPathExpr::And(left, right) => {
+                operand(f, left)?;
+                f.write_str(" && ")?;
+                operand(f, right)?;
+            }
+            JsonPathExpr::Or(left, right) => {
+                operand(f, left)?;
+                f.write_str(" || ")?;
+                operand(f, right)?;
+            }
+            JsonPathExpr::Not(expr) => {
+                f.write_str("!(")?;
+                expr.fmt_with_parens(f, false)?;
+                f.write_str(")")?;
+            }
+            JsonPathExpr::IsUnknown(expr) => {
+                f.write_str("(")?;
+                expr.fmt_with_parens(f, false)?;
+                f.write_str(") is unknown")?;
+            }
+            JsonPathExpr::Exists(expr) => {
+                f.write_str("exists (")?;
+                expr.fmt_with_parens(f, false)?;
+                f.write_str(")")?;
+            }
+            JsonPathExpr::LikeRegex {
+                expr,
+                pattern,
+                flags,
+                regex: _,
+            } => {
+                operand(f, expr)?;
+                f.write_str(" like_regex ")?;
+                write_quoted(f, pattern)?;
+                if !flags.is_empty() {
+                    f.write_str(" flag ")?;
+                    write_quoted(f, flags)?;
+                }
+            }
+            JsonPathExpr::StartsWith(left, right) => {
+                operand(f, left)?;
+                f.write_str(" starts with ")?;
+                operand(f, right)?;
+            }
+        }
+        if parens {
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+/// A literal scalar value in a [`JsonPath`].
+#[derive(Debug, Clone)]
+pub enum JsonPathLiteral {
+    Null,
+    Bool(bool),
+    Number(Numeric),
+    String(String),
+}
+
+impl fmt::Display for JsonPathLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonPathLiteral::Null => f.write_str("null"),
+            JsonPathLiteral::Bool(b) => write!(f, "{b}"),
+            JsonPathLiteral::Number(n) => f.write_str(&n.to_standard_notation_string()),
+            JsonPathLiteral::String(s) => write_quoted(f, s),
+        }
+    }
+}
+
+/// An accessor, which selects items from each item it is applied to.
+#[derive(Debug, Clone)]
+pub enum JsonPathAccessor {
+    /// `.key`, the value of a key of an object.
+    Member(String),
+    /// `.*`, the values of all keys of an object.
+    MemberWildcard,
+    /// `.**{first to last}`, the item and all values nested in it at the given
+    /// levels, where the item itself is at level 0.
+    Any { first: u32, last: u32 },
+    /// `[*]`, all elements of an array.
+    ArrayWildcard,
+    /// `[a, b to c]`, the elements of an array at the given subscripts and
+    /// ranges of subscripts.
+    Subscripts(Vec<(JsonPathExpr, Option<JsonPathExpr>)>),
+    /// `?(predicate)`, the item if the predicate is true of it.
+    Filter(Box<JsonPathExpr>),
+    /// `.method()`, the result of an item method.
+    Method(JsonPathMethod),
+}
+
+impl fmt::Display for JsonPathAccessor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fmt_level = |f: &mut fmt::Formatter, level: u32| {
+            if level == LAST_LEVEL {
+                f.write_str("last")
+            } else {
+                write!(f, "{level}")
+            }
+        };
+        match self {
+            JsonPathAccessor::Member(key) => {
+                f.write_str(".")?;
+                write_quoted(f, key)
+            }
+            JsonPathAccessor::MemberWildcard => f.write_str(".*"),
+            JsonPathAccessor::Any { first, last } => {
+                f.write_str(".**")?;
+                if *first == 0 && *last == LAST_LEVEL {
+                    return Ok(());
+                }
+                f.write_str("{")?;
+                fmt_level(f, *first)?;
+                if first != last {
+                    f.write_str(" to ")?;
+                    fmt_level(f, *last)?;
+                }
+                f.write_str("}")
+            }
+            JsonPathAccessor::ArrayWildcard => f.write_str("[*]"),
+            JsonPathAccessor::Subscripts(subscripts) => {
+                f.write_str("[")?;
+                for (i, (from, to)) in subscripts.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    from.fmt_with_parens(f, false)?;
+                    if let Some(to) = to {
+                        f.write_str(" to ")?;
+                        to.fmt_with_parens(f, false)?;
+                    }
+                }
+                f.write_str("]")
+            }
+            JsonPathAccessor::Filter(predicate) => {
+                f.write_str("?(")?;
+                predicate.fmt_with_parens(f, false)?;
+                f.write_str(")")
+            }
+            JsonPathAccessor::Method(method) => write!(f, ".{}()", method.name()),
+        }
+    }
+}
+
+/// An item method, e.g. `.size()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonPathMethod {
+    Type,
+    Size,
+    Double,
+    Ceiling,
+    Floor,
+    Abs,
+    Datetime,
+    KeyValue,
+}
+
+impl JsonPathMethod {
+    const ALL: [JsonPathMethod; 8] = [
+        JsonPathMethod::Type,
+        JsonPathMethod::Size,
+        JsonPathMethod::Double,
+        JsonPathMethod::Ceiling,
+        JsonPathMethod::Floor,
+        JsonPathMethod::Abs,
+        JsonPathMethod::Datetime,
+        JsonPathMethod::KeyValue,
+    ];
+
+    /// The name of the method, as written in a path.
+    pub fn name(&self) -> &'static str {
+        match self {
+            JsonPathMethod::Type => "type",
+            JsonPathMethod::Size => "size",
+            JsonPathMethod::Double => "double",
+            JsonPathMethod::Ceiling => "ceiling",
+            JsonPathMethod::Floor => "floor",
+            JsonPathMethod::Abs => "abs",
+            JsonPathMethod::Datetime => "datetime",
+            JsonPathMethod::KeyValue => "keyvalue",
+        }
+    }
+}
+
+/// A unary arithmetic operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonPathUnaryOp {
+    Plus,
+    Minus,
+}
+
+impl fmt::Display for JsonPathUnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonPathUnaryOp::Plus => f.write_str("+"),
+            JsonPathUnaryOp::Minus => f.write_str("-"),
+        }
+    }
+}
+
+/// A binary arithmetic operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonPathArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+impl fmt::Display for JsonPathArithmeticOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonPathArithmeticOp::Add => f.write_str("+"),
+            JsonPathArithmeticOp::Sub => f.write_str("-"),
+            JsonPathArithmeticOp::Mul => f.write_str("*"),
+            JsonPathArithmeticOp::Div => f.write_str("/"),
+            JsonPathArithmeticOp::Mod => f.write_str("%"),
+        }
+    }
+}
+
+/// A comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonPathComparisonOp {
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl fmt::Display for JsonPathComparisonOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonPathComparisonOp::Eq => f.write_str("=="),
+            JsonPathComparisonOp::NotEq => f.write_str("!="),
+            JsonPathComparisonOp::Lt => f.write_str("<"),
+            JsonPathComparisonOp::Lte => f.write_str("<="),
+            JsonPathComparisonOp::Gt => f.write_str(">"),
+            JsonPathComparisonOp::Gte => f.write_str(">="),
+        }
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.strict {
+            f.write_str("strict ")?;
+        }
+        self.expr.fmt_with_parens(f, true)
+    }
+}
+
+/// Writes `s` as a double-quoted string, escaping it as in JSON.
+fn write_quoted(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\u{08}' => f.write_str("\\b")?,
+            '\u{0C}' => f.write_str("\\f")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c < ' ' => write!(f, "\\u{:04x}", u32::from(c))?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    f.write_str("\"")
+}
+
+impl FromStr for JsonPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s)?;
+        let mut parser = Parser {
+            input: s,
+            tokens,
+            pos: 0,
+            filter_depth: 0,
+            subscript_depth: 0,
+        };
+        let strict = if parser.eat_keyword("strict") {
+            true
+        } else {
+            parser.eat_keyword("lax");
+            false
+        };
+        let expr = parser.parse_or()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(parser.syntax_error());
+        }
+        Ok(JsonPath { strict, expr })
+    }
+}
+
+/// A token of the text representation of a [`JsonPath`].
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Dollar,
+    Variable(String),
+    At,
+    Ident(String),
+    String(String),
+    Number(Numeric),
+    Dot,
+    Star,
+    DoubleStar,
+    LeftBracket,
+    RightBracket,
+    LeftParen,
+    RightParen,
+    LeftBrace,
+    RightBrace,
+    Comma,
+    Question,
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    And,
+    Or,
+    Not,
+    Plus,
+    Minus,
+    Slash,
+    Percent,
+}
+
+/// A token and the byte range of the input it was read from.
+type Spanned = (Token, usize, usize);
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn tokenize(s: &str) -> Result<Vec<Spanned>, Error> {
+    let mut chars = s.char_indices().peekable();
+    let mut tokens = vec![];
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '$' => match chars.peek() {
+                Some((_, '"')) => {
+                    chars.next();
+                    Token::Variable(read_string(&mut chars)?)
+                }
+                Some((_, c)) if is_ident_char(*c) => {
+                    let mut name = String::new();
+                    while let Some((_, c)) = chars.next_if(|(_, c)| is_ident_char(*c)) {
+                        name.push(c);
+                    }
+                    Token::Variable(name)
+                }
+                _ => Token::Dollar,
+            },
+            '@' => Token::At,
+            '"' => Token::String(read_string(&mut chars)?),
+            '0'..='9' => {
+                let mut number = String::from(c);
+                let digits = |chars: &mut std::iter::Peekable<std::str::CharIndices>,
+                              number: &mut String| {
+                    while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                        number.push(c);
+                    }
+                };
+                digits(&mut chars, &mut number);
+                // A dot is only part of the number if a digit follows it, so
+                // that e.g. `1.type()` applies a method to `1`.
+                let mut lookahead = chars.clone();
+                if let (Some((_, '.')), Some((_, c))) = (lookahead.next(), lookahead.next()) {
+                    if c.is_ascii_digit() {
+                        chars.next();
+                        number.push('.');
+                        digits(&mut chars, &mut number);
+                    }
+                }
+                if let Some((_, e)) = chars.next_if(|(_, c)| *c == 'e' || *c == 'E') {
+                    number.push(e);
+                    if let Some((_, sign)) = chars.next_if(|(_, c)| *c == '+' || *c == '-') {
+                        number.push(sign);
+                    }
+                    let len = number.len();
+                    digits(&mut chars, &mut number);
+                    if number.len() == len {
+                        bail!("invalid numeric literal \"{number}\"");
+                    }
+                }
+                if let Some((_, c)) = chars.peek() {
+                    if is_ident_char(*c) {
+                        bail!("trailing junk after numeric literal");
+                    }
+                }
+                Token::Number(parse_number(&number)?)
+            }
+            c if is_ident_char(c) => {
+                let mut ident = String::from(c);
+                while let Some((_, c)) = chars.next_if(|(_, c)| is_ident_char(*c)) {
+                    ident.push(c);
+                }
+                Token::Ident(ident)
+            }
+            '.' => Token::Dot,
+            '*' => match chars.next_if(|(_, c)| *c == '*') {
+                Some(_) => Token::DoubleStar,
+                None => Token::Star,
+            },
+            '[' => Token::LeftBracket,
+            ']' => Token::RightBracket,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '{' => Token::LeftBrace,
+            '}' => Token::RightBrace,
+            ',' => Token::Comma,
+            '?' => Token::Question,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '/' => Token::Slash,
+            '%' => Token::Percent,
+            '=' => match chars.next_if(|(_, c)| *c == '=') {
+                Some(_) => Token::Eq,
+                None => bail!("syntax error at or near \"=\" of jsonpath input"),
+            },
+            '!' => match chars.next_if(|(_, c)| *c == '=') {
+                Some(_) => Token::NotEq,
+                None => Token::Not,
+            },
+            '<' => match chars.next_if(|(_, c)| *c == '=' || *c == '>') {
+                Some((_, '=')) => Token::Lte,
+                Some(_) => Token::NotEq,
+                None => Token::Lt,
+            },
+            '>' => match chars.next_if(|(_, c)| *c == '=') {
+                Some(_) => Token::Gte,
+                None => Token::Gt,
+            },
+            '&' => match chars.next_if(|(_, c)| *c == '&') {
+                Some(_) => Token::And,
+                None => bail!("syntax error at or near \"&\" of jsonpath input"),
+            },
+            '|' => match chars.next_if(|(_, c)| *c == '|') {
+                Some(_) => Token::Or,
+                None => bail!("syntax error at or near \"|\" of jsonpath input"),
+            },
+            c => bail!("syntax error at or near \"{c}\" of jsonpath input"),
+        };
+        let end = chars.peek().map_or(s.len(), |(i, _)| *i);
+        tokens.push((token, start, end));
+    }
+    Ok(tokens)
+}
+
+/// Reads the remainder of a double-quoted string whose opening quote has
+/// been consumed.
+fn read_string(chars: &mut std::iter::Peekable<std::str::CharIndices>) -> Result<String, Error> {
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            None => bail!("unterminated quoted string"),
+            Some((_, '"')) => return Ok(s),
+            Some((_, '\\')) => match chars.next() {
+                None => bail!("unterminated quoted string"),
+                Some((_, 'b')) => s.push('\u{08}'),
+                Some((_, 'f')) => s.push('\u{0C}'),
+                Some((_, 'n')) => s.push('\n'),
+                Some((_, 'r')) => s.push('\r'),
+                Some((_, 't')) => s.push('\t'),
+                Some((_, 'v')) => s.push('\u{0B}'),
+                Some((_, 'x')) => {
+                    let mut hex = String::new();
+                    while hex.len() < 2 {
+                        match chars.next_if(|(_, c)| c.is_ascii_hexdigit()) {
+                            Some((_, c)) => hex.push(c),
+                            None => break,
+                        }
+                    }
+                    s.push(parse_code_point(&hex)?);
+                }
+                Some((_, 'u')) => {
+                    let mut hex = String::new();
+                    if chars.next_if(|(_, c)| *c == '{').is_some() {
+                        while let Some((_, c)) = chars.next_if(|(_, c)| *c != '}') {
+                            hex.push(c);
+                        }
+                        if chars.next().is_none() {
+                            bail!("unterminated quoted string");
+                        }
+                    } else {
+                        for _ in 0..4 {
+                            match chars.next() {
+                                Some((_, c)) => hex.push(c),
+                                None => bail!("unterminated quoted string"),
+                            }
+                        }
+                    }
+                    s.push(parse_code_point(&hex)?);
+                }
+                Some((_, c)) => s.push(c),
+            },
+            Some((_, c)) => s.push(c),
+        }
+    }
+}
+
+fn parse_code_point(hex: &str) -> Result<char, Error> {
+    u32::from_str_radix(hex, 16)
+        .ok()
+        .filter(|c| *c != 0)
+        .and_then(char::from_u32)
+        .ok_or_else(|| anyhow!("invalid Unicode escape value \"{hex}\""))
+}
+
+fn parse_number(s: &str) -> Result<Numeric, Error> {
+    let mut cx = numeric::cx_datum();
+    let mut n = cx
+        .parse(s)
+        .map_err(|_| anyhow!("invalid numeric literal \"{s}\""))?;
+    if cx.status().overflow() || numeric::munge_numeric(&mut n).is_err() {
+        bail!("numeric literal \"{s}\" is out of range");
+    }
+    Ok(n)
+}
+
+/// Compiles the pattern of a `like_regex` predicate, returning the flags in
+/// canonical order along with the compiled regex.
+fn compile_like_regex(pattern: &str, flags: &str) -> Result<(String, Regex), Error> {
+    let mut enabled = [false; 5];
+    for c in flags.chars() {
+        match "ismxq".find(c) {
+            Some(i) => enabled[i] = true,
+            None => bail!("Unrecognized flag character \"{c}\" in LIKE_REGEX predicate."),
+        }
+    }
+    let [case_insensitive, dot_matches_newline, multi_line, extended, quote] = enabled;
+    let canonical_flags = "ismxq"
+        .chars()
+        .zip(enabled)
+        .filter_map(|(c, enabled)| enabled.then_some(c))
+        .collect();
+    let mut full_pattern = String::new();
+    for (enabled, flag) in [
+        (dot_matches_newline, "(?s)"),
+        (multi_line, "(?m)"),
+        (extended && !quote, "(?x)"),
+    ] {
+        if enabled {
+            full_pattern.push_str(flag);
+        }
+    }
+    if quote {
+        full_pattern.push_str(&regex::escape(pattern));
+    } else {
+        full_pattern.push_str(pattern);
+    }
+    let regex = Regex::new(full_pattern, case_insensitive)
+        .map_err(|e| anyhow!("invalid regular expression: {e}"))?;
+    Ok((canonical_flags, regex))
+}
+
+/// A recursive descent parser of a tokenized [`JsonPath`].
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<Spanned>,
+    pos: usize,
+    /// The number of filters that enclose the current position.
+    filter_depth: usize,
+    /// The number of array subscripts that enclose the current position.
+    subscript_depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _, _)| token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Ident(ident)) if ident == keyword => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<(), Error> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.syntax_error())
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), Error> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.syntax_error())
+        }
+    }
+
+    /// Returns a syntax error at the current token.
+    fn syntax_error(&self) -> Error {
+        match self.tokens.get(self.pos) {
+            None => anyhow!("syntax error at end of jsonpath input"),
+            Some((_, start, end)) => anyhow!(
+                "syntax error at or near \"{}\" of jsonpath input",
+                &self.input[*start..*end]
+            ),
+        }
+    }
+
+    /// Returns a syntax error at the previous token, which introduced an
+    /// operand of the wrong kind.
+    fn operand_error(&self) -> Error {
+        let (_, start, end) = &self.tokens[self.pos - 1];
+        anyhow!(
+            "syntax error at or near \"{}\" of jsonpath input",
+            &self.input[*start..*end]
+        )
+    }
+
+    /// Requires that `expr` is a predicate.
+    fn predicate(&self, expr: JsonPathExpr) -> Result<Box<JsonPathExpr>, Error> {
+        if expr.is_predicate() {
+            Ok(Box::new(expr))
+        } else {
+            Err(self.syntax_error())
+        }
+    }
+
+    /// Requires that `expr` is not a predicate.
+    fn value(&self, expr: JsonPathExpr) -> Result<Box<JsonPathExpr>, Error> {
+        if expr.is_predicate() {
+            Err(self.syntax_error())
+        } else {
+            Ok(Box::new(expr))
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<JsonPathExpr, Error> {
+        let mut expr = self.parse_and()?;
+        while self.eat(&Token::Or) {
+            let left = self.predicate(expr)?;
+            let right = self.parse_and()?;
+            expr = JsonPathExpr::Or(left, self.predicate(right)?);
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<JsonPathExpr, Error> {
+        let mut expr = self.parse_not()?;
+        while self.eat(&Token::And) {
+            let left = self.predicate(expr)?;
+            let right = self.parse_not()?;
+            expr = JsonPathExpr::And(left, self.predicate(right)?);
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<JsonPathExpr, Error> {
+        if self.eat(&Token::Not) {
+            let expr = self.parse_accessor_expr()?;
+            return Ok(JsonPathExpr::Not(self.predicate(expr)?));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<JsonPathExpr, Error> {
+        let expr = self.parse_additive()?;
+        let op = match self.peek() {
+            Some(Token::Eq) => JsonPathComparisonOp::Eq,
+            Some(Token::NotEq) => JsonPathComparisonOp::NotEq,
+            Some(Token::Lt) => JsonPathComparisonOp::Lt,
+            Some(Token::Lte) => JsonPathComparisonOp::Lte,
+            Some(Token::Gt) => JsonPathComparisonOp::Gt,
+            Some(Token::Gte) => JsonPathComparisonOp::Gte,
+            Some(Token::Ident(ident)) if ident == "starts" => {
+                self.pos += 1;
+                let left = self.value(expr)?;
+                self.expect_keyword("with")?;
+                let right = match self.next() {
+                    Some(Token::String(s)) => JsonPathExpr::Literal(JsonPathLiteral::String(s)),
+                    Some(Token::Variable(name)) => JsonPathExpr::Variable(name),
+                    _ => return Err(self.operand_error()),
+                };
+                return Ok(JsonPathExpr::StartsWith(left, Box::new(right)));
+            }
+            Some(Token::Ident(ident)) if ident == "like_regex" => {
+                self.pos += 1;
+                let expr = self.value(expr)?;
+                let Some(Token::String(pattern)) = self.next() else {
+                    return Err(self.operand_error());
+                };
+                let flags = if self.eat_keyword("flag") {
+                    match self.next() {
+                        Some(Token::String(flags)) => flags,
+                        _ => return Err(self.operand_error()),
+                    }
+                } else {
+                    String::new()
+                };
+                let (flags, regex) = compile_like_regex(&pattern, &flags)?;
+                return Ok(JsonPathExpr::LikeRegex {
+                    expr,
+                    pattern,
+                    flags,
+                    regex,
+                });
+            }
+            _ => return Ok(expr),
+        };
+        self.pos += 1;
+        let left = self.value(expr)?;
+        let right = self.parse_additive()?;
+        Ok(JsonPathExpr::Comparison(op, left, self.value(right)?))
+    }
+
+    fn parse_additive(&mut self) -> Result<JsonPathExpr, Error> {
+        let mut expr = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => JsonPathArithmeticOp::Add,
+                Some(Token::Minus) => JsonPathArithmeticOp::Sub,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let left = self.value(expr)?;
+            let right = self.parse_multiplicative()?;
+            expr = JsonPathExpr::Arithmetic(op, left, self.value(right)?);
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<JsonPathExpr, Error> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => JsonPathArithmeticOp::Mul,
+                Some(Token::Slash) => JsonPathArithmeticOp::Div,
+                Some(Token::Percent) => JsonPathArithmeticOp::Mod,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let left = self.value(expr)?;
+            let right = self.parse_unary()?;
+            expr = JsonPathExpr::Arithmetic(op, left, self.value(right)?);
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<JsonPathExpr, Error> {
+        let op = match self.peek() {
+            Some(Token::Plus) => JsonPathUnaryOp::Plus,
+            Some(Token::Minus) => JsonPathUnaryOp::Minus,
+            _ => return self.parse_accessor_expr(),
+        };
+        self.pos += 1;
+        let expr = self.parse_unary()?;
+        // Like PostgreSQL, fold signs into numeric literals.
+        match (op, expr) {
+            (JsonPathUnaryOp::Plus, expr @ JsonPathExpr::Literal(JsonPathLiteral::Number(_))) => {
+                Ok(expr)
+            }
+            (JsonPathUnaryOp::Minus, JsonPathExpr::Literal(JsonPathLiteral::Number(mut n))) => {
+                numeric::cx_datum().neg(&mut n);
+                Ok(JsonPathExpr::Literal(JsonPathLiteral::Number(n)))
+            }
+            (op, expr) => Ok(JsonPathExpr::Unary(op, self.value(expr)?)),
+        }
+    }
+
+    fn parse_accessor_expr(&mut self) -> Result<JsonPathExpr, Error> {
+        let mut expr = self.parse_primary()?;
+        loop {
+            let accessor = match self.peek() {
+                Some(Token::Dot) => {
+                    self.pos += 1;
+                    self.parse_dot_accessor()?
+                }
+                Some(Token::LeftBracket) => {
+                    self.pos += 1;
+                    self.parse_subscripts()?
+                }
+                Some(Token::Question) => {
+                    self.pos += 1;
+                    self.expect(&Token::LeftParen)?;
+                    self.filter_depth += 1;
+                    let predicate = self.parse_or()?;
+                    self.filter_depth -= 1;
+                    let predicate = self.predicate(predicate)?;
+                    self.expect(&Token::RightParen)?;
+                    JsonPathAccessor::Filter(predicate)
+                }
+                _ => return Ok(expr),
+            };
+            expr = JsonPathExpr::Accessor(Box::new(expr), accessor);
+        }
+    }
+
+    fn parse_dot_accessor(&mut self) -> Result<JsonPathAccessor, Error> {
+        match self.next() {
+            Some(Token::Star) => Ok(JsonPathAccessor::MemberWildcard),
+            Some(Token::DoubleStar) => {
+                if !self.eat(&Token::LeftBrace) {
+                    return Ok(JsonPathAccessor::Any {
+                        first: 0,
+                        last: LAST_LEVEL,
+                    });
+                }
+                let first = self.parse_level()?;
+                let last = if self.eat_keyword("to") {
+                    self.parse_level()?
+                } else {
+                    first
+                };
+                self.expect(&Token::RightBrace)?;
+                Ok(JsonPathAccessor::Any { first, last })
+            }
+            Some(Token::String(key)) => Ok(JsonPathAccessor::Member(key)),
+            Some(Token::Ident(ident)) => {
+                if self.peek() == Some(&Token::LeftParen) {
+                    if let Some(method) = JsonPathMethod::ALL
+                        .into_iter()
+                        .find(|method| method.name() == ident)
+                    {
+                        self.pos += 1;
+                        if method == JsonPathMethod::Datetime
+                            && matches!(self.peek(), Some(Token::String(_)))
+                        {
+                            bail!("jsonpath .datetime() templates are not supported");
+                        }
+                        self.expect(&Token::RightParen)?;
+                        return Ok(JsonPathAccessor::Method(method));
+                    }
+                }
+                Ok(JsonPathAccessor::Member(ident))
+            }
+            _ => Err(self.operand_error()),
+        }
+    }
+
+    fn parse_level(&mut self) -> Result<u32, Error> {
+        match self.next() {
+            Some(Token::Ident(ident)) if ident == "last" => Ok(LAST_LEVEL),
+            Some(Token::Number(n)) => match n.to_standard_notation_string().parse::<u32>() {
+                Ok(level) if level != LAST_LEVEL => Ok(level),
+                _ => bail!(
+                    "invalid level {} in .** accessor",
+                    n.to_standard_notation_string()
+                ),
+            },
+            _ => Err(self.operand_error()),
+        }
+    }
+
+    fn parse_subscripts(&mut self) -> Result<JsonPathAccessor, Error> {
+        if self.eat(&Token::Star) {
+            self.expect(&Token::RightBracket)?;
+            return Ok(JsonPathAccessor::ArrayWildcard);
+        }
+        self.subscript_depth += 1;
+        let mut subscripts = vec![];
+        loop {
+            let from = self.parse_or()?;
+            let from = *self.value(from)?;
+            let to = if self.eat_keyword("to") {
+                let to = self.parse_or()?;
+                Some(*self.value(to)?)
+            } else {
+                None
+            };
+            subscripts.push((from, to));
+            if !self.eat(&Token::Comma) {
+                break;
+            }
+        }
+        self.subscript_depth -= 1;
+        self.expect(&Token::RightBracket)?;
+        Ok(JsonPathAccessor::Subscripts(subscripts))
+    }
+
+    fn parse_primary(&mut self) -> Result<JsonPathExpr, Error> {
+        let Some(token) = self.next() else {
+            return Err(self.syntax_error());
+        };
+        let expr = match token {
+            Token::Dollar => JsonPathExpr::Root,
+            Token::Variable(name) => JsonPathExpr::Variable(name),
+            Token::At => {
+                if self.filter_depth == 0 {
+                    bail!("@ is not allowed in root expressions");
+                }
+                JsonPathExpr::Current
+            }
+            Token::String(s) => JsonPathExpr::Literal(JsonPathLiteral::String(s)),
+            Token::Number(n) => JsonPathExpr::Literal(JsonPathLiteral::Number(n)),
+            Token::Ident(ident) => match ident.as_str() {
+                "null" => JsonPathExpr::Literal(JsonPathLiteral::Null),
+                "true" => JsonPathExpr::Literal(JsonPathLiteral::Bool(true)),
+                "false" => JsonPathExpr::Literal(JsonPathLiteral::Bool(false)),
+                "last" => {
+                    if self.subscript_depth == 0 {
+                        bail!("LAST is allowed only in array subscripts");
+                    }
+                    JsonPathExpr::Last
+                }
+                "exists" => {
+                    self.expect(&Token::LeftParen)?;
+                    let expr = self.parse_or()?;
+                    let expr = self.value(expr)?;
+                    self.expect(&Token::RightParen)?;
+                    JsonPathExpr::Exists(expr)
+                }
+                _ => return Err(self.operand_error()),
+            },
+            Token::LeftParen => {
+                let expr = self.parse_or()?;
+                self.expect(&Token::RightParen)?;
+                if self.eat_keyword("is") {
+                    self.expect_keyword("unknown")?;
+                    JsonPathExpr::IsUnknown(self.predicate(expr)?)
+                } else {
+                    expr
+                }
+            }
+            _ => return Err(self.operand_error()),
+        };
+        Ok(expr)
+    }
+}
+
+/// Generates arbitrary [`JsonPath`]s from a fixed set of paths that exercise
+/// each kind of path expression.
+pub fn any_jsonpath() -> impl Strategy<Value = JsonPath> {
+    select(vec![
+        "$",
+        "$.a.b",
+        "strict $.a[*]",
+        "$.a[0, 2 to last]",
+        "$.**{1 to last}",
+        "$.a ? (@ > 1 && @ like_regex \"^x\" flag \"i\")",
+        "$.a.size() + $x * -2",
+        "$ ? (exists (@.a) || @.b starts with \"c\")",
+        "$.a.datetime().type()",
+        "lax $.*.keyvalue()",
+    ])
+    .prop_map(|path| path.parse().expect("valid jsonpath"))
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_proto::protobuf_roundtrip;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[mz_ore::test]
+        fn jsonpath_protobuf_roundtrip(expect in any_jsonpath()) {
+            let actual = protobuf_roundtrip::<_, ProtoJsonPath>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_parse_display() {
+        for (input, output) in [
+            ("$", "$"),
+            ("  strict $ ", "strict $"),
+            ("lax $.a", "$.\"a\""),
+            ("$.a.\"b c\"", "$.\"a\".\"b c\""),
+            ("$.a[*].b", "$.\"a\"[*].\"b\""),
+            ("$.a[0, 2 to last]", "$.\"a\"[0,2 to last]"),
+            ("$.a[$.i + 1]", "$.\"a\"[$.\"i\" + 1]"),
+            ("$.*", "$.*"),
+            ("$.**", "$.**"),
+            ("$.**{2}", "$.**{2}"),
+            ("$.**{1 to last}", "$.**{1 to last}"),
+            ("$.a.size()", "$.\"a\".size()"),
+            ("$.size", "$.\"size\""),
+            ("$.a.datetime().type()", "$.\"a\".datetime().type()"),
+            ("$ ? (@.a > 1)", "$?(@.\"a\" > 1)"),
+            (
+                "$.a ? (@ > 1 && @ < 5 || !(@ == 3))",
+                "$.\"a\"?(@ > 1 && @ < 5 || !(@ == 3))",
+            ),
+            (
+                "$ ? ((@ > 1 || @ < 0) && @ != 2)",
+                "$?((@ > 1 || @ < 0) && @ != 2)",
+            ),
+            ("$ ? (@ <> 2)", "$?(@ != 2)"),
+            ("$.a + 1", "($.\"a\" + 1)"),
+            ("1 + 2 * 3", "(1 + 2 * 3)"),
+            ("(1 + 2) * 3", "((1 + 2) * 3)"),
+            ("1 - 2 - 3", "((1 - 2) - 3)"),
+            ("-$.a", "(-$.\"a\")"),
+            ("- -1", "1"),
+            ("-$.a - -1", "(-$.\"a\" - -1)"),
+            ("$ ? (@ > -1.5)", "$?(@ > -1.5)"),
+            ("($.a + 1).type()", "($.\"a\" + 1).type()"),
+            ("$.a == 1", "($.\"a\" == 1)"),
+            ("$var", "$\"var\""),
+            ("$\"my var\".a", "$\"my var\".\"a\""),
+            ("1.5e2", "150"),
+            ("\"a\\\"b\\n\\u0041\"", "\"a\\\"b\\nA\""),
+            ("null", "null"),
+            ("true", "true"),
+            ("$ ? (exists (@.a))", "$?(exists (@.\"a\"))"),
+            ("$ ? ((@ > 1) is unknown)", "$?((@ > 1) is unknown)"),
+            (
+                "$ ? (@ like_regex \"^a.*\" flag \"qi\")",
+                "$?(@ like_regex \"^a.*\" flag \"iq\")",
+            ),
+            ("$ ? (@ starts with \"ab\")", "$?(@ starts with \"ab\")"),
+            ("$ ? (@ starts with $x)", "$?(@ starts with $\"x\")"),
+        ] {
+            let path: JsonPath = input.parse().unwrap_or_else(|e| panic!("{input}: {e}"));
+            assert_eq!(path.to_string(), output, "{input}");
+            let path: JsonPath = output.parse().unwrap_or_else(|e| panic!("{output}: {e}"));
+            assert_eq!(path.to_string(), output, "{output}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_parse_errors() {
+        for (input, error) in [
+            ("", "syntax error at end of jsonpath input"),
+            ("$.", "syntax error at end of jsonpath input"),
+            ("$ $", "syntax error at or near \"$\" of jsonpath input"),
+            ("@", "@ is not allowed in root expressions"),
+            ("last", "LAST is allowed only in array subscripts"),
+            (
+                "$ ? (@.a)",
+                "syntax error at or near \")\" of jsonpath input",
+            ),
+            (
+                "$ ? (@ == 1 + (2 < 3))",
+                "syntax error at or near \")\" of jsonpath input",
+            ),
+            (
+                "$ ? (@ like_regex \"a\" flag \"z\")",
+                "Unrecognized flag character \"z\" in LIKE_REGEX predicate.",
+            ),
+            (
+                "$.a.datetime(\"HH24\")",
+                "jsonpath .datetime() templates are not supported",
+            ),
+            ("1a", "trailing junk after numeric literal"),
+            ("\"abc", "unterminated quoted string"),
+        ] {
+            let err = input.parse::<JsonPath>().unwrap_err();
+            assert_eq!(err.to_string(), error, "{input}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_equality() {
+        let a: JsonPath = "$.a ? (@ <> 1)".parse().unwrap();
+        let b: JsonPath = "lax $.\"a\"?(@ != 1)".parse().unwrap();
+        let c: JsonPath = "strict $.a ? (@ != 1)".parse().unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+}
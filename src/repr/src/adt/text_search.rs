@@ -0,0 +1,1003 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Full text search types.
+//!
+//! Both types are stored as [`Datum::String`](crate::Datum::String)s
+//! containing their canonical text representation, which this module parses
+//! and produces.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error};
+use serde::{Deserialize, Serialize};
+
+/// The largest position of a lexeme in a [`TsVector`]. Larger positions are
+/// clamped to this value, as in PostgreSQL.
+pub const MAX_POSITION: u16 = 16383;
+
+/// The largest number of positions recorded for a single lexeme in a
+/// [`TsVector`]. Additional positions are discarded, as in PostgreSQL.
+pub const MAX_POSITIONS_PER_LEXEME: usize = 256;
+
+/// The largest distance of a phrase operator in a [`TsQuery`].
+pub const MAX_PHRASE_DISTANCE: u16 = 16384;
+
+/// The weight of a lexeme position, which ranking functions use to score
+/// matches in some parts of a document (e.g. its title) more highly than in
+/// others.
+///
+/// Weights are ordered from least (`D`) to most (`A`) important.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TsWeight {
+    D,
+    C,
+    B,
+    A,
+}
+
+impl TsWeight {
+    /// The index of the weight in a ranking function's weights array, which
+    /// lists the weights from `D` to `A`.
+    pub fn index(&self) -> usize {
+        match self {
+            TsWeight::D => 0,
+            TsWeight::C => 1,
+            TsWeight::B => 2,
+            TsWeight::A => 3,
+        }
+    }
+
+    /// The bit that represents the weight in a [`TsOperand`]'s weight mask.
+    pub fn mask(&self) -> u8 {
+        1 << self.index()
+    }
+
+    /// Constructs a weight from its [index](TsWeight::index).
+    pub fn from_index(index: u8) -> Option<TsWeight> {
+        match index {
+            0 => Some(TsWeight::D),
+            1 => Some(TsWeight::C),
+            2 => Some(TsWeight::B),
+            3 => Some(TsWeight::A),
+            _ => None,
+        }
+    }
+
+    fn from_char(c: char) -> Option<TsWeight> {
+        match c.to_ascii_uppercase() {
+            'A' => Some(TsWeight::A),
+            'B' => Some(TsWeight::B),
+            'C' => Some(TsWeight::C),
+            'D' => Some(TsWeight::D),
+            _ => None,
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            TsWeight::A => 'A',
+            TsWeight::B => 'B',
+            TsWeight::C => 'C',
+            TsWeight::D => 'D',
+        }
+    }
+}
+
+/// A position of a lexeme in a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TsPosition {
+    /// The 1-based position of the word in the document.
+    pub position: u16,
+    /// The weight of the position.
+    pub weight: TsWeight,
+}
+
+/// A normalized word and the positions at which it occurs in a document.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TsLexeme {
+    /// The normalized word.
+    pub word: String,
+    /// The positions of the word, which may be empty if the document's
+    /// positions were not recorded.
+    pub positions: Vec<TsPosition>,
+}
+
+/// A document in a form optimized for text search: the sorted set of the
+/// distinct lexemes in the document, with their positions.
+///
+/// See: <https://www.postgresql.org/docs/current/datatype-textsearch.html#DATATYPE-TSVECTOR>
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TsVector {
+    lexemes: Vec<TsLexeme>,
+}
+
+impl TsVector {
+    /// Constructs a vector from the given lexemes, which need not be sorted or
+    /// distinct.
+    ///
+    /// The positions of duplicate lexemes are merged. If a position is
+    /// duplicated, the highest of its weights is retained.
+    pub fn new(mut lexemes: Vec<TsLexeme>) -> TsVector {
+        lexemes.sort_by(|a, b| a.word.cmp(&b.word));
+        let mut merged: Vec<TsLexeme> = Vec::with_capacity(lexemes.len());
+        for lexeme in lexemes {
+            match merged.last_mut() {
+                Some(last) if last.word == lexeme.word => last.positions.extend(lexeme.positions),
+                _ => merged.push(lexeme),
+            }
+        }
+        for lexeme in &mut merged {
+            lexeme
+                .positions
+                .sort_by(|a, b| a.position.cmp(&b.position).then(b.weight.cmp(&a.weight)));
+            lexeme.positions.dedup_by_key(|p| p.position);
+            lexeme.positions.truncate(MAX_POSITIONS_PER_LEXEME);
+        }
+        TsVector { lexemes: merged }
+    }
+
+    /// Returns the lexemes of the vector, sorted by word.
+    pub fn lexemes(&self) -> &[TsLexeme] {
+        &self.lexemes
+    }
+
+    /// Returns the lexemes that match the given operand, ignoring its
+    /// weights: the lexeme equal to the operand's word or, if the operand is
+    /// a prefix, all lexemes that start with the operand's word.
+    pub fn find<'a>(&'a self, operand: &'a TsOperand) -> impl Iterator<Item = &'a TsLexeme> {
+        let start = self
+            .lexemes
+            .partition_point(|l| l.word.as_str() < operand.word.as_str());
+        self.lexemes[start..].iter().take_while(move |l| {
+            if operand.prefix {
+                l.word.starts_with(&operand.word)
+            } else {
+                l.word == operand.word
+            }
+        })
+    }
+
+    /// Reports whether the vector matches the query, i.e. the `@@` operator.
+    pub fn matches(&self, query: &TsQuery) -> bool {
+        match &query.root {
+            None => false,
+            Some(root) => self.execute(root),
+        }
+    }
+
+    fn execute(&self, node: &TsQueryNode) -> bool {
+        match node {
+            TsQueryNode::Operand(operand) => self.find(operand).any(|lexeme| {
+                // A lexeme without positions has unknown weights, and so
+                // matches any weight restriction.
+                operand.weights == 0
+                    || lexeme.positions.is_empty()
+                    || lexeme
+                        .positions
+                        .iter()
+                        .any(|p| operand.weights & p.weight.mask() != 0)
+            }),
+            TsQueryNode::Not(node) => !self.execute(node),
+            TsQueryNode::And(left, right) => self.execute(left) && self.execute(right),
+            TsQueryNode::Or(left, right) => self.execute(left) || self.execute(right),
+            TsQueryNode::Phrase(..) => self.execute_phrase(node) != PhraseMatch::No,
+        }
+    }
+
+    /// Computes the positions at which the node matches. A phrase operator
+    /// matches at the positions of its right operand.
+    fn execute_phrase(&self, node: &TsQueryNode) -> PhraseMatch {
+        match node {
+            TsQueryNode::Operand(operand) => {
+                let mut positions = BTreeSet::new();
+                let mut unpositioned = false;
+                for lexeme in self.find(operand) {
+                    unpositioned |= lexeme.positions.is_empty();
+                    positions.extend(
+                        lexeme
+                            .positions
+                            .iter()
+                            .filter(|p| {
+                                operand.weights == 0 || operand.weights & p.weight.mask() != 0
+                            })
+                            .map(|p| p.position),
+                    );
+                }
+                if positions.is_empty() && unpositioned {
+                    // Without positions, a phrase match can neither be
+                    // confirmed nor ruled out.
+                    PhraseMatch::Maybe
+                } else {
+                    PhraseMatch::positions(positions, false)
+                }
+            }
+            TsQueryNode::Not(node) => match self.execute_phrase(node) {
+                PhraseMatch::No => PhraseMatch::positions(BTreeSet::new(), true),
+                PhraseMatch::Maybe => PhraseMatch::Maybe,
+                PhraseMatch::Positions { positions, negated } => {
+                    PhraseMatch::positions(positions, !negated)
+                }
+            },
+            TsQueryNode::And(left, right) => {
+                match (self.execute_phrase(left), self.execute_phrase(right)) {
+                    (PhraseMatch::No, _) | (_, PhraseMatch::No) => PhraseMatch::No,
+                    (PhraseMatch::Maybe, _) | (_, PhraseMatch::Maybe) => PhraseMatch::Maybe,
+                    (
+                        PhraseMatch::Positions {
+                            positions: l,
+                            negated: ln,
+                        },
+                        PhraseMatch::Positions {
+                            positions: r,
+                            negated: rn,
+                        },
+                    ) => match (ln, rn) {
+                        (false, false) => PhraseMatch::positions(&l & &r, false),
+                        (true, true) => PhraseMatch::positions(&l | &r, true),
+                        (false, true) => PhraseMatch::positions(&l - &r, false),
+                        (true, false) => PhraseMatch::positions(&r - &l, false),
+                    },
+                }
+            }
+            TsQueryNode::Or(left, right) => {
+                match (self.execute_phrase(left), self.execute_phrase(right)) {
+                    (PhraseMatch::No, m) | (m, PhraseMatch::No) => m,
+                    (PhraseMatch::Maybe, _) | (_, PhraseMatch::Maybe) => PhraseMatch::Maybe,
+                    (
+                        PhraseMatch::Positions {
+                            positions: l,
+                            negated: ln,
+                        },
+                        PhraseMatch::Positions {
+                            positions: r,
+                            negated: rn,
+                        },
+                    ) => match (ln, rn) {
+                        (false, false) => PhraseMatch::positions(&l | &r, false),
+                        (true, true) => PhraseMatch::positions(&l & &r, true),
+                        (false, true) => PhraseMatch::positions(&r - &l, true),
+                        (true, false) => PhraseMatch::positions(&l - &r, true),
+                    },
+                }
+            }
+            TsQueryNode::Phrase(left, right, distance) => {
+                match (self.execute_phrase(left), self.execute_phrase(right)) {
+                    (PhraseMatch::No, _) | (_, PhraseMatch::No) => PhraseMatch::No,
+                    (PhraseMatch::Maybe, _) | (_, PhraseMatch::Maybe) => PhraseMatch::Maybe,
+                    (
+                        PhraseMatch::Positions {
+                            positions: l,
+                            negated: ln,
+                        },
+                        PhraseMatch::Positions {
+                            positions: r,
+                            negated: rn,
+                        },
+                    ) => {
+                        let left_matches_at = |p: u16| match p.checked_sub(*distance) {
+                            Some(p) => l.contains(&p) != ln,
+                            None => ln,
+                        };
+                        match (ln, rn) {
+                            (_, false) => {
+                                let positions = r.into_iter().filter(|p| left_matches_at(*p));
+                                PhraseMatch::positions(positions.collect(), false)
+                            }
+                            (false, true) => {
+                                let positions = l
+                                    .iter()
+                                    .filter_map(|p| p.checked_add(*distance))
+                                    .filter(|p| !r.contains(p));
+                                PhraseMatch::positions(positions.collect(), false)
+                            }
+                            (true, true) => {
+                                let shifted = l.iter().filter_map(|p| p.checked_add(*distance));
+                                PhraseMatch::positions(
+                                    r.iter().copied().chain(shifted).collect(),
+                                    true,
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The result of matching a node of a [`TsQuery`] within a phrase.
+#[derive(Debug, PartialEq, Eq)]
+enum PhraseMatch {
+    /// The node does not match anywhere.
+    No,
+    /// The node may match, but the vector lacks the positions to determine
+    /// where.
+    Maybe,
+    /// The node matches at `positions` or, if `negated` is set, at every
+    /// position except `positions`.
+    Positions {
+        positions: BTreeSet<u16>,
+        negated: bool,
+    },
+}
+
+impl PhraseMatch {
+    fn positions(positions: BTreeSet<u16>, negated: bool) -> PhraseMatch {
+        if positions.is_empty() && !negated {
+            PhraseMatch::No
+        } else {
+            PhraseMatch::Positions { positions, negated }
+        }
+    }
+}
+
+impl FromStr for TsVector {
+    type Err = Error;
+
+    /// Parses a vector of the form `'lexeme':1A,2 lexeme ...`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars().peekable();
+        let mut lexemes = vec![];
+        loop {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            let Some(c) = chars.next() else { break };
+            let mut word = String::new();
+            if c == '\'' {
+                loop {
+                    match chars.next() {
+                        None => bail!("unterminated quoted string"),
+                        Some('\'') if chars.next_if_eq(&'\'').is_some() => word.push('\''),
+                        Some('\'') => break,
+                        Some('\\') => match chars.next() {
+                            None => bail!("there is no escaped character"),
+                            Some(c) => word.push(c),
+                        },
+                        Some(c) => word.push(c),
+                    }
+                }
+            } else {
+                let mut c = c;
+                loop {
+                    match c {
+                        '\\' => match chars.next() {
+                            None => bail!("there is no escaped character"),
+                            Some(c) => word.push(c),
+                        },
+                        c => word.push(c),
+                    }
+                    match chars.next_if(|c| !c.is_whitespace() && *c != ':') {
+                        Some(next) => c = next,
+                        None => break,
+                    }
+                }
+            }
+            let mut positions = vec![];
+            if chars.next_if_eq(&':').is_some() {
+                loop {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                        digits.push(c);
+                    }
+                    let position: u64 = digits
+                        .parse()
+                        .map_err(|_| anyhow!("missing position for lexeme \"{word}\""))?;
+                    if position == 0 {
+                        bail!("wrong position info for lexeme \"{word}\"");
+                    }
+                    let position = u16::try_from(position)
+                        .unwrap_or(MAX_POSITION)
+                        .min(MAX_POSITION);
+                    let weight = match chars.next_if(|c| TsWeight::from_char(*c).is_some()) {
+                        Some(c) => TsWeight::from_char(c).expect("known to be valid"),
+                        None => TsWeight::D,
+                    };
+                    positions.push(TsPosition { position, weight });
+                    if chars.next_if_eq(&',').is_none() {
+                        break;
+                    }
+                }
+            }
+            if let Some(c) = chars.peek() {
+                if !c.is_whitespace() {
+                    bail!("unexpected character \"{c}\" after lexeme \"{word}\"");
+                }
+            }
+            lexemes.push(TsLexeme { word, positions });
+        }
+        Ok(TsVector::new(lexemes))
+    }
+}
+
+/// Writes `word` as a quoted string, doubling any quotes and backslashes.
+fn write_quoted(f: &mut fmt::Formatter, word: &str) -> fmt::Result {
+    f.write_str("'")?;
+    for c in word.chars() {
+        match c {
+            '\'' => f.write_str("''")?,
+            '\\' => f.write_str("\\\\")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    f.write_str("'")
+}
+
+impl fmt::Display for TsVector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, lexeme) in self.lexemes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write_quoted(f, &lexeme.word)?;
+            for (j, position) in lexeme.positions.iter().enumerate() {
+                f.write_str(if j == 0 { ":" } else { "," })?;
+                write!(f, "{}", position.position)?;
+                if position.weight != TsWeight::D {
+                    write!(f, "{}", position.weight.as_char())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A word to search for in a [`TsQuery`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TsOperand {
+    /// The normalized word.
+    pub word: String,
+    /// Whether the operand matches any lexeme that starts with the word,
+    /// rather than only the word itself.
+    pub prefix: bool,
+    /// The [masks](TsWeight::mask) of the weights of the positions the
+    /// operand matches, or zero if it matches positions of any weight.
+    pub weights: u8,
+}
+
+impl TsOperand {
+    /// Constructs an operand that matches exactly `word`, at positions of any
+    /// weight.
+    pub fn new(word: String) -> TsOperand {
+        TsOperand {
+            word,
+            prefix: false,
+            weights: 0,
+        }
+    }
+}
+
+/// A node of a [`TsQuery`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TsQueryNode {
+    /// Matches documents that contain the operand.
+    Operand(TsOperand),
+    /// Matches documents that the node does not match, i.e. `!`.
+    Not(Box<TsQueryNode>),
+    /// Matches documents that both nodes match, i.e. `&`.
+    And(Box<TsQueryNode>, Box<TsQueryNode>),
+    /// Matches documents that either node matches, i.e. `|`.
+    Or(Box<TsQueryNode>, Box<TsQueryNode>),
+    /// Matches documents in which the right node matches exactly the given
+    /// distance after the left node, i.e. `<->` or `<N>`.
+    Phrase(Box<TsQueryNode>, Box<TsQueryNode>, u16),
+}
+
+impl TsQueryNode {
+    /// The precedence of the node's operator, which binds more tightly the
+    /// higher it is.
+    fn priority(&self) -> u8 {
+        match self {
+            TsQueryNode::Operand(_) => 5,
+            TsQueryNode::Not(_) => 4,
+            TsQueryNode::Phrase(..) => 3,
+            TsQueryNode::And(..) => 2,
+            TsQueryNode::Or(..) => 1,
+        }
+    }
+
+    /// Calls `f` on each operand of the node, from left to right.
+    pub fn for_each_operand<'a, F>(&'a self, f: &mut F)
+    where
+        F: FnMut(&'a TsOperand),
+    {
+        match self {
+            TsQueryNode::Operand(operand) => f(operand),
+            TsQueryNode::Not(node) => node.for_each_operand(f),
+            TsQueryNode::And(left, right)
+            | TsQueryNode::Or(left, right)
+            | TsQueryNode::Phrase(left, right, _) => {
+                left.for_each_operand(f);
+                right.for_each_operand(f);
+            }
+        }
+    }
+
+    /// Writes the node, parenthesizing it if its operator binds less tightly
+    /// than that of its parent, or if it is a phrase on the right-hand side
+    /// of another phrase.
+    fn fmt_infix(
+        &self,
+        f: &mut fmt::Formatter,
+        parent_priority: u8,
+        right_of_phrase: bool,
+    ) -> fmt::Result {
+        let priority = self.priority();
+        match self {
+            TsQueryNode::Operand(operand) => {
+                write_quoted(f, &operand.word)?;
+                if operand.prefix || operand.weights != 0 {
+                    f.write_str(":")?;
+                    if operand.prefix {
+                        f.write_str("*")?;
+                    }
+                    for weight in [TsWeight::A, TsWeight::B, TsWeight::C, TsWeight::D] {
+                        if operand.weights & weight.mask() != 0 {
+                            write!(f, "{}", weight.as_char())?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            TsQueryNode::Not(node) => {
+                let parenthesize = priority < parent_priority;
+                if parenthesize {
+                    f.write_str("( ")?;
+                }
+                f.write_str("!")?;
+                node.fmt_infix(f, priority, false)?;
+                if parenthesize {
+                    f.write_str(" )")?;
+                }
+                Ok(())
+            }
+            TsQueryNode::And(left, right)
+            | TsQueryNode::Or(left, right)
+            | TsQueryNode::Phrase(left, right, _) => {
+                let is_phrase = matches!(self, TsQueryNode::Phrase(..));
+                let parenthesize = priority < parent_priority || (is_phrase && right_of_phrase);
+                if parenthesize {
+                    f.write_str("( ")?;
+                }
+                left.fmt_infix(f, priority, false)?;
+                match self {
+                    TsQueryNode::And(..) => f.write_str(" & ")?,
+                    TsQueryNode::Or(..) => f.write_str(" | ")?,
+                    TsQueryNode::Phrase(_, _, 1) => f.write_str(" <-> ")?,
+                    TsQueryNode::Phrase(_, _, distance) => write!(f, " <{distance}> ")?,
+                    _ => unreachable!(),
+                }
+                right.fmt_infix(f, priority, is_phrase)?;
+                if parenthesize {
+                    f.write_str(" )")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A text search query: a boolean expression of words, which
+/// [`TsVector`]s may match.
+///
+/// A query may be empty, e.g. if all of its words were stop words, in which
+/// case it matches nothing.
+///
+/// See: <https://www.postgresql.org/docs/current/datatype-textsearch.html#DATATYPE-TSQUERY>
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TsQuery {
+    /// The root node of the query, if it is not empty.
+    pub root: Option<TsQueryNode>,
+}
+
+impl TsQuery {
+    /// Returns the distinct operands of the query, sorted by word.
+    pub fn operands(&self) -> Vec<&TsOperand> {
+        let mut operands = vec![];
+        if let Some(root) = &self.root {
+            root.for_each_operand(&mut |operand| operands.push(operand));
+        }
+        operands.sort_by(|a, b| a.word.cmp(&b.word));
+        operands.dedup_by(|a, b| a.word == b.word);
+        operands
+    }
+
+    /// Parses a query of the form `'word' & ( other:* | !third:AB ) <-> fourth`,
+    /// calling `normalize` on the text of each operand.
+    ///
+    /// `normalize` returns the normalized words of the operand, with their
+    /// positions. Operands with no words are removed from the query, and
+    /// operands with more than one word are replaced by a phrase of the words.
+    pub fn parse_with<F>(s: &str, mut normalize: F) -> Result<TsQuery, Error>
+    where
+        F: FnMut(&str) -> Vec<(String, u16)>,
+    {
+        let tokens = tokenize_query(s)?;
+        let mut parser = QueryParser {
+            tokens: &tokens,
+            pos: 0,
+            normalize: &mut normalize,
+        };
+        if tokens.is_empty() {
+            return Ok(TsQuery { root: None });
+        }
+        let root = parser.parse_or()?;
+        if parser.pos != tokens.len() {
+            bail!("unexpected {}", tokens[parser.pos]);
+        }
+        Ok(TsQuery { root })
+    }
+}
+
+impl FromStr for TsQuery {
+    type Err = Error;
+
+    /// Parses a query without normalizing its operands.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TsQuery::parse_with(s, |word| vec![(word.to_string(), 1)])
+    }
+}
+
+impl fmt::Display for TsQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.root {
+            None => Ok(()),
+            Some(root) => root.fmt_infix(f, 0, false),
+        }
+    }
+}
+
+/// A token of the text representation of a [`TsQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum QueryToken {
+    Operand {
+        text: String,
+        prefix: bool,
+        weights: u8,
+    },
+    Not,
+    And,
+    Or,
+    Phrase(u16),
+    LeftParen,
+    RightParen,
+}
+
+impl fmt::Display for QueryToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryToken::Operand { text, .. } => write!(f, "operand \"{text}\""),
+            QueryToken::Not => f.write_str("operator \"!\""),
+            QueryToken::And => f.write_str("operator \"&\""),
+            QueryToken::Or => f.write_str("operator \"|\""),
+            QueryToken::Phrase(distance) => write!(f, "operator \"<{distance}>\""),
+            QueryToken::LeftParen => f.write_str("\"(\""),
+            QueryToken::RightParen => f.write_str("\")\""),
+        }
+    }
+}
+
+fn tokenize_query(s: &str) -> Result<Vec<QueryToken>, Error> {
+    let mut chars = s.chars().peekable();
+    let mut tokens = vec![];
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '!' => QueryToken::Not,
+            '&' => QueryToken::And,
+            '|' => QueryToken::Or,
+            '(' => QueryToken::LeftParen,
+            ')' => QueryToken::RightParen,
+            '<' => {
+                let mut distance = String::new();
+                while let Some(c) = chars.next_if(|c| *c != '>') {
+                    distance.push(c);
+                }
+                if chars.next().is_none() {
+                    bail!("unterminated phrase operator");
+                }
+                let distance = match distance.trim() {
+                    "-" => 1,
+                    d => d
+                        .parse::<u16>()
+                        .ok()
+                        .filter(|d| *d <= MAX_PHRASE_DISTANCE)
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "distance in phrase operator must be an integer value \
+                                 between zero and {MAX_PHRASE_DISTANCE} inclusive"
+                            )
+                        })?,
+                };
+                QueryToken::Phrase(distance)
+            }
+            c => {
+                let mut text = String::new();
+                if c == '\'' {
+                    loop {
+                        match chars.next() {
+                            None => bail!("unterminated quoted string"),
+                            Some('\'') if chars.next_if_eq(&'\'').is_some() => text.push('\''),
+                            Some('\'') => break,
+                            Some('\\') => match chars.next() {
+                                None => bail!("there is no escaped character"),
+                                Some(c) => text.push(c),
+                            },
+                            Some(c) => text.push(c),
+                        }
+                    }
+                } else {
+                    let mut c = c;
+                    loop {
+                        match c {
+                            '\\' => match chars.next() {
+                                None => bail!("there is no escaped character"),
+                                Some(c) => text.push(c),
+                            },
+                            c => text.push(c),
+                        }
+                        match chars.next_if(|c| !c.is_whitespace() && !"!&|()<:".contains(*c)) {
+                            Some(next) => c = next,
+                            None => break,
+                        }
+                    }
+                }
+                let mut prefix = false;
+                let mut weights = 0;
+                if chars.next_if_eq(&':').is_some() {
+                    while let Some(c) =
+                        chars.next_if(|c| *c == '*' || TsWeight::from_char(*c).is_some())
+                    {
+                        match TsWeight::from_char(c) {
+                            Some(weight) => weights |= weight.mask(),
+                            None => prefix = true,
+                        }
+                    }
+                }
+                QueryToken::Operand {
+                    text,
+                    prefix,
+                    weights,
+                }
+            }
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// A recursive descent parser for the tokens of a [`TsQuery`].
+///
+/// Each method returns `None` if the parsed expression consisted entirely of
+/// operands that normalized to no words.
+struct QueryParser<'a, F> {
+    tokens: &'a [QueryToken],
+    pos: usize,
+    normalize: &'a mut F,
+}
+
+impl<'a, F> QueryParser<'a, F>
+where
+    F: FnMut(&str) -> Vec<(String, u16)>,
+{
+    fn next_if(&mut self, token: &QueryToken) -> bool {
+        if self.tokens.get(self.pos) == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Option<TsQueryNode>, Error> {
+        let mut node = self.parse_and()?;
+        while self.next_if(&QueryToken::Or) {
+            let right = self.parse_and()?;
+            node = combine(node, right, TsQueryNode::Or);
+        }
+        Ok(node)
+    }
+
+    fn parse_and(&mut self) -> Result<Option<TsQueryNode>, Error> {
+        let mut node = self.parse_phrase()?;
+        while self.next_if(&QueryToken::And) {
+            let right = self.parse_phrase()?;
+            node = combine(node, right, TsQueryNode::And);
+        }
+        Ok(node)
+    }
+
+    fn parse_phrase(&mut self) -> Result<Option<TsQueryNode>, Error> {
+        let mut node = self.parse_not()?;
+        while let Some(QueryToken::Phrase(distance)) = self.tokens.get(self.pos) {
+            let distance = *distance;
+            self.pos += 1;
+            let right = self.parse_not()?;
+            node = combine(node, right, |l, r| TsQueryNode::Phrase(l, r, distance));
+        }
+        Ok(node)
+    }
+
+    fn parse_not(&mut self) -> Result<Option<TsQueryNode>, Error> {
+        if self.next_if(&QueryToken::Not) {
+            Ok(self
+                .parse_not()?
+                .map(|node| TsQueryNode::Not(Box::new(node))))
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Option<TsQueryNode>, Error> {
+        match self.tokens.get(self.pos) {
+            Some(QueryToken::LeftParen) => {
+                self.pos += 1;
+                let node = self.parse_or()?;
+                if !self.next_if(&QueryToken::RightParen) {
+                    bail!("missing \")\"");
+                }
+                Ok(node)
+            }
+            Some(QueryToken::Operand {
+                text,
+                prefix,
+                weights,
+            }) => {
+                self.pos += 1;
+                let words = (self.normalize)(text);
+                let mut node: Option<TsQueryNode> = None;
+                let mut last_position = 0;
+                for (word, position) in words {
+                    let operand = TsQueryNode::Operand(TsOperand {
+                        word,
+                        prefix: *prefix,
+                        weights: *weights,
+                    });
+                    node = Some(match node {
+                        None => operand,
+                        Some(node) => TsQueryNode::Phrase(
+                            Box::new(node),
+                            Box::new(operand),
+                            position.saturating_sub(last_position),
+                        ),
+                    });
+                    last_position = position;
+                }
+                Ok(node)
+            }
+            Some(token) => bail!("unexpected {token}"),
+            None => bail!("unexpected end of input"),
+        }
+    }
+}
+
+/// Combines two optional nodes with a binary operator, dropping whichever
+/// node is absent.
+fn combine<F>(left: Option<TsQueryNode>, right: Option<TsQueryNode>, op: F) -> Option<TsQueryNode>
+where
+    F: FnOnce(Box<TsQueryNode>, Box<TsQueryNode>) -> TsQueryNode,
+{
+    match (left, right) {
+        (Some(left), Some(right)) => Some(op(Box::new(left), Box::new(right))),
+        (Some(node), None) | (None, Some(node)) => Some(node),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(s: &str) -> TsVector {
+        s.parse().unwrap()
+    }
+
+    fn query(s: &str) -> TsQuery {
+        s.parse().unwrap()
+    }
+
+    #[mz_ore::test]
+    fn test_vector_parse_display() {
+        for (input, output) in [
+            ("", ""),
+            ("a fat cat", "'a' 'cat' 'fat'"),
+            ("fat:2 cat:1,3 fat:1", "'cat':1,3 'fat':1,2"),
+            ("'it''s' 'a\\\\b'", "'a\\\\b' 'it''s'"),
+            ("a:1A,2b,3C,4d", "'a':1A,2B,3C,4"),
+            ("a:3,1,3A", "'a':1,3A"),
+            ("a:99999", "'a':16383"),
+        ] {
+            assert_eq!(vector(input).to_string(), output, "{input}");
+            assert_eq!(vector(output).to_string(), output, "{output}");
+        }
+        for input in ["'a", "a:", "a:0", "a:1x", "a\\"] {
+            assert!(input.parse::<TsVector>().is_err(), "{input} parsed");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_query_parse_display() {
+        for (input, output) in [
+            ("", ""),
+            ("fat", "'fat'"),
+            ("fat & rat", "'fat' & 'rat'"),
+            ("fat & (rat | cat)", "'fat' & ( 'rat' | 'cat' )"),
+            ("fat | rat & cat", "'fat' | 'rat' & 'cat'"),
+            ("!fat", "!'fat'"),
+            ("!(fat | rat)", "!( 'fat' | 'rat' )"),
+            ("fat <-> rat <2> cat", "'fat' <-> 'rat' <2> 'cat'"),
+            ("fat <-> (rat <-> cat)", "'fat' <-> ( 'rat' <-> 'cat' )"),
+            ("fat <-> (rat & cat)", "'fat' <-> ( 'rat' & 'cat' )"),
+            ("super:*AB", "'super':*AB"),
+            ("'it''s':ba", "'it''s':AB"),
+        ] {
+            assert_eq!(query(input).to_string(), output, "{input}");
+            assert_eq!(query(output).to_string(), output, "{output}");
+        }
+        for input in [
+            "fat &",
+            "(fat",
+            "fat)",
+            "fat <x> rat",
+            "fat <16385> rat",
+            "& fat",
+        ] {
+            assert!(input.parse::<TsQuery>().is_err(), "{input} parsed");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_matches() {
+        let doc = vector("a:1 fat:2 cat:3B sat:4 on:5 a:6 mat:7");
+        for (q, expected) in [
+            ("cat", true),
+            ("dog", false),
+            ("fat & cat", true),
+            ("fat & dog", false),
+            ("fat | dog", true),
+            ("!dog", true),
+            ("!cat", false),
+            ("ca:*", true),
+            ("cat:B", true),
+            ("cat:A", false),
+            ("fat <-> cat", true),
+            ("cat <-> fat", false),
+            ("fat <2> sat", true),
+            ("fat <-> sat", false),
+            ("a <-> (fat | mat)", true),
+            ("on <-> a <-> mat", true),
+            ("cat <-> !fat", true),
+            ("fat <-> !cat", false),
+            ("!a <-> fat", false),
+            ("!on <-> a", true),
+        ] {
+            assert_eq!(doc.matches(&query(q)), expected, "{q}");
+        }
+        assert!(!doc.matches(&query("")));
+
+        // Without positions, phrases match like conjunctions.
+        let stripped = vector("fat cat");
+        assert!(stripped.matches(&query("cat <-> fat")));
+        assert!(!stripped.matches(&query("cat <-> dog")));
+    }
+
+    #[mz_ore::test]
+    fn test_parse_with_normalization() {
+        let q = TsQuery::parse_with("The & supernovae_stars:* | a", |text| {
+            text.split('_')
+                .enumerate()
+                .filter(|(_, w)| !["the", "a"].contains(&w.to_lowercase().as_str()))
+                .map(|(i, w)| (w.to_lowercase(), u16::try_from(i + 1).unwrap()))
+                .collect()
+        })
+        .unwrap();
+        assert_eq!(q.to_string(), "'supernovae':* <-> 'stars':*");
+    }
+}
@@ -33,6 +33,7 @@ use crate::adt::array::{
     Array, ArrayDimension, ArrayDimensions, InvalidArrayError, MAX_ARRAY_DIMENSIONS,
 };
 use crate::adt::date::Date;
+use crate::adt::inet::Inet;
 use crate::adt::interval::Interval;
 use crate::adt::mz_acl_item::{AclItem, MzAclItem};
 use crate::adt::numeric;
@@ -497,6 +498,8 @@ enum Tag {
     UInt64_48,
     UInt64_56,
     UInt64_64,
+
+    Inet,
 }
 
 impl Tag {
@@ -1010,6 +1013,12 @@ pub unsafe fn read_datum<'a>(data: &'a [u8], offset: &mut usize) -> Datum<'a> {
                 .expect("invalid aclitem");
             Datum::AclItem(acl_item)
         }
+        Tag::Inet => {
+            const N: usize = Inet::binary_size();
+            let inet =
+                Inet::decode_binary(&read_byte_array::<N>(data, offset)).expect("invalid inet");
+            Datum::Inet(inet)
+        }
     }
 }
 
@@ -1378,6 +1387,10 @@ where
             data.push(Tag::AclItem.into());
             data.extend_from_slice(&acl_item.encode_binary());
         }
+        Datum::Inet(inet) => {
+            data.push(Tag::Inet.into());
+            data.extend_from_slice(&inet.encode_binary());
+        }
     }
 }
 
@@ -1490,6 +1503,7 @@ pub fn datum_size(datum: &Datum) -> usize {
         }
         Datum::MzAclItem(_) => 1 + MzAclItem::binary_size(),
         Datum::AclItem(_) => 1 + AclItem::binary_size(),
+        Datum::Inet(_) => 1 + Inet::binary_size(),
     }
 }
 
@@ -113,21 +113,42 @@ impl ColumnType {
             (true, Date) => Some(f.call::<Option<crate::adt::date::Date>>()),
             (false, PgLegacyChar) => Some(f.call::<u8>()),
             (true, PgLegacyChar) => Some(f.call::<Option<u8>>()),
-            (false, Bytes) => Some(f.call::<Vec<u8>>()),
-            (true, Bytes) => Some(f.call::<Option<Vec<u8>>>()),
-            (false, String | Char { .. } | VarChar { .. } | PgLegacyName) => {
-                Some(f.call::<std::string::String>())
-            }
-            (true, String | Char { .. } | VarChar { .. } | PgLegacyName) => {
-                Some(f.call::<Option<std::string::String>>())
-            }
+            (false, Bytes | Hll) => Some(f.call::<Vec<u8>>()),
+            (true, Bytes | Hll) => Some(f.call::<Option<Vec<u8>>>()),
+            (
+                false,
+                String
+                | Char { .. }
+                | VarChar { .. }
+                | PgLegacyName
+                | TsVector
+                | TsQuery
+                | JsonPath,
+            ) => Some(f.call::<std::string::String>()),
+            (
+                true,
+                String
+                | Char { .. }
+                | VarChar { .. }
+                | PgLegacyName
+                | TsVector
+                | TsQuery
+                | JsonPath,
+            ) => Some(f.call::<Option<std::string::String>>()),
             (false, Jsonb) => Some(f.call::<crate::adt::jsonb::Jsonb>()),
             (true, Jsonb) => Some(f.call::<Option<crate::adt::jsonb::Jsonb>>()),
             (false, MzTimestamp) => Some(f.call::<crate::Timestamp>()),
             (true, MzTimestamp) => Some(f.call::<Option<crate::Timestamp>>()),
             (
                 _,
-                Numeric { .. } | Time | Timestamp { .. } | TimestampTz { .. } | Interval | Uuid,
+                Numeric { .. }
+                | Time
+                | Timestamp { .. }
+                | TimestampTz { .. }
+                | Interval
+                | Uuid
+                | Inet
+                | Cidr,
             ) => {
                 if *nullable {
                     Some(f.call::<NullableProtoDatumToPersist>())
@@ -144,7 +165,8 @@ impl ColumnType {
                 | Int2Vector
                 | Range { .. }
                 | MzAclItem
-                | AclItem,
+                | AclItem
+                | Enum { .. },
             ) => None,
         }
     }
@@ -846,6 +868,7 @@ impl<'a> From<Datum<'a>> for ProtoDatum {
             })),
             Datum::MzAclItem(x) => DatumType::MzAclItem(x.into_proto()),
             Datum::AclItem(x) => DatumType::AclItem(x.into_proto()),
+            Datum::Inet(x) => DatumType::Inet(x.into_proto()),
         };
         ProtoDatum {
             datum_type: Some(datum_type),
@@ -995,6 +1018,7 @@ impl RowPacker<'_> {
             }
             Some(DatumType::MzAclItem(x)) => self.push(Datum::MzAclItem(x.clone().into_rust()?)),
             Some(DatumType::AclItem(x)) => self.push(Datum::AclItem(x.clone().into_rust()?)),
+            Some(DatumType::Inet(x)) => self.push(Datum::Inet(x.clone().into_rust()?)),
             None => return Err("unknown datum type".into()),
         };
         Ok(())
@@ -1102,6 +1126,8 @@ mod tests {
             Datum::from(Numeric::nan()),
             Datum::JsonNull,
             Datum::Uuid(Uuid::from_u128(30)),
+            Datum::Inet("192.168.1.5/24".parse().unwrap()),
+            Datum::Inet("2001:db8::/32".parse().unwrap()),
             Datum::Dummy,
             Datum::Null,
         ]);
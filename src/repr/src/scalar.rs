@@ -28,13 +28,18 @@ use uuid::Uuid;
 use crate::adt::array::{Array, ArrayDimension};
 use crate::adt::char::{Char, CharLength};
 use crate::adt::date::Date;
+use crate::adt::enum_type::EnumValue;
+use crate::adt::hll::Hll;
+use crate::adt::inet::{Cidr, Inet};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
+use crate::adt::jsonpath::JsonPath;
 use crate::adt::mz_acl_item::{AclItem, AclMode, MzAclItem};
 use crate::adt::numeric::{Numeric, NumericMaxScale};
 use crate::adt::pg_legacy_name::PgLegacyName;
 use crate::adt::range::{Range, RangeLowerBound, RangeUpperBound};
 use crate::adt::system::{Oid, PgLegacyChar, RegClass, RegProc, RegType};
+use crate::adt::text_search::{TsQuery, TsVector};
 use crate::adt::timestamp::{CheckedTimestamp, TimestampError, TimestampPrecision};
 use crate::adt::varchar::{VarChar, VarCharMaxLength};
 pub use crate::relation_and_scalar::proto_scalar_type::ProtoRecordField;
@@ -130,6 +135,8 @@ pub enum Datum<'a> {
     /// A list of privileges granted to a user that uses [`Oid`]s for role references.
     /// This type is used primarily for compatibility with PostgreSQL.
     AclItem(AclItem),
+    /// An IPv4 or IPv6 address with a network prefix length.
+    Inet(Inet),
     /// A placeholder value.
     ///
     /// Dummy values are never meant to be observed. Many operations on `Datum`
@@ -903,6 +910,19 @@ impl<'a> Datum<'a> {
         }
     }
 
+    /// Unwraps the network address value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::Inet`].
+    #[track_caller]
+    pub fn unwrap_inet(&self) -> Inet {
+        match self {
+            Datum::Inet(inet) => *inet,
+            _ => panic!("Datum::unwrap_inet called on {:?}", self),
+        }
+    }
+
     /// Reports whether this datum is an instance of the specified column type.
     pub fn is_instance_of(self, column_type: &ColumnType) -> bool {
         fn is_instance_of_scalar(datum: Datum, scalar_type: &ScalarType) -> bool {
@@ -964,11 +984,15 @@ impl<'a> Datum<'a> {
                     (Datum::Interval(_), ScalarType::Interval) => true,
                     (Datum::Interval(_), _) => false,
                     (Datum::Bytes(_), ScalarType::Bytes) => true,
+                    (Datum::Bytes(b), ScalarType::Hll) => Hll::decode(b).is_ok(),
                     (Datum::Bytes(_), _) => false,
                     (Datum::String(_), ScalarType::String)
                     | (Datum::String(_), ScalarType::VarChar { .. })
                     | (Datum::String(_), ScalarType::Char { .. })
-                    | (Datum::String(_), ScalarType::PgLegacyName) => true,
+                    | (Datum::String(_), ScalarType::PgLegacyName)
+                    | (Datum::String(_), ScalarType::TsVector)
+                    | (Datum::String(_), ScalarType::TsQuery)
+                    | (Datum::String(_), ScalarType::JsonPath) => true,
                     (Datum::String(_), _) => false,
                     (Datum::Uuid(_), ScalarType::Uuid) => true,
                     (Datum::Uuid(_), _) => false,
@@ -994,6 +1018,9 @@ impl<'a> Datum<'a> {
                             (e.is_null() && t.nullable) || is_instance_of_scalar(e, &t.scalar_type)
                         })
                     }
+                    (list @ Datum::List(_), ScalarType::Enum { .. }) => {
+                        EnumValue::try_from_datum(list).is_some()
+                    }
                     (Datum::List(_), _) => false,
                     (Datum::Map(map), ScalarType::Map { value_type, .. }) => map
                         .iter()
@@ -1023,6 +1050,9 @@ impl<'a> Datum<'a> {
                     (Datum::MzAclItem(_), _) => false,
                     (Datum::AclItem(_), ScalarType::AclItem) => true,
                     (Datum::AclItem(_), _) => false,
+                    (Datum::Inet(_), ScalarType::Inet) => true,
+                    (Datum::Inet(inet), ScalarType::Cidr) => inet.is_network(),
+                    (Datum::Inet(_), _) => false,
                 }
             }
         }
@@ -1248,6 +1278,13 @@ impl<'a> From<MzAclItem> for Datum<'a> {
     }
 }
 
+impl<'a> From<Inet> for Datum<'a> {
+    #[inline]
+    fn from(inet: Inet) -> Self {
+        Datum::Inet(inet)
+    }
+}
+
 impl<'a, T> From<Option<T>> for Datum<'a>
 where
     Datum<'a>: From<T>,
@@ -1348,6 +1385,7 @@ impl fmt::Display for Datum<'_> {
             Datum::Range(i) => write!(f, "{}", i),
             Datum::MzAclItem(mz_acl_item) => write!(f, "{mz_acl_item}"),
             Datum::AclItem(acl_item) => write!(f, "{acl_item}"),
+            Datum::Inet(inet) => write!(f, "{inet}"),
         }
     }
 }
@@ -1497,6 +1535,37 @@ pub enum ScalarType {
     MzAclItem,
     /// The type of [`Datum::AclItem`]
     AclItem,
+    /// A user-defined enumerated type, whose labels live in the catalog.
+    ///
+    /// See [`crate::adt::enum_type`] for the representation of its values.
+    Enum {
+        custom_id: GlobalId,
+        /// The OID of the type, which is reported to clients in place of the
+        /// OID of a builtin type.
+        custom_oid: u32,
+    },
+    /// An IPv4 or IPv6 host address and, optionally, its network. The type
+    /// of [`Datum::Inet`].
+    Inet,
+    /// An IPv4 or IPv6 network. Values are [`Datum::Inet`]s without any bits
+    /// set to the right of their prefix.
+    Cidr,
+    /// A document optimized for text search. Values are [`Datum::String`]s
+    /// containing the canonical text representation of a
+    /// [`TsVector`](crate::adt::text_search::TsVector).
+    TsVector,
+    /// A text search query. Values are [`Datum::String`]s containing the
+    /// canonical text representation of a
+    /// [`TsQuery`](crate::adt::text_search::TsQuery).
+    TsQuery,
+    /// A SQL/JSON path. Values are [`Datum::String`]s containing the
+    /// canonical text representation of a
+    /// [`JsonPath`](crate::adt::jsonpath::JsonPath).
+    JsonPath,
+    /// A HyperLogLog sketch for approximate distinct counting. Values are
+    /// [`Datum::Bytes`] containing the canonical binary encoding of an
+    /// [`Hll`](crate::adt::hll::Hll).
+    Hll,
 }
 
 impl RustType<ProtoRecordField> for (ColumnName, ColumnType) {
@@ -1589,6 +1658,19 @@ impl RustType<ProtoScalarType> for ScalarType {
                 })),
                 ScalarType::MzAclItem => MzAclItem(()),
                 ScalarType::AclItem => AclItem(()),
+                ScalarType::Enum {
+                    custom_id,
+                    custom_oid,
+                } => Enum(ProtoEnum {
+                    custom_id: Some(custom_id.into_proto()),
+                    custom_oid: *custom_oid,
+                }),
+                ScalarType::Inet => Inet(()),
+                ScalarType::Cidr => Cidr(()),
+                ScalarType::TsVector => TsVector(()),
+                ScalarType::TsQuery => TsQuery(()),
+                ScalarType::JsonPath => JsonPath(()),
+                ScalarType::Hll => Hll(()),
             }),
         }
     }
@@ -1675,6 +1757,16 @@ impl RustType<ProtoScalarType> for ScalarType {
             }),
             MzAclItem(()) => Ok(ScalarType::MzAclItem),
             AclItem(()) => Ok(ScalarType::AclItem),
+            Enum(x) => Ok(ScalarType::Enum {
+                custom_id: x.custom_id.into_rust_if_some("ProtoEnum::custom_id")?,
+                custom_oid: x.custom_oid,
+            }),
+            Inet(()) => Ok(ScalarType::Inet),
+            Cidr(()) => Ok(ScalarType::Cidr),
+            TsVector(()) => Ok(ScalarType::TsVector),
+            TsQuery(()) => Ok(ScalarType::TsQuery),
+            JsonPath(()) => Ok(ScalarType::JsonPath),
+            Hll(()) => Ok(ScalarType::Hll),
         }
     }
 }
@@ -1805,6 +1897,7 @@ impl_datum_type_copy!(Uuid, Uuid);
 impl_datum_type_copy!('a, &'a str, String);
 impl_datum_type_copy!('a, &'a [u8], Bytes);
 impl_datum_type_copy!(crate::Timestamp, MzTimestamp);
+impl_datum_type_copy!(Inet, Inet);
 
 impl<'a, E> DatumType<'a, E> for Datum<'a> {
     fn nullable() -> bool {
@@ -2469,6 +2562,189 @@ impl<'a, E> DatumType<'a, E> for AclItem {
     }
 }
 
+impl AsColumnType for Cidr {
+    fn as_column_type() -> ColumnType {
+        ScalarType::Cidr.nullable(false)
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for Cidr {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn fallible() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(Datum::Inet(inet)) => Ok(Cidr(inet)),
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, _temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(Datum::Inet(self.0))
+    }
+}
+
+impl AsColumnType for TsVector {
+    fn as_column_type() -> ColumnType {
+        ScalarType::TsVector.nullable(false)
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for TsVector {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn fallible() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(Datum::String(s)) => s.parse().map_err(|_| res),
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(Datum::String(temp_storage.push_string(self.to_string())))
+    }
+}
+
+impl AsColumnType for TsQuery {
+    fn as_column_type() -> ColumnType {
+        ScalarType::TsQuery.nullable(false)
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for TsQuery {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn fallible() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(Datum::String(s)) => s.parse().map_err(|_| res),
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(Datum::String(temp_storage.push_string(self.to_string())))
+    }
+}
+
+impl AsColumnType for JsonPath {
+    fn as_column_type() -> ColumnType {
+        ScalarType::JsonPath.nullable(false)
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for JsonPath {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn fallible() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(Datum::String(s)) => s.parse().map_err(|_| res),
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(Datum::String(temp_storage.push_string(self.to_string())))
+    }
+}
+
+impl AsColumnType for Hll {
+    fn as_column_type() -> ColumnType {
+        ScalarType::Hll.nullable(false)
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for Hll {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn fallible() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(Datum::Bytes(b)) => Hll::decode(b).map_err(|_| res),
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(Datum::Bytes(temp_storage.push_bytes(self.encode())))
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for EnumValue<&'a str> {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn fallible() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(datum) => EnumValue::try_from_datum(datum).ok_or(res),
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(temp_storage.make_datum(|packer| self.push(packer)))
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for EnumValue<String> {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn fallible() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(datum) => match EnumValue::try_from_datum(datum) {
+                Some(EnumValue { position, label }) => Ok(EnumValue {
+                    position,
+                    label: label.to_owned(),
+                }),
+                None => Err(res),
+            },
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(temp_storage.make_datum(|packer| self.push(packer)))
+    }
+}
+
 impl AsColumnType for CheckedTimestamp<NaiveDateTime> {
     fn as_column_type() -> ColumnType {
         ScalarType::Timestamp { precision: None }.nullable(false)
@@ -2836,6 +3112,22 @@ impl ScalarType {
                         .map(|(_, t)| t)
                         .any(|t| t.scalar_type.is_custom_type())
             }
+            Enum { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Returns whether `self` is or contains a [`ScalarType::Enum`].
+    pub fn contains_enum(&self) -> bool {
+        use ScalarType::*;
+        match self {
+            Enum { .. } => true,
+            Array(t) => t.contains_enum(),
+            List {
+                element_type: t, ..
+            }
+            | Map { value_type: t, .. } => t.contains_enum(),
+            Record { fields, .. } => fields.iter().any(|(_, t)| t.scalar_type.contains_enum()),
             _ => false,
         }
     }
@@ -2909,6 +3201,9 @@ impl ScalarType {
                                 && a.1.scalar_type.eq_inner(&b.1.scalar_type, structure_only)
                         })
             }
+            // Values of an enum type are only interoperable with values of
+            // the same type.
+            (Enum { custom_id: a, .. }, Enum { custom_id: b, .. }) => a == b || structure_only,
             (s, o) => ScalarBaseType::from(s) == ScalarBaseType::from(o),
         }
     }
@@ -3267,6 +3562,72 @@ impl ScalarType {
         });
         // aclitem has no binary encoding so we can't test it here.
         static ACLITEM: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
+        static ENUM: Lazy<Row> = Lazy::new(|| {
+            let mut row = Row::default();
+            let mut packer = row.packer();
+            for (position, label) in [(0, ""), (1, "a label"), (u32::MAX, "🌍")] {
+                EnumValue { position, label }.push(&mut packer);
+            }
+            row
+        });
+        static INET: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::Inet("0.0.0.0/0".parse().unwrap()),
+                Datum::Inet("192.168.1.5".parse().unwrap()),
+                Datum::Inet("192.168.1.5/24".parse().unwrap()),
+                Datum::Inet("255.255.255.255".parse().unwrap()),
+                Datum::Inet("::/0".parse().unwrap()),
+                Datum::Inet("::1".parse().unwrap()),
+                Datum::Inet("2001:db8::1/32".parse().unwrap()),
+            ])
+        });
+        static HLL: Lazy<Row> = Lazy::new(|| {
+            let empty = Hll::default().encode();
+            let single = Hll::from_hash(0).encode();
+            let mut full = Hll::default();
+            for index in 0..u64::try_from(crate::adt::hll::NUM_REGISTERS).expect("fits") {
+                full.insert_hash(index << (64 - crate::adt::hll::PRECISION));
+            }
+            let full = full.encode();
+            Row::pack_slice(&[
+                Datum::Bytes(&empty),
+                Datum::Bytes(&single),
+                Datum::Bytes(&full),
+            ])
+        });
+        static TSVECTOR: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::String(""),
+                Datum::String("'a' 'cat' 'fat'"),
+                Datum::String("'cat':1,3 'fat':2A"),
+                Datum::String("'it''s':1B"),
+            ])
+        });
+        static TSQUERY: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::String(""),
+                Datum::String("'fat'"),
+                Datum::String("'fat' & ( 'rat' | !'cat' )"),
+                Datum::String("'super':*AB <-> 'nova'"),
+            ])
+        });
+        static JSONPATH: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::String("$"),
+                Datum::String("strict $.\"a\"[*]"),
+                Datum::String("$.\"a\"?(@ > 1 && @.\"b\" like_regex \"^x\" flag \"i\")"),
+                Datum::String("($.\"a\".size() + $\"x\")"),
+            ])
+        });
+        static CIDR: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::Inet("0.0.0.0/0".parse().unwrap()),
+                Datum::Inet("192.168.1.0/24".parse().unwrap()),
+                Datum::Inet("255.255.255.255/32".parse().unwrap()),
+                Datum::Inet("::/0".parse().unwrap()),
+                Datum::Inet("2001:db8::/32".parse().unwrap()),
+            ])
+        });
 
         match self {
             ScalarType::Bool => (*BOOL).iter(),
@@ -3305,6 +3666,13 @@ impl ScalarType {
             ScalarType::Range { .. } => (*RANGE).iter(),
             ScalarType::MzAclItem { .. } => (*MZACLITEM).iter(),
             ScalarType::AclItem { .. } => (*ACLITEM).iter(),
+            ScalarType::Enum { .. } => (*ENUM).iter(),
+            ScalarType::Inet => (*INET).iter(),
+            ScalarType::Cidr => (*CIDR).iter(),
+            ScalarType::TsVector => (*TSVECTOR).iter(),
+            ScalarType::TsQuery => (*TSQUERY).iter(),
+            ScalarType::JsonPath => (*JSONPATH).iter(),
+            ScalarType::Hll => (*HLL).iter(),
         }
     }
 
@@ -3361,6 +3729,12 @@ impl ScalarType {
             ScalarType::Int2Vector,
             ScalarType::MzTimestamp,
             ScalarType::MzAclItem,
+            ScalarType::Inet,
+            ScalarType::Cidr,
+            ScalarType::TsVector,
+            ScalarType::TsQuery,
+            ScalarType::JsonPath,
+            ScalarType::Hll,
             // TODO: Fill in some variants of these.
             /*
             ScalarType::AclItem,
@@ -3421,7 +3795,13 @@ impl ScalarType {
             | ScalarType::Int2Vector
             | ScalarType::MzTimestamp
             | ScalarType::Range { .. }
-            | ScalarType::MzAclItem { .. }) => Ok(t),
+            | ScalarType::MzAclItem { .. }
+            | ScalarType::Inet
+            | ScalarType::Cidr
+            | ScalarType::TsVector
+            | ScalarType::TsQuery
+            | ScalarType::JsonPath
+            | ScalarType::Hll) => Ok(t),
 
             ScalarType::Array(elem) => Ok(elem.array_of_self_elem_type()?),
 
@@ -3429,7 +3809,9 @@ impl ScalarType {
             t @ (ScalarType::Char { .. }
             // not sensible to put in arrays
             | ScalarType::Map { .. }
-            | ScalarType::List { .. }) => Err(t),
+            | ScalarType::List { .. }
+            // enum types do not have corresponding array types
+            | ScalarType::Enum { .. }) => Err(t),
         }
     }
 }
@@ -52,12 +52,16 @@ use uuid::Uuid;
 use crate::adt::array::ArrayDimension;
 use crate::adt::date::Date;
 use crate::adt::datetime::{self, DateTimeField, ParsedDateTime};
+use crate::adt::hll::Hll;
+use crate::adt::inet::{Cidr, Inet};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
+use crate::adt::jsonpath::JsonPath;
 use crate::adt::mz_acl_item::{AclItem, MzAclItem};
 use crate::adt::numeric::{self, Numeric, NUMERIC_DATUM_MAX_PRECISION};
 use crate::adt::pg_legacy_name::NAME_MAX_BYTES;
 use crate::adt::range::{Range, RangeBound, RangeInner};
+use crate::adt::text_search::{TsQuery, TsVector};
 use crate::adt::timestamp::CheckedTimestamp;
 
 include!(concat!(env!("OUT_DIR"), "/mz_repr.strconv.rs"));
@@ -247,6 +251,17 @@ pub fn parse_oid(s: &str) -> Result<u32, ParseError> {
     Ok(u32::reinterpret_cast(oid))
 }
 
+/// Parses an enum label from `s`, returning its position within `labels`.
+///
+/// Unlike most other types, enum labels are matched exactly: surrounding
+/// whitespace and case are significant.
+pub fn parse_enum(labels: &[String], s: &str) -> Result<u32, ParseError> {
+    match labels.iter().position(|l| l == s) {
+        Some(i) => Ok(u32::try_from(i).expect("enum label count fits in u32")),
+        None => Err(ParseError::invalid_input_syntax("enum", s)),
+    }
+}
+
 fn parse_float<Fl>(type_name: &'static str, s: &str) -> Result<Fl, ParseError>
 where
     Fl: NumFloat + FastFloat,
@@ -1727,6 +1742,116 @@ pub fn parse_acl_item(s: &str) -> Result<AclItem, ParseError> {
         .map_err(|e| ParseError::invalid_input_syntax("aclitem", s).with_details(e))
 }
 
+/// Parses an `inet` from `s`.
+pub fn parse_inet(s: &str) -> Result<Inet, ParseError> {
+    s.trim()
+        .parse()
+        .map_err(|e| ParseError::invalid_input_syntax("inet", s).with_details(e))
+}
+
+/// Writes an `inet` to `buf`.
+pub fn format_inet<F>(buf: &mut F, inet: Inet) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{inet}");
+    Nestable::Yes
+}
+
+/// Parses a `cidr` from `s`, which, unlike an `inet`, must not have any bits
+/// set to the right of its prefix.
+pub fn parse_cidr(s: &str) -> Result<Cidr, ParseError> {
+    let inet: Inet = s
+        .trim()
+        .parse()
+        .map_err(|e| ParseError::invalid_input_syntax("cidr", s).with_details(e))?;
+    if !inet.is_network() {
+        return Err(ParseError::invalid_input_syntax("cidr", s)
+            .with_details("Value has bits set to right of mask."));
+    }
+    Ok(Cidr(inet))
+}
+
+/// Writes a `cidr` to `buf`. Unlike an `inet`, a `cidr` is always written
+/// with its prefix length.
+pub fn format_cidr<F>(buf: &mut F, cidr: Cidr) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{cidr}");
+    Nestable::Yes
+}
+
+/// Parses a `tsvector` from `s`.
+pub fn parse_tsvector(s: &str) -> Result<TsVector, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("tsvector", s).with_details(e))
+}
+
+/// Writes a `tsvector` to `buf`.
+pub fn format_tsvector<F>(buf: &mut F, tsvector: &TsVector) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{tsvector}");
+    Nestable::MayNeedEscaping
+}
+
+/// Parses a `tsquery` from `s`.
+pub fn parse_tsquery(s: &str) -> Result<TsQuery, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("tsquery", s).with_details(e))
+}
+
+/// Parses a `tsquery` from `s`, normalizing each operand with `normalize`, as
+/// described in [`TsQuery::parse_with`].
+pub fn parse_tsquery_with<F>(s: &str, normalize: F) -> Result<TsQuery, ParseError>
+where
+    F: FnMut(&str) -> Vec<(String, u16)>,
+{
+    TsQuery::parse_with(s, normalize)
+        .map_err(|e| ParseError::invalid_input_syntax("tsquery", s).with_details(e))
+}
+
+/// Writes a `tsquery` to `buf`.
+pub fn format_tsquery<F>(buf: &mut F, tsquery: &TsQuery) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{tsquery}");
+    Nestable::MayNeedEscaping
+}
+
+/// Parses a `jsonpath` from `s`.
+pub fn parse_jsonpath(s: &str) -> Result<JsonPath, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("jsonpath", s).with_details(e))
+}
+
+/// Writes a `jsonpath` to `buf`.
+pub fn format_jsonpath<F>(buf: &mut F, jsonpath: &JsonPath) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{jsonpath}");
+    Nestable::MayNeedEscaping
+}
+
+/// Parses an `hll` from `s`.
+pub fn parse_hll(s: &str) -> Result<Hll, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("hll", s).with_details(e))
+}
+
+/// Writes an `hll` to `buf`.
+pub fn format_hll<F>(buf: &mut F, hll: &Hll) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{hll}");
+    Nestable::Yes
+}
+
 pub trait ElementEscaper {
     fn needs_escaping(elem: &[u8]) -> bool;
     fn escape_char(c: u8) -> u8;
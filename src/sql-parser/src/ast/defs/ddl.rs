@@ -909,6 +909,124 @@ impl<T: AstInfo> AstDisplay for KafkaSinkConfigOption<T> {
 }
 impl_display_t!(KafkaSinkConfigOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PostgresSinkConfigOptionName {
+    /// The name of the upstream table to keep in sync with the sinked relation.
+    Table,
+    /// The name of the upstream table in which the sink records its progress.
+    ProgressTable,
+}
+
+impl AstDisplay for PostgresSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            PostgresSinkConfigOptionName::Table => "TABLE",
+            PostgresSinkConfigOptionName::ProgressTable => "PROGRESS TABLE",
+        })
+    }
+}
+impl_display!(PostgresSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PostgresSinkConfigOption<T: AstInfo> {
+    pub name: PostgresSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for PostgresSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(PostgresSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WebhookSinkConfigOptionName {
+    /// The endpoint to which the sink POSTs its batches.
+    ///
+    /// The sink does not record its progress in Materialize. Instead, the
+    /// endpoint must durably record the `frontier` of every batch it accepts,
+    /// return the last recorded frontier in response to a `GET`, and ignore
+    /// batches whose `frontier` is not beyond the recorded one. Delivery is
+    /// only exactly-once for endpoints that uphold this contract.
+    Url,
+    /// The secret used to sign each request with an HMAC.
+    Secret,
+}
+
+impl AstDisplay for WebhookSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            WebhookSinkConfigOptionName::Url => "URL",
+            WebhookSinkConfigOptionName::Secret => "SECRET",
+        })
+    }
+}
+impl_display!(WebhookSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WebhookSinkConfigOption<T: AstInfo> {
+    pub name: WebhookSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for WebhookSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(WebhookSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IcebergSinkConfigOptionName {
+    /// The AWS connection used to access the bucket of the table.
+    AwsConnection,
+    /// The `s3://` URI of the Iceberg table.
+    ///
+    /// Object stores cannot atomically create the next metadata version of
+    /// the table, so sinks in clusters with more than one replica are
+    /// rejected.
+    Path,
+    /// How often the sink commits a new snapshot to the table.
+    CommitInterval,
+}
+
+impl AstDisplay for IcebergSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            IcebergSinkConfigOptionName::AwsConnection => "AWS CONNECTION",
+            IcebergSinkConfigOptionName::Path => "PATH",
+            IcebergSinkConfigOptionName::CommitInterval => "COMMIT INTERVAL",
+        })
+    }
+}
+impl_display!(IcebergSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IcebergSinkConfigOption<T: AstInfo> {
+    pub name: IcebergSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for IcebergSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(IcebergSinkConfigOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PgConfigOptionName {
     /// Hex encoded string of binary serialization of
@@ -918,6 +1036,9 @@ pub enum PgConfigOptionName {
     Publication,
     /// Columns whose types you want to unconditionally format as text
     TextColumns,
+    /// Columns of upstream enum types, paired with the enum types they are
+    /// ingested as
+    EnumColumns,
 }
 
 impl AstDisplay for PgConfigOptionName {
@@ -926,6 +1047,7 @@ impl AstDisplay for PgConfigOptionName {
             PgConfigOptionName::Details => "DETAILS",
             PgConfigOptionName::Publication => "PUBLICATION",
             PgConfigOptionName::TextColumns => "TEXT COLUMNS",
+            PgConfigOptionName::EnumColumns => "ENUM COLUMNS",
         })
     }
 }
@@ -1130,6 +1252,19 @@ pub enum CreateSinkConnection<T: AstInfo> {
         options: Vec<KafkaSinkConfigOption<T>>,
         key: Option<KafkaSinkKey>,
     },
+    Postgres {
+        connection: T::ItemName,
+        options: Vec<PostgresSinkConfigOption<T>>,
+        key: Option<KafkaSinkKey>,
+    },
+    Webhook {
+        options: Vec<WebhookSinkConfigOption<T>>,
+        key: Option<KafkaSinkKey>,
+    },
+    Iceberg {
+        options: Vec<IcebergSinkConfigOption<T>>,
+        key: Option<KafkaSinkKey>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
@@ -1151,6 +1286,38 @@ impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
                     f.write_node(key);
                 }
             }
+            CreateSinkConnection::Postgres {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("POSTGRES CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::Webhook { options, key } => {
+                f.write_str("WEBHOOK (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::Iceberg { options, key } => {
+                f.write_str("ICEBERG (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
+            }
         }
     }
 }
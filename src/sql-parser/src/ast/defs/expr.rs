@@ -693,7 +693,7 @@ impl_display_t!(SubscriptPosition);
 pub struct WindowSpec<T: AstInfo> {
     pub partition_by: Vec<Expr<T>>,
     pub order_by: Vec<OrderByExpr<T>>,
-    pub window_frame: Option<WindowFrame>,
+    pub window_frame: Option<WindowFrame<T>>,
     // Note that IGNORE NULLS and RESPECT NULLS are mutually exclusive. We validate that not both
     // are present during HIR planning.
     pub ignore_nulls: bool,
@@ -747,13 +747,13 @@ impl_display_t!(WindowSpec);
 /// Note: The parser does not validate the specified bounds; the caller should
 /// reject invalid bounds like `ROWS UNBOUNDED FOLLOWING` before execution.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct WindowFrame {
+pub struct WindowFrame<T: AstInfo> {
     pub units: WindowFrameUnits,
-    pub start_bound: WindowFrameBound,
+    pub start_bound: WindowFrameBound<T>,
     /// The right bound of the `BETWEEN .. AND` clause. The end bound of `None`
     /// indicates the shorthand form (e.g. `ROWS 1 PRECEDING`), which must
     /// behave the same as `end_bound = WindowFrameBound::CurrentRow`.
-    pub end_bound: Option<WindowFrameBound>,
+    pub end_bound: Option<WindowFrameBound<T>>,
     // TBD: EXCLUDE
 }
 
@@ -777,33 +777,33 @@ impl_display!(WindowFrameUnits);
 
 /// Specifies [WindowFrame]'s `start_bound` and `end_bound`
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum WindowFrameBound {
+pub enum WindowFrameBound<T: AstInfo> {
     /// `CURRENT ROW`
     CurrentRow,
-    /// `<N> PRECEDING` or `UNBOUNDED PRECEDING`
-    Preceding(Option<u64>),
-    /// `<N> FOLLOWING` or `UNBOUNDED FOLLOWING`.
-    Following(Option<u64>),
+    /// `<offset> PRECEDING` or `UNBOUNDED PRECEDING`
+    Preceding(Option<Box<Expr<T>>>),
+    /// `<offset> FOLLOWING` or `UNBOUNDED FOLLOWING`.
+    Following(Option<Box<Expr<T>>>),
 }
 
-impl AstDisplay for WindowFrameBound {
+impl<T: AstInfo> AstDisplay for WindowFrameBound<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             WindowFrameBound::CurrentRow => f.write_str("CURRENT ROW"),
             WindowFrameBound::Preceding(None) => f.write_str("UNBOUNDED PRECEDING"),
             WindowFrameBound::Following(None) => f.write_str("UNBOUNDED FOLLOWING"),
-            WindowFrameBound::Preceding(Some(n)) => {
-                f.write_str(n);
+            WindowFrameBound::Preceding(Some(offset)) => {
+                f.write_node(offset);
                 f.write_str(" PRECEDING");
             }
-            WindowFrameBound::Following(Some(n)) => {
-                f.write_str(n);
+            WindowFrameBound::Following(Some(offset)) => {
+                f.write_node(offset);
                 f.write_str(" FOLLOWING");
             }
         }
     }
 }
-impl_display!(WindowFrameBound);
+impl_display_t!(WindowFrameBound);
 
 /// A function call
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -853,6 +853,11 @@ impl<T: AstInfo> AstDisplay for Function<T> {
         }
         f.write_node(&self.args);
         f.write_str(")");
+        if let FunctionArgs::WithinGroup { order_by, .. } = &self.args {
+            f.write_str(" WITHIN GROUP (ORDER BY ");
+            f.write_node(&display::comma_separated(order_by));
+            f.write_str(")");
+        }
         if let Some(filter) = &self.filter {
             f.write_str(" FILTER (WHERE ");
             f.write_node(&filter);
@@ -875,6 +880,13 @@ pub enum FunctionArgs<T: AstInfo> {
         args: Vec<Expr<T>>,
         order_by: Vec<OrderByExpr<T>>,
     },
+    /// The direct arguments of an ordered-set aggregate, along with the
+    /// ordering from its `WITHIN GROUP (ORDER BY ...)` clause, as in
+    /// `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`.
+    WithinGroup {
+        args: Vec<Expr<T>>,
+        order_by: Vec<OrderByExpr<T>>,
+    },
 }
 
 impl<T: AstInfo> FunctionArgs<T> {
@@ -889,7 +901,9 @@ impl<T: AstInfo> FunctionArgs<T> {
     pub fn len(&self) -> Option<usize> {
         match self {
             FunctionArgs::Star => None,
-            FunctionArgs::Args { args, .. } => Some(args.len()),
+            FunctionArgs::Args { args, .. } | FunctionArgs::WithinGroup { args, .. } => {
+                Some(args.len())
+            }
         }
     }
 
@@ -901,7 +915,7 @@ impl<T: AstInfo> FunctionArgs<T> {
     ) {
         let args = match self {
             FunctionArgs::Star => unreachable!(),
-            FunctionArgs::Args { args, .. } => args,
+            FunctionArgs::Args { args, .. } | FunctionArgs::WithinGroup { args, .. } => args,
         };
         soft_assert_eq_or_log!(args.len(), kws.len());
         let mut delim = "";
@@ -929,6 +943,11 @@ impl<T: AstInfo> AstDisplay for FunctionArgs<T> {
                     f.write_node(&display::comma_separated(order_by));
                 }
             }
+            // The ordering is printed by `Function`, after the closing
+            // parenthesis.
+            FunctionArgs::WithinGroup { args, .. } => {
+                f.write_node(&display::comma_separated(args));
+            }
         }
     }
 }
@@ -319,11 +319,13 @@ impl<T: AstInfo> AstDisplay for Distinct<T> {
 /// A block of common table expressions (CTEs).
 ///
 /// The block can either be entirely "simple" (traditional SQL `WITH` block),
-/// or "mutually recursive", which introduce their bindings before the block
-/// and may result in mutually recursive definitions.
+/// "recursive" (SQL `WITH RECURSIVE` block), whose bindings may reference
+/// themselves, or "mutually recursive", which introduce their bindings before
+/// the block and may result in mutually recursive definitions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum CteBlock<T: AstInfo> {
     Simple(Vec<Cte<T>>),
+    Recursive(Vec<Cte<T>>),
     MutuallyRecursive(MutRecBlock<T>),
 }
 
@@ -341,7 +343,7 @@ impl<T: AstInfo> CteBlock<T> {
     /// True if there are no bindings in the block.
     pub fn is_empty(&self) -> bool {
         match self {
-            CteBlock::Simple(list) => list.is_empty(),
+            CteBlock::Simple(list) | CteBlock::Recursive(list) => list.is_empty(),
             CteBlock::MutuallyRecursive(list) => list.ctes.is_empty(),
         }
     }
@@ -349,7 +351,7 @@ impl<T: AstInfo> CteBlock<T> {
     pub fn bound_identifiers(&self) -> impl Iterator<Item = &Ident> {
         let mut names = Vec::new();
         match self {
-            CteBlock::Simple(list) => {
+            CteBlock::Simple(list) | CteBlock::Recursive(list) => {
                 for cte in list.iter() {
                     names.push(&cte.alias.name);
                 }
@@ -372,6 +374,10 @@ impl<T: AstInfo> AstDisplay for CteBlock<T> {
                     f.write_str("WITH ");
                     f.write_node(&display::comma_separated(list));
                 }
+                CteBlock::Recursive(list) => {
+                    f.write_str("WITH RECURSIVE ");
+                    f.write_node(&display::comma_separated(list));
+                }
                 CteBlock::MutuallyRecursive(MutRecBlock { options, ctes }) => {
                     f.write_str("WITH MUTUALLY RECURSIVE ");
                     if !options.is_empty() {
@@ -565,6 +571,16 @@ pub enum TableFactor<T: AstInfo> {
         join: Box<TableWithJoins<T>>,
         alias: Option<TableAlias>,
     },
+    /// Represents a `JSON_TABLE(doc, path COLUMNS (...))` call, which
+    /// produces a row for each item that `path` selects from `doc`.
+    JsonTable {
+        doc: Expr<T>,
+        path: String,
+        passing: Vec<JsonTablePassing<T>>,
+        columns: Vec<JsonTableColumn<T>>,
+        on_error: Option<JsonTableBehavior<T>>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for TableFactor<T> {
@@ -632,11 +648,186 @@ impl<T: AstInfo> AstDisplay for TableFactor<T> {
                     f.write_node(alias);
                 }
             }
+            TableFactor::JsonTable {
+                doc,
+                path,
+                passing,
+                columns,
+                on_error,
+                alias,
+            } => {
+                f.write_str("JSON_TABLE(");
+                f.write_node(doc);
+                f.write_str(", '");
+                f.write_node(&display::escape_single_quote_string(path));
+                f.write_str("'");
+                if !passing.is_empty() {
+                    f.write_str(" PASSING ");
+                    f.write_node(&display::comma_separated(passing));
+                }
+                f.write_str(" COLUMNS (");
+                f.write_node(&display::comma_separated(columns));
+                f.write_str(")");
+                if let Some(on_error) = on_error {
+                    f.write_str(" ");
+                    f.write_node(on_error);
+                    f.write_str(" ON ERROR");
+                }
+                f.write_str(")");
+                if let Some(alias) = alias {
+                    f.write_str(" AS ");
+                    f.write_node(alias);
+                }
+            }
         }
     }
 }
 impl_display_t!(TableFactor);
 
+/// A value passed to the path of a `JSON_TABLE` call, as in `PASSING expr AS
+/// name`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JsonTablePassing<T: AstInfo> {
+    pub expr: Expr<T>,
+    pub name: Ident,
+}
+
+impl<T: AstInfo> AstDisplay for JsonTablePassing<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.expr);
+        f.write_str(" AS ");
+        f.write_node(&self.name);
+    }
+}
+impl_display_t!(JsonTablePassing);
+
+/// A column in the `COLUMNS` clause of a `JSON_TABLE` call.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum JsonTableColumn<T: AstInfo> {
+    /// `name FOR ORDINALITY`
+    Ordinality { name: Ident },
+    /// `name type [PATH 'path'] [behavior ON EMPTY] [behavior ON ERROR]`
+    Regular {
+        name: Ident,
+        data_type: T::DataType,
+        path: Option<String>,
+        on_empty: Option<JsonTableBehavior<T>>,
+        on_error: Option<JsonTableBehavior<T>>,
+    },
+    /// `name type EXISTS [PATH 'path'] [behavior ON ERROR]`
+    Exists {
+        name: Ident,
+        data_type: T::DataType,
+        path: Option<String>,
+        on_error: Option<JsonTableBehavior<T>>,
+    },
+    /// `NESTED PATH 'path' COLUMNS (...)`
+    Nested {
+        path: String,
+        columns: Vec<JsonTableColumn<T>>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for JsonTableColumn<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        fn write_path<W: fmt::Write>(f: &mut AstFormatter<W>, path: &Option<String>) {
+            if let Some(path) = path {
+                f.write_str(" PATH '");
+                f.write_node(&display::escape_single_quote_string(path));
+                f.write_str("'");
+            }
+        }
+
+        match self {
+            JsonTableColumn::Ordinality { name } => {
+                f.write_node(name);
+                f.write_str(" FOR ORDINALITY");
+            }
+            JsonTableColumn::Regular {
+                name,
+                data_type,
+                path,
+                on_empty,
+                on_error,
+            } => {
+                f.write_node(name);
+                f.write_str(" ");
+                f.write_node(data_type);
+                write_path(f, path);
+                if let Some(on_empty) = on_empty {
+                    f.write_str(" ");
+                    f.write_node(on_empty);
+                    f.write_str(" ON EMPTY");
+                }
+                if let Some(on_error) = on_error {
+                    f.write_str(" ");
+                    f.write_node(on_error);
+                    f.write_str(" ON ERROR");
+                }
+            }
+            JsonTableColumn::Exists {
+                name,
+                data_type,
+                path,
+                on_error,
+            } => {
+                f.write_node(name);
+                f.write_str(" ");
+                f.write_node(data_type);
+                f.write_str(" EXISTS");
+                write_path(f, path);
+                if let Some(on_error) = on_error {
+                    f.write_str(" ");
+                    f.write_node(on_error);
+                    f.write_str(" ON ERROR");
+                }
+            }
+            JsonTableColumn::Nested { path, columns } => {
+                f.write_str("NESTED PATH '");
+                f.write_node(&display::escape_single_quote_string(path));
+                f.write_str("' COLUMNS (");
+                f.write_node(&display::comma_separated(columns));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(JsonTableColumn);
+
+/// What a `JSON_TABLE` call or one of its columns produces when a path selects
+/// no items (`ON EMPTY`) or fails (`ON ERROR`).
+///
+/// Not every behavior is valid in every position; the parser only accepts the
+/// ones that are.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum JsonTableBehavior<T: AstInfo> {
+    Error,
+    Null,
+    Empty,
+    True,
+    False,
+    Unknown,
+    Default(Expr<T>),
+}
+
+impl<T: AstInfo> AstDisplay for JsonTableBehavior<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            JsonTableBehavior::Error => f.write_str("ERROR"),
+            JsonTableBehavior::Null => f.write_str("NULL"),
+            JsonTableBehavior::Empty => f.write_str("EMPTY"),
+            JsonTableBehavior::True => f.write_str("TRUE"),
+            JsonTableBehavior::False => f.write_str("FALSE"),
+            JsonTableBehavior::Unknown => f.write_str("UNKNOWN"),
+            JsonTableBehavior::Default(expr) => {
+                f.write_str("DEFAULT ");
+                f.write_node(expr);
+            }
+        }
+    }
+}
+impl_display_t!(JsonTableBehavior);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct TableAlias {
     pub name: Ident,
@@ -43,6 +43,7 @@ pub enum Statement<T: AstInfo> {
     Copy(CopyStatement<T>),
     Update(UpdateStatement<T>),
     Delete(DeleteStatement<T>),
+    Merge(MergeStatement<T>),
     CreateConnection(CreateConnectionStatement<T>),
     CreateDatabase(CreateDatabaseStatement),
     CreateSchema(CreateSchemaStatement),
@@ -55,6 +56,7 @@ pub enum Statement<T: AstInfo> {
     CreateTable(CreateTableStatement<T>),
     CreateIndex(CreateIndexStatement<T>),
     CreateType(CreateTypeStatement<T>),
+    CreateFunction(CreateFunctionStatement<T>),
     CreateRole(CreateRoleStatement),
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
@@ -65,6 +67,8 @@ pub enum Statement<T: AstInfo> {
     AlterObjectSwap(AlterObjectSwapStatement),
     AlterIndex(AlterIndexStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
+    AlterType(AlterTypeStatement),
+    AlterTable(AlterTableStatement<T>),
     AlterSetCluster(AlterSetClusterStatement<T>),
     AlterSink(AlterSinkStatement<T>),
     AlterSource(AlterSourceStatement<T>),
@@ -112,6 +116,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Copy(stmt) => f.write_node(stmt),
             Statement::Update(stmt) => f.write_node(stmt),
             Statement::Delete(stmt) => f.write_node(stmt),
+            Statement::Merge(stmt) => f.write_node(stmt),
             Statement::CreateConnection(stmt) => f.write_node(stmt),
             Statement::CreateDatabase(stmt) => f.write_node(stmt),
             Statement::CreateSchema(stmt) => f.write_node(stmt),
@@ -126,6 +131,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
+            Statement::CreateFunction(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
             Statement::AlterCluster(stmt) => f.write_node(stmt),
@@ -135,6 +141,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterIndex(stmt) => f.write_node(stmt),
             Statement::AlterSetCluster(stmt) => f.write_node(stmt),
             Statement::AlterSecret(stmt) => f.write_node(stmt),
+            Statement::AlterType(stmt) => f.write_node(stmt),
+            Statement::AlterTable(stmt) => f.write_node(stmt),
             Statement::AlterSink(stmt) => f.write_node(stmt),
             Statement::AlterSource(stmt) => f.write_node(stmt),
             Statement::AlterSystemSet(stmt) => f.write_node(stmt),
@@ -184,6 +192,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Copy => "copy",
         StatementKind::Update => "update",
         StatementKind::Delete => "delete",
+        StatementKind::Merge => "merge",
         StatementKind::CreateConnection => "create_connection",
         StatementKind::CreateDatabase => "create_database",
         StatementKind::CreateSchema => "create_schema",
@@ -196,6 +205,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateTable => "create_table",
         StatementKind::CreateIndex => "create_index",
         StatementKind::CreateType => "create_type",
+        StatementKind::CreateFunction => "create_function",
         StatementKind::CreateRole => "create_role",
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
@@ -206,6 +216,8 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::AlterIndex => "alter_index",
         StatementKind::AlterRole => "alter_role",
         StatementKind::AlterSecret => "alter_secret",
+        StatementKind::AlterType => "alter_type",
+        StatementKind::AlterTable => "alter_table",
         StatementKind::AlterSetCluster => "alter_set_cluster",
         StatementKind::AlterSink => "alter_sink",
         StatementKind::AlterSource => "alter_source",
@@ -273,6 +285,8 @@ pub struct InsertStatement<T: AstInfo> {
     pub columns: Vec<Ident>,
     /// A SQL query that specifies what to insert.
     pub source: InsertSource<T>,
+    /// ON CONFLICT
+    pub on_conflict: Option<OnConflict<T>>,
     /// RETURNING
     pub returning: Vec<SelectItem<T>>,
 }
@@ -288,6 +302,10 @@ impl<T: AstInfo> AstDisplay for InsertStatement<T> {
         }
         f.write_str(" ");
         f.write_node(&self.source);
+        if let Some(on_conflict) = &self.on_conflict {
+            f.write_str(" ");
+            f.write_node(on_conflict);
+        }
         if !self.returning.is_empty() {
             f.write_str(" RETURNING ");
             f.write_node(&display::comma_separated(&self.returning));
@@ -296,6 +314,56 @@ impl<T: AstInfo> AstDisplay for InsertStatement<T> {
 }
 impl_display_t!(InsertStatement);
 
+/// `ON CONFLICT (<columns>) DO ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnConflict<T: AstInfo> {
+    /// The columns of the key on which proposed rows conflict with existing
+    /// rows.
+    pub columns: Vec<Ident>,
+    pub action: OnConflictAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for OnConflict<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ON CONFLICT (");
+        f.write_node(&display::comma_separated(&self.columns));
+        f.write_str(") ");
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(OnConflict);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OnConflictAction<T: AstInfo> {
+    /// `DO NOTHING`
+    DoNothing,
+    /// `DO UPDATE SET <assignments> [WHERE <selection>]`
+    DoUpdate {
+        assignments: Vec<Assignment<T>>,
+        selection: Option<Expr<T>>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for OnConflictAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            OnConflictAction::DoNothing => f.write_str("DO NOTHING"),
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            } => {
+                f.write_str("DO UPDATE SET ");
+                f.write_node(&display::comma_separated(assignments));
+                if let Some(selection) = selection {
+                    f.write_str(" WHERE ");
+                    f.write_node(selection);
+                }
+            }
+        }
+    }
+}
+impl_display_t!(OnConflictAction);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CopyRelation<T: AstInfo> {
     Table {
@@ -323,20 +391,22 @@ impl AstDisplay for CopyDirection {
 impl_display!(CopyDirection);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum CopyTarget {
+pub enum CopyTarget<T: AstInfo> {
     Stdin,
     Stdout,
+    Expr(Expr<T>),
 }
 
-impl AstDisplay for CopyTarget {
+impl<T: AstInfo> AstDisplay for CopyTarget<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
-        f.write_str(match self {
-            CopyTarget::Stdin => "STDIN",
-            CopyTarget::Stdout => "STDOUT",
-        })
+        match self {
+            CopyTarget::Stdin => f.write_str("STDIN"),
+            CopyTarget::Stdout => f.write_str("STDOUT"),
+            CopyTarget::Expr(expr) => f.write_node(expr),
+        }
     }
 }
-impl_display!(CopyTarget);
+impl_display_t!(CopyTarget);
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CopyOptionName {
@@ -346,6 +416,8 @@ pub enum CopyOptionName {
     Escape,
     Quote,
     Header,
+    AwsConnection,
+    MaxFileSize,
 }
 
 impl AstDisplay for CopyOptionName {
@@ -357,6 +429,8 @@ impl AstDisplay for CopyOptionName {
             CopyOptionName::Escape => "ESCAPE",
             CopyOptionName::Quote => "QUOTE",
             CopyOptionName::Header => "HEADER",
+            CopyOptionName::AwsConnection => "AWS CONNECTION",
+            CopyOptionName::MaxFileSize => "MAX FILE SIZE",
         })
     }
 }
@@ -385,7 +459,7 @@ pub struct CopyStatement<T: AstInfo> {
     /// DIRECTION
     pub direction: CopyDirection,
     // TARGET
-    pub target: CopyTarget,
+    pub target: CopyTarget<T>,
     // OPTIONS
     pub options: Vec<CopyOption<T>>,
 }
@@ -491,6 +565,114 @@ impl<T: AstInfo> AstDisplay for DeleteStatement<T> {
 }
 impl_display_t!(DeleteStatement);
 
+/// `MERGE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeStatement<T: AstInfo> {
+    /// `INTO`
+    pub table_name: T::ItemName,
+    /// `AS`
+    pub alias: Option<TableAlias>,
+    /// `USING`
+    pub using: TableWithJoins<T>,
+    /// `ON`
+    pub on: Expr<T>,
+    /// `WHEN`
+    pub when_clauses: Vec<MergeWhenClause<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MergeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("MERGE INTO ");
+        f.write_node(&self.table_name);
+        if let Some(alias) = &self.alias {
+            f.write_str(" AS ");
+            f.write_node(alias);
+        }
+        f.write_str(" USING ");
+        f.write_node(&self.using);
+        f.write_str(" ON ");
+        f.write_node(&self.on);
+        for clause in &self.when_clauses {
+            f.write_str(" ");
+            f.write_node(clause);
+        }
+    }
+}
+impl_display_t!(MergeStatement);
+
+/// A `WHEN [NOT] MATCHED` clause of a `MERGE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeWhenClause<T: AstInfo> {
+    /// Whether the clause applies to source rows that match a target row
+    /// (`WHEN MATCHED`) or to those that do not (`WHEN NOT MATCHED`).
+    pub matched: bool,
+    /// `AND`
+    pub condition: Option<Expr<T>>,
+    /// `THEN`
+    pub action: MergeAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for MergeWhenClause<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("WHEN ");
+        if !self.matched {
+            f.write_str("NOT ");
+        }
+        f.write_str("MATCHED");
+        if let Some(condition) = &self.condition {
+            f.write_str(" AND ");
+            f.write_node(condition);
+        }
+        f.write_str(" THEN ");
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(MergeWhenClause);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeAction<T: AstInfo> {
+    /// `UPDATE SET ...`, valid only in `WHEN MATCHED` clauses.
+    Update { assignments: Vec<Assignment<T>> },
+    /// `DELETE`, valid only in `WHEN MATCHED` clauses.
+    Delete,
+    /// `INSERT [(...)] VALUES (...)`, valid only in `WHEN NOT MATCHED`
+    /// clauses.
+    Insert {
+        columns: Vec<Ident>,
+        values: Vec<Expr<T>>,
+    },
+    /// `INSERT DEFAULT VALUES`, valid only in `WHEN NOT MATCHED` clauses.
+    InsertDefaultValues,
+    /// `DO NOTHING`
+    DoNothing,
+}
+
+impl<T: AstInfo> AstDisplay for MergeAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            MergeAction::Update { assignments } => {
+                f.write_str("UPDATE SET ");
+                f.write_node(&display::comma_separated(assignments));
+            }
+            MergeAction::Delete => f.write_str("DELETE"),
+            MergeAction::Insert { columns, values } => {
+                f.write_str("INSERT ");
+                if !columns.is_empty() {
+                    f.write_str("(");
+                    f.write_node(&display::comma_separated(columns));
+                    f.write_str(") ");
+                }
+                f.write_str("VALUES (");
+                f.write_node(&display::comma_separated(values));
+                f.write_str(")");
+            }
+            MergeAction::InsertDefaultValues => f.write_str("INSERT DEFAULT VALUES"),
+            MergeAction::DoNothing => f.write_str("DO NOTHING"),
+        }
+    }
+}
+impl_display_t!(MergeAction);
+
 /// `CREATE DATABASE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateDatabaseStatement {
@@ -1555,6 +1737,26 @@ pub struct CreateTypeStatement<T: AstInfo> {
 
 impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if let CreateTypeAs::Domain {
+            data_type,
+            not_null,
+            checks,
+        } = &self.as_type
+        {
+            f.write_str("CREATE DOMAIN ");
+            f.write_node(&self.name);
+            f.write_str(" AS ");
+            f.write_node(data_type);
+            if *not_null {
+                f.write_str(" NOT NULL");
+            }
+            for check in checks {
+                f.write_str(" CHECK (");
+                f.write_node(check);
+                f.write_str(")");
+            }
+            return;
+        }
         f.write_str("CREATE TYPE ");
         f.write_node(&self.name);
         f.write_str(" AS ");
@@ -1582,11 +1784,115 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
                 }
                 f.write_str(")");
             }
+            CreateTypeAs::Enum { labels } => {
+                f.write_str(&self.as_type);
+                f.write_str("(");
+                for (i, label) in labels.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ");
+                    }
+                    f.write_str("'");
+                    f.write_node(&display::escape_single_quote_string(label));
+                    f.write_str("'");
+                }
+                f.write_str(")");
+            }
+            CreateTypeAs::Domain { .. } => unreachable!("handled above"),
         };
     }
 }
 impl_display_t!(CreateTypeStatement);
 
+/// `CREATE FUNCTION ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateFunctionStatement<T: AstInfo> {
+    /// Name of the created function.
+    pub name: UnresolvedItemName,
+    /// The function's parameters.
+    pub parameters: Vec<FunctionParameter<T>>,
+    /// The function's return type.
+    pub return_type: FunctionReturnType<T>,
+    /// The SQL query that computes the function's result.
+    pub body: Query<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE FUNCTION ");
+        f.write_node(&self.name);
+        f.write_str("(");
+        f.write_node(&display::comma_separated(&self.parameters));
+        f.write_str(") RETURNS ");
+        f.write_node(&self.return_type);
+        f.write_str(" LANGUAGE SQL AS ");
+        f.write_node_as_literal(&self.body);
+    }
+}
+impl_display_t!(CreateFunctionStatement);
+
+/// A parameter of a function in a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionParameter<T: AstInfo> {
+    pub name: Option<Ident>,
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionParameter<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if let Some(name) = &self.name {
+            f.write_node(name);
+            f.write_str(" ");
+        }
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionParameter);
+
+/// The return type of a function in a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionReturnType<T: AstInfo> {
+    /// `RETURNS <type>`
+    Scalar(T::DataType),
+    /// `RETURNS SETOF <type>`
+    SetOf(T::DataType),
+    /// `RETURNS TABLE (<column> <type>, ...)`
+    Table(Vec<FunctionTableColumn<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for FunctionReturnType<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionReturnType::Scalar(data_type) => f.write_node(data_type),
+            FunctionReturnType::SetOf(data_type) => {
+                f.write_str("SETOF ");
+                f.write_node(data_type);
+            }
+            FunctionReturnType::Table(columns) => {
+                f.write_str("TABLE (");
+                f.write_node(&display::comma_separated(columns));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(FunctionReturnType);
+
+/// A column of a `RETURNS TABLE` clause in a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionTableColumn<T: AstInfo> {
+    pub name: Ident,
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionTableColumn<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        f.write_str(" ");
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionTableColumn);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ClusterOptionName {
     /// The `AVAILABILITY ZONES [[=] '[' <values> ']' ]` option.
@@ -1830,6 +2136,16 @@ pub enum CreateTypeAs<T: AstInfo> {
     Record {
         column_defs: Vec<ColumnDef<T>>,
     },
+    Enum {
+        labels: Vec<String>,
+    },
+    /// `CREATE DOMAIN`, whose check expressions refer to the value being
+    /// checked as `VALUE`.
+    Domain {
+        data_type: T::DataType,
+        not_null: bool,
+        checks: Vec<Expr<T>>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
@@ -1838,6 +2154,8 @@ impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
             CreateTypeAs::List { .. } => f.write_str("LIST "),
             CreateTypeAs::Map { .. } => f.write_str("MAP "),
             CreateTypeAs::Record { .. } => f.write_str("RECORD "),
+            CreateTypeAs::Enum { .. } => f.write_str("ENUM "),
+            CreateTypeAs::Domain { .. } => f.write_str("DOMAIN "),
         }
     }
 }
@@ -2054,12 +2372,16 @@ impl<T: AstInfo> AstDisplay for AlterSinkStatement<T> {
 pub enum AlterSourceAddSubsourceOptionName {
     /// Columns whose types you want to unconditionally format as text
     TextColumns,
+    /// Columns of upstream enum types, paired with the enum types they are
+    /// ingested as; only generated during purification
+    EnumColumns,
 }
 
 impl AstDisplay for AlterSourceAddSubsourceOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
             AlterSourceAddSubsourceOptionName::TextColumns => "TEXT COLUMNS",
+            AlterSourceAddSubsourceOptionName::EnumColumns => "ENUM COLUMNS",
         })
     }
 }
@@ -2185,6 +2507,110 @@ impl<T: AstInfo> AstDisplay for AlterSecretStatement<T> {
 
 impl_display_t!(AlterSecretStatement);
 
+/// `ALTER TYPE ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTypeStatement {
+    pub name: UnresolvedItemName,
+    pub if_exists: bool,
+    pub action: AlterTypeAction,
+}
+
+impl AstDisplay for AlterTypeStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER TYPE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ");
+        f.write_node(&self.action);
+    }
+}
+impl_display!(AlterTypeStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterTypeAction {
+    /// `ADD VALUE [IF NOT EXISTS] '<label>'`
+    AddValue { if_not_exists: bool, label: String },
+}
+
+impl AstDisplay for AlterTypeAction {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            AlterTypeAction::AddValue {
+                if_not_exists,
+                label,
+            } => {
+                f.write_str("ADD VALUE ");
+                if *if_not_exists {
+                    f.write_str("IF NOT EXISTS ");
+                }
+                f.write_str("'");
+                f.write_node(&display::escape_single_quote_string(label));
+                f.write_str("'");
+            }
+        }
+    }
+}
+impl_display!(AlterTypeAction);
+
+/// `ALTER TABLE ... { ADD | DROP } COLUMN ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableStatement<T: AstInfo> {
+    pub name: UnresolvedItemName,
+    pub if_exists: bool,
+    pub action: AlterTableAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterTableStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ");
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(AlterTableStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterTableAction<T: AstInfo> {
+    /// `ADD [COLUMN] [IF NOT EXISTS] <column_def>`
+    AddColumn {
+        if_not_exists: bool,
+        column: ColumnDef<T>,
+    },
+    /// `DROP [COLUMN] [IF EXISTS] <name>`
+    DropColumn { if_exists: bool, name: Ident },
+}
+
+impl<T: AstInfo> AstDisplay for AlterTableAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            AlterTableAction::AddColumn {
+                if_not_exists,
+                column,
+            } => {
+                f.write_str("ADD COLUMN ");
+                if *if_not_exists {
+                    f.write_str("IF NOT EXISTS ");
+                }
+                f.write_node(column);
+            }
+            AlterTableAction::DropColumn { if_exists, name } => {
+                f.write_str("DROP COLUMN ");
+                if *if_exists {
+                    f.write_str("IF EXISTS ");
+                }
+                f.write_node(name);
+            }
+        }
+    }
+}
+impl_display_t!(AlterTableAction);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AlterConnectionAction<T: AstInfo> {
     RotateKeys,
@@ -2691,6 +3117,20 @@ impl<T: AstInfo> AstDisplay for ShowCreateViewStatement<T> {
 }
 impl_display_t!(ShowCreateViewStatement);
 
+/// `SHOW CREATE FUNCTION <function>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ShowCreateFunctionStatement<T: AstInfo> {
+    pub function_name: T::ItemName,
+}
+
+impl<T: AstInfo> AstDisplay for ShowCreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("SHOW CREATE FUNCTION ");
+        f.write_node(&self.function_name);
+    }
+}
+impl_display_t!(ShowCreateFunctionStatement);
+
 /// `SHOW CREATE MATERIALIZED VIEW <name>`
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ShowCreateMaterializedViewStatement<T: AstInfo> {
@@ -3749,6 +4189,7 @@ pub enum ShowStatement<T: AstInfo> {
     ShowCreateSink(ShowCreateSinkStatement<T>),
     ShowCreateIndex(ShowCreateIndexStatement<T>),
     ShowCreateConnection(ShowCreateConnectionStatement<T>),
+    ShowCreateFunction(ShowCreateFunctionStatement<T>),
     ShowVariable(ShowVariableStatement),
     InspectShard(InspectShardStatement),
 }
@@ -3765,6 +4206,7 @@ impl<T: AstInfo> AstDisplay for ShowStatement<T> {
             ShowStatement::ShowCreateSink(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateIndex(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateConnection(stmt) => f.write_node(stmt),
+            ShowStatement::ShowCreateFunction(stmt) => f.write_node(stmt),
             ShowStatement::ShowVariable(stmt) => f.write_node(stmt),
             ShowStatement::InspectShard(stmt) => f.write_node(stmt),
         }
@@ -3816,6 +4258,7 @@ pub enum Privilege {
     INSERT,
     UPDATE,
     DELETE,
+    EXECUTE,
     USAGE,
     CREATE,
     CREATEROLE,
@@ -3830,6 +4273,7 @@ impl AstDisplay for Privilege {
             Privilege::INSERT => "INSERT",
             Privilege::UPDATE => "UPDATE",
             Privilege::DELETE => "DELETE",
+            Privilege::EXECUTE => "EXECUTE",
             Privilege::CREATE => "CREATE",
             Privilege::USAGE => "USAGE",
             Privilege::CREATEROLE => "CREATEROLE",
@@ -102,6 +102,14 @@ where
         write!(self.buf, "{}", s).expect("unexpected error in fmt::Display implementation");
     }
 
+    /// Writes a node as a string literal, e.g. the body of a function, in the
+    /// same mode as the enclosing AST.
+    pub fn write_node_as_literal<T: AstDisplay>(&mut self, s: &T) {
+        let mut buf = String::new();
+        s.fmt(&mut AstFormatter::new(&mut buf, self.mode));
+        self.write_node(&escaped_string_literal(&buf));
+    }
+
     // Whether the AST should be optimized for persistence.
     pub fn stable(&self) -> bool {
         self.mode == FormatMode::Stable
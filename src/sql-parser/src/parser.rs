@@ -377,6 +377,9 @@ impl<'a> Parser<'a> {
                 Token::Keyword(INSERT) => {
                     Ok(self.parse_insert().map_parser_err(StatementKind::Insert)?)
                 }
+                Token::Keyword(MERGE) => {
+                    Ok(self.parse_merge().map_parser_err(StatementKind::Merge)?)
+                }
                 Token::Keyword(UPDATE) => {
                     Ok(self.parse_update().map_parser_err(StatementKind::Update)?)
                 }
@@ -734,6 +737,40 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        let within_group_pos = self.peek_pos();
+        let args = if self.parse_keywords(&[WITHIN, GROUP]) {
+            let args = match args {
+                FunctionArgs::Star => {
+                    return Err(self.error(
+                        within_group_pos,
+                        "* not supported as direct argument to an ordered-set aggregate"
+                            .to_string(),
+                    ))
+                }
+                FunctionArgs::Args { order_by, .. } if !order_by.is_empty() => {
+                    return Err(self.error(
+                        within_group_pos,
+                        "cannot use multiple ORDER BY clauses with WITHIN GROUP".to_string(),
+                    ))
+                }
+                FunctionArgs::Args { args, .. } => args,
+                FunctionArgs::WithinGroup { .. } => unreachable!(),
+            };
+            if distinct {
+                return Err(self.error(
+                    within_group_pos,
+                    "cannot use DISTINCT with WITHIN GROUP".to_string(),
+                ));
+            }
+            self.expect_token(&Token::LParen)?;
+            self.expect_keywords(&[ORDER, BY])?;
+            let order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            self.expect_token(&Token::RParen)?;
+            FunctionArgs::WithinGroup { args, order_by }
+        } else {
+            args
+        };
+
         let filter = if self.parse_keyword(FILTER) {
             self.expect_token(&Token::LParen)?;
             self.expect_keyword(WHERE)?;
@@ -792,7 +829,7 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_window_frame(&mut self) -> Result<WindowFrame, ParserError> {
+    fn parse_window_frame(&mut self) -> Result<WindowFrame<Raw>, ParserError> {
         let units = match self.expect_one_of_keywords(&[ROWS, RANGE, GROUPS])? {
             ROWS => WindowFrameUnits::Rows,
             RANGE => WindowFrameUnits::Range,
@@ -814,20 +851,20 @@ impl<'a> Parser<'a> {
         })
     }
 
-    /// Parse `CURRENT ROW` or `{ <positive number> | UNBOUNDED } { PRECEDING | FOLLOWING }`
-    fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound, ParserError> {
+    /// Parse `CURRENT ROW` or `{ <offset> | UNBOUNDED } { PRECEDING | FOLLOWING }`
+    fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound<Raw>, ParserError> {
         if self.parse_keywords(&[CURRENT, ROW]) {
             Ok(WindowFrameBound::CurrentRow)
         } else {
-            let rows = if self.parse_keyword(UNBOUNDED) {
+            let offset = if self.parse_keyword(UNBOUNDED) {
                 None
             } else {
-                Some(self.parse_literal_uint()?)
+                Some(Box::new(self.parse_expr()?))
             };
             if self.parse_keyword(PRECEDING) {
-                Ok(WindowFrameBound::Preceding(rows))
+                Ok(WindowFrameBound::Preceding(offset))
             } else if self.parse_keyword(FOLLOWING) {
-                Ok(WindowFrameBound::Following(rows))
+                Ok(WindowFrameBound::Following(offset))
             } else {
                 self.expected(self.peek_pos(), "PRECEDING or FOLLOWING", self.peek_token())
             }
@@ -1792,6 +1829,12 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(TYPE) {
             self.parse_create_type()
                 .map_parser_err(StatementKind::CreateType)
+        } else if self.peek_keyword(DOMAIN) {
+            self.parse_create_domain()
+                .map_parser_err(StatementKind::CreateType)
+        } else if self.peek_keyword(FUNCTION) {
+            self.parse_create_function()
+                .map_parser_err(StatementKind::CreateFunction)
         } else if self.peek_keyword(ROLE) {
             self.parse_create_role()
                 .map_parser_err(StatementKind::CreateRole)
@@ -1850,7 +1893,7 @@ impl<'a> Parser<'a> {
             } else {
                 self.expected(
                     self.peek_pos(),
-                    "DATABASE, SCHEMA, ROLE, TYPE, INDEX, SINK, SOURCE, TABLE, SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW after CREATE",
+                    "DATABASE, SCHEMA, ROLE, TYPE, FUNCTION, INDEX, SINK, SOURCE, TABLE, SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW after CREATE",
                     self.peek_token(),
                 ).map_no_statement_parser_err()
             }
@@ -2374,6 +2417,62 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_postgres_sink_config_option(
+        &mut self,
+    ) -> Result<PostgresSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[PROGRESS, TABLE])? {
+            PROGRESS => {
+                self.expect_keyword(TABLE)?;
+                PostgresSinkConfigOptionName::ProgressTable
+            }
+            TABLE => PostgresSinkConfigOptionName::Table,
+            _ => unreachable!(),
+        };
+        let _ = self.consume_token(&Token::Eq);
+        Ok(PostgresSinkConfigOption {
+            name,
+            value: Some(WithOptionValue::UnresolvedItemName(self.parse_item_name()?)),
+        })
+    }
+
+    fn parse_webhook_sink_config_option(
+        &mut self,
+    ) -> Result<WebhookSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[SECRET, URL])? {
+            SECRET => WebhookSinkConfigOptionName::Secret,
+            URL => WebhookSinkConfigOptionName::Url,
+            _ => unreachable!(),
+        };
+        Ok(WebhookSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_iceberg_sink_config_option(
+        &mut self,
+    ) -> Result<IcebergSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[AWS, COMMIT, PATH])? {
+            AWS => {
+                self.expect_keyword(CONNECTION)?;
+                return Ok(IcebergSinkConfigOption {
+                    name: IcebergSinkConfigOptionName::AwsConnection,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
+            COMMIT => {
+                self.expect_keyword(INTERVAL)?;
+                IcebergSinkConfigOptionName::CommitInterval
+            }
+            PATH => IcebergSinkConfigOptionName::Path,
+            _ => unreachable!(),
+        };
+        Ok(IcebergSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_connection_option_name(&mut self) -> Result<ConnectionOptionName, ParserError> {
         Ok(
             match self.expect_one_of_keywords(&[
@@ -3060,9 +3159,33 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_pg_connection_option(&mut self) -> Result<PgConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[DETAILS, PUBLICATION, TEXT])? {
+        let name = match self.expect_one_of_keywords(&[DETAILS, PUBLICATION, TEXT, ENUM])? {
             DETAILS => PgConfigOptionName::Details,
             PUBLICATION => PgConfigOptionName::Publication,
+            ENUM => {
+                self.expect_keyword(COLUMNS)?;
+
+                let _ = self.consume_token(&Token::Eq);
+
+                let value = self
+                    .parse_option_sequence(|parser| {
+                        parser.expect_token(&Token::LParen)?;
+                        let column = parser.parse_item_name()?;
+                        parser.expect_token(&Token::Comma)?;
+                        let data_type = parser.parse_data_type()?;
+                        parser.expect_token(&Token::RParen)?;
+                        Ok(WithOptionValue::Sequence(vec![
+                            WithOptionValue::UnresolvedItemName(column),
+                            WithOptionValue::DataType(data_type),
+                        ]))
+                    })?
+                    .map(WithOptionValue::Sequence);
+
+                return Ok(PgConfigOption {
+                    name: PgConfigOptionName::EnumColumns,
+                    value,
+                });
+            }
             TEXT => {
                 self.expect_keyword(COLUMNS)?;
 
@@ -3128,19 +3251,76 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_create_sink_connection(&mut self) -> Result<CreateSinkConnection<Raw>, ParserError> {
-        self.expect_keyword(KAFKA)?;
-        self.expect_keyword(CONNECTION)?;
+        match self.expect_one_of_keywords(&[KAFKA, POSTGRES, WEBHOOK, ICEBERG])? {
+            KAFKA => {
+                self.expect_keyword(CONNECTION)?;
 
-        let connection = self.parse_raw_name()?;
+                let connection = self.parse_raw_name()?;
 
-        let options = if self.consume_token(&Token::LParen) {
-            let options = self.parse_comma_separated(Parser::parse_kafka_sink_config_option)?;
-            self.expect_token(&Token::RParen)?;
-            options
-        } else {
-            vec![]
-        };
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_kafka_sink_config_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                let key = self.parse_sink_key()?;
+
+                Ok(CreateSinkConnection::Kafka {
+                    connection,
+                    options,
+                    key,
+                })
+            }
+            POSTGRES => {
+                self.expect_keyword(CONNECTION)?;
+
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_postgres_sink_config_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
 
+                let key = self.parse_sink_key()?;
+
+                Ok(CreateSinkConnection::Postgres {
+                    connection,
+                    options,
+                    key,
+                })
+            }
+            WEBHOOK => {
+                self.expect_token(&Token::LParen)?;
+                let options =
+                    self.parse_comma_separated(Parser::parse_webhook_sink_config_option)?;
+                self.expect_token(&Token::RParen)?;
+
+                let key = self.parse_sink_key()?;
+
+                Ok(CreateSinkConnection::Webhook { options, key })
+            }
+            ICEBERG => {
+                self.expect_token(&Token::LParen)?;
+                let options =
+                    self.parse_comma_separated(Parser::parse_iceberg_sink_config_option)?;
+                self.expect_token(&Token::RParen)?;
+
+                let key = self.parse_sink_key()?;
+
+                Ok(CreateSinkConnection::Iceberg { options, key })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_sink_key(&mut self) -> Result<Option<KafkaSinkKey>, ParserError> {
         // one token of lookahead:
         // * `KEY (` means we're parsing a list of columns for the key
         // * `KEY FORMAT` means there is no key, we'll parse a KeyValueFormat later
@@ -3162,12 +3342,7 @@ impl<'a> Parser<'a> {
             } else {
                 None
             };
-
-        Ok(CreateSinkConnection::Kafka {
-            connection,
-            options,
-            key,
-        })
+        Ok(key)
     }
 
     fn parse_create_view(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -3482,7 +3657,7 @@ impl<'a> Parser<'a> {
         let name = self.parse_item_name()?;
         self.expect_keyword(AS)?;
 
-        match self.parse_one_of_keywords(&[LIST, MAP]) {
+        match self.parse_one_of_keywords(&[LIST, MAP, ENUM]) {
             Some(LIST) => {
                 self.expect_token(&Token::LParen)?;
                 let options = self.parse_comma_separated(Parser::parse_create_type_list_option)?;
@@ -3501,6 +3676,20 @@ impl<'a> Parser<'a> {
                     as_type: CreateTypeAs::Map { options },
                 }))
             }
+            Some(ENUM) => {
+                self.expect_token(&Token::LParen)?;
+                let labels = if self.consume_token(&Token::RParen) {
+                    vec![]
+                } else {
+                    let labels = self.parse_comma_separated(Parser::parse_literal_string)?;
+                    self.expect_token(&Token::RParen)?;
+                    labels
+                };
+                Ok(Statement::CreateType(CreateTypeStatement {
+                    name,
+                    as_type: CreateTypeAs::Enum { labels },
+                }))
+            }
             None => {
                 let column_defs = self.parse_composite_type_definition()?;
 
@@ -3513,6 +3702,133 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_create_domain(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(DOMAIN)?;
+        let name = self.parse_item_name()?;
+        let _ = self.parse_keyword(AS);
+        let data_type = self.parse_data_type()?;
+
+        let mut not_null = false;
+        let mut checks = vec![];
+        loop {
+            if self.parse_keywords(&[NOT, NULL]) {
+                not_null = true;
+            } else if self.parse_keyword(NULL) {
+                not_null = false;
+            } else if self.parse_keyword(CHECK) {
+                self.expect_token(&Token::LParen)?;
+                checks.push(self.parse_expr()?);
+                self.expect_token(&Token::RParen)?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(Statement::CreateType(CreateTypeStatement {
+            name,
+            as_type: CreateTypeAs::Domain {
+                data_type,
+                not_null,
+                checks,
+            },
+        }))
+    }
+
+    fn parse_create_function(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(FUNCTION)?;
+        let name = self.parse_item_name()?;
+        self.expect_token(&Token::LParen)?;
+        let parameters = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let parameters = self.parse_comma_separated(Parser::parse_function_parameter)?;
+            self.expect_token(&Token::RParen)?;
+            parameters
+        };
+        self.expect_keyword(RETURNS)?;
+        let return_type = if self.parse_keyword(SETOF) {
+            FunctionReturnType::SetOf(self.parse_data_type()?)
+        } else if self.parse_keyword(TABLE) {
+            self.expect_token(&Token::LParen)?;
+            let columns = self.parse_comma_separated(|parser| {
+                Ok(FunctionTableColumn {
+                    name: parser.parse_identifier()?,
+                    data_type: parser.parse_data_type()?,
+                })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            FunctionReturnType::Table(columns)
+        } else {
+            FunctionReturnType::Scalar(self.parse_data_type()?)
+        };
+        // As in PostgreSQL, the `LANGUAGE` and `AS` clauses may appear in
+        // either order.
+        let body = if self.parse_keyword(LANGUAGE) {
+            self.parse_function_language()?;
+            self.expect_keyword(AS)?;
+            self.parse_function_body()?
+        } else {
+            self.expect_keyword(AS)?;
+            let body = self.parse_function_body()?;
+            self.expect_keyword(LANGUAGE)?;
+            self.parse_function_language()?;
+            body
+        };
+        Ok(Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            parameters,
+            return_type,
+            body,
+        }))
+    }
+
+    fn parse_function_parameter(&mut self) -> Result<FunctionParameter<Raw>, ParserError> {
+        // A parameter is either a type, e.g. `double precision`, or a name
+        // followed by a type, e.g. `x int`.
+        let data_type = self.maybe_parse(|parser| {
+            let data_type = parser.parse_data_type()?;
+            match parser.peek_token() {
+                Some(Token::Comma | Token::RParen) => Ok(data_type),
+                other => parser.expected(parser.peek_pos(), "',' or ')'", other),
+            }
+        });
+        match data_type {
+            Some(data_type) => Ok(FunctionParameter {
+                name: None,
+                data_type,
+            }),
+            None => Ok(FunctionParameter {
+                name: Some(self.parse_identifier()?),
+                data_type: self.parse_data_type()?,
+            }),
+        }
+    }
+
+    fn parse_function_language(&mut self) -> Result<(), ParserError> {
+        if self.parse_keyword(SQL) {
+            Ok(())
+        } else {
+            self.expected(self.peek_pos(), "SQL", self.peek_token())
+        }
+    }
+
+    /// Parses the body of a SQL function, which is a string literal that
+    /// contains a single query.
+    fn parse_function_body(&mut self) -> Result<Query<Raw>, ParserError> {
+        let pos = self.peek_pos();
+        let body = self.parse_literal_string()?;
+        let tokens = lexer::lex(&body).map_err(|e| ParserError::new(pos, e.message))?;
+        let mut parser = Parser::new(&body, tokens);
+        let query = parser
+            .parse_query()
+            .map_err(|e| ParserError::new(pos, e.message))?;
+        while parser.consume_token(&Token::Semicolon) {}
+        if parser.peek_token().is_some() {
+            return parser_err!(self, pos, "function body must contain a single query");
+        }
+        Ok(query)
+    }
+
     fn parse_create_type_list_option(&mut self) -> Result<CreateTypeListOption<Raw>, ParserError> {
         self.expect_keywords(&[ELEMENT, TYPE])?;
         let name = CreateTypeListOptionName::ElementType;
@@ -3790,7 +4106,12 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_drop_objects(&mut self) -> Result<Statement<Raw>, ParserError> {
-        let object_type = self.expect_object_type()?;
+        // Domains are types, so `DROP DOMAIN` is an alias for `DROP TYPE`.
+        let object_type = if self.parse_keyword(DOMAIN) {
+            ObjectType::Type
+        } else {
+            self.expect_object_type()?
+        };
         let if_exists = self.parse_if_exists()?;
         match object_type {
             ObjectType::Database => {
@@ -3843,7 +4164,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Index
             | ObjectType::Type
             | ObjectType::Secret
-            | ObjectType::Connection => {
+            | ObjectType::Connection
+            | ObjectType::Func => {
                 let names = self.parse_comma_separated(|parser| {
                     Ok(UnresolvedObjectName::Item(parser.parse_item_name()?))
                 })?;
@@ -3858,7 +4180,7 @@ impl<'a> Parser<'a> {
                     cascade,
                 }))
             }
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Subsource => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported DROP on {object_type}")
@@ -4209,26 +4531,7 @@ impl<'a> Parser<'a> {
             ObjectType::View | ObjectType::MaterializedView | ObjectType::Table => {
                 self.parse_alter_views(object_type)
             }
-            ObjectType::Type => {
-                let if_exists = self
-                    .parse_if_exists()
-                    .map_parser_err(StatementKind::AlterOwner)?;
-                let name = UnresolvedObjectName::Item(
-                    self.parse_item_name()
-                        .map_parser_err(StatementKind::AlterOwner)?,
-                );
-                self.expect_keywords(&[OWNER, TO])
-                    .map_parser_err(StatementKind::AlterOwner)?;
-                let new_owner = self
-                    .parse_identifier()
-                    .map_parser_err(StatementKind::AlterOwner)?;
-                Ok(Statement::AlterOwner(AlterOwnerStatement {
-                    object_type,
-                    if_exists,
-                    name,
-                    new_owner,
-                }))
-            }
+            ObjectType::Type => self.parse_alter_type(),
             ObjectType::Cluster => self.parse_alter_cluster(object_type),
             ObjectType::ClusterReplica => {
                 let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
@@ -4669,6 +4972,52 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Parse an ALTER TYPE statement.
+    fn parse_alter_type(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
+        let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
+        let name = self.parse_item_name().map_no_statement_parser_err()?;
+
+        Ok(
+            match self
+                .expect_one_of_keywords(&[ADD, OWNER])
+                .map_no_statement_parser_err()?
+            {
+                ADD => {
+                    self.expect_keyword(VALUE)
+                        .map_parser_err(StatementKind::AlterType)?;
+                    let if_not_exists = self
+                        .parse_if_not_exists()
+                        .map_parser_err(StatementKind::AlterType)?;
+                    let label = self
+                        .parse_literal_string()
+                        .map_parser_err(StatementKind::AlterType)?;
+                    Statement::AlterType(AlterTypeStatement {
+                        name,
+                        if_exists,
+                        action: AlterTypeAction::AddValue {
+                            if_not_exists,
+                            label,
+                        },
+                    })
+                }
+                OWNER => {
+                    self.expect_keyword(TO)
+                        .map_parser_err(StatementKind::AlterOwner)?;
+                    let new_owner = self
+                        .parse_identifier()
+                        .map_parser_err(StatementKind::AlterOwner)?;
+                    Statement::AlterOwner(AlterOwnerStatement {
+                        object_type: ObjectType::Type,
+                        if_exists,
+                        name: UnresolvedObjectName::Item(name),
+                        new_owner,
+                    })
+                }
+                _ => unreachable!(),
+            },
+        )
+    }
+
     /// Parse an ALTER SINK statement.
     fn parse_alter_sink(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
@@ -4988,10 +5337,25 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement<Raw>, ParserStatementError> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
+        let keywords: &[Keyword] = if object_type == ObjectType::Table {
+            &[SET, RENAME, OWNER, ADD, DROP]
+        } else {
+            &[SET, RENAME, OWNER]
+        };
         let action = self
-            .expect_one_of_keywords(&[SET, RENAME, OWNER])
+            .expect_one_of_keywords(keywords)
             .map_no_statement_parser_err()?;
         match action {
+            ADD | DROP => self
+                .parse_alter_table_action(action)
+                .map(|action| {
+                    Statement::AlterTable(AlterTableStatement {
+                        name,
+                        if_exists,
+                        action,
+                    })
+                })
+                .map_parser_err(StatementKind::AlterTable),
             RENAME => {
                 self.expect_keyword(TO).map_no_statement_parser_err()?;
                 let to_item_name = self
@@ -5021,6 +5385,49 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the column action of an `ALTER TABLE` statement, after its
+    /// leading `ADD` or `DROP` keyword.
+    fn parse_alter_table_action(
+        &mut self,
+        keyword: Keyword,
+    ) -> Result<AlterTableAction<Raw>, ParserError> {
+        let _ = self.parse_keyword(COLUMN);
+        match keyword {
+            ADD => {
+                let if_not_exists = self.parse_if_not_exists()?;
+                let name = self.parse_identifier()?;
+                let data_type = self.parse_data_type()?;
+                let collation = if self.parse_keyword(COLLATE) {
+                    Some(self.parse_item_name()?)
+                } else {
+                    None
+                };
+                let mut options = vec![];
+                loop {
+                    match self.peek_token() {
+                        None | Some(Token::Semicolon) => break,
+                        _ => options.push(self.parse_column_option_def()?),
+                    }
+                }
+                Ok(AlterTableAction::AddColumn {
+                    if_not_exists,
+                    column: ColumnDef {
+                        name,
+                        data_type,
+                        collation,
+                        options,
+                    },
+                })
+            }
+            DROP => {
+                let if_exists = self.parse_if_exists()?;
+                let name = self.parse_identifier()?;
+                Ok(AlterTableAction::DropColumn { if_exists, name })
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn parse_alter_schema(
         &mut self,
         object_type: ObjectType,
@@ -5153,9 +5560,16 @@ impl<'a> Parser<'a> {
                 (CopyDirection::From, CopyTarget::Stdin)
             }
             TO => {
-                self.expect_keyword(STDOUT)
-                    .map_parser_err(StatementKind::Copy)?;
-                (CopyDirection::To, CopyTarget::Stdout)
+                if self.parse_keyword(STDOUT) {
+                    (CopyDirection::To, CopyTarget::Stdout)
+                } else if self.peek_keyword(STDIN) {
+                    return self
+                        .expected(self.peek_pos(), STDOUT, self.peek_token())
+                        .map_parser_err(StatementKind::Copy);
+                } else {
+                    let url_expr = self.parse_expr().map_parser_err(StatementKind::Copy)?;
+                    (CopyDirection::To, CopyTarget::Expr(url_expr))
+                }
             }
             _ => unreachable!(),
         };
@@ -5188,16 +5602,28 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_copy_option(&mut self) -> Result<CopyOption<Raw>, ParserError> {
-        let name =
-            match self.expect_one_of_keywords(&[FORMAT, DELIMITER, NULL, ESCAPE, QUOTE, HEADER])? {
-                FORMAT => CopyOptionName::Format,
-                DELIMITER => CopyOptionName::Delimiter,
-                NULL => CopyOptionName::Null,
-                ESCAPE => CopyOptionName::Escape,
-                QUOTE => CopyOptionName::Quote,
-                HEADER => CopyOptionName::Header,
-                _ => unreachable!(),
-            };
+        let name = match self
+            .expect_one_of_keywords(&[FORMAT, DELIMITER, NULL, ESCAPE, QUOTE, HEADER, AWS, MAX])?
+        {
+            FORMAT => CopyOptionName::Format,
+            DELIMITER => CopyOptionName::Delimiter,
+            NULL => CopyOptionName::Null,
+            ESCAPE => CopyOptionName::Escape,
+            QUOTE => CopyOptionName::Quote,
+            HEADER => CopyOptionName::Header,
+            AWS => {
+                self.expect_keyword(CONNECTION)?;
+                return Ok(CopyOption {
+                    name: CopyOptionName::AwsConnection,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
+            MAX => {
+                self.expect_keywords(&[FILE, SIZE])?;
+                CopyOptionName::MaxFileSize
+            }
+            _ => unreachable!(),
+        };
         let value = self.parse_optional_option_value()?;
         Ok(CopyOption { name, value })
     }
@@ -5807,6 +6233,86 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_merge(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(INTO)?;
+        let table_name = RawItemName::Name(self.parse_item_name()?);
+        // The alias here doesn't support columns, so don't use parse_optional_table_alias.
+        let alias = self.parse_optional_alias(Keyword::is_reserved_in_table_alias)?;
+        let alias = alias.map(|name| TableAlias {
+            name,
+            columns: Vec::new(),
+            strict: false,
+        });
+        self.expect_keyword(USING)?;
+        let using = self.parse_table_and_joins()?;
+        self.expect_keyword(ON)?;
+        let on = self.parse_expr()?;
+        let mut when_clauses = vec![self.parse_merge_when_clause()?];
+        while self.peek_keyword(WHEN) {
+            when_clauses.push(self.parse_merge_when_clause()?);
+        }
+
+        Ok(Statement::Merge(MergeStatement {
+            table_name,
+            alias,
+            using,
+            on,
+            when_clauses,
+        }))
+    }
+
+    fn parse_merge_when_clause(&mut self) -> Result<MergeWhenClause<Raw>, ParserError> {
+        self.expect_keyword(WHEN)?;
+        let matched = !self.parse_keyword(NOT);
+        self.expect_keyword(MATCHED)?;
+        let condition = if self.parse_keyword(AND) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        self.expect_keyword(THEN)?;
+        let action = if matched {
+            match self.expect_one_of_keywords(&[UPDATE, DELETE, DO])? {
+                UPDATE => {
+                    self.expect_keyword(SET)?;
+                    let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                    MergeAction::Update { assignments }
+                }
+                DELETE => MergeAction::Delete,
+                DO => {
+                    self.expect_keyword(NOTHING)?;
+                    MergeAction::DoNothing
+                }
+                _ => unreachable!(),
+            }
+        } else {
+            match self.expect_one_of_keywords(&[INSERT, DO])? {
+                INSERT => {
+                    if self.parse_keywords(&[DEFAULT, VALUES]) {
+                        MergeAction::InsertDefaultValues
+                    } else {
+                        let columns = self.parse_parenthesized_column_list(Optional)?;
+                        self.expect_keyword(VALUES)?;
+                        self.expect_token(&Token::LParen)?;
+                        let values = self.parse_comma_separated(Parser::parse_expr)?;
+                        self.expect_token(&Token::RParen)?;
+                        MergeAction::Insert { columns, values }
+                    }
+                }
+                DO => {
+                    self.expect_keyword(NOTHING)?;
+                    MergeAction::DoNothing
+                }
+                _ => unreachable!(),
+            }
+        };
+        Ok(MergeWhenClause {
+            matched,
+            condition,
+            action,
+        })
+    }
+
     /// Parses a SELECT (or WITH, VALUES, TABLE) statement with optional AS OF.
     fn parse_select_statement(&mut self) -> Result<SelectStatement<Raw>, ParserError> {
         Ok(SelectStatement {
@@ -5836,8 +6342,9 @@ impl<'a> Parser<'a> {
                         options,
                         ctes: parser.parse_comma_separated(Parser::parse_cte_mut_rec)?,
                     })
+                } else if parser.parse_keyword(RECURSIVE) {
+                    CteBlock::Recursive(parser.parse_comma_separated(Parser::parse_cte)?)
                 } else {
-                    // TODO: optional RECURSIVE
                     CteBlock::Simple(parser.parse_comma_separated(Parser::parse_cte)?)
                 }
             } else {
@@ -6499,6 +7006,12 @@ impl<'a> Parser<'a> {
                     connection_name: self.parse_raw_name()?,
                 },
             ))
+        } else if self.parse_keywords(&[CREATE, FUNCTION]) {
+            Ok(ShowStatement::ShowCreateFunction(
+                ShowCreateFunctionStatement {
+                    function_name: self.parse_raw_name()?,
+                },
+            ))
         } else {
             let variable = if self.parse_keywords(&[TRANSACTION, ISOLATION, LEVEL]) {
                 ident!("transaction_isolation")
@@ -6657,6 +7170,8 @@ impl<'a> Parser<'a> {
                 return self.parse_derived_table_factor(Lateral);
             } else if self.parse_keywords(&[ROWS, FROM]) {
                 return self.parse_rows_from();
+            } else if self.peek_json_table() {
+                return self.parse_json_table();
             } else {
                 let name = self.parse_raw_name()?;
                 self.expect_token(&Token::LParen)?;
@@ -6729,6 +7244,8 @@ impl<'a> Parser<'a> {
             })
         } else if self.parse_keywords(&[ROWS, FROM]) {
             Ok(self.parse_rows_from()?)
+        } else if self.peek_json_table() {
+            self.parse_json_table()
         } else {
             let name = self.parse_raw_name()?;
             if self.consume_token(&Token::LParen) {
@@ -6755,6 +7272,149 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Reports whether the next tokens begin a `JSON_TABLE` call.
+    ///
+    /// `JSON_TABLE` is not a keyword, so it is recognized by name only when
+    /// it is immediately followed by an opening parenthesis.
+    fn peek_json_table(&self) -> bool {
+        matches!(self.peek_token(), Some(Token::Ident(id)) if id.as_str() == "json_table")
+            && self.peek_nth_token(1) == Some(Token::LParen)
+    }
+
+    fn parse_json_table(&mut self) -> Result<TableFactor<Raw>, ParserError> {
+        self.next_token();
+        self.expect_token(&Token::LParen)?;
+        let doc = self.parse_expr()?;
+        self.expect_token(&Token::Comma)?;
+        let path = self.parse_literal_string()?;
+        let passing = if self.parse_keyword(PASSING) {
+            self.parse_comma_separated(|parser| {
+                let expr = parser.parse_expr()?;
+                parser.expect_keyword(AS)?;
+                let name = parser.parse_identifier()?;
+                Ok(JsonTablePassing { expr, name })
+            })?
+        } else {
+            vec![]
+        };
+        let columns = self.parse_json_table_columns()?;
+        let (_, on_error) = self.parse_json_table_behaviors(&[ERROR, EMPTY], false)?;
+        self.expect_token(&Token::RParen)?;
+        let alias = self.parse_optional_table_alias()?;
+        Ok(TableFactor::JsonTable {
+            doc,
+            path,
+            passing,
+            columns,
+            on_error,
+            alias,
+        })
+    }
+
+    fn parse_json_table_columns(&mut self) -> Result<Vec<JsonTableColumn<Raw>>, ParserError> {
+        self.expect_keyword(COLUMNS)?;
+        self.expect_token(&Token::LParen)?;
+        let columns = self.parse_comma_separated(Parser::parse_json_table_column)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(columns)
+    }
+
+    fn parse_json_table_column(&mut self) -> Result<JsonTableColumn<Raw>, ParserError> {
+        // `NESTED` is also a valid column name, so only treat it as the start
+        // of a nested path if a path follows.
+        if self.peek_keyword(NESTED)
+            && matches!(
+                self.peek_nth_token(1),
+                Some(Token::Keyword(PATH) | Token::String(_))
+            )
+        {
+            self.expect_keyword(NESTED)?;
+            let _ = self.parse_keyword(PATH);
+            let path = self.parse_literal_string()?;
+            let columns = self.parse_json_table_columns()?;
+            return Ok(JsonTableColumn::Nested { path, columns });
+        }
+
+        let name = self.parse_identifier()?;
+        if self.parse_keywords(&[FOR, ORDINALITY]) {
+            return Ok(JsonTableColumn::Ordinality { name });
+        }
+        let data_type = self.parse_data_type()?;
+        let exists = self.parse_keyword(EXISTS);
+        let path = if self.parse_keyword(PATH) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        if exists {
+            let (_, on_error) =
+                self.parse_json_table_behaviors(&[ERROR, TRUE, FALSE, UNKNOWN], false)?;
+            Ok(JsonTableColumn::Exists {
+                name,
+                data_type,
+                path,
+                on_error,
+            })
+        } else {
+            let (on_empty, on_error) =
+                self.parse_json_table_behaviors(&[ERROR, NULL, DEFAULT], true)?;
+            Ok(JsonTableColumn::Regular {
+                name,
+                data_type,
+                path,
+                on_empty,
+                on_error,
+            })
+        }
+    }
+
+    /// Parses the `behavior ON EMPTY` and `behavior ON ERROR` clauses of a
+    /// `JSON_TABLE` call or column, in that order, accepting only the
+    /// behaviors in `allowed`.
+    fn parse_json_table_behaviors(
+        &mut self,
+        allowed: &[Keyword],
+        allow_on_empty: bool,
+    ) -> Result<
+        (
+            Option<JsonTableBehavior<Raw>>,
+            Option<JsonTableBehavior<Raw>>,
+        ),
+        ParserError,
+    > {
+        let mut on_empty = None;
+        let mut on_error = None;
+        while on_error.is_none() {
+            let Some(kw) = self.parse_one_of_keywords(allowed) else {
+                break;
+            };
+            let behavior = match kw {
+                ERROR => JsonTableBehavior::Error,
+                NULL => JsonTableBehavior::Null,
+                EMPTY => {
+                    let _ = self.parse_keyword(ARRAY);
+                    JsonTableBehavior::Empty
+                }
+                TRUE => JsonTableBehavior::True,
+                FALSE => JsonTableBehavior::False,
+                UNKNOWN => JsonTableBehavior::Unknown,
+                DEFAULT => JsonTableBehavior::Default(self.parse_expr()?),
+                _ => unreachable!(),
+            };
+            self.expect_keyword(ON)?;
+            if allow_on_empty && on_empty.is_none() {
+                match self.expect_one_of_keywords(&[EMPTY, ERROR])? {
+                    EMPTY => on_empty = Some(behavior),
+                    _ => on_error = Some(behavior),
+                }
+            } else {
+                self.expect_keyword(ERROR)?;
+                on_error = Some(behavior);
+            }
+        }
+        Ok((on_empty, on_error))
+    }
+
     fn parse_rows_from(&mut self) -> Result<TableFactor<Raw>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let functions = self.parse_comma_separated(Parser::parse_named_function)?;
@@ -6823,15 +7483,46 @@ impl<'a> Parser<'a> {
         } else {
             InsertSource::Query(self.parse_query()?)
         };
+        let on_conflict = if self.parse_keywords(&[ON, CONFLICT]) {
+            Some(self.parse_on_conflict()?)
+        } else {
+            None
+        };
         let returning = self.parse_returning()?;
         Ok(Statement::Insert(InsertStatement {
             table_name,
             columns,
             source,
+            on_conflict,
             returning,
         }))
     }
 
+    /// Parse the remainder of an `ON CONFLICT` clause, assuming the `ON
+    /// CONFLICT` keywords have already been consumed.
+    fn parse_on_conflict(&mut self) -> Result<OnConflict<Raw>, ParserError> {
+        let columns = self.parse_parenthesized_column_list(Mandatory)?;
+        self.expect_keyword(DO)?;
+        let action = match self.expect_one_of_keywords(&[NOTHING, UPDATE])? {
+            NOTHING => OnConflictAction::DoNothing,
+            UPDATE => {
+                self.expect_keyword(SET)?;
+                let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                let selection = if self.parse_keyword(WHERE) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+                OnConflictAction::DoUpdate {
+                    assignments,
+                    selection,
+                }
+            }
+            _ => unreachable!(),
+        };
+        Ok(OnConflict { columns, action })
+    }
+
     fn parse_returning(&mut self) -> Result<Vec<SelectItem<Raw>>, ParserError> {
         Ok(if self.parse_keyword(RETURNING) {
             self.parse_comma_separated(Parser::parse_select_item)?
@@ -6866,7 +7557,8 @@ impl<'a> Parser<'a> {
         }))
     }
 
-    /// Parse a `var = expr` assignment, used in an UPDATE statement
+    /// Parse a `var = expr` assignment, used in UPDATE statements and
+    /// `ON CONFLICT DO UPDATE` clauses
     fn parse_assignment(&mut self) -> Result<Assignment<Raw>, ParserError> {
         let id = self.parse_identifier()?;
         self.expect_token(&Token::Eq)?;
@@ -7363,6 +8055,7 @@ impl<'a> Parser<'a> {
             Statement::Select(_)
                 | Statement::Insert(_)
                 | Statement::Delete(_)
+                | Statement::Merge(_)
                 | Statement::Update(_),
         ) {
             return parser_err!(self, pos, "unpreparable statement").map_no_statement_parser_err();
@@ -7639,7 +8332,6 @@ impl<'a> Parser<'a> {
             | ObjectType::Index
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::Func
             | ObjectType::Subsource => {
                 parser_err!(
                     self,
@@ -7649,6 +8341,7 @@ impl<'a> Parser<'a> {
             }
             ObjectType::Table
             | ObjectType::Type
+            | ObjectType::Func
             | ObjectType::Cluster
             | ObjectType::Secret
             | ObjectType::Connection
@@ -7960,6 +8653,7 @@ impl<'a> Parser<'a> {
                 SELECT,
                 UPDATE,
                 DELETE,
+                EXECUTE,
                 USAGE,
                 CREATE,
                 CREATEROLE,
@@ -7970,6 +8664,7 @@ impl<'a> Parser<'a> {
                 SELECT => Privilege::SELECT,
                 UPDATE => Privilege::UPDATE,
                 DELETE => Privilege::DELETE,
+                EXECUTE => Privilege::EXECUTE,
                 USAGE => Privilege::USAGE,
                 CREATE => Privilege::CREATE,
                 CREATEROLE => Privilege::CREATEROLE,
@@ -216,6 +216,9 @@ pub(crate) fn doc_insert<T: AstInfo>(v: &InsertStatement<T>) -> RcDoc {
         _ => doc_display(&v.source, "insert source"),
     };
     let mut doc = intersperse_line_nest([intersperse_line_nest(first), sources]);
+    if let Some(on_conflict) = &v.on_conflict {
+        doc = nest(doc, doc_on_conflict(on_conflict));
+    }
     if !v.returning.is_empty() {
         doc = nest(
             doc,
@@ -225,6 +228,32 @@ pub(crate) fn doc_insert<T: AstInfo>(v: &InsertStatement<T>) -> RcDoc {
     doc
 }
 
+fn doc_on_conflict<T: AstInfo>(v: &OnConflict<T>) -> RcDoc {
+    let target = bracket(
+        "ON CONFLICT (",
+        comma_separate(doc_display_pass, &v.columns),
+        ")",
+    );
+    let action = match &v.action {
+        OnConflictAction::DoNothing => RcDoc::text("DO NOTHING"),
+        OnConflictAction::DoUpdate {
+            assignments,
+            selection,
+        } => {
+            let mut docs = vec![title_comma_separate(
+                "DO UPDATE SET",
+                doc_display_pass,
+                assignments,
+            )];
+            if let Some(selection) = selection {
+                docs.push(nest_title("WHERE", doc_expr(selection)));
+            }
+            RcDoc::intersperse(docs, Doc::line()).group()
+        }
+    };
+    nest(target, action)
+}
+
 pub(crate) fn doc_select_statement<T: AstInfo>(v: &SelectStatement<T>) -> RcDoc {
     let mut doc = doc_query(&v.query);
     if let Some(as_of) = &v.as_of {
@@ -256,6 +285,9 @@ fn doc_query<T: AstInfo>(v: &Query<T>) -> RcDoc {
     if !v.ctes.is_empty() {
         match &v.ctes {
             CteBlock::Simple(ctes) => docs.push(title_comma_separate("WITH", doc_cte, ctes)),
+            CteBlock::Recursive(ctes) => {
+                docs.push(title_comma_separate("WITH RECURSIVE", doc_cte, ctes))
+            }
             CteBlock::MutuallyRecursive(mutrec) => {
                 let mut doc = RcDoc::text("WITH MUTUALLY RECURSIVE");
                 if !mutrec.options.is_empty() {
@@ -628,6 +660,7 @@ pub fn doc_expr<T: AstInfo>(v: &Expr<T>) -> RcDoc {
 fn doc_function<T: AstInfo>(v: &Function<T>) -> RcDoc {
     match &v.args {
         FunctionArgs::Star => doc_display_pass(v),
+        FunctionArgs::WithinGroup { .. } => doc_display(v, "function within group"),
         FunctionArgs::Args { args, order_by } => {
             if args.is_empty() {
                 // Nullary, don't allow newline between parens, so just delegate.
@@ -17,10 +17,11 @@ use mz_repr::GlobalId;
 use crate::ast::visit::{self, Visit};
 use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
-    AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query,
-    Raw, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
+    AstInfo, CreateConnectionStatement, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreateSecretStatement, CreateSinkStatement,
+    CreateSourceStatement, CreateSubsourceStatement, CreateTableStatement, CreateViewStatement,
+    CreateWebhookSourceStatement, Expr, Ident, Query, Raw, RawItemName, Select, SelectItem,
+    Statement, TableAlias, TableFactor, TableWithJoins, UnresolvedItemName, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -45,6 +46,7 @@ pub fn create_stmt_rename_schema_refs(
         | stmt @ Statement::CreateTable(_)
         | stmt @ Statement::CreateIndex(_)
         | stmt @ Statement::CreateType(_)
+        | stmt @ Statement::CreateFunction(_)
         | stmt @ Statement::CreateSecret(_) => {
             let mut visitor = CreateSqlRewriteSchema {
                 database,
@@ -200,7 +202,8 @@ pub fn create_stmt_rename_refs(
             definition: ViewDefinition { query, .. },
             ..
         })
-        | Statement::CreateMaterializedView(CreateMaterializedViewStatement { query, .. }) => {
+        | Statement::CreateMaterializedView(CreateMaterializedViewStatement { query, .. })
+        | Statement::CreateFunction(CreateFunctionStatement { body: query, .. }) => {
             rewrite_query(from_name, to_item_name, query)?;
         }
         Statement::CreateSource(_)
@@ -487,3 +490,65 @@ impl<'ast> VisitMut<'ast, Raw> for CreateSqlIdReplacer<'_> {
         }
     }
 }
+
+/// Replaces every reference to the table `id` within `create_stmt` with a
+/// subquery that selects only `columns` from the table, visible under the
+/// same name as the original reference.
+///
+/// This keeps the columns that `create_stmt` observes from the table stable
+/// as the table's columns change.
+pub fn create_stmt_project_table(
+    create_stmt: &mut Statement<Raw>,
+    id: GlobalId,
+    columns: &[Ident],
+) {
+    let mut projector = CreateSqlTableProjector { id, columns };
+    projector.visit_statement_mut(create_stmt);
+}
+
+struct CreateSqlTableProjector<'a> {
+    id: GlobalId,
+    columns: &'a [Ident],
+}
+
+impl<'ast> VisitMut<'ast, Raw> for CreateSqlTableProjector<'_> {
+    fn visit_table_factor_mut(&mut self, table_factor: &'ast mut TableFactor<Raw>) {
+        let TableFactor::Table {
+            name: RawItemName::Id(id, name),
+            alias,
+        } = table_factor
+        else {
+            return visit_mut::visit_table_factor_mut(self, table_factor);
+        };
+        if id.parse::<GlobalId>().ok() != Some(self.id) {
+            return;
+        }
+
+        let alias = alias.take().unwrap_or_else(|| TableAlias {
+            name: name.0.last().expect("item names are non-empty").clone(),
+            columns: vec![],
+            strict: false,
+        });
+        let table = TableWithJoins {
+            relation: TableFactor::Table {
+                name: RawItemName::Id(id.clone(), name.clone()),
+                alias: None,
+            },
+            joins: vec![],
+        };
+        let select = self
+            .columns
+            .iter()
+            .fold(Select::default().from(table), |select, column| {
+                select.project(SelectItem::Expr {
+                    expr: Expr::Identifier(vec![column.clone()]),
+                    alias: None,
+                })
+            });
+        *table_factor = TableFactor::Derived {
+            lateral: false,
+            subquery: Box::new(Query::select(select)),
+            alias: Some(alias),
+        };
+    }
+}
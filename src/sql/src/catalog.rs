@@ -26,6 +26,7 @@ use mz_controller_types::{ClusterId, ReplicaId};
 use mz_expr::MirScalarExpr;
 use mz_ore::now::{EpochMillis, NowFn};
 use mz_ore::str::StrExt;
+use mz_repr::adt::collation::Collation;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::explain::ExprHumanizer;
 use mz_repr::role_id::RoleId;
@@ -33,6 +34,7 @@ use mz_repr::{ColumnName, GlobalId, RelationDesc};
 use mz_sql_parser::ast::{Expr, Ident, QualifiedReplica, UnresolvedItemName};
 use mz_storage_types::connections::inline::{ConnectionResolver, ReferencedConnection};
 use mz_storage_types::connections::{Connection, ConnectionContext};
+use mz_storage_types::controller::TableLayout;
 use mz_storage_types::sources::SourceDesc;
 use once_cell::sync::Lazy;
 use proptest_derive::Arbitrary;
@@ -581,7 +583,7 @@ pub trait CatalogItem {
     ///
     /// If the catalog item is not of a type that produces functions (i.e.,
     /// anything other than a function), it returns an error.
-    fn func(&self) -> Result<&'static Func, CatalogError>;
+    fn func(&self) -> Result<&Func, CatalogError>;
 
     /// Returns the resolved source connection.
     ///
@@ -631,9 +633,18 @@ pub trait CatalogItem {
     /// catalog item is an index.
     fn index_details(&self) -> Option<(&[MirScalarExpr], GlobalId)>;
 
-    /// Returns the column defaults associated with the catalog item, if the
+    /// Returns the column defaults and the domains that the columns were
+    /// declared as associated with the catalog item, if the catalog item is a
+    /// table.
+    fn table_details(&self) -> Option<(&[Expr<Aug>], &[Option<GlobalId>])>;
+
+    /// Returns the collations that the columns were declared with, if the
     /// catalog item is a table.
-    fn table_details(&self) -> Option<&[Expr<Aug>]>;
+    fn table_collations(&self) -> Option<&[Option<Collation>]>;
+
+    /// Returns the columns stored in the shard of the catalog item, if the
+    /// catalog item is a user table.
+    fn table_layout(&self) -> Option<&TableLayout>;
 
     /// Returns the type information associated with the catalog item, if the
     /// catalog item is a type.
@@ -798,11 +809,26 @@ pub enum CatalogType<T: TypeReference> {
         element_reference: T::Reference,
     },
     Bool,
+    Enum {
+        labels: Vec<String>,
+    },
+    /// A domain over the referenced base type. Values of a domain are values
+    /// of the base type that are non-null, if `not_null` is set, and satisfy
+    /// each of the `checks`, which refer to the value as `VALUE`.
+    Domain {
+        base_reference: T::Reference,
+        base_modifiers: Vec<i64>,
+        not_null: bool,
+        checks: Vec<Expr<Aug>>,
+    },
     Bytes,
     Char,
     Date,
     Float32,
     Float64,
+    Hll,
+    Inet,
+    Cidr,
     Int16,
     Int32,
     Int64,
@@ -812,6 +838,7 @@ pub enum CatalogType<T: TypeReference> {
     MzTimestamp,
     Interval,
     Jsonb,
+    JsonPath,
     List {
         element_reference: T::Reference,
         element_modifiers: Vec<i64>,
@@ -840,6 +867,8 @@ pub enum CatalogType<T: TypeReference> {
     Time,
     Timestamp,
     TimestampTz,
+    TsQuery,
+    TsVector,
     Uuid,
     VarChar,
     Int2Vector,
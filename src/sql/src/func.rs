@@ -23,17 +23,19 @@ use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, ColumnType, Datum, RelationType, Row, ScalarBaseType, ScalarType};
 use once_cell::sync::Lazy;
 
-use crate::ast::{SelectStatement, Statement};
+use crate::ast::{CteBlock, Expr, Query, Select, SelectItem, SelectStatement, SetExpr, Statement};
 use crate::catalog::{CatalogType, TypeCategory, TypeReference};
-use crate::names::{self, ResolvedItemName};
+use crate::names::{self, Aug, ResolvedItemName};
 use crate::plan::error::PlanError;
 use crate::plan::expr::{
     AggregateFunc, BinaryFunc, CoercibleScalarExpr, ColumnOrder, HirRelationExpr, HirScalarExpr,
-    ScalarWindowFunc, TableFunc, UnaryFunc, UnmaterializableFunc, ValueWindowFunc, VariadicFunc,
+    RegressionFunc, ScalarWindowFunc, TableFunc, UnaryFunc, UnmaterializableFunc, ValueWindowFunc,
+    VariadicFunc, VarianceFunc,
 };
-use crate::plan::query::{self, ExprContext, QueryContext};
+use crate::plan::query::{self, ExprContext, QueryContext, QueryLifetime};
 use crate::plan::scope::Scope;
 use crate::plan::side_effecting_func::PG_CATALOG_SEF_BUILTINS;
+use crate::plan::statement::StatementContext;
 use crate::plan::transform_ast;
 use crate::plan::typeconv::{self, CastContext};
 use crate::session::vars::{self, ENABLE_TIME_AT_TIME_ZONE};
@@ -77,6 +79,10 @@ impl TypeCategory {
             | ScalarType::Bytes
             | ScalarType::Jsonb
             | ScalarType::Uuid
+            | ScalarType::TsVector
+            | ScalarType::TsQuery
+            | ScalarType::JsonPath
+            | ScalarType::Hll
             | ScalarType::MzAclItem => Self::UserDefined,
             ScalarType::Date
             | ScalarType::Time
@@ -95,6 +101,7 @@ impl TypeCategory {
             | ScalarType::RegProc
             | ScalarType::RegType
             | ScalarType::Numeric { .. } => Self::Numeric,
+            ScalarType::Inet | ScalarType::Cidr => Self::NetworkAddress,
             ScalarType::Interval => Self::Timespan,
             ScalarType::List { .. } => Self::List,
             ScalarType::PgLegacyChar
@@ -112,6 +119,7 @@ impl TypeCategory {
             ScalarType::Map { .. } => Self::Pseudo,
             ScalarType::MzTimestamp => Self::Numeric,
             ScalarType::Range { .. } => Self::Range,
+            ScalarType::Enum { .. } => Self::Enum,
         }
     }
 
@@ -122,6 +130,7 @@ impl TypeCategory {
             | ParamType::ArrayAny
             | ParamType::ArrayAnyCompatible
             | ParamType::AnyCompatible
+            | ParamType::EnumAny
             | ParamType::ListAny
             | ParamType::ListAnyCompatible
             | ParamType::ListElementAnyCompatible
@@ -151,6 +160,10 @@ impl TypeCategory {
             | CatalogType::Bytes
             | CatalogType::Jsonb
             | CatalogType::Uuid
+            | CatalogType::TsVector
+            | CatalogType::TsQuery
+            | CatalogType::JsonPath
+            | CatalogType::Hll
             | CatalogType::MzAclItem => Self::UserDefined,
             CatalogType::Date
             | CatalogType::Time
@@ -169,6 +182,7 @@ impl TypeCategory {
             | CatalogType::RegProc
             | CatalogType::RegType
             | CatalogType::Numeric { .. } => Self::Numeric,
+            CatalogType::Inet | CatalogType::Cidr => Self::NetworkAddress,
             CatalogType::Interval => Self::Timespan,
             CatalogType::List { .. } => Self::List,
             CatalogType::PgLegacyChar
@@ -180,6 +194,10 @@ impl TypeCategory {
             CatalogType::Map { .. } | CatalogType::Pseudo => Self::Pseudo,
             CatalogType::MzTimestamp => Self::String,
             CatalogType::Range { .. } => Self::Range,
+            CatalogType::Enum { .. } => Self::Enum,
+            // Domains belong to the category of their base type, which cannot
+            // be resolved without a catalog.
+            CatalogType::Domain { .. } => Self::UserDefined,
         }
     }
 
@@ -198,13 +216,13 @@ impl TypeCategory {
             | Self::Enum
             | Self::Geometric
             | Self::List
-            | Self::NetworkAddress
             | Self::Pseudo
             | Self::Range
             | Self::Unknown
             | Self::UserDefined => None,
             Self::Boolean => Some(ScalarType::Bool),
             Self::DateTime => Some(ScalarType::TimestampTz { precision: None }),
+            Self::NetworkAddress => Some(ScalarType::Inet),
             Self::Numeric => Some(ScalarType::Float64),
             Self::String => Some(ScalarType::String),
             Self::Timespan => Some(ScalarType::Interval),
@@ -473,6 +491,234 @@ fn experimental_sql_impl_table_func(
     sql_impl_table_func_inner(sql, Some(feature))
 }
 
+/// The return type of a user-defined function.
+#[derive(Debug, Clone)]
+pub enum UserFuncReturnType {
+    /// The function returns a single value of the given type.
+    Scalar(ScalarType),
+    /// The function returns a set of rows with the given columns.
+    Table(Vec<(ColumnName, ScalarType)>),
+}
+
+/// A function defined by a `CREATE FUNCTION` statement.
+///
+/// Like the built-in functions that are defined in SQL, a user-defined
+/// function is inlined into the expression that calls it, with its body
+/// referring to its arguments via parameters.
+#[derive(Debug, Clone)]
+pub struct UserFunc {
+    /// The names, if any, and the types of the function's parameters.
+    pub params: Vec<(Option<ColumnName>, ScalarType)>,
+    pub return_type: UserFuncReturnType,
+    /// The query that computes the function's result.
+    pub body: Query<Aug>,
+}
+
+impl UserFunc {
+    /// Plans the body of the function in a context derived from `qcx` in
+    /// which the function's parameters are bound to their declared types.
+    fn plan_body<R>(
+        &self,
+        qcx: &QueryContext,
+        plan: impl FnOnce(&Self, &mut QueryContext) -> Result<R, PlanError>,
+    ) -> Result<R, PlanError> {
+        let mut scx = qcx.scx.clone();
+        scx.param_types = RefCell::new(
+            self.params
+                .iter()
+                .enumerate()
+                .map(|(i, (_name, ty))| (i + 1, ty.clone()))
+                .collect(),
+        );
+        let mut body_qcx = QueryContext::root(&scx, qcx.lifetime);
+        // The body is planned within the planning of the calling query, so it
+        // counts against the same recursion limit.
+        body_qcx.recursion_guard = qcx.recursion_guard.clone();
+        body_qcx.param_names = self
+            .params
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (name, _ty))| Some((name.clone()?, i + 1)))
+            .collect();
+        let out = plan(self, &mut body_qcx)?;
+        // Referring to parameters other than the declared ones binds them to
+        // new types, rather than failing to plan.
+        if let Some(n) = scx
+            .param_types
+            .borrow()
+            .keys()
+            .find(|n| **n > self.params.len())
+        {
+            sql_bail!(
+                "there is no parameter ${} in a function with {} parameters",
+                n,
+                self.params.len()
+            );
+        }
+        Ok(out)
+    }
+
+    /// Plans the body of a function that returns a single value of type
+    /// `return_type`.
+    fn plan_scalar(
+        &self,
+        qcx: &mut QueryContext,
+        return_type: &ScalarType,
+    ) -> Result<HirScalarExpr, PlanError> {
+        // A body of the form `SELECT <expr>` is planned as the expression
+        // itself rather than as a scalar subquery, which is much easier to
+        // optimize.
+        let expr = match simple_select_expr(&self.body) {
+            Some(expr) => expr.clone(),
+            None => Expr::Subquery(Box::new(self.body.clone())),
+        };
+        let ecx = ExprContext {
+            qcx,
+            name: "function body",
+            scope: &Scope::empty(),
+            relation_type: &RelationType::empty(),
+            allow_aggregates: false,
+            allow_subqueries: true,
+            allow_parameters: true,
+            allow_windows: false,
+        };
+        query::plan_expr(&ecx, &expr)?
+            .cast_to(&ecx, CastContext::Assignment, return_type)
+            .map_err(|_| {
+                sql_err!(
+                    "return type mismatch in function declared to return {}",
+                    ecx.humanize_scalar_type(return_type)
+                )
+            })
+    }
+
+    /// Plans the body of a function that returns a set of rows with the given
+    /// columns.
+    fn plan_table(
+        &self,
+        qcx: &mut QueryContext,
+        columns: &[(ColumnName, ScalarType)],
+    ) -> Result<HirRelationExpr, PlanError> {
+        let (expr, _scope) = query::plan_nested_query(qcx, &self.body)?;
+        let arity = qcx.relation_type(&expr).arity();
+        if arity != columns.len() {
+            sql_bail!(
+                "return type mismatch in function declared to return {} columns: \
+                query returns {} columns",
+                columns.len(),
+                arity
+            );
+        }
+        query::cast_relation(
+            qcx,
+            CastContext::Assignment,
+            expr,
+            columns.iter().map(|(_name, ty)| ty),
+        )
+        .map_err(|e| {
+            sql_err!(
+                "return type mismatch in function declared to return {}: \
+                column {} has type {}",
+                qcx.humanize_scalar_type(&e.target_type),
+                e.column + 1,
+                qcx.humanize_scalar_type(&e.source_type),
+            )
+        })
+    }
+
+    /// Validates that the body of the function plans with the function's
+    /// declared parameter and return types.
+    pub fn validate(&self, scx: &StatementContext) -> Result<(), PlanError> {
+        let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+        match &self.return_type {
+            UserFuncReturnType::Scalar(ty) => {
+                self.plan_body(&qcx, |f, qcx| f.plan_scalar(qcx, ty))?;
+            }
+            UserFuncReturnType::Table(columns) => {
+                self.plan_body(&qcx, |f, qcx| f.plan_table(qcx, columns))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the function into its catalog representation.
+    ///
+    /// The catalog assigns user-defined functions their OIDs, so the
+    /// function's implementation has an OID of zero.
+    pub fn into_func(self) -> Func {
+        let params = ParamList::Exact(
+            self.params
+                .iter()
+                .map(|(_name, ty)| ParamType::Plain(ty.clone()))
+                .collect(),
+        );
+        match self.return_type.clone() {
+            UserFuncReturnType::Scalar(ty) => Func::Scalar(vec![FuncImpl {
+                oid: 0,
+                params,
+                return_type: ReturnType::scalar(ParamType::Plain(ty.clone())),
+                op: Operation::variadic(move |ecx, args| {
+                    let mut expr = self.plan_body(ecx.qcx, |f, qcx| f.plan_scalar(qcx, &ty))?;
+                    expr.splice_parameters(&args, 0);
+                    Ok(expr)
+                }),
+            }]),
+            UserFuncReturnType::Table(columns) => {
+                let typ = match &columns[..] {
+                    [(_name, ty)] => ParamType::Plain(ty.clone()),
+                    _ => ParamType::RecordAny,
+                };
+                Func::Table(vec![FuncImpl {
+                    oid: 0,
+                    params,
+                    return_type: ReturnType::set_of(typ),
+                    op: Operation::variadic(move |ecx, args| {
+                        let mut expr =
+                            self.plan_body(ecx.qcx, |f, qcx| f.plan_table(qcx, &columns))?;
+                        expr.splice_parameters(&args, 0);
+                        Ok(TableFuncPlan {
+                            expr,
+                            column_names: columns.iter().map(|(name, _ty)| name.clone()).collect(),
+                        })
+                    }),
+                }])
+            }
+        }
+    }
+}
+
+/// Returns the expression that `query` computes if it has the simple form
+/// `SELECT <expr>`.
+fn simple_select_expr(query: &Query<Aug>) -> Option<&Expr<Aug>> {
+    let select = match query {
+        Query {
+            ctes: CteBlock::Simple(ctes),
+            body: SetExpr::Select(select),
+            order_by,
+            limit: None,
+            offset: None,
+        } if ctes.is_empty() && order_by.is_empty() => select,
+        _ => return None,
+    };
+    match &**select {
+        Select {
+            distinct: None,
+            projection,
+            from,
+            selection: None,
+            group_by,
+            having: None,
+            options,
+        } if from.is_empty() && group_by.is_empty() && options.is_empty() => {
+            match &projection[..] {
+                [SelectItem::Expr { expr, alias: _ }] => Some(expr),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Describes a single function's implementation.
 pub struct FuncImpl<R> {
     pub oid: u32,
@@ -682,6 +928,9 @@ pub enum ParamType {
     /// A pseudotype permitting any array type, permitting other "Compatibility"-type
     /// parameters to find the best common type.
     ArrayAnyCompatible,
+    /// A pseudotype permitting any enum type, requiring other "Any"-type
+    /// parameters to be of the same type.
+    EnumAny,
     /// An pseudotype permitting any list type, requiring other "Any"-type
     /// parameters to be of the same type.
     ListAny,
@@ -737,6 +986,7 @@ impl ParamType {
         match self {
             Any | AnyElement | AnyCompatible | ListElementAnyCompatible => true,
             ArrayAny | ArrayAnyCompatible => matches!(t, Array(..) | Int2Vector),
+            EnumAny => matches!(t, Enum { .. }),
             ListAny | ListAnyCompatible => matches!(t, List { .. }),
             MapAny | MapAnyCompatible => matches!(t, Map { .. }),
             RangeAny | RangeAnyCompatible => matches!(t, Range { .. }),
@@ -783,6 +1033,7 @@ impl ParamType {
             | ArrayAny
             | ArrayAnyCompatible
             | AnyCompatible
+            | EnumAny
             | ListAny
             | ListAnyCompatible
             | ListElementAnyCompatible
@@ -814,6 +1065,7 @@ impl ParamType {
             ParamType::AnyElement => "anyelement",
             ParamType::ArrayAny => "anyarray",
             ParamType::ArrayAnyCompatible => "anycompatiblearray",
+            ParamType::EnumAny => "anyenum",
             ParamType::Internal => "internal",
             ParamType::ListAny => "list",
             ParamType::ListAnyCompatible => "anycompatiblelist",
@@ -856,7 +1108,7 @@ impl From<ScalarBaseType> for ParamType {
     fn from(s: ScalarBaseType) -> ParamType {
         use ScalarBaseType::*;
         let s = match s {
-            Array | List | Map | Record | Range => {
+            Array | List | Map | Record | Range | Enum => {
                 panic!("use polymorphic parameters rather than {:?}", s);
             }
             AclItem => ScalarType::AclItem,
@@ -883,6 +1135,12 @@ impl From<ScalarBaseType> for ParamType {
             PgLegacyName => ScalarType::PgLegacyName,
             Jsonb => ScalarType::Jsonb,
             Uuid => ScalarType::Uuid,
+            Inet => ScalarType::Inet,
+            Cidr => ScalarType::Cidr,
+            TsVector => ScalarType::TsVector,
+            TsQuery => ScalarType::TsQuery,
+            JsonPath => ScalarType::JsonPath,
+            Hll => ScalarType::Hll,
             Oid => ScalarType::Oid,
             RegClass => ScalarType::RegClass,
             RegProc => ScalarType::RegProc,
@@ -1308,7 +1566,7 @@ impl TryFrom<&ParamType> for PolymorphicCompatClass {
         use ParamType::*;
 
         Ok(match param {
-            AnyElement | ArrayAny | ListAny | MapAny | NonVecAny | RangeAny => {
+            AnyElement | ArrayAny | EnumAny | ListAny | MapAny | NonVecAny | RangeAny => {
                 PolymorphicCompatClass::Any
             }
             ArrayAnyCompatible | AnyCompatible | RangeAnyCompatible | NonVecAnyCompatible => {
@@ -1381,7 +1639,7 @@ impl PolymorphicSolution {
 
         self.seen.push(match param {
             // These represent the keys of their respective compatibility classes.
-            AnyElement | AnyCompatible | ListAnyCompatible |  MapAnyCompatible | NonVecAny | RecordAny => seen,
+            AnyElement | AnyCompatible | EnumAny | ListAnyCompatible |  MapAnyCompatible | NonVecAny | RecordAny => seen,
             MapAny => seen.map(|array| array.unwrap_map_value_type().clone()),
             ListAny => seen.map(|array| array.unwrap_list_element_type().clone()),
             ArrayAny | ArrayAnyCompatible => seen.map(|array| array.unwrap_array_element_type().clone()),
@@ -1501,9 +1759,8 @@ impl PolymorphicSolution {
         );
 
         match param {
-            AnyElement | AnyCompatible | ListAnyCompatible | MapAnyCompatible | NonVecAny => {
-                self.key.clone()
-            }
+            AnyElement | AnyCompatible | EnumAny | ListAnyCompatible | MapAnyCompatible
+            | NonVecAny => self.key.clone(),
             ArrayAny | ArrayAnyCompatible => self
                 .key
                 .as_ref()
@@ -2029,6 +2286,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Float64) => UnaryFunc::Exp(func::Exp) => Float64, 1347;
             params!(Numeric) => UnaryFunc::ExpNumeric(func::ExpNumeric) => Numeric, 1732;
         },
+        "family" => Scalar {
+            params!(Inet) => UnaryFunc::InetFamily(func::InetFamily) => Int32, 711;
+        },
         "floor" => Scalar {
             params!(Float32) => UnaryFunc::FloorFloat32(func::FloorFloat32) => Float32, oid::FUNC_FLOOR_F32_OID;
             params!(Float64) => UnaryFunc::FloorFloat64(func::FloorFloat64) => Float64, 2309;
@@ -2082,6 +2342,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, String, String) => VariadicFunc::HmacString => Bytes, oid::FUNC_PG_HMAC_STRING;
             params!(Bytes, Bytes, String) => VariadicFunc::HmacBytes => Bytes, oid::FUNC_PG_HMAC_BYTES;
         },
+        "host" => Scalar {
+            params!(Inet) => UnaryFunc::InetHost(func::InetHost) => String, 699;
+        },
         "int4range" => Scalar {
             params!(Int32, Int32) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(Datum::String("[)"), ScalarType::String));
@@ -2141,9 +2404,42 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 })
             }) => Jsonb, 3273;
         },
+        "jsonb_path_exists" => Scalar {
+            params!(Jsonb, JsonPath) => jsonb_path_defaults(VariadicFunc::JsonbPathExists, false) => Bool, oid::FUNC_JSONB_PATH_EXISTS_NOVARS_OID;
+            params!(Jsonb, JsonPath, Jsonb) => jsonb_path_defaults(VariadicFunc::JsonbPathExists, false) => Bool, oid::FUNC_JSONB_PATH_EXISTS_NOSILENT_OID;
+            params!(Jsonb, JsonPath, Jsonb, Bool) => VariadicFunc::JsonbPathExists => Bool, 4005;
+        },
+        "jsonb_path_match" => Scalar {
+            params!(Jsonb, JsonPath) => jsonb_path_defaults(VariadicFunc::JsonbPathMatch, false) => Bool, oid::FUNC_JSONB_PATH_MATCH_NOVARS_OID;
+            params!(Jsonb, JsonPath, Jsonb) => jsonb_path_defaults(VariadicFunc::JsonbPathMatch, false) => Bool, oid::FUNC_JSONB_PATH_MATCH_NOSILENT_OID;
+            params!(Jsonb, JsonPath, Jsonb, Bool) => VariadicFunc::JsonbPathMatch => Bool, 4009;
+        },
+        "jsonb_path_query_array" => Scalar {
+            params!(Jsonb, JsonPath) => jsonb_path_defaults(VariadicFunc::JsonbPathQueryArray, false) => Jsonb, oid::FUNC_JSONB_PATH_QUERY_ARRAY_NOVARS_OID;
+            params!(Jsonb, JsonPath, Jsonb) => jsonb_path_defaults(VariadicFunc::JsonbPathQueryArray, false) => Jsonb, oid::FUNC_JSONB_PATH_QUERY_ARRAY_NOSILENT_OID;
+            params!(Jsonb, JsonPath, Jsonb, Bool) => VariadicFunc::JsonbPathQueryArray => Jsonb, 4007;
+        },
+        "jsonb_path_query_first" => Scalar {
+            params!(Jsonb, JsonPath) => jsonb_path_defaults(VariadicFunc::JsonbPathQueryFirst, false) => Jsonb, oid::FUNC_JSONB_PATH_QUERY_FIRST_NOVARS_OID;
+            params!(Jsonb, JsonPath, Jsonb) => jsonb_path_defaults(VariadicFunc::JsonbPathQueryFirst, false) => Jsonb, oid::FUNC_JSONB_PATH_QUERY_FIRST_NOSILENT_OID;
+            params!(Jsonb, JsonPath, Jsonb, Bool) => VariadicFunc::JsonbPathQueryFirst => Jsonb, 4008;
+        },
         "jsonb_pretty" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbPretty(func::JsonbPretty) => String, 3306;
         },
+        "jsonb_insert" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => jsonb_modify_defaults(VariadicFunc::JsonbInsert, vec![(Datum::False, ScalarType::Bool)]) => Jsonb, oid::FUNC_JSONB_INSERT_NOAFTER_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbInsert => Jsonb, 3579;
+        },
+        "jsonb_set" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => jsonb_modify_defaults(VariadicFunc::JsonbSet, vec![(Datum::True, ScalarType::Bool)]) => Jsonb, oid::FUNC_JSONB_SET_NOCREATE_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbSet => Jsonb, 3304;
+        },
+        "jsonb_set_lax" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => jsonb_modify_defaults(VariadicFunc::JsonbSetLax, vec![(Datum::True, ScalarType::Bool), (Datum::String("use_json_null"), ScalarType::String)]) => Jsonb, oid::FUNC_JSONB_SET_LAX_NOCREATE_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => jsonb_modify_defaults(VariadicFunc::JsonbSetLax, vec![(Datum::True, ScalarType::Bool), (Datum::String("use_json_null"), ScalarType::String)]) => Jsonb, oid::FUNC_JSONB_SET_LAX_NOTREATMENT_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool, String) => VariadicFunc::JsonbSetLax => Jsonb, 6254;
+        },
         "jsonb_strip_nulls" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbStripNulls(func::JsonbStripNulls) => Jsonb, 3262;
         },
@@ -2208,6 +2504,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "make_timestamp" => Scalar {
             params!(Int64, Int64, Int64, Int64, Int64, Float64) => VariadicFunc::MakeTimestamp => Timestamp, 3461;
         },
+        "masklen" => Scalar {
+            params!(Inet) => UnaryFunc::InetMasklen(func::InetMasklen) => Int32, 696;
+        },
         "md5" => Scalar {
             params!(String) => Operation::unary(move |_ecx, input| {
                 let algorithm = HirScalarExpr::literal(Datum::String("md5"), ScalarType::String);
@@ -2229,6 +2528,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(UInt32, UInt32) => Operation::nullary(|_ecx| catalog_name_only!("mod")) => UInt32, oid::FUNC_MOD_UINT32_OID;
             params!(UInt64, UInt64) => Operation::nullary(|_ecx| catalog_name_only!("mod")) => UInt64, oid::FUNC_MOD_UINT64_OID;
         },
+        "network" => Scalar {
+            params!(Inet) => UnaryFunc::InetNetwork(func::InetNetwork) => Cidr, 683;
+        },
         "now" => Scalar {
             params!() => UnmaterializableFunc::CurrentTimestamp => TimestampTz, 1299;
         },
@@ -2479,6 +2781,14 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 Ok(HirScalarExpr::literal(Datum::String(&name), ScalarType::String))
             }) => String, 1619;
         },
+        "phraseto_tsquery" => Scalar {
+            params!(String) => default_text_search_config(BinaryFunc::PhraseToTsQuery) => TsQuery, 5001;
+            params!(String, String) => BinaryFunc::PhraseToTsQuery => TsQuery, 5006;
+        },
+        "plainto_tsquery" => Scalar {
+            params!(String) => default_text_search_config(BinaryFunc::PlainToTsQuery) => TsQuery, 3751;
+            params!(String, String) => BinaryFunc::PlainToTsQuery => TsQuery, 3747;
+        },
         "position" => Scalar {
             params!(String, String) => BinaryFunc::Position => Int32, 849;
         },
@@ -2518,6 +2828,10 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String) => UnaryFunc::TrimTrailingWhitespace(func::TrimTrailingWhitespace) => String, 882;
             params!(String, String) => BinaryFunc::TrimTrailing => String, 876;
         },
+        "set_masklen" => Scalar {
+            params!(Inet, Int32) => BinaryFunc::SetMasklenInet => Inet, 605;
+            params!(Cidr, Int32) => BinaryFunc::SetMasklenCidr => Cidr, 635;
+        },
         "sha224" => Scalar {
             params!(Bytes) => digest("sha224") => Bytes, 3419;
         },
@@ -2545,36 +2859,6 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "split_part" => Scalar {
             params!(String, String, Int32) => VariadicFunc::SplitPart => String, 2088;
         },
-        "stddev" => Scalar {
-            params!(Float32) => Operation::nullary(|_ecx| catalog_name_only!("stddev")) => Float64, 2157;
-            params!(Float64) => Operation::nullary(|_ecx| catalog_name_only!("stddev")) => Float64, 2158;
-            params!(Int16) => Operation::nullary(|_ecx| catalog_name_only!("stddev")) => Numeric, 2156;
-            params!(Int32) => Operation::nullary(|_ecx| catalog_name_only!("stddev")) => Numeric, 2155;
-            params!(Int64) => Operation::nullary(|_ecx| catalog_name_only!("stddev")) => Numeric, 2154;
-            params!(UInt16) => Operation::nullary(|_ecx| catalog_name_only!("stddev")) => Numeric, oid::FUNC_STDDEV_UINT16_OID;
-            params!(UInt32) => Operation::nullary(|_ecx| catalog_name_only!("stddev")) => Numeric, oid::FUNC_STDDEV_UINT32_OID;
-            params!(UInt64) => Operation::nullary(|_ecx| catalog_name_only!("stddev")) => Numeric, oid::FUNC_STDDEV_UINT64_OID;
-        },
-        "stddev_pop" => Scalar {
-            params!(Float32) => Operation::nullary(|_ecx| catalog_name_only!("stddev_pop")) => Float64, 2727;
-            params!(Float64) => Operation::nullary(|_ecx| catalog_name_only!("stddev_pop")) => Float64, 2728;
-            params!(Int16) => Operation::nullary(|_ecx| catalog_name_only!("stddev_pop")) => Numeric, 2726;
-            params!(Int32) => Operation::nullary(|_ecx| catalog_name_only!("stddev_pop")) => Numeric, 2725;
-            params!(Int64) => Operation::nullary(|_ecx| catalog_name_only!("stddev_pop")) => Numeric, 2724;
-            params!(UInt16) => Operation::nullary(|_ecx| catalog_name_only!("stddev_pop")) => Numeric, oid::FUNC_STDDEV_POP_UINT16_OID;
-            params!(UInt32) => Operation::nullary(|_ecx| catalog_name_only!("stddev_pop")) => Numeric, oid::FUNC_STDDEV_POP_UINT32_OID;
-            params!(UInt64) => Operation::nullary(|_ecx| catalog_name_only!("stddev_pop")) => Numeric, oid::FUNC_STDDEV_POP_UINT64_OID;
-        },
-        "stddev_samp" => Scalar {
-            params!(Float32) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Float64, 2715;
-            params!(Float64) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Float64, 2716;
-            params!(Int16) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Numeric, 2714;
-            params!(Int32) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Numeric, 2713;
-            params!(Int64) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Numeric, 2712;
-            params!(UInt16) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Numeric, oid::FUNC_STDDEV_SAMP_UINT16_OID;
-            params!(UInt32) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Numeric, oid::FUNC_STDDEV_SAMP_UINT32_OID;
-            params!(UInt64) => Operation::nullary(|_ecx| catalog_name_only!("stddev_samp")) => Numeric, oid::FUNC_STDDEV_SAMP_UINT64_OID;
-        },
         "substr" => Scalar {
             params!(String, Int32) => VariadicFunc::Substr => String, 883;
             params!(String, Int32, Int32) => VariadicFunc::Substr => String, 877;
@@ -2663,6 +2947,14 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "to_timestamp" => Scalar {
             params!(Float64) => UnaryFunc::ToTimestamp(func::ToTimestamp) => TimestampTz, 1158;
         },
+        "to_tsquery" => Scalar {
+            params!(String) => default_text_search_config(BinaryFunc::ToTsQuery) => TsQuery, 3750;
+            params!(String, String) => BinaryFunc::ToTsQuery => TsQuery, 3746;
+        },
+        "to_tsvector" => Scalar {
+            params!(String) => default_text_search_config(BinaryFunc::ToTsVector) => TsVector, 3749;
+            params!(String, String) => BinaryFunc::ToTsVector => TsVector, 3745;
+        },
         "translate" => Scalar {
             params!(String, String, String) => VariadicFunc::Translate => String, 878;
         },
@@ -2686,6 +2978,18 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 })
             }) => ScalarType::Range { element_type: Box::new(ScalarType::Timestamp { precision: None})}, 3934;
         },
+        "ts_rank" => Scalar {
+            params!(TsVector, TsQuery) => Operation::variadic(|_ecx, mut exprs| {
+                exprs.push(HirScalarExpr::literal(Datum::Int32(0), ScalarType::Int32));
+                Ok(HirScalarExpr::CallVariadic { func: VariadicFunc::TsRank, exprs })
+            }) => Float32, 3707;
+            params!(TsVector, TsQuery, Int32) => VariadicFunc::TsRank => Float32, 3706;
+            params!(ScalarType::Array(Box::new(ScalarType::Float32)), TsVector, TsQuery) => Operation::variadic(|_ecx, mut exprs| {
+                exprs.push(HirScalarExpr::literal(Datum::Int32(0), ScalarType::Int32));
+                Ok(HirScalarExpr::CallVariadic { func: VariadicFunc::TsRank, exprs })
+            }) => Float32, 3704;
+            params!(ScalarType::Array(Box::new(ScalarType::Float32)), TsVector, TsQuery, Int32) => VariadicFunc::TsRank => Float32, 3703;
+        },
         "tstzrange" => Scalar {
             params!(TimestampTz, TimestampTz) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(Datum::String("[)"), ScalarType::String));
@@ -2714,39 +3018,13 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "uuid_generate_v5" => Scalar {
             params!(Uuid, String) => BinaryFunc::UuidGenerateV5 => Uuid, oid::FUNC_PG_UUID_GENERATE_V5;
         },
-        "variance" => Scalar {
-            params!(Float32) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Float64, 2151;
-            params!(Float64) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Float64, 2152;
-            params!(Int16) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Numeric, 2150;
-            params!(Int32) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Numeric, 2149;
-            params!(Int64) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Numeric, 2148;
-            params!(UInt16) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Numeric, oid::FUNC_VARIANCE_UINT16_OID;
-            params!(UInt32) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Numeric, oid::FUNC_VARIANCE_UINT32_OID;
-            params!(UInt64) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Numeric, oid::FUNC_VARIANCE_UINT64_OID;
-        },
-        "var_pop" => Scalar {
-            params!(Float32) => Operation::nullary(|_ecx| catalog_name_only!("var_pop")) => Float64, 2721;
-            params!(Float64) => Operation::nullary(|_ecx| catalog_name_only!("var_pop")) => Float64, 2722;
-            params!(Int16) => Operation::nullary(|_ecx| catalog_name_only!("var_pop")) => Numeric, 2720;
-            params!(Int32) => Operation::nullary(|_ecx| catalog_name_only!("var_pop")) => Numeric, 2719;
-            params!(Int64) => Operation::nullary(|_ecx| catalog_name_only!("var_pop")) => Numeric, 2718;
-            params!(UInt16) => Operation::nullary(|_ecx| catalog_name_only!("var_pop")) => Numeric, oid::FUNC_VAR_POP_UINT16_OID;
-            params!(UInt32) => Operation::nullary(|_ecx| catalog_name_only!("var_pop")) => Numeric, oid::FUNC_VAR_POP_UINT32_OID;
-            params!(UInt64) => Operation::nullary(|_ecx| catalog_name_only!("var_pop")) => Numeric, oid::FUNC_VAR_POP_UINT64_OID;
-        },
-        "var_samp" => Scalar {
-            params!(Float32) => Operation::nullary(|_ecx| catalog_name_only!("var_samp")) => Float64, 2644;
-            params!(Float64) => Operation::nullary(|_ecx| catalog_name_only!("var_samp")) => Float64, 2645;
-            params!(Int16) => Operation::nullary(|_ecx| catalog_name_only!("var_samp")) => Numeric, 2643;
-            params!(Int32) => Operation::nullary(|_ecx| catalog_name_only!("var_samp")) => Numeric, 2642;
-            params!(Int64) => Operation::nullary(|_ecx| catalog_name_only!("var_samp")) => Numeric, 2641;
-            params!(UInt16) => Operation::nullary(|_ecx| catalog_name_only!("var_samp")) => Numeric, oid::FUNC_VAR_SAMP_UINT16_OID;
-            params!(UInt32) => Operation::nullary(|_ecx| catalog_name_only!("var_samp")) => Numeric, oid::FUNC_VAR_SAMP_UINT32_OID;
-            params!(UInt64) => Operation::nullary(|_ecx| catalog_name_only!("var_samp")) => Numeric, oid::FUNC_VAR_SAMP_UINT64_OID;
-        },
         "version" => Scalar {
             params!() => UnmaterializableFunc::Version => String, 89;
         },
+        "websearch_to_tsquery" => Scalar {
+            params!(String) => default_text_search_config(BinaryFunc::WebsearchToTsQuery) => TsQuery, 5009;
+            params!(String, String) => BinaryFunc::WebsearchToTsQuery => TsQuery, 5007;
+        },
 
         // Internal conversion stubs.
         "aclitemin" => Scalar {
@@ -2773,6 +3051,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "anyelement_in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anyelement_in")) => AnyElement, 2312;
         },
+        "anyenum_in" => Scalar {
+            params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anyenum_in")) => EnumAny, 3504;
+        },
         "anynonarray_in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anynonarray_in")) => NonVecAny, 2777;
         },
@@ -2994,6 +3275,15 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             }) => Int64, 2803;
             params!(Any) => AggregateFunc::Count => Int64, 2147;
         },
+        "corr" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::Corr) => Float64, 2829;
+        },
+        "covar_pop" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::CovarPop) => Float64, 2827;
+        },
+        "covar_samp" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::CovarSamp) => Float64, 2828;
+        },
         "max" => Aggregate {
             params!(Bool) => AggregateFunc::MaxBool => Bool, oid::FUNC_MAX_BOOL_OID;
             params!(Int16) => AggregateFunc::MaxInt16 => Int16, 2117;
@@ -3077,6 +3367,108 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 Ok((e, AggregateFunc::JsonbObjectAgg { order_by }))
             }) => Jsonb, 3270;
         },
+        "mode" => Aggregate {
+            params!(AnyElement) => Operation::unary_ordered(|_ecx, value, order_by| {
+                // `mode` has no direct argument, so use a placeholder.
+                let e = ordered_set_input(HirScalarExpr::literal_true(), value);
+                Ok((e, AggregateFunc::Mode { order_by }))
+            }) => AnyElement, 3984;
+        },
+        "percentile_cont" => Aggregate {
+            params!(Float64, Float64) => Operation::binary_ordered(|_ecx, fraction, value, order_by| {
+                let fraction = fraction.call_unary(UnaryFunc::CheckPercentileFraction(func::CheckPercentileFraction));
+                let e = ordered_set_input(fraction, value);
+                Ok((e, AggregateFunc::PercentileCont { order_by }))
+            }) => Float64, 3974;
+            params!(Float64, Interval) => Operation::binary(|_ecx, _fraction, _value| {
+                bail_unsupported!("percentile_cont on interval")
+            }) => Interval, 3976;
+            params!(ScalarType::Array(Box::new(ScalarType::Float64)), Float64) => Operation::binary_ordered(|_ecx, fractions, value, order_by| {
+                let fractions = fractions.call_unary(UnaryFunc::CheckPercentileFractions(func::CheckPercentileFractions));
+                let e = ordered_set_input(fractions, value);
+                Ok((e, AggregateFunc::PercentileContMulti { order_by }))
+            }) => ScalarType::Array(Box::new(ScalarType::Float64)), 3980;
+            params!(ScalarType::Array(Box::new(ScalarType::Float64)), Interval) => Operation::binary(|_ecx, _fractions, _value| {
+                bail_unsupported!("percentile_cont on interval")
+            }) => ScalarType::Array(Box::new(ScalarType::Interval)), 3982;
+        },
+        "percentile_disc" => Aggregate {
+            params!(Float64, AnyElement) => Operation::binary_ordered(|_ecx, fraction, value, order_by| {
+                let fraction = fraction.call_unary(UnaryFunc::CheckPercentileFraction(func::CheckPercentileFraction));
+                let e = ordered_set_input(fraction, value);
+                Ok((e, AggregateFunc::PercentileDisc { order_by }))
+            }) => AnyElement, 3972;
+            params!(ScalarType::Array(Box::new(ScalarType::Float64)), AnyElement) => Operation::binary_ordered(|ecx, fractions, value, order_by| {
+                if let Err(elem_type) = ecx.scalar_type(&value).array_of_self_elem_type() {
+                    bail_unsupported!(
+                        format!("percentile_disc on {}", ecx.humanize_scalar_type(&elem_type))
+                    );
+                }
+                let fractions = fractions.call_unary(UnaryFunc::CheckPercentileFractions(func::CheckPercentileFractions));
+                let e = ordered_set_input(fractions, value);
+                Ok((e, AggregateFunc::PercentileDiscMulti { order_by }))
+            }) => ArrayAny, 3978;
+        },
+        "regr_avgx" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrAvgX) => Float64, 2822;
+        },
+        "regr_avgy" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrAvgY) => Float64, 2823;
+        },
+        "regr_count" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrCount) => Int64, 2818;
+        },
+        "regr_intercept" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrIntercept) => Float64, 2826;
+        },
+        "regr_r2" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrR2) => Float64, 2824;
+        },
+        "regr_slope" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrSlope) => Float64, 2825;
+        },
+        "regr_sxx" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrSxx) => Float64, 2819;
+        },
+        "regr_sxy" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrSxy) => Float64, 2821;
+        },
+        "regr_syy" => Aggregate {
+            params!(Float64, Float64) => regression(RegressionFunc::RegrSyy) => Float64, 2820;
+        },
+        "stddev" => Aggregate {
+            params!(Float32) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::StddevSamp } => Float64, 2157;
+            params!(Float64) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::StddevSamp } => Float64, 2158;
+            params!(Int16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, 2156;
+            params!(Int32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, 2155;
+            params!(Int64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, 2154;
+            params!(UInt16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, oid::FUNC_STDDEV_UINT16_OID;
+            params!(UInt32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, oid::FUNC_STDDEV_UINT32_OID;
+            params!(UInt64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, oid::FUNC_STDDEV_UINT64_OID;
+            params!(Numeric) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, 2159;
+        },
+        "stddev_pop" => Aggregate {
+            params!(Float32) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::StddevPop } => Float64, 2727;
+            params!(Float64) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::StddevPop } => Float64, 2728;
+            params!(Int16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevPop } => Numeric, 2726;
+            params!(Int32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevPop } => Numeric, 2725;
+            params!(Int64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevPop } => Numeric, 2724;
+            params!(UInt16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevPop } => Numeric, oid::FUNC_STDDEV_POP_UINT16_OID;
+            params!(UInt32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevPop } => Numeric, oid::FUNC_STDDEV_POP_UINT32_OID;
+            params!(UInt64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevPop } => Numeric, oid::FUNC_STDDEV_POP_UINT64_OID;
+            params!(Numeric) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevPop } => Numeric, 2729;
+        },
+        "stddev_samp" => Aggregate {
+            params!(Float32) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::StddevSamp } => Float64, 2715;
+            params!(Float64) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::StddevSamp } => Float64, 2716;
+            params!(Int16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, 2714;
+            params!(Int32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, 2713;
+            params!(Int64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, 2712;
+            params!(UInt16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, oid::FUNC_STDDEV_SAMP_UINT16_OID;
+            params!(UInt32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, oid::FUNC_STDDEV_SAMP_UINT32_OID;
+            params!(UInt64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, oid::FUNC_STDDEV_SAMP_UINT64_OID;
+            params!(Numeric) => AggregateFunc::VarianceNumeric { func: VarianceFunc::StddevSamp } => Numeric, 2717;
+        },
         "string_agg" => Aggregate {
             params!(String, String) => Operation::binary_ordered(|_ecx, value, sep, order_by| {
                 let e = HirScalarExpr::CallVariadic {
@@ -3107,6 +3499,39 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 bail_unsupported!("sum(interval)");
             }) => Interval, 2113;
         },
+        "variance" => Aggregate {
+            params!(Float32) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::VarSamp } => Float64, 2151;
+            params!(Float64) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::VarSamp } => Float64, 2152;
+            params!(Int16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, 2150;
+            params!(Int32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, 2149;
+            params!(Int64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, 2148;
+            params!(UInt16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, oid::FUNC_VARIANCE_UINT16_OID;
+            params!(UInt32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, oid::FUNC_VARIANCE_UINT32_OID;
+            params!(UInt64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, oid::FUNC_VARIANCE_UINT64_OID;
+            params!(Numeric) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, 2153;
+        },
+        "var_pop" => Aggregate {
+            params!(Float32) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::VarPop } => Float64, 2721;
+            params!(Float64) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::VarPop } => Float64, 2722;
+            params!(Int16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarPop } => Numeric, 2720;
+            params!(Int32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarPop } => Numeric, 2719;
+            params!(Int64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarPop } => Numeric, 2718;
+            params!(UInt16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarPop } => Numeric, oid::FUNC_VAR_POP_UINT16_OID;
+            params!(UInt32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarPop } => Numeric, oid::FUNC_VAR_POP_UINT32_OID;
+            params!(UInt64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarPop } => Numeric, oid::FUNC_VAR_POP_UINT64_OID;
+            params!(Numeric) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarPop } => Numeric, 2723;
+        },
+        "var_samp" => Aggregate {
+            params!(Float32) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::VarSamp } => Float64, 2644;
+            params!(Float64) => AggregateFunc::VarianceFloat64 { func: VarianceFunc::VarSamp } => Float64, 2645;
+            params!(Int16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, 2643;
+            params!(Int32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, 2642;
+            params!(Int64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, 2641;
+            params!(UInt16) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, oid::FUNC_VAR_SAMP_UINT16_OID;
+            params!(UInt32) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, oid::FUNC_VAR_SAMP_UINT32_OID;
+            params!(UInt64) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, oid::FUNC_VAR_SAMP_UINT64_OID;
+            params!(Numeric) => AggregateFunc::VarianceNumeric { func: VarianceFunc::VarSamp } => Numeric, 2646;
+        },
 
         // Scalar window functions.
         "row_number" => ScalarWindow {
@@ -3118,6 +3543,18 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "dense_rank" => ScalarWindow {
             params!() => ScalarWindowFunc::DenseRank => Int64, 3102;
         },
+        "percent_rank" => ScalarWindow {
+            params!() => ScalarWindowFunc::PercentRank => Float64, 3103;
+        },
+        "cume_dist" => ScalarWindow {
+            params!() => ScalarWindowFunc::CumeDist => Float64, 3104;
+        },
+        "ntile" => ValueWindow {
+            params!(Int32) => Operation::unary(|_ecx, num_buckets| {
+                let num_buckets = num_buckets.call_unary(UnaryFunc::CheckNtileArgument(func::CheckNtileArgument));
+                Ok((num_buckets, ValueWindowFunc::Ntile))
+            }) => Int32, 3105;
+        },
         "lag" => ValueWindow {
             // All args are encoded into a single record to be handled later
             params!(AnyElement) => Operation::unary(|ecx, e| {
@@ -3188,6 +3625,18 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "last_value" => ValueWindow {
             params!(AnyElement) => ValueWindowFunc::LastValue => AnyElement, 3113;
         },
+        "nth_value" => ValueWindow {
+            // Both args are encoded into a single record to be handled later
+            params!(AnyElement, Int32) => Operation::binary(|_ecx, e, n| {
+                let e = HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::RecordCreate {
+                        field_names: vec![ColumnName::from("expr"), ColumnName::from("n")]
+                    },
+                    exprs: vec![e, n.call_unary(UnaryFunc::CheckNthValueArgument(func::CheckNthValueArgument))],
+                };
+                Ok((e, ValueWindowFunc::NthValue))
+            }) => AnyElement, 3114;
+        },
 
         // Table functions.
         "generate_series" => Table {
@@ -3316,6 +3765,20 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 })
             }) => ReturnType::set_of(String.into()), 3931;
         },
+        "jsonb_path_query" => Table {
+            params!(Jsonb, JsonPath) => sql_impl_table_func("
+                SELECT value AS jsonb_path_query
+                FROM jsonb_array_elements(jsonb_path_query_array($1, $2))
+            ") => ReturnType::set_of(Jsonb.into()), oid::FUNC_JSONB_PATH_QUERY_NOVARS_OID;
+            params!(Jsonb, JsonPath, Jsonb) => sql_impl_table_func("
+                SELECT value AS jsonb_path_query
+                FROM jsonb_array_elements(jsonb_path_query_array($1, $2, $3))
+            ") => ReturnType::set_of(Jsonb.into()), oid::FUNC_JSONB_PATH_QUERY_NOSILENT_OID;
+            params!(Jsonb, JsonPath, Jsonb, Bool) => sql_impl_table_func("
+                SELECT value AS jsonb_path_query
+                FROM jsonb_array_elements(jsonb_path_query_array($1, $2, $3, $4))
+            ") => ReturnType::set_of(Jsonb.into()), 4006;
+        },
         // Note that these implementations' input to `generate_series` is
         // contrived to match Flink's expected values. There are other,
         // equally valid windows we could generate.
@@ -3447,6 +3910,9 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Float64) => Operation::nullary(|_ecx| catalog_name_only!("avg_internal_v1")) => Float64, oid::FUNC_AVG_INTERNAL_V1_FLOAT64_OID;
             params!(Interval) => Operation::nullary(|_ecx| catalog_name_only!("avg_internal_v1")) => Interval, oid::FUNC_AVG_INTERNAL_V1_INTERVAL_OID;
         },
+        "approx_count_distinct" => Aggregate {
+            params!(Any) => Operation::nullary(|_ecx| catalog_name_only!("approx_count_distinct")) => Int64, oid::FUNC_APPROX_COUNT_DISTINCT_OID;
+        },
         "csv_extract" => Table {
             params!(Int64, String) => Operation::binary(move |_ecx, ncols, input| {
                 const MAX_EXTRACT_COLUMNS: i64 = 8192;
@@ -3593,6 +4059,18 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, String) => sql_impl_func("has_type_privilege(current_user, $1, $2)") => Bool, 3142;
             params!(Oid, String) => sql_impl_func("has_type_privilege(current_user, $1, $2)") => Bool, 3143;
         },
+        "hll_estimate" => Scalar {
+            params!(Hll) => UnaryFunc::HllEstimate(func::HllEstimate) => Int64, oid::FUNC_HLL_ESTIMATE_OID;
+        },
+        "hll_merge" => Aggregate {
+            params!(Hll) => Operation::unary(|_ecx, e| Ok((e, AggregateFunc::HllMerge))) => Hll, oid::FUNC_HLL_MERGE_OID;
+        },
+        "hll_sketch" => Aggregate {
+            // Sketches each value on its own, then merges the sketches.
+            params!(Any) => Operation::unary(|_ecx, e| {
+                Ok((e.call_unary(UnaryFunc::HllSingleton(func::HllSingleton)), AggregateFunc::HllMerge))
+            }) => Hll, oid::FUNC_HLL_SKETCH_OID;
+        },
         "list_agg" => Aggregate {
             params!(Any) => Operation::unary_ordered(|ecx, e, order_by| {
                 if let ScalarType::Char {.. }  = ecx.scalar_type(&e) {
@@ -4077,6 +4555,80 @@ fn digest(algorithm: &'static str) -> Operation<HirScalarExpr> {
     })
 }
 
+/// Plans a two-argument regression aggregate, which takes its `(y, x)`
+/// arguments as a single record.
+fn regression(func: RegressionFunc) -> Operation<(HirScalarExpr, AggregateFunc)> {
+    Operation::binary(move |_ecx, y, x| {
+        let e = HirScalarExpr::CallVariadic {
+            func: VariadicFunc::RecordCreate {
+                field_names: vec![ColumnName::from("y"), ColumnName::from("x")],
+            },
+            exprs: vec![y, x],
+        };
+        Ok((e, AggregateFunc::Regression { func }))
+    })
+}
+
+/// Builds the `(direct_arg, value)` record that ordered-set aggregates take as
+/// input.
+fn ordered_set_input(direct_arg: HirScalarExpr, value: HirScalarExpr) -> HirScalarExpr {
+    HirScalarExpr::CallVariadic {
+        func: VariadicFunc::RecordCreate {
+            field_names: vec![ColumnName::from("direct_arg"), ColumnName::from("value")],
+        },
+        exprs: vec![direct_arg, value],
+    }
+}
+
+/// Plans a `jsonb_path_*` function that was called without its optional
+/// `vars` or `silent` arguments, which default to an empty object and to
+/// `silent`, respectively.
+fn jsonb_path_defaults(func: VariadicFunc, silent: bool) -> Operation<HirScalarExpr> {
+    Operation::variadic(move |_ecx, mut exprs| {
+        if exprs.len() < 3 {
+            let vars = HirScalarExpr::literal(Datum::String("{}"), ScalarType::String);
+            exprs.push(vars.call_unary(UnaryFunc::CastStringToJsonb(func::CastStringToJsonb)));
+        }
+        if exprs.len() < 4 {
+            exprs.push(HirScalarExpr::literal(
+                Datum::from(silent),
+                ScalarType::Bool,
+            ));
+        }
+        Ok(HirScalarExpr::CallVariadic {
+            func: func.clone(),
+            exprs,
+        })
+    })
+}
+
+/// Plans the forms of `jsonb_set`, `jsonb_insert` and `jsonb_set_lax` that
+/// omit trailing arguments, filling them in from `defaults`, which holds the
+/// default for each argument following the new value.
+fn jsonb_modify_defaults(
+    func: VariadicFunc,
+    defaults: Vec<(Datum<'static>, ScalarType)>,
+) -> Operation<HirScalarExpr> {
+    Operation::variadic(move |_ecx, mut exprs| {
+        for (datum, typ) in &defaults[exprs.len() - 3..] {
+            exprs.push(HirScalarExpr::literal(*datum, typ.clone()));
+        }
+        Ok(HirScalarExpr::CallVariadic {
+            func: func.clone(),
+            exprs,
+        })
+    })
+}
+
+/// Plans the single-argument form of a text search function, which uses the
+/// default text search configuration.
+fn default_text_search_config(func: BinaryFunc) -> Operation<HirScalarExpr> {
+    Operation::unary(move |_ecx, input| {
+        let config = HirScalarExpr::literal(Datum::String("english"), ScalarType::String);
+        Ok(config.call_binary(input, func.clone()))
+    })
+}
+
 fn array_to_string(
     ecx: &ExprContext,
     exprs: Vec<HirScalarExpr>,
@@ -4269,6 +4821,9 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(UInt32, UInt32) => BitShiftLeftUInt32 => UInt32, oid::FUNC_SHIFT_LEFT_UINT32;
             params!(UInt64, UInt32) => BitShiftLeftUInt64 => UInt64, oid::FUNC_SHIFT_LEFT_UINT64;
             params!(RangeAny, RangeAny) => RangeBefore => Bool, 3893;
+            params!(Inet, Inet) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BinaryFunc::InetContainsInet { rev: true }))
+            }) => Bool, 931;
         },
         ">>" => Scalar {
             params!(Int16, Int32) => BitShiftRightInt16 => Int16, 1879;
@@ -4278,6 +4833,19 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(UInt32, UInt32) => BitShiftRightUInt32 => UInt32, oid::FUNC_SHIFT_RIGHT_UINT32;
             params!(UInt64, UInt32) => BitShiftRightUInt64 => UInt64, oid::FUNC_SHIFT_RIGHT_UINT64;
             params!(RangeAny, RangeAny) => RangeAfter => Bool, 3894;
+            params!(Inet, Inet) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(lhs.call_binary(rhs, BinaryFunc::InetContainsInet { rev: false }))
+            }) => Bool, 933;
+        },
+        "<<=" => Scalar {
+            params!(Inet, Inet) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BinaryFunc::InetContainsOrEqualsInet { rev: true }))
+            }) => Bool, 932;
+        },
+        ">>=" => Scalar {
+            params!(Inet, Inet) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(lhs.call_binary(rhs, BinaryFunc::InetContainsOrEqualsInet { rev: false }))
+            }) => Bool, 934;
         },
 
         // ILIKE
@@ -4428,6 +4996,9 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
         "#>" => Scalar {
             params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String))) => JsonbGetPath { stringify: false } => Jsonb, 3213;
         },
+        "#-" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String))) => JsonbDeletePath => Jsonb, 3287;
+        },
         "#>>" => Scalar {
             params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String))) => JsonbGetPath { stringify: true } => String, 3206;
         },
@@ -4492,6 +5063,21 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
         },
         "&&" => Scalar {
             params!(RangeAny, RangeAny) => BinaryFunc::RangeOverlaps => Bool, 3888;
+            params!(Inet, Inet) => BinaryFunc::InetOverlaps => Bool, 3552;
+        },
+        "@@" => Scalar {
+            params!(TsVector, TsQuery) => BinaryFunc::TsMatch => Bool, 3636;
+            params!(TsQuery, TsVector) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BinaryFunc::TsMatch))
+            }) => Bool, 3637;
+            params!(String, TsQuery) => Operation::binary(|_ecx, lhs, rhs| {
+                let config = HirScalarExpr::literal(Datum::String("english"), ScalarType::String);
+                Ok(config.call_binary(lhs, BinaryFunc::ToTsVector).call_binary(rhs, BinaryFunc::TsMatch))
+            }) => Bool, 3760;
+            params!(Jsonb, JsonPath) => jsonb_path_defaults(VariadicFunc::JsonbPathMatch, true) => Bool, 4013;
+        },
+        "@?" => Scalar {
+            params!(Jsonb, JsonPath) => jsonb_path_defaults(VariadicFunc::JsonbPathExists, true) => Bool, 4012;
         },
         "&<" => Scalar {
             params!(RangeAny, RangeAny) => BinaryFunc::RangeOverleft => Bool, 3895;
@@ -4521,6 +5107,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Lt => Bool, 2062;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Lt => Bool, 1322;
             params!(Uuid, Uuid) => BinaryFunc::Lt => Bool, 2974;
+            params!(Inet, Inet) => BinaryFunc::Lt => Bool, 1203;
             params!(Interval, Interval) => BinaryFunc::Lt => Bool, 1332;
             params!(Bytes, Bytes) => BinaryFunc::Lt => Bool, 1957;
             params!(String, String) => BinaryFunc::Lt => Bool, 664;
@@ -4532,6 +5119,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lt => Bool, 2990;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lt =>Bool, oid::FUNC_MZ_TIMESTAMP_LT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lt => Bool, 3884;
+            params!(EnumAny, EnumAny) => BinaryFunc::Lt => Bool, 3518;
         },
         "<=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Lte => Bool, 1755;
@@ -4550,6 +5138,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Lte => Bool, 2063;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Lte => Bool, 1323;
             params!(Uuid, Uuid) => BinaryFunc::Lte => Bool, 2976;
+            params!(Inet, Inet) => BinaryFunc::Lte => Bool, 1204;
             params!(Interval, Interval) => BinaryFunc::Lte => Bool, 1333;
             params!(Bytes, Bytes) => BinaryFunc::Lte => Bool, 1958;
             params!(String, String) => BinaryFunc::Lte => Bool, 665;
@@ -4561,6 +5150,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lte => Bool, 2992;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lte =>Bool, oid::FUNC_MZ_TIMESTAMP_LTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lte => Bool, 3885;
+            params!(EnumAny, EnumAny) => BinaryFunc::Lte => Bool, 3520;
         },
         ">" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gt => Bool, 1756;
@@ -4579,6 +5169,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Gt => Bool, 2064;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Gt => Bool, 1324;
             params!(Uuid, Uuid) => BinaryFunc::Gt => Bool, 2975;
+            params!(Inet, Inet) => BinaryFunc::Gt => Bool, 1205;
             params!(Interval, Interval) => BinaryFunc::Gt => Bool, 1334;
             params!(Bytes, Bytes) => BinaryFunc::Gt => Bool, 1959;
             params!(String, String) => BinaryFunc::Gt => Bool, 666;
@@ -4590,6 +5181,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gt => Bool, 2991;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gt =>Bool, oid::FUNC_MZ_TIMESTAMP_GT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gt => Bool, 3887;
+            params!(EnumAny, EnumAny) => BinaryFunc::Gt => Bool, 3519;
         },
         ">=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gte => Bool, 1757;
@@ -4608,6 +5200,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Gte => Bool, 2065;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Gte => Bool, 1325;
             params!(Uuid, Uuid) => BinaryFunc::Gte => Bool, 2977;
+            params!(Inet, Inet) => BinaryFunc::Gte => Bool, 1206;
             params!(Interval, Interval) => BinaryFunc::Gte => Bool, 1335;
             params!(Bytes, Bytes) => BinaryFunc::Gte => Bool, 1960;
             params!(String, String) => BinaryFunc::Gte => Bool, 667;
@@ -4619,6 +5212,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gte => Bool, 2993;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gte =>Bool, oid::FUNC_MZ_TIMESTAMP_GTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gte => Bool, 3886;
+            params!(EnumAny, EnumAny) => BinaryFunc::Gte => Bool, 3521;
         },
         // Warning!
         // - If you are writing functions here that do not simply use
@@ -4646,6 +5240,9 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Eq => Bool, 2060;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Eq => Bool, 1320;
             params!(Uuid, Uuid) => BinaryFunc::Eq => Bool, 2972;
+            params!(Inet, Inet) => BinaryFunc::Eq => Bool, 1201;
+            params!(TsVector, TsVector) => BinaryFunc::Eq => Bool, 3629;
+            params!(TsQuery, TsQuery) => BinaryFunc::Eq => Bool, 3676;
             params!(Interval, Interval) => BinaryFunc::Eq => Bool, 1330;
             params!(Bytes, Bytes) => BinaryFunc::Eq => Bool, 1955;
             params!(String, String) => BinaryFunc::Eq => Bool, 98;
@@ -4658,6 +5255,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Eq => Bool, 2988;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_TIMESTAMP_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Eq => Bool, 3882;
+            params!(EnumAny, EnumAny) => BinaryFunc::Eq => Bool, 3516;
             params!(MzAclItem, MzAclItem) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_ACL_ITEM_EQ_MZ_ACL_ITEM_OID;
             params!(AclItem, AclItem) => BinaryFunc::Eq => Bool, 974;
         },
@@ -4678,6 +5276,9 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::NotEq => Bool, 2061;
             params!(TimestampTz, TimestampTz) => BinaryFunc::NotEq => Bool, 1321;
             params!(Uuid, Uuid) => BinaryFunc::NotEq => Bool, 2973;
+            params!(Inet, Inet) => BinaryFunc::NotEq => Bool, 1202;
+            params!(TsVector, TsVector) => BinaryFunc::NotEq => Bool, 3630;
+            params!(TsQuery, TsQuery) => BinaryFunc::NotEq => Bool, 3678;
             params!(Interval, Interval) => BinaryFunc::NotEq => Bool, 1331;
             params!(Bytes, Bytes) => BinaryFunc::NotEq => Bool, 1956;
             params!(String, String) => BinaryFunc::NotEq => Bool, 531;
@@ -4689,6 +5290,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::NotEq => Bool, 2989;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_TIMESTAMP_NOT_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::NotEq => Bool, 3883;
+            params!(EnumAny, EnumAny) => BinaryFunc::NotEq => Bool, 3517;
             params!(MzAclItem, MzAclItem) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_ACL_ITEM_NOT_EQ_MZ_ACL_ITEM_OID;
         }
     }
@@ -1483,6 +1483,28 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 }
                 CteBlock::Simple(result_ctes)
             }
+            CteBlock::Recursive(ctes) => {
+                let mut result_ctes = Vec::<Cte<Aug>>::new();
+
+                let initial_id = self.ctes.len();
+
+                for (offset, cte) in ctes.into_iter().enumerate() {
+                    let cte_name = normalize::ident(cte.alias.name.clone());
+                    let local_id = LocalId::new(u64::cast_from(initial_id + offset));
+
+                    // Unlike in a simple block, the CTE is in scope in its own
+                    // query.
+                    let shadowed_id = self.ctes.insert(cte_name.clone(), local_id);
+                    shadowed_cte_ids.push((cte_name, shadowed_id));
+
+                    result_ctes.push(Cte {
+                        alias: cte.alias,
+                        id: local_id,
+                        query: self.fold_query(cte.query),
+                    });
+                }
+                CteBlock::Recursive(result_ctes)
+            }
             CteBlock::MutuallyRecursive(MutRecBlock { options, ctes }) => {
                 let mut result_ctes = Vec::<CteMutRec<Aug>>::new();
 
@@ -1868,6 +1890,22 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
         }
     }
 
+    fn fold_show_create_function_statement(
+        &mut self,
+        node: ShowCreateFunctionStatement<Raw>,
+    ) -> ShowCreateFunctionStatement<Aug> {
+        ShowCreateFunctionStatement {
+            function_name: self.resolve_item_name(
+                node.function_name,
+                ItemResolutionConfig {
+                    functions: true,
+                    types: false,
+                    relations: false,
+                },
+            ),
+        }
+    }
+
     fn fold_table_factor(
         &mut self,
         node: mz_sql_parser::ast::TableFactor<Raw>,
@@ -1925,6 +1963,27 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 join: Box::new(self.fold_table_with_joins(*join)),
                 alias: alias.map(|alias| self.fold_table_alias(alias)),
             },
+            JsonTable {
+                doc,
+                path,
+                passing,
+                columns,
+                on_error,
+                alias,
+            } => JsonTable {
+                doc: self.fold_expr(doc),
+                path,
+                passing: passing
+                    .into_iter()
+                    .map(|p| self.fold_json_table_passing(p))
+                    .collect(),
+                columns: columns
+                    .into_iter()
+                    .map(|c| self.fold_json_table_column(c))
+                    .collect(),
+                on_error: on_error.map(|b| self.fold_json_table_behavior(b)),
+                alias: alias.map(|alias| self.fold_table_alias(alias)),
+            },
         }
     }
 
@@ -1934,10 +1993,10 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
     ) -> GrantTargetSpecification<Aug> {
         match node {
             GrantTargetSpecification::Object {
-                object_type: ObjectType::Type,
+                object_type: object_type @ (ObjectType::Type | ObjectType::Func),
                 object_spec_inner: GrantTargetSpecificationInner::Objects { names },
             } => GrantTargetSpecification::Object {
-                object_type: ObjectType::Type,
+                object_type,
                 object_spec_inner: GrantTargetSpecificationInner::Objects {
                     names: names
                         .into_iter()
@@ -1946,10 +2005,12 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                                 ResolvedObjectName::Item(self.resolve_item_name_name(
                                     name,
                                     // `{GRANT|REVOKE} ... ON TYPE ...` can only
-                                    // refer to type names.
+                                    // refer to type names, and
+                                    // `{GRANT|REVOKE} ... ON FUNCTION ...` can
+                                    // only refer to function names.
                                     ItemResolutionConfig {
-                                        functions: false,
-                                        types: true,
+                                        functions: object_type == ObjectType::Func,
+                                        types: object_type == ObjectType::Type,
                                         relations: false,
                                     },
                                 ))
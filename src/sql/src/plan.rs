@@ -39,16 +39,19 @@ use mz_expr::refresh_schedule::RefreshSchedule;
 use mz_expr::{CollectionPlan, ColumnOrder, MirRelationExpr, MirScalarExpr, RowSetFinishing};
 use mz_ore::now::{self, NOW_ZERO};
 use mz_pgcopy::CopyFormatParams;
+use mz_repr::adt::collation::Collation;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
 use mz_repr::role_id::RoleId;
-use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType};
+use mz_repr::{ColumnName, ColumnType, Diff, GlobalId, RelationDesc, Row, ScalarType};
 use mz_sql_parser::ast::{
     AlterSourceAddSubsourceOption, ConnectionOptionName, CreateSourceSubsource, QualifiedReplica,
     TransactionIsolationLevel, TransactionMode, WithOptionValue,
 };
+use mz_storage_types::connections::aws::AwsConnection;
 use mz_storage_types::connections::inline::ReferencedConnection;
-use mz_storage_types::sinks::{SinkEnvelope, StorageSinkConnection};
+use mz_storage_types::controller::TableLayout;
+use mz_storage_types::sinks::{S3SinkFormat, SinkEnvelope, StorageSinkConnection};
 use mz_storage_types::sources::{SourceDesc, Timeline};
 use serde::{Deserialize, Serialize};
 
@@ -60,6 +63,7 @@ use crate::catalog::{
     CatalogType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference, ObjectType,
     RoleAttributes,
 };
+use crate::func::Func;
 use crate::names::{
     Aug, CommentObjectId, FullItemName, ObjectId, QualifiedItemName, ResolvedDatabaseSpecifier,
     ResolvedIds, SchemaSpecifier, SystemObjectId,
@@ -116,6 +120,7 @@ pub enum Plan {
     CreateMaterializedView(CreateMaterializedViewPlan),
     CreateIndex(CreateIndexPlan),
     CreateType(CreateTypePlan),
+    CreateFunction(CreateFunctionPlan),
     Comment(CommentPlan),
     DiscardTemp,
     DiscardAll,
@@ -136,6 +141,7 @@ pub enum Plan {
     Select(SelectPlan),
     Subscribe(SubscribePlan),
     CopyFrom(CopyFromPlan),
+    CopyTo(CopyToPlan),
     ExplainPlan(ExplainPlanPlan),
     ExplainTimestamp(ExplainTimestampPlan),
     ExplainSinkSchema(ExplainSinkSchemaPlan),
@@ -161,6 +167,8 @@ pub enum Plan {
     AlterSchemaRename(AlterSchemaRenamePlan),
     AlterSchemaSwap(AlterSchemaSwapPlan),
     AlterSecret(AlterSecretPlan),
+    AlterType(AlterTypePlan),
+    AlterTable(AlterTablePlan),
     AlterSystemSet(AlterSystemSetPlan),
     AlterSystemReset(AlterSystemResetPlan),
     AlterSystemResetAll(AlterSystemResetAllPlan),
@@ -218,6 +226,8 @@ impl Plan {
             }
             StatementKind::AlterRole => vec![PlanKind::AlterRole],
             StatementKind::AlterSecret => vec![PlanKind::AlterNoop, PlanKind::AlterSecret],
+            StatementKind::AlterType => vec![PlanKind::AlterNoop, PlanKind::AlterType],
+            StatementKind::AlterTable => vec![PlanKind::AlterNoop, PlanKind::AlterTable],
             StatementKind::AlterSetCluster => {
                 vec![PlanKind::AlterNoop, PlanKind::AlterSetCluster]
             }
@@ -235,7 +245,12 @@ impl Plan {
             StatementKind::Close => vec![PlanKind::Close],
             StatementKind::Comment => vec![PlanKind::Comment],
             StatementKind::Commit => vec![PlanKind::CommitTransaction],
-            StatementKind::Copy => vec![PlanKind::CopyFrom, PlanKind::Select, PlanKind::Subscribe],
+            StatementKind::Copy => vec![
+                PlanKind::CopyFrom,
+                PlanKind::Select,
+                PlanKind::Subscribe,
+                PlanKind::CopyTo,
+            ],
             StatementKind::CreateCluster => vec![PlanKind::CreateCluster],
             StatementKind::CreateClusterReplica => vec![PlanKind::CreateClusterReplica],
             StatementKind::CreateConnection => vec![PlanKind::CreateConnection],
@@ -253,6 +268,7 @@ impl Plan {
             }
             StatementKind::CreateTable => vec![PlanKind::CreateTable],
             StatementKind::CreateType => vec![PlanKind::CreateType],
+            StatementKind::CreateFunction => vec![PlanKind::CreateFunction],
             StatementKind::CreateView => vec![PlanKind::CreateView],
             StatementKind::Deallocate => vec![PlanKind::Deallocate],
             StatementKind::Declare => vec![PlanKind::Declare],
@@ -267,7 +283,8 @@ impl Plan {
             StatementKind::Fetch => vec![PlanKind::Fetch],
             StatementKind::GrantPrivileges => vec![PlanKind::GrantPrivileges],
             StatementKind::GrantRole => vec![PlanKind::GrantRole],
-            StatementKind::Insert => vec![PlanKind::Insert],
+            StatementKind::Insert => vec![PlanKind::Insert, PlanKind::ReadThenWrite],
+            StatementKind::Merge => vec![PlanKind::ReadThenWrite],
             StatementKind::Prepare => vec![PlanKind::Prepare],
             StatementKind::Raise => vec![PlanKind::Raise],
             StatementKind::ReassignOwned => vec![PlanKind::ReassignOwned],
@@ -311,6 +328,7 @@ impl Plan {
             Plan::CreateMaterializedView(_) => "create materialized view",
             Plan::CreateIndex(_) => "create index",
             Plan::CreateType(_) => "create type",
+            Plan::CreateFunction(_) => "create function",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
             Plan::DiscardAll => "discard all",
@@ -347,6 +365,7 @@ impl Plan {
             Plan::Select(_) => "select",
             Plan::Subscribe(_) => "subscribe",
             Plan::CopyFrom(_) => "copy from",
+            Plan::CopyTo(_) => "copy to",
             Plan::ExplainPlan(_) => "explain plan",
             Plan::ExplainTimestamp(_) => "explain timestamp",
             Plan::ExplainSinkSchema(_) => "explain schema",
@@ -382,6 +401,8 @@ impl Plan {
             Plan::AlterSchemaRename(_) => "alter rename schema",
             Plan::AlterSchemaSwap(_) => "alter swap schema",
             Plan::AlterSecret(_) => "alter secret",
+            Plan::AlterType(_) => "alter type",
+            Plan::AlterTable(_) => "alter table",
             Plan::AlterSystemSet(_) => "alter system",
             Plan::AlterSystemReset(_) => "alter system",
             Plan::AlterSystemResetAll(_) => "alter system",
@@ -410,6 +431,7 @@ impl Plan {
                 MutationKind::Insert => "insert into select",
                 MutationKind::Update => "update",
                 MutationKind::Delete => "delete",
+                MutationKind::Merge => "merge",
             },
             Plan::Prepare(_) => "prepare",
             Plan::Execute(_) => "execute",
@@ -643,6 +665,12 @@ pub struct CreateTypePlan {
     pub typ: Type,
 }
 
+#[derive(Debug)]
+pub struct CreateFunctionPlan {
+    pub name: QualifiedItemName,
+    pub func: Function,
+}
+
 #[derive(Debug)]
 pub struct DropObjectsPlan {
     /// The IDs of only the objects directly referenced in the `DROP` statement.
@@ -781,6 +809,24 @@ pub struct CopyFromPlan {
     pub params: CopyFormatParams<'static>,
 }
 
+#[derive(Debug)]
+pub struct CopyToPlan {
+    /// The select query whose results are copied.
+    pub select_plan: SelectPlan,
+    /// The description of the query's results.
+    pub desc: RelationDesc,
+    /// The destination URI, which is evaluated once the statement is sequenced.
+    pub to: MirScalarExpr,
+    /// The AWS connection used to upload the results.
+    pub connection: AwsConnection,
+    /// The ID of the AWS connection.
+    pub connection_id: GlobalId,
+    /// The encoding of the uploaded files.
+    pub format: S3SinkFormat,
+    /// The maximum size in bytes of each uploaded file.
+    pub max_file_size: u64,
+}
+
 #[derive(Clone, Debug)]
 pub struct ExplainPlanPlan {
     pub stage: ExplainStage,
@@ -921,6 +967,9 @@ pub struct SendDiffsPlan {
     pub kind: MutationKind,
     pub returning: Vec<(Row, NonZeroUsize)>,
     pub max_result_size: u32,
+    /// The number of rows the mutation affected, if it is not determined by
+    /// `updates`, as is the case for `MERGE`.
+    pub affected_rows: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -938,6 +987,66 @@ pub struct ReadThenWritePlan {
     pub assignments: BTreeMap<usize, mz_expr::MirScalarExpr>,
     pub kind: MutationKind,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    /// How an `INSERT` resolves proposed rows that conflict with existing
+    /// rows. If present, each row of `selection` is a proposed row, followed
+    /// by the existing row that shares its key (or `NULL`s), followed by the
+    /// number of copies of the existing row in the table (or `NULL`).
+    pub on_conflict: Option<OnConflictPlan>,
+    /// The `WHEN` clauses of a `MERGE`, in order. Empty for other mutations.
+    /// For a `MERGE`, each row of `selection` is the target row that the
+    /// source row matched (or `NULL`s), followed by the source row, followed
+    /// by the number of copies of the target row in the table (or `NULL`).
+    pub when_clauses: Vec<MergeWhenPlan>,
+}
+
+/// The `ON CONFLICT` clause of an `INSERT`.
+#[derive(Debug, Clone)]
+pub struct OnConflictPlan {
+    /// The indices of the columns of the table's declared key on which
+    /// proposed rows conflict with existing rows.
+    pub key: Vec<usize>,
+    pub action: OnConflictAction,
+}
+
+#[derive(Debug, Clone)]
+pub enum OnConflictAction {
+    /// Skip proposed rows that conflict.
+    DoNothing,
+    /// Update the existing rows that proposed rows conflict with.
+    ///
+    /// Both `assignments` and `selection` are evaluated over the existing row
+    /// followed by the proposed (`EXCLUDED`) row.
+    DoUpdate {
+        /// Map from column index to SET expression.
+        assignments: BTreeMap<usize, mz_expr::MirScalarExpr>,
+        /// Only existing rows for which this predicate is true are updated.
+        selection: Option<mz_expr::MirScalarExpr>,
+    },
+}
+
+/// A `WHEN [NOT] MATCHED` clause of a `MERGE`.
+#[derive(Debug, Clone)]
+pub struct MergeWhenPlan {
+    /// Whether the clause applies to source rows that matched a target row.
+    pub matched: bool,
+    /// Only source rows for which this predicate is true are acted upon.
+    ///
+    /// Like the expressions of `action`, evaluated over the target row
+    /// followed by the source row if `matched`, and over the source row alone
+    /// otherwise.
+    pub condition: Option<mz_expr::MirScalarExpr>,
+    pub action: MergeActionPlan,
+}
+
+#[derive(Debug, Clone)]
+pub enum MergeActionPlan {
+    /// Update the matched target row. Maps column index to SET expression.
+    Update(BTreeMap<usize, mz_expr::MirScalarExpr>),
+    /// Delete the matched target row.
+    Delete,
+    /// Insert a row, whose columns are produced by these expressions in order.
+    Insert(Vec<mz_expr::MirScalarExpr>),
+    DoNothing,
 }
 
 /// Generated by `ALTER ... IF EXISTS` if the named object did not exist.
@@ -1075,6 +1184,47 @@ pub struct AlterSecretPlan {
     pub secret_as: MirScalarExpr,
 }
 
+#[derive(Debug)]
+pub struct AlterTypePlan {
+    pub id: GlobalId,
+    /// The type's new `CREATE TYPE` statement.
+    pub create_sql: String,
+}
+
+#[derive(Debug)]
+pub struct AlterTablePlan {
+    pub id: GlobalId,
+    /// The table's new `CREATE TABLE` statement.
+    pub create_sql: String,
+    pub action: AlterTableAction,
+    /// The columns stored in the table's shard after the alteration, which
+    /// include the columns that have been dropped from the table.
+    pub layout: TableLayout,
+    /// The items that directly depend on the table, with definitions that
+    /// keep the columns they observe from the table unchanged.
+    pub dependents: Vec<(GlobalId, String)>,
+    /// The indexes on the table, which must be rebuilt for the table's new
+    /// columns.
+    pub indexes: Vec<GlobalId>,
+}
+
+#[derive(Debug)]
+pub enum AlterTableAction {
+    /// Appends a column to the table.
+    AddColumn {
+        name: ColumnName,
+        typ: ColumnType,
+        /// The value of the new column in the table's existing rows, if not
+        /// `NULL`.
+        fill: Option<Row>,
+    },
+    /// Drops one of the table's columns.
+    DropColumn {
+        /// The position of the dropped column.
+        column: usize,
+    },
+}
+
 #[derive(Debug)]
 pub struct AlterSystemSetPlan {
     pub name: String,
@@ -1229,6 +1379,13 @@ pub struct Table {
     pub create_sql: String,
     pub desc: RelationDesc,
     pub defaults: Vec<Expr<Aug>>,
+    /// The domain that each column was declared as, if any.
+    pub domains: Vec<Option<GlobalId>>,
+    /// The collation that each column was declared with, if any.
+    pub collations: Vec<Option<Collation>>,
+    /// The columns of each of the table's `PRIMARY KEY` and `UNIQUE`
+    /// constraints, which writes to the table must not violate.
+    pub unique_keys: Vec<Vec<usize>>,
     pub temporary: bool,
 }
 
@@ -1401,6 +1558,12 @@ pub struct Type {
     pub inner: CatalogType<IdReference>,
 }
 
+#[derive(Debug)]
+pub struct Function {
+    pub create_sql: String,
+    pub inner: Func,
+}
+
 /// Specifies when a `Peek` or `Subscribe` should occur.
 #[derive(Deserialize, Clone, Debug, PartialEq)]
 pub enum QueryWhen {
@@ -1481,6 +1644,7 @@ pub enum MutationKind {
     Insert,
     Update,
     Delete,
+    Merge,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
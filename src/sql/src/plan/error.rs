@@ -24,6 +24,7 @@ use mz_postgres_util::PostgresError;
 use mz_repr::adt::char::InvalidCharLengthError;
 use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::adt::numeric::InvalidNumericMaxScaleError;
+use mz_repr::adt::pg_legacy_name::NAME_MAX_BYTES;
 use mz_repr::adt::timestamp::InvalidTimestampPrecisionError;
 use mz_repr::adt::varchar::InvalidVarCharMaxLengthError;
 use mz_repr::{strconv, ColumnName, GlobalId};
@@ -39,8 +40,8 @@ use crate::plan::plan_utils::JoinSide;
 use crate::plan::scope::ScopeItem;
 use crate::pure::error::{
     CsrPurificationError, KafkaSinkPurificationError, KafkaSourcePurificationError,
-    LoadGeneratorSourcePurificationError, MySqlSourcePurificationError, PgSourcePurificationError,
-    TestScriptSourcePurificationError,
+    LoadGeneratorSourcePurificationError, MySqlSourcePurificationError, PgSinkPurificationError,
+    PgSourcePurificationError, TestScriptSourcePurificationError,
 };
 use crate::session::vars::VarError;
 
@@ -240,6 +241,7 @@ pub enum PlanError {
     PgSourcePurification(PgSourcePurificationError),
     KafkaSourcePurification(KafkaSourcePurificationError),
     KafkaSinkPurification(KafkaSinkPurificationError),
+    PgSinkPurification(PgSinkPurificationError),
     TestScriptSourcePurification(TestScriptSourcePurificationError),
     LoadGeneratorSourcePurification(LoadGeneratorSourcePurificationError),
     CsrPurification(CsrPurificationError),
@@ -259,6 +261,9 @@ pub enum PlanError {
     AlterSourceSinkSizeUnsupported {
         cluster: String,
     },
+    InvalidEnumLabel {
+        label: String,
+    },
     // TODO(benesch): eventually all errors should be structured.
     Unstructured(String),
 }
@@ -311,6 +316,11 @@ impl PlanError {
             Self::LoadGeneratorSourcePurification(e) => e.detail(),
             Self::CsrPurification(e) => e.detail(),
             Self::KafkaSinkPurification(e) => e.detail(),
+            Self::PgSinkPurification(e) => e.detail(),
+            Self::InvalidEnumLabel { .. } => Some(format!(
+                "Labels must be {} bytes or less.",
+                NAME_MAX_BYTES
+            )),
             Self::CreateReplicaFailStorageObjects { current_replica_count: current, internal_replica_count: internal, hypothetical_replica_count: target } => {
                 Some(format!(
                     "Currently have {} replica{}{}; command would result in {}",
@@ -415,6 +425,7 @@ impl PlanError {
             Self::LoadGeneratorSourcePurification(e) => e.hint(),
             Self::CsrPurification(e) => e.hint(),
             Self::KafkaSinkPurification(e) => e.hint(),
+            Self::PgSinkPurification(e) => e.hint(),
             Self::UnknownColumn { table, similar, .. } => {
                 let suffix = "Make sure to surround case sensitive names in double quotes.";
                 match &similar[..] {
@@ -679,6 +690,7 @@ impl fmt::Display for PlanError {
             Self::TestScriptSourcePurification(e) => write!(f, "TEST SCRIPT source validation: {}", e),
             Self::LoadGeneratorSourcePurification(e) => write!(f, "LOAD GENERATOR source validation: {}", e),
             Self::KafkaSinkPurification(e) => write!(f, "KAFKA sink validation: {}", e),
+            Self::PgSinkPurification(e) => write!(f, "POSTGRES sink validation: {}", e),
             Self::CsrPurification(e) => write!(f, "CONFLUENT SCHEMA REGISTRY validation: {}", e),
             Self::MySqlSourcePurification(e) => write!(f, "MYSQL source validation: {}", e),
             Self::MangedReplicaName(name) => {
@@ -703,6 +715,9 @@ impl fmt::Display for PlanError {
             } => {
                 f.write_str("altering size of sources and sinks no longer supported")
             }
+            Self::InvalidEnumLabel { label } => {
+                write!(f, "invalid enum label {}", label.quoted())
+            }
         }
     }
 }
@@ -824,6 +839,12 @@ impl From<KafkaSinkPurificationError> for PlanError {
     }
 }
 
+impl From<PgSinkPurificationError> for PlanError {
+    fn from(e: PgSinkPurificationError) -> Self {
+        PlanError::PgSinkPurification(e)
+    }
+}
+
 impl From<CsrPurificationError> for PlanError {
     fn from(e: CsrPurificationError) -> Self {
         PlanError::CsrPurification(e)
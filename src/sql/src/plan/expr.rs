@@ -23,12 +23,14 @@ use mz_expr::{func, CollectionPlan, Id, LetRecLimit, RowSetFinishing};
 // these happen to be unchanged at the moment, but there might be additions later
 use mz_expr::AggregateFunc::WindowAggregate;
 pub use mz_expr::{
-    BinaryFunc, ColumnOrder, TableFunc, UnaryFunc, UnmaterializableFunc, VariadicFunc, WindowFrame,
+    BinaryFunc, ColumnOrder, RegressionFunc, TableFunc, UnaryFunc, UnmaterializableFunc,
+    VariadicFunc, VarianceFunc, WindowFrame,
 };
 use mz_ore::collections::CollectionExt;
 use mz_ore::stack;
 use mz_ore::stack::RecursionLimitError;
 use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::collation::Collation;
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::*;
 use serde::{Deserialize, Serialize};
@@ -461,6 +463,8 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::Rank => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -474,6 +478,8 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::Rank => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -498,6 +504,12 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::DenseRank => mz_expr::AggregateFunc::DenseRank {
                 order_by: self.order_by,
             },
+            ScalarWindowFunc::PercentRank => mz_expr::AggregateFunc::PercentRank {
+                order_by: self.order_by,
+            },
+            ScalarWindowFunc::CumeDist => mz_expr::AggregateFunc::CumeDist {
+                order_by: self.order_by,
+            },
         }
     }
 }
@@ -508,6 +520,8 @@ pub enum ScalarWindowFunc {
     RowNumber,
     Rank,
     DenseRank,
+    PercentRank,
+    CumeDist,
 }
 
 impl Display for ScalarWindowFunc {
@@ -516,6 +530,8 @@ impl Display for ScalarWindowFunc {
             ScalarWindowFunc::RowNumber => write!(f, "row_number"),
             ScalarWindowFunc::Rank => write!(f, "rank"),
             ScalarWindowFunc::DenseRank => write!(f, "dense_rank"),
+            ScalarWindowFunc::PercentRank => write!(f, "percent_rank"),
+            ScalarWindowFunc::CumeDist => write!(f, "cume_dist"),
         }
     }
 }
@@ -526,6 +542,8 @@ impl ScalarWindowFunc {
             ScalarWindowFunc::RowNumber => ScalarType::Int64.nullable(false),
             ScalarWindowFunc::Rank => ScalarType::Int64.nullable(false),
             ScalarWindowFunc::DenseRank => ScalarType::Int64.nullable(false),
+            ScalarWindowFunc::PercentRank => ScalarType::Float64.nullable(false),
+            ScalarWindowFunc::CumeDist => ScalarType::Float64.nullable(false),
         }
     }
 }
@@ -549,6 +567,8 @@ impl Display for ValueWindowFunc {
             ValueWindowFunc::Lead => write!(f, "lead"),
             ValueWindowFunc::FirstValue => write!(f, "first_value"),
             ValueWindowFunc::LastValue => write!(f, "last_value"),
+            ValueWindowFunc::NthValue => write!(f, "nth_value"),
+            ValueWindowFunc::Ntile => write!(f, "ntile"),
         }
     }
 }
@@ -602,6 +622,14 @@ impl ValueWindowExpr {
                     order_by: self.order_by,
                     window_frame: self.window_frame,
                 },
+                ValueWindowFunc::NthValue => mz_expr::AggregateFunc::NthValue {
+                    order_by: self.order_by,
+                    window_frame: self.window_frame,
+                },
+                // The window frame doesn't affect ntile
+                ValueWindowFunc::Ntile => mz_expr::AggregateFunc::Ntile {
+                    order_by: self.order_by,
+                },
             },
         )
     }
@@ -646,13 +674,16 @@ pub enum ValueWindowFunc {
     Lead,
     FirstValue,
     LastValue,
+    NthValue,
+    Ntile,
 }
 
 impl ValueWindowFunc {
     pub fn output_type(&self, input_type: ColumnType) -> ColumnType {
         match self {
-            ValueWindowFunc::Lag | ValueWindowFunc::Lead => {
-                // The input is a (value, offset, default) record, so extract the type of the first arg
+            ValueWindowFunc::Lag | ValueWindowFunc::Lead | ValueWindowFunc::NthValue => {
+                // The input is a (value, offset, default) or (value, n) record, so extract the
+                // type of the first arg
                 input_type.scalar_type.unwrap_record_element_type()[0]
                     .clone()
                     .nullable(true)
@@ -660,6 +691,7 @@ impl ValueWindowFunc {
             ValueWindowFunc::FirstValue | ValueWindowFunc::LastValue => {
                 input_type.scalar_type.nullable(true)
             }
+            ValueWindowFunc::Ntile => ScalarType::Int32.nullable(true),
         }
     }
 }
@@ -706,6 +738,7 @@ impl AggregateWindowExpr {
                 wrapped_aggregate: Box::new(self.aggregate_expr.func.into_expr()),
                 order_by: self.order_by,
                 window_frame: self.window_frame,
+                distinct: self.aggregate_expr.distinct,
             },
         )
     }
@@ -969,6 +1002,9 @@ pub enum AggregateFunc {
     MaxFloat64,
     MaxBool,
     MaxString,
+    MaxStringCollated {
+        collation: Collation,
+    },
     MaxDate,
     MaxTimestamp,
     MaxTimestampTz,
@@ -985,6 +1021,9 @@ pub enum AggregateFunc {
     MinFloat64,
     MinBool,
     MinString,
+    MinStringCollated {
+        collation: Collation,
+    },
     MinDate,
     MinTimestamp,
     MinTimestampTz,
@@ -1001,6 +1040,20 @@ pub enum AggregateFunc {
     Count,
     Any,
     All,
+    /// Computes a variance or standard deviation of integer or numeric values.
+    VarianceNumeric {
+        func: VarianceFunc,
+    },
+    /// Computes a variance or standard deviation of float values.
+    VarianceFloat64 {
+        func: VarianceFunc,
+    },
+    /// Computes a regression statistic over `(y, x)` records.
+    Regression {
+        func: RegressionFunc,
+    },
+    /// Merges `hll` sketches into the sketch of their union.
+    HllMerge,
     /// Accumulates `Datum::List`s whose first element is a JSON-typed `Datum`s
     /// into a JSON list. The other elements are columns used by `order_by`.
     ///
@@ -1028,6 +1081,24 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Ordered-set aggregates accumulate `Datum::List`s whose first element is
+    /// the direct argument and whose second element is the value being
+    /// aggregated. `order_by` sorts the values.
+    PercentileCont {
+        order_by: Vec<ColumnOrder>,
+    },
+    PercentileContMulti {
+        order_by: Vec<ColumnOrder>,
+    },
+    PercentileDisc {
+        order_by: Vec<ColumnOrder>,
+    },
+    PercentileDiscMulti {
+        order_by: Vec<ColumnOrder>,
+    },
+    Mode {
+        order_by: Vec<ColumnOrder>,
+    },
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -1051,6 +1122,9 @@ impl AggregateFunc {
             AggregateFunc::MaxFloat64 => mz_expr::AggregateFunc::MaxFloat64,
             AggregateFunc::MaxBool => mz_expr::AggregateFunc::MaxBool,
             AggregateFunc::MaxString => mz_expr::AggregateFunc::MaxString,
+            AggregateFunc::MaxStringCollated { collation } => {
+                mz_expr::AggregateFunc::MaxStringCollated { collation }
+            }
             AggregateFunc::MaxDate => mz_expr::AggregateFunc::MaxDate,
             AggregateFunc::MaxTimestamp => mz_expr::AggregateFunc::MaxTimestamp,
             AggregateFunc::MaxTimestampTz => mz_expr::AggregateFunc::MaxTimestampTz,
@@ -1067,6 +1141,9 @@ impl AggregateFunc {
             AggregateFunc::MinFloat64 => mz_expr::AggregateFunc::MinFloat64,
             AggregateFunc::MinBool => mz_expr::AggregateFunc::MinBool,
             AggregateFunc::MinString => mz_expr::AggregateFunc::MinString,
+            AggregateFunc::MinStringCollated { collation } => {
+                mz_expr::AggregateFunc::MinStringCollated { collation }
+            }
             AggregateFunc::MinDate => mz_expr::AggregateFunc::MinDate,
             AggregateFunc::MinTimestamp => mz_expr::AggregateFunc::MinTimestamp,
             AggregateFunc::MinTimestampTz => mz_expr::AggregateFunc::MinTimestampTz,
@@ -1083,6 +1160,14 @@ impl AggregateFunc {
             AggregateFunc::Count => mz_expr::AggregateFunc::Count,
             AggregateFunc::Any => mz_expr::AggregateFunc::Any,
             AggregateFunc::All => mz_expr::AggregateFunc::All,
+            AggregateFunc::VarianceNumeric { func } => {
+                mz_expr::AggregateFunc::VarianceNumeric { func }
+            }
+            AggregateFunc::VarianceFloat64 { func } => {
+                mz_expr::AggregateFunc::VarianceFloat64 { func }
+            }
+            AggregateFunc::Regression { func } => mz_expr::AggregateFunc::Regression { func },
+            AggregateFunc::HllMerge => mz_expr::AggregateFunc::HllMerge,
             AggregateFunc::JsonbAgg { order_by } => mz_expr::AggregateFunc::JsonbAgg { order_by },
             AggregateFunc::JsonbObjectAgg { order_by } => {
                 mz_expr::AggregateFunc::JsonbObjectAgg { order_by }
@@ -1094,6 +1179,19 @@ impl AggregateFunc {
                 mz_expr::AggregateFunc::ListConcat { order_by }
             }
             AggregateFunc::StringAgg { order_by } => mz_expr::AggregateFunc::StringAgg { order_by },
+            AggregateFunc::PercentileCont { order_by } => {
+                mz_expr::AggregateFunc::PercentileCont { order_by }
+            }
+            AggregateFunc::PercentileContMulti { order_by } => {
+                mz_expr::AggregateFunc::PercentileContMulti { order_by }
+            }
+            AggregateFunc::PercentileDisc { order_by } => {
+                mz_expr::AggregateFunc::PercentileDisc { order_by }
+            }
+            AggregateFunc::PercentileDiscMulti { order_by } => {
+                mz_expr::AggregateFunc::PercentileDiscMulti { order_by }
+            }
+            AggregateFunc::Mode { order_by } => mz_expr::AggregateFunc::Mode { order_by },
             AggregateFunc::Dummy => mz_expr::AggregateFunc::Dummy,
         }
     }
@@ -1121,6 +1219,13 @@ impl AggregateFunc {
             AggregateFunc::Count => ScalarType::Int64,
             AggregateFunc::Any => ScalarType::Bool,
             AggregateFunc::All => ScalarType::Bool,
+            AggregateFunc::VarianceNumeric { .. } => ScalarType::Numeric { max_scale: None },
+            AggregateFunc::VarianceFloat64 { .. } => ScalarType::Float64,
+            AggregateFunc::Regression {
+                func: RegressionFunc::RegrCount,
+            } => ScalarType::Int64,
+            AggregateFunc::Regression { .. } => ScalarType::Float64,
+            AggregateFunc::HllMerge => ScalarType::Hll,
             AggregateFunc::JsonbAgg { .. } => ScalarType::Jsonb,
             AggregateFunc::JsonbObjectAgg { .. } => ScalarType::Jsonb,
             AggregateFunc::StringAgg { .. } => ScalarType::String,
@@ -1139,10 +1244,27 @@ impl AggregateFunc {
                     _ => unreachable!(),
                 }
             }
+            AggregateFunc::PercentileCont { .. } => ScalarType::Float64,
+            AggregateFunc::PercentileContMulti { .. } => {
+                ScalarType::Array(Box::new(ScalarType::Float64))
+            }
+            // The input is a `(direct_arg, value)` record, so extract the value.
+            AggregateFunc::PercentileDisc { .. } | AggregateFunc::Mode { .. } => {
+                input_type.scalar_type.unwrap_record_element_type()[1].clone()
+            }
+            AggregateFunc::PercentileDiscMulti { .. } => ScalarType::Array(Box::new(
+                input_type.scalar_type.unwrap_record_element_type()[1].clone(),
+            )),
             _ => input_type.scalar_type,
         };
         // max/min/sum return null on empty sets
-        let nullable = !matches!(self, AggregateFunc::Count);
+        let nullable = !matches!(
+            self,
+            AggregateFunc::Count
+                | AggregateFunc::Regression {
+                    func: RegressionFunc::RegrCount
+                }
+        );
         scalar_type.nullable(nullable)
     }
 
@@ -1157,6 +1279,20 @@ impl AggregateFunc {
                 | StringAgg { .. }
         )
     }
+
+    /// Returns whether this is an ordered-set aggregate, which must be called
+    /// with `WITHIN GROUP (ORDER BY ...)`.
+    pub fn is_ordered_set(&self) -> bool {
+        use AggregateFunc::*;
+        matches!(
+            self,
+            PercentileCont { .. }
+                | PercentileContMulti { .. }
+                | PercentileDisc { .. }
+                | PercentileDiscMulti { .. }
+                | Mode { .. }
+        )
+    }
 }
 
 impl HirRelationExpr {
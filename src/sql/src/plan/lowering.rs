@@ -800,6 +800,7 @@ impl HirRelationExpr {
                             column: column_order.column + get_outer.arity(),
                             desc: column_order.desc,
                             nulls_last: column_order.nulls_last,
+                            collation: column_order.collation,
                         })
                         .collect();
 
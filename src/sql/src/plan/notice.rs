@@ -37,6 +37,18 @@ pub enum PlanNotice {
         key: Vec<ColumnName>,
         name: String,
     },
+    EnumLabelAlreadyExists {
+        label: String,
+        type_name: String,
+    },
+    ColumnAlreadyExists {
+        column: String,
+        table_name: String,
+    },
+    ColumnDoesNotExist {
+        column: String,
+        table_name: String,
+    },
 }
 
 impl PlanNotice {
@@ -83,6 +95,30 @@ impl fmt::Display for PlanNotice {
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 write!(f, "upsert key not validated to be unique")
             }
+            PlanNotice::EnumLabelAlreadyExists { label, type_name } => {
+                write!(
+                    f,
+                    "enum label {} already exists in {}, skipping",
+                    label.quoted(),
+                    type_name.quoted()
+                )
+            }
+            PlanNotice::ColumnAlreadyExists { column, table_name } => {
+                write!(
+                    f,
+                    "column {} of relation {} already exists, skipping",
+                    column.quoted(),
+                    table_name.quoted()
+                )
+            }
+            PlanNotice::ColumnDoesNotExist { column, table_name } => {
+                write!(
+                    f,
+                    "column {} of relation {} does not exist, skipping",
+                    column.quoted(),
+                    table_name.quoted()
+                )
+            }
         }
     }
 }
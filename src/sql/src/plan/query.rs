@@ -48,6 +48,9 @@ use mz_ore::soft_panic_or_log;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_ore::str::StrExt;
 use mz_repr::adt::char::CharLength;
+use mz_repr::adt::collation::Collation;
+use mz_repr::adt::interval::Interval;
+use mz_repr::adt::jsonpath::JsonPath;
 use mz_repr::adt::numeric::{NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::adt::timestamp::TimestampPrecision;
 use mz_repr::adt::varchar::VarCharMaxLength;
@@ -60,17 +63,19 @@ use mz_sql_parser::ast::visit::Visit;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     visit, AsOf, Assignment, AstInfo, CreateWebhookSourceBody, CreateWebhookSourceCheck,
-    CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock, DeleteStatement, Distinct,
+    CreateWebhookSourceHeader, CreateWebhookSourceSecret, Cte, CteBlock, DeleteStatement, Distinct,
     Expr, Function, FunctionArgs, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join,
-    JoinConstraint, JoinOperator, Limit, MutRecBlock, MutRecBlockOption, MutRecBlockOptionName,
-    OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName, SetExpr, SetOperator,
-    ShowStatement, SubscriptPosition, TableAlias, TableFactor, TableWithJoins, UnresolvedItemName,
-    UpdateStatement, Value, Values, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    JoinConstraint, JoinOperator, JsonTableBehavior, JsonTableColumn, JsonTablePassing, Limit,
+    MergeAction, MergeStatement, MergeWhenClause, MutRecBlock, MutRecBlockOption,
+    MutRecBlockOptionName, OnConflict, OnConflictAction, OrderByExpr, Query, Select, SelectItem,
+    SelectOption, SelectOptionName, SetExpr, SetOperator, ShowStatement, SubscriptPosition,
+    TableAlias, TableFactor, TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values,
+    WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
 };
 use mz_sql_parser::ident;
 use uuid::Uuid;
 
-use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
+use crate::catalog::{CatalogItemType, CatalogType, CatalogTypeDetails, SessionCatalog};
 use crate::func::{self, Func, FuncSpec};
 use crate::names::{
     Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
@@ -217,16 +222,36 @@ fn try_push_projection_order_by(
     }
 }
 
+/// The `ON CONFLICT` clause of an `INSERT`, planned against the target table.
+pub struct InsertOnConflict {
+    /// The indices of the columns of the table's declared key on which
+    /// proposed rows conflict with existing rows.
+    pub key: Vec<usize>,
+    /// Map from column index to `DO UPDATE SET` expression, along with the
+    /// `DO UPDATE WHERE` predicate, if any. Both are planned over the existing
+    /// row followed by the proposed (`EXCLUDED`) row. `None` for `DO NOTHING`.
+    pub update: Option<(BTreeMap<usize, HirScalarExpr>, Option<HirScalarExpr>)>,
+}
+
+/// Plans an `INSERT` into `table_name`.
+///
+/// Returns the relation expression that produces the rows to insert. If
+/// `on_conflict` is present, the expression instead produces each proposed
+/// row, followed by the existing row that shares its conflict key (or
+/// `NULL`s), followed by the number of copies of the existing row in the
+/// table (or `NULL`, if no such existing row was found).
 pub fn plan_insert_query(
     scx: &StatementContext,
     table_name: ResolvedItemName,
     columns: Vec<Ident>,
     source: InsertSource<Aug>,
+    on_conflict: Option<OnConflict<Aug>>,
     returning: Vec<SelectItem<Aug>>,
 ) -> Result<
     (
         GlobalId,
         HirRelationExpr,
+        Option<InsertOnConflict>,
         PlannedRootQuery<Vec<HirScalarExpr>>,
     ),
     PlanError,
@@ -243,10 +268,10 @@ pub fn plan_insert_query(
         );
     }
     let desc = table.desc(&scx.catalog.resolve_full_name(table.name()))?;
-    let mut defaults = table
+    let (defaults, domains) = table
         .table_details()
-        .expect("attempted to insert into non-table")
-        .to_vec();
+        .expect("attempted to insert into non-table");
+    let mut defaults = defaults.to_vec();
 
     for default in &mut defaults {
         transform_ast::transform(scx, default)?;
@@ -372,6 +397,20 @@ pub fn plan_insert_query(
         }
     }
 
+    let on_conflict = match on_conflict {
+        Some(mut on_conflict) => {
+            transform_ast::transform(scx, &mut on_conflict)?;
+            Some(plan_insert_on_conflict(
+                &qcx,
+                table_name.clone(),
+                &desc,
+                domains,
+                on_conflict,
+            )?)
+        }
+        None => None,
+    };
+
     let returning = {
         let (scope, typ) = if let ResolvedItemName::Item { full_name, .. } = table_name {
             let desc = table.desc(&full_name)?;
@@ -423,11 +462,147 @@ pub fn plan_insert_query(
         }
     };
 
-    Ok((
-        table.id(),
-        expr.map(map_exprs).project(project_key),
-        returning,
-    ))
+    let mut expr =
+        plan_table_domain_constraints(scx, domains, expr.map(map_exprs).project(project_key))?;
+
+    if let Some(InsertOnConflict { key, .. }) = &on_conflict {
+        // Left join the proposed rows to the existing rows on the conflict
+        // key. Copies of each existing row are collapsed and counted, which
+        // also distinguishes the absence of a match from a matched row of
+        // `NULL`s.
+        let arity = desc.arity();
+        let existing = HirRelationExpr::Reduce {
+            input: Box::new(HirRelationExpr::Get {
+                id: Id::Global(table.id()),
+                typ: desc.typ().clone(),
+            }),
+            group_key: (0..arity).collect(),
+            aggregates: vec![AggregateExpr {
+                func: AggregateFunc::Count,
+                expr: Box::new(HirScalarExpr::literal_true()),
+                distinct: false,
+            }],
+            expected_group_size: None,
+        };
+        let on = key
+            .iter()
+            .map(|k| {
+                let proposed = HirScalarExpr::column(*k);
+                let existing = HirScalarExpr::column(arity + *k);
+                let eq = proposed
+                    .clone()
+                    .call_binary(existing.clone(), BinaryFunc::Eq);
+                if desc.typ().column_types[*k].nullable {
+                    // Keys over nullable columns were declared `NULLS NOT
+                    // DISTINCT`.
+                    eq.or(proposed.call_is_null().and(existing.call_is_null()))
+                } else {
+                    eq
+                }
+            })
+            .collect();
+        expr = expr.join(
+            existing,
+            HirScalarExpr::variadic_and(on),
+            JoinKind::LeftOuter,
+        );
+    }
+
+    Ok((table.id(), expr, on_conflict, returning))
+}
+
+/// Plans the `ON CONFLICT` clause of an `INSERT` into `table_name`, whose
+/// description is `desc`.
+fn plan_insert_on_conflict(
+    qcx: &QueryContext,
+    table_name: ResolvedItemName,
+    desc: &RelationDesc,
+    domains: &[Option<GlobalId>],
+    OnConflict { columns, action }: OnConflict<Aug>,
+) -> Result<InsertOnConflict, PlanError> {
+    let mut target = Vec::with_capacity(columns.len());
+    for column in columns {
+        let name = normalize::column_name(column);
+        match desc.get_by_name(&name) {
+            Some((idx, _)) => target.push(idx),
+            None => sql_bail!("column {} does not exist", name.as_str().quoted()),
+        }
+    }
+    target.sort();
+    target.dedup();
+
+    // As in PostgreSQL, the conflict target must name exactly the columns of
+    // one of the table's keys, in any order.
+    let key = desc
+        .typ()
+        .keys
+        .iter()
+        .find(|key| {
+            let mut key = key.to_vec();
+            key.sort();
+            key == target
+        })
+        .cloned()
+        .ok_or_else(|| {
+            sql_err!(
+                "there is no unique or exclusion constraint matching the ON CONFLICT specification"
+            )
+        })?;
+
+    let (assignments, selection) = match action {
+        OnConflictAction::DoNothing => return Ok(InsertOnConflict { key, update: None }),
+        OnConflictAction::DoUpdate {
+            assignments,
+            selection,
+        } => (assignments, selection),
+    };
+
+    // Expressions may refer to both the existing row, by the table's name,
+    // and the proposed row, as `excluded`.
+    let (existing, existing_scope) = qcx.resolve_table_name(table_name)?;
+    let excluded_scope = Scope::from_source(
+        Some(PartialItemName::from("excluded".to_string())),
+        desc.iter_names().cloned(),
+    );
+    let scope = existing_scope.product(excluded_scope)?;
+    let mut relation_type = qcx.relation_type(&existing);
+    relation_type
+        .column_types
+        .extend(desc.iter_types().cloned());
+
+    let ecx = &ExprContext {
+        qcx,
+        name: "SET clause",
+        scope: &scope,
+        relation_type: &relation_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let sets = plan_set_clause(ecx, desc, domains, assignments)?;
+
+    let selection = match selection {
+        Some(selection) => {
+            let ecx = &ExprContext {
+                qcx,
+                name: "WHERE clause",
+                scope: &scope,
+                relation_type: &relation_type,
+                allow_aggregates: false,
+                allow_subqueries: false,
+                allow_parameters: true,
+                allow_windows: false,
+            };
+            Some(plan_expr(ecx, &selection)?.type_as(ecx, &ScalarType::Bool)?)
+        }
+        None => None,
+    };
+
+    Ok(InsertOnConflict {
+        key,
+        update: Some((sets, selection)),
+    })
 }
 
 pub fn plan_copy_from(
@@ -511,10 +686,10 @@ pub fn plan_copy_from_rows(
     let table = catalog.get_item(&id);
     let desc = table.desc(&catalog.resolve_full_name(table.name()))?;
 
-    let mut defaults = table
+    let (defaults, domains) = table
         .table_details()
-        .expect("attempted to insert into non-table")
-        .to_vec();
+        .expect("attempted to insert into non-table");
+    let mut defaults = defaults.to_vec();
 
     for default in &mut defaults {
         transform_ast::transform(&scx, default)?;
@@ -543,7 +718,7 @@ pub fn plan_copy_from_rows(
     // projection).
     let default: Vec<_> = (0..desc.arity()).collect();
     if columns == default {
-        return Ok(expr);
+        return plan_table_domain_constraints(&scx, domains, expr);
     }
 
     // Fill in any omitted columns and rearrange into correct order
@@ -564,7 +739,7 @@ pub fn plan_copy_from_rows(
         }
     }
 
-    Ok(expr.map(map_exprs).project(project_key))
+    plan_table_domain_constraints(&scx, domains, expr.map(map_exprs).project(project_key))
 }
 
 /// Common information used for DELETE, UPDATE, and INSERT INTO ... SELECT plans.
@@ -649,6 +824,7 @@ pub fn plan_mutation_query_inner(
     let (mut get, scope) = qcx.resolve_table_name(table_name)?;
     let scope = plan_table_alias(scope, alias.as_ref())?;
     let desc = item.desc(&qcx.scx.catalog.resolve_full_name(item.name()))?;
+    let (_, domains) = item.table_details().expect("attempted to mutate non-table");
     let relation_type = qcx.relation_type(&get);
 
     if using.is_empty() {
@@ -670,35 +846,17 @@ pub fn plan_mutation_query_inner(
         get = handle_mutation_using_clause(&qcx, selection, using, get, scope.clone())?;
     }
 
-    let mut sets = BTreeMap::new();
-    for Assignment { id, value } in assignments {
-        // Get the index and type of the column.
-        let name = normalize::column_name(id);
-        match desc.get_by_name(&name) {
-            Some((idx, typ)) => {
-                let ecx = &ExprContext {
-                    qcx: &qcx,
-                    name: "SET clause",
-                    scope: &scope,
-                    relation_type: &relation_type,
-                    allow_aggregates: false,
-                    allow_subqueries: false,
-                    allow_parameters: true,
-                    allow_windows: false,
-                };
-                let expr = plan_expr(ecx, &value)?.cast_to(
-                    ecx,
-                    CastContext::Assignment,
-                    &typ.scalar_type,
-                )?;
-
-                if sets.insert(idx, expr).is_some() {
-                    sql_bail!("column {} set twice", name)
-                }
-            }
-            None => sql_bail!("unknown column {}", name),
-        };
-    }
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "SET clause",
+        scope: &scope,
+        relation_type: &relation_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let sets = plan_set_clause(ecx, &desc, domains, assignments)?;
 
     let finishing = RowSetFinishing {
         order_by: vec![],
@@ -816,16 +974,325 @@ fn handle_mutation_using_clause(
     Ok(get.filter(vec![using_rel_expr.exists()]))
 }
 
-struct CastRelationError {
-    column: usize,
-    source_type: ScalarType,
-    target_type: ScalarType,
+/// Plans the assignments of a `SET` clause against the columns of the table
+/// described by `desc`, returning a map from column index to the expression
+/// that column is set to.
+fn plan_set_clause(
+    ecx: &ExprContext,
+    desc: &RelationDesc,
+    domains: &[Option<GlobalId>],
+    assignments: Vec<Assignment<Aug>>,
+) -> Result<BTreeMap<usize, HirScalarExpr>, PlanError> {
+    let mut sets = BTreeMap::new();
+    for Assignment { id, value } in assignments {
+        // Get the index and type of the column.
+        let name = normalize::column_name(id);
+        match desc.get_by_name(&name) {
+            Some((idx, typ)) => {
+                let mut expr = plan_expr(ecx, &value)?.cast_to(
+                    ecx,
+                    CastContext::Assignment,
+                    &typ.scalar_type,
+                )?;
+                if let Some(domain) = domains[idx] {
+                    expr = typeconv::plan_domain_constraints(ecx.qcx.scx, expr, domain)?;
+                }
+
+                if sets.insert(idx, expr).is_some() {
+                    sql_bail!("column {} set twice", name)
+                }
+            }
+            None => sql_bail!("unknown column {}", name),
+        };
+    }
+    Ok(sets)
+}
+
+/// A `WHEN [NOT] MATCHED` clause of a `MERGE`, planned against the target
+/// table.
+pub struct MergeWhen {
+    /// Whether the clause applies to source rows that matched a target row.
+    pub matched: bool,
+    /// The clause's `AND` condition, if any. Like the expressions of `action`,
+    /// planned over the target row followed by the source row if `matched`,
+    /// and over the source row alone otherwise.
+    pub condition: Option<HirScalarExpr>,
+    pub action: MergeWhenAction,
+}
+
+pub enum MergeWhenAction {
+    /// Map from column index to `UPDATE SET` expression.
+    Update(BTreeMap<usize, HirScalarExpr>),
+    Delete,
+    /// The expression for each column of the inserted row, in table order.
+    Insert(Vec<HirScalarExpr>),
+    DoNothing,
+}
+
+/// Plans a `MERGE`.
+///
+/// The selection of the returned plan produces, for each source row, each
+/// distinct target row the source row matches (or `NULL`s, if none), followed
+/// by the source row, followed by the number of copies of the target row in
+/// the table (or `NULL`, if none).
+pub fn plan_merge_query(
+    scx: &StatementContext,
+    mut merge_stmt: MergeStatement<Aug>,
+) -> Result<(ReadThenWritePlan, Vec<MergeWhen>), PlanError> {
+    transform_ast::transform(scx, &mut merge_stmt)?;
+    let MergeStatement {
+        table_name,
+        alias,
+        using,
+        on,
+        when_clauses,
+    } = merge_stmt;
+
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+
+    // Get global ID.
+    let id = match table_name {
+        ResolvedItemName::Item { id, .. } => id,
+        _ => sql_bail!("cannot merge into non-user table"),
+    };
+
+    // Perform checks on item with given ID.
+    let item = scx.get_item(&id);
+    if item.item_type() != CatalogItemType::Table {
+        sql_bail!(
+            "cannot merge into {} '{}'",
+            item.item_type(),
+            table_name.full_name_str()
+        );
+    }
+    if id.is_system() {
+        sql_bail!(
+            "cannot merge into system table '{}'",
+            table_name.full_name_str()
+        );
+    }
+    let desc = item.desc(&scx.catalog.resolve_full_name(item.name()))?;
+    let (defaults, domains) = item
+        .table_details()
+        .expect("attempted to merge into non-table");
+    let arity = desc.arity();
+
+    let (target, target_scope) = qcx.resolve_table_name(table_name.clone())?;
+    let target_scope = plan_table_alias(target_scope, alias.as_ref())?;
+    let target_type = qcx.relation_type(&target);
+    let (source, source_scope) = plan_table_with_joins(&qcx, &using)?;
+    let source_type = qcx.relation_type(&source);
+    let source_arity = source_type.arity();
+
+    // Collapse the copies of each target row, so that a source row matches
+    // each distinct target row once, but remember their number so that an
+    // update or delete applies to all of them.
+    let target = HirRelationExpr::Reduce {
+        input: Box::new(target),
+        group_key: (0..arity).collect(),
+        aggregates: vec![AggregateExpr {
+            func: AggregateFunc::Count,
+            expr: Box::new(HirScalarExpr::literal_true()),
+            distinct: false,
+        }],
+        expected_group_size: None,
+    };
+
+    // Left join the source to the target on the `ON` condition, which may
+    // refer to both. The condition cannot refer to the trailing count column,
+    // which is absent from its scope.
+    let on_scope = source_scope.clone().product(target_scope.clone())?;
+    let mut on_type = source_type.clone();
+    on_type
+        .column_types
+        .extend(target_type.column_types.iter().cloned());
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "ON clause",
+        scope: &on_scope,
+        relation_type: &on_type,
+        allow_aggregates: false,
+        allow_subqueries: true,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let on = plan_expr(ecx, &on)?.type_as(ecx, &ScalarType::Bool)?;
+    let selection = source.join(target, on, JoinKind::LeftOuter).project(
+        (source_arity..source_arity + arity)
+            .chain(0..source_arity)
+            .chain(iter::once(source_arity + arity))
+            .collect(),
+    );
+
+    // `WHEN MATCHED` clauses may refer to both the target and source rows,
+    // but `WHEN NOT MATCHED` clauses only to the source row.
+    let matched_scope = target_scope.product(source_scope.clone())?;
+    let mut matched_type = target_type;
+    matched_type
+        .column_types
+        .extend(source_type.column_types.iter().cloned());
+
+    let mut planned_clauses = Vec::with_capacity(when_clauses.len());
+    for MergeWhenClause {
+        matched,
+        condition,
+        action,
+    } in when_clauses
+    {
+        let (scope, relation_type) = if matched {
+            (&matched_scope, &matched_type)
+        } else {
+            (&source_scope, &source_type)
+        };
+        let ecx = &ExprContext {
+            qcx: &qcx,
+            name: "WHEN clause",
+            scope,
+            relation_type,
+            allow_aggregates: false,
+            allow_subqueries: false,
+            allow_parameters: true,
+            allow_windows: false,
+        };
+        let condition = match condition {
+            Some(condition) => Some(plan_expr(ecx, &condition)?.type_as(ecx, &ScalarType::Bool)?),
+            None => None,
+        };
+        let action = match action {
+            MergeAction::Update { assignments } => {
+                let ecx = &ExprContext {
+                    name: "SET clause",
+                    ..ecx.clone()
+                };
+                MergeWhenAction::Update(plan_set_clause(ecx, &desc, domains, assignments)?)
+            }
+            MergeAction::Delete => MergeWhenAction::Delete,
+            MergeAction::Insert { columns, values } => {
+                let ecx = &ExprContext {
+                    name: "VALUES",
+                    ..ecx.clone()
+                };
+                MergeWhenAction::Insert(plan_merge_insert(
+                    ecx,
+                    &table_name,
+                    &desc,
+                    defaults,
+                    domains,
+                    columns,
+                    values,
+                )?)
+            }
+            MergeAction::InsertDefaultValues => MergeWhenAction::Insert(plan_merge_insert(
+                ecx,
+                &table_name,
+                &desc,
+                defaults,
+                domains,
+                vec![],
+                vec![],
+            )?),
+            MergeAction::DoNothing => MergeWhenAction::DoNothing,
+        };
+        planned_clauses.push(MergeWhen {
+            matched,
+            condition,
+            action,
+        });
+    }
+
+    let finishing = RowSetFinishing {
+        order_by: vec![],
+        limit: None,
+        offset: 0,
+        project: (0..arity + source_arity + 1).collect(),
+    };
+
+    Ok((
+        ReadThenWritePlan {
+            id,
+            selection,
+            finishing,
+            assignments: BTreeMap::new(),
+        },
+        planned_clauses,
+    ))
+}
+
+/// Plans the row inserted by the `INSERT` action of a `MERGE` into the table
+/// described by `desc`, filling in the defaults of omitted columns.
+fn plan_merge_insert(
+    ecx: &ExprContext,
+    table_name: &ResolvedItemName,
+    desc: &RelationDesc,
+    defaults: &[Expr<Aug>],
+    domains: &[Option<GlobalId>],
+    columns: Vec<Ident>,
+    values: Vec<Expr<Aug>>,
+) -> Result<Vec<HirScalarExpr>, PlanError> {
+    let ordering: Vec<usize> = if columns.is_empty() {
+        if values.len() > desc.arity() {
+            sql_bail!("INSERT has more expressions than target columns");
+        }
+        (0..values.len()).collect()
+    } else {
+        let columns: Vec<_> = columns.into_iter().map(normalize::column_name).collect();
+        if let Some(dup) = columns.iter().duplicates().next() {
+            sql_bail!("column {} specified more than once", dup.as_str().quoted());
+        }
+        if values.len() > columns.len() {
+            sql_bail!("INSERT has more expressions than target columns");
+        }
+        if values.len() < columns.len() {
+            sql_bail!("INSERT has more target columns than expressions");
+        }
+        columns
+            .iter()
+            .map(|c| match desc.get_by_name(c) {
+                Some((idx, _)) => Ok(idx),
+                None => sql_bail!(
+                    "column {} of relation {} does not exist",
+                    c.as_str().quoted(),
+                    table_name.full_name_str().quoted()
+                ),
+            })
+            .collect::<Result<_, PlanError>>()?
+    };
+
+    let mut row: Vec<Option<HirScalarExpr>> = vec![None; desc.arity()];
+    for (idx, value) in ordering.into_iter().zip_eq(values) {
+        let scalar_type = &desc.typ().column_types[idx].scalar_type;
+        let expr = plan_expr(ecx, &value)?.cast_to(ecx, CastContext::Assignment, scalar_type)?;
+        row[idx] = Some(expr);
+    }
+
+    let mut exprs = Vec::with_capacity(desc.arity());
+    for (idx, (expr, col_typ)) in row.into_iter().zip_eq(desc.iter_types()).enumerate() {
+        let mut expr = match expr {
+            Some(expr) => expr,
+            None => {
+                let mut default = defaults[idx].clone();
+                transform_ast::transform(ecx.qcx.scx, &mut default)?;
+                plan_default_expr(ecx.qcx.scx, &default, &col_typ.scalar_type)?
+            }
+        };
+        if let Some(domain) = domains[idx] {
+            expr = typeconv::plan_domain_constraints(ecx.qcx.scx, expr, domain)?;
+        }
+        exprs.push(expr);
+    }
+    Ok(exprs)
+}
+
+pub(crate) struct CastRelationError {
+    pub(crate) column: usize,
+    pub(crate) source_type: ScalarType,
+    pub(crate) target_type: ScalarType,
 }
 
 /// Cast a relation from one type to another using the specified type of cast.
 ///
 /// The length of `target_types` must match the arity of `expr`.
-fn cast_relation<'a, I>(
+pub(crate) fn cast_relation<'a, I>(
     qcx: &QueryContext,
     ccx: CastContext,
     expr: HirRelationExpr,
@@ -900,6 +1367,33 @@ pub fn plan_up_to(
         .lower_uncorrelated()
 }
 
+/// Plans the destination expression of a `COPY ... TO <expr>` statement.
+///
+/// The expression must be of type `text`. It is evaluated once, when the statement is
+/// sequenced.
+pub fn plan_copy_to_expr(
+    scx: &StatementContext,
+    mut to: Expr<Aug>,
+) -> Result<MirScalarExpr, PlanError> {
+    let scope = Scope::empty();
+    let desc = RelationDesc::empty();
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    transform_ast::transform(scx, &mut to)?;
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "COPY TO target",
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    plan_expr(ecx, &to)?
+        .type_as(ecx, &ScalarType::String)?
+        .lower_uncorrelated()
+}
+
 /// Plans an expression in the AS OF position of a `SELECT` or `SUBSCRIBE` statement.
 pub fn plan_as_of(
     scx: &StatementContext,
@@ -1142,6 +1636,60 @@ pub fn plan_default_expr(
     Ok(hir)
 }
 
+/// Plans the `CHECK` constraint `check` of a domain over `base_type`. The
+/// value being checked, which `check` refers to as `VALUE`, is column 0.
+pub fn plan_domain_check(
+    scx: &StatementContext,
+    check: &Expr<Aug>,
+    base_type: &ScalarType,
+) -> Result<HirScalarExpr, PlanError> {
+    let mut check = check.clone();
+    transform_ast::transform(scx, &mut check)?;
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let scope = Scope::from_source(None, iter::once("value"));
+    let relation_type = RelationType::new(vec![base_type.clone().nullable(true)]);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "CHECK constraint",
+        scope: &scope,
+        relation_type: &relation_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    plan_expr(ecx, &check)?.type_as(ecx, &ScalarType::Bool)
+}
+
+/// Enforces the constraints of the domains that the columns of the table
+/// described by `domains` were declared as on `expr`, a relation whose
+/// columns are in the order of the table's columns.
+fn plan_table_domain_constraints(
+    scx: &StatementContext,
+    domains: &[Option<GlobalId>],
+    expr: HirRelationExpr,
+) -> Result<HirRelationExpr, PlanError> {
+    if domains.iter().all(|domain| domain.is_none()) {
+        return Ok(expr);
+    }
+    let mut map_exprs = vec![];
+    let mut project_key = Vec::with_capacity(domains.len());
+    for (i, domain) in domains.iter().enumerate() {
+        match domain {
+            Some(id) => {
+                project_key.push(domains.len() + map_exprs.len());
+                map_exprs.push(typeconv::plan_domain_constraints(
+                    scx,
+                    HirScalarExpr::column(i),
+                    *id,
+                )?);
+            }
+            None => project_key.push(i),
+        }
+    }
+    Ok(expr.map(map_exprs).project(project_key))
+}
+
 pub fn plan_params<'a>(
     scx: &'a StatementContext,
     params: Vec<Expr<Aug>>,
@@ -1361,7 +1909,7 @@ fn plan_query_inner(qcx: &mut QueryContext, q: &Query<Aug>) -> Result<PlannedQue
         }
     };
 
-    // Both introduce `Let` bindings atop `result` and re-install shadowed bindings.
+    // All introduce `Let` bindings atop `result` and re-install shadowed bindings.
     match &q.ctes {
         CteBlock::Simple(_) => {
             for (id, value, shadowed_val) in cte_bindings.into_iter().rev() {
@@ -1378,6 +1926,30 @@ fn plan_query_inner(qcx: &mut QueryContext, q: &Query<Aug>) -> Result<PlannedQue
                 }
             }
         }
+        CteBlock::Recursive(ctes) => {
+            // Only the CTEs that reference themselves need `LetRec` bindings.
+            for ((id, value, shadowed_val), cte) in cte_bindings.into_iter().zip(ctes).rev() {
+                if let Some(cte_desc) = qcx.ctes.remove(&id) {
+                    planned_query.expr = if is_recursive_cte(cte) {
+                        HirRelationExpr::LetRec {
+                            limit: None,
+                            bindings: vec![(cte_desc.name, id, value, cte_desc.desc.typ().clone())],
+                            body: Box::new(planned_query.expr),
+                        }
+                    } else {
+                        HirRelationExpr::Let {
+                            name: cte_desc.name,
+                            id: id.clone(),
+                            value: Box::new(value),
+                            body: Box::new(planned_query.expr),
+                        }
+                    };
+                }
+                if let Some(shadowed_val) = shadowed_val {
+                    qcx.ctes.insert(id, shadowed_val);
+                }
+            }
+        }
         CteBlock::MutuallyRecursive(MutRecBlock { options, ctes: _ }) => {
             let MutRecBlockOptionExtracted {
                 recursion_limit,
@@ -1450,9 +2022,13 @@ pub fn plan_ctes(
     }
 
     match &q.ctes {
-        CteBlock::Simple(ctes) => {
+        CteBlock::Simple(ctes) | CteBlock::Recursive(ctes) => {
             // Plan all CTEs, introducing the types for non-recursive CTEs as we go.
             for cte in ctes.iter() {
+                if matches!(q.ctes, CteBlock::Recursive(_)) && is_recursive_cte(cte) {
+                    result.push(plan_recursive_cte(qcx, cte)?);
+                    continue;
+                }
                 let cte_name = normalize::ident(cte.alias.name.clone());
                 let (val, scope) = plan_nested_query(qcx, &cte.query)?;
                 let typ = qcx.relation_type(&val);
@@ -1569,6 +2145,271 @@ pub fn plan_ctes(
     Ok(result)
 }
 
+/// Reports whether a CTE of a `WITH RECURSIVE` block references itself.
+fn is_recursive_cte(cte: &Cte<Aug>) -> bool {
+    let mut counter = CteReferenceCounter {
+        id: cte.id,
+        count: 0,
+    };
+    counter.visit_query(&cte.query);
+    counter.count > 0
+}
+
+/// Counts the references to a CTE.
+struct CteReferenceCounter {
+    id: LocalId,
+    count: usize,
+}
+
+impl<'ast> Visit<'ast, Aug> for CteReferenceCounter {
+    fn visit_item_name(&mut self, item_name: &'ast <Aug as AstInfo>::ItemName) {
+        if matches!(item_name, ResolvedItemName::Cte { id, .. } if *id == self.id) {
+            self.count += 1;
+        }
+    }
+}
+
+/// Checks that the recursive term of a CTE of a `WITH RECURSIVE` block is
+/// linear in the CTE, i.e., that each row of the term is derived from at most
+/// one row of the CTE, which makes the CTE's fixpoint the result of SQL's
+/// iterative evaluation.
+struct RecursiveTermChecker<'a> {
+    scx: &'a StatementContext<'a>,
+    id: LocalId,
+    name: &'a str,
+    err: Option<PlanError>,
+}
+
+impl RecursiveTermChecker<'_> {
+    /// Reports whether `visit` visits a reference to the CTE.
+    fn references(&self, visit: impl FnOnce(&mut CteReferenceCounter)) -> bool {
+        let mut counter = CteReferenceCounter {
+            id: self.id,
+            count: 0,
+        };
+        visit(&mut counter);
+        counter.count > 0
+    }
+
+    /// Records that the recursive term contains `feature`, unless an earlier
+    /// error was recorded.
+    fn disallow(&mut self, feature: &str) {
+        self.err.get_or_insert_with(|| {
+            sql_err!("{feature} not allowed in a recursive query's recursive term")
+        });
+    }
+
+    /// Records that the CTE is referenced within `context`, unless an earlier
+    /// error was recorded.
+    fn disallow_reference(&mut self, context: &str) {
+        let name = self.name.quoted();
+        self.err.get_or_insert_with(|| {
+            sql_err!("recursive reference to query {name} must not appear within {context}")
+        });
+    }
+}
+
+impl<'ast> Visit<'ast, Aug> for RecursiveTermChecker<'_> {
+    fn visit_set_expr(&mut self, set_expr: &'ast SetExpr<Aug>) {
+        match set_expr {
+            SetExpr::SetOperation {
+                op: op @ (SetOperator::Except | SetOperator::Intersect),
+                ..
+            } if self.references(|counter| counter.visit_set_expr(set_expr)) => {
+                self.disallow_reference(&op.to_string());
+            }
+            // A parenthesized part of the recursive term.
+            SetExpr::Query(query) => {
+                if !query.order_by.is_empty() {
+                    self.disallow("ORDER BY is");
+                }
+                if query.limit.is_some() {
+                    self.disallow("LIMIT is");
+                }
+                if query.offset.is_some() {
+                    self.disallow("OFFSET is");
+                }
+                if self.references(|counter| counter.visit_cte_block(&query.ctes)) {
+                    self.disallow_reference("a subquery");
+                }
+                self.visit_set_expr(&query.body);
+            }
+            _ => visit::visit_set_expr(self, set_expr),
+        }
+    }
+
+    fn visit_select(&mut self, select: &'ast Select<Aug>) {
+        if select.distinct.is_some() {
+            self.disallow("DISTINCT is");
+        }
+        if !select.group_by.is_empty() || select.having.is_some() {
+            self.disallow("GROUP BY is");
+        }
+        visit::visit_select(self, select);
+    }
+
+    fn visit_table_with_joins(&mut self, table: &'ast TableWithJoins<Aug>) {
+        // The CTE must not be referenced on the side of an outer join that is
+        // padded with `NULL`s.
+        let mut left = self.references(|counter| counter.visit_table_factor(&table.relation));
+        for join in &table.joins {
+            let right = self.references(|counter| counter.visit_table_factor(&join.relation));
+            let padded = match &join.join_operator {
+                JoinOperator::LeftOuter(_) => right,
+                JoinOperator::RightOuter(_) => left,
+                JoinOperator::FullOuter(_) => left || right,
+                JoinOperator::Inner(_) | JoinOperator::CrossJoin => false,
+            };
+            if padded {
+                self.disallow_reference("an outer join");
+            }
+            left |= right;
+        }
+        visit::visit_table_with_joins(self, table);
+    }
+
+    fn visit_function(&mut self, func: &'ast Function<Aug>) {
+        if func.over.is_some() {
+            self.disallow("window functions are");
+        } else if let Ok(item) = self.scx.get_item_by_resolved_name(&func.name) {
+            if let Ok(Func::Aggregate { .. }) = item.func() {
+                self.disallow("aggregate functions are");
+            }
+        }
+        visit::visit_function(self, func);
+    }
+
+    fn visit_query(&mut self, query: &'ast Query<Aug>) {
+        // Subqueries are only inspected for references to the CTE.
+        if self.references(|counter| counter.visit_query(query)) {
+            self.disallow_reference("a subquery");
+        }
+    }
+}
+
+/// Plans a CTE of a `WITH RECURSIVE` block that references itself and
+/// introduces it to `qcx.ctes`.
+///
+/// As in PostgreSQL, the CTE's query must have the form `non_recursive_term
+/// UNION [ALL] recursive_term`, where only the recursive term references the
+/// CTE, and only once, and the CTE's column types are those of the
+/// non-recursive term. The recursive term must also be linear in the CTE, as
+/// checked by [`RecursiveTermChecker`]. The CTE is the fixpoint of its query,
+/// which is bound by a `LetRec`. Because the query is linear in the CTE, the
+/// fixpoint has the rows that SQL's iterative evaluation produces, with set
+/// semantics for `UNION` and bag semantics for `UNION ALL`.
+fn plan_recursive_cte(
+    qcx: &mut QueryContext,
+    cte: &Cte<Aug>,
+) -> Result<(LocalId, HirRelationExpr, Option<CteDesc>), PlanError> {
+    let cte_name = normalize::ident(cte.alias.name.clone());
+    let (non_recursive_term, recursive_term) = match &cte.query {
+        Query {
+            ctes,
+            body:
+                SetExpr::SetOperation {
+                    op: SetOperator::Union,
+                    all: _,
+                    left,
+                    right,
+                },
+            order_by,
+            limit: None,
+            offset: None,
+        } if ctes.is_empty() && order_by.is_empty() => (left, right),
+        _ => sql_bail!(
+            "recursive query {} does not have the form non-recursive-term UNION [ALL] recursive-term",
+            cte_name.quoted()
+        ),
+    };
+    let mut counter = CteReferenceCounter {
+        id: cte.id,
+        count: 0,
+    };
+    counter.visit_set_expr(non_recursive_term);
+    if counter.count > 0 {
+        sql_bail!(
+            "recursive reference to query {} must not appear within its non-recursive term",
+            cte_name.quoted()
+        );
+    }
+    counter.visit_set_expr(recursive_term);
+    if counter.count > 1 {
+        sql_bail!(
+            "recursive reference to query {} must not appear more than once",
+            cte_name.quoted()
+        );
+    }
+    let mut checker = RecursiveTermChecker {
+        scx: qcx.scx,
+        id: cte.id,
+        name: &cte_name,
+        err: None,
+    };
+    checker.visit_set_expr(recursive_term);
+    if let Some(err) = checker.err {
+        return Err(err);
+    }
+
+    // Infer the CTE's type from the non-recursive term.
+    let (val, scope) = plan_set_expr(qcx, non_recursive_term)?;
+    let typ = qcx.relation_type(&val);
+    let mut desc = RelationDesc::from_names_and_types(
+        scope
+            .column_names()
+            .cloned()
+            .zip(typ.column_types.into_iter().map(|ty| ty.nullable(true))),
+    );
+    plan_utils::maybe_rename_columns(
+        format!("CTE {}", cte.alias.name),
+        &mut desc,
+        &cte.alias.columns,
+    )?;
+    let proposed_typ = desc.typ().clone();
+    // Capture the prior value if it exists, so that it can be re-installed.
+    let shadowed = qcx.ctes.insert(
+        cte.id,
+        CteDesc {
+            name: cte_name.clone(),
+            desc,
+        },
+    );
+
+    let (val, _scope) = plan_nested_query(qcx, &cte.query)?;
+    let derived_typ = qcx.relation_type(&val);
+    for (i, (proposed, derived)) in proposed_typ
+        .column_types
+        .iter()
+        .zip(derived_typ.column_types.iter())
+        .enumerate()
+    {
+        if !proposed.scalar_type.base_eq(&derived.scalar_type) {
+            sql_bail!(
+                "recursive query {} column {} has type {} in non-recursive term but type {} overall",
+                cte_name.quoted(),
+                i + 1,
+                qcx.humanize_scalar_type(&proposed.scalar_type),
+                qcx.humanize_scalar_type(&derived.scalar_type)
+            );
+        }
+    }
+    // Impose the type modifiers of the non-recursive term, if any.
+    let val = cast_relation(
+        qcx,
+        CastContext::Assignment,
+        val,
+        proposed_typ.column_types.iter().map(|c| &c.scalar_type),
+    )
+    .map_err(|_| {
+        sql_err!(
+            "recursive query {} does not match the type of its non-recursive term",
+            cte_name.quoted()
+        )
+    })?;
+
+    Ok((cte.id, val, shadowed))
+}
+
 pub fn plan_nested_query(
     qcx: &mut QueryContext,
     q: &Query<Aug>,
@@ -1823,6 +2664,10 @@ fn plan_set_expr(
                     show::plan_show_create_materialized_view(qcx.scx, stmt.clone())?,
                     show::describe_show_create_materialized_view(qcx.scx, stmt)?,
                 ),
+                ShowStatement::ShowCreateFunction(stmt) => to_hirscope(
+                    show::plan_show_create_function(qcx.scx, stmt.clone())?,
+                    show::describe_show_create_function(qcx.scx, stmt)?,
+                ),
                 ShowStatement::ShowObjects(stmt) => {
                     show::show_objects(qcx.scx, stmt)?.plan_hir(qcx)
                 }
@@ -2314,19 +3159,34 @@ fn plan_view_select(
                 allow_parameters: true,
                 allow_windows: true,
             };
-            let expr = match select_item {
+            let (expr, collation) = match select_item {
                 ExpandedSelectItem::InputOrdinal(i) => {
                     if let Some(column) = select_all_mapping.get(i).copied() {
-                        HirScalarExpr::column(column)
+                        let collation = group_scope.items[column].collation;
+                        (HirScalarExpr::column(column), collation)
                     } else {
                         return Err(PlanError::ungrouped_column(&from_scope.items[*i]));
                     }
                 }
-                ExpandedSelectItem::Expr(expr) => plan_expr(ecx, expr)
-                    .map_err(check_ungrouped_col)?
-                    .type_as_any(ecx)?,
+                ExpandedSelectItem::Expr(expr) => {
+                    let collation = plan_collation(ecx, expr)?;
+                    let expr = plan_expr(ecx, expr)
+                        .map_err(check_ungrouped_col)?
+                        .type_as_any(ecx)?;
+                    (expr, collation)
+                }
+            };
+            // A column reference with a collation other than the column's,
+            // as in `a COLLATE "und-x-icu"`, needs a column of its own.
+            let column = match expr {
+                HirScalarExpr::Column(ColumnRef { level: 0, column })
+                    if group_scope.items[column].collation == collation =>
+                {
+                    Some(column)
+                }
+                _ => None,
             };
-            if let HirScalarExpr::Column(ColumnRef { level: 0, column }) = expr {
+            if let Some(column) = column {
                 // Simple column reference; no need to map on a new expression.
                 output_columns.push((column, column_name));
             } else {
@@ -2340,9 +3200,9 @@ fn plan_view_select(
                 new_type.column_types.push(typ);
                 new_exprs.push(expr);
                 output_columns.push((group_scope.len(), column_name));
-                group_scope
-                    .items
-                    .push(ScopeItem::from_expr(select_item.as_expr().cloned()));
+                let mut scope_item = ScopeItem::from_expr(select_item.as_expr().cloned());
+                scope_item.collation = collation;
+                group_scope.items.push(scope_item);
             }
         }
         relation_expr = relation_expr.map(new_exprs);
@@ -2474,7 +3334,14 @@ fn plan_view_select(
     // accumulated in the scope during planning of this SELECT is erased. The
     // clean scope has at most one name for each column, and the names are not
     // associated with any table.
-    let scope = Scope::from_source(None, projection.into_iter().map(|(_expr, name)| name));
+    let collations: Vec<_> = output_columns
+        .iter()
+        .map(|(column, _name)| group_scope.items[*column].collation)
+        .collect();
+    let mut scope = Scope::from_source(None, projection.into_iter().map(|(_expr, name)| name));
+    for (item, collation) in scope.items.iter_mut().zip(collations) {
+        item.collation = collation;
+    }
 
     Ok(SelectPlan {
         expr: relation_expr,
@@ -2632,6 +3499,15 @@ pub(crate) fn plan_order_by_exprs(
     let mut map_exprs = vec![];
     for obe in order_by_exprs {
         let expr = plan_order_by_or_distinct_expr(ecx, &obe.expr, output_columns)?;
+        // Unless the expression has a `COLLATE` clause, a reference to a
+        // column, including an output column, uses the column's collation.
+        let collation = match (&obe.expr, &expr) {
+            (Expr::Collate { .. }, _) => plan_collation(ecx, &obe.expr)?,
+            (_, HirScalarExpr::Column(ColumnRef { level: 0, column })) => {
+                ecx.scope.items.get(*column).and_then(|item| item.collation)
+            }
+            _ => plan_collation(ecx, &obe.expr)?,
+        };
         // If the expression is a reference to an existing column,
         // do not introduce a new column to support it.
         let column = match expr {
@@ -2641,7 +3517,9 @@ pub(crate) fn plan_order_by_exprs(
                 ecx.relation_type.arity() + map_exprs.len() - 1
             }
         };
-        order_by.push(resolve_desc_and_nulls_last(obe, column));
+        let mut column_order = resolve_desc_and_nulls_last(obe, column);
+        column_order.collation = collation;
+        order_by.push(column_order);
     }
     Ok((order_by, map_exprs))
 }
@@ -2688,74 +3566,343 @@ fn plan_order_by_or_distinct_expr(
         }
     }
 
-    plan_expr(ecx, expr)?.type_as_any(ecx)
+    plan_expr(ecx, expr)?.type_as_any(ecx)
+}
+
+fn plan_table_with_joins(
+    qcx: &QueryContext,
+    table_with_joins: &TableWithJoins<Aug>,
+) -> Result<(HirRelationExpr, Scope), PlanError> {
+    let (mut expr, mut scope) = plan_table_factor(qcx, &table_with_joins.relation)?;
+    for join in &table_with_joins.joins {
+        let (new_expr, new_scope) = plan_join(qcx, expr, scope, join)?;
+        expr = new_expr;
+        scope = new_scope;
+    }
+    Ok((expr, scope))
+}
+
+fn plan_table_factor(
+    qcx: &QueryContext,
+    table_factor: &TableFactor<Aug>,
+) -> Result<(HirRelationExpr, Scope), PlanError> {
+    match table_factor {
+        TableFactor::Table { name, alias } => {
+            let (expr, scope) = qcx.resolve_table_name(name.clone())?;
+            let scope = plan_table_alias(scope, alias.as_ref())?;
+            Ok((expr, scope))
+        }
+
+        TableFactor::Function {
+            function,
+            alias,
+            with_ordinality,
+        } => plan_solitary_table_function(qcx, function, alias.as_ref(), *with_ordinality),
+
+        TableFactor::RowsFrom {
+            functions,
+            alias,
+            with_ordinality,
+        } => plan_rows_from(qcx, functions, alias.as_ref(), *with_ordinality),
+
+        TableFactor::Derived {
+            lateral,
+            subquery,
+            alias,
+        } => {
+            let mut qcx = (*qcx).clone();
+            if !lateral {
+                // Since this derived table was not marked as `LATERAL`,
+                // make elements in outer scopes invisible until we reach the
+                // next lateral barrier.
+                for scope in &mut qcx.outer_scopes {
+                    if scope.lateral_barrier {
+                        break;
+                    }
+                    scope.items.clear();
+                }
+            }
+            qcx.outer_scopes[0].lateral_barrier = true;
+            let (expr, scope) = plan_nested_query(&mut qcx, subquery)?;
+            let scope = plan_table_alias(scope, alias.as_ref())?;
+            Ok((expr, scope))
+        }
+
+        TableFactor::NestedJoin { join, alias } => {
+            let (expr, scope) = plan_table_with_joins(qcx, join)?;
+            let scope = plan_table_alias(scope, alias.as_ref())?;
+            Ok((expr, scope))
+        }
+
+        TableFactor::JsonTable {
+            doc,
+            path,
+            passing,
+            columns,
+            on_error,
+            alias,
+        } => plan_json_table(
+            qcx,
+            doc,
+            path,
+            passing,
+            columns,
+            on_error.as_ref(),
+            alias.as_ref(),
+        ),
+    }
+}
+
+/// Plans a `JSON_TABLE` call as a call to [`mz_expr::TableFunc::JsonTable`],
+/// whose arguments are the document and a `jsonb` object containing the
+/// variables in the `PASSING` clause.
+fn plan_json_table(
+    qcx: &QueryContext,
+    doc: &Expr<Aug>,
+    path: &str,
+    passing: &[JsonTablePassing<Aug>],
+    columns: &[JsonTableColumn<Aug>],
+    on_error: Option<&JsonTableBehavior<Aug>>,
+    alias: Option<&TableAlias>,
+) -> Result<(HirRelationExpr, Scope), PlanError> {
+    let ecx = &ExprContext {
+        qcx,
+        name: "JSON_TABLE",
+        scope: &Scope::empty(),
+        relation_type: &RelationType::empty(),
+        allow_aggregates: false,
+        allow_subqueries: true,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+
+    let doc = plan_expr(ecx, doc)?.type_as(ecx, &ScalarType::Jsonb)?;
+    let vars = if passing.is_empty() {
+        HirScalarExpr::literal(Datum::String("{}"), ScalarType::String)
+            .call_unary(UnaryFunc::CastStringToJsonb(expr_func::CastStringToJsonb))
+    } else {
+        let mut exprs = vec![];
+        for JsonTablePassing { expr, name } in passing {
+            let name = normalize::ident(name.clone());
+            exprs.push(HirScalarExpr::literal(
+                Datum::String(&name),
+                ScalarType::String,
+            ));
+            let expr = plan_expr(ecx, expr)?.type_as_any(ecx)?;
+            exprs.push(typeconv::to_jsonb(ecx, expr));
+        }
+        HirScalarExpr::CallVariadic {
+            func: VariadicFunc::JsonbBuildObject,
+            exprs,
+        }
+    };
+
+    let error_on_error = match on_error {
+        None | Some(JsonTableBehavior::Empty) => false,
+        Some(JsonTableBehavior::Error) => true,
+        Some(behavior) => sql_bail!("{} ON ERROR is not valid for JSON_TABLE", behavior),
+    };
+    let mut names = vec![];
+    let plan = plan_json_table_plan(ecx, path, columns, error_on_error, &mut names)?;
+
+    let scope = Scope::from_source(
+        Some(PartialItemName {
+            database: None,
+            schema: None,
+            item: "json_table".into(),
+        }),
+        names,
+    );
+    let scope = plan_table_alias(scope, alias)?;
+    let expr = HirRelationExpr::CallTable {
+        func: mz_expr::TableFunc::JsonTable {
+            plan,
+            error_on_error,
+        },
+        exprs: vec![doc, vars],
+    };
+    Ok((expr, scope))
+}
+
+/// Plans the path and columns of a `JSON_TABLE` call or of one of its `NESTED
+/// PATH` clauses, appending the names of the columns to `names`.
+fn plan_json_table_plan(
+    ecx: &ExprContext,
+    path: &str,
+    columns: &[JsonTableColumn<Aug>],
+    error_on_error: bool,
+    names: &mut Vec<ColumnName>,
+) -> Result<mz_expr::JsonTablePlan, PlanError> {
+    let path = strconv::parse_jsonpath(path)?;
+    let mut plan_columns = vec![];
+    for column in columns {
+        let mut push_name = |name: &Ident| {
+            let name = normalize::column_name(name.clone());
+            if names.contains(&name) {
+                sql_bail!("duplicate JSON_TABLE column name: {}", name);
+            }
+            names.push(name.clone());
+            Ok::<_, PlanError>(name)
+        };
+        let plan_column = match column {
+            JsonTableColumn::Ordinality { name } => {
+                push_name(name)?;
+                mz_expr::JsonTableColumn::Ordinality
+            }
+            JsonTableColumn::Regular {
+                name,
+                data_type,
+                path,
+                on_empty,
+                on_error,
+            } => {
+                let name = push_name(name)?;
+                let typ = scalar_type_from_sql(ecx.qcx.scx, data_type)?;
+                let from = match typ {
+                    ScalarType::Jsonb => ScalarType::Jsonb,
+                    _ => ScalarType::String,
+                };
+                let default_on_error = if error_on_error {
+                    mz_expr::JsonTableBehavior::Error
+                } else {
+                    mz_expr::JsonTableBehavior::Null
+                };
+                mz_expr::JsonTableColumn::Regular {
+                    path: plan_json_table_column_path(&name, path.as_deref())?,
+                    cast_expr: Box::new(plan_json_table_cast(ecx, &from, data_type)?),
+                    on_empty: plan_json_table_behavior(
+                        ecx,
+                        on_empty.as_ref(),
+                        data_type,
+                        mz_expr::JsonTableBehavior::Null,
+                    )?,
+                    on_error: plan_json_table_behavior(
+                        ecx,
+                        on_error.as_ref(),
+                        data_type,
+                        default_on_error,
+                    )?,
+                    name: name.to_string(),
+                    typ,
+                }
+            }
+            JsonTableColumn::Exists {
+                name,
+                data_type,
+                path,
+                on_error,
+            } => {
+                let name = push_name(name)?;
+                let on_error = match on_error {
+                    None if error_on_error => mz_expr::JsonTableBehavior::Error,
+                    None | Some(JsonTableBehavior::False) => {
+                        mz_expr::JsonTableBehavior::Default(MirScalarExpr::literal_false())
+                    }
+                    Some(JsonTableBehavior::True) => {
+                        mz_expr::JsonTableBehavior::Default(MirScalarExpr::literal_true())
+                    }
+                    Some(JsonTableBehavior::Unknown) => mz_expr::JsonTableBehavior::Null,
+                    Some(JsonTableBehavior::Error) => mz_expr::JsonTableBehavior::Error,
+                    Some(behavior) => {
+                        sql_bail!("{} ON ERROR is not valid for EXISTS columns", behavior)
+                    }
+                };
+                mz_expr::JsonTableColumn::Exists {
+                    path: plan_json_table_column_path(&name, path.as_deref())?,
+                    typ: scalar_type_from_sql(ecx.qcx.scx, data_type)?,
+                    cast_expr: Box::new(plan_json_table_cast(ecx, &ScalarType::Bool, data_type)?),
+                    on_error,
+                }
+            }
+            JsonTableColumn::Nested { path, columns } => mz_expr::JsonTableColumn::Nested(
+                plan_json_table_plan(ecx, path, columns, error_on_error, names)?,
+            ),
+        };
+        plan_columns.push(plan_column);
+    }
+    Ok(mz_expr::JsonTablePlan {
+        path,
+        columns: plan_columns,
+    })
+}
+
+/// Parses the path of a `JSON_TABLE` column, which defaults to the member of
+/// the row's item with the same name as the column.
+fn plan_json_table_column_path(
+    name: &ColumnName,
+    path: Option<&str>,
+) -> Result<JsonPath, PlanError> {
+    let path = match path {
+        Some(path) => path.to_string(),
+        None => format!(
+            "$.\"{}\"",
+            name.as_str().replace('\\', "\\\\").replace('"', "\\\"")
+        ),
+    };
+    Ok(strconv::parse_jsonpath(&path)?)
 }
 
-fn plan_table_with_joins(
-    qcx: &QueryContext,
-    table_with_joins: &TableWithJoins<Aug>,
-) -> Result<(HirRelationExpr, Scope), PlanError> {
-    let (mut expr, mut scope) = plan_table_factor(qcx, &table_with_joins.relation)?;
-    for join in &table_with_joins.joins {
-        let (new_expr, new_scope) = plan_join(qcx, expr, scope, join)?;
-        expr = new_expr;
-        scope = new_scope;
+/// Returns an expression that casts the first column of an imaginary row, of
+/// type `from`, to `data_type`, enforcing its constraints if it is a domain.
+fn plan_json_table_cast(
+    ecx: &ExprContext,
+    from: &ScalarType,
+    data_type: &ResolvedDataType,
+) -> Result<MirScalarExpr, PlanError> {
+    let qcx = QueryContext::root(ecx.qcx.scx, ecx.qcx.lifetime);
+    let relation_type = RelationType::new(vec![from.clone().nullable(true)]);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "JSON_TABLE",
+        scope: &Scope::empty(),
+        relation_type: &relation_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let to = scalar_type_from_sql(ecx.qcx.scx, data_type)?;
+    let mut expr = typeconv::plan_cast(ecx, CastContext::Explicit, HirScalarExpr::column(0), &to)?;
+    if let Some(domain) = domain_from_sql(ecx.qcx.scx, data_type) {
+        expr = typeconv::plan_domain_constraints(ecx.qcx.scx, expr, domain)?;
     }
-    Ok((expr, scope))
+    expr.lower_uncorrelated()
 }
 
-fn plan_table_factor(
-    qcx: &QueryContext,
-    table_factor: &TableFactor<Aug>,
-) -> Result<(HirRelationExpr, Scope), PlanError> {
-    match table_factor {
-        TableFactor::Table { name, alias } => {
-            let (expr, scope) = qcx.resolve_table_name(name.clone())?;
-            let scope = plan_table_alias(scope, alias.as_ref())?;
-            Ok((expr, scope))
-        }
-
-        TableFactor::Function {
-            function,
-            alias,
-            with_ordinality,
-        } => plan_solitary_table_function(qcx, function, alias.as_ref(), *with_ordinality),
-
-        TableFactor::RowsFrom {
-            functions,
-            alias,
-            with_ordinality,
-        } => plan_rows_from(qcx, functions, alias.as_ref(), *with_ordinality),
-
-        TableFactor::Derived {
-            lateral,
-            subquery,
-            alias,
-        } => {
-            let mut qcx = (*qcx).clone();
-            if !lateral {
-                // Since this derived table was not marked as `LATERAL`,
-                // make elements in outer scopes invisible until we reach the
-                // next lateral barrier.
-                for scope in &mut qcx.outer_scopes {
-                    if scope.lateral_barrier {
-                        break;
-                    }
-                    scope.items.clear();
-                }
+/// Plans the `ON EMPTY` or `ON ERROR` behavior of a regular `JSON_TABLE`
+/// column, using `default` if the behavior is not specified.
+fn plan_json_table_behavior(
+    ecx: &ExprContext,
+    behavior: Option<&JsonTableBehavior<Aug>>,
+    data_type: &ResolvedDataType,
+    default: mz_expr::JsonTableBehavior,
+) -> Result<mz_expr::JsonTableBehavior, PlanError> {
+    Ok(match behavior {
+        None => default,
+        Some(JsonTableBehavior::Null) => mz_expr::JsonTableBehavior::Null,
+        Some(JsonTableBehavior::Error) => mz_expr::JsonTableBehavior::Error,
+        Some(JsonTableBehavior::Default(expr)) => {
+            // The default is evaluated without access to any columns.
+            let qcx = QueryContext::root(ecx.qcx.scx, ecx.qcx.lifetime);
+            let ecx = &ExprContext {
+                qcx: &qcx,
+                name: "DEFAULT in JSON_TABLE",
+                scope: &Scope::empty(),
+                relation_type: &RelationType::empty(),
+                allow_aggregates: false,
+                allow_subqueries: false,
+                allow_parameters: false,
+                allow_windows: false,
+            };
+            let to = scalar_type_from_sql(ecx.qcx.scx, data_type)?;
+            let mut expr = plan_expr(ecx, expr)?.cast_to(ecx, CastContext::Assignment, &to)?;
+            if let Some(domain) = domain_from_sql(ecx.qcx.scx, data_type) {
+                expr = typeconv::plan_domain_constraints(ecx.qcx.scx, expr, domain)?;
             }
-            qcx.outer_scopes[0].lateral_barrier = true;
-            let (expr, scope) = plan_nested_query(&mut qcx, subquery)?;
-            let scope = plan_table_alias(scope, alias.as_ref())?;
-            Ok((expr, scope))
-        }
-
-        TableFactor::NestedJoin { join, alias } => {
-            let (expr, scope) = plan_table_with_joins(qcx, join)?;
-            let scope = plan_table_alias(scope, alias.as_ref())?;
-            Ok((expr, scope))
+            mz_expr::JsonTableBehavior::Default(expr.lower_uncorrelated()?)
         }
-    }
+        Some(behavior) => sql_bail!("{} is not a valid JSON_TABLE column behavior", behavior),
+    })
 }
 
 /// Plans a `ROWS FROM` expression.
@@ -3014,6 +4161,10 @@ fn plan_table_function_internal(
 
     let scalar_args = match args {
         FunctionArgs::Star => sql_bail!("{} does not accept * as an argument", name),
+        FunctionArgs::WithinGroup { .. } => sql_bail!(
+            "{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+            name
+        ),
         FunctionArgs::Args { args, order_by } => {
             if !order_by.is_empty() {
                 sql_bail!(
@@ -3816,7 +4967,10 @@ fn plan_cast(
     };
     let ecx = &ecx.with_name("CAST");
     let expr = typeconv::plan_coerce(ecx, expr, &to_scalar_type)?;
-    let expr = typeconv::plan_cast(ecx, CastContext::Explicit, expr, &to_scalar_type)?;
+    let mut expr = typeconv::plan_cast(ecx, CastContext::Explicit, expr, &to_scalar_type)?;
+    if let Some(domain) = domain_from_sql(ecx.qcx.scx, data_type) {
+        expr = typeconv::plan_domain_constraints(ecx.qcx.scx, expr, domain)?;
+    }
     Ok(expr.into())
 }
 
@@ -4399,7 +5553,73 @@ fn plan_collate(
     {
         plan_expr(ecx, expr)
     } else {
-        bail_unsupported!("COLLATE");
+        // The collation itself is applied by the comparisons, orderings, and
+        // aggregates of the expression. See `plan_collation`.
+        let expr = plan_expr(ecx, expr)?.type_as_any(ecx)?;
+        plan_column_collation(ecx.qcx.scx, &ecx.scalar_type(&expr), collation)?;
+        Ok(expr.into())
+    }
+}
+
+/// Resolves the name of a collation.
+///
+/// Returns `None` for the collations that compare strings bytewise, which is
+/// the default.
+fn resolve_collation(name: &UnresolvedItemName) -> Result<Option<Collation>, PlanError> {
+    let name = normalize::unresolved_item_name(name.clone())?;
+    let collation = match (&name.database, name.schema.as_deref()) {
+        (None, None | Some(mz_repr::namespaces::PG_CATALOG_SCHEMA)) => match name.item.as_str() {
+            "default" | "C" | "POSIX" | "ucs_basic" => return Ok(None),
+            item => Collation::from_name(item),
+        },
+        _ => None,
+    };
+    match collation {
+        Some(collation) => Ok(Some(collation)),
+        None => sql_bail!(
+            "collation {} for encoding \"UTF8\" does not exist",
+            name.to_string().quoted()
+        ),
+    }
+}
+
+/// Resolves the collation of a value of type `ty`, as in a `COLLATE` clause.
+pub(crate) fn plan_column_collation(
+    scx: &StatementContext,
+    ty: &ScalarType,
+    collation: &UnresolvedItemName,
+) -> Result<Option<Collation>, PlanError> {
+    if !matches!(
+        ty,
+        ScalarType::String | ScalarType::VarChar { .. } | ScalarType::Char { .. }
+    ) {
+        sql_bail!(
+            "collations are not supported by type {}",
+            scx.humanize_scalar_type(ty)
+        );
+    }
+    resolve_collation(collation)
+}
+
+/// Determines the collation with which the strings that `expr` evaluates to
+/// are compared, if they are not to be compared bytewise.
+///
+/// The collation is either given explicitly by a `COLLATE` clause or is that of
+/// the column that `expr` references. Like in PostgreSQL, the collation of any
+/// other expression is the default one.
+fn plan_collation(ecx: &ExprContext, expr: &Expr<Aug>) -> Result<Option<Collation>, PlanError> {
+    match expr {
+        Expr::Collate { collation, .. } => resolve_collation(collation),
+        Expr::Nested(expr) => plan_collation(ecx, expr),
+        Expr::Identifier(names) => match plan_identifier(ecx, names) {
+            Ok(HirScalarExpr::Column(column)) => Ok(ecx
+                .scope
+                .all_items(&ecx.qcx.outer_scopes)
+                .find(|(c, _, _)| *c == column)
+                .and_then(|(_, _, item)| item.collation)),
+            _ => Ok(None),
+        },
+        _ => Ok(None),
     }
 }
 
@@ -4600,6 +5820,7 @@ pub(crate) fn resolve_desc_and_nulls_last<T: AstInfo>(
         // https://www.postgresql.org/docs/14/queries-order.html
         //   "NULLS FIRST is the default for DESC order, and NULLS LAST otherwise"
         nulls_last: obe.nulls_last.unwrap_or(!desc),
+        collation: None,
     }
 }
 
@@ -4623,7 +5844,9 @@ fn plan_function_order_by(
             // `plan_expr` directly rather than `plan_order_by_or_distinct_expr`.
             let expr = plan_expr(ecx, &obe.expr)?.type_as_any(ecx)?;
             order_by_exprs.push(expr);
-            col_orders.push(resolve_desc_and_nulls_last(obe, i));
+            let mut col_order = resolve_desc_and_nulls_last(obe, i);
+            col_order.collation = plan_collation(ecx, &obe.expr)?;
+            col_orders.push(col_order);
         }
     }
     Ok((order_by_exprs, col_orders))
@@ -4636,7 +5859,7 @@ fn plan_aggregate_common(
         name,
         args,
         filter,
-        over: _,
+        over,
         distinct,
     }: &Function<Aug>,
 ) -> Result<AggregateExpr, PlanError> {
@@ -4667,6 +5890,13 @@ fn plan_aggregate_common(
     // rules to all aggregates, not just `count`, since we may one day support
     // user-defined aggregates, including user-defined aggregates that take no
     // parameters.
+    // The string extrema honor the collation of their argument.
+    let collation = match args {
+        FunctionArgs::Args { args, .. } if args.len() == 1 => plan_collation(ecx, &args[0])?,
+        _ => None,
+    };
+
+    let within_group = matches!(args, FunctionArgs::WithinGroup { .. });
     let (args, order_by) = match &args {
         FunctionArgs::Star => (vec![], vec![]),
         FunctionArgs::Args { args, order_by } => {
@@ -4682,11 +5912,65 @@ fn plan_aggregate_common(
             let args = plan_exprs(ecx, args)?;
             (args, order_by.clone())
         }
+        // Ordered-set aggregates aggregate the expressions of their `WITHIN
+        // GROUP` ordering, which we pass after the direct arguments.
+        FunctionArgs::WithinGroup { args, order_by } => {
+            let mut args = plan_exprs(ecx, args)?;
+            for obe in order_by {
+                args.push(plan_expr(ecx, &obe.expr)?);
+            }
+            (args, order_by.clone())
+        }
     };
 
     let (order_by_exprs, col_orders) = plan_function_order_by(ecx, &order_by)?;
 
     let (mut expr, func) = func::select_impl(ecx, FuncSpec::Func(name), impls, args, col_orders)?;
+    let func = match (func, collation) {
+        (AggregateFunc::MaxString, Some(collation)) => {
+            AggregateFunc::MaxStringCollated { collation }
+        }
+        (AggregateFunc::MinString, Some(collation)) => {
+            AggregateFunc::MinStringCollated { collation }
+        }
+        (func, _) => func,
+    };
+    if within_group != func.is_ordered_set() || (func.is_ordered_set() && over.is_some()) {
+        let name = ecx
+            .qcx
+            .scx
+            .humanize_resolved_name(name)
+            .expect("name actually resolved");
+        if !within_group {
+            sql_bail!(
+                "WITHIN GROUP is required for ordered-set aggregate {}",
+                name
+            );
+        } else if func.is_ordered_set() {
+            bail_unsupported!(format!("OVER for ordered-set aggregate {}", name));
+        } else {
+            sql_bail!(
+                "{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+                name
+            );
+        }
+    }
+    if func.is_ordered_set() {
+        // The direct argument is evaluated for every input row, and the
+        // aggregate uses an arbitrary one, so it must not vary within a group.
+        let mut seen_input = false;
+        if let HirScalarExpr::CallVariadic { exprs, .. } = &expr {
+            #[allow(deprecated)]
+            exprs[0].visit_columns(0, &mut |depth, col| {
+                if col.level == depth {
+                    seen_input = true;
+                }
+            });
+        }
+        if seen_input {
+            bail_unsupported!("ordered-set aggregate direct arguments that refer to input columns");
+        }
+    }
     if let Some(filter) = &filter {
         // If a filter is present, as in
         //
@@ -4776,12 +6060,17 @@ fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr,
         },
     )?;
     match items.as_slice() {
-        // The name doesn't refer to a table either. Return an error.
-        [] => Err(PlanError::UnknownColumn {
-            table: None,
-            column: col_name,
-            similar: similar_names,
-        }),
+        // The name doesn't refer to a table either. Check if it refers to a
+        // named parameter of a user-defined function, and otherwise return an
+        // error.
+        [] => match ecx.qcx.param_names.get(&col_name) {
+            Some(n) => Ok(HirScalarExpr::Parameter(*n)),
+            None => Err(PlanError::UnknownColumn {
+                table: None,
+                column: col_name,
+                similar: similar_names,
+            }),
+        },
         // The name refers to a table that is the result of a function that
         // returned a single column. Per PostgreSQL, this is a special case
         // that returns the value directly.
@@ -4840,7 +6129,47 @@ fn plan_op(
         None => plan_exprs(ecx, &[expr1])?,
         Some(expr2) => plan_exprs(ecx, &[expr1, expr2])?,
     };
-    func::select_impl(ecx, FuncSpec::Op(op), impls, args, vec![])
+    let expr = func::select_impl(ecx, FuncSpec::Op(op), impls, args, vec![])?;
+
+    // String comparisons honor the collation of their operands.
+    match (expr, expr2) {
+        (
+            HirScalarExpr::CallBinary {
+                func,
+                expr1: arg1,
+                expr2: arg2,
+            },
+            Some(expr2),
+        ) if matches!(
+            func,
+            BinaryFunc::Lt | BinaryFunc::Lte | BinaryFunc::Gt | BinaryFunc::Gte
+        ) && matches!(
+            ecx.scalar_type(&*arg1),
+            ScalarType::String | ScalarType::VarChar { .. } | ScalarType::Char { .. }
+        ) =>
+        {
+            let collation = match (plan_collation(ecx, expr1)?, plan_collation(ecx, expr2)?) {
+                (Some(c1), Some(c2)) if c1 != c2 => {
+                    sql_bail!("could not determine which collation to use for string comparison")
+                }
+                (c1, c2) => c1.or(c2),
+            };
+            let func = match (func, collation) {
+                (func, None) => func,
+                (BinaryFunc::Lt, Some(collation)) => BinaryFunc::LtCollated { collation },
+                (BinaryFunc::Lte, Some(collation)) => BinaryFunc::LteCollated { collation },
+                (BinaryFunc::Gt, Some(collation)) => BinaryFunc::GtCollated { collation },
+                (BinaryFunc::Gte, Some(collation)) => BinaryFunc::GteCollated { collation },
+                _ => unreachable!("checked above"),
+            };
+            Ok(HirScalarExpr::CallBinary {
+                func,
+                expr1: arg1,
+                expr2: arg2,
+            })
+        }
+        (expr, _) => Ok(expr),
+    }
 }
 
 fn plan_function<'a>(
@@ -4875,6 +6204,7 @@ fn plan_function<'a>(
                 _window_frame,
                 partition_by,
                 scalar_args,
+                filter,
             ) = plan_window_function_non_aggr(ecx, f)?;
 
             // All scalar window functions have 0 parameters. Let's print a nice error msg if the
@@ -4905,18 +6235,26 @@ fn plan_function<'a>(
                 bail_unsupported!(IGNORE_NULLS_ERROR_MSG);
             }
 
-            return Ok(HirScalarExpr::Windowing(WindowExpr {
+            let window_expr = HirScalarExpr::Windowing(WindowExpr {
                 func: WindowExprType::Scalar(ScalarWindowExpr {
                     func,
                     order_by: col_orders,
                 }),
                 partition_by,
                 order_by: order_by_exprs,
-            }));
+            });
+            return Ok(apply_window_filter(ecx, window_expr, filter));
         }
         Func::ValueWindow(impls) => {
-            let (ignore_nulls, order_by_exprs, col_orders, window_frame, partition_by, scalar_args) =
-                plan_window_function_non_aggr(ecx, f)?;
+            let (
+                ignore_nulls,
+                order_by_exprs,
+                col_orders,
+                window_frame,
+                partition_by,
+                scalar_args,
+                filter,
+            ) = plan_window_function_non_aggr(ecx, f)?;
 
             let (args_encoded, func) =
                 func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
@@ -4928,7 +6266,7 @@ fn plan_function<'a>(
                 }
             }
 
-            return Ok(HirScalarExpr::Windowing(WindowExpr {
+            let window_expr = HirScalarExpr::Windowing(WindowExpr {
                 func: WindowExprType::Value(ValueWindowExpr {
                     func,
                     args: Box::new(args_encoded),
@@ -4938,7 +6276,8 @@ fn plan_function<'a>(
                 }),
                 partition_by,
                 order_by: order_by_exprs,
-            }));
+            });
+            return Ok(apply_window_filter(ecx, window_expr, filter));
         }
         Func::Aggregate(_) => {
             if f.over.is_none() {
@@ -4963,27 +6302,6 @@ fn plan_function<'a>(
                 let (ignore_nulls, order_by_exprs, col_orders, window_frame, partition_by) =
                     plan_window_function_common(ecx, &f.name, &f.over)?;
 
-                // https://github.com/MaterializeInc/materialize/issues/22268
-                match (&window_frame.start_bound, &window_frame.end_bound) {
-                    (
-                        mz_expr::WindowFrameBound::UnboundedPreceding,
-                        mz_expr::WindowFrameBound::OffsetPreceding(..),
-                    )
-                    | (
-                        mz_expr::WindowFrameBound::UnboundedPreceding,
-                        mz_expr::WindowFrameBound::OffsetFollowing(..),
-                    )
-                    | (
-                        mz_expr::WindowFrameBound::OffsetPreceding(..),
-                        mz_expr::WindowFrameBound::UnboundedFollowing,
-                    )
-                    | (
-                        mz_expr::WindowFrameBound::OffsetFollowing(..),
-                        mz_expr::WindowFrameBound::UnboundedFollowing,
-                    ) => bail_unsupported!("mixed unbounded - offset frames"),
-                    (_, _) => {} // other cases are ok
-                }
-
                 if ignore_nulls {
                     // https://github.com/MaterializeInc/materialize/issues/22272
                     // If we ever add support for ignore_nulls for a window aggregate, then don't
@@ -4993,11 +6311,6 @@ fn plan_function<'a>(
 
                 let aggregate_expr = plan_aggregate_common(ecx, f)?;
 
-                if aggregate_expr.distinct {
-                    // https://github.com/MaterializeInc/materialize/issues/22015
-                    bail_unsupported!("DISTINCT in window aggregates");
-                }
-
                 return Ok(HirScalarExpr::Windowing(WindowExpr {
                     func: WindowExprType::Aggregate(AggregateWindowExpr {
                         aggregate_expr,
@@ -5044,6 +6357,15 @@ fn plan_function<'a>(
                     .expect("already resolved")
             )
         }
+        FunctionArgs::WithinGroup { .. } => {
+            sql_bail!(
+                "{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+                ecx.qcx
+                    .scx
+                    .humanize_resolved_name(name)
+                    .expect("already resolved")
+            )
+        }
         FunctionArgs::Args { args, order_by } => {
             if !order_by.is_empty() {
                 sql_bail!(
@@ -5066,12 +6388,12 @@ pub const IGNORE_NULLS_ERROR_MSG: &str =
 
 /// Resolves the name to a set of function implementations.
 ///
-/// If the name does not specify a known built-in function, returns an error.
-pub fn resolve_func(
-    ecx: &ExprContext,
+/// If the name does not specify a known function, returns an error.
+pub fn resolve_func<'a>(
+    ecx: &ExprContext<'a>,
     name: &ResolvedItemName,
     args: &mz_sql_parser::ast::FunctionArgs<Aug>,
-) -> Result<&'static Func, PlanError> {
+) -> Result<&'a Func, PlanError> {
     if let Ok(i) = ecx.qcx.scx.get_item_by_resolved_name(name) {
         if let Ok(f) = i.func() {
             return Ok(f);
@@ -5082,6 +6404,13 @@ pub fn resolve_func(
     // message.
     let cexprs = match args {
         mz_sql_parser::ast::FunctionArgs::Star => vec![],
+        mz_sql_parser::ast::FunctionArgs::WithinGroup { args, order_by } => {
+            let mut cexprs = plan_exprs(ecx, args)?;
+            for obe in order_by {
+                cexprs.push(plan_expr(ecx, &obe.expr)?);
+            }
+            cexprs
+        }
         mz_sql_parser::ast::FunctionArgs::Args { args, order_by } => {
             if !order_by.is_empty() {
                 sql_bail!(
@@ -5248,10 +6577,11 @@ fn plan_window_function_non_aggr<'a>(
         mz_expr::WindowFrame,
         Vec<HirScalarExpr>,
         Vec<CoercibleScalarExpr>,
+        Option<HirScalarExpr>,
     ),
     PlanError,
 > {
-    let (ignore_nulls, order_by_exprs, col_orders, window_frame, partition) =
+    let (ignore_nulls, order_by_exprs, col_orders, window_frame, mut partition) =
         plan_window_function_common(ecx, name, over)?;
 
     if *distinct {
@@ -5261,14 +6591,30 @@ fn plan_window_function_non_aggr<'a>(
         );
     }
 
-    if filter.is_some() {
-        bail_unsupported!("FILTER in non-aggregate window functions");
-    }
+    // A FILTER restricts the window function to the rows satisfying the condition, i.e., these
+    // rows are computed as if the other rows weren't there, and the other rows get NULL. We
+    // achieve this by also partitioning by the condition, and then nulling out the result of the
+    // rows where the condition is not true (see `apply_window_filter`).
+    let filter = match filter {
+        Some(filter) => {
+            let cond =
+                plan_expr(&ecx.with_name("FILTER"), filter)?.type_as(ecx, &ScalarType::Bool)?;
+            partition.push(cond.clone());
+            Some(cond)
+        }
+        None => None,
+    };
 
     let scalar_args = match &args {
         FunctionArgs::Star => {
             sql_bail!("* argument is invalid with non-aggregate function {}", name)
         }
+        FunctionArgs::WithinGroup { .. } => {
+            sql_bail!(
+                "{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+                name
+            )
+        }
         FunctionArgs::Args { args, order_by } => {
             if !order_by.is_empty() {
                 sql_bail!(
@@ -5287,9 +6633,30 @@ fn plan_window_function_non_aggr<'a>(
         window_frame,
         partition,
         scalar_args,
+        filter,
     ))
 }
 
+/// Nulls out the result of a non-aggregate window function for the rows that don't satisfy its
+/// FILTER condition. See `plan_window_function_non_aggr`.
+fn apply_window_filter(
+    ecx: &ExprContext,
+    expr: HirScalarExpr,
+    filter: Option<HirScalarExpr>,
+) -> HirScalarExpr {
+    match filter {
+        Some(cond) => {
+            let typ = ecx.scalar_type(&expr);
+            HirScalarExpr::If {
+                cond: Box::new(cond),
+                then: Box::new(expr),
+                els: Box::new(HirScalarExpr::literal_null(typ)),
+            }
+        }
+        None => expr,
+    }
+}
+
 /// The common part of the planning of all window functions.
 fn plan_window_function_common(
     ecx: &ExprContext,
@@ -5320,10 +6687,6 @@ fn plan_window_function_common(
     if window_spec.ignore_nulls && window_spec.respect_nulls {
         sql_bail!("Both IGNORE NULLS and RESPECT NULLS were given.");
     }
-    let window_frame = match window_spec.window_frame.as_ref() {
-        Some(frame) => plan_window_frame(frame)?,
-        None => mz_expr::WindowFrame::default(),
-    };
     let mut partition = Vec::new();
     for expr in &window_spec.partition_by {
         partition.push(plan_expr(ecx, expr)?.type_as_any(ecx)?);
@@ -5331,6 +6694,11 @@ fn plan_window_function_common(
 
     let (order_by_exprs, col_orders) = plan_function_order_by(ecx, &window_spec.order_by)?;
 
+    let window_frame = match window_spec.window_frame.as_ref() {
+        Some(frame) => plan_window_frame(ecx, frame, &order_by_exprs, &col_orders)?,
+        None => mz_expr::WindowFrame::default(),
+    };
+
     Ok((
         window_spec.ignore_nulls,
         order_by_exprs,
@@ -5341,19 +6709,36 @@ fn plan_window_function_common(
 }
 
 fn plan_window_frame(
+    ecx: &ExprContext,
     WindowFrame {
         units,
         start_bound,
         end_bound,
-    }: &WindowFrame,
+    }: &WindowFrame<Aug>,
+    order_by_exprs: &[HirScalarExpr],
+    col_orders: &[ColumnOrder],
 ) -> Result<mz_expr::WindowFrame, PlanError> {
     use mz_expr::WindowFrameBound::*;
-    let units = window_frame_unit_ast_to_expr(units)?;
-    let start_bound = window_frame_bound_ast_to_expr(start_bound);
-    let end_bound = end_bound
-        .as_ref()
-        .map(window_frame_bound_ast_to_expr)
-        .unwrap_or(CurrentRow);
+    let units = window_frame_unit_ast_to_expr(units);
+    let start_bound = window_frame_bound_ast_to_expr(
+        ecx,
+        &units,
+        start_bound,
+        "starting",
+        order_by_exprs,
+        col_orders,
+    )?;
+    let end_bound = match end_bound {
+        Some(end_bound) => window_frame_bound_ast_to_expr(
+            ecx,
+            &units,
+            end_bound,
+            "ending",
+            order_by_exprs,
+            col_orders,
+        )?,
+        None => CurrentRow,
+    };
 
     // Validate bounds according to Postgres rules
     match (&start_bound, &end_bound) {
@@ -5366,52 +6751,21 @@ fn plan_window_frame(
             sql_bail!("frame end cannot be UNBOUNDED PRECEDING")
         }
         // Start bound should come before end bound in the list of bound definitions
-        (CurrentRow, OffsetPreceding(_)) => {
+        (CurrentRow, OffsetPreceding(_) | ValuePreceding(_)) => {
             sql_bail!("frame starting from current row cannot have preceding rows")
         }
-        (OffsetFollowing(_), OffsetPreceding(_) | CurrentRow) => {
+        (
+            OffsetFollowing(_) | ValueFollowing(_),
+            OffsetPreceding(_) | ValuePreceding(_) | CurrentRow,
+        ) => {
             sql_bail!("frame starting from following row cannot have preceding rows")
         }
-        // The above rules are adopted from Postgres.
-        // The following rules are Materialize-specific.
-        (OffsetPreceding(o1), OffsetFollowing(o2)) => {
-            // Note that the only hard limit is that partition size + offset should fit in i64, so
-            // in theory, we could support much larger offsets than this. But for our current
-            // performance, even 1000000 is quite big.
-            if *o1 > 1000000 || *o2 > 1000000 {
-                sql_bail!("Window frame offsets greater than 1000000 are currently not supported")
-            }
-        }
-        (OffsetPreceding(o1), OffsetPreceding(o2)) => {
-            if *o1 > 1000000 || *o2 > 1000000 {
-                sql_bail!("Window frame offsets greater than 1000000 are currently not supported")
-            }
-        }
-        (OffsetFollowing(o1), OffsetFollowing(o2)) => {
-            if *o1 > 1000000 || *o2 > 1000000 {
-                sql_bail!("Window frame offsets greater than 1000000 are currently not supported")
-            }
-        }
-        (OffsetPreceding(o), CurrentRow) => {
-            if *o > 1000000 {
-                sql_bail!("Window frame offsets greater than 1000000 are currently not supported")
-            }
-        }
-        (CurrentRow, OffsetFollowing(o)) => {
-            if *o > 1000000 {
-                sql_bail!("Window frame offsets greater than 1000000 are currently not supported")
-            }
-        }
         // Other bounds are valid
         (_, _) => (),
     }
 
-    // RANGE is only supported in the default frame
-    // https://github.com/MaterializeInc/materialize/issues/21934
-    if units == mz_expr::WindowFrameUnits::Range
-        && (start_bound != UnboundedPreceding || end_bound != CurrentRow)
-    {
-        bail_unsupported!("RANGE in non-default window frames")
+    if units == mz_expr::WindowFrameUnits::Groups && col_orders.is_empty() {
+        sql_bail!("GROUPS mode requires an ORDER BY clause")
     }
 
     let frame = mz_expr::WindowFrame {
@@ -5422,28 +6776,116 @@ fn plan_window_frame(
     Ok(frame)
 }
 
-fn window_frame_unit_ast_to_expr(
-    unit: &WindowFrameUnits,
-) -> Result<mz_expr::WindowFrameUnits, PlanError> {
+fn window_frame_unit_ast_to_expr(unit: &WindowFrameUnits) -> mz_expr::WindowFrameUnits {
     match unit {
-        WindowFrameUnits::Rows => Ok(mz_expr::WindowFrameUnits::Rows),
-        WindowFrameUnits::Range => Ok(mz_expr::WindowFrameUnits::Range),
-        WindowFrameUnits::Groups => bail_unsupported!("GROUPS in window frames"),
+        WindowFrameUnits::Rows => mz_expr::WindowFrameUnits::Rows,
+        WindowFrameUnits::Range => mz_expr::WindowFrameUnits::Range,
+        WindowFrameUnits::Groups => mz_expr::WindowFrameUnits::Groups,
     }
 }
 
-fn window_frame_bound_ast_to_expr(bound: &WindowFrameBound) -> mz_expr::WindowFrameBound {
-    match bound {
-        WindowFrameBound::CurrentRow => mz_expr::WindowFrameBound::CurrentRow,
-        WindowFrameBound::Preceding(None) => mz_expr::WindowFrameBound::UnboundedPreceding,
-        WindowFrameBound::Preceding(Some(offset)) => {
-            mz_expr::WindowFrameBound::OffsetPreceding(*offset)
+/// Plans a window frame bound. In ROWS and GROUPS mode, offsets count rows or peer groups,
+/// respectively. In RANGE mode, offsets are added to or subtracted from the value of the ORDER BY
+/// expression, so their type depends on the type of that expression.
+fn window_frame_bound_ast_to_expr(
+    ecx: &ExprContext,
+    units: &mz_expr::WindowFrameUnits,
+    bound: &WindowFrameBound<Aug>,
+    which: &str,
+    order_by_exprs: &[HirScalarExpr],
+    col_orders: &[ColumnOrder],
+) -> Result<mz_expr::WindowFrameBound, PlanError> {
+    let (offset, preceding) = match bound {
+        WindowFrameBound::CurrentRow => return Ok(mz_expr::WindowFrameBound::CurrentRow),
+        WindowFrameBound::Preceding(None) => {
+            return Ok(mz_expr::WindowFrameBound::UnboundedPreceding)
+        }
+        WindowFrameBound::Following(None) => {
+            return Ok(mz_expr::WindowFrameBound::UnboundedFollowing)
+        }
+        WindowFrameBound::Preceding(Some(offset)) => (offset, true),
+        WindowFrameBound::Following(Some(offset)) => (offset, false),
+    };
+
+    let offset_type = match units {
+        mz_expr::WindowFrameUnits::Rows | mz_expr::WindowFrameUnits::Groups => ScalarType::Int64,
+        mz_expr::WindowFrameUnits::Range => {
+            if col_orders.len() != 1 {
+                sql_bail!(
+                    "RANGE with offset PRECEDING/FOLLOWING requires exactly one ORDER BY column"
+                );
+            }
+            match ecx.scalar_type(&order_by_exprs[col_orders[0].column]) {
+                ScalarType::Int16 | ScalarType::Int32 | ScalarType::Int64 => ScalarType::Int64,
+                ScalarType::Float32 | ScalarType::Float64 => ScalarType::Float64,
+                ScalarType::Numeric { .. } => ScalarType::Numeric { max_scale: None },
+                ScalarType::Date
+                | ScalarType::Timestamp { .. }
+                | ScalarType::TimestampTz { .. }
+                | ScalarType::Interval => ScalarType::Interval,
+                typ => sql_bail!(
+                    "RANGE with offset PRECEDING/FOLLOWING is not supported for column type {}",
+                    ecx.humanize_scalar_type(&typ)
+                ),
+            }
+        }
+    };
+
+    // The offset must be a constant.
+    let offset_ecx = &ExprContext {
+        qcx: ecx.qcx,
+        name: "window frame offset",
+        scope: &Scope::empty(),
+        relation_type: &RelationType::empty(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let offset =
+        plan_expr(offset_ecx, offset)?.cast_to(offset_ecx, CastContext::Implicit, &offset_type)?;
+    if !offset.is_constant() {
+        sql_bail!("window frame offset must be a constant");
+    }
+    let arena = RowArena::new();
+    let offset = offset.lower_uncorrelated()?.eval(&[], &arena)?;
+
+    Ok(match (units, offset) {
+        (_, Datum::Null) => sql_bail!("frame {} offset must not be null", which),
+        (
+            mz_expr::WindowFrameUnits::Rows | mz_expr::WindowFrameUnits::Groups,
+            Datum::Int64(offset),
+        ) => {
+            if offset < 0 {
+                sql_bail!("frame {} offset must not be negative", which);
+            }
+            let offset = offset.unsigned_abs();
+            if preceding {
+                mz_expr::WindowFrameBound::OffsetPreceding(offset)
+            } else {
+                mz_expr::WindowFrameBound::OffsetFollowing(offset)
+            }
         }
-        WindowFrameBound::Following(None) => mz_expr::WindowFrameBound::UnboundedFollowing,
-        WindowFrameBound::Following(Some(offset)) => {
-            mz_expr::WindowFrameBound::OffsetFollowing(*offset)
+        (mz_expr::WindowFrameUnits::Range, offset) => {
+            let invalid = match offset {
+                Datum::Int64(i) => i < 0,
+                Datum::Float64(f) => f.is_nan() || f.into_inner() < 0.0,
+                Datum::Numeric(n) => n.0.is_nan() || (n.0.is_negative() && !n.0.is_zero()),
+                Datum::Interval(i) => i < Interval::default(),
+                _ => unreachable!("offset was cast to {:?}", offset_type),
+            };
+            if invalid {
+                sql_bail!("invalid preceding or following size in window function");
+            }
+            let offset = Row::pack_slice(&[offset]);
+            if preceding {
+                mz_expr::WindowFrameBound::ValuePreceding(offset)
+            } else {
+                mz_expr::WindowFrameBound::ValueFollowing(offset)
+            }
         }
-    }
+        (_, offset) => unreachable!("offset was cast to {:?}: {:?}", offset_type, offset),
+    })
 }
 
 pub fn scalar_type_from_sql(
@@ -5485,6 +6927,20 @@ pub fn scalar_type_from_sql(
     }
 }
 
+/// Returns the ID of the domain that `data_type` names, if it names a domain.
+pub fn domain_from_sql(scx: &StatementContext, data_type: &ResolvedDataType) -> Option<GlobalId> {
+    match data_type {
+        ResolvedDataType::Named { id, .. } => match scx.catalog.get_item(id).type_details() {
+            Some(CatalogTypeDetails {
+                typ: CatalogType::Domain { .. },
+                ..
+            }) => Some(*id),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub fn scalar_type_from_catalog(
     catalog: &dyn SessionCatalog,
     id: GlobalId,
@@ -5645,6 +7101,17 @@ pub fn scalar_type_from_catalog(
                         custom_id: Some(id),
                     })
                 }
+                CatalogType::Enum { .. } => Ok(ScalarType::Enum {
+                    custom_id: id,
+                    custom_oid: entry.oid(),
+                }),
+                // Values of a domain are represented as values of its base
+                // type; its constraints are enforced when casting to it.
+                CatalogType::Domain {
+                    base_reference,
+                    base_modifiers,
+                    ..
+                } => scalar_type_from_catalog(catalog, *base_reference, base_modifiers),
                 CatalogType::AclItem => Ok(ScalarType::AclItem),
                 CatalogType::Bool => Ok(ScalarType::Bool),
                 CatalogType::Bytes => Ok(ScalarType::Bytes),
@@ -5675,6 +7142,12 @@ pub fn scalar_type_from_catalog(
                 CatalogType::String => Ok(ScalarType::String),
                 CatalogType::Time => Ok(ScalarType::Time),
                 CatalogType::Uuid => Ok(ScalarType::Uuid),
+                CatalogType::Inet => Ok(ScalarType::Inet),
+                CatalogType::Cidr => Ok(ScalarType::Cidr),
+                CatalogType::TsVector => Ok(ScalarType::TsVector),
+                CatalogType::TsQuery => Ok(ScalarType::TsQuery),
+                CatalogType::JsonPath => Ok(ScalarType::JsonPath),
+                CatalogType::Hll => Ok(ScalarType::Hll),
                 CatalogType::Int2Vector => Ok(ScalarType::Int2Vector),
                 CatalogType::MzAclItem => Ok(ScalarType::MzAclItem),
                 CatalogType::Numeric => unreachable!("handled above"),
@@ -5972,6 +7445,9 @@ pub struct QueryContext<'a> {
     pub outer_relation_types: Vec<RelationType>,
     /// CTEs for this query, mapping their assigned LocalIds to their definition.
     pub ctes: BTreeMap<LocalId, CteDesc>,
+    /// The named parameters in scope, mapping their names to their positions.
+    /// Only the bodies of user-defined functions have named parameters.
+    pub param_names: BTreeMap<ColumnName, usize>,
     pub recursion_guard: RecursionGuard,
 }
 
@@ -5989,6 +7465,7 @@ impl<'a> QueryContext<'a> {
             outer_scopes: vec![],
             outer_relation_types: vec![],
             ctes: BTreeMap::new(),
+            param_names: BTreeMap::new(),
             recursion_guard: RecursionGuard::with_limit(1024), // chosen arbitrarily
         }
     }
@@ -6012,6 +7489,7 @@ impl<'a> QueryContext<'a> {
             outer_scopes,
             outer_relation_types,
             ctes,
+            param_names: self.param_names.clone(),
             recursion_guard: self.recursion_guard.clone(),
         }
     }
@@ -6041,7 +7519,12 @@ impl<'a> QueryContext<'a> {
                     typ: desc.typ().clone(),
                 };
 
-                let scope = Scope::from_source(Some(name), desc.iter_names().cloned());
+                let mut scope = Scope::from_source(Some(name), desc.iter_names().cloned());
+                if let Some(collations) = item.table_collations() {
+                    for (scope_item, collation) in scope.items.iter_mut().zip(collations) {
+                        scope_item.collation = *collation;
+                    }
+                }
 
                 Ok((expr, scope))
             }
@@ -45,6 +45,7 @@ use std::collections::BTreeSet;
 use std::iter;
 
 use mz_ore::iter::IteratorExt;
+use mz_repr::adt::collation::Collation;
 use mz_repr::ColumnName;
 
 use crate::ast::Expr;
@@ -92,6 +93,9 @@ pub struct ScopeItem {
     /// `*` expansion should yield a single `NULL` instead of a record with various
     /// datums.
     pub is_exists_column_for_a_table_function_that_was_in_the_target_list: bool,
+    /// The collation with which the column's strings are compared, if they are
+    /// not to be compared bytewise.
+    pub collation: Option<Collation>,
     // Force use of the constructor methods.
     _private: (),
 }
@@ -132,6 +136,7 @@ impl ScopeItem {
             allow_unqualified_references: true,
             lateral_error_if_referenced: false,
             is_exists_column_for_a_table_function_that_was_in_the_target_list: false,
+            collation: None,
             _private: (),
         }
     }
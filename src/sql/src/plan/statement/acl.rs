@@ -585,6 +585,7 @@ fn privilege_to_acl_mode(privilege: Privilege) -> AclMode {
         Privilege::INSERT => AclMode::INSERT,
         Privilege::UPDATE => AclMode::UPDATE,
         Privilege::DELETE => AclMode::DELETE,
+        Privilege::EXECUTE => AclMode::EXECUTE,
         Privilege::USAGE => AclMode::USAGE,
         Privilege::CREATE => AclMode::CREATE,
         Privilege::CREATEROLE => AclMode::CREATE_ROLE,
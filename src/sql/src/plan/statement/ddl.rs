@@ -23,34 +23,41 @@ use mz_expr::refresh_schedule::{RefreshEvery, RefreshSchedule};
 use mz_expr::{CollectionPlan, UnmaterializableFunc};
 use mz_interchange::avro::{AvroSchemaGenerator, AvroSchemaOptions, DocTarget};
 use mz_ore::cast::{CastFrom, TryCastFrom};
-use mz_ore::collections::HashSet;
+use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::soft_panic_or_log;
 use mz_ore::str::StrExt;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::adt::pg_legacy_name::NAME_MAX_BYTES;
 use mz_repr::adt::system::Oid;
 use mz_repr::role_id::RoleId;
-use mz_repr::{strconv, ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType};
+use mz_repr::{
+    strconv, ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, Row, ScalarType,
+};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
     AlterClusterAction, AlterClusterStatement, AlterConnectionAction, AlterConnectionOption,
     AlterConnectionOptionName, AlterRoleOption, AlterRoleStatement, AlterSetClusterStatement,
     AlterSinkStatement, AlterSourceAction, AlterSourceAddSubsourceOption,
     AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterSystemResetAllStatement,
-    AlterSystemResetStatement, AlterSystemSetStatement, CommentObjectType, CommentStatement,
+    AlterSystemResetStatement, AlterSystemSetStatement, AlterTableAction, AlterTableStatement,
+    AlterTypeAction, AlterTypeStatement, CommentObjectType, CommentStatement,
     CreateConnectionOption, CreateConnectionOptionName, CreateConnectionType, CreateTypeListOption,
     CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName, DeferredItemName,
     DocOnIdentifier, DocOnSchema, DropOwnedStatement, KafkaSinkConfigOption,
-    MaterializedViewOption, MaterializedViewOptionName, RefreshAtOptionValue,
+    MaterializedViewOption, MaterializedViewOptionName, Raw, RefreshAtOptionValue,
     RefreshEveryOptionValue, RefreshOptionValue, SetRoleVar, UnresolvedItemName,
     UnresolvedObjectName, UnresolvedSchemaName, Value,
 };
 use mz_sql_parser::ident;
 use mz_storage_types::connections::inline::{ConnectionAccess, ReferencedConnection};
 use mz_storage_types::connections::Connection;
+use mz_storage_types::controller::StoredColumn;
 use mz_storage_types::sinks::{
-    KafkaIdStyle, KafkaSinkConnection, KafkaSinkFormat, SinkEnvelope, StorageSinkConnection,
+    IcebergSinkConnection, KafkaIdStyle, KafkaSinkConnection, KafkaSinkFormat,
+    PostgresSinkConnection, PostgresTableName, SinkEnvelope, StorageSinkConnection,
+    WebhookSinkConnection,
 };
 use mz_storage_types::sources::encoding::{
     included_column_desc, AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, DataEncodingInner,
@@ -76,27 +83,30 @@ use crate::ast::display::AstDisplay;
 use crate::ast::{
     AlterConnectionStatement, AlterIndexAction, AlterIndexStatement, AlterObjectRenameStatement,
     AlterObjectSwapStatement, AlterSecretStatement, AvroSchema, AvroSchemaOption,
-    AvroSchemaOptionName, ClusterOption, ClusterOptionName, ColumnOption,
+    AvroSchemaOptionName, ClusterOption, ClusterOptionName, ColumnOption, ColumnOptionDef,
     CreateClusterReplicaStatement, CreateClusterStatement, CreateConnectionStatement,
-    CreateDatabaseStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateRoleStatement, CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection,
-    CreateSinkOption, CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection,
-    CreateSourceFormat, CreateSourceOption, CreateSourceOptionName, CreateSourceStatement,
-    CreateSubsourceOption, CreateSubsourceOptionName, CreateSubsourceStatement,
-    CreateTableStatement, CreateTypeAs, CreateTypeStatement, CreateViewStatement,
-    CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
-    CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DbzMode,
-    DropObjectsStatement, Envelope, Expr, Format, Ident, IfExistsBehavior, IndexOption,
-    IndexOptionName, KeyConstraint, LoadGeneratorOption, LoadGeneratorOptionName,
-    MySqlConfigOption, MySqlConfigOptionName, PgConfigOption, PgConfigOptionName, ProtobufSchema,
+    CreateDatabaseStatement, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreateRoleStatement, CreateSchemaStatement,
+    CreateSecretStatement, CreateSinkConnection, CreateSinkOption, CreateSinkOptionName,
+    CreateSinkStatement, CreateSourceConnection, CreateSourceFormat, CreateSourceOption,
+    CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
+    CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableStatement, CreateTypeAs,
+    CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement, CsrConfigOption,
+    CsrConfigOptionName, CsrConnection, CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf,
+    CsvColumns, DbzMode, DropObjectsStatement, Envelope, Expr, Format, FunctionParameter,
+    FunctionReturnType, FunctionTableColumn, IcebergSinkConfigOption, IcebergSinkConfigOptionName,
+    Ident, IfExistsBehavior, IndexOption, IndexOptionName, KeyConstraint, LoadGeneratorOption,
+    LoadGeneratorOptionName, MySqlConfigOption, MySqlConfigOptionName, PgConfigOption,
+    PgConfigOptionName, PostgresSinkConfigOption, PostgresSinkConfigOptionName, ProtobufSchema,
     QualifiedReplica, ReferencedSubsources, ReplicaDefinition, ReplicaOption, ReplicaOptionName,
     RoleAttribute, SourceIncludeMetadata, Statement, TableConstraint, UnresolvedDatabaseName,
-    ViewDefinition,
+    ViewDefinition, WebhookSinkConfigOption, WebhookSinkConfigOptionName,
 };
 use crate::catalog::{
     CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
     CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, SystemObjectType,
 };
+use crate::func::{UserFunc, UserFuncReturnType};
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
     Aug, CommentObjectId, DatabaseId, ObjectId, PartialItemName, QualifiedItemName,
@@ -110,23 +120,24 @@ use crate::plan::query::{plan_expr, scalar_type_from_catalog, ExprContext, Query
 use crate::plan::scope::Scope;
 use crate::plan::statement::ddl::connection::{INALTERABLE_OPTIONS, MUTUALLY_EXCLUSIVE_SETS};
 use crate::plan::statement::{scl, StatementContext, StatementDesc};
-use crate::plan::typeconv::{plan_cast, CastContext};
-use crate::plan::with_options::{OptionalDuration, TryFromValue};
+use crate::plan::typeconv::{self, plan_cast, CastContext};
+use crate::plan::with_options::{self, OptionalDuration, TryFromValue};
 use crate::plan::{
     plan_utils, query, transform_ast, AlterClusterPlan, AlterClusterRenamePlan,
     AlterClusterReplicaRenamePlan, AlterClusterSwapPlan, AlterConnectionPlan,
     AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan, AlterNoopPlan,
     AlterOptionParameter, AlterRolePlan, AlterSchemaRenamePlan, AlterSchemaSwapPlan,
     AlterSecretPlan, AlterSetClusterPlan, AlterSourcePlan, AlterSystemResetAllPlan,
-    AlterSystemResetPlan, AlterSystemSetPlan, CommentPlan, CompactionWindow, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, CreateClusterManagedPlan, CreateClusterPlan,
-    CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
-    CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
-    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
-    CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan,
-    DropOwnedPlan, FullItemName, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan,
-    PlanClusterOption, PlanContext, PlanNotice, QueryContext, ReplicaConfig, Secret, Sink, Source,
-    Table, Type, VariableValue, View, WebhookHeaderFilters, WebhookHeaders, WebhookValidation,
+    AlterSystemResetPlan, AlterSystemSetPlan, AlterTablePlan, AlterTypePlan, CommentPlan,
+    CompactionWindow, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig,
+    CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
+    CreateClusterUnmanagedPlan, CreateClusterVariant, CreateConnectionPlan, CreateDatabasePlan,
+    CreateFunctionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan,
+    CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan, FullItemName,
+    Function, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan, PlanClusterOption,
+    PlanContext, PlanNotice, QueryContext, ReplicaConfig, Secret, Sink, Source, Table, Type,
+    VariableValue, View, WebhookHeaderFilters, WebhookHeaders, WebhookValidation,
 };
 use crate::session::vars;
 use crate::session::vars::ENABLE_REFRESH_EVERY_MVS;
@@ -233,12 +244,21 @@ pub fn plan_create_table(
     // and NOT NULL constraints.
     let mut column_types = Vec::with_capacity(columns.len());
     let mut defaults = Vec::with_capacity(columns.len());
+    let mut domains = Vec::with_capacity(columns.len());
+    let mut collations = Vec::with_capacity(columns.len());
     let mut keys = Vec::new();
+    let mut unique_keys = Vec::new();
 
     for (i, c) in columns.into_iter().enumerate() {
         let aug_data_type = &c.data_type;
         let ty = query::scalar_type_from_sql(scx, aug_data_type)?;
+        domains.push(query::domain_from_sql(scx, aug_data_type));
+        collations.push(match &c.collation {
+            Some(collation) => query::plan_column_collation(scx, &ty, collation)?,
+            None => None,
+        });
         let mut nullable = true;
+        let mut unique = false;
         let mut default = Expr::null();
         for option in &c.options {
             match &option.option {
@@ -252,7 +272,7 @@ pub fn plan_create_table(
                     default = expr.clone();
                 }
                 ColumnOption::Unique { is_primary } => {
-                    keys.push(vec![i]);
+                    unique = true;
                     if *is_primary {
                         nullable = false;
                     }
@@ -262,12 +282,20 @@ pub fn plan_create_table(
                 }
             }
         }
+        if unique {
+            // As for table constraints, a unique column is only a key if it is
+            // `NOT NULL`, as otherwise its `NULL`s are distinct.
+            if !nullable {
+                keys.push(vec![i]);
+            }
+            unique_keys.push(vec![i]);
+        }
         column_types.push(ty.nullable(nullable));
         defaults.push(default);
     }
 
     let mut seen_primary = false;
-    'c: for constraint in constraints {
+    for constraint in constraints {
         match constraint {
             TableConstraint::Unique {
                 name: _,
@@ -284,6 +312,7 @@ pub fn plan_create_table(
                 seen_primary = *is_primary || seen_primary;
 
                 let mut key = vec![];
+                let mut is_key = true;
                 for column in columns {
                     let column = normalize::column_name(column.clone());
                     match names.iter().position(|name| *name == column) {
@@ -301,7 +330,7 @@ pub fn plan_create_table(
                             } else if !(*nulls_not_distinct || !*nullable) {
                                 // Non-primary key unique constraints are only keys if all of their
                                 // columns are `NOT NULL` or the constraint is `NULLS NOT DISTINCT`.
-                                break 'c;
+                                is_key = false;
                             }
 
                             key.push(i);
@@ -309,11 +338,14 @@ pub fn plan_create_table(
                     }
                 }
 
-                if *is_primary {
-                    keys.insert(0, key);
-                } else {
-                    keys.push(key);
+                if is_key {
+                    if *is_primary {
+                        keys.insert(0, key.clone());
+                    } else {
+                        keys.push(key.clone());
+                    }
                 }
+                unique_keys.push(key);
             }
             TableConstraint::ForeignKey { .. } => {
                 // Foreign key constraints are not presently enforced. We allow
@@ -328,9 +360,9 @@ pub fn plan_create_table(
         }
     }
 
-    if !keys.is_empty() {
-        // Unique constraints are not presently enforced. We allow them with feature flags for
-        // sqllogictest's sake.
+    if !unique_keys.is_empty() {
+        // Unique constraints are enforced, but we only allow them with feature
+        // flags for now.
         scx.require_feature_flag(&vars::ENABLE_TABLE_KEYS)?
     }
 
@@ -365,6 +397,9 @@ pub fn plan_create_table(
         create_sql,
         desc,
         defaults,
+        domains,
+        collations,
+        unique_keys,
         temporary,
     };
     Ok(Plan::CreateTable(CreateTablePlan {
@@ -408,7 +443,12 @@ generate_extracted_config!(
     PgConfigOption,
     (Details, String),
     (Publication, String),
-    (TextColumns, Vec::<UnresolvedItemName>, Default(vec![]))
+    (TextColumns, Vec::<UnresolvedItemName>, Default(vec![])),
+    (
+        EnumColumns,
+        Vec::<(UnresolvedItemName, ResolvedDataType)>,
+        Default(vec![])
+    )
 );
 
 generate_extracted_config!(MySqlConfigOption, (Details, String));
@@ -756,6 +796,7 @@ pub fn plan_create_source(
                 details,
                 publication,
                 text_columns,
+                enum_columns,
                 seen: _,
             } = options.clone().try_into()?;
 
@@ -808,6 +849,26 @@ pub fn plan_create_source(
                     .insert(col);
             }
 
+            let mut enum_cols: BTreeMap<Oid, BTreeMap<String, ScalarType>> = BTreeMap::new();
+
+            // Look up the referenced enum_columns in the publication_catalog.
+            for (name, data_type) in enum_columns {
+                let (qual, col) = match name.0.split_last().expect("must have at least one element")
+                {
+                    (col, qual) => (UnresolvedItemName(qual.to_vec()), col.as_str().to_string()),
+                };
+
+                let (_name, table_desc) = publication_catalog
+                    .resolve(qual)
+                    .expect("known to exist from purification");
+
+                let scalar_type = query::scalar_type_from_sql(scx, &data_type)?;
+                enum_cols
+                    .entry(Oid(table_desc.oid))
+                    .or_default()
+                    .insert(col, scalar_type);
+            }
+
             // Register the available subsources
             let mut available_subsources = BTreeMap::new();
 
@@ -850,53 +911,67 @@ pub fn plan_create_source(
                 // column and casts it to the appropriate target type
                 let mut column_casts = vec![];
                 for (i, column) in table.columns.iter().enumerate() {
-                    let ty = match text_cols.get(&Oid(table.oid)) {
-                        // Treat the column as text if it was referenced in
-                        // `TEXT COLUMNS`. This is the only place we need to
-                        // perform this logic; even if the type is unsupported,
-                        // we'll be able to ingest its values as text in
-                        // storage.
-                        Some(names) if names.contains(&column.name) => mz_pgrepr::Type::Text,
-                        _ => {
-                            match mz_pgrepr::Type::from_oid_and_typmod(
-                                column.type_oid,
-                                column.type_mod,
-                            ) {
-                                Ok(t) => t,
-                                // If this reference survived purification, we
-                                // do not expect it to be from a table that the
-                                // user will consume., i.e. expect this table to
-                                // be filtered out of table casts.
-                                Err(_) => {
-                                    column_casts.push(
-                                        HirScalarExpr::CallVariadic {
-                                            func: mz_expr::VariadicFunc::ErrorIfNull,
-                                            exprs: vec![
-                                                HirScalarExpr::literal_null(ScalarType::String),
-                                                HirScalarExpr::literal(
-                                                    mz_repr::Datum::from(
-                                                        format!(
-                                                            "Unsupported type with OID {}",
-                                                            column.type_oid
-                                                        )
-                                                        .as_str(),
-                                                    ),
-                                                    ScalarType::String,
-                                                ),
-                                            ],
+                    let scalar_type = match enum_cols
+                        .get(&Oid(table.oid))
+                        .and_then(|cols| cols.get(&column.name))
+                    {
+                        // Columns of upstream enum types are ingested as the
+                        // enum type that purification paired them with.
+                        Some(scalar_type) => scalar_type.clone(),
+                        None => {
+                            let ty = match text_cols.get(&Oid(table.oid)) {
+                                // Treat the column as text if it was referenced in
+                                // `TEXT COLUMNS`. This is the only place we need to
+                                // perform this logic; even if the type is unsupported,
+                                // we'll be able to ingest its values as text in
+                                // storage.
+                                Some(names) if names.contains(&column.name) => {
+                                    mz_pgrepr::Type::Text
+                                }
+                                _ => {
+                                    match mz_pgrepr::Type::from_oid_and_typmod(
+                                        column.type_oid,
+                                        column.type_mod,
+                                    ) {
+                                        Ok(t) => t,
+                                        // If this reference survived purification, we
+                                        // do not expect it to be from a table that the
+                                        // user will consume., i.e. expect this table to
+                                        // be filtered out of table casts.
+                                        Err(_) => {
+                                            column_casts.push(
+                                                HirScalarExpr::CallVariadic {
+                                                    func: mz_expr::VariadicFunc::ErrorIfNull,
+                                                    exprs: vec![
+                                                        HirScalarExpr::literal_null(
+                                                            ScalarType::String,
+                                                        ),
+                                                        HirScalarExpr::literal(
+                                                            mz_repr::Datum::from(
+                                                                format!(
+                                                                    "Unsupported type with OID {}",
+                                                                    column.type_oid
+                                                                )
+                                                                .as_str(),
+                                                            ),
+                                                            ScalarType::String,
+                                                        ),
+                                                    ],
+                                                }
+                                                .lower_uncorrelated()
+                                                .expect("no correlation"),
+                                            );
+                                            continue;
                                         }
-                                        .lower_uncorrelated()
-                                        .expect("no correlation"),
-                                    );
-                                    continue;
+                                    }
                                 }
-                            }
+                            };
+
+                            let data_type = scx.resolve_type(ty)?;
+                            query::scalar_type_from_sql(scx, &data_type)?
                         }
                     };
 
-                    let data_type = scx.resolve_type(ty)?;
-                    let scalar_type = query::scalar_type_from_sql(scx, &data_type)?;
-
                     let col_expr = HirScalarExpr::Column(ColumnRef {
                         level: 0,
                         column: i,
@@ -2480,7 +2555,10 @@ pub fn plan_create_sink(
     let from = scx.get_item_by_resolved_name(&from)?;
     let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
     let key_indices = match &connection {
-        CreateSinkConnection::Kafka { key, .. } => {
+        CreateSinkConnection::Kafka { key, .. }
+        | CreateSinkConnection::Postgres { key, .. }
+        | CreateSinkConnection::Webhook { key, .. }
+        | CreateSinkConnection::Iceberg { key, .. } => {
             if let Some(key) = key.clone() {
                 let key_columns = key
                     .key_columns
@@ -2576,6 +2654,37 @@ pub fn plan_create_sink(
             envelope,
             from.id(),
         )?,
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            ..
+        } => postgres_sink_builder(
+            scx,
+            connection,
+            options,
+            format,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+            envelope,
+        )?,
+        CreateSinkConnection::Webhook { options, .. } => webhook_sink_builder(
+            scx,
+            options,
+            format,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+        )?,
+        CreateSinkConnection::Iceberg { options, .. } => iceberg_sink_builder(
+            scx,
+            options,
+            format,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+            envelope,
+        )?,
     };
 
     let CreateSinkOptionExtracted {
@@ -2858,6 +2967,28 @@ fn kafka_sink_builder(
             }
         }
         Some(Format::Json) => KafkaSinkFormat::Json,
+        Some(Format::Text) => {
+            validate_single_column_sink_format(
+                scx,
+                "TEXT",
+                envelope,
+                key_desc_and_indices.as_ref(),
+                &value_desc,
+                None,
+            )?;
+            KafkaSinkFormat::Text
+        }
+        Some(Format::Bytes) => {
+            validate_single_column_sink_format(
+                scx,
+                "BYTES",
+                envelope,
+                key_desc_and_indices.as_ref(),
+                &value_desc,
+                Some(&ScalarType::Bytes),
+            )?;
+            KafkaSinkFormat::Bytes
+        }
         Some(format) => bail_unsupported!(format!("sink format {:?}", format)),
         None => bail_unsupported!("sink without format"),
     };
@@ -2876,6 +3007,282 @@ fn kafka_sink_builder(
     }))
 }
 
+/// Validates that the key, if any, and the value of a sink with a `FORMAT`
+/// that writes a single column as is each consist of exactly one non-nullable
+/// column, of type `required_type` if given.
+///
+/// The key columns are written to the message key, so they are not considered
+/// part of the value.
+fn validate_single_column_sink_format(
+    scx: &StatementContext,
+    format: &str,
+    envelope: SinkEnvelope,
+    key_desc_and_indices: Option<&(RelationDesc, Vec<usize>)>,
+    value_desc: &RelationDesc,
+    required_type: Option<&ScalarType>,
+) -> Result<(), PlanError> {
+    // Debezium values wrap each row in a record with `before` and `after`
+    // fields, which has no single-column representation.
+    if envelope == SinkEnvelope::Debezium {
+        sql_bail!("FORMAT {format} is only supported with ENVELOPE UPSERT");
+    }
+
+    let key_indices = key_desc_and_indices.map_or(&[][..], |(_desc, indices)| indices);
+    let key_columns =
+        key_desc_and_indices.map(|(desc, _indices)| ("key", desc.iter().collect::<Vec<_>>()));
+    let value_columns = value_desc
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !key_indices.contains(i))
+        .map(|(_i, column)| column)
+        .collect::<Vec<_>>();
+    let parts = key_columns
+        .into_iter()
+        .chain(iter::once(("value", value_columns)));
+    for (part, columns) in parts {
+        let [(name, typ)] = columns[..] else {
+            sql_bail!(
+                "FORMAT {format} requires the sink {part} to have exactly one column, found {}",
+                columns.len()
+            );
+        };
+        if typ.nullable {
+            sql_bail!(
+                "FORMAT {format} requires the sink {part} column {} to be non-nullable",
+                name.as_str().quoted()
+            );
+        }
+        if let Some(required_type) = required_type {
+            if !typ.scalar_type.base_eq(required_type) {
+                sql_bail!(
+                    "FORMAT {format} requires the sink {part} column {} to be of type {}, found {}",
+                    name.as_str().quoted(),
+                    scx.humanize_scalar_type(required_type),
+                    scx.humanize_scalar_type(&typ.scalar_type)
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+generate_extracted_config!(
+    PostgresSinkConfigOption,
+    (Table, UnresolvedItemName),
+    (ProgressTable, UnresolvedItemName)
+);
+
+/// The name of the progress table used by `POSTGRES` sinks that do not specify
+/// a `PROGRESS TABLE`. It is created in the schema of the sink's table.
+const DEFAULT_POSTGRES_SINK_PROGRESS_TABLE: &str = "mz_sink_progress";
+
+fn postgres_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<PostgresSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+    envelope: SinkEnvelope,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_POSTGRES_SINK)?;
+
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let connection_id = connection_item.id();
+    match connection_item.connection()? {
+        Connection::Postgres(_) => (),
+        _ => sql_bail!(
+            "{} is not a postgres connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+
+    let PostgresSinkConfigOptionExtracted {
+        table,
+        progress_table,
+        seen: _,
+    } = options.try_into()?;
+
+    if format.is_some() {
+        sql_bail!("POSTGRES sinks do not support FORMAT");
+    }
+
+    // Each key's latest value is applied to the upstream table, so only the
+    // upsert envelope makes sense.
+    match envelope {
+        SinkEnvelope::Upsert => (),
+        SinkEnvelope::Debezium => bail_unsupported!("ENVELOPE DEBEZIUM for POSTGRES sinks"),
+    }
+
+    let table = table.ok_or_else(|| sql_err!("POSTGRES CONNECTION must specify TABLE"))?;
+    let table = postgres_sink_table_name(table)?;
+    let progress_table = match progress_table {
+        Some(progress_table) => postgres_sink_table_name(progress_table)?,
+        None => PostgresTableName {
+            schema: table.schema.clone(),
+            name: DEFAULT_POSTGRES_SINK_PROGRESS_TABLE.into(),
+        },
+    };
+    if progress_table == table {
+        sql_bail!("POSTGRES sink PROGRESS TABLE must differ from TABLE");
+    }
+
+    Ok(StorageSinkConnection::Postgres(PostgresSinkConnection {
+        connection_id,
+        connection: connection_id,
+        table,
+        progress_table,
+        relation_key_indices,
+        key_desc_and_indices,
+        value_desc,
+    }))
+}
+
+/// Converts a possibly schema-qualified table name into a [`PostgresTableName`],
+/// defaulting to the `public` schema.
+pub(crate) fn postgres_sink_table_name(
+    name: UnresolvedItemName,
+) -> Result<PostgresTableName, PlanError> {
+    let mut parts = name.0.into_iter().map(normalize::ident);
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(name), None, None) => Ok(PostgresTableName {
+            schema: "public".into(),
+            name,
+        }),
+        (Some(schema), Some(name), None) => Ok(PostgresTableName { schema, name }),
+        _ => sql_bail!("POSTGRES sink table names must be of the form [schema.]table"),
+    }
+}
+
+generate_extracted_config!(
+    WebhookSinkConfigOption,
+    (Url, String),
+    (Secret, with_options::Secret)
+);
+
+fn webhook_sink_builder(
+    scx: &StatementContext,
+    options: Vec<WebhookSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_WEBHOOK_SINK)?;
+
+    let WebhookSinkConfigOptionExtracted {
+        url,
+        secret,
+        seen: _,
+    } = options.try_into()?;
+
+    // Request bodies are always JSON.
+    match format {
+        None | Some(Format::Json) => (),
+        Some(_) => sql_bail!("WEBHOOK sinks only support FORMAT JSON"),
+    }
+
+    let url = url.ok_or_else(|| sql_err!("WEBHOOK sink must specify URL"))?;
+    let parsed: reqwest::Url = url
+        .parse()
+        .map_err(|e| sql_err!("parsing WEBHOOK sink URL: {e}"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        sql_bail!("WEBHOOK sink URL must use the http or https scheme");
+    }
+
+    Ok(StorageSinkConnection::Webhook(WebhookSinkConnection {
+        url,
+        signing_secret: secret.map(|secret| secret.into()),
+        relation_key_indices,
+        key_desc_and_indices,
+        value_desc,
+        progress_shard: None,
+    }))
+}
+
+generate_extracted_config!(
+    IcebergSinkConfigOption,
+    (AwsConnection, with_options::Object),
+    (Path, String),
+    (CommitInterval, Duration, Default(Duration::from_secs(10)))
+);
+
+fn iceberg_sink_builder(
+    scx: &StatementContext,
+    options: Vec<IcebergSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+    envelope: SinkEnvelope,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ICEBERG_SINK)?;
+
+    let IcebergSinkConfigOptionExtracted {
+        aws_connection,
+        path,
+        commit_interval,
+        seen: _,
+    } = options.try_into()?;
+
+    // Data files are always Parquet.
+    if format.is_some() {
+        sql_bail!("ICEBERG sinks do not support FORMAT");
+    }
+
+    // Updated keys are removed with equality deletes and their latest value
+    // is written as a new row, so only the upsert envelope makes sense.
+    match envelope {
+        SinkEnvelope::Upsert => (),
+        SinkEnvelope::Debezium => bail_unsupported!("ENVELOPE DEBEZIUM for ICEBERG sinks"),
+    }
+
+    let Some(aws_connection_id) = aws_connection.map(GlobalId::from) else {
+        sql_bail!("ICEBERG sink must specify AWS CONNECTION");
+    };
+    let connection_item = scx.get_item(&aws_connection_id);
+    match connection_item.connection()? {
+        Connection::Aws(_) => (),
+        _ => sql_bail!(
+            "{} is not an AWS CONNECTION",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    }
+
+    let path = path.ok_or_else(|| sql_err!("ICEBERG sink must specify PATH"))?;
+    let parsed: reqwest::Url = path
+        .parse()
+        .map_err(|e| sql_err!("parsing ICEBERG sink PATH: {e}"))?;
+    if parsed.scheme() != "s3" || parsed.host_str().map_or(true, |bucket| bucket.is_empty()) {
+        sql_bail!("ICEBERG sink PATH must be an s3://<bucket>/<prefix> URI");
+    }
+    if commit_interval.is_zero() {
+        sql_bail!("ICEBERG sink COMMIT INTERVAL must be greater than zero");
+    }
+
+    for (name, typ) in value_desc.iter() {
+        if IcebergSinkConnection::iceberg_type(&typ.scalar_type).is_none() {
+            sql_bail!(
+                "ICEBERG sinks do not support column {} of type {}",
+                name.as_str().quoted(),
+                scx.humanize_scalar_type(&typ.scalar_type)
+            );
+        }
+    }
+
+    Ok(StorageSinkConnection::Iceberg(IcebergSinkConnection {
+        aws_connection_id,
+        aws_connection: aws_connection_id,
+        path,
+        commit_interval,
+        relation_key_indices,
+        key_desc_and_indices,
+        value_desc,
+        progress_shard: None,
+    }))
+}
+
 pub fn describe_create_index(
     _: &StatementContext,
     _: CreateIndexStatement<Aug>,
@@ -3124,6 +3531,43 @@ pub fn plan_create_type(
             }
             CatalogType::Record { fields }
         }
+        CreateTypeAs::Enum { labels } => {
+            scx.require_feature_flag(&vars::ENABLE_ENUM_TYPES)?;
+            let mut seen = BTreeSet::new();
+            for label in &labels {
+                validate_enum_label(label)?;
+                if !seen.insert(label) {
+                    sql_bail!("enum label {} specified more than once", label.quoted());
+                }
+            }
+            CatalogType::Enum { labels }
+        }
+        CreateTypeAs::Domain {
+            data_type,
+            not_null,
+            checks,
+        } => {
+            scx.require_feature_flag(&vars::ENABLE_DOMAIN_TYPES)?;
+            let (base_id, base_modifiers) = match data_type {
+                ResolvedDataType::Named { id, modifiers, .. } => (id, modifiers),
+                _ => sql_bail!(
+                    "CREATE DOMAIN can only use named data types, but found unnamed data type {}. \
+                        Use CREATE TYPE to create a named type first",
+                    data_type.human_readable_name(),
+                ),
+            };
+            // Validates the base type and its modifiers.
+            let base_type = scalar_type_from_catalog(scx.catalog, base_id, &base_modifiers)?;
+            for check in &checks {
+                query::plan_domain_check(scx, check, &base_type)?;
+            }
+            CatalogType::Domain {
+                base_reference: base_id,
+                base_modifiers,
+                not_null,
+                checks,
+            }
+        }
     };
 
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
@@ -3148,6 +3592,131 @@ pub fn plan_create_type(
     }))
 }
 
+pub fn describe_create_function(
+    _: &StatementContext,
+    _: CreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_function(
+    scx: &StatementContext,
+    stmt: CreateFunctionStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let create_sql = normalize::create_statement(scx, Statement::CreateFunction(stmt.clone()))?;
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(stmt.name.clone())?)?;
+
+    // Functions live in their own namespace, so a function only conflicts with
+    // another function of the same name.
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    if let Ok(item) = scx.catalog.resolve_function(&partial_name) {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+
+    let func = plan_user_func(scx, &name.item, stmt)?;
+
+    Ok(Plan::CreateFunction(CreateFunctionPlan {
+        name,
+        func: Function {
+            create_sql,
+            inner: func.into_func(),
+        },
+    }))
+}
+
+/// Plans the function named `name` defined by `stmt`, and validates that its
+/// body produces its declared result.
+fn plan_user_func(
+    scx: &StatementContext,
+    name: &str,
+    stmt: CreateFunctionStatement<Aug>,
+) -> Result<UserFunc, PlanError> {
+    let CreateFunctionStatement {
+        name: _,
+        parameters,
+        return_type,
+        mut body,
+    } = stmt;
+
+    // The types of a function's parameters and results are recorded in the
+    // catalog by name, which custom types do not yet support.
+    let plan_type = |data_type: &ResolvedDataType| {
+        let ty = query::scalar_type_from_sql(scx, data_type)?;
+        if ty.is_custom_type() {
+            bail_unsupported!("custom types in function signatures");
+        }
+        Ok::<_, PlanError>(ty)
+    };
+
+    let mut params = vec![];
+    let mut seen = BTreeSet::new();
+    for FunctionParameter { name, data_type } in &parameters {
+        let name = name
+            .as_ref()
+            .map(|name| normalize::column_name(name.clone()));
+        if let Some(name) = &name {
+            if !seen.insert(name.clone()) {
+                sql_bail!(
+                    "parameter name {} used more than once",
+                    name.as_str().quoted()
+                );
+            }
+        }
+        params.push((name, plan_type(data_type)?));
+    }
+
+    let return_type = match &return_type {
+        FunctionReturnType::Scalar(data_type) => UserFuncReturnType::Scalar(plan_type(data_type)?),
+        // Like PostgreSQL, name the column of a set-returning function that
+        // returns a single type after the function.
+        FunctionReturnType::SetOf(data_type) => {
+            UserFuncReturnType::Table(vec![(ColumnName::from(name), plan_type(data_type)?)])
+        }
+        FunctionReturnType::Table(columns) => {
+            let mut seen = BTreeSet::new();
+            let mut out = vec![];
+            for FunctionTableColumn { name, data_type } in columns {
+                let name = normalize::column_name(name.clone());
+                if !seen.insert(name.clone()) {
+                    sql_bail!(
+                        "column name {} specified more than once",
+                        name.as_str().quoted()
+                    );
+                }
+                out.push((name, plan_type(data_type)?));
+            }
+            UserFuncReturnType::Table(out)
+        }
+    };
+
+    // Desugar the body once, rather than every time the function is called.
+    transform_ast::transform(scx, &mut body)?;
+
+    let func = UserFunc {
+        params,
+        return_type,
+        body,
+    };
+    func.validate(scx)?;
+    Ok(func)
+}
+
+/// Validates that `label` is usable as the label of an enum type.
+fn validate_enum_label(label: &str) -> Result<(), PlanError> {
+    // PostgreSQL stores enum labels as `name`s, so they are subject to the
+    // same length limit.
+    if label.len() > NAME_MAX_BYTES {
+        return Err(PlanError::InvalidEnumLabel {
+            label: label.to_string(),
+        });
+    }
+    Ok(())
+}
+
 generate_extracted_config!(CreateTypeListOption, (ElementType, ResolvedDataType));
 
 generate_extracted_config!(
@@ -3564,6 +4133,9 @@ pub fn plan_create_cluster_replica(
         .catalog
         .resolve_cluster(Some(&normalize::ident(of_cluster)))?;
     let current_replica_count = cluster.replica_ids().iter().count();
+    if contains_iceberg_sinks(scx, cluster) && current_replica_count > 0 {
+        sql_bail!("{}", ICEBERG_SINK_REPLICAS_ERROR);
+    }
     if contains_storage_objects(scx, cluster) && current_replica_count > 0 {
         let internal_replica_count = cluster.replicas().iter().filter(|r| r.internal()).count();
         return Err(PlanError::CreateReplicaFailStorageObjects {
@@ -3730,11 +4302,6 @@ pub fn plan_drop_objects(
         cascade,
     }: DropObjectsStatement,
 ) -> Result<Plan, PlanError> {
-    assert_ne!(
-        object_type,
-        mz_sql_parser::ast::ObjectType::Func,
-        "rejected in parser"
-    );
     let object_type = object_type.into();
 
     let mut referenced_ids = Vec::new();
@@ -3869,6 +4436,31 @@ fn contains_storage_objects(scx: &StatementContext, cluster: &dyn CatalogCluster
     })
 }
 
+/// The error returned when adding a replica to a cluster that runs an ICEBERG sink.
+const ICEBERG_SINK_REPLICAS_ERROR: &str =
+    "cannot create more than one replica of a cluster containing ICEBERG sinks";
+
+/// Reports whether `cluster` runs an ICEBERG sink.
+///
+/// ICEBERG sinks commit new metadata versions to an object store, which
+/// cannot reject a version that another replica has already written.
+fn contains_iceberg_sinks(scx: &StatementContext, cluster: &dyn CatalogCluster) -> bool {
+    cluster.bound_objects().iter().any(|id| {
+        let item = scx.catalog.get_item(id);
+        item.item_type() == CatalogItemType::Sink
+            && matches!(
+                crate::parse::parse(item.create_sql())
+                    .expect("create_sql is valid")
+                    .into_element()
+                    .ast,
+                Statement::CreateSink(CreateSinkStatement {
+                    connection: CreateSinkConnection::Iceberg { .. },
+                    ..
+                })
+            )
+    })
+}
+
 fn plan_drop_cluster_replica(
     scx: &StatementContext,
     if_exists: bool,
@@ -4430,6 +5022,9 @@ pub fn plan_alter_cluster(
 
                         // Total number of replicas running is internal replicas
                         // + replication factor.
+                        if contains_iceberg_sinks(scx, cluster) && hypothetical_replica_count > 1 {
+                            sql_bail!("{}", ICEBERG_SINK_REPLICAS_ERROR);
+                        }
                         if contains_storage_objects(scx, cluster) && hypothetical_replica_count > 1
                         {
                             return Err(PlanError::CreateReplicaFailStorageObjects {
@@ -4997,6 +5592,465 @@ pub fn plan_alter_secret(
     Ok(Plan::AlterSecret(AlterSecretPlan { id, secret_as }))
 }
 
+pub fn describe_alter_type(
+    _: &StatementContext,
+    _: AlterTypeStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_type(
+    scx: &mut StatementContext,
+    stmt: AlterTypeStatement,
+) -> Result<Plan, PlanError> {
+    let AlterTypeStatement {
+        name,
+        if_exists,
+        action,
+    } = stmt;
+    let name = normalize::unresolved_item_name(name)?;
+    let entry = match scx.catalog.resolve_type(&name) {
+        Ok(typ) => typ,
+        Err(_) if if_exists => {
+            scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+                name: name.to_string(),
+                object_type: ObjectType::Type,
+            });
+
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Type,
+            }));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let full_name = scx.catalog.resolve_full_name(entry.name());
+    let Some(CatalogTypeDetails {
+        typ: CatalogType::Enum { labels },
+        ..
+    }) = entry.type_details()
+    else {
+        sql_bail!("{} is not an enum", full_name.to_string().quoted());
+    };
+    let id = entry.id();
+
+    match action {
+        AlterTypeAction::AddValue {
+            if_not_exists,
+            label,
+        } => {
+            validate_enum_label(&label)?;
+            if labels.contains(&label) {
+                if if_not_exists {
+                    scx.catalog.add_notice(PlanNotice::EnumLabelAlreadyExists {
+                        label,
+                        type_name: full_name.to_string(),
+                    });
+                    return Ok(Plan::AlterNoop(AlterNoopPlan {
+                        object_type: ObjectType::Type,
+                    }));
+                }
+                sql_bail!("enum label {} already exists", label.quoted());
+            }
+
+            // Existing values of the type are stored by label position, so
+            // new labels are only ever appended, and values carry their label,
+            // so items that depend on the type keep working as is. Sources,
+            // however, decode upstream values with the labels the type had
+            // when they were created.
+            let mut to_visit = entry.referenced_by().to_vec();
+            let mut seen = BTreeSet::new();
+            while let Some(dep_id) = to_visit.pop() {
+                if !seen.insert(dep_id) {
+                    continue;
+                }
+                let dep = scx.catalog.get_item(&dep_id);
+                if dep.item_type() == CatalogItemType::Source {
+                    sql_bail!(
+                        "cannot add value to enum {}: source {} depends on it",
+                        full_name.to_string().quoted(),
+                        scx.catalog
+                            .resolve_full_name(dep.name())
+                            .to_string()
+                            .quoted(),
+                    );
+                }
+                to_visit.extend(dep.referenced_by());
+            }
+
+            let create_sql = match crate::parse::parse(entry.create_sql())
+                .expect("create_sql is valid")
+                .into_element()
+                .ast
+            {
+                Statement::CreateType(mut stmt) => {
+                    match &mut stmt.as_type {
+                        CreateTypeAs::Enum { labels } => labels.push(label),
+                        _ => unreachable!("enum type must be created as an enum"),
+                    }
+                    stmt.to_ast_string_stable()
+                }
+                _ => unreachable!("type must be created by CREATE TYPE"),
+            };
+
+            Ok(Plan::AlterType(AlterTypePlan { id, create_sql }))
+        }
+    }
+}
+
+pub fn describe_alter_table(
+    _: &StatementContext,
+    _: AlterTableStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table(
+    scx: &mut StatementContext,
+    stmt: AlterTableStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let AlterTableStatement {
+        name,
+        if_exists,
+        action,
+    } = stmt;
+    let Some(entry) = resolve_item_or_type(scx, ObjectType::Table, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string(),
+            object_type: ObjectType::Table,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan {
+            object_type: ObjectType::Table,
+        }));
+    };
+    let full_name = scx.catalog.resolve_full_name(entry.name());
+    if entry.item_type() != CatalogItemType::Table {
+        sql_bail!("\"{}\" is a {} not a table", full_name, entry.item_type());
+    }
+    if entry.id().is_system() {
+        sql_bail!(
+            "cannot alter table {} because it is required by the database system",
+            full_name.to_string().quoted()
+        );
+    }
+    if entry.name().qualifiers.schema_spec == SchemaSpecifier::Temporary {
+        bail_unsupported!("ALTER TABLE on temporary tables");
+    }
+    let id = entry.id();
+    let desc = entry.desc(&full_name)?.into_owned();
+    let mut layout = entry
+        .table_layout()
+        .expect("user tables have a layout")
+        .clone();
+
+    let create_stmt = crate::parse::parse(entry.create_sql())
+        .expect("create_sql is valid")
+        .into_element()
+        .ast;
+    let mut create_stmt = match crate::names::resolve(scx.catalog, create_stmt)?.0 {
+        Statement::CreateTable(stmt) => stmt,
+        _ => unreachable!("table must be created by CREATE TABLE"),
+    };
+    // The table's own name is already taken by the table.
+    create_stmt.if_not_exists = true;
+
+    let (dropped, columns) = match action {
+        AlterTableAction::AddColumn {
+            if_not_exists,
+            column,
+        } => {
+            let column_name = normalize::column_name(column.name.clone());
+            // Dropped columns remain in the table's shard under a reserved
+            // name, which cannot be reused.
+            let exists = layout
+                .columns
+                .iter()
+                .any(|stored| stored.name == column_name);
+            if exists {
+                if if_not_exists {
+                    scx.catalog.add_notice(PlanNotice::ColumnAlreadyExists {
+                        column: column_name.to_string(),
+                        table_name: full_name.to_string(),
+                    });
+                    return Ok(Plan::AlterNoop(AlterNoopPlan {
+                        object_type: ObjectType::Table,
+                    }));
+                }
+                sql_bail!(
+                    "column {} of relation {} already exists",
+                    column_name.as_str().quoted(),
+                    full_name.to_string().quoted()
+                );
+            }
+            for option in &column.options {
+                match &option.option {
+                    ColumnOption::NotNull | ColumnOption::Default(_) => {}
+                    other => bail_unsupported!(format!(
+                        "ALTER TABLE ... ADD COLUMN with column constraint: {}",
+                        other
+                    )),
+                }
+            }
+            create_stmt.columns.push(column);
+
+            // Items that depend on the table continue to observe only the
+            // table's current columns.
+            let columns: Vec<_> = desc.iter_names().cloned().collect();
+            (None, columns)
+        }
+        AlterTableAction::DropColumn {
+            if_exists,
+            name: column_name,
+        } => {
+            let column_name = normalize::column_name(column_name);
+            let Some((column, _)) = desc.get_by_name(&column_name) else {
+                if if_exists {
+                    scx.catalog.add_notice(PlanNotice::ColumnDoesNotExist {
+                        column: column_name.to_string(),
+                        table_name: full_name.to_string(),
+                    });
+                    return Ok(Plan::AlterNoop(AlterNoopPlan {
+                        object_type: ObjectType::Table,
+                    }));
+                }
+                sql_bail!(
+                    "column {} of relation {} does not exist",
+                    column_name.as_str().quoted(),
+                    full_name.to_string().quoted()
+                );
+            };
+
+            drop_column(&mut create_stmt, &desc, &column_name);
+            let columns: Vec<_> = desc
+                .iter_names()
+                .filter(|name| **name != column_name)
+                .cloned()
+                .collect();
+            (Some(column), columns)
+        }
+    };
+
+    let Plan::CreateTable(CreateTablePlan { table, .. }) = plan_create_table(scx, create_stmt)?
+    else {
+        unreachable!("CREATE TABLE must be planned as a table");
+    };
+
+    let verb = match dropped {
+        None => "add column to".to_string(),
+        Some(column) => format!("drop column {} of", desc.get_name(column).as_str().quoted()),
+    };
+    let columns: Vec<_> = columns
+        .iter()
+        .map(|name| Ident::new_unchecked(name.as_str()))
+        .collect();
+    let mut dependents = vec![];
+    let mut indexes = vec![];
+    for dep_id in entry.referenced_by() {
+        let dep = scx.catalog.get_item(dep_id);
+        let create_sql = match dep.item_type() {
+            // Indexes on the table are rebuilt, which is only possible for
+            // indexes that do not use a dropped column.
+            CatalogItemType::Index => {
+                let (keys, _) = dep.index_details().expect("index has details");
+                let uses_column = dropped.map_or(false, |column| {
+                    keys.iter().any(|key| key.support().contains(&column))
+                });
+                if !uses_column {
+                    indexes.push(*dep_id);
+                    continue;
+                }
+                None
+            }
+            CatalogItemType::View | CatalogItemType::MaterializedView | CatalogItemType::Func => {
+                project_dependent(scx, dep, id, &columns)?
+            }
+            _ => None,
+        };
+        let Some(create_sql) = create_sql else {
+            sql_bail!(
+                "cannot {} table {} because {} {} depends on it",
+                verb,
+                full_name.to_string().quoted(),
+                dep.item_type(),
+                scx.catalog
+                    .resolve_full_name(dep.name())
+                    .to_string()
+                    .quoted(),
+            );
+        };
+        dependents.push((*dep_id, create_sql));
+    }
+
+    let action = match dropped {
+        None => {
+            let (name, typ) = table.desc.iter().last().expect("table has the new column");
+            let default = table.defaults.last().expect("table has the new column");
+            let domain = *table.domains.last().expect("table has the new column");
+            // Rows that predate the column have the column's default value.
+            let fill = plan_column_fill(scx, default, &typ.scalar_type, domain)?;
+            layout.columns.push(StoredColumn {
+                name: name.clone(),
+                typ: typ.clone(),
+                dropped: false,
+                fill: fill.clone(),
+            });
+            crate::plan::AlterTableAction::AddColumn {
+                name: name.clone(),
+                typ: typ.clone(),
+                fill,
+            }
+        }
+        Some(column) => {
+            let (position, stored) = layout
+                .columns
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, stored)| !stored.dropped)
+                .nth(column)
+                .expect("column is stored");
+            stored.dropped = true;
+            stored.name = ColumnName::from(format!("........mz.dropped.{}........", position + 1));
+            crate::plan::AlterTableAction::DropColumn { column }
+        }
+    };
+    Ok(Plan::AlterTable(AlterTablePlan {
+        id,
+        create_sql: table.create_sql,
+        action,
+        layout,
+        dependents,
+        indexes,
+    }))
+}
+
+/// Removes the column `column_name` from the table defined by `create_stmt`,
+/// whose current columns are described by `desc`.
+///
+/// Like PostgreSQL, this removes the constraints that refer to the column, too.
+fn drop_column(
+    create_stmt: &mut CreateTableStatement<Aug>,
+    desc: &RelationDesc,
+    column_name: &ColumnName,
+) {
+    let is_column = |ident: &Ident| normalize::column_name(ident.clone()) == *column_name;
+    // Columns that were `NOT NULL` because of a removed primary key stay
+    // `NOT NULL`, as the columns of the table's shard keep their types.
+    let mut not_null = BTreeSet::new();
+    create_stmt
+        .constraints
+        .retain(|constraint| match constraint {
+            TableConstraint::Unique {
+                columns,
+                is_primary,
+                ..
+            } => {
+                let retain = !columns.iter().any(is_column);
+                if !retain && *is_primary {
+                    not_null.extend(columns.iter().cloned().map(normalize::column_name));
+                }
+                retain
+            }
+            TableConstraint::ForeignKey { columns, .. } => !columns.iter().any(is_column),
+            TableConstraint::Check { .. } => true,
+        });
+
+    create_stmt.columns.retain(|c| !is_column(&c.name));
+    for c in &mut create_stmt.columns {
+        let name = normalize::column_name(c.name.clone());
+        let is_not_null = desc
+            .get_by_name(&name)
+            .map_or(false, |(_, typ)| !typ.nullable);
+        let has_not_null = c
+            .options
+            .iter()
+            .any(|option| matches!(option.option, ColumnOption::NotNull));
+        if not_null.contains(&name) && is_not_null && !has_not_null {
+            c.options.insert(
+                0,
+                ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::NotNull,
+                },
+            );
+        }
+    }
+}
+
+/// Returns the value of a column added to a table, whose default is
+/// `default`, in the rows that predate the column, if not `NULL`.
+fn plan_column_fill(
+    scx: &StatementContext,
+    default: &Expr<Aug>,
+    ty: &ScalarType,
+    domain: Option<GlobalId>,
+) -> Result<Option<Row>, PlanError> {
+    // The default is evaluated once, when the column is added.
+    let mut default = default.clone();
+    transform_ast::transform(scx, &mut default)?;
+    let mut default = query::plan_default_expr(scx, &default, ty)?;
+    if let Some(domain) = domain {
+        default = typeconv::plan_domain_constraints(scx, default, domain)?;
+    }
+    let mut default = default.lower_uncorrelated()?;
+    default.reduce(&[]);
+    let fill = match default.as_literal_owned() {
+        Some(fill) => fill?,
+        None => bail_unsupported!("ALTER TABLE ... ADD COLUMN with a non-constant DEFAULT"),
+    };
+    if fill.unpack_first().is_null() {
+        Ok(None)
+    } else {
+        Ok(Some(fill))
+    }
+}
+
+/// Rewrites the definition of `dep`, an item that depends on the table `id`,
+/// to observe only `columns` of the table.
+///
+/// Returns `None` if the rewritten definition is not valid, or does not
+/// produce the same columns as the original definition.
+fn project_dependent(
+    scx: &StatementContext,
+    dep: &dyn CatalogItem,
+    id: GlobalId,
+    columns: &[Ident],
+) -> Result<Option<String>, PlanError> {
+    let create_stmt = crate::parse::parse(dep.create_sql())
+        .expect("create_sql is valid")
+        .into_element()
+        .ast;
+    let mut projected = create_stmt.clone();
+    crate::ast::transform::create_stmt_project_table(&mut projected, id, columns);
+
+    let plan_desc = |stmt: Statement<Raw>| -> Result<Option<RelationDesc>, PlanError> {
+        let (query, lifetime) = match stmt {
+            Statement::CreateView(stmt) => (stmt.definition.query, QueryLifetime::View),
+            Statement::CreateMaterializedView(stmt) => {
+                (stmt.query, QueryLifetime::MaterializedView)
+            }
+            // A function's body must produce the function's declared result.
+            Statement::CreateFunction(stmt) => {
+                let stmt = crate::names::resolve(scx.catalog, stmt)?.0;
+                let name = normalize::unresolved_item_name(stmt.name.clone())?;
+                plan_user_func(scx, &name.item, stmt)?;
+                return Ok(None);
+            }
+            _ => unreachable!("only views, materialized views and functions can be projected"),
+        };
+        let (query, _) = crate::names::resolve(scx.catalog, query)?;
+        Ok(Some(query::plan_root_query(scx, query, lifetime)?.desc))
+    };
+    let desc = plan_desc(create_stmt)?;
+    match plan_desc(projected.clone()) {
+        Ok(projected_desc)
+            if projected_desc.as_ref().map(|desc| &desc.typ().column_types)
+                == desc.as_ref().map(|desc| &desc.typ().column_types) =>
+        {
+            Ok(Some(projected.to_ast_string_stable()))
+        }
+        _ => Ok(None),
+    }
+}
+
 pub fn describe_alter_connection(
     _: &StatementContext,
     _: AlterConnectionStatement<Aug>,
@@ -5230,7 +6284,12 @@ pub fn describe_alter_source(
 
 generate_extracted_config!(
     AlterSourceAddSubsourceOption,
-    (TextColumns, Vec::<UnresolvedItemName>, Default(vec![]))
+    (TextColumns, Vec::<UnresolvedItemName>, Default(vec![])),
+    (
+        EnumColumns,
+        Vec::<(UnresolvedItemName, ResolvedDataType)>,
+        Default(vec![])
+    )
 );
 
 pub fn plan_alter_source(
@@ -5670,6 +6729,7 @@ pub(crate) fn resolve_item_or_type<'a>(
     let name = normalize::unresolved_item_name(name)?;
     let catalog_item = match object_type {
         ObjectType::Type => scx.catalog.resolve_type(&name),
+        ObjectType::Func => scx.catalog.resolve_function(&name),
         _ => scx.catalog.resolve_item(&name),
     };
     match catalog_item {
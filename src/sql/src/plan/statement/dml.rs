@@ -15,25 +15,29 @@
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 
+use bytesize::ByteSize;
 use itertools::Itertools;
-use mz_expr::MirRelationExpr;
+use mz_expr::{MirRelationExpr, RowSetFinishing};
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
-use mz_repr::{RelationDesc, ScalarType};
+use mz_repr::{GlobalId, RelationDesc, ScalarType};
 use mz_sql_parser::ast::{
     ExplainSinkSchemaFor, ExplainSinkSchemaStatement, ExplainTimestampStatement, Expr,
     IfExistsBehavior, OrderByExpr, SubscribeOutput, UnresolvedItemName,
 };
 use mz_sql_parser::ident;
-use mz_storage_types::sinks::{KafkaSinkConnection, KafkaSinkFormat, StorageSinkConnection};
+use mz_storage_types::connections::Connection;
+use mz_storage_types::sinks::{
+    KafkaSinkConnection, KafkaSinkFormat, S3SinkFormat, StorageSinkConnection,
+};
 
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
-    DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement, Query,
-    SelectStatement, SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement,
-    UpdateStatement,
+    DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement,
+    MergeStatement, Query, SelectStatement, SubscribeOption, SubscribeOptionName,
+    SubscribeRelation, SubscribeStatement, UpdateStatement,
 };
 use crate::catalog::CatalogItemType;
 use crate::names::{Aug, ResolvedItemName};
@@ -41,13 +45,14 @@ use crate::normalize;
 use crate::plan::query::{plan_up_to, ExprContext, QueryLifetime};
 use crate::plan::scope::Scope;
 use crate::plan::statement::{ddl, StatementContext, StatementDesc};
-use crate::plan::with_options::TryFromValue;
+use crate::plan::with_options::{self, TryFromValue};
 use crate::plan::{
     self, side_effecting_func, CreateSinkPlan, ExplainSinkSchemaPlan, ExplainTimestampPlan,
 };
 use crate::plan::{
-    query, CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MutationKind, Params, Plan,
-    PlanError, QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan,
+    query, CopyFormat, CopyFromPlan, CopyToPlan, ExplainPlanPlan, InsertPlan, MergeActionPlan,
+    MergeWhenPlan, MutationKind, OnConflictAction, OnConflictPlan, Params, Plan, PlanError,
+    QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan,
 };
 use crate::session::vars;
 
@@ -63,10 +68,12 @@ pub fn describe_insert(
         table_name,
         columns,
         source,
+        on_conflict,
         returning,
     }: InsertStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
-    let (_, _, returning) = query::plan_insert_query(scx, table_name, columns, source, returning)?;
+    let (_, _, _, returning) =
+        query::plan_insert_query(scx, table_name, columns, source, on_conflict, returning)?;
     let desc = if returning.expr.is_empty() {
         None
     } else {
@@ -81,12 +88,13 @@ pub fn plan_insert(
         table_name,
         columns,
         source,
+        on_conflict,
         returning,
     }: InsertStatement<Aug>,
     params: &Params,
 ) -> Result<Plan, PlanError> {
-    let (id, mut expr, returning) =
-        query::plan_insert_query(scx, table_name, columns, source, returning)?;
+    let (id, mut expr, on_conflict, returning) =
+        query::plan_insert_query(scx, table_name, columns, source, on_conflict, returning)?;
     expr.bind_parameters(params)?;
     let returning = returning
         .expr
@@ -94,10 +102,54 @@ pub fn plan_insert(
         .map(|expr| expr.lower_uncorrelated())
         .collect::<Result<Vec<_>, _>>()?;
 
-    Ok(Plan::Insert(InsertPlan {
+    let Some(query::InsertOnConflict { key, update }) = on_conflict else {
+        return Ok(Plan::Insert(InsertPlan {
+            id,
+            values: expr,
+            returning,
+        }));
+    };
+
+    // Resolving conflicts requires reading the existing rows that proposed
+    // rows conflict with, so `ON CONFLICT` is always a read-then-write.
+    let action = match update {
+        None => OnConflictAction::DoNothing,
+        Some((assignments, selection)) => {
+            let mut assignments_outer = BTreeMap::new();
+            for (idx, mut set) in assignments {
+                set.bind_parameters(params)?;
+                let set = set.lower_uncorrelated()?;
+                assignments_outer.insert(idx, set);
+            }
+            let selection = match selection {
+                Some(mut selection) => {
+                    selection.bind_parameters(params)?;
+                    Some(selection.lower_uncorrelated()?)
+                }
+                None => None,
+            };
+            OnConflictAction::DoUpdate {
+                assignments: assignments_outer,
+                selection,
+            }
+        }
+    };
+    let finishing = RowSetFinishing {
+        order_by: vec![],
+        limit: None,
+        offset: 0,
+        project: (0..expr.arity()).collect(),
+    };
+
+    Ok(Plan::ReadThenWrite(ReadThenWritePlan {
         id,
-        values: expr,
+        selection: expr.lower(scx.catalog.system_vars())?,
+        finishing,
+        assignments: BTreeMap::new(),
+        kind: MutationKind::Insert,
         returning,
+        on_conflict: Some(OnConflictPlan { key, action }),
+        when_clauses: Vec::new(),
     }))
 }
 
@@ -115,7 +167,7 @@ pub fn plan_delete(
     params: &Params,
 ) -> Result<Plan, PlanError> {
     let rtw_plan = query::plan_delete_query(scx, stmt)?;
-    plan_read_then_write(MutationKind::Delete, scx, params, rtw_plan)
+    plan_read_then_write(MutationKind::Delete, scx, params, rtw_plan, Vec::new())
 }
 
 pub fn describe_update(
@@ -132,7 +184,71 @@ pub fn plan_update(
     params: &Params,
 ) -> Result<Plan, PlanError> {
     let rtw_plan = query::plan_update_query(scx, stmt)?;
-    plan_read_then_write(MutationKind::Update, scx, params, rtw_plan)
+    plan_read_then_write(MutationKind::Update, scx, params, rtw_plan, Vec::new())
+}
+
+pub fn describe_merge(
+    scx: &StatementContext,
+    stmt: MergeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    query::plan_merge_query(scx, stmt)?;
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_merge(
+    scx: &StatementContext,
+    stmt: MergeStatement<Aug>,
+    params: &Params,
+) -> Result<Plan, PlanError> {
+    let (rtw_plan, when_clauses) = query::plan_merge_query(scx, stmt)?;
+    let mut when_clauses_outer = Vec::with_capacity(when_clauses.len());
+    for query::MergeWhen {
+        matched,
+        condition,
+        action,
+    } in when_clauses
+    {
+        let condition = match condition {
+            Some(mut condition) => {
+                condition.bind_parameters(params)?;
+                Some(condition.lower_uncorrelated()?)
+            }
+            None => None,
+        };
+        let action = match action {
+            query::MergeWhenAction::Update(assignments) => {
+                let mut assignments_outer = BTreeMap::new();
+                for (idx, mut set) in assignments {
+                    set.bind_parameters(params)?;
+                    let set = set.lower_uncorrelated()?;
+                    assignments_outer.insert(idx, set);
+                }
+                MergeActionPlan::Update(assignments_outer)
+            }
+            query::MergeWhenAction::Delete => MergeActionPlan::Delete,
+            query::MergeWhenAction::Insert(values) => {
+                let mut values_outer = Vec::with_capacity(values.len());
+                for mut value in values {
+                    value.bind_parameters(params)?;
+                    values_outer.push(value.lower_uncorrelated()?);
+                }
+                MergeActionPlan::Insert(values_outer)
+            }
+            query::MergeWhenAction::DoNothing => MergeActionPlan::DoNothing,
+        };
+        when_clauses_outer.push(MergeWhenPlan {
+            matched,
+            condition,
+            action,
+        });
+    }
+    plan_read_then_write(
+        MutationKind::Merge,
+        scx,
+        params,
+        rtw_plan,
+        when_clauses_outer,
+    )
 }
 
 pub fn plan_read_then_write(
@@ -145,6 +261,7 @@ pub fn plan_read_then_write(
         finishing,
         assignments,
     }: query::ReadThenWritePlan,
+    when_clauses: Vec<MergeWhenPlan>,
 ) -> Result<Plan, PlanError> {
     selection.bind_parameters(params)?;
     let selection = selection.lower(scx.catalog.system_vars())?;
@@ -162,6 +279,8 @@ pub fn plan_read_then_write(
         assignments: assignments_outer,
         kind,
         returning: Vec::new(),
+        on_conflict: None,
+        when_clauses,
     }))
 }
 
@@ -751,8 +870,14 @@ pub fn describe_table(
 
 pub fn describe_copy(
     scx: &StatementContext,
-    CopyStatement { relation, .. }: CopyStatement<Aug>,
+    CopyStatement {
+        relation, target, ..
+    }: CopyStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
+    // `COPY ... TO <expr>` does not return any rows to the client.
+    if let CopyTarget::Expr(_) = target {
+        return Ok(StatementDesc::new(None));
+    }
     Ok(match relation {
         CopyRelation::Table { name, columns } => describe_table(scx, name, columns)?,
         CopyRelation::Select(stmt) => describe_select(scx, stmt)?,
@@ -843,9 +968,95 @@ generate_extracted_config!(
     (Null, String),
     (Escape, String),
     (Quote, String),
-    (Header, bool)
+    (Header, bool),
+    (AwsConnection, with_options::Object),
+    (MaxFileSize, ByteSize)
 );
 
+/// The default maximum size in bytes of the files written by `COPY ... TO <expr>`.
+const DEFAULT_COPY_TO_MAX_FILE_SIZE: u64 = 256 * 1024 * 1024;
+
+fn plan_copy_to_expr(
+    scx: &StatementContext,
+    select: SelectStatement<Aug>,
+    to: Expr<Aug>,
+    options: CopyOptionExtracted,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_COPY_TO_EXPR)?;
+
+    let query::PlannedRootQuery {
+        expr,
+        desc,
+        finishing,
+        ..
+    } = plan_query(scx, select.query, &Params::empty(), QueryLifetime::OneShot)?;
+    // The rows are spread across several files, so ordering them is meaningless. Limits and
+    // offsets, however, would change which rows are copied.
+    if finishing.limit.is_some() || finishing.offset > 0 {
+        sql_bail!("COPY ... TO <expr> does not support LIMIT or OFFSET");
+    }
+    let select_plan = SelectPlan {
+        source: expr,
+        when: query::plan_as_of(scx, select.as_of)?,
+        finishing,
+        copy_to: None,
+    };
+
+    if options.delimiter.is_some() || options.null.is_some() {
+        sql_bail!("COPY ... TO <expr> does not support DELIMITER or NULL");
+    }
+    if options.quote.is_some() || options.escape.is_some() {
+        sql_bail!("COPY ... TO <expr> does not support QUOTE or ESCAPE");
+    }
+
+    let format = match options.format.to_lowercase().as_str() {
+        "csv" => S3SinkFormat::Csv {
+            header: options.header.unwrap_or(false),
+        },
+        "parquet" => {
+            if options.header.is_some() {
+                sql_bail!("COPY HEADER available only in CSV mode");
+            }
+            S3SinkFormat::Parquet
+        }
+        _ => sql_bail!(
+            "COPY ... TO <expr> only supports FORMAT CSV and FORMAT PARQUET, got {}",
+            options.format
+        ),
+    };
+
+    let Some(connection_id) = options.aws_connection.map(GlobalId::from) else {
+        sql_bail!("COPY ... TO <expr> requires an AWS CONNECTION");
+    };
+    let connection_item = scx.get_item(&connection_id);
+    let connection = match connection_item.connection()? {
+        Connection::Aws(connection) => connection.clone(),
+        _ => sql_bail!(
+            "{} is not an AWS CONNECTION",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+
+    let max_file_size = options
+        .max_file_size
+        .map_or(DEFAULT_COPY_TO_MAX_FILE_SIZE, |size| size.as_u64());
+    if max_file_size == 0 {
+        sql_bail!("MAX FILE SIZE must be greater than 0");
+    }
+
+    let to = query::plan_copy_to_expr(scx, to)?;
+
+    Ok(Plan::CopyTo(CopyToPlan {
+        select_plan,
+        desc,
+        to,
+        connection,
+        connection_id,
+        format,
+        max_file_size,
+    }))
+}
+
 pub fn plan_copy(
     scx: &StatementContext,
     CopyStatement {
@@ -856,6 +1067,15 @@ pub fn plan_copy(
     }: CopyStatement<Aug>,
 ) -> Result<Plan, PlanError> {
     let options = CopyOptionExtracted::try_from(options)?;
+    if let CopyTarget::Expr(to) = target {
+        let CopyRelation::Select(stmt) = relation else {
+            sql_bail!("COPY ... TO <expr> only supports SELECT queries");
+        };
+        return plan_copy_to_expr(scx, stmt, to, options);
+    }
+    if options.aws_connection.is_some() || options.max_file_size.is_some() {
+        sql_bail!("AWS CONNECTION and MAX FILE SIZE are only supported for COPY ... TO <expr>");
+    }
     let format = match options.format.to_lowercase().as_str() {
         "text" => CopyFormat::Text,
         "csv" => CopyFormat::Csv,
@@ -19,8 +19,8 @@ use mz_ore::collections::CollectionExt;
 use mz_repr::{Datum, GlobalId, RelationDesc, Row, ScalarType};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
-    ObjectType, ShowCreateConnectionStatement, ShowCreateMaterializedViewStatement, ShowObjectType,
-    SystemObjectType,
+    ObjectType, ShowCreateConnectionStatement, ShowCreateFunctionStatement,
+    ShowCreateMaterializedViewStatement, ShowObjectType, SystemObjectType,
 };
 use query::QueryContext;
 
@@ -105,7 +105,7 @@ fn plan_show_create(
     if item.id().is_system()
         && matches!(
             expect_type,
-            CatalogItemType::Table | CatalogItemType::Source
+            CatalogItemType::Table | CatalogItemType::Source | CatalogItemType::Func
         )
     {
         sql_bail!("cannot show create for system object {name}");
@@ -203,6 +203,24 @@ pub fn plan_show_create_connection(
     plan_show_create(scx, &connection_name, CatalogItemType::Connection)
 }
 
+pub fn describe_show_create_function(
+    _: &StatementContext,
+    _: ShowCreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(Some(
+        RelationDesc::empty()
+            .with_column("name", ScalarType::String.nullable(false))
+            .with_column("create_sql", ScalarType::String.nullable(false)),
+    )))
+}
+
+pub fn plan_show_create_function(
+    scx: &StatementContext,
+    ShowCreateFunctionStatement { function_name }: ShowCreateFunctionStatement<Aug>,
+) -> Result<ShowCreatePlan, PlanError> {
+    plan_show_create(scx, &function_name, CatalogItemType::Func)
+}
+
 pub fn show_databases<'a>(
     scx: &'a StatementContext<'a>,
     filter: Option<ShowStatementFilter<Aug>>,
@@ -17,8 +17,8 @@ use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_repr::namespaces::{MZ_CATALOG_SCHEMA, MZ_UNSAFE_SCHEMA, PG_CATALOG_SCHEMA};
 use mz_sql_parser::ast::visit_mut::{self, VisitMut, VisitMutNode};
 use mz_sql_parser::ast::{
-    Expr, Function, FunctionArgs, Ident, Op, OrderByExpr, Query, Select, SelectItem, TableAlias,
-    TableFactor, TableWithJoins, Value, WindowSpec,
+    Expr, Function, FunctionArgs, HomogenizingFunction, Ident, Op, OrderByExpr, Query, Select,
+    SelectItem, TableAlias, TableFactor, TableWithJoins, Value, WindowSpec,
 };
 use mz_sql_parser::ident;
 use uuid::Uuid;
@@ -56,8 +56,9 @@ where
 //     has the nice side effect of reusing the division planning logic, which
 //     is not trivial for some types, like decimals.
 //
-//   * Rewrites the suite of standard deviation and variance functions in a
-//     manner similar to `avg`.
+//   * Rewrites `approx_count_distinct(col)` to
+//     `coalesce(hll_estimate(hll_sketch(col)), 0)`, so that the maintained
+//     state is a mergeable sketch rather than the set of distinct values.
 //
 // TODO(sploiselle): rewrite these in terms of func::sql_op!
 struct FuncRewriter<'a> {
@@ -203,84 +204,34 @@ impl<'a> FuncRewriter<'a> {
         Self::plan_divide(sum, count)
     }
 
-    fn plan_variance(
+    fn plan_approx_count_distinct(
         &mut self,
         expr: Expr<Aug>,
         filter: Option<Box<Expr<Aug>>>,
         distinct: bool,
-        sample: bool,
         over: Option<WindowSpec<Aug>>,
     ) -> Expr<Aug> {
-        // N.B. this variance calculation uses the "textbook" algorithm, which
-        // is known to accumulate problematic amounts of error. The numerically
-        // stable variants, the most well-known of which is Welford's, are
-        // however difficult to implement inside of Differential Dataflow, as
-        // they do not obviously support retractions efficiently (#1240).
-        //
-        // The code below converts var_samp(x) into
-        //
-        //     (sum(x²) - sum(x)² / count(x)) / (count(x) - 1)
-        //
-        // and var_pop(x) into:
-        //
-        //     (sum(x²) - sum(x)² / count(x)) / count(x)
-        //
-        let expr = expr.call_unary(
-            self.scx
-                .dangerous_resolve_name(vec![MZ_UNSAFE_SCHEMA, "mz_avg_promotion"]),
-        );
-        let expr_squared = expr.clone().multiply(expr.clone());
-        let sum_squares = self.plan_agg(
-            self.scx
-                .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "sum"]),
-            expr_squared,
-            vec![],
-            filter.clone(),
-            distinct,
-            over.clone(),
-        );
-        let sum = self.plan_agg(
-            self.scx
-                .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "sum"]),
-            expr.clone(),
-            vec![],
-            filter.clone(),
-            distinct,
-            over.clone(),
-        );
-        let sum_squared = sum.clone().multiply(sum);
-        let count = self.plan_agg(
+        // Sketches are insensitive to duplicates, so `distinct` does not
+        // change the result, but we pass it along for consistency with the
+        // other rewrites.
+        let sketch = self.plan_agg(
             self.scx
-                .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "count"]),
+                .dangerous_resolve_name(vec![MZ_CATALOG_SCHEMA, "hll_sketch"]),
             expr,
             vec![],
             filter,
             distinct,
             over,
         );
-        Self::plan_divide(
-            sum_squares.minus(Self::plan_divide(sum_squared, count.clone())),
-            if sample {
-                count.minus(Expr::number("1"))
-            } else {
-                count
-            },
-        )
-    }
-
-    fn plan_stddev(
-        &mut self,
-        expr: Expr<Aug>,
-        filter: Option<Box<Expr<Aug>>>,
-        distinct: bool,
-        sample: bool,
-        over: Option<WindowSpec<Aug>>,
-    ) -> Expr<Aug> {
-        self.plan_variance(expr, filter, distinct, sample, over)
-            .call_unary(
-                self.scx
-                    .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "sqrt"]),
-            )
+        let estimate = sketch.call_unary(
+            self.scx
+                .dangerous_resolve_name(vec![MZ_CATALOG_SCHEMA, "hll_estimate"]),
+        );
+        // Like `count`, return zero rather than NULL for empty inputs.
+        Expr::HomogenizingFunction {
+            function: HomogenizingFunction::Coalesce,
+            exprs: vec![estimate, Expr::number("0")],
+        }
     }
 
     fn plan_bool_and(
@@ -387,12 +338,9 @@ impl<'a> FuncRewriter<'a> {
                 match name.as_str() {
                     "avg_internal_v1" => self.plan_avg_internal_v1(arg, filter, distinct, over),
                     "avg" => self.plan_avg(arg, filter, distinct, over),
-                    "variance" | "var_samp" => {
-                        self.plan_variance(arg, filter, distinct, true, over)
+                    "approx_count_distinct" => {
+                        self.plan_approx_count_distinct(arg, filter, distinct, over)
                     }
-                    "var_pop" => self.plan_variance(arg, filter, distinct, false, over),
-                    "stddev" | "stddev_samp" => self.plan_stddev(arg, filter, distinct, true, over),
-                    "stddev_pop" => self.plan_stddev(arg, filter, distinct, false, over),
                     "bool_and" => self.plan_bool_and(arg, filter, distinct, over),
                     "bool_or" => self.plan_bool_or(arg, filter, distinct, over),
                     _ => return None,
@@ -14,15 +14,18 @@ use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 use itertools::Itertools;
+use mz_expr::visit::Visit;
 use mz_expr::{func, VariadicFunc};
-use mz_repr::{ColumnName, ColumnType, Datum, RelationType, ScalarBaseType, ScalarType};
+use mz_ore::str::StrExt;
+use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationType, ScalarBaseType, ScalarType};
 use once_cell::sync::Lazy;
 
-use crate::catalog::TypeCategory;
+use crate::catalog::{CatalogType, CatalogTypeDetails, TypeCategory};
 use crate::plan::error::PlanError;
 use crate::plan::expr::{CoercibleScalarExpr, ColumnRef, HirScalarExpr, UnaryFunc};
-use crate::plan::query::{ExprContext, QueryContext};
+use crate::plan::query::{self, ExprContext, QueryContext};
 use crate::plan::scope::Scope;
+use crate::plan::statement::StatementContext;
 
 /// Like func::sql_impl_func, but for casts.
 fn sql_impl_cast(expr: &'static str) -> CastTemplate {
@@ -400,6 +403,7 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
 
         // BYTES
         (Bytes, String) => Assignment: CastBytesToString(func::CastBytesToString),
+        (Bytes, Hll) => Explicit: CastBytesToHll(func::CastBytesToHll),
 
         // STRING
         (String, Bool) => Explicit: CastStringToBool(func::CastStringToBool),
@@ -485,6 +489,12 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         (String, Bytes) => Explicit: CastStringToBytes(func::CastStringToBytes),
         (String, Jsonb) => Explicit: CastStringToJsonb(func::CastStringToJsonb),
         (String, Uuid) => Explicit: CastStringToUuid(func::CastStringToUuid),
+        (String, Inet) => Explicit: CastStringToInet(func::CastStringToInet),
+        (String, Cidr) => Explicit: CastStringToCidr(func::CastStringToCidr),
+        (String, TsVector) => Explicit: CastStringToTsVector(func::CastStringToTsVector),
+        (String, TsQuery) => Explicit: CastStringToTsQuery(func::CastStringToTsQuery),
+        (String, JsonPath) => Explicit: CastStringToJsonPath(func::CastStringToJsonPath),
+        (String, Hll) => Explicit: CastStringToHll(func::CastStringToHll),
         (String, Array) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
             let return_ty = to_type.clone();
             let to_el_type = to_type.unwrap_array_element_type();
@@ -531,6 +541,15 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
             Some(move |e: HirScalarExpr| e.call_unary(CastStringToVarChar(func::CastStringToVarChar {length, fail_on_len: ccx != CastContext::Explicit})))
         }),
         (String, PgLegacyChar) => Assignment: CastStringToPgLegacyChar(func::CastStringToPgLegacyChar),
+        (String, Enum) => Explicit: CastTemplate::new(|ecx, _ccx, _from_type, to_type| {
+            let return_ty = to_type.clone();
+            let ScalarType::Enum { custom_id, .. } = to_type else { unreachable!() };
+            let labels = match &ecx.catalog().get_item(custom_id).type_details()?.typ {
+                CatalogType::Enum { labels } => labels.clone(),
+                _ => return None,
+            };
+            Some(|e: HirScalarExpr| e.call_unary(UnaryFunc::CastStringToEnum(func::CastStringToEnum { return_ty, labels })))
+        }),
         // CHAR
         (Char, String) => Implicit: CastCharToString(func::CastCharToString),
         (Char, Char) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
@@ -670,6 +689,27 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         // UUID
         (Uuid, String) => Assignment: CastUuidToString(func::CastUuidToString),
 
+        // INET
+        (Inet, String) => Assignment: CastInetToString(func::CastInetToString),
+        (Inet, Cidr) => Assignment: CastInetToCidr(func::CastInetToCidr),
+
+        // CIDR
+        (Cidr, String) => Assignment: CastCidrToString(func::CastCidrToString),
+        (Cidr, Inet) => Implicit: CastCidrToInet(func::CastCidrToInet),
+
+        // TSVECTOR
+        (TsVector, String) => Assignment: CastTsVectorToString(func::CastTsVectorToString),
+
+        // TSQUERY
+        (TsQuery, String) => Assignment: CastTsQueryToString(func::CastTsQueryToString),
+
+        // JSONPATH
+        (JsonPath, String) => Assignment: CastJsonPathToString(func::CastJsonPathToString),
+
+        // HLL
+        (Hll, String) => Assignment: CastHllToString(func::CastHllToString),
+        (Hll, Bytes) => Explicit: CastHllToBytes(func::CastHllToBytes),
+
         // Numeric
         (Numeric, Numeric) => Assignment: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
             let scale = to_type.unwrap_numeric_max_scale();
@@ -688,6 +728,20 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         (Numeric, UInt64) => Assignment: CastNumericToUint64(func::CastNumericToUint64),
         (Numeric, String) => Assignment: CastNumericToString(func::CastNumericToString),
 
+        // ENUM
+        (Enum, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
+            let ty = from_type.clone();
+            Some(|e: HirScalarExpr| e.call_unary(UnaryFunc::CastEnumToString(func::CastEnumToString { ty })))
+        }),
+        (Enum, Enum) => Implicit: CastTemplate::new(|_ecx, _ccx, from_type, to_type| {
+            // Distinct enum types are never convertible to one another.
+            if from_type == to_type {
+                Some(|e: HirScalarExpr| e)
+            } else {
+                None
+            }
+        }),
+
         // Range
         (Range, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
             let ty = from_type.clone();
@@ -1069,6 +1123,113 @@ pub fn plan_cast(
     }
 }
 
+/// Plans the enforcement of the constraints of the type with ID `id` on
+/// `expr`, which must already be of the type's underlying [`ScalarType`].
+///
+/// Only domains have constraints. The resulting expression produces the value
+/// of `expr`, or an evaluation error if the value violates the `NOT NULL` or a
+/// `CHECK` constraint of the domain or of any domain it is based on.
+///
+/// Constraints never change the value they are enforced on, so every
+/// constraint refers to `expr` itself rather than to the result of enforcing
+/// the constraints before it. `expr` appears once per constraint, so callers
+/// that have a relation at hand should bind the value to a column and pass a
+/// reference to that column.
+pub fn plan_domain_constraints(
+    scx: &StatementContext,
+    expr: HirScalarExpr,
+    id: GlobalId,
+) -> Result<HirScalarExpr, PlanError> {
+    let mut violations = vec![];
+    plan_domain_violations(scx, &expr, id, &mut violations)?;
+    if violations.is_empty() {
+        return Ok(expr);
+    }
+
+    let typ = query::scalar_type_from_catalog(scx.catalog, id, &[])?;
+    // Nest the checks so that the first violated constraint is reported.
+    let expr = violations
+        .into_iter()
+        .rev()
+        .fold(expr, |expr, (violated, message)| HirScalarExpr::If {
+            cond: Box::new(violated),
+            then: Box::new(HirScalarExpr::CallVariadic {
+                func: VariadicFunc::ErrorIfNull,
+                exprs: vec![
+                    HirScalarExpr::literal_null(typ.clone()),
+                    HirScalarExpr::literal(Datum::from(message.as_str()), ScalarType::String),
+                ],
+            }),
+            els: Box::new(expr),
+        });
+    Ok(expr)
+}
+
+/// Appends to `violations`, for each constraint of the type with ID `id` and
+/// of the domains it is based on, base domains first, an expression that is
+/// true if `value` violates the constraint and the message to report if it
+/// does.
+fn plan_domain_violations(
+    scx: &StatementContext,
+    value: &HirScalarExpr,
+    id: GlobalId,
+    violations: &mut Vec<(HirScalarExpr, String)>,
+) -> Result<(), PlanError> {
+    let item = scx.catalog.get_item(&id);
+    let Some(CatalogTypeDetails {
+        typ:
+            CatalogType::Domain {
+                base_reference,
+                base_modifiers,
+                not_null,
+                checks,
+            },
+        ..
+    }) = item.type_details()
+    else {
+        return Ok(());
+    };
+
+    plan_domain_violations(scx, value, *base_reference, violations)?;
+    let base_type = query::scalar_type_from_catalog(scx.catalog, *base_reference, base_modifiers)?;
+    let name = &item.name().item;
+
+    if *not_null {
+        let message = format!("domain {} does not allow null values", name);
+        violations.push((value.clone().call_is_null(), message));
+    }
+
+    for (i, check) in checks.iter().enumerate() {
+        let mut check = query::plan_domain_check(scx, check, &base_type)?;
+        // Checks cannot contain subqueries, so `VALUE` is always a reference
+        // to column 0 at level 0.
+        check.visit_mut_post(&mut |e| {
+            if let HirScalarExpr::Column(ColumnRef {
+                level: 0,
+                column: 0,
+            }) = e
+            {
+                *e = value.clone();
+            }
+        })?;
+
+        // Name the constraints as PostgreSQL does for unnamed constraints.
+        let constraint = match i {
+            0 => format!("{}_check", name),
+            i => format!("{}_check{}", name, i),
+        };
+        let message = format!(
+            "value for domain {} violates check constraint {}",
+            name,
+            constraint.quoted()
+        );
+        // As in PostgreSQL, a check that evaluates to NULL is satisfied.
+        violations.push((check.not(), message));
+    }
+
+    Ok(())
+}
+
 /// Reports whether it is possible to perform a cast from the specified types.
 pub fn can_cast(
     ecx: &ExprContext,
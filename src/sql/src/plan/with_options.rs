@@ -11,6 +11,7 @@
 
 use std::time::Duration;
 
+use bytesize::ByteSize;
 use mz_repr::adt::interval::Interval;
 use mz_repr::{strconv, GlobalId};
 use mz_sql_parser::ast::{Ident, KafkaBroker, RefreshOptionValue, ReplicaDefinition};
@@ -144,6 +145,30 @@ impl ImpliedValue for ResolvedDataType {
     }
 }
 
+impl TryFromValue<WithOptionValue<Aug>> for (UnresolvedItemName, ResolvedDataType) {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        Ok(match v {
+            WithOptionValue::Sequence(pair) => match <[_; 2]>::try_from(pair) {
+                Ok([name, ty]) => (
+                    UnresolvedItemName::try_from_value(name)?,
+                    ResolvedDataType::try_from_value(ty)?,
+                ),
+                Err(_) => sql_bail!("must provide an object name and a data type"),
+            },
+            _ => sql_bail!("must provide an object name and a data type"),
+        })
+    }
+    fn name() -> String {
+        "object name and data type".to_string()
+    }
+}
+
+impl ImpliedValue for (UnresolvedItemName, ResolvedDataType) {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide an object name and a data type")
+    }
+}
+
 impl TryFromValue<WithOptionValue<Aug>> for StringOrSecret {
     fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
         Ok(match v {
@@ -228,6 +253,26 @@ impl ImpliedValue for OptionalDuration {
     }
 }
 
+impl TryFromValue<Value> for ByteSize {
+    fn try_from_value(v: Value) -> Result<Self, PlanError> {
+        match v {
+            Value::Number(value) | Value::String(value) => value
+                .parse::<ByteSize>()
+                .map_err(|e| sql_err!("invalid bytes value: {e}")),
+            _ => sql_bail!("cannot use value as bytes"),
+        }
+    }
+    fn name() -> String {
+        "bytes".to_string()
+    }
+}
+
+impl ImpliedValue for ByteSize {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a value for bytes")
+    }
+}
+
 impl TryFromValue<Value> for String {
     fn try_from_value(v: Value) -> Result<Self, PlanError> {
         match v {
@@ -31,15 +31,16 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit::{visit_function, Visit};
 use mz_sql_parser::ast::visit_mut::{visit_expr_mut, VisitMut};
 use mz_sql_parser::ast::{
-    AlterSourceAction, AlterSourceAddSubsourceOptionName, AlterSourceStatement, AvroDocOn,
-    CreateMaterializedViewStatement, CreateSinkConnection, CreateSinkStatement,
-    CreateSubsourceOption, CreateSubsourceOptionName, CsrConfigOption, CsrConfigOptionName,
-    CsrConnection, CsrSeedAvro, CsrSeedProtobuf, CsrSeedProtobufSchema, DbzMode, DeferredItemName,
-    DocOnIdentifier, DocOnSchema, Envelope, Expr, Function, FunctionArgs, Ident,
-    KafkaSourceConfigOption, KafkaSourceConfigOptionName, MaterializedViewOption,
-    MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName, PgConfigOption,
-    PgConfigOptionName, RawItemName, ReaderSchemaSelectionStrategy, RefreshAtOptionValue,
-    RefreshEveryOptionValue, RefreshOptionValue, Statement, UnresolvedItemName,
+    AlterSourceAction, AlterSourceAddSubsourceOption, AlterSourceAddSubsourceOptionName,
+    AlterSourceStatement, AvroDocOn, CreateMaterializedViewStatement, CreateSinkConnection,
+    CreateSinkStatement, CreateSubsourceOption, CreateSubsourceOptionName, CsrConfigOption,
+    CsrConfigOptionName, CsrConnection, CsrSeedAvro, CsrSeedProtobuf, CsrSeedProtobufSchema,
+    DbzMode, DeferredItemName, DocOnIdentifier, DocOnSchema, Envelope, Expr, Function,
+    FunctionArgs, Ident, KafkaSourceConfigOption, KafkaSourceConfigOptionName,
+    MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName,
+    PgConfigOption, PgConfigOptionName, RawItemName, ReaderSchemaSelectionStrategy,
+    RefreshAtOptionValue, RefreshEveryOptionValue, RefreshOptionValue, Statement,
+    UnresolvedItemName,
 };
 use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::connections::inline::IntoInlineConnection;
@@ -72,8 +73,8 @@ use crate::{kafka_util, normalize};
 
 use self::error::{
     CsrPurificationError, KafkaSinkPurificationError, KafkaSourcePurificationError,
-    LoadGeneratorSourcePurificationError, MySqlSourcePurificationError, PgSourcePurificationError,
-    TestScriptSourcePurificationError,
+    LoadGeneratorSourcePurificationError, MySqlSourcePurificationError, PgSinkPurificationError,
+    PgSourcePurificationError, TestScriptSourcePurificationError,
 };
 
 pub(crate) mod error;
@@ -428,6 +429,85 @@ async fn purify_create_sink(
                 Err(KafkaSinkPurificationError::ZeroBrokers)?;
             }
         }
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            key: _,
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                match item.connection()? {
+                    Connection::Postgres(connection) => {
+                        connection.clone().into_inline_connection(scx.catalog)
+                    }
+                    _ => Err(PgSinkPurificationError::NotPgConnection(
+                        scx.catalog.resolve_full_name(item.name()),
+                    ))?,
+                }
+            };
+
+            let crate::plan::statement::ddl::PostgresSinkConfigOptionExtracted { table, .. } =
+                options.clone().try_into()?;
+
+            // Planning reports a missing TABLE option.
+            if let Some(table) = table {
+                let table = crate::plan::statement::ddl::postgres_sink_table_name(table)?;
+                let from = scx.get_item_by_resolved_name(&stmt.from)?;
+                let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
+
+                let config = connection
+                    .config(
+                        &*storage_configuration.connection_context.secrets_reader,
+                        storage_configuration,
+                    )
+                    .await?;
+                let client = config
+                    .connect(
+                        "postgres sink purification",
+                        &storage_configuration.connection_context.ssh_tunnel_manager,
+                    )
+                    .await?;
+
+                // Only check that the table exists and has the expected columns;
+                // the sink creates its progress table itself.
+                let upstream_columns: BTreeSet<String> = client
+                    .query(
+                        "SELECT column_name::text FROM information_schema.columns \
+                        WHERE table_schema = $1 AND table_name = $2",
+                        &[&table.schema, &table.name],
+                    )
+                    .await
+                    .map_err(PostgresError::from)?
+                    .into_iter()
+                    .map(|row| row.get(0))
+                    .collect();
+
+                let qualified_name = format!("{}.{}", table.schema, table.name);
+                if upstream_columns.is_empty() {
+                    Err(PgSinkPurificationError::MissingTable(qualified_name))?;
+                }
+
+                let missing_columns: Vec<_> = desc
+                    .iter_names()
+                    .filter(|name| !upstream_columns.contains(name.as_str()))
+                    .map(|name| name.to_string())
+                    .collect();
+                if !missing_columns.is_empty() {
+                    Err(PgSinkPurificationError::MissingColumns {
+                        table: qualified_name,
+                        columns: missing_columns,
+                    })?;
+                }
+            }
+        }
+        // The endpoint is not contacted during purification; the sink reports
+        // any problems reaching it through its status once it is running.
+        CreateSinkConnection::Webhook { .. } => {}
+        // The table is created by the sink itself if it does not exist yet,
+        // and the AWS connection reports its problems through the status of
+        // the sink.
+        CreateSinkConnection::Iceberg { .. } => {}
     }
 
     if let Some(format) = format {
@@ -664,6 +744,7 @@ async fn purify_create_source(
             let crate::plan::statement::PgConfigOptionExtracted {
                 publication,
                 mut text_columns,
+                enum_columns,
                 details,
                 ..
             } = options.clone().try_into()?;
@@ -674,6 +755,10 @@ async fn purify_create_source(
                 Err(PgSourcePurificationError::UserSpecifiedDetails)?;
             }
 
+            if !enum_columns.is_empty() {
+                Err(PgSourcePurificationError::UserSpecifiedEnumColumns)?;
+            }
+
             // verify that we can connect upstream and snapshot publication metadata
             let config = connection
                 .config(
@@ -845,10 +930,32 @@ async fn purify_create_source(
                 text_cols_option.value = Some(WithOptionValue::Sequence(seq));
             }
 
+            let enum_types = postgres::resolve_enum_types(
+                &scx,
+                &config,
+                &validated_requested_subsources,
+                &text_cols_dict,
+                &storage_configuration.connection_context.ssh_tunnel_manager,
+            )
+            .await?;
+
+            let enum_columns = postgres::generate_enum_columns(
+                &validated_requested_subsources,
+                &text_cols_dict,
+                &enum_types,
+            )?;
+            if !enum_columns.is_empty() {
+                options.push(PgConfigOption {
+                    name: PgConfigOptionName::EnumColumns,
+                    value: Some(WithOptionValue::Sequence(enum_columns)),
+                });
+            }
+
             let (targeted_subsources, new_subsources) = postgres::generate_targeted_subsources(
                 &scx,
                 validated_requested_subsources,
                 text_cols_dict,
+                &enum_types,
                 get_transient_subsource_id,
                 &publication_tables,
             )?;
@@ -1428,10 +1535,32 @@ async fn purify_alter_source(
         text_cols_option.value = Some(WithOptionValue::Sequence(seq));
     }
 
+    let enum_types = postgres::resolve_enum_types(
+        &scx,
+        &config,
+        &validated_requested_subsources,
+        &text_cols_dict,
+        &storage_configuration.connection_context.ssh_tunnel_manager,
+    )
+    .await?;
+
+    let enum_columns = postgres::generate_enum_columns(
+        &validated_requested_subsources,
+        &text_cols_dict,
+        &enum_types,
+    )?;
+    if !enum_columns.is_empty() {
+        options.push(AlterSourceAddSubsourceOption {
+            name: AlterSourceAddSubsourceOptionName::EnumColumns,
+            value: Some(WithOptionValue::Sequence(enum_columns)),
+        });
+    }
+
     let (named_subsources, new_subsources) = postgres::generate_targeted_subsources(
         &scx,
         validated_requested_subsources,
         text_cols_dict,
+        &enum_types,
         get_transient_subsource_id,
         &publication_tables,
     )?;
@@ -23,6 +23,8 @@ use crate::pure::Aug;
 pub enum PgSourcePurificationError {
     #[error("CREATE SOURCE specifies DETAILS option")]
     UserSpecifiedDetails,
+    #[error("CREATE SOURCE specifies ENUM COLUMNS option")]
+    UserSpecifiedEnumColumns,
     #[error("PUBLICATION {0} is empty")]
     EmptyPublication(String),
     #[error("database {database} missing referenced schemas")]
@@ -106,6 +108,11 @@ impl PgSourcePurificationError {
                 "If trying to use the output of SHOW CREATE SOURCE, remove the DETAILS option."
                     .into(),
             ),
+            Self::UserSpecifiedEnumColumns => Some(
+                "If trying to use the output of SHOW CREATE SOURCE, remove the ENUM COLUMNS option; \
+                Materialize determines it automatically."
+                    .into(),
+            ),
             Self::RequiresReferencedSubsources => {
                 Some("provide a FOR TABLES (..), FOR SCHEMAS (..), or FOR ALL TABLES clause".into())
             }
@@ -113,7 +120,8 @@ impl PgSourcePurificationError {
                 cols: _,
             } => Some(
                 "Use the TEXT COLUMNS option naming the listed columns, and Materialize can ingest their values \
-                as text."
+                as text. Columns of enum types can instead be ingested as an enum type created with \
+                CREATE TYPE ... AS ENUM that has the same schema-qualified name and contains all of the upstream labels."
                     .into(),
             ),
             Self::InsufficientReplicationSlotsAvailable { .. } => Some(
@@ -219,6 +227,38 @@ impl KafkaSinkPurificationError {
     }
 }
 
+/// Logical errors detectable during purification for a POSTGRES SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PgSinkPurificationError {
+    #[error("{0} is not a POSTGRES CONNECTION")]
+    NotPgConnection(FullItemName),
+    #[error("table {0} does not exist")]
+    MissingTable(String),
+    #[error("table {table} is missing columns")]
+    MissingColumns { table: String, columns: Vec<String> },
+}
+
+impl PgSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::MissingColumns { table: _, columns } => {
+                Some(format!("missing columns: {}", columns.join(", ")))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::MissingTable(_) | Self::MissingColumns { .. } => Some(
+                "The upstream table must exist and contain a column for every column of the sinked relation."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
 use mz_ore::error::ErrorExt;
 
 /// Logical errors detectable during purification for Confluent Schema Registry.
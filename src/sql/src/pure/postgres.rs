@@ -23,8 +23,8 @@ use mz_sql_parser::ast::{
 use mz_sql_parser::ast::{CreateSourceSubsource, UnresolvedItemName};
 use mz_ssh_util::tunnel_manager::SshTunnelManager;
 
-use crate::catalog::SubsourceCatalog;
-use crate::names::{Aug, PartialItemName};
+use crate::catalog::{CatalogType, CatalogTypeDetails, SubsourceCatalog};
+use crate::names::{Aug, PartialItemName, ResolvedDataType};
 use crate::normalize;
 use crate::plan::{PlanError, StatementContext};
 
@@ -155,10 +155,122 @@ pub(super) fn generate_text_columns(
     Ok(text_cols_dict)
 }
 
+/// Resolves the upstream enum types used by the columns of the requested
+/// subsources to the Materialize enum types they are ingested as.
+///
+/// An upstream enum type maps to the Materialize enum type of the same name in
+/// the schema of the same name in the active database, provided that the
+/// Materialize type contains all of the upstream labels in the same relative
+/// order. Columns of upstream enum types without such a
+/// counterpart are left unsupported.
+pub(super) async fn resolve_enum_types(
+    scx: &StatementContext<'_>,
+    config: &Config,
+    requested_subsources: &[RequestedSubsource<'_, PostgresTableDesc>],
+    text_cols_dict: &BTreeMap<u32, BTreeSet<String>>,
+    ssh_tunnel_manager: &SshTunnelManager,
+) -> Result<BTreeMap<u32, ResolvedDataType>, PlanError> {
+    let mut type_oids = BTreeSet::new();
+    for RequestedSubsource { table, .. } in requested_subsources {
+        let text_cols = text_cols_dict.get(&table.oid);
+        for c in table.columns.iter() {
+            if text_cols.map_or(false, |names| names.contains(&c.name)) {
+                continue;
+            }
+            if mz_pgrepr::Type::from_oid_and_typmod(c.type_oid, c.type_mod).is_err() {
+                type_oids.insert(c.type_oid);
+            }
+        }
+    }
+
+    let mut enum_types = BTreeMap::new();
+    if type_oids.is_empty() {
+        return Ok(enum_types);
+    }
+
+    for (oid, schema, name, upstream_labels) in
+        enum_labels::get_enum_labels(config, type_oids.into_iter().collect(), ssh_tunnel_manager)
+            .await?
+    {
+        let partial_name = PartialItemName {
+            database: None,
+            schema: Some(schema),
+            item: name,
+        };
+        let Ok(item) = scx.catalog.resolve_type(&partial_name) else {
+            continue;
+        };
+        let Some(CatalogTypeDetails {
+            typ: CatalogType::Enum { labels },
+            ..
+        }) = item.type_details()
+        else {
+            continue;
+        };
+        // Values are ingested by label, so every upstream label must exist
+        // in the Materialize type, and both types must order them alike.
+        if !labels
+            .iter()
+            .filter(|label| upstream_labels.contains(label))
+            .eq(upstream_labels.iter())
+        {
+            continue;
+        }
+
+        enum_types.insert(
+            oid,
+            ResolvedDataType::Named {
+                id: item.id(),
+                qualifiers: item.name().qualifiers.clone(),
+                full_name: scx.catalog.resolve_full_name(item.name()),
+                modifiers: vec![],
+                print_id: true,
+            },
+        );
+    }
+
+    Ok(enum_types)
+}
+
+/// Generates the values of the `ENUM COLUMNS` option, which pairs each column
+/// of the requested subsources that is ingested as an enum type with that
+/// type.
+pub(super) fn generate_enum_columns(
+    requested_subsources: &[RequestedSubsource<'_, PostgresTableDesc>],
+    text_cols_dict: &BTreeMap<u32, BTreeSet<String>>,
+    enum_types: &BTreeMap<u32, ResolvedDataType>,
+) -> Result<Vec<WithOptionValue<Aug>>, PlanError> {
+    let mut enum_columns = vec![];
+    for RequestedSubsource {
+        upstream_name,
+        table,
+        ..
+    } in requested_subsources
+    {
+        let text_cols = text_cols_dict.get(&table.oid);
+        for c in table.columns.iter() {
+            if text_cols.map_or(false, |names| names.contains(&c.name)) {
+                continue;
+            }
+            if let Some(data_type) = enum_types.get(&c.type_oid) {
+                let mut column = upstream_name.clone();
+                column.0.push(Ident::new(c.name.clone())?);
+                enum_columns.push(WithOptionValue::Sequence(vec![
+                    WithOptionValue::UnresolvedItemName(column),
+                    WithOptionValue::DataType(data_type.clone()),
+                ]));
+            }
+        }
+    }
+    enum_columns.sort();
+    Ok(enum_columns)
+}
+
 pub(crate) fn generate_targeted_subsources<F>(
     scx: &StatementContext,
     validated_requested_subsources: Vec<RequestedSubsource<'_, PostgresTableDesc>>,
     mut text_cols_dict: BTreeMap<u32, BTreeSet<String>>,
+    enum_types: &BTreeMap<u32, ResolvedDataType>,
     mut get_transient_subsource_id: F,
     publication_tables: &[PostgresTableDesc],
 ) -> Result<
@@ -189,23 +301,26 @@ where
         let text_cols_dict = text_cols_dict.remove(&table.oid);
         for c in table.columns.iter() {
             let name = Ident::new(c.name.clone())?;
-            let ty = match &text_cols_dict {
-                Some(names) if names.contains(&c.name) => mz_pgrepr::Type::Text,
+            let data_type = match &text_cols_dict {
+                Some(names) if names.contains(&c.name) => {
+                    scx.resolve_type(mz_pgrepr::Type::Text)?
+                }
                 _ => match mz_pgrepr::Type::from_oid_and_typmod(c.type_oid, c.type_mod) {
-                    Ok(t) => t,
-                    Err(_) => {
-                        let mut full_name = upstream_name.0.clone();
-                        full_name.push(name);
-                        unsupported_cols.push((
-                            UnresolvedItemName(full_name).to_ast_string(),
-                            Oid(c.type_oid),
-                        ));
-                        continue;
-                    }
+                    Ok(t) => scx.resolve_type(t)?,
+                    Err(_) => match enum_types.get(&c.type_oid) {
+                        Some(data_type) => data_type.clone(),
+                        None => {
+                            let mut full_name = upstream_name.0.clone();
+                            full_name.push(name);
+                            unsupported_cols.push((
+                                UnresolvedItemName(full_name).to_ast_string(),
+                                Oid(c.type_oid),
+                            ));
+                            continue;
+                        }
+                    },
                 },
             };
-
-            let data_type = scx.resolve_type(ty)?;
             let mut options = vec![];
 
             if !c.nullable {
@@ -535,3 +650,67 @@ mod replica_identity {
         }
     }
 }
+
+mod enum_labels {
+    use postgres_array::{Array, Dimension};
+    use tokio_postgres::types::Oid;
+
+    use mz_postgres_util::{Config, PostgresError};
+
+    use super::SshTunnelManager;
+    use crate::plan::PlanError;
+
+    /// Returns the schema, name, and labels, in sort order, of each of the
+    /// provided OIDs that identifies an enum type.
+    pub async fn get_enum_labels(
+        config: &Config,
+        oids: Vec<Oid>,
+        ssh_tunnel_manager: &SshTunnelManager,
+    ) -> Result<Vec<(Oid, String, String, Vec<String>)>, PlanError> {
+        let client = config
+            .connect("get_enum_labels", ssh_tunnel_manager)
+            .await?;
+
+        let oids_len = oids.len();
+
+        let oids = Array::from_parts(
+            oids,
+            vec![Dimension {
+                len: i32::try_from(oids_len).expect("fewer than i32::MAX types"),
+                lower_bound: 0,
+            }],
+        );
+
+        let enum_labels = client
+            .query(
+                "
+            SELECT
+                pg_type.oid,
+                pg_namespace.nspname::TEXT AS schema,
+                pg_type.typname::TEXT AS name,
+                array_agg(pg_enum.enumlabel::TEXT ORDER BY pg_enum.enumsortorder) AS labels
+            FROM
+                (SELECT unnest($1::OID[]) AS oid) AS input
+                JOIN pg_type ON input.oid = pg_type.oid
+                JOIN pg_namespace ON pg_namespace.oid = pg_type.typnamespace
+                JOIN pg_enum ON pg_enum.enumtypid = pg_type.oid
+            GROUP BY
+                pg_type.oid, pg_namespace.nspname, pg_type.typname;",
+                &[&oids],
+            )
+            .await
+            .map_err(PostgresError::from)?
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get("oid"),
+                    row.get("schema"),
+                    row.get("name"),
+                    row.get("labels"),
+                )
+            })
+            .collect();
+
+        Ok(enum_labels)
+    }
+}
@@ -86,9 +86,10 @@ macro_rules! rbac_preamble {
     };
 }
 
-// The default item types that most statements require USAGE privileges for.
+// The default item types that most statements require USAGE privileges for. Functions require
+// EXECUTE privileges instead.
 static DEFAULT_ITEM_USAGE: Lazy<BTreeSet<CatalogItemType>> = Lazy::new(|| {
-    btreeset! {CatalogItemType::Secret, CatalogItemType::Connection}
+    btreeset! {CatalogItemType::Secret, CatalogItemType::Connection, CatalogItemType::Func}
 });
 // CREATE statements require USAGE privileges on the default item types and USAGE privileges on
 // Types.
@@ -570,6 +571,15 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::CreateFunction(plan::CreateFunctionPlan { name, func: _ }) => RbacRequirements {
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::Comment(plan::CommentPlan {
             object_id,
             sub_component: _,
@@ -736,6 +746,45 @@ fn generate_rbac_requirements(
             ],
             ..Default::default()
         },
+        Plan::CopyTo(plan::CopyToPlan {
+            select_plan,
+            desc: _,
+            to: _,
+            connection: _,
+            connection_id,
+            format: _,
+            max_file_size: _,
+        }) => {
+            let mut privileges = generate_rbac_requirements(
+                catalog,
+                &Plan::Select(select_plan.clone()),
+                active_conns,
+                target_cluster_id,
+                role_id,
+            )
+            .privileges;
+            privileges.push((
+                SystemObjectId::Object(
+                    catalog
+                        .get_item(connection_id)
+                        .name()
+                        .qualifiers
+                        .clone()
+                        .into(),
+                ),
+                AclMode::USAGE,
+                role_id,
+            ));
+            privileges.push((
+                SystemObjectId::Object(connection_id.into()),
+                AclMode::USAGE,
+                role_id,
+            ));
+            RbacRequirements {
+                privileges,
+                ..Default::default()
+            }
+        }
         Plan::ExplainPlan(plan::ExplainPlanPlan {
             stage: _,
             format: _,
@@ -1025,6 +1074,20 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterType(plan::AlterTypePlan {
+            id,
+            create_sql: _,
+            dependents: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::AlterTable(plan::AlterTablePlan { id, action: _ }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::AlterRole(plan::AlterRolePlan {
             id,
             name: _,
@@ -1088,12 +1151,32 @@ fn generate_rbac_requirements(
             assignments,
             kind,
             returning,
+            on_conflict,
+            when_clauses,
         }) => {
-            let acl_mode = match kind {
+            let mut acl_mode = match kind {
                 MutationKind::Insert => AclMode::INSERT,
                 MutationKind::Update => AclMode::UPDATE,
                 MutationKind::Delete => AclMode::DELETE,
+                // `MERGE` requires the privileges of each action it may take, as in PostgreSQL.
+                MutationKind::Merge => when_clauses
+                    .iter()
+                    .map(|clause| match clause.action {
+                        plan::MergeActionPlan::Update(_) => AclMode::UPDATE,
+                        plan::MergeActionPlan::Delete => AclMode::DELETE,
+                        plan::MergeActionPlan::Insert(_) => AclMode::INSERT,
+                        plan::MergeActionPlan::DoNothing => AclMode::empty(),
+                    })
+                    .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode)),
             };
+            // `ON CONFLICT DO UPDATE` additionally requires UPDATE privileges, as in PostgreSQL.
+            if let Some(plan::OnConflictPlan {
+                action: plan::OnConflictAction::DoUpdate { .. },
+                ..
+            }) = on_conflict
+            {
+                acl_mode |= AclMode::UPDATE;
+            }
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             let mut privileges = vec![
                 (
@@ -1498,11 +1581,20 @@ fn generate_usage_privileges(
         .iter()
         .filter_map(move |id| {
             let item = catalog.get_item(id);
-            if item_types.contains(&item.item_type()) {
+            let item_type = item.item_type();
+            // Built-in functions may be executed by anyone.
+            if item_type == CatalogItemType::Func && id.is_system() {
+                return None;
+            }
+            if item_types.contains(&item_type) {
                 let schema_id = item.name().qualifiers.clone().into();
+                let acl_mode = match item_type {
+                    CatalogItemType::Func => AclMode::EXECUTE,
+                    _ => AclMode::USAGE,
+                };
                 Some([
                     (SystemObjectId::Object(schema_id), AclMode::USAGE, role_id),
-                    (SystemObjectId::Object(id.into()), AclMode::USAGE, role_id),
+                    (SystemObjectId::Object(id.into()), acl_mode, role_id),
                 ])
             } else {
                 None
@@ -1587,7 +1679,7 @@ pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
         SystemObjectType::Object(ObjectType::Connection) => AclMode::USAGE,
         SystemObjectType::Object(ObjectType::Database) => USAGE_CREATE_ACL_MODE,
         SystemObjectType::Object(ObjectType::Schema) => USAGE_CREATE_ACL_MODE,
-        SystemObjectType::Object(ObjectType::Func) => EMPTY_ACL_MODE,
+        SystemObjectType::Object(ObjectType::Func) => AclMode::EXECUTE,
         SystemObjectType::System => ALL_SYSTEM_PRIVILEGES,
     }
 }
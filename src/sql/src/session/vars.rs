@@ -2213,6 +2213,48 @@ feature_flags!(
         internal: true,
         enable_for_item_parsing: false,
     },
+    {
+        name: enable_postgres_sink,
+        desc: "CREATE SINK ... INTO POSTGRES CONNECTION",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_webhook_sink,
+        desc: "CREATE SINK ... INTO WEBHOOK",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_iceberg_sink,
+        desc: "CREATE SINK ... INTO ICEBERG",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_enum_types,
+        desc: "CREATE TYPE ... AS ENUM",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_domain_types,
+        desc: "CREATE DOMAIN",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_copy_to_expr,
+        desc: "COPY ... TO 's3://...'",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: false,
+    },
     {
         name: enable_expressions_in_limit_syntax,
         desc: "LIMIT <expr> syntax",
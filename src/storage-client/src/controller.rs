@@ -23,6 +23,7 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 use differential_dataflow::lattice::Lattice;
+use futures::future::BoxFuture;
 use mz_cluster_client::client::ClusterReplicaLocation;
 use mz_cluster_client::ReplicaId;
 use mz_persist_client::read::{Cursor, ReadHandle};
@@ -30,7 +31,7 @@ use mz_persist_client::stats::SnapshotStats;
 use mz_persist_types::Codec64;
 use mz_repr::{Diff, GlobalId, RelationDesc, Row};
 use mz_storage_types::configuration::StorageConfiguration;
-use mz_storage_types::controller::{CollectionMetadata, StorageError};
+use mz_storage_types::controller::{CollectionMetadata, StorageError, TableLayout};
 use mz_storage_types::instances::StorageInstanceId;
 use mz_storage_types::parameters::StorageParameters;
 use mz_storage_types::read_policy::ReadPolicy;
@@ -119,6 +120,9 @@ pub struct CollectionDescription<T> {
     /// A GlobalId to use for this collection to use for the status collection.
     /// Used to keep track of source status/error information.
     pub status_collection_id: Option<GlobalId>,
+    /// How the rows stored for this collection map to `desc`, if this is a
+    /// table whose columns have been altered.
+    pub table_layout: Option<TableLayout>,
 }
 
 impl<T> CollectionDescription<T> {
@@ -169,6 +173,7 @@ impl<T> CollectionDescription<T> {
             data_source: DataSource::Other(source),
             since: None,
             status_collection_id: None,
+            table_layout: None,
         }
     }
 }
@@ -319,6 +324,21 @@ pub trait StorageController: Debug {
         collections: BTreeMap<GlobalId, IngestionDescription>,
     ) -> Result<(), StorageError>;
 
+    /// Alter the table identified by `id` to be described by `desc`, with its
+    /// rows stored as described by `layout` from `register_ts` on.
+    ///
+    /// `layout` must extend the table's current layout. The table's existing
+    /// data is left as is: readers with the new layout see the fill values of
+    /// added columns in rows written before this call, and do not see dropped
+    /// columns.
+    async fn alter_table_desc(
+        &mut self,
+        id: GlobalId,
+        desc: RelationDesc,
+        layout: TableLayout,
+        register_ts: Self::Timestamp,
+    ) -> Result<(), StorageError>;
+
     /// Acquire an immutable reference to the export state, should it exist.
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError>;
 
@@ -394,6 +414,19 @@ pub trait StorageController: Debug {
         as_of: Self::Timestamp,
     ) -> Result<Vec<(Row, Diff)>, StorageError>;
 
+    /// Returns a future that resolves to the consolidated updates of the
+    /// local input named `id` at `as_of` whose rows satisfy `filter`.
+    ///
+    /// Unlike [`StorageController::snapshot`], the future does not borrow the
+    /// controller, so its owner can await it in a separate task, and only the
+    /// rows that satisfy `filter` are kept in memory.
+    fn snapshot_filtered(
+        &self,
+        id: GlobalId,
+        as_of: Self::Timestamp,
+        filter: Box<dyn FnMut(&Row) -> bool + Send>,
+    ) -> Result<BoxFuture<'static, Result<Vec<(Row, Diff)>, StorageError>>, StorageError>;
+
     /// Returns the snapshot of the contents of the local input named `id` at `as_of`.
     async fn snapshot_cursor(
         &mut self,
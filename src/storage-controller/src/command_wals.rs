@@ -125,9 +125,10 @@ where
             .expect("stash operation succeeds");
 
         // Partition metadata into the collections we want to have and those we failed to drop.
+        // Sinks record the shards in which they track their progress in the same collection.
         let (in_use_collections, leaked_collections): (Vec<_>, Vec<_>) = metadata
             .into_iter()
-            .partition(|(id, _)| self.collection(*id).is_ok());
+            .partition(|(id, _)| self.collection(*id).is_ok() || self.export(*id).is_ok());
 
         // Get all shard IDs
         let shard_finalization: BTreeSet<_> = shard_finalization
@@ -19,6 +19,7 @@ use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use differential_dataflow::lattice::Lattice;
+use futures::future::BoxFuture;
 use futures::stream::BoxStream;
 use itertools::Itertools;
 use mz_build_info::BuildInfo;
@@ -56,7 +57,8 @@ use mz_storage_types::collections as proto;
 use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::connections::ConnectionContext;
 use mz_storage_types::controller::{
-    CollectionMetadata, DurableCollectionMetadata, PersistTxnTablesImpl, StorageError, TxnsCodecRow,
+    CollectionMetadata, DurableCollectionMetadata, PersistTxnTablesImpl, StorageError, TableLayout,
+    TxnsCodecRow,
 };
 use mz_storage_types::instances::StorageInstanceId;
 use mz_storage_types::parameters::StorageParameters;
@@ -142,6 +144,9 @@ pub struct Controller<T: Timestamp + Lattice + Codec64 + From<EpochMillis> + Tim
     /// This is to prevent the re-binding of identifiers to other descriptions.
     pub(crate) collections: BTreeMap<GlobalId, CollectionState<T>>,
     pub(crate) exports: BTreeMap<GlobalId, ExportState<T>>,
+    /// The shards in which sinks that do not have a transactional progress store of their own
+    /// record their progress.
+    pub(crate) sink_progress_shards: BTreeMap<GlobalId, ShardId>,
     pub(crate) stash: mz_stash::Stash,
     /// Write handle for table shards.
     pub(crate) persist_table_worker: persist_handles::PersistTableWriteWorker<T>,
@@ -468,6 +473,7 @@ where
                     status_shard,
                     relation_desc: description.desc.clone(),
                     txns_shard,
+                    table_layout: description.table_layout.clone(),
                 };
 
                 Ok((id, description, metadata))
@@ -497,12 +503,18 @@ where
                     id, metadata.remap_shard, metadata.data_shard, metadata.status_shard
                 );
 
+                // Tables whose columns have been altered store more columns
+                // than they have.
+                let write_desc = match &metadata.table_layout {
+                    Some(layout) => layout.stored_desc(),
+                    None => metadata.relation_desc.clone(),
+                };
                 let (write, mut since_handle) = this
                     .open_data_handles(
                         &id,
                         metadata.data_shard,
                         description.since.as_ref(),
-                        metadata.relation_desc.clone(),
+                        write_desc,
                         persist_client,
                     )
                     .await;
@@ -861,6 +873,63 @@ where
         Ok(())
     }
 
+    async fn alter_table_desc(
+        &mut self,
+        id: GlobalId,
+        desc: RelationDesc,
+        layout: TableLayout,
+        register_ts: Self::Timestamp,
+    ) -> Result<(), StorageError> {
+        let collection = self.collection_mut(id)?;
+        let is_table = matches!(
+            collection.description.data_source,
+            DataSource::Other(DataSourceOther::TableWrites)
+        );
+        let is_extension = collection
+            .collection_metadata
+            .table_layout
+            .as_ref()
+            .map_or(false, |cur_layout| cur_layout.is_extended_by(&layout));
+        if !is_table || !is_extension {
+            tracing::info!(
+                "{id:?} inalterable because {:?} does not extend the table {:?}",
+                layout,
+                collection.description
+            );
+            return Err(StorageError::InvalidAlter { id });
+        }
+
+        collection.description.desc = desc.clone();
+        collection.description.table_layout = Some(layout.clone());
+        collection.collection_metadata.relation_desc = desc;
+        collection.collection_metadata.table_layout = Some(layout.clone());
+        let data_shard = collection.collection_metadata.data_shard;
+
+        // Writes must be encoded with the new stored columns, so that the
+        // stats of the shard describe them.
+        let persist_client = self
+            .persist
+            .open(self.persist_location.clone())
+            .await
+            .unwrap();
+        let mut write = persist_client
+            .open_writer(
+                data_shard,
+                Arc::new(layout.stored_desc()),
+                Arc::new(UnitSchema),
+                Diagnostics {
+                    shard_name: id.to_string(),
+                    handle_purpose: format!("controller data for {}", id),
+                },
+            )
+            .await
+            .expect("invalid persist usage");
+        write.fetch_recent_upper().await;
+        self.persist_table_worker.update_at(register_ts, id, write);
+
+        Ok(())
+    }
+
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError> {
         self.exports
             .get(&id)
@@ -939,12 +1008,15 @@ where
                 None
             };
 
+            let mut connection = description.sink.connection;
+            self.fill_sink_progress_shard(id, &mut connection).await?;
+
             let cmd = RunSinkCommand {
                 id,
                 description: StorageSinkDesc {
                     from: from_id,
                     from_desc: description.sink.from_desc,
-                    connection: description.sink.connection,
+                    connection,
                     envelope: description.sink.envelope,
                     as_of: description.sink.as_of,
                     status_id,
@@ -1001,12 +1073,15 @@ where
                 None
             };
 
+            let mut connection = export.description.sink.connection.clone();
+            self.fill_sink_progress_shard(id, &mut connection).await?;
+
             let cmd = RunSinkCommand {
                 id,
                 description: StorageSinkDesc {
                     from: export.description.sink.from,
                     from_desc: export.description.sink.from_desc.clone(),
-                    connection: export.description.sink.connection.clone(),
+                    connection,
                     envelope: export.description.sink.envelope,
                     with_snapshot: export.description.sink.with_snapshot,
                     // Here we are about to send a RunSinkCommand with the current read capaibility
@@ -1126,6 +1201,24 @@ where
             }
         }
 
+        // Tables store the columns that have been dropped from them.
+        let commands = commands
+            .into_iter()
+            .map(|(id, mut updates)| {
+                let layout = self
+                    .collection(id)
+                    .ok()
+                    .and_then(|collection| collection.collection_metadata.table_layout.as_ref())
+                    .filter(|layout| layout.columns.iter().any(|column| column.dropped));
+                if let Some(layout) = layout {
+                    for update in &mut updates {
+                        update.row = layout.encode(&update.row);
+                    }
+                }
+                (id, updates)
+            })
+            .collect();
+
         Ok(self
             .persist_table_worker
             .append(write_ts, advance_to, commands))
@@ -1147,6 +1240,7 @@ where
         as_of: Self::Timestamp,
     ) -> Result<Vec<(Row, Diff)>, StorageError> {
         let metadata = &self.collection(id)?.collection_metadata;
+        let table_layout = metadata.table_layout.clone();
         let contents = match metadata.txns_shard.as_ref() {
             None => {
                 // We're not using persist-txn for tables, so we can take a snapshot directly.
@@ -1181,10 +1275,14 @@ where
         match contents {
             Ok(contents) => {
                 let mut snapshot = Vec::with_capacity(contents.len());
+                let mut buf = Row::default();
                 for ((data, _), _, diff) in contents {
                     // TODO(petrosagg): We should accumulate the errors too and let the user
                     // interprret the result
-                    let row = data.expect("invalid protobuf data").0?;
+                    let mut row = data.expect("invalid protobuf data").0?;
+                    if let Some(table_layout) = &table_layout {
+                        row = table_layout.decode(row, &mut buf);
+                    }
                     snapshot.push((row, diff));
                 }
                 Ok(snapshot)
@@ -1193,6 +1291,72 @@ where
         }
     }
 
+    fn snapshot_filtered(
+        &self,
+        id: GlobalId,
+        as_of: Self::Timestamp,
+        mut filter: Box<dyn FnMut(&Row) -> bool + Send>,
+    ) -> Result<BoxFuture<'static, Result<Vec<(Row, Diff)>, StorageError>>, StorageError> {
+        use futures::stream::StreamExt;
+
+        let metadata = self.collection(id)?.collection_metadata.clone();
+        let persist = Arc::clone(&self.persist);
+        let txns_read = metadata
+            .txns_shard
+            .as_ref()
+            .map(|txns_id| self.txns.expect_enabled_lazy(txns_id).clone());
+        let snapshot = async move {
+            let persist_client = persist
+                .open(metadata.persist_location.clone())
+                .await
+                .unwrap();
+            let mut read_handle = persist_client
+                .open_leased_reader::<SourceData, (), _, _>(
+                    metadata.data_shard,
+                    Arc::new(metadata.relation_desc.clone()),
+                    Arc::new(UnitSchema),
+                    Diagnostics {
+                        shard_name: id.to_string(),
+                        handle_purpose: format!("snapshot {}", id),
+                    },
+                )
+                .await
+                .expect("invalid persist usage");
+            // See the comments in Self::snapshot for what's going on here.
+            let contents = match txns_read {
+                None => read_handle
+                    .snapshot_and_stream(Antichain::from_elem(as_of))
+                    .await
+                    .map(|contents| contents.boxed()),
+                Some(txns_read) => {
+                    txns_read.update_gt(as_of.clone()).await;
+                    let data_snapshot = txns_read
+                        .data_snapshot(metadata.data_shard, as_of.clone())
+                        .await;
+                    data_snapshot
+                        .snapshot_and_stream(&mut read_handle)
+                        .await
+                        .map(|contents| contents.boxed())
+                }
+            };
+            let mut contents = contents.map_err(|_| StorageError::ReadBeforeSince(id))?;
+            let mut snapshot = Vec::new();
+            let mut buf = Row::default();
+            while let Some(((data, _), _, diff)) = contents.next().await {
+                let mut row = data.expect("invalid protobuf data").0?;
+                if let Some(table_layout) = &metadata.table_layout {
+                    row = table_layout.decode(row, &mut buf);
+                }
+                if filter(&row) {
+                    snapshot.push((row, diff));
+                }
+            }
+            differential_dataflow::consolidation::consolidate(&mut snapshot);
+            Ok(snapshot)
+        };
+        Ok(Box::pin(snapshot))
+    }
+
     async fn snapshot_cursor(
         &mut self,
         id: GlobalId,
@@ -1482,7 +1646,7 @@ where
                 updated_frontiers = Some(Response::FrontierUpdates(updates));
             }
             Some(StorageResponse::DroppedIds(ids)) => {
-                let shards_to_finalize: Vec<_> = ids
+                let mut shards_to_finalize: Vec<_> = ids
                     .iter()
                     .filter_map(|id| {
                         // Drop all write handles. This is safe to do because
@@ -1506,6 +1670,10 @@ where
                         )
                     })
                     .collect();
+                shards_to_finalize.extend(
+                    ids.iter()
+                        .filter_map(|id| self.sink_progress_shards.remove(id)),
+                );
 
                 // Ensure we don't leak any shards by tracking all of them we intend to
                 // finalize.
@@ -2149,6 +2317,7 @@ where
             build_info,
             collections: BTreeMap::default(),
             exports: BTreeMap::default(),
+            sink_progress_shards: BTreeMap::default(),
             stash,
             persist_table_worker,
             persist_monotonic_worker,
@@ -2258,6 +2427,49 @@ where
         Ok(())
     }
 
+    /// Fills in the shard in which the sink `id` records its progress, if its `connection` needs
+    /// one, allocating a shard the first time the sink is created.
+    async fn fill_sink_progress_shard(
+        &mut self,
+        id: GlobalId,
+        connection: &mut StorageSinkConnection,
+    ) -> Result<(), StorageError> {
+        let connection_progress_shard = match connection {
+            StorageSinkConnection::Webhook(connection) => &mut connection.progress_shard,
+            StorageSinkConnection::Iceberg(connection) => &mut connection.progress_shard,
+            StorageSinkConnection::Kafka(_) | StorageSinkConnection::Postgres(_) => return Ok(()),
+        };
+
+        let progress_shard = match self.sink_progress_shards.get(&id) {
+            Some(shard) => *shard,
+            None => {
+                let metadata = DurableCollectionMetadata {
+                    data_shard: ShardId::new(),
+                };
+                let durable_metadata: BTreeMap<GlobalId, DurableCollectionMetadata> =
+                    METADATA_COLLECTION
+                        .insert_without_overwrite(
+                            &mut self.stash,
+                            [(id.into_proto(), metadata.into_proto())],
+                        )
+                        .await?
+                        .into_iter()
+                        .map(RustType::from_proto)
+                        .collect::<Result<_, _>>()
+                        .map_err(|e| StorageError::IOError(e.into()))?;
+                let shard = durable_metadata
+                    .get(&id)
+                    .expect("inserted above")
+                    .data_shard;
+                self.sink_progress_shards.insert(id, shard);
+                shard
+            }
+        };
+        *connection_progress_shard = Some(progress_shard);
+
+        Ok(())
+    }
+
     /// Opens a write and critical since handles for the given `shard`.
     ///
     /// `since` is an optional `since` that the read handle will be forwarded to if it is less than
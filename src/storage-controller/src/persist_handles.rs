@@ -235,6 +235,7 @@ pub struct PersistTableWriteWorker<T: Timestamp + Lattice + Codec64 + TimestampM
 enum PersistTableWriteCmd<T: Timestamp + Lattice + Codec64> {
     Register(T, Vec<(GlobalId, WriteHandle<SourceData, (), T, Diff>)>),
     Update(GlobalId, WriteHandle<SourceData, (), T, Diff>),
+    UpdateAt(T, GlobalId, WriteHandle<SourceData, (), T, Diff>),
     DropHandle(GlobalId),
     Append {
         write_ts: T,
@@ -354,6 +355,24 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> PersistTableWrite
         self.send(PersistTableWriteCmd::Update(id, write_handle))
     }
 
+    /// Update the existing write handle associated with `id` to `write_handle`,
+    /// which writes to the same shard, as of `update_ts`.
+    ///
+    /// Unlike [`Self::update`], this is supported for persist-txn backed
+    /// collections, where it re-registers the shard at `update_ts`.
+    ///
+    /// # Panics
+    /// - If `id` is not currently associated with a write handle for the same
+    ///   shard.
+    pub(crate) fn update_at(
+        &self,
+        update_ts: T,
+        id: GlobalId,
+        write_handle: WriteHandle<SourceData, (), T, Diff>,
+    ) {
+        self.send(PersistTableWriteCmd::UpdateAt(update_ts, id, write_handle))
+    }
+
     pub(crate) fn append(
         &self,
         write_ts: T,
@@ -423,7 +442,8 @@ async fn legacy_table_worker<T: Timestamp + Lattice + Codec64 + TimestampManipul
                         }
                     }
                 }
-                PersistTableWriteCmd::Update(id, write_handle) => {
+                PersistTableWriteCmd::Update(id, write_handle)
+                | PersistTableWriteCmd::UpdateAt(_, id, write_handle) => {
                     write_handles.insert(id, write_handle).expect(
                         "PersistTableWriteCmd::Update only valid for updating extant write handles",
                     );
@@ -533,6 +553,11 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> TxnsTableWorker<T
                 PersistTableWriteCmd::Update(_id, _write_handle) => {
                     unimplemented!("TODO: Support migrations on persist-txn backed collections")
                 }
+                PersistTableWriteCmd::UpdateAt(update_ts, id, write_handle) => {
+                    self.update_at(update_ts, id, write_handle)
+                        .instrument(span)
+                        .await
+                }
                 PersistTableWriteCmd::DropHandle(id) => self.drop_handle(id).instrument(span).await,
                 PersistTableWriteCmd::Append {
                     write_ts,
@@ -611,6 +636,44 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> TxnsTableWorker<T
         }
     }
 
+    async fn update_at(
+        &mut self,
+        update_ts: T,
+        id: GlobalId,
+        write_handle: WriteHandle<SourceData, (), T, i64>,
+    ) {
+        debug!(
+            "tables update {} {:.9}",
+            id,
+            write_handle.shard_id().to_string()
+        );
+        if self.write_handles.get(&id) != Some(&write_handle.shard_id()) {
+            panic!("no WriteHandle registered for collection {:?}", id);
+        }
+        // Registering an already registered shard replaces its WriteHandle and
+        // advances its logical upper.
+        let new_uppers = vec![(
+            id,
+            Antichain::from_elem(TimestampManipulation::step_forward(&update_ts)),
+        )];
+        let res = self.txns.register(update_ts.clone(), [write_handle]).await;
+        match res {
+            Ok(tidy) => {
+                self.tidy.merge(tidy);
+                if !self.lazy_persist_txn_tables {
+                    self.tidy.merge(self.txns.apply_eager_le(&update_ts).await);
+                }
+                self.send_new_uppers(new_uppers);
+            }
+            Err(current) => {
+                panic!(
+                    "cannot update {:?} at {:?} because txns is at {:?}",
+                    id, update_ts, current
+                );
+            }
+        }
+    }
+
     async fn drop_handle(&mut self, id: GlobalId) {
         debug!("tables drop {}", id);
         // n.b. this should only remove the handle from the persist
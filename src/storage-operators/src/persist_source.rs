@@ -30,7 +30,7 @@ use mz_persist_txn::operator::txns_progress;
 use mz_persist_types::codec_impls::UnitSchema;
 use mz_persist_types::Codec64;
 use mz_repr::{Datum, DatumVec, Diff, GlobalId, RelationType, Row, RowArena, Timestamp};
-use mz_storage_types::controller::{CollectionMetadata, TxnsCodecRow};
+use mz_storage_types::controller::{CollectionMetadata, TableLayout, TxnsCodecRow};
 use mz_storage_types::errors::DataflowError;
 use mz_storage_types::sources::SourceData;
 use mz_storage_types::stats::RelationPartStats;
@@ -286,7 +286,13 @@ where
     let cfg = persist_clients.cfg().clone();
     let name = source_id.to_string();
     let desc = metadata.relation_desc.clone();
-    let filter_plan = map_filter_project.as_ref().map(|p| (*p).clone());
+    let table_layout = metadata.table_layout.clone();
+    // The stats of a table whose columns have been altered describe the
+    // columns stored in its shard, rather than the columns in `desc`.
+    let filter_plan = match &table_layout {
+        Some(layout) if layout != &TableLayout::new(&desc) => None,
+        _ => map_filter_project.as_ref().map(|p| (*p).clone()),
+    };
 
     let desc_transformer = match flow_control {
         Some(flow_control) => Some(move |mut scope: _, descs: &Stream<_, _>, chosen_worker| {
@@ -350,7 +356,14 @@ where
         },
         listen_sleep,
     );
-    let rows = decode_and_mfp(cfg, &fetched, &name, until, map_filter_project);
+    let rows = decode_and_mfp(
+        cfg,
+        &fetched,
+        &name,
+        table_layout,
+        until,
+        map_filter_project,
+    );
     (rows, token)
 }
 
@@ -381,6 +394,7 @@ pub fn decode_and_mfp<G>(
     cfg: PersistConfig,
     fetched: &Stream<G, FetchedPart<SourceData, (), Timestamp, Diff>>,
     name: &str,
+    table_layout: Option<TableLayout>,
     until: Antichain<Timestamp>,
     mut map_filter_project: Option<&mut MfpPlan>,
 ) -> Stream<G, (Result<Row, DataflowError>, G::Timestamp, Diff)>
@@ -438,6 +452,7 @@ where
                 let done = pending_work.front_mut().unwrap().do_work(
                     &mut work,
                     &name,
+                    table_layout.as_ref(),
                     start_time,
                     yield_fn,
                     &until,
@@ -474,6 +489,7 @@ impl PendingWork {
         &mut self,
         work: &mut usize,
         name: &str,
+        table_layout: Option<&TableLayout>,
         start_time: Instant,
         yield_fn: YFn,
         until: &Antichain<Timestamp>,
@@ -516,7 +532,14 @@ impl PendingWork {
                         // evaluation against our fuel.
                         *work += 1;
                         let arena = mz_repr::RowArena::new();
-                        let mut datums_local = datum_vec.borrow_with(&row);
+                        let mut datums_local = match table_layout {
+                            Some(layout) => {
+                                let mut datums_local = datum_vec.borrow();
+                                datums_local.extend(layout.datums(&row));
+                                datums_local
+                            }
+                            None => datum_vec.borrow_with(&row),
+                        };
                         for result in mfp.evaluate(
                             &mut datums_local,
                             &arena,
@@ -552,6 +575,10 @@ impl PendingWork {
                             }
                         }
                     } else {
+                        let row = match table_layout {
+                            Some(layout) => layout.decode(row, row_builder),
+                            None => row,
+                        };
                         let mut emit_time = *self.capability.time();
                         emit_time.0 = time;
                         output.give_at(&self.capability, (Ok(row), emit_time, diff));
@@ -0,0 +1,720 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Uploads a consolidated snapshot of a collection to S3.
+//!
+//! This operator backs `COPY ... TO 's3://...'`. Every worker uploads the rows it holds as one or
+//! more files named `part-<worker>-<file>.<ext>` below the requested prefix. A new file is started
+//! once the current one exceeds the configured maximum file size. Files are uploaded in parts, as
+//! rows arrive, so that no worker holds more than a part of a file in memory.
+//!
+//! ```text
+//!        input            errors
+//!          │                 │
+//!   ┏━━━━━━v━━━━━━━━━━━━━━━━━v━━┓     ┏━━━━━━━━━━━━┓
+//!   ┃          upload           ┃<────┨ initialize ┃ (lead worker)
+//!   ┗━━━━━━┯━━━━━━━━━━━━━┯━━━━━━┛     ┗━━━━━━┯━━━━━┛
+//!          │ result      │ result            │
+//!          │      ┏━━━━━━v━━━━━━━┓           │
+//!          │      ┃   complete   ┃<──────────╯
+//!          │      ┗━━━━━━┯━━━━━━━┛ (lead worker)
+//!   ┏━━━━━━v━━━━━━━━━━━━━v━━┓
+//!   ┃        respond        ┃
+//!   ┗━━━━━━━━━━━━━━━━━━━━━━━┛
+//! ```
+//!
+//! Before any worker uploads a file, a single lead worker checks that the prefix is empty and
+//! marks it with an `INCOMPLETE` object. Once all workers are done, the lead worker removes the
+//! marker if all of them succeeded, or removes everything below the prefix if any of them failed.
+//! Every worker then reports the outcome. Should the cleanup itself fail, the marker is left in
+//! place to flag the partial output.
+
+use std::any::Any;
+use std::rc::Rc;
+
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart};
+use differential_dataflow::{Collection, Hashable};
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_pgcopy::encode_copy_row_csv;
+use mz_repr::{ColumnType, Datum, Diff, GlobalId, RelationDesc, RelationType, Row, Timestamp};
+use mz_storage_types::connections::aws::AwsConnection;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{S3SinkFormat, S3UploadInfo};
+use mz_timely_util::builder_async::{
+    Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::dataflow::channels::pact::{Exchange, Pipeline};
+use timely::dataflow::operators::Broadcast;
+use timely::dataflow::{Scope, Stream};
+use timely::progress::Antichain;
+use timely::PartialOrder;
+use tracing::{info, warn};
+
+use crate::s3_oneshot_sink::parquet::ParquetWriter;
+
+pub mod parquet;
+
+/// The name of the object that marks a prefix whose upload has not completed.
+const INCOMPLETE_MARKER: &str = "INCOMPLETE";
+
+/// The size in bytes of the parts in which files are uploaded. S3 requires all parts of a file but
+/// the last one to be at least 5 MiB.
+const PART_SIZE: usize = 8 << 20;
+
+/// Uploads the snapshot of `input_collection` at times before `up_to` to the S3 location
+/// described by `connection_details`.
+///
+/// The input must be consolidated, so that every row appears at most once per time.
+///
+/// Once the upload has completed, `one_time_callback` is invoked with the number of rows this
+/// worker uploaded, or with the first error encountered by any worker. The callback is not
+/// invoked if the returned token is dropped before the upload completes.
+pub fn copy_to<G, F>(
+    input_collection: Collection<G, Row, Diff>,
+    err_collection: Collection<G, DataflowError, Diff>,
+    up_to: Antichain<Timestamp>,
+    connection_details: S3UploadInfo,
+    connection_context: ConnectionContext,
+    aws_connection: AwsConnection,
+    sink_id: GlobalId,
+    connection_id: GlobalId,
+    one_time_callback: F,
+) -> Rc<dyn Any>
+where
+    G: Scope<Timestamp = Timestamp>,
+    F: FnOnce(Result<u64, String>) + 'static,
+{
+    let scope = input_collection.scope();
+    let target = S3Target {
+        connection_details,
+        connection_context,
+        aws_connection,
+        connection_id,
+    };
+    // A single worker prepares the prefix before, and cleans it up after, the upload.
+    let lead_worker = usize::cast_from(sink_id.hashed()) % scope.peers();
+
+    let (initialized, initialize_button) = initialize(&scope, sink_id, target.clone(), lead_worker);
+    let (results, upload_button) = upload(
+        &input_collection,
+        &err_collection,
+        &initialized.broadcast(),
+        up_to,
+        sink_id,
+        target.clone(),
+    );
+    let (outcome, complete_button) = complete(&initialized, &results, sink_id, target, lead_worker);
+    let respond_button = respond(&results, &outcome.broadcast(), sink_id, one_time_callback);
+
+    Rc::new(vec![
+        initialize_button,
+        upload_button,
+        complete_button,
+        respond_button,
+    ])
+}
+
+/// Checks, on the lead worker, that the prefix is empty and marks it as incomplete.
+fn initialize<G: Scope<Timestamp = Timestamp>>(
+    scope: &G,
+    sink_id: GlobalId,
+    target: S3Target,
+    lead_worker: usize,
+) -> (Stream<G, Result<(), String>>, PressOnDropButton) {
+    let is_lead_worker = scope.index() == lead_worker;
+    let mut builder = AsyncOperatorBuilder::new(
+        format!("s3_oneshot_sink_initialize-{}", sink_id),
+        scope.clone(),
+    );
+    let (mut output, stream) = builder.new_output();
+
+    let button = builder.build(move |capabilities| async move {
+        let [capability]: [_; 1] = capabilities.try_into().expect("one capability per output");
+        if !is_lead_worker {
+            return;
+        }
+        let result = async {
+            let client = target.client().await?;
+            if let Some(key) = client.list_keys(Some(1)).await?.into_iter().next() {
+                return Err(format!(
+                    "S3 location s3://{}/{} is not empty, found {}",
+                    client.bucket, client.key_prefix, key
+                ));
+            }
+            client.put_object(&client.marker_key(), Vec::new()).await
+        }
+        .await;
+        output.give(&capability, result).await;
+    });
+
+    (stream, button.press_on_drop())
+}
+
+/// Uploads the rows of each worker once the prefix has been initialized, reporting the number of
+/// rows uploaded or the first error encountered.
+fn upload<G: Scope<Timestamp = Timestamp>>(
+    input_collection: &Collection<G, Row, Diff>,
+    err_collection: &Collection<G, DataflowError, Diff>,
+    initialized: &Stream<G, Result<(), String>>,
+    up_to: Antichain<Timestamp>,
+    sink_id: GlobalId,
+    target: S3Target,
+) -> (Stream<G, Result<u64, String>>, PressOnDropButton) {
+    let scope = input_collection.scope();
+    let worker_id = scope.index();
+    let mut builder = AsyncOperatorBuilder::new(format!("s3_oneshot_sink-{}", sink_id), scope);
+
+    let mut input_handle = builder.new_disconnected_input(&input_collection.inner, Pipeline);
+    let mut err_handle = builder.new_disconnected_input(&err_collection.inner, Pipeline);
+    let mut initialized_handle = builder.new_disconnected_input(initialized, Pipeline);
+    let (mut output, stream) = builder.new_output();
+
+    let button = builder.build(move |capabilities| async move {
+        let [capability]: [_; 1] = capabilities.try_into().expect("one capability per output");
+        let result = async {
+            while let Some(event) = err_handle.next().await {
+                match event {
+                    AsyncEvent::Data(_ts, data) => {
+                        if let Some((err, _ts, _diff)) =
+                            data.into_iter().find(|(_, ts, _)| !up_to.less_equal(ts))
+                        {
+                            return Err(err.to_string());
+                        }
+                    }
+                    AsyncEvent::Progress(frontier) => {
+                        if PartialOrder::less_equal(&up_to, &frontier) {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            let mut initialization = None;
+            while let Some(event) = initialized_handle.next().await {
+                if let AsyncEvent::Data(_ts, data) = event {
+                    initialization = data.into_iter().next();
+                    break;
+                }
+            }
+            initialization.unwrap_or_else(|| Err("S3 location was not initialized".into()))?;
+
+            let mut uploader = S3Uploader::new(target.client().await?, &target, worker_id);
+            let result = async {
+                let mut row_count = 0;
+                while let Some(event) = input_handle.next().await {
+                    match event {
+                        AsyncEvent::Data(_ts, data) => {
+                            for (row, ts, diff) in data {
+                                if up_to.less_equal(&ts) {
+                                    continue;
+                                }
+                                let count = u64::try_from(diff).map_err(|_| {
+                                    format!(
+                                        "invalid data in source, \
+                                         saw retractions ({diff}) for row {row:?}"
+                                    )
+                                })?;
+                                for _ in 0..count {
+                                    uploader.append_row(&row).await?;
+                                }
+                                row_count += count;
+                            }
+                        }
+                        AsyncEvent::Progress(frontier) => {
+                            if PartialOrder::less_equal(&up_to, &frontier) {
+                                break;
+                            }
+                        }
+                    }
+                }
+                uploader.finish_file().await?;
+                Ok(row_count)
+            }
+            .await;
+            if result.is_err() {
+                uploader.abort().await;
+            }
+            result
+        }
+        .await;
+
+        if let Ok(row_count) = &result {
+            info!(
+                %sink_id, %worker_id, %row_count,
+                "s3_oneshot_sink: uploaded rows",
+            );
+        }
+        output.give(&capability, result).await;
+    });
+
+    (stream, button.press_on_drop())
+}
+
+/// Collects the results of all workers on the lead worker and cleans up the prefix, reporting
+/// the outcome of the upload.
+///
+/// If the prefix was initialized by this upload, a successful upload removes the marker and a
+/// failed one removes all objects below the prefix.
+fn complete<G: Scope<Timestamp = Timestamp>>(
+    initialized: &Stream<G, Result<(), String>>,
+    results: &Stream<G, Result<u64, String>>,
+    sink_id: GlobalId,
+    target: S3Target,
+    lead_worker: usize,
+) -> (Stream<G, Result<(), String>>, PressOnDropButton) {
+    let scope = results.scope();
+    let is_lead_worker = scope.index() == lead_worker;
+    let mut builder = AsyncOperatorBuilder::new(
+        format!("s3_oneshot_sink_complete-{}", sink_id),
+        scope.clone(),
+    );
+
+    let mut initialized_handle = builder.new_disconnected_input(initialized, Pipeline);
+    let lead_worker = u64::cast_from(lead_worker);
+    let mut results_handle =
+        builder.new_disconnected_input(results, Exchange::new(move |_| lead_worker));
+    let (mut output, stream) = builder.new_output();
+
+    let button = builder.build(move |capabilities| async move {
+        let [capability]: [_; 1] = capabilities.try_into().expect("one capability per output");
+        if !is_lead_worker {
+            return;
+        }
+
+        let mut initialization = None;
+        while let Some(event) = initialized_handle.next().await {
+            if let AsyncEvent::Data(_ts, data) = event {
+                initialization = data.into_iter().next();
+            }
+        }
+        let mut first_error = None;
+        while let Some(event) = results_handle.next().await {
+            if let AsyncEvent::Data(_ts, data) = event {
+                for result in data {
+                    if let Err(err) = result {
+                        first_error.get_or_insert(err);
+                    }
+                }
+            }
+        }
+
+        let outcome = match initialization {
+            // Nothing below the prefix belongs to this upload.
+            None => Err("S3 location was not initialized".into()),
+            Some(Err(err)) => Err(err),
+            Some(Ok(())) => {
+                async {
+                    let client = target.client().await?;
+                    match first_error {
+                        None => client.delete_object(&client.marker_key()).await,
+                        Some(err) => match client.delete_all().await {
+                            Ok(()) => Err(err),
+                            Err(cleanup_err) => {
+                                warn!(
+                                    %sink_id, %cleanup_err,
+                                    "s3_oneshot_sink: failed to remove partial output",
+                                );
+                                Err(format!(
+                                    "{err} (partial output below s3://{}/{} could not be removed: \
+                                 {cleanup_err})",
+                                    client.bucket, client.key_prefix
+                                ))
+                            }
+                        },
+                    }
+                }
+                .await
+            }
+        };
+        output.give(&capability, outcome).await;
+    });
+
+    (stream, button.press_on_drop())
+}
+
+/// Invokes `one_time_callback` on every worker with the worker's own result, or with the
+/// outcome of the upload if it failed.
+fn respond<G, F>(
+    results: &Stream<G, Result<u64, String>>,
+    outcome: &Stream<G, Result<(), String>>,
+    sink_id: GlobalId,
+    one_time_callback: F,
+) -> PressOnDropButton
+where
+    G: Scope<Timestamp = Timestamp>,
+    F: FnOnce(Result<u64, String>) + 'static,
+{
+    let mut builder = AsyncOperatorBuilder::new(
+        format!("s3_oneshot_sink_respond-{}", sink_id),
+        results.scope(),
+    );
+    let mut results_handle = builder.new_disconnected_input(results, Pipeline);
+    let mut outcome_handle = builder.new_disconnected_input(outcome, Pipeline);
+
+    let button = builder.build(move |_capabilities| async move {
+        let mut result = None;
+        while let Some(event) = results_handle.next().await {
+            if let AsyncEvent::Data(_ts, data) = event {
+                result = data.into_iter().next();
+            }
+        }
+        let mut outcome = None;
+        while let Some(event) = outcome_handle.next().await {
+            if let AsyncEvent::Data(_ts, data) = event {
+                outcome = data.into_iter().next();
+            }
+        }
+
+        let result = match (outcome, result) {
+            (Some(Ok(())), Some(result)) => result,
+            (Some(Err(err)), _) => Err(err),
+            _ => Err("S3 upload did not complete".into()),
+        };
+        one_time_callback(result);
+    });
+
+    button.press_on_drop()
+}
+
+/// The S3 location of an upload and the means to connect to it.
+#[derive(Clone)]
+struct S3Target {
+    connection_details: S3UploadInfo,
+    connection_context: ConnectionContext,
+    aws_connection: AwsConnection,
+    connection_id: GlobalId,
+}
+
+impl S3Target {
+    async fn client(&self) -> Result<S3Client, String> {
+        let (bucket, key_prefix) = S3UploadInfo::parse_uri(&self.connection_details.uri)
+            .map_err(|e| e.display_with_causes().to_string())?;
+        let sdk_config = self
+            .aws_connection
+            .load_sdk_config(&self.connection_context, self.connection_id)
+            .await
+            .map_err(|e| e.display_with_causes().to_string())?;
+        Ok(S3Client {
+            client: mz_aws_util::s3::new_client(&sdk_config),
+            bucket,
+            key_prefix,
+        })
+    }
+}
+
+/// A client for the objects below the prefix of an upload.
+struct S3Client {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+    key_prefix: String,
+}
+
+impl S3Client {
+    fn marker_key(&self) -> String {
+        format!("{}{}", self.key_prefix, INCOMPLETE_MARKER)
+    }
+
+    /// Formats the error of a failed `operation` on the object `key`.
+    fn error(&self, operation: &str, key: &str, e: impl ErrorExt) -> String {
+        format!(
+            "failed to {} s3://{}/{}: {}",
+            operation,
+            self.bucket,
+            key,
+            e.display_with_causes()
+        )
+    }
+
+    /// Lists the keys below the prefix, stopping after `limit` keys if given.
+    async fn list_keys(&self, limit: Option<usize>) -> Result<Vec<String>, String> {
+        let mut keys = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            let output = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(&self.key_prefix)
+                .set_max_keys(limit.and_then(|limit| i32::try_from(limit).ok()))
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .map_err(|e| self.error("list", &self.key_prefix, e))?;
+            keys.extend(
+                output
+                    .contents()
+                    .iter()
+                    .filter_map(|o| o.key().map(String::from)),
+            );
+            if let Some(limit) = limit {
+                if keys.len() >= limit {
+                    keys.truncate(limit);
+                    return Ok(keys);
+                }
+            }
+            match output.next_continuation_token() {
+                Some(token) => continuation_token = Some(token.to_string()),
+                None => return Ok(keys),
+            }
+        }
+    }
+
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> Result<(), String> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from(body))
+            .send()
+            .await
+            .map_err(|e| self.error("upload", key, e))?;
+        Ok(())
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), String> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| self.error("delete", key, e))?;
+        Ok(())
+    }
+
+    /// Deletes all objects below the prefix, deleting the marker last.
+    async fn delete_all(&self) -> Result<(), String> {
+        let marker_key = self.marker_key();
+        for key in self.list_keys(None).await? {
+            if key != marker_key {
+                self.delete_object(&key).await?;
+            }
+        }
+        self.delete_object(&marker_key).await
+    }
+}
+
+/// Uploads rows to S3 in parts, starting a new file whenever the current one reaches the maximum
+/// file size.
+struct S3Uploader {
+    client: S3Client,
+    desc: RelationDesc,
+    format: S3SinkFormat,
+    max_file_size: u64,
+    worker_id: usize,
+    /// The index of the file currently being written.
+    file_index: usize,
+    /// The file currently being written, if any.
+    file: Option<FileUpload>,
+}
+
+/// A file whose upload is in progress.
+struct FileUpload {
+    key: String,
+    /// The ID of the multipart upload of the file.
+    upload_id: String,
+    /// The parts of the file that have been uploaded.
+    parts: Vec<CompletedPart>,
+    encoder: FileEncoder,
+    /// The encoded bytes of the file that have not been uploaded yet.
+    pending: Vec<u8>,
+    /// The number of bytes of the file that have been uploaded.
+    uploaded_bytes: u64,
+}
+
+/// The encoder of the contents of a file.
+enum FileEncoder {
+    /// Rows are encoded directly into the pending bytes of the file.
+    Csv,
+    /// Rows are buffered into row groups, which are encoded once they are large enough.
+    Parquet(ParquetWriter),
+}
+
+impl S3Uploader {
+    fn new(client: S3Client, target: &S3Target, worker_id: usize) -> Self {
+        S3Uploader {
+            client,
+            desc: target.connection_details.desc.clone(),
+            format: target.connection_details.format,
+            max_file_size: target.connection_details.max_file_size,
+            worker_id,
+            file_index: 0,
+            file: None,
+        }
+    }
+
+    /// Appends `row` to the current file, uploading the parts of the file that are complete and
+    /// finishing the file if it has reached the maximum file size.
+    async fn append_row(&mut self, row: &Row) -> Result<(), String> {
+        if self.file.is_none() {
+            self.file = Some(self.start_file().await?);
+        }
+        let file = self.file.as_mut().expect("file started above");
+        let mut buffered_bytes = 0;
+        match &mut file.encoder {
+            FileEncoder::Csv => {
+                encode_copy_row_csv(row.clone(), self.desc.typ(), &mut file.pending)
+                    .map_err(|e| e.to_string())?
+            }
+            FileEncoder::Parquet(writer) => {
+                writer.push(row);
+                if writer.buffered_bytes() >= PART_SIZE {
+                    writer.flush_row_group().map_err(|e| e.to_string())?;
+                    file.pending.extend(writer.take_output());
+                }
+                buffered_bytes = writer.buffered_bytes();
+            }
+        }
+        if file.pending.len() >= PART_SIZE {
+            Self::upload_part(&self.client, file).await?;
+        }
+        let file_size = file.uploaded_bytes + u64::cast_from(file.pending.len() + buffered_bytes);
+        if file_size >= self.max_file_size {
+            self.finish_file().await?;
+        }
+        Ok(())
+    }
+
+    async fn start_file(&self) -> Result<FileUpload, String> {
+        let key = format!(
+            "{}part-{:04}-{:04}.{}",
+            self.client.key_prefix,
+            self.worker_id,
+            self.file_index,
+            self.format.file_extension(),
+        );
+        let mut pending = Vec::new();
+        let encoder = match self.format {
+            S3SinkFormat::Csv { header } => {
+                if header {
+                    let names =
+                        Row::pack(self.desc.iter_names().map(|n| Datum::String(n.as_str())));
+                    let typ = RelationType::new(
+                        self.desc
+                            .iter_names()
+                            .map(|_| ColumnType {
+                                scalar_type: mz_repr::ScalarType::String,
+                                nullable: false,
+                            })
+                            .collect(),
+                    );
+                    encode_copy_row_csv(names, &typ, &mut pending).map_err(|e| e.to_string())?;
+                }
+                FileEncoder::Csv
+            }
+            S3SinkFormat::Parquet => {
+                FileEncoder::Parquet(ParquetWriter::new(&self.desc).map_err(|e| e.to_string())?)
+            }
+        };
+        let upload = self
+            .client
+            .client
+            .create_multipart_upload()
+            .bucket(&self.client.bucket)
+            .key(&key)
+            .send()
+            .await
+            .map_err(|e| self.client.error("upload", &key, e))?;
+        let upload_id = upload
+            .upload_id()
+            .ok_or_else(|| format!("missing upload ID for s3://{}/{}", self.client.bucket, key))?
+            .to_string();
+        Ok(FileUpload {
+            key,
+            upload_id,
+            parts: Vec::new(),
+            encoder,
+            pending,
+            uploaded_bytes: 0,
+        })
+    }
+
+    /// Uploads the pending bytes of `file` as its next part.
+    async fn upload_part(client: &S3Client, file: &mut FileUpload) -> Result<(), String> {
+        let body = std::mem::take(&mut file.pending);
+        let size = u64::cast_from(body.len());
+        let part_number = i32::try_from(file.parts.len() + 1)
+            .map_err(|_| format!("too many parts for s3://{}/{}", client.bucket, file.key))?;
+        let part = client
+            .client
+            .upload_part()
+            .bucket(&client.bucket)
+            .key(&file.key)
+            .upload_id(&file.upload_id)
+            .part_number(part_number)
+            .body(ByteStream::from(body))
+            .send()
+            .await
+            .map_err(|e| client.error("upload", &file.key, e))?;
+        file.parts.push(
+            CompletedPart::builder()
+                .set_e_tag(part.e_tag().map(String::from))
+                .part_number(part_number)
+                .build(),
+        );
+        file.uploaded_bytes += size;
+        Ok(())
+    }
+
+    /// Uploads the remainder of the current file, if any, and completes its upload.
+    async fn finish_file(&mut self) -> Result<(), String> {
+        let Some(mut file) = self.file.take() else {
+            return Ok(());
+        };
+        if let FileEncoder::Parquet(writer) = std::mem::replace(&mut file.encoder, FileEncoder::Csv)
+        {
+            file.pending
+                .extend(writer.finish().map_err(|e| e.to_string())?);
+        }
+        if !file.pending.is_empty() || file.parts.is_empty() {
+            Self::upload_part(&self.client, &mut file).await?;
+        }
+        self.client
+            .client
+            .complete_multipart_upload()
+            .bucket(&self.client.bucket)
+            .key(&file.key)
+            .upload_id(&file.upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(file.parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(|e| self.client.error("upload", &file.key, e))?;
+        self.file_index += 1;
+        Ok(())
+    }
+
+    /// Aborts the upload of the current file, if any, so that S3 discards its parts.
+    async fn abort(&mut self) {
+        let Some(file) = self.file.take() else {
+            return;
+        };
+        let result = self
+            .client
+            .client
+            .abort_multipart_upload()
+            .bucket(&self.client.bucket)
+            .key(&file.key)
+            .upload_id(&file.upload_id)
+            .send()
+            .await;
+        if let Err(e) = result {
+            warn!(
+                "s3_oneshot_sink: {}",
+                self.client.error("abort the upload of", &file.key, e)
+            );
+        }
+    }
+}
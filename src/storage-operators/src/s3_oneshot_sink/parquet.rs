@@ -0,0 +1,390 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Apache Parquet encoding of rows, used by the S3 oneshot sink and the Iceberg sink.
+//!
+//! Columns whose type has a natural Arrow equivalent (booleans, integers, floats, strings, bytes,
+//! dates, times and timestamps) are written using that Arrow type. All other columns (e.g.
+//! `numeric`, `jsonb`, `interval` or lists) are written as UTF-8 strings, using the same text
+//! representation that `COPY ... TO STDOUT` produces.
+
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
+
+use arrow2::array::{
+    MutableArray, MutableBinaryArray, MutableBooleanArray, MutablePrimitiveArray, MutableUtf8Array,
+};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow2::io::parquet::write::{
+    to_parquet_type, CompressionOptions, Encoding, FileWriter, ParquetType, RowGroupIterator,
+    SchemaDescriptor, Version, WriteOptions,
+};
+use mz_repr::{Datum, RelationDesc, Row, ScalarType};
+
+/// Accumulates rows into Arrow columns and encodes them as a Parquet file.
+pub struct ParquetBuilder {
+    schema: Schema,
+    /// The field id of every column, if the file should record them.
+    field_ids: Option<Vec<i32>>,
+    scalar_types: Vec<ScalarType>,
+    columns: Vec<Column>,
+}
+
+/// A column under construction.
+enum Column {
+    Boolean(MutableBooleanArray),
+    Int16(MutablePrimitiveArray<i16>),
+    Int32(MutablePrimitiveArray<i32>),
+    Int64(MutablePrimitiveArray<i64>),
+    UInt16(MutablePrimitiveArray<u16>),
+    UInt32(MutablePrimitiveArray<u32>),
+    UInt64(MutablePrimitiveArray<u64>),
+    Float32(MutablePrimitiveArray<f32>),
+    Float64(MutablePrimitiveArray<f64>),
+    Utf8(MutableUtf8Array<i32>),
+    Binary(MutableBinaryArray<i32>),
+    /// Days since the Unix epoch.
+    Date32(MutablePrimitiveArray<i32>),
+    /// Microseconds since midnight.
+    Time64(MutablePrimitiveArray<i64>),
+    /// Microseconds since the Unix epoch.
+    Timestamp(MutablePrimitiveArray<i64>),
+    /// Columns without a native Arrow equivalent, encoded in their text representation.
+    Text(MutableUtf8Array<i32>),
+}
+
+impl Column {
+    fn new(scalar_type: &ScalarType) -> (Self, DataType) {
+        use ScalarType::*;
+        match scalar_type {
+            Bool => (
+                Column::Boolean(MutableBooleanArray::new()),
+                DataType::Boolean,
+            ),
+            Int16 => (Column::Int16(MutablePrimitiveArray::new()), DataType::Int16),
+            Int32 => (Column::Int32(MutablePrimitiveArray::new()), DataType::Int32),
+            Int64 => (Column::Int64(MutablePrimitiveArray::new()), DataType::Int64),
+            UInt16 => (
+                Column::UInt16(MutablePrimitiveArray::new()),
+                DataType::UInt16,
+            ),
+            UInt32 => (
+                Column::UInt32(MutablePrimitiveArray::new()),
+                DataType::UInt32,
+            ),
+            UInt64 => (
+                Column::UInt64(MutablePrimitiveArray::new()),
+                DataType::UInt64,
+            ),
+            Float32 => (
+                Column::Float32(MutablePrimitiveArray::new()),
+                DataType::Float32,
+            ),
+            Float64 => (
+                Column::Float64(MutablePrimitiveArray::new()),
+                DataType::Float64,
+            ),
+            String | VarChar { .. } | Char { .. } => {
+                (Column::Utf8(MutableUtf8Array::new()), DataType::Utf8)
+            }
+            Bytes => (Column::Binary(MutableBinaryArray::new()), DataType::Binary),
+            Date => {
+                let data_type = DataType::Date32;
+                let array = MutablePrimitiveArray::new().to(data_type.clone());
+                (Column::Date32(array), data_type)
+            }
+            Time => {
+                let data_type = DataType::Time64(TimeUnit::Microsecond);
+                let array = MutablePrimitiveArray::new().to(data_type.clone());
+                (Column::Time64(array), data_type)
+            }
+            Timestamp { .. } => {
+                let data_type = DataType::Timestamp(TimeUnit::Microsecond, None);
+                let array = MutablePrimitiveArray::new().to(data_type.clone());
+                (Column::Timestamp(array), data_type)
+            }
+            TimestampTz { .. } => {
+                let data_type = DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into()));
+                let array = MutablePrimitiveArray::new().to(data_type.clone());
+                (Column::Timestamp(array), data_type)
+            }
+            _ => (Column::Text(MutableUtf8Array::new()), DataType::Utf8),
+        }
+    }
+
+    fn push(&mut self, datum: Datum, scalar_type: &ScalarType) {
+        if datum.is_null() {
+            self.push_null();
+            return;
+        }
+        match self {
+            Column::Boolean(a) => a.push(Some(datum.unwrap_bool())),
+            Column::Int16(a) => a.push(Some(datum.unwrap_int16())),
+            Column::Int32(a) => a.push(Some(datum.unwrap_int32())),
+            Column::Int64(a) => a.push(Some(datum.unwrap_int64())),
+            Column::UInt16(a) => a.push(Some(datum.unwrap_uint16())),
+            Column::UInt32(a) => a.push(Some(datum.unwrap_uint32())),
+            Column::UInt64(a) => a.push(Some(datum.unwrap_uint64())),
+            Column::Float32(a) => a.push(Some(datum.unwrap_float32())),
+            Column::Float64(a) => a.push(Some(datum.unwrap_float64())),
+            Column::Utf8(a) => a.push(Some(datum.unwrap_str())),
+            Column::Binary(a) => a.push(Some(datum.unwrap_bytes())),
+            Column::Date32(a) => a.push(Some(datum.unwrap_date().unix_epoch_days())),
+            Column::Time64(a) => {
+                use chrono::Timelike;
+                let time = datum.unwrap_time();
+                let micros = i64::from(time.num_seconds_from_midnight()) * 1_000_000
+                    + i64::from(time.nanosecond() / 1_000);
+                a.push(Some(micros))
+            }
+            Column::Timestamp(a) => {
+                let micros = match datum {
+                    Datum::Timestamp(ts) => ts.timestamp_micros(),
+                    Datum::TimestampTz(ts) => ts.timestamp_micros(),
+                    _ => unreachable!("timestamp column with datum {datum:?}"),
+                };
+                a.push(Some(micros))
+            }
+            Column::Text(a) => {
+                let mut buf = bytes::BytesMut::new();
+                mz_pgrepr::Value::from_datum(datum, scalar_type)
+                    .expect("datum is not null")
+                    .encode_text(&mut buf);
+                let text = std::str::from_utf8(&buf).expect("text encoding is valid UTF-8");
+                a.push(Some(text))
+            }
+        }
+    }
+
+    fn push_null(&mut self) {
+        match self {
+            Column::Boolean(a) => a.push_null(),
+            Column::Int16(a) => a.push_null(),
+            Column::Int32(a) | Column::Date32(a) => a.push_null(),
+            Column::Int64(a) | Column::Time64(a) | Column::Timestamp(a) => a.push_null(),
+            Column::UInt16(a) => a.push_null(),
+            Column::UInt32(a) => a.push_null(),
+            Column::UInt64(a) => a.push_null(),
+            Column::Float32(a) => a.push_null(),
+            Column::Float64(a) => a.push_null(),
+            Column::Utf8(a) | Column::Text(a) => a.push_null(),
+            Column::Binary(a) => a.push_null(),
+        }
+    }
+
+    fn as_box(&mut self) -> Box<dyn arrow2::array::Array> {
+        match self {
+            Column::Boolean(a) => a.as_box(),
+            Column::Int16(a) => a.as_box(),
+            Column::Int32(a) | Column::Date32(a) => a.as_box(),
+            Column::Int64(a) | Column::Time64(a) | Column::Timestamp(a) => a.as_box(),
+            Column::UInt16(a) => a.as_box(),
+            Column::UInt32(a) => a.as_box(),
+            Column::UInt64(a) => a.as_box(),
+            Column::Float32(a) => a.as_box(),
+            Column::Float64(a) => a.as_box(),
+            Column::Utf8(a) | Column::Text(a) => a.as_box(),
+            Column::Binary(a) => a.as_box(),
+        }
+    }
+}
+
+impl ParquetBuilder {
+    pub fn new(desc: &RelationDesc) -> Self {
+        let mut fields = Vec::new();
+        let mut scalar_types = Vec::new();
+        let mut columns = Vec::new();
+        for (name, typ) in desc.iter() {
+            let (column, data_type) = Column::new(&typ.scalar_type);
+            fields.push(Field::new(name.as_str(), data_type, typ.nullable));
+            scalar_types.push(typ.scalar_type.clone());
+            columns.push(column);
+        }
+        ParquetBuilder {
+            schema: Schema::from(fields),
+            field_ids: None,
+            scalar_types,
+            columns,
+        }
+    }
+
+    /// Records `field_ids` as the field ids of the columns in the Parquet schema, as required by
+    /// table formats that resolve columns by id, like Iceberg.
+    pub fn with_field_ids(mut self, field_ids: Vec<i32>) -> Self {
+        assert_eq!(
+            field_ids.len(),
+            self.columns.len(),
+            "one field id per column"
+        );
+        self.field_ids = Some(field_ids);
+        self
+    }
+
+    /// Creates a writer for a file with the schema of this builder.
+    fn file_writer<W: Write>(&self, writer: W) -> Result<FileWriter<W>, arrow2::error::Error> {
+        let Some(field_ids) = &self.field_ids else {
+            return FileWriter::try_new(writer, self.schema.clone(), write_options());
+        };
+        let fields = self
+            .schema
+            .fields
+            .iter()
+            .zip(field_ids)
+            .map(|(field, id)| {
+                let mut typ = to_parquet_type(field)?;
+                match &mut typ {
+                    ParquetType::PrimitiveType(primitive) => primitive.field_info.id = Some(*id),
+                    ParquetType::GroupType { field_info, .. } => field_info.id = Some(*id),
+                }
+                Ok(typ)
+            })
+            .collect::<Result<_, arrow2::error::Error>>()?;
+        Ok(FileWriter::new_with_parquet_schema(
+            writer,
+            self.schema.clone(),
+            SchemaDescriptor::new("root".into(), fields),
+            write_options(),
+        ))
+    }
+
+    /// Appends `row` to the file under construction.
+    pub fn push(&mut self, row: &Row) {
+        for ((datum, column), scalar_type) in row
+            .iter()
+            .zip(self.columns.iter_mut())
+            .zip(self.scalar_types.iter())
+        {
+            column.push(datum, scalar_type);
+        }
+    }
+
+    /// Encodes all rows pushed so far into a Parquet file, resetting the builder.
+    pub fn finish(&mut self) -> Result<Vec<u8>, arrow2::error::Error> {
+        let mut buf = Vec::new();
+        let mut writer = self.file_writer(&mut buf)?;
+        self.write_row_group(&mut writer)?;
+        writer.end(None)?;
+
+        Ok(buf)
+    }
+
+    /// Writes all rows pushed so far to `writer` as a row group, resetting the builder.
+    fn write_row_group<W: Write>(
+        &mut self,
+        writer: &mut FileWriter<W>,
+    ) -> Result<(), arrow2::error::Error> {
+        let arrays = self.columns.iter_mut().map(|c| c.as_box()).collect();
+        let chunk = Chunk::try_new(arrays)?;
+
+        let encodings = self
+            .schema
+            .fields
+            .iter()
+            .map(|_| vec![Encoding::Plain])
+            .collect();
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(Ok(chunk)),
+            &self.schema,
+            write_options(),
+            encodings,
+        )?;
+        for group in row_groups {
+            writer.write(group?)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_options() -> WriteOptions {
+    WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None, // use default limit
+    }
+}
+
+/// Encodes rows as a Parquet file one row group at a time, so that the beginning of the file can
+/// be uploaded while the rest of it is still being written.
+pub struct ParquetWriter {
+    builder: ParquetBuilder,
+    writer: FileWriter<SharedBuffer>,
+    /// The encoded bytes that have not been taken yet.
+    output: SharedBuffer,
+    /// The number of rows in the current row group.
+    buffered_rows: usize,
+    /// The (approximate) size in bytes of the rows in the current row group.
+    buffered_bytes: usize,
+}
+
+impl ParquetWriter {
+    pub fn new(desc: &RelationDesc) -> Result<Self, arrow2::error::Error> {
+        let builder = ParquetBuilder::new(desc);
+        let output = SharedBuffer::default();
+        let writer = builder.file_writer(output.clone())?;
+        Ok(ParquetWriter {
+            builder,
+            writer,
+            output,
+            buffered_rows: 0,
+            buffered_bytes: 0,
+        })
+    }
+
+    /// Appends `row` to the current row group.
+    pub fn push(&mut self, row: &Row) {
+        self.builder.push(row);
+        self.buffered_rows += 1;
+        self.buffered_bytes += row.byte_len();
+    }
+
+    /// Returns the (approximate) size in bytes of the rows in the current row group.
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes
+    }
+
+    /// Encodes the current row group, if it has any rows, and starts a new one.
+    pub fn flush_row_group(&mut self) -> Result<(), arrow2::error::Error> {
+        if self.buffered_rows > 0 {
+            self.builder.write_row_group(&mut self.writer)?;
+            self.buffered_rows = 0;
+            self.buffered_bytes = 0;
+        }
+        Ok(())
+    }
+
+    /// Takes the bytes of the file that have been encoded so far.
+    pub fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(&mut *self.output.0.borrow_mut())
+    }
+
+    /// Encodes the last row group and the footer of the file, returning the remaining bytes of
+    /// the file.
+    pub fn finish(mut self) -> Result<Vec<u8>, arrow2::error::Error> {
+        self.flush_row_group()?;
+        self.writer.end(None)?;
+        Ok(self.take_output())
+    }
+}
+
+/// A [`Write`] whose contents can be taken while a [`FileWriter`] holds on to it.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
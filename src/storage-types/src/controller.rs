@@ -18,8 +18,8 @@ use mz_persist_types::codec_impls::UnitSchema;
 use mz_persist_types::columnar::Data;
 use mz_persist_types::dyn_struct::DynStruct;
 use mz_persist_types::stats::StructStats;
-use mz_proto::{IntoRustIfSome, RustType, TryFromProtoError};
-use mz_repr::{Datum, GlobalId, RelationDesc, Row, ScalarType};
+use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
+use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, Row, ScalarType};
 use mz_stash_types::StashError;
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
@@ -47,6 +47,10 @@ pub struct CollectionMetadata {
     /// The shard id of the persist-txn shard, if `self.data_shard` is managed
     /// by the persist-txn system, or None if it's not.
     pub txns_shard: Option<ShardId>,
+    /// How the rows of `self.data_shard` map to `self.relation_desc`, if this
+    /// is a table whose columns have been altered, or None if the rows are
+    /// described by `self.relation_desc` directly.
+    pub table_layout: Option<TableLayout>,
 }
 
 impl crate::AlterCompatible for CollectionMetadata {
@@ -69,6 +73,7 @@ impl crate::AlterCompatible for CollectionMetadata {
             status_shard,
             relation_desc,
             txns_shard,
+            table_layout,
         } = self;
 
         let compatibility_checks = [
@@ -77,6 +82,7 @@ impl crate::AlterCompatible for CollectionMetadata {
             (status_shard == &other.status_shard, "status_shard"),
             (relation_desc == &other.relation_desc, "relation_desc"),
             (txns_shard == &other.txns_shard, "txns_shard"),
+            (table_layout == &other.table_layout, "table_layout"),
         ];
 
         for (compatible, field) in compatibility_checks {
@@ -105,6 +111,7 @@ impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
             status_shard: self.status_shard.map(|s| s.to_string()),
             relation_desc: Some(self.relation_desc.into_proto()),
             txns_shard: self.txns_shard.map(|x| x.to_string()),
+            table_layout: self.table_layout.into_proto(),
         }
     }
 
@@ -133,6 +140,162 @@ impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
                 .txns_shard
                 .map(|s| s.parse().map_err(TryFromProtoError::InvalidShardId))
                 .transpose()?,
+            table_layout: value.table_layout.into_rust()?,
+        })
+    }
+}
+
+/// The columns stored in the shard of a table, and how they map to the table's
+/// current columns.
+///
+/// Altering a table's columns does not rewrite the rows in its shard. Instead,
+/// the shard stores every column the table has had, in the order in which they
+/// were added, including columns that have since been dropped. Rows written
+/// before a column was added lack that column and all columns added after it,
+/// and rows written after a reader's layout was determined may contain columns
+/// that the reader does not know about.
+#[derive(Arbitrary, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TableLayout {
+    /// The columns stored in the shard.
+    pub columns: Vec<StoredColumn>,
+}
+
+/// A column stored in the shard of a table. See [`TableLayout`].
+#[derive(Arbitrary, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct StoredColumn {
+    /// The name of the column.
+    pub name: ColumnName,
+    /// The type of the column.
+    pub typ: ColumnType,
+    /// Whether the column has been dropped from the table.
+    pub dropped: bool,
+    /// If the column was added to an existing table, a row containing the
+    /// value of the column in the rows that were written before it was added.
+    pub fill: Option<Row>,
+}
+
+impl TableLayout {
+    /// Returns the layout of a table with the columns described by `desc`,
+    /// which have never been altered.
+    pub fn new(desc: &RelationDesc) -> TableLayout {
+        let columns = desc
+            .iter()
+            .map(|(name, typ)| StoredColumn {
+                name: name.clone(),
+                typ: typ.clone(),
+                dropped: false,
+                fill: None,
+            })
+            .collect();
+        TableLayout { columns }
+    }
+
+    /// Returns the description of the rows stored in the table's shard.
+    ///
+    /// The table's shard contains `NULL`s in the columns that have been
+    /// dropped from the table.
+    pub fn stored_desc(&self) -> RelationDesc {
+        RelationDesc::from_names_and_types(self.columns.iter().map(|column| {
+            let typ = column.typ.clone();
+            (
+                column.name.clone(),
+                typ.nullable(column.typ.nullable || column.dropped),
+            )
+        }))
+    }
+
+    /// Returns the datums of the table's current columns in `row`, a row
+    /// stored in the table's shard.
+    pub fn datums<'a>(&'a self, row: &'a Row) -> impl Iterator<Item = Datum<'a>> + 'a {
+        let mut stored = row.iter();
+        self.columns.iter().filter_map(move |column| {
+            let datum = stored
+                .next()
+                .unwrap_or_else(|| column.fill.as_ref().map_or(Datum::Null, Row::unpack_first));
+            (!column.dropped).then_some(datum)
+        })
+    }
+
+    /// Returns the row of the table's current columns for `row`, a row stored
+    /// in the table's shard, using `buf` to assemble it if necessary.
+    pub fn decode(&self, row: Row, buf: &mut Row) -> Row {
+        let mut stored = row.iter();
+        let is_decoded = self
+            .columns
+            .iter()
+            .all(|column| !column.dropped && stored.next().is_some())
+            && stored.next().is_none();
+        if is_decoded {
+            return row;
+        }
+        buf.packer().extend(self.datums(&row));
+        buf.clone()
+    }
+
+    /// Returns the row to store in the table's shard for `row`, a row of the
+    /// table's current columns.
+    pub fn encode(&self, row: &Row) -> Row {
+        let mut datums = row.iter();
+        Row::pack(self.columns.iter().map(|column| {
+            if column.dropped {
+                Datum::Null
+            } else {
+                datums.next().expect("row has a datum for each column")
+            }
+        }))
+    }
+
+    /// Reports whether `other` describes the columns of this layout, followed
+    /// by zero or more new columns, and drops at least the columns that this
+    /// layout drops.
+    ///
+    /// Columns may be renamed when they are dropped.
+    pub fn is_extended_by(&self, other: &TableLayout) -> bool {
+        self.columns.len() <= other.columns.len()
+            && self
+                .columns
+                .iter()
+                .zip(other.columns.iter())
+                .all(|(column, other)| {
+                    let is_dropped = !column.dropped && other.dropped;
+                    (column.name == other.name || is_dropped)
+                        && column.typ == other.typ
+                        && column.fill == other.fill
+                        && (!column.dropped || other.dropped)
+                })
+    }
+}
+
+impl RustType<ProtoTableLayout> for TableLayout {
+    fn into_proto(&self) -> ProtoTableLayout {
+        ProtoTableLayout {
+            columns: self.columns.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoTableLayout) -> Result<Self, TryFromProtoError> {
+        Ok(TableLayout {
+            columns: proto.columns.into_rust()?,
+        })
+    }
+}
+
+impl RustType<ProtoStoredColumn> for StoredColumn {
+    fn into_proto(&self) -> ProtoStoredColumn {
+        ProtoStoredColumn {
+            name: Some(self.name.into_proto()),
+            typ: Some(self.typ.into_proto()),
+            dropped: self.dropped,
+            fill: self.fill.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoStoredColumn) -> Result<Self, TryFromProtoError> {
+        Ok(StoredColumn {
+            name: proto.name.into_rust_if_some("ProtoStoredColumn::name")?,
+            typ: proto.typ.into_rust_if_some("ProtoStoredColumn::typ")?,
+            dropped: proto.dropped,
+            fill: proto.fill.into_rust()?,
         })
     }
 }
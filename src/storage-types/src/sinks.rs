@@ -11,10 +11,11 @@
 
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::time::Duration;
 
 use mz_persist_client::ShardId;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
-use mz_repr::{GlobalId, RelationDesc};
+use mz_repr::{GlobalId, RelationDesc, ScalarType};
 use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
@@ -237,6 +238,9 @@ impl RustType<ProtoSinkEnvelope> for SinkEnvelope {
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum StorageSinkConnection<C: ConnectionAccess = InlinedConnection> {
     Kafka(KafkaSinkConnection<C>),
+    Postgres(PostgresSinkConnection<C>),
+    Webhook(WebhookSinkConnection),
+    Iceberg(IcebergSinkConnection<C>),
 }
 
 impl<C: ConnectionAccess> StorageSinkConnection<C> {
@@ -256,6 +260,23 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
             (StorageSinkConnection::Kafka(s), StorageSinkConnection::Kafka(o)) => {
                 s.alter_compatible(id, o)?
             }
+            (StorageSinkConnection::Postgres(s), StorageSinkConnection::Postgres(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::Webhook(s), StorageSinkConnection::Webhook(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::Iceberg(s), StorageSinkConnection::Iceberg(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            _ => {
+                tracing::warn!(
+                    "StorageSinkConnection incompatible:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+                return Err(StorageError::InvalidAlter { id });
+            }
         }
 
         Ok(())
@@ -268,6 +289,9 @@ impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnection, R>
     fn into_inline_connection(self, r: R) -> StorageSinkConnection {
         match self {
             Self::Kafka(conn) => StorageSinkConnection::Kafka(conn.into_inline_connection(r)),
+            Self::Postgres(conn) => StorageSinkConnection::Postgres(conn.into_inline_connection(r)),
+            Self::Webhook(conn) => StorageSinkConnection::Webhook(conn),
+            Self::Iceberg(conn) => StorageSinkConnection::Iceberg(conn.into_inline_connection(r)),
         }
     }
 }
@@ -279,6 +303,9 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
         ProtoStorageSinkConnection {
             kind: Some(match self {
                 Self::Kafka(conn) => KafkaV2(conn.into_proto()),
+                Self::Postgres(conn) => Postgres(conn.into_proto()),
+                Self::Webhook(conn) => Webhook(conn.into_proto()),
+                Self::Iceberg(conn) => Iceberg(conn.into_proto()),
             }),
         }
     }
@@ -291,6 +318,9 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
 
         Ok(match kind {
             KafkaV2(proto) => Self::Kafka(proto.into_rust()?),
+            Postgres(proto) => Self::Postgres(proto.into_rust()?),
+            Webhook(proto) => Self::Webhook(proto.into_rust()?),
+            Iceberg(proto) => Self::Iceberg(proto.into_rust()?),
         })
     }
 }
@@ -301,6 +331,11 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
         use StorageSinkConnection::*;
         match self {
             Kafka(KafkaSinkConnection { connection_id, .. }) => Some(*connection_id),
+            Postgres(PostgresSinkConnection { connection_id, .. }) => Some(*connection_id),
+            Iceberg(IcebergSinkConnection {
+                aws_connection_id, ..
+            }) => Some(*aws_connection_id),
+            Webhook(_) => None,
         }
     }
 
@@ -309,6 +344,9 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
         use StorageSinkConnection::*;
         match self {
             Kafka(_) => "kafka",
+            Postgres(_) => "postgres",
+            Webhook(_) => "webhook",
+            Iceberg(_) => "iceberg",
         }
     }
 }
@@ -653,6 +691,10 @@ pub enum KafkaSinkFormat<C: ConnectionAccess = InlinedConnection> {
         csr_connection: C::Csr,
     },
     Json,
+    /// The only column of the key and of the value, in its text representation.
+    Text,
+    /// The only column of the key and of the value, which must be of type `bytea`, as is.
+    Bytes,
 }
 
 impl<C: ConnectionAccess> KafkaSinkFormat<C> {
@@ -660,6 +702,8 @@ impl<C: ConnectionAccess> KafkaSinkFormat<C> {
         match self {
             Self::Avro { .. } => "avro",
             Self::Json => "json",
+            Self::Text => "text",
+            Self::Bytes => "bytes",
         }
     }
 
@@ -729,6 +773,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkFormat, R>
                 csr_connection: r.resolve_connection(csr_connection).unwrap_csr(),
             },
             Self::Json => KafkaSinkFormat::Json,
+            Self::Text => KafkaSinkFormat::Text,
+            Self::Bytes => KafkaSinkFormat::Bytes,
         }
     }
 }
@@ -748,6 +794,8 @@ impl RustType<ProtoKafkaSinkFormat> for KafkaSinkFormat {
                     csr_connection: Some(csr_connection.into_proto()),
                 }),
                 Self::Json => Kind::Json(()),
+                Self::Text => Kind::Text(()),
+                Self::Bytes => Kind::Bytes(()),
             }),
         }
     }
@@ -767,6 +815,536 @@ impl RustType<ProtoKafkaSinkFormat> for KafkaSinkFormat {
                     .into_rust_if_some("ProtoKafkaSinkAvroFormat::csr_connection")?,
             },
             Kind::Json(()) => Self::Json,
+            Kind::Text(()) => Self::Text,
+            Kind::Bytes(()) => Self::Bytes,
+        })
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: GlobalId,
+    pub connection: C::Pg,
+    /// The upstream table that is kept in sync with the sinked relation.
+    pub table: PostgresTableName,
+    /// The upstream table in which the sink records the frontier up to which it
+    /// has committed updates.
+    pub progress_table: PostgresTableName,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub value_desc: RelationDesc,
+}
+
+impl<C: ConnectionAccess> PostgresSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(
+        &self,
+        id: GlobalId,
+        other: &PostgresSinkConnection<C>,
+    ) -> Result<(), StorageError> {
+        if self == other {
+            return Ok(());
+        }
+        let PostgresSinkConnection {
+            connection_id,
+            // The details of the Postgres connection itself may change
+            connection: _,
+            table,
+            progress_table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (table == &other.table, "table"),
+            (progress_table == &other.progress_table, "progress_table"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "PostgresSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(StorageError::InvalidAlter { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<PostgresSinkConnection, R>
+    for PostgresSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> PostgresSinkConnection {
+        let PostgresSinkConnection {
+            connection_id,
+            connection,
+            table,
+            progress_table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        } = self;
+        PostgresSinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_pg(),
+            table,
+            progress_table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        }
+    }
+}
+
+impl RustType<ProtoPostgresSinkConnection> for PostgresSinkConnection {
+    fn into_proto(&self) -> ProtoPostgresSinkConnection {
+        ProtoPostgresSinkConnection {
+            connection_id: Some(self.connection_id.into_proto()),
+            connection: Some(self.connection.into_proto()),
+            table: Some(self.table.into_proto()),
+            progress_table: Some(self.progress_table.into_proto()),
+            key_desc_and_indices: self.key_desc_and_indices.into_proto(),
+            relation_key_indices: self.relation_key_indices.into_proto(),
+            value_desc: Some(self.value_desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(PostgresSinkConnection {
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoPostgresSinkConnection::connection_id")?,
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoPostgresSinkConnection::connection")?,
+            table: proto
+                .table
+                .into_rust_if_some("ProtoPostgresSinkConnection::table")?,
+            progress_table: proto
+                .progress_table
+                .into_rust_if_some("ProtoPostgresSinkConnection::progress_table")?,
+            key_desc_and_indices: proto.key_desc_and_indices.into_rust()?,
+            relation_key_indices: proto.relation_key_indices.into_rust()?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoPostgresSinkConnection::value_desc")?,
+        })
+    }
+}
+
+/// A schema-qualified table name in an upstream PostgreSQL database.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresTableName {
+    pub schema: String,
+    pub name: String,
+}
+
+impl RustType<ProtoPostgresTableName> for PostgresTableName {
+    fn into_proto(&self) -> ProtoPostgresTableName {
+        ProtoPostgresTableName {
+            schema: self.schema.clone(),
+            name: self.name.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresTableName) -> Result<Self, TryFromProtoError> {
+        Ok(PostgresTableName {
+            schema: proto.schema,
+            name: proto.name,
+        })
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WebhookSinkConnection {
+    /// The endpoint to which batches are POSTed.
+    pub url: String,
+    /// The secret used to sign each request with an HMAC, if any.
+    pub signing_secret: Option<GlobalId>,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub value_desc: RelationDesc,
+    /// The persist shard whose upper records the frontier up to which the
+    /// sink has delivered all batches.
+    ///
+    /// This is allocated by the storage controller when it runs the sink, and
+    /// is `None` in the descriptions it receives from the adapter.
+    pub progress_shard: Option<ShardId>,
+}
+
+impl WebhookSinkConnection {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(
+        &self,
+        id: GlobalId,
+        other: &WebhookSinkConnection,
+    ) -> Result<(), StorageError> {
+        if self == other {
+            return Ok(());
+        }
+        let WebhookSinkConnection {
+            url,
+            // The secret may be rotated
+            signing_secret: _,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+            progress_shard,
+        } = self;
+
+        let compatibility_checks = [
+            (url == &other.url, "url"),
+            (progress_shard == &other.progress_shard, "progress_shard"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "WebhookSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(StorageError::InvalidAlter { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RustType<ProtoWebhookSinkConnection> for WebhookSinkConnection {
+    fn into_proto(&self) -> ProtoWebhookSinkConnection {
+        ProtoWebhookSinkConnection {
+            url: self.url.clone(),
+            signing_secret: self.signing_secret.into_proto(),
+            key_desc_and_indices: self.key_desc_and_indices.into_proto(),
+            relation_key_indices: self.relation_key_indices.into_proto(),
+            value_desc: Some(self.value_desc.into_proto()),
+            progress_shard: self.progress_shard.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoWebhookSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(WebhookSinkConnection {
+            url: proto.url,
+            signing_secret: proto.signing_secret.into_rust()?,
+            key_desc_and_indices: proto.key_desc_and_indices.into_rust()?,
+            relation_key_indices: proto.relation_key_indices.into_rust()?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoWebhookSinkConnection::value_desc")?,
+            progress_shard: proto.progress_shard.into_rust()?,
+        })
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IcebergSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    /// The AWS connection used to access the bucket of the table.
+    pub aws_connection_id: GlobalId,
+    pub aws_connection: C::Aws,
+    /// The location of the Iceberg table, as an `s3://bucket/prefix` URI. The
+    /// table is created if it does not exist yet.
+    ///
+    /// Object stores cannot atomically create the next metadata version, so
+    /// the sink must only ever run on a single replica.
+    pub path: String,
+    /// How often the sink commits a new snapshot to the table.
+    pub commit_interval: Duration,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub value_desc: RelationDesc,
+    /// The persist shard whose upper records the frontier up to which the
+    /// sink has committed all updates to the table.
+    ///
+    /// This is allocated by the storage controller when it runs the sink, and
+    /// is `None` in the descriptions it receives from the adapter.
+    pub progress_shard: Option<ShardId>,
+}
+
+impl IcebergSinkConnection {
+    /// Returns the Iceberg type with which a column of type `scalar_type` is
+    /// stored, or `None` if such columns cannot be sinked to Iceberg.
+    ///
+    /// Types without an Iceberg equivalent are stored as strings in their text
+    /// representation, like Parquet files written by `COPY ... TO`.
+    pub fn iceberg_type(scalar_type: &ScalarType) -> Option<&'static str> {
+        use ScalarType::*;
+        Some(match scalar_type {
+            Bool => "boolean",
+            Int16 | Int32 | UInt16 => "int",
+            Int64 => "long",
+            // Iceberg has no unsigned integers, and the Parquet encoding of
+            // these types cannot be read as a wider signed type.
+            UInt32 | UInt64 => return None,
+            Float32 => "float",
+            Float64 => "double",
+            Bytes => "binary",
+            Date => "date",
+            Time => "time",
+            Timestamp { .. } => "timestamp",
+            TimestampTz { .. } => "timestamptz",
+            _ => "string",
+        })
+    }
+}
+
+impl<C: ConnectionAccess> IcebergSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(
+        &self,
+        id: GlobalId,
+        other: &IcebergSinkConnection<C>,
+    ) -> Result<(), StorageError> {
+        if self == other {
+            return Ok(());
+        }
+        let IcebergSinkConnection {
+            aws_connection_id,
+            // The details of the AWS connection itself may change
+            aws_connection: _,
+            path,
+            // The commit interval does not affect the contents of the table
+            commit_interval: _,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+            progress_shard,
+        } = self;
+
+        let compatibility_checks = [
+            (
+                aws_connection_id == &other.aws_connection_id,
+                "aws_connection_id",
+            ),
+            (path == &other.path, "path"),
+            (progress_shard == &other.progress_shard, "progress_shard"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "IcebergSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(StorageError::InvalidAlter { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<IcebergSinkConnection, R>
+    for IcebergSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> IcebergSinkConnection {
+        let IcebergSinkConnection {
+            aws_connection_id,
+            aws_connection,
+            path,
+            commit_interval,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+            progress_shard,
+        } = self;
+        IcebergSinkConnection {
+            aws_connection_id,
+            aws_connection: r.resolve_connection(aws_connection).unwrap_aws(),
+            path,
+            commit_interval,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+            progress_shard,
+        }
+    }
+}
+
+impl RustType<ProtoIcebergSinkConnection> for IcebergSinkConnection {
+    fn into_proto(&self) -> ProtoIcebergSinkConnection {
+        ProtoIcebergSinkConnection {
+            aws_connection_id: Some(self.aws_connection_id.into_proto()),
+            aws_connection: Some(self.aws_connection.into_proto()),
+            path: self.path.clone(),
+            commit_interval: Some(self.commit_interval.into_proto()),
+            key_desc_and_indices: self.key_desc_and_indices.into_proto(),
+            relation_key_indices: self.relation_key_indices.into_proto(),
+            value_desc: Some(self.value_desc.into_proto()),
+            progress_shard: self.progress_shard.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoIcebergSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(IcebergSinkConnection {
+            aws_connection_id: proto
+                .aws_connection_id
+                .into_rust_if_some("ProtoIcebergSinkConnection::aws_connection_id")?,
+            aws_connection: proto
+                .aws_connection
+                .into_rust_if_some("ProtoIcebergSinkConnection::aws_connection")?,
+            path: proto.path,
+            commit_interval: proto
+                .commit_interval
+                .into_rust_if_some("ProtoIcebergSinkConnection::commit_interval")?,
+            key_desc_and_indices: proto.key_desc_and_indices.into_rust()?,
+            relation_key_indices: proto.relation_key_indices.into_rust()?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoIcebergSinkConnection::value_desc")?,
+            progress_shard: proto.progress_shard.into_rust()?,
+        })
+    }
+}
+
+/// Describes the objects a one-shot `COPY ... TO` export writes to S3.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct S3UploadInfo {
+    /// The S3 URI (`s3://bucket/prefix`) under which the files are written.
+    pub uri: String,
+    /// The maximum size in bytes of each written file. Rows are never split
+    /// across files, so a single row larger than this limit yields a file that
+    /// exceeds it.
+    pub max_file_size: u64,
+    /// The relation being exported.
+    pub desc: RelationDesc,
+    /// The encoding of the written files.
+    pub format: S3SinkFormat,
+}
+
+impl S3UploadInfo {
+    /// Splits an `s3://bucket/prefix` URI into its bucket and key prefix.
+    ///
+    /// The returned key prefix is either empty or ends in a `/`, so that object names can be
+    /// appended to it directly.
+    pub fn parse_uri(uri: &str) -> Result<(String, String), anyhow::Error> {
+        let url = url::Url::parse(uri)?;
+        if url.scheme() != "s3" {
+            anyhow::bail!("only 's3://...' urls are supported, got {}", uri);
+        }
+        let bucket = match url.host_str() {
+            Some(bucket) if !bucket.is_empty() => bucket.to_string(),
+            _ => anyhow::bail!("missing bucket name in {}", uri),
+        };
+        let mut prefix = url.path().trim_start_matches('/').to_string();
+        if !prefix.is_empty() && !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+        Ok((bucket, prefix))
+    }
+}
+
+impl RustType<ProtoS3UploadInfo> for S3UploadInfo {
+    fn into_proto(&self) -> ProtoS3UploadInfo {
+        ProtoS3UploadInfo {
+            uri: self.uri.clone(),
+            max_file_size: self.max_file_size,
+            desc: Some(self.desc.into_proto()),
+            format: Some(self.format.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoS3UploadInfo) -> Result<Self, TryFromProtoError> {
+        Ok(S3UploadInfo {
+            uri: proto.uri,
+            max_file_size: proto.max_file_size,
+            desc: proto.desc.into_rust_if_some("ProtoS3UploadInfo::desc")?,
+            format: proto
+                .format
+                .into_rust_if_some("ProtoS3UploadInfo::format")?,
+        })
+    }
+}
+
+/// The file format of a one-shot S3 export.
+#[derive(Arbitrary, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum S3SinkFormat {
+    /// PostgreSQL `COPY` CSV with the default parameters, optionally starting
+    /// each file with a header line of column names.
+    Csv { header: bool },
+    /// Apache Parquet.
+    Parquet,
+}
+
+impl S3SinkFormat {
+    /// The file extension used for objects written in this format.
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            S3SinkFormat::Csv { .. } => "csv",
+            S3SinkFormat::Parquet => "parquet",
+        }
+    }
+}
+
+impl RustType<ProtoS3SinkFormat> for S3SinkFormat {
+    fn into_proto(&self) -> ProtoS3SinkFormat {
+        use proto_s3_sink_format::{Kind, ProtoS3SinkCsvFormat};
+        ProtoS3SinkFormat {
+            kind: Some(match self {
+                S3SinkFormat::Csv { header } => Kind::Csv(ProtoS3SinkCsvFormat { header: *header }),
+                S3SinkFormat::Parquet => Kind::Parquet(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoS3SinkFormat) -> Result<Self, TryFromProtoError> {
+        use proto_s3_sink_format::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoS3SinkFormat::kind"))?;
+        Ok(match kind {
+            Kind::Csv(csv) => S3SinkFormat::Csv { header: csv.header },
+            Kind::Parquet(()) => S3SinkFormat::Parquet,
         })
     }
 }
@@ -54,6 +54,8 @@ pub enum StatusNamespace {
     Kafka,
     Postgres,
     MySql,
+    Webhook,
+    Iceberg,
     Ssh,
     Upsert,
     Decode,
@@ -75,6 +77,8 @@ impl fmt::Display for StatusNamespace {
             Kafka => write!(f, "kafka"),
             Postgres => write!(f, "postgres"),
             MySql => write!(f, "mysql"),
+            Webhook => write!(f, "webhook"),
+            Iceberg => write!(f, "iceberg"),
             Ssh => write!(f, "ssh"),
             Upsert => write!(f, "upsert"),
             Decode => write!(f, "decode"),
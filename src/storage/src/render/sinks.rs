@@ -244,5 +244,8 @@ where
 {
     match connection {
         StorageSinkConnection::Kafka(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Postgres(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Webhook(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Iceberg(connection) => Box::new(connection.clone()),
     }
 }
@@ -0,0 +1,595 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of an [`IcebergSinkConnection`]. The dataflow consists of a
+//! single operator that commits the updates of closed timestamps to an Apache Iceberg table.
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ keyed updates, the input to this module
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃   iceberg   ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━━━━━┯━━━━━━┛
+//!               │ one snapshot per commit interval
+//!        ╭──────v──────╮
+//!        │    table    │
+//!        ╰─────────────╯
+//! ```
+//!
+//! # Table layout
+//!
+//! The table lives in S3, at the location given by the `PATH` of the sink, and is managed by the
+//! file-based catalog in [`table`], so that it can be read by any engine that supports Iceberg's
+//! Hadoop catalog, or by pointing a reader directly at its latest metadata file. The sink creates
+//! the table, unpartitioned and in format version 2, if it does not exist yet, and otherwise
+//! requires its schema to match the sinked relation. Columns are stored with the types returned
+//! by [`IcebergSinkConnection::iceberg_type`], and the Parquet files record the field id of every
+//! column.
+//!
+//! S3 cannot reject a metadata version that another writer has already created, so only a single
+//! replica may ever commit to the table. The planner therefore rejects ICEBERG sinks in clusters
+//! with more than one replica, as well as adding replicas to a cluster that runs an ICEBERG sink.
+//!
+//! # Commits
+//!
+//! Updates are buffered until the input frontier advances past their timestamp. Once per commit
+//! interval, the sink commits all buffered updates of closed timestamps as a single snapshot,
+//! which contains:
+//!
+//!   * a Parquet equality delete file on the key columns, holding the key of every updated row,
+//!     and
+//!   * a Parquet data file holding the latest value of every updated row that was not deleted.
+//!
+//! As the deletes of a snapshot only apply to data files with a lower sequence number, this
+//! replaces the previous value of every updated key with its latest value.
+//!
+//! # Progress
+//!
+//! Every commit records the frontier up to which the table reflects the input in the
+//! `materialize.sink.<id>.frontier` table property, even if no updates were committed. The sink
+//! reads back this frontier when it starts up and resumes right after it, so that no timestamp
+//! is committed twice across restarts.
+//!
+//! After every commit, the sink also records the frontier in its progress shard, so that
+//! Materialize knows how far the table must have gotten independently of the table itself. If the
+//! metadata of the table is missing when the sink starts up, or records a frontier that is behind
+//! the progress shard, the table has been removed or rolled back outside of Materialize and the
+//! sink fails instead of recreating the table and committing the full snapshot to it again.
+//!
+//! # Error handling
+//!
+//! Any error while writing files or committing metadata is a transient error that is wired up
+//! with our health monitoring and will trigger a restart of the sink dataflow, which reloads the
+//! table.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{anyhow, bail, Context};
+use differential_dataflow::{Collection, Hashable};
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::vec::VecExt;
+use mz_persist_client::cache::PersistClientCache;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_storage_operators::s3_oneshot_sink::parquet::ParquetBuilder;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{IcebergSinkConnection, MetadataFilled, StorageSinkDesc};
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use rand::Rng;
+use serde_json::json;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::{Concatenate, Map, ToStream};
+use timely::dataflow::{Scope, Stream};
+use timely::progress::{Antichain, Timestamp as _};
+use timely::PartialOrder;
+use uuid::Uuid;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::sink::iceberg::manifest::{DataFile, FileContent, ManifestFile};
+use crate::sink::iceberg::table::{ObjectStore, SchemaField, Snapshot, Table, TableSchema};
+use crate::sink::ProgressRecorder;
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+mod manifest;
+mod table;
+
+impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for IcebergSinkConnection {
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        input: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        // TODO(benesch): errors should stream out through the sink,
+        // if we figure out a protocol for that.
+        _err_collection: Collection<G, DataflowError, Diff>,
+    ) -> (Stream<G, HealthStatusMessage>, Vec<PressOnDropButton>) {
+        let mut scope = input.scope();
+
+        let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, Rc::clone(&write_frontier));
+
+        let (sink_status, sink_token) = sink_collection(
+            format!("iceberg-{sink_id}-sink"),
+            &input,
+            sink_id,
+            self.clone(),
+            storage_state.storage_configuration.clone(),
+            Arc::clone(&storage_state.persist_clients),
+            sink.from_storage_metadata.clone(),
+            sink.as_of.clone(),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            write_frontier,
+        );
+
+        let running_status = Some(HealthStatusMessage {
+            index: 0,
+            update: HealthStatusUpdate::Running,
+            namespace: StatusNamespace::Iceberg,
+        })
+        .to_stream(&mut scope);
+
+        let status = scope.concatenate([running_status, sink_status]);
+
+        (status, vec![sink_token])
+    }
+}
+
+/// Returns the Iceberg schema of the table an [`IcebergSinkConnection`] writes to.
+///
+/// The field id of every column is its position in the relation, starting at 1. The key columns
+/// are the identifier fields of the schema, unless one of them is nullable.
+fn table_schema(connection: &IcebergSinkConnection) -> TableSchema {
+    let fields = connection
+        .value_desc
+        .iter()
+        .zip(1..)
+        .map(|((name, typ), id)| SchemaField {
+            id,
+            name: name.to_string(),
+            required: !typ.nullable,
+            typ: IcebergSinkConnection::iceberg_type(&typ.scalar_type)
+                .expect("validated during planning")
+                .into(),
+            other: BTreeMap::new(),
+        })
+        .collect::<Vec<_>>();
+    let identifier_field_ids = match &connection.key_desc_and_indices {
+        Some((key_desc, indices)) if key_desc.iter().all(|(_, typ)| !typ.nullable) => {
+            field_ids(indices)
+        }
+        _ => vec![],
+    };
+    TableSchema {
+        typ: "struct".into(),
+        schema_id: 0,
+        identifier_field_ids,
+        fields,
+    }
+}
+
+/// Returns the field ids of the columns at `indices`.
+fn field_ids(indices: &[usize]) -> Vec<i32> {
+    indices
+        .iter()
+        .map(|i| i32::try_from(*i + 1).expect("column count fits in i32"))
+        .collect()
+}
+
+/// The table property that records the progress of the sink `sink_id`.
+fn progress_property(sink_id: GlobalId) -> String {
+    format!("materialize.sink.{sink_id}.frontier")
+}
+
+/// Encodes a Parquet file with the given rows, whose columns have the given field ids, returning
+/// it and the number of rows it holds.
+fn encode_parquet<'a>(
+    desc: &RelationDesc,
+    field_ids: Vec<i32>,
+    rows: impl Iterator<Item = &'a Row>,
+) -> Result<(Vec<u8>, i64), anyhow::Error> {
+    let mut builder = ParquetBuilder::new(desc).with_field_ids(field_ids);
+    let mut count = 0;
+    for row in rows {
+        builder.push(row);
+        count += 1;
+    }
+    let bytes = builder.finish().context("encoding Parquet file")?;
+    Ok((bytes, count))
+}
+
+/// Commits the latest value of every key in `updates` to `table` as a new snapshot, together
+/// with `frontier` as the progress of the sink.
+///
+/// If `updates` is empty, only the progress of the sink is committed.
+async fn commit(
+    table: &mut Table,
+    connection: &IcebergSinkConnection,
+    sink_id: GlobalId,
+    updates: BTreeMap<Row, Option<Row>>,
+    frontier: &Antichain<Timestamp>,
+    statistics: &SinkStatistics,
+) -> Result<(), anyhow::Error> {
+    let mut metadata = table.metadata.clone();
+    metadata.properties.insert(
+        progress_property(sink_id),
+        json!(frontier.elements()).to_string(),
+    );
+
+    if updates.is_empty() {
+        return table.commit(metadata).await;
+    }
+
+    let (key_desc, key_indices) = connection
+        .key_desc_and_indices
+        .as_ref()
+        .expect("ICEBERG sinks require a key");
+    let messages = u64::cast_from(updates.len());
+    let schema = serde_json::to_string(metadata.current_schema()?).expect("infallible");
+    let snapshot_id = rand::thread_rng().gen_range(1..i64::MAX);
+    let sequence_number = metadata.last_sequence_number + 1;
+    let parent = metadata.current_snapshot().cloned();
+    let file_prefix = Uuid::new_v4();
+
+    // Every updated key is deleted, including keys that did not exist before, and the latest
+    // value of every key that was not deleted is added back.
+    let mut files = vec![];
+    let (deletes, record_count) = encode_parquet(key_desc, field_ids(key_indices), updates.keys())?;
+    let file_size_in_bytes = i64::try_from(deletes.len()).expect("file size fits in i64");
+    files.push(DataFile {
+        content: FileContent::EqualityDeletes,
+        path: table
+            .store
+            .put(
+                &table.data_path(&format!("{file_prefix}-eq-deletes.parquet")),
+                deletes,
+            )
+            .await?,
+        record_count,
+        file_size_in_bytes,
+        equality_ids: field_ids(key_indices),
+    });
+    let value_field_ids = (0..connection.value_desc.arity()).collect::<Vec<_>>();
+    let (data, record_count) = encode_parquet(
+        &connection.value_desc,
+        field_ids(&value_field_ids),
+        updates.values().flatten(),
+    )?;
+    if record_count > 0 {
+        let file_size_in_bytes = i64::try_from(data.len()).expect("file size fits in i64");
+        files.push(DataFile {
+            content: FileContent::Data,
+            path: table
+                .store
+                .put(
+                    &table.data_path(&format!("{file_prefix}-data.parquet")),
+                    data,
+                )
+                .await?,
+            record_count,
+            file_size_in_bytes,
+            equality_ids: vec![],
+        });
+    }
+    let bytes: usize = files
+        .iter()
+        .map(|f| usize::try_from(f.file_size_in_bytes).expect("positive file size"))
+        .sum();
+    let bytes = u64::cast_from(bytes);
+    statistics.inc_messages_staged_by(messages);
+    statistics.inc_bytes_staged_by(bytes);
+
+    // The new snapshot keeps all manifests of its parent and adds one for each new file.
+    let mut manifests = match &parent {
+        Some(parent) => {
+            let list = table.store.get_uri(&parent.manifest_list).await?;
+            manifest::read_manifest_list(&list)
+                .with_context(|| format!("reading {}", parent.manifest_list))?
+        }
+        None => vec![],
+    };
+    for (i, file) in files.iter().enumerate() {
+        let bytes = manifest::write_manifest(&schema, snapshot_id, file);
+        let len = bytes.len();
+        let path = table
+            .store
+            .put(
+                &table.metadata_file_path(&format!("{file_prefix}-m{i}.avro")),
+                bytes,
+            )
+            .await?;
+        manifests.push(ManifestFile::new(
+            path,
+            len,
+            file,
+            snapshot_id,
+            sequence_number,
+        ));
+    }
+    let list = manifest::write_manifest_list(
+        snapshot_id,
+        parent.as_ref().map(|p| p.snapshot_id),
+        sequence_number,
+        &manifests,
+    );
+    let manifest_list = table
+        .store
+        .put(
+            &table.metadata_file_path(&format!("snap-{snapshot_id}-{file_prefix}.avro")),
+            list,
+        )
+        .await?;
+
+    let data_files: Vec<_> = files
+        .iter()
+        .filter(|f| f.content == FileContent::Data)
+        .collect();
+    let added_records: i64 = data_files.iter().map(|f| f.record_count).sum();
+    let summary = BTreeMap::from([
+        ("operation".to_string(), "overwrite".to_string()),
+        ("added-data-files".into(), data_files.len().to_string()),
+        ("added-records".into(), added_records.to_string()),
+        ("added-delete-files".into(), "1".into()),
+        ("added-equality-delete-files".into(), "1".into()),
+        ("added-equality-deletes".into(), updates.len().to_string()),
+    ]);
+    metadata.add_snapshot(Snapshot {
+        snapshot_id,
+        parent_snapshot_id: parent.map(|p| p.snapshot_id),
+        sequence_number,
+        timestamp_ms: table::now_ms(),
+        manifest_list,
+        summary,
+        schema_id: Some(metadata.current_schema_id),
+        other: BTreeMap::new(),
+    });
+    table.commit(metadata).await?;
+
+    statistics.inc_messages_committed_by(messages);
+    statistics.inc_bytes_committed_by(bytes);
+    Ok(())
+}
+
+/// Sinks a collection of keyed updates to an Iceberg table.
+///
+/// This operator exchanges all updates to a single worker by hashing on the given sink `id`.
+fn sink_collection<G: Scope<Timestamp = Timestamp>>(
+    name: String,
+    input: &Collection<G, (Option<Row>, Option<Row>), Diff>,
+    sink_id: GlobalId,
+    connection: IcebergSinkConnection,
+    storage_configuration: StorageConfiguration,
+    persist_clients: Arc<PersistClientCache>,
+    metadata: CollectionMetadata,
+    as_of: Antichain<Timestamp>,
+    statistics: SinkStatistics,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+) -> (Stream<G, HealthStatusMessage>, PressOnDropButton) {
+    let scope = input.scope();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to commit all the data, in order.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(&input.inner, Exchange::new(move |_| hashed_id));
+
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            let location = &connection.path;
+            let sdk_config = connection
+                .aws_connection
+                .load_sdk_config(
+                    &storage_configuration.connection_context,
+                    connection.aws_connection_id,
+                )
+                .await
+                .context("loading AWS configuration")?;
+            let client = mz_aws_util::s3::new_client(&sdk_config);
+            let store = ObjectStore::new(client, location)?;
+
+            let mut recorder = ProgressRecorder::new(
+                sink_id,
+                connection.progress_shard,
+                &persist_clients,
+                &metadata,
+            )
+            .await?;
+            let recorded_upper = recorder.upper().clone();
+
+            let schema = table_schema(&connection);
+            let mut table = match Table::load(store)
+                .await
+                .with_context(|| format!("loading Iceberg table at {location}"))?
+            {
+                Some(table) => table,
+                // The sink has committed to the table before, so it must have been removed
+                // outside of Materialize.
+                None if *recorded_upper != [Timestamp::minimum()] => bail!(
+                    "Iceberg table at {location} has no metadata, but the sink has committed \
+                    up to {} to it",
+                    recorded_upper.pretty()
+                ),
+                None => {
+                    let store =
+                        ObjectStore::new(mz_aws_util::s3::new_client(&sdk_config), location)?;
+                    let properties =
+                        BTreeMap::from([("write.format.default".into(), "parquet".into())]);
+                    Table::create(store, schema.clone(), properties)
+                        .await
+                        .with_context(|| format!("creating Iceberg table at {location}"))?
+                }
+            };
+
+            let current_schema = table.metadata.current_schema()?;
+            let columns = |s: &TableSchema| {
+                s.fields
+                    .iter()
+                    .map(|f| (f.name.clone(), f.typ.clone(), f.required))
+                    .collect::<Vec<_>>()
+            };
+            if columns(current_schema) != columns(&schema) {
+                bail!(
+                    "schema of Iceberg table at {location} does not match the sinked relation: {}",
+                    serde_json::to_string(&current_schema.fields).expect("infallible")
+                );
+            }
+
+            let resume_upper = match table.metadata.properties.get(&progress_property(sink_id)) {
+                Some(frontier) => {
+                    let frontier: Vec<Timestamp> = serde_json::from_str(frontier)
+                        .with_context(|| format!("invalid progress {frontier:?}"))?;
+                    Antichain::from(frontier)
+                }
+                None => Antichain::from_elem(Timestamp::minimum()),
+            };
+            if PartialOrder::less_than(&resume_upper, &recorded_upper) {
+                bail!(
+                    "Iceberg table at {location} reflects the sinked relation up to {}, but the \
+                    sink has committed up to {} to it",
+                    resume_upper.pretty(),
+                    recorded_upper.pretty()
+                );
+            }
+            // The table may be ahead of the progress shard if the sink restarted right after a
+            // commit.
+            recorder.record(&resume_upper).await?;
+            *write_frontier.borrow_mut() = resume_upper.clone();
+
+            super::check_resume_upper(&name, &as_of, &resume_upper)?;
+
+            // The section below relies on TotalOrder for correctness so we'll work with timestamps
+            // directly to make sure this doesn't compile if someone attempts to make this operator
+            // generic over partial orders in the future.
+            let Some(upper) = resume_upper.as_option().copied() else {
+                return Ok(());
+            };
+            let mut committed_upper = resume_upper.clone();
+            let mut last_commit: Option<Instant> = None;
+            let mut deferred_updates = vec![];
+            while let Some(event) = input.next().await {
+                match event {
+                    Event::Data(_cap, batch) => {
+                        for (update, time, diff) in batch {
+                            // Updates not beyond `upper` have already been committed.
+                            if time < upper {
+                                continue;
+                            }
+                            if diff != 1 {
+                                return Err(anyhow!("invalid sink update with diff {diff}"));
+                            }
+                            deferred_updates.push((update, time));
+                        }
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier.
+                        if !super::is_beyond_as_of(&as_of, &progress) {
+                            continue;
+                        }
+                        if progress == committed_upper {
+                            continue;
+                        }
+                        // Batch up closed timestamps until the commit interval has passed, unless
+                        // the input is complete.
+                        let due = last_commit
+                            .map_or(true, |last| last.elapsed() >= connection.commit_interval);
+                        if !due && !progress.is_empty() {
+                            continue;
+                        }
+
+                        let mut ready = deferred_updates
+                            .drain_filter_swapping(|(_, time)| !progress.less_equal(time))
+                            .collect::<Vec<_>>();
+                        ready.sort_unstable_by(|a, b| a.1.cmp(&b.1));
+                        let mut updates = BTreeMap::new();
+                        for ((key, value), _time) in ready {
+                            let key = key.expect("ICEBERG sinks require a key");
+                            updates.insert(key, value);
+                        }
+
+                        commit(
+                            &mut table,
+                            &connection,
+                            sink_id,
+                            updates,
+                            &progress,
+                            &statistics,
+                        )
+                        .await
+                        .with_context(|| {
+                            format!("committing frontier {} to Iceberg table", progress.pretty())
+                        })?;
+                        recorder.record(&progress).await?;
+                        *write_frontier.borrow_mut() = progress.clone();
+                        committed_upper = progress.clone();
+                        last_commit = Some(Instant::now());
+
+                        if progress.is_empty() {
+                            break;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(|error: Rc<anyhow::Error>| HealthStatusMessage {
+        index: 0,
+        update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+        namespace: StatusNamespace::Iceberg,
+    });
+
+    (statuses, button.press_on_drop())
+}
@@ -0,0 +1,457 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Iceberg manifests and manifest lists, in the format version 2 layout.
+//!
+//! Both are Avro object container files whose schemas carry the Iceberg `field-id` of every
+//! field, and whose header metadata describes the table the files belong to. Neither can be
+//! expressed with [`mz_avro::Writer`], which derives the schema it writes to the header from a
+//! parsed [`Schema`] and so drops unknown attributes, so this module writes the container files
+//! itself and only uses `mz_avro` to encode individual values.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context};
+use mz_avro::types::Value;
+use mz_avro::{Reader, Schema};
+use once_cell::sync::Lazy;
+
+/// The magic bytes at the start of every Avro object container file.
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+
+/// The schema of the entries of a manifest, restricted to the fields this sink writes.
+const MANIFEST_ENTRY_SCHEMA: &str = r#"{
+  "type": "record",
+  "name": "manifest_entry",
+  "fields": [
+    {"name": "status", "type": "int", "field-id": 0},
+    {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+    {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+    {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+    {"name": "data_file", "field-id": 2, "type": {
+      "type": "record",
+      "name": "r2",
+      "fields": [
+        {"name": "content", "type": "int", "field-id": 134},
+        {"name": "file_path", "type": "string", "field-id": 100},
+        {"name": "file_format", "type": "string", "field-id": 101},
+        {"name": "partition", "type": {"type": "record", "name": "r102", "fields": []}, "field-id": 102},
+        {"name": "record_count", "type": "long", "field-id": 103},
+        {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+        {"name": "equality_ids", "type": ["null", {"type": "array", "items": "int", "element-id": 136}], "default": null, "field-id": 135}
+      ]
+    }}
+  ]
+}"#;
+
+/// The schema of the entries of a manifest list.
+const MANIFEST_FILE_SCHEMA: &str = r#"{
+  "type": "record",
+  "name": "manifest_file",
+  "fields": [
+    {"name": "manifest_path", "type": "string", "field-id": 500},
+    {"name": "manifest_length", "type": "long", "field-id": 501},
+    {"name": "partition_spec_id", "type": "int", "field-id": 502},
+    {"name": "content", "type": "int", "field-id": 517},
+    {"name": "sequence_number", "type": "long", "field-id": 515},
+    {"name": "min_sequence_number", "type": "long", "field-id": 516},
+    {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+    {"name": "added_files_count", "type": "int", "field-id": 504},
+    {"name": "existing_files_count", "type": "int", "field-id": 505},
+    {"name": "deleted_files_count", "type": "int", "field-id": 506},
+    {"name": "added_rows_count", "type": "long", "field-id": 512},
+    {"name": "existing_rows_count", "type": "long", "field-id": 513},
+    {"name": "deleted_rows_count", "type": "long", "field-id": 514}
+  ]
+}"#;
+
+static MANIFEST_ENTRY: Lazy<Schema> =
+    Lazy::new(|| Schema::from_str(MANIFEST_ENTRY_SCHEMA).expect("valid schema"));
+static MANIFEST_FILE: Lazy<Schema> =
+    Lazy::new(|| Schema::from_str(MANIFEST_FILE_SCHEMA).expect("valid schema"));
+static METADATA: Lazy<Schema> =
+    Lazy::new(|| Schema::from_str(r#"{"type": "map", "values": "bytes"}"#).expect("valid schema"));
+static LONG: Lazy<Schema> = Lazy::new(|| Schema::from_str(r#""long""#).expect("valid schema"));
+
+/// The `status` of a manifest entry for a file added by the snapshot that wrote the manifest.
+const STATUS_ADDED: i32 = 1;
+
+/// The kind of rows a data file contains.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum FileContent {
+    /// Rows of the table.
+    Data,
+    /// Rows that delete all rows of the table with equal values in the `equality_ids` columns.
+    EqualityDeletes,
+}
+
+impl FileContent {
+    fn data_file_content(&self) -> i32 {
+        match self {
+            FileContent::Data => 0,
+            FileContent::EqualityDeletes => 2,
+        }
+    }
+
+    fn manifest_content(&self) -> i32 {
+        match self {
+            FileContent::Data => 0,
+            FileContent::EqualityDeletes => 1,
+        }
+    }
+}
+
+/// A Parquet file added to the table.
+#[derive(Clone, Debug)]
+pub(super) struct DataFile {
+    pub content: FileContent,
+    /// The absolute path of the file.
+    pub path: String,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    /// The field ids of the columns compared by equality deletes.
+    pub equality_ids: Vec<i32>,
+}
+
+/// An entry of a manifest list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct ManifestFile {
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    /// `0` for manifests of data files, `1` for manifests of delete files.
+    pub content: i32,
+    pub sequence_number: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i32,
+    pub existing_files_count: i32,
+    pub deleted_files_count: i32,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+}
+
+impl ManifestFile {
+    /// Describes a manifest written by [`write_manifest`] for `file`.
+    pub fn new(
+        path: String,
+        length: usize,
+        file: &DataFile,
+        snapshot_id: i64,
+        sequence_number: i64,
+    ) -> Self {
+        ManifestFile {
+            manifest_path: path,
+            manifest_length: i64::try_from(length).expect("manifest fits in i64"),
+            partition_spec_id: 0,
+            content: file.content.manifest_content(),
+            sequence_number,
+            min_sequence_number: sequence_number,
+            added_snapshot_id: snapshot_id,
+            added_files_count: 1,
+            existing_files_count: 0,
+            deleted_files_count: 0,
+            added_rows_count: file.record_count,
+            existing_rows_count: 0,
+            deleted_rows_count: 0,
+        }
+    }
+
+    fn to_avro(&self) -> Value {
+        Value::Record(vec![
+            (
+                "manifest_path".into(),
+                Value::String(self.manifest_path.clone()),
+            ),
+            ("manifest_length".into(), Value::Long(self.manifest_length)),
+            (
+                "partition_spec_id".into(),
+                Value::Int(self.partition_spec_id),
+            ),
+            ("content".into(), Value::Int(self.content)),
+            ("sequence_number".into(), Value::Long(self.sequence_number)),
+            (
+                "min_sequence_number".into(),
+                Value::Long(self.min_sequence_number),
+            ),
+            (
+                "added_snapshot_id".into(),
+                Value::Long(self.added_snapshot_id),
+            ),
+            (
+                "added_files_count".into(),
+                Value::Int(self.added_files_count),
+            ),
+            (
+                "existing_files_count".into(),
+                Value::Int(self.existing_files_count),
+            ),
+            (
+                "deleted_files_count".into(),
+                Value::Int(self.deleted_files_count),
+            ),
+            (
+                "added_rows_count".into(),
+                Value::Long(self.added_rows_count),
+            ),
+            (
+                "existing_rows_count".into(),
+                Value::Long(self.existing_rows_count),
+            ),
+            (
+                "deleted_rows_count".into(),
+                Value::Long(self.deleted_rows_count),
+            ),
+        ])
+    }
+
+    fn from_avro(value: Value) -> Result<Self, anyhow::Error> {
+        let Value::Record(fields) = value else {
+            return Err(anyhow!("manifest list entry is not a record: {value:?}"));
+        };
+        let mut fields: BTreeMap<_, _> = fields.into_iter().collect();
+        let mut field = |name: &str| {
+            let value = fields
+                .remove(name)
+                .ok_or_else(|| anyhow!("manifest list entry is missing {name}"))?;
+            // Fields that are optional in other writers' schemas are read as unions.
+            match value {
+                Value::Union { inner, .. } => Ok::<_, anyhow::Error>(*inner),
+                value => Ok(value),
+            }
+        };
+        let int = |value: Value| match value {
+            Value::Int(i) => Ok(i),
+            value => Err(anyhow!("expected int, found {value:?}")),
+        };
+        let long = |value: Value| match value {
+            Value::Long(i) => Ok(i),
+            value => Err(anyhow!("expected long, found {value:?}")),
+        };
+        Ok(ManifestFile {
+            manifest_path: match field("manifest_path")? {
+                Value::String(s) => s,
+                value => return Err(anyhow!("expected string, found {value:?}")),
+            },
+            manifest_length: long(field("manifest_length")?)?,
+            partition_spec_id: int(field("partition_spec_id")?)?,
+            content: int(field("content")?)?,
+            sequence_number: long(field("sequence_number")?)?,
+            min_sequence_number: long(field("min_sequence_number")?)?,
+            added_snapshot_id: long(field("added_snapshot_id")?)?,
+            added_files_count: int(field("added_files_count")?)?,
+            existing_files_count: int(field("existing_files_count")?)?,
+            deleted_files_count: int(field("deleted_files_count")?)?,
+            added_rows_count: long(field("added_rows_count")?)?,
+            existing_rows_count: long(field("existing_rows_count")?)?,
+            deleted_rows_count: long(field("deleted_rows_count")?)?,
+        })
+    }
+}
+
+/// Encodes a manifest that adds `file` in the snapshot `snapshot_id`, to a table whose current
+/// schema is `table_schema`.
+///
+/// The sequence numbers of the entry are inherited from the manifest list entry that refers to
+/// the manifest.
+pub(super) fn write_manifest(table_schema: &str, snapshot_id: i64, file: &DataFile) -> Vec<u8> {
+    let equality_ids = match file.content {
+        FileContent::Data => Value::Union {
+            index: 0,
+            inner: Box::new(Value::Null),
+            n_variants: 2,
+            null_variant: Some(0),
+        },
+        FileContent::EqualityDeletes => Value::Union {
+            index: 1,
+            inner: Box::new(Value::Array(
+                file.equality_ids.iter().map(|id| Value::Int(*id)).collect(),
+            )),
+            n_variants: 2,
+            null_variant: Some(0),
+        },
+    };
+    let entry = Value::Record(vec![
+        ("status".into(), Value::Int(STATUS_ADDED)),
+        ("snapshot_id".into(), some(Value::Long(snapshot_id))),
+        ("sequence_number".into(), none()),
+        ("file_sequence_number".into(), none()),
+        (
+            "data_file".into(),
+            Value::Record(vec![
+                (
+                    "content".into(),
+                    Value::Int(file.content.data_file_content()),
+                ),
+                ("file_path".into(), Value::String(file.path.clone())),
+                ("file_format".into(), Value::String("PARQUET".into())),
+                ("partition".into(), Value::Record(vec![])),
+                ("record_count".into(), Value::Long(file.record_count)),
+                (
+                    "file_size_in_bytes".into(),
+                    Value::Long(file.file_size_in_bytes),
+                ),
+                ("equality_ids".into(), equality_ids),
+            ]),
+        ),
+    ]);
+    let content = match file.content {
+        FileContent::Data => "data",
+        FileContent::EqualityDeletes => "deletes",
+    };
+    write_container(
+        MANIFEST_ENTRY_SCHEMA,
+        &MANIFEST_ENTRY,
+        &[
+            ("schema", table_schema),
+            ("schema-id", "0"),
+            ("partition-spec", "[]"),
+            ("partition-spec-id", "0"),
+            ("format-version", "2"),
+            ("content", content),
+        ],
+        &[entry],
+    )
+}
+
+/// Encodes the manifest list of the snapshot `snapshot_id`.
+pub(super) fn write_manifest_list(
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    sequence_number: i64,
+    manifests: &[ManifestFile],
+) -> Vec<u8> {
+    let snapshot_id = snapshot_id.to_string();
+    let parent_snapshot_id = parent_snapshot_id.map_or_else(|| "null".into(), |id| id.to_string());
+    let sequence_number = sequence_number.to_string();
+    let records: Vec<_> = manifests.iter().map(|m| m.to_avro()).collect();
+    write_container(
+        MANIFEST_FILE_SCHEMA,
+        &MANIFEST_FILE,
+        &[
+            ("snapshot-id", &snapshot_id),
+            ("parent-snapshot-id", &parent_snapshot_id),
+            ("sequence-number", &sequence_number),
+            ("format-version", "2"),
+        ],
+        &records,
+    )
+}
+
+/// Decodes the entries of a manifest list.
+pub(super) fn read_manifest_list(bytes: &[u8]) -> Result<Vec<ManifestFile>, anyhow::Error> {
+    let reader = Reader::new(bytes).context("reading manifest list header")?;
+    reader
+        .map(|value| ManifestFile::from_avro(value.context("reading manifest list entry")?))
+        .collect()
+}
+
+fn none() -> Value {
+    Value::Union {
+        index: 0,
+        inner: Box::new(Value::Null),
+        n_variants: 2,
+        null_variant: Some(0),
+    }
+}
+
+fn some(value: Value) -> Value {
+    Value::Union {
+        index: 1,
+        inner: Box::new(value),
+        n_variants: 2,
+        null_variant: Some(0),
+    }
+}
+
+/// Encodes an uncompressed Avro object container file with a single block holding `records`.
+///
+/// `schema_json` is written to the header verbatim, so that attributes unknown to `mz_avro` (like
+/// Iceberg's field ids) are preserved, while `schema` is its parsed form used for encoding.
+fn write_container(
+    schema_json: &str,
+    schema: &Schema,
+    metadata: &[(&str, &str)],
+    records: &[Value],
+) -> Vec<u8> {
+    let mut header_metadata = BTreeMap::new();
+    header_metadata.insert(
+        "avro.schema".to_string(),
+        Value::Bytes(schema_json.as_bytes().to_vec()),
+    );
+    header_metadata.insert("avro.codec".to_string(), Value::Bytes(b"null".to_vec()));
+    for (key, value) in metadata {
+        header_metadata.insert(key.to_string(), Value::Bytes(value.as_bytes().to_vec()));
+    }
+    let sync_marker: [u8; 16] = rand::random();
+
+    let mut buf = AVRO_MAGIC.to_vec();
+    mz_avro::encode_unchecked(&Value::Map(header_metadata), &METADATA, &mut buf);
+    buf.extend_from_slice(&sync_marker);
+
+    let mut block = vec![];
+    for record in records {
+        mz_avro::encode_unchecked(record, schema, &mut block);
+    }
+    let count = i64::try_from(records.len()).expect("record count fits in i64");
+    let size = i64::try_from(block.len()).expect("block size fits in i64");
+    mz_avro::encode_unchecked(&Value::Long(count), &LONG, &mut buf);
+    mz_avro::encode_unchecked(&Value::Long(size), &LONG, &mut buf);
+    buf.extend_from_slice(&block);
+    buf.extend_from_slice(&sync_marker);
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn manifest_list_roundtrip() {
+        let file = DataFile {
+            content: FileContent::EqualityDeletes,
+            path: "/t/data/a.parquet".into(),
+            record_count: 3,
+            file_size_in_bytes: 1024,
+            equality_ids: vec![1],
+        };
+        let manifests = vec![
+            ManifestFile::new("/t/metadata/a.avro".into(), 100, &file, 7, 2),
+            ManifestFile {
+                content: 0,
+                added_snapshot_id: 6,
+                ..ManifestFile::new("/t/metadata/b.avro".into(), 200, &file, 6, 1)
+            },
+        ];
+        let bytes = write_manifest_list(7, Some(6), 2, &manifests);
+        assert_eq!(read_manifest_list(&bytes).unwrap(), manifests);
+
+        let empty = write_manifest_list(1, None, 1, &[]);
+        assert_eq!(read_manifest_list(&empty).unwrap(), vec![]);
+    }
+
+    #[mz_ore::test]
+    fn manifest_is_readable() {
+        let file = DataFile {
+            content: FileContent::Data,
+            path: "/t/data/a.parquet".into(),
+            record_count: 3,
+            file_size_in_bytes: 1024,
+            equality_ids: vec![],
+        };
+        let bytes = write_manifest("{}", 7, &file);
+        let entries: Vec<_> = Reader::new(&bytes[..])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+    }
+}
@@ -0,0 +1,459 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A file-based catalog for a single Iceberg table in S3.
+//!
+//! The table follows the layout of Iceberg's Hadoop catalog: every version of the table metadata
+//! is stored in `metadata/v<N>.metadata.json` below the location of the table, and
+//! `metadata/version-hint.text` holds the number of the latest version. The version hint is only
+//! advisory, and readers probe for newer versions past it.
+//!
+//! S3 cannot create an object only if it does not exist yet, so a new version is committed by
+//! checking that the next metadata file does not exist and then writing it. This is only safe
+//! with a single writer, which is why ICEBERG sinks are restricted to clusters with a single
+//! replica.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Context};
+use aws_sdk_s3::Client;
+use mz_ore::error::ErrorExt;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The name of the file holding the number of the latest metadata version.
+const VERSION_HINT: &str = "version-hint.text";
+
+/// The objects below the location of a table in S3.
+pub(super) struct ObjectStore {
+    client: Client,
+    bucket: String,
+    /// The key prefix of the table, without a trailing slash.
+    prefix: String,
+}
+
+impl ObjectStore {
+    /// Creates a store for the objects below `location`, an `s3://bucket/prefix` URI.
+    pub fn new(client: Client, location: &str) -> Result<Self, anyhow::Error> {
+        let Some((bucket, prefix)) = location
+            .strip_prefix("s3://")
+            .map(|rest| rest.split_once('/').unwrap_or((rest, "")))
+        else {
+            bail!("invalid Iceberg table location {location}");
+        };
+        Ok(ObjectStore {
+            client,
+            bucket: bucket.to_string(),
+            prefix: prefix.trim_matches('/').to_string(),
+        })
+    }
+
+    /// The `s3://` URI of the table.
+    pub fn location(&self) -> String {
+        match self.prefix.as_str() {
+            "" => format!("s3://{}", self.bucket),
+            prefix => format!("s3://{}/{}", self.bucket, prefix),
+        }
+    }
+
+    /// The `s3://` URI of the object at `path` below the location of the table.
+    pub fn uri(&self, path: &str) -> String {
+        format!("{}/{}", self.location(), path)
+    }
+
+    /// The key of the object at `path` below the location of the table.
+    fn key(&self, path: &str) -> String {
+        match self.prefix.as_str() {
+            "" => path.to_string(),
+            prefix => format!("{prefix}/{path}"),
+        }
+    }
+
+    /// Reads the object at `path`, returning `None` if it does not exist.
+    pub async fn get(&self, path: &str) -> Result<Option<Vec<u8>>, anyhow::Error> {
+        self.get_key(&self.key(path)).await
+    }
+
+    /// Reads the object at the `s3://` URI `uri`, which must be in the bucket of the table.
+    pub async fn get_uri(&self, uri: &str) -> Result<Vec<u8>, anyhow::Error> {
+        let key = uri
+            .strip_prefix(&format!("s3://{}/", self.bucket))
+            .ok_or_else(|| anyhow!("{uri} is not in bucket {}", self.bucket))?;
+        self.get_key(key)
+            .await?
+            .ok_or_else(|| anyhow!("{uri} does not exist"))
+    }
+
+    async fn get_key(&self, key: &str) -> Result<Option<Vec<u8>>, anyhow::Error> {
+        let result = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await;
+        let output = match result {
+            Ok(output) => output,
+            Err(e) => {
+                let e = e.into_service_error();
+                if e.is_no_such_key() {
+                    return Ok(None);
+                }
+                bail!(
+                    "failed to read s3://{}/{key}: {}",
+                    self.bucket,
+                    e.display_with_causes()
+                );
+            }
+        };
+        let bytes = output
+            .body
+            .collect()
+            .await
+            .with_context(|| format!("reading s3://{}/{key}", self.bucket))?;
+        Ok(Some(bytes.into_bytes().to_vec()))
+    }
+
+    /// Reports whether an object exists at `path`.
+    pub async fn exists(&self, path: &str) -> Result<bool, anyhow::Error> {
+        let key = self.key(path);
+        let result = self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(&key)
+            .send()
+            .await;
+        match result {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                let e = e.into_service_error();
+                if e.is_not_found() {
+                    return Ok(false);
+                }
+                bail!(
+                    "failed to check s3://{}/{key}: {}",
+                    self.bucket,
+                    e.display_with_causes()
+                );
+            }
+        }
+    }
+
+    /// Writes `bytes` to the object at `path` and returns its `s3://` URI.
+    pub async fn put(&self, path: &str, bytes: Vec<u8>) -> Result<String, anyhow::Error> {
+        let key = self.key(path);
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&key)
+            .body(bytes.into())
+            .send()
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "failed to write s3://{}/{key}: {}",
+                    self.bucket,
+                    e.display_with_causes()
+                )
+            })?;
+        Ok(self.uri(path))
+    }
+}
+
+/// An Iceberg table in S3.
+pub(super) struct Table {
+    /// The objects of the table.
+    pub store: ObjectStore,
+    /// The number of the current metadata version.
+    version: u64,
+    /// The current table metadata.
+    pub metadata: TableMetadata,
+}
+
+/// The contents of a table metadata file, in format version 2.
+///
+/// Fields this sink does not interpret are preserved verbatim across commits.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct TableMetadata {
+    pub format_version: u8,
+    pub table_uuid: Uuid,
+    pub location: String,
+    pub last_sequence_number: i64,
+    pub last_updated_ms: i64,
+    pub last_column_id: i32,
+    pub schemas: Vec<TableSchema>,
+    pub current_schema_id: i32,
+    pub partition_specs: Vec<serde_json::Value>,
+    pub default_spec_id: i32,
+    pub last_partition_id: i32,
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+    /// The current snapshot, if any. Other implementations write `-1` for no snapshot.
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub snapshot_log: Vec<SnapshotLogEntry>,
+    #[serde(default)]
+    pub metadata_log: Vec<MetadataLogEntry>,
+    pub sort_orders: Vec<serde_json::Value>,
+    pub default_sort_order_id: i32,
+    #[serde(default)]
+    pub refs: BTreeMap<String, SnapshotRef>,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct TableSchema {
+    #[serde(rename = "type")]
+    pub typ: String,
+    pub schema_id: i32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub identifier_field_ids: Vec<i32>,
+    pub fields: Vec<SchemaField>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub(super) struct SchemaField {
+    pub id: i32,
+    pub name: String,
+    pub required: bool,
+    /// The type of the field. Nested types are JSON objects.
+    #[serde(rename = "type")]
+    pub typ: serde_json::Value,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_snapshot_id: Option<i64>,
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    pub manifest_list: String,
+    pub summary: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct SnapshotLogEntry {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct MetadataLogEntry {
+    pub metadata_file: String,
+    pub timestamp_ms: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct SnapshotRef {
+    pub snapshot_id: i64,
+    #[serde(rename = "type")]
+    pub typ: String,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+impl TableMetadata {
+    /// The metadata of a new, empty table at `location` with the given schema.
+    fn new(location: String, schema: TableSchema, properties: BTreeMap<String, String>) -> Self {
+        TableMetadata {
+            format_version: 2,
+            table_uuid: Uuid::new_v4(),
+            location,
+            last_sequence_number: 0,
+            last_updated_ms: now_ms(),
+            last_column_id: schema.fields.iter().map(|f| f.id).max().unwrap_or(0),
+            schemas: vec![schema],
+            current_schema_id: 0,
+            partition_specs: vec![serde_json::json!({"spec-id": 0, "fields": []})],
+            default_spec_id: 0,
+            // Partition field ids start at 1000.
+            last_partition_id: 999,
+            properties,
+            current_snapshot_id: None,
+            snapshots: vec![],
+            snapshot_log: vec![],
+            metadata_log: vec![],
+            sort_orders: vec![serde_json::json!({"order-id": 0, "fields": []})],
+            default_sort_order_id: 0,
+            refs: BTreeMap::new(),
+            other: BTreeMap::new(),
+        }
+    }
+
+    /// The current schema of the table.
+    pub fn current_schema(&self) -> Result<&TableSchema, anyhow::Error> {
+        self.schemas
+            .iter()
+            .find(|s| s.schema_id == self.current_schema_id)
+            .ok_or_else(|| anyhow!("current schema {} not found", self.current_schema_id))
+    }
+
+    /// The current snapshot of the table, if any.
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        let id = self.current_snapshot_id?;
+        self.snapshots.iter().find(|s| s.snapshot_id == id)
+    }
+
+    /// Makes `snapshot` the current snapshot of the table.
+    pub fn add_snapshot(&mut self, snapshot: Snapshot) {
+        self.last_sequence_number = snapshot.sequence_number;
+        self.current_snapshot_id = Some(snapshot.snapshot_id);
+        self.snapshot_log.push(SnapshotLogEntry {
+            snapshot_id: snapshot.snapshot_id,
+            timestamp_ms: snapshot.timestamp_ms,
+        });
+        self.refs.insert(
+            "main".into(),
+            SnapshotRef {
+                snapshot_id: snapshot.snapshot_id,
+                typ: "branch".into(),
+                other: BTreeMap::new(),
+            },
+        );
+        self.snapshots.push(snapshot);
+    }
+}
+
+impl Table {
+    /// Loads the latest version of the table in `store`, returning `None` if the table does not
+    /// have any metadata.
+    pub async fn load(store: ObjectStore) -> Result<Option<Self>, anyhow::Error> {
+        let hint = match store.get(&metadata_path(VERSION_HINT)).await? {
+            Some(hint) => {
+                let hint = String::from_utf8_lossy(&hint);
+                hint.trim()
+                    .parse::<u64>()
+                    .with_context(|| format!("invalid version hint {hint:?}"))?
+            }
+            None => 0,
+        };
+        let mut version = hint;
+        while store.exists(&version_path(version + 1)).await? {
+            version += 1;
+        }
+        if version == 0 {
+            return Ok(None);
+        }
+
+        let path = version_path(version);
+        let bytes = store
+            .get(&path)
+            .await?
+            .ok_or_else(|| anyhow!("{} does not exist", store.uri(&path)))?;
+        let metadata: TableMetadata = serde_json::from_slice(&bytes)
+            .with_context(|| format!("parsing {}", store.uri(&path)))?;
+        if metadata.format_version != 2 {
+            bail!(
+                "unsupported Iceberg format version {}",
+                metadata.format_version
+            );
+        }
+        Ok(Some(Table {
+            store,
+            version,
+            metadata,
+        }))
+    }
+
+    /// Creates a new, empty table in `store` with `schema` and `properties`.
+    pub async fn create(
+        store: ObjectStore,
+        schema: TableSchema,
+        properties: BTreeMap<String, String>,
+    ) -> Result<Self, anyhow::Error> {
+        let metadata = TableMetadata::new(store.location(), schema, properties);
+        let mut table = Table {
+            store,
+            version: 0,
+            metadata: metadata.clone(),
+        };
+        table.commit(metadata).await.context("creating table")?;
+        Ok(table)
+    }
+
+    /// The path of a new file named `name` in the data directory of the table.
+    pub fn data_path(&self, name: &str) -> String {
+        format!("data/{name}")
+    }
+
+    /// The path of a new file named `name` in the metadata directory of the table.
+    pub fn metadata_file_path(&self, name: &str) -> String {
+        metadata_path(name)
+    }
+
+    /// Replaces the current metadata of the table with `metadata`.
+    ///
+    /// Fails if another writer has committed a new version since this table was loaded, in which
+    /// case the table must be reloaded. As the check and the write are not atomic, this relies on
+    /// there being a single writer.
+    pub async fn commit(&mut self, mut metadata: TableMetadata) -> Result<(), anyhow::Error> {
+        let version = self.version + 1;
+        let now = now_ms();
+        if self.version > 0 {
+            metadata.metadata_log.push(MetadataLogEntry {
+                metadata_file: self.store.uri(&version_path(self.version)),
+                timestamp_ms: self.metadata.last_updated_ms,
+            });
+        }
+        metadata.last_updated_ms = now;
+
+        let path = version_path(version);
+        if self.store.exists(&path).await? {
+            bail!(
+                "concurrent commit to Iceberg table at {}: version {version} already exists",
+                self.store.location()
+            );
+        }
+        let bytes = serde_json::to_vec_pretty(&metadata).expect("infallible");
+        self.store.put(&path, bytes).await?;
+        self.store
+            .put(
+                &metadata_path(VERSION_HINT),
+                version.to_string().into_bytes(),
+            )
+            .await?;
+
+        self.version = version;
+        self.metadata = metadata;
+        Ok(())
+    }
+}
+
+/// The path of the file named `name` in the metadata directory of a table.
+fn metadata_path(name: &str) -> String {
+    format!("metadata/{name}")
+}
+
+/// The path of the metadata file of `version`.
+fn version_path(version: u64) -> String {
+    metadata_path(&format!("v{version}.metadata.json"))
+}
+
+/// The current wall clock time in milliseconds since the Unix epoch.
+pub(super) fn now_ms() -> i64 {
+    i64::try_from((mz_ore::now::SYSTEM_TIME)()).expect("current time fits in i64")
+}
@@ -81,6 +81,7 @@ use differential_dataflow::{AsCollection, Collection, Hashable};
 use mz_interchange::avro::{AvroEncoder, AvroSchemaGenerator, AvroSchemaOptions};
 use mz_interchange::encode::Encode;
 use mz_interchange::json::JsonEncoder;
+use mz_interchange::text_binary::{BinaryEncoder, TextEncoder};
 use mz_kafka_util::client::{MzClientContext, TunnelingClientContext};
 use mz_ore::cast::CastFrom;
 use mz_ore::error::ErrorExt;
@@ -108,7 +109,7 @@ use timely::dataflow::operators::{CapabilitySet, Concatenate, Map, ToStream};
 use timely::dataflow::{Scope, Stream};
 use timely::progress::{Antichain, Timestamp as _};
 use timely::PartialOrder;
-use tracing::{error, info};
+use tracing::info;
 
 use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
 use crate::render::sinks::SinkRender;
@@ -474,30 +475,7 @@ fn sink_collection<G: Scope<Timestamp = Timestamp>>(
                 }
             };
 
-            // The input has overcompacted if
-            let overcompacted =
-                // ..we have made some progress in the past
-                *resume_upper != [Timestamp::minimum()] &&
-                // ..but the since frontier is now beyond that
-                !PartialOrder::less_equal(&as_of, &resume_upper);
-            if overcompacted {
-                let err = format!(
-                    "{name}: input compacted past resume upper: as_of {}, resume_upper: {}",
-                    as_of.pretty(),
-                    resume_upper.pretty()
-                );
-                // This would normally be an assertion but because it can happen after a
-                // Materialize backup/restore we log an error so that it appears on Sentry but
-                // leaves the rest of the objects in the cluster unaffected.
-                error!("{err}");
-                return Err(anyhow!("{err}").into());
-            }
-
-            info!(
-                "{name}: as_of: {}, resume upper: {}",
-                as_of.pretty(),
-                resume_upper.pretty()
-            );
+            super::check_resume_upper(&name, &as_of, &resume_upper)?;
 
             // The section below relies on TotalOrder for correctness so we'll work with timestamps
             // directly to make sure this doesn't compile if someone attempts to make this operator
@@ -543,26 +521,8 @@ fn sink_collection<G: Scope<Timestamp = Timestamp>>(
                         if !PartialOrder::less_equal(&resume_upper, &progress) {
                             continue;
                         }
-                        // Also ignore progress updates until we are past the as_of frontier. This
-                        // is to avoid the following pathological scenario:
-                        // 1. Sink gets instantiated with an as_of = {10}, resume_upper = {0}.
-                        //    `progress` initially jumps at {10}, then the snapshot appears at time
-                        //    10.
-                        // 2. `progress` would normally advance to say {11} and we would commit the
-                        //    snapshot but clusterd crashes instead.
-                        // 3. A new cluster restarts the sink with an earlier as_of, say {5}. This
-                        //    is valid, the earlier as_of has strictly more information. The
-                        //    snapshot now appears at time 5.
-                        //
-                        // If we were to commit an empty transaction in step 1 and advanced the
-                        // resume_upper to {10} then in step 3 we would ignore the snapshot that
-                        // now appears at 5 completely. So it is important to only start committing
-                        // transactions after we're strictly beyond the as_of.
-                        // TODO(petrosagg): is this logic an indication of us holding something
-                        // wrong elsewhere? Investigate.
-                        // Note: !PartialOrder::less_than(as_of, progress) would not be equivalent
-                        // nor correct for partially ordered times.
-                        if !as_of.iter().all(|t| !progress.less_equal(t)) {
+                        // Also ignore progress updates until we are past the as_of frontier.
+                        if !super::is_beyond_as_of(&as_of, &progress) {
                             continue;
                         }
                         if !transaction_begun {
@@ -647,6 +607,10 @@ fn encode_collection<G: Scope>(
                 .key_desc_and_indices
                 .as_ref()
                 .map(|(desc, _indices)| desc.clone());
+            let key_indices = connection
+                .key_desc_and_indices
+                .as_ref()
+                .map(|(_desc, indices)| indices.as_slice());
             let value_desc = connection.value_desc;
 
             let encoder: Box<dyn Encode> = match connection.format {
@@ -690,6 +654,10 @@ fn encode_collection<G: Scope>(
                     value_desc,
                     matches!(envelope, SinkEnvelope::Debezium),
                 )),
+                KafkaSinkFormat::Text => {
+                    Box::new(TextEncoder::new(key_desc, value_desc, key_indices))
+                }
+                KafkaSinkFormat::Bytes => Box::new(BinaryEncoder::new(&value_desc, key_indices)),
             };
 
             // !IMPORTANT!
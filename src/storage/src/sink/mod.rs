@@ -9,4 +9,149 @@
 
 //! Moving data to external systems
 
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Context};
+use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::write::WriteHandle;
+use mz_persist_client::{Diagnostics, ShardId};
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Diff, GlobalId, RelationDesc, Timestamp};
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::antichain::AntichainExt;
+use timely::progress::{Antichain, Timestamp as _};
+use timely::PartialOrder;
+use tracing::{error, info};
+
+mod iceberg;
 mod kafka;
+mod postgres;
+mod webhook;
+
+/// Checks that a sink that has committed its output up to `resume_upper` can resume from an input
+/// that is readable at `as_of`.
+///
+/// The input has overcompacted if the sink has made some progress in the past, but the since
+/// frontier of the input is now beyond that progress. This would normally be an assertion but
+/// because it can happen after a Materialize backup/restore we log an error so that it appears on
+/// Sentry but leaves the rest of the objects in the cluster unaffected.
+pub(crate) fn check_resume_upper(
+    name: &str,
+    as_of: &Antichain<Timestamp>,
+    resume_upper: &Antichain<Timestamp>,
+) -> Result<(), anyhow::Error> {
+    let overcompacted =
+        **resume_upper != [Timestamp::minimum()] && !PartialOrder::less_equal(as_of, resume_upper);
+    if overcompacted {
+        let err = format!(
+            "{name}: input compacted past resume upper: as_of {}, resume_upper: {}",
+            as_of.pretty(),
+            resume_upper.pretty()
+        );
+        error!("{err}");
+        bail!("{err}");
+    }
+
+    info!(
+        "{name}: as_of: {}, resume upper: {}",
+        as_of.pretty(),
+        resume_upper.pretty()
+    );
+    Ok(())
+}
+
+/// Reports whether a sink may record `progress` as the frontier up to which it has committed its
+/// output, which is only the case once `progress` is strictly beyond `as_of`.
+///
+/// Otherwise the following pathological scenario could occur:
+/// 1. The sink gets instantiated with an as_of = {10}, resume_upper = {0}. `progress` initially
+///    jumps at {10}, then the snapshot appears at time 10.
+/// 2. `progress` would normally advance to say {11} and we would commit the snapshot but clusterd
+///    crashes instead.
+/// 3. A new cluster restarts the sink with an earlier as_of, say {5}. This is valid, the earlier
+///    as_of has strictly more information. The snapshot now appears at time 5.
+///
+/// If we were to record progress in step 1 and advanced the resume_upper to {10} then in step 3 we
+/// would ignore the snapshot that now appears at 5 completely.
+///
+/// Note that `!PartialOrder::less_than(as_of, progress)` would not be equivalent nor correct for
+/// partially ordered times.
+pub(crate) fn is_beyond_as_of(
+    as_of: &Antichain<Timestamp>,
+    progress: &Antichain<Timestamp>,
+) -> bool {
+    as_of.iter().all(|t| !progress.less_equal(t))
+}
+
+/// Records the progress of a sink that cannot commit its progress together with its output, as
+/// the upper of a persist shard that the storage controller allocates for the sink.
+///
+/// The shard never contains any data.
+pub(crate) struct ProgressRecorder {
+    write: WriteHandle<SourceData, (), Timestamp, Diff>,
+    /// The upper of the progress shard, as last observed by this instance of the sink.
+    upper: Antichain<Timestamp>,
+}
+
+impl ProgressRecorder {
+    pub(crate) async fn new(
+        sink_id: GlobalId,
+        progress_shard: Option<ShardId>,
+        persist_clients: &PersistClientCache,
+        metadata: &CollectionMetadata,
+    ) -> Result<Self, anyhow::Error> {
+        let shard_id =
+            progress_shard.ok_or_else(|| anyhow!("sink {sink_id} has no progress shard"))?;
+        let client = persist_clients
+            .open(metadata.persist_location.clone())
+            .await
+            .context("opening persist client")?;
+        let mut write = client
+            .open_writer(
+                shard_id,
+                Arc::new(RelationDesc::empty()),
+                Arc::new(UnitSchema),
+                Diagnostics {
+                    shard_name: sink_id.to_string(),
+                    handle_purpose: format!("sink progress {sink_id}"),
+                },
+            )
+            .await
+            .context("opening sink progress shard")?;
+        let upper = write.fetch_recent_upper().await.clone();
+        Ok(ProgressRecorder { write, upper })
+    }
+
+    /// The frontier up to which the sink has recorded its progress.
+    pub(crate) fn upper(&self) -> &Antichain<Timestamp> {
+        &self.upper
+    }
+
+    /// Records that the sink has committed all of its output that is not beyond `new_upper`.
+    ///
+    /// Fails if another instance of the sink has recorded progress since this instance last
+    /// observed the shard, in which case this instance has been fenced out.
+    pub(crate) async fn record(
+        &mut self,
+        new_upper: &Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        if !PartialOrder::less_than(&self.upper, new_upper) {
+            return Ok(());
+        }
+        let updates: [((SourceData, ()), Timestamp, Diff); 0] = [];
+        self.write
+            .compare_and_append(updates, self.upper.clone(), new_upper.clone())
+            .await
+            .context("recording sink progress")?
+            .map_err(|mismatch| {
+                anyhow!(
+                    "sink was fenced out: expected progress {} but found {}",
+                    mismatch.expected.pretty(),
+                    mismatch.current.pretty()
+                )
+            })?;
+        self.upper = new_upper.clone();
+        Ok(())
+    }
+}
@@ -0,0 +1,571 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`PostgresSinkConnection`]. The dataflow consists of a
+//! single operator that keeps an upstream PostgreSQL table in sync with the sinked relation.
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ keyed upserts, the input to this module
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃  postgres   ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━┯━━━━━━━━┯━┛
+//!      rows │        │ uppers
+//!      ╭────v──╮ ╭───v──────╮
+//!      │ data  │ │ progress │  <- rows and uppers are written
+//!      │ table │ │  table   │     transactionally to both tables
+//!      ╰───────╯ ╰──────────╯
+//! ```
+//!
+//! # Transactions
+//!
+//! Updates are buffered until the input frontier advances past their timestamp. All the updates
+//! of a single timestamp are then applied in one upstream transaction, in ascending timestamp
+//! order. Each transaction deletes every row whose key was touched at that timestamp, inserts the
+//! new value of every key that still has one, and records the timestamp's successor in the
+//! progress table. The last transaction for a frontier records the frontier itself. When the
+//! frontier advances without any data, the new frontier is recorded in a transaction of its own
+//! at most once every [`PROGRESS_INTERVAL`].
+//!
+//! # Fencing
+//!
+//! Every instance of the sink bumps the `epoch` column of its row in the progress table when it
+//! starts up and reads back the frontier that was last committed. Each transaction then locks the
+//! progress row and checks that the epoch is still its own before writing anything. A previous
+//! instance that is still running is therefore fenced out as soon as a new instance starts, which
+//! together with the transactional progress updates makes the sink exactly-once across restarts.
+//!
+//! # Error handling
+//!
+//! Any error talking to the upstream database is a transient error that is wired up with our
+//! health monitoring and will trigger a restart of the sink dataflow.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context};
+use bytes::Bytes;
+use differential_dataflow::{Collection, Hashable};
+use futures::{pin_mut, SinkExt};
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::vec::VecExt;
+use mz_postgres_util::PostgresError;
+use mz_repr::{ColumnType, Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_sql_parser::ast::{display::AstDisplay, Ident};
+use mz_storage_client::sink::ProgressRecord;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    MetadataFilled, PostgresSinkConnection, PostgresTableName, StorageSinkDesc,
+};
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::{Concatenate, Map, ToStream};
+use timely::dataflow::{Scope, Stream};
+use timely::progress::{Antichain, Timestamp as _};
+use timely::PartialOrder;
+use tokio_postgres::Client;
+use tracing::info;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The name of the temporary table used to stage the keys touched by a transaction.
+const STAGING_TABLE: &str = "mz_sink_staging";
+
+/// The minimum time between two transactions that only record progress, i.e., that advance the
+/// frontier in the progress table without changing the data table.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for PostgresSinkConnection {
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        input: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        // TODO(benesch): errors should stream out through the sink,
+        // if we figure out a protocol for that.
+        _err_collection: Collection<G, DataflowError, Diff>,
+    ) -> (Stream<G, HealthStatusMessage>, Vec<PressOnDropButton>) {
+        let mut scope = input.scope();
+
+        let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, Rc::clone(&write_frontier));
+
+        let (sink_status, sink_token) = sink_collection(
+            format!("postgres-{sink_id}-sink"),
+            &input,
+            sink_id,
+            self.clone(),
+            storage_state.storage_configuration.clone(),
+            sink.as_of.clone(),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            write_frontier,
+        );
+
+        let running_status = Some(HealthStatusMessage {
+            index: 0,
+            update: HealthStatusUpdate::Running,
+            namespace: StatusNamespace::Postgres,
+        })
+        .to_stream(&mut scope);
+
+        let status = scope.concatenate([running_status, sink_status]);
+
+        (status, vec![sink_token])
+    }
+}
+
+/// Returns the fully qualified and quoted name of `table`.
+fn quoted_table_name(table: &PostgresTableName) -> String {
+    format!(
+        "{}.{}",
+        Ident::new_unchecked(table.schema.as_str()).to_ast_string(),
+        Ident::new_unchecked(table.name.as_str()).to_ast_string()
+    )
+}
+
+/// Returns the comma separated, quoted names of the columns of `desc`.
+fn quoted_column_names(desc: &RelationDesc) -> String {
+    itertools::join(
+        desc.iter_names()
+            .map(|name| Ident::new_unchecked(name.as_str()).to_ast_string()),
+        ", ",
+    )
+}
+
+/// Sleeps until the pending frontier in `pending_progress`, if any, is due to be recorded.
+async fn sleep_until_due(pending_progress: &Option<(Antichain<Timestamp>, Instant)>) {
+    if let Some((_, due)) = pending_progress {
+        tokio::time::sleep_until((*due).into()).await;
+    }
+}
+
+/// A connection to the upstream database that applies the updates of a
+/// [`PostgresSinkConnection`] transactionally.
+struct TransactionalWriter {
+    /// The connection to the upstream database.
+    client: Client,
+    /// The ID of the sink, which identifies its row in the progress table.
+    sink_id: String,
+    /// The epoch this writer was started with. Any transaction that finds a different epoch in
+    /// the progress table has been fenced out by a newer instance of the sink.
+    epoch: i64,
+    /// The description of the key of the sink.
+    key_desc: RelationDesc,
+    /// The relation that is sinked.
+    value_desc: RelationDesc,
+    /// `COPY` statement that stages the keys of a transaction.
+    copy_keys: String,
+    /// `DELETE` statement that removes all rows whose key was staged.
+    delete_staged: String,
+    /// `COPY` statement that inserts new values into the data table.
+    copy_values: String,
+    /// Statement that locks the sink's progress row and returns its epoch.
+    lock_progress: String,
+    /// Statement that records a new frontier in the progress table.
+    update_progress: String,
+    /// A handle to the metrics associated with this sink.
+    statistics: SinkStatistics,
+}
+
+impl TransactionalWriter {
+    /// Connects to the upstream database of `connection` and fences out all previous instances of
+    /// the sink identified by `sink_id`. Returns the writer along with the frontier up to which
+    /// updates have already been committed.
+    async fn new(
+        sink_id: GlobalId,
+        connection: &PostgresSinkConnection,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+    ) -> Result<(Self, Antichain<Timestamp>), anyhow::Error> {
+        let (key_desc, _indices) = connection
+            .key_desc_and_indices
+            .clone()
+            .ok_or_else(|| anyhow!("postgres sinks require a key"))?;
+        let value_desc = connection.value_desc.clone();
+
+        let config = connection
+            .connection
+            .config(
+                &*storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+            )
+            .await?;
+        let client = config
+            .connect(
+                &format!("postgres_sink:{sink_id}"),
+                &storage_configuration.connection_context.ssh_tunnel_manager,
+            )
+            .await?;
+
+        let table = quoted_table_name(&connection.table);
+        let progress_table = quoted_table_name(&connection.progress_table);
+        let staging_table = Ident::new_unchecked(STAGING_TABLE).to_ast_string();
+        let key_columns = quoted_column_names(&key_desc);
+
+        client
+            .batch_execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {progress_table} (
+                    sink_id text PRIMARY KEY,
+                    epoch bigint NOT NULL,
+                    frontier text NOT NULL
+                );
+                CREATE TEMPORARY TABLE {staging_table} ON COMMIT DELETE ROWS AS
+                    SELECT {key_columns} FROM {table} WITH NO DATA;"
+            ))
+            .await
+            .map_err(PostgresError::from)?;
+
+        // Bumping the epoch takes a lock on the progress row that any concurrent transaction of a
+        // previous instance also needs, so once this statement returns the previous instance can
+        // no longer commit and the frontier we read back is final.
+        let initial_progress = ProgressRecord {
+            frontier: vec![Timestamp::minimum()],
+        };
+        let row = client
+            .query_one(
+                &format!(
+                    "INSERT INTO {progress_table} (sink_id, epoch, frontier) VALUES ($1, 0, $2)
+                    ON CONFLICT (sink_id) DO UPDATE SET epoch = {progress_table}.epoch + 1
+                    RETURNING epoch, frontier"
+                ),
+                &[
+                    &sink_id.to_string(),
+                    &serde_json::to_string(&initial_progress).expect("infallible"),
+                ],
+            )
+            .await
+            .map_err(PostgresError::from)?;
+        let epoch: i64 = row.get("epoch");
+        let frontier: String = row.get("frontier");
+        let progress: ProgressRecord = serde_json::from_str(&frontier)
+            .with_context(|| format!("invalid progress record in {progress_table}: {frontier}"))?;
+        let resume_upper = Antichain::from(progress.frontier);
+
+        // Keys may be NULL, in which case rows must be matched with `IS NOT DISTINCT FROM`. We
+        // only do so for nullable columns because it prevents the use of indexes.
+        let key_predicate = itertools::join(
+            key_desc.iter().map(|(name, typ): (_, &ColumnType)| {
+                let name = Ident::new_unchecked(name.as_str()).to_ast_string();
+                let op = if typ.nullable {
+                    "IS NOT DISTINCT FROM"
+                } else {
+                    "="
+                };
+                format!("{table}.{name} {op} {staging_table}.{name}")
+            }),
+            " AND ",
+        );
+
+        let writer = TransactionalWriter {
+            client,
+            sink_id: sink_id.to_string(),
+            epoch,
+            copy_keys: format!("COPY {staging_table} ({key_columns}) FROM STDIN"),
+            delete_staged: format!(
+                "DELETE FROM {table} USING {staging_table} WHERE {key_predicate}"
+            ),
+            copy_values: format!(
+                "COPY {table} ({}) FROM STDIN",
+                quoted_column_names(&value_desc)
+            ),
+            lock_progress: format!(
+                "SELECT epoch FROM {progress_table} WHERE sink_id = $1 FOR UPDATE"
+            ),
+            update_progress: format!(
+                "UPDATE {progress_table} SET frontier = $2 WHERE sink_id = $1"
+            ),
+            key_desc,
+            value_desc,
+            statistics,
+        };
+
+        Ok((writer, resume_upper))
+    }
+
+    /// Applies `updates` to the data table and records `upper` in the progress table in a single
+    /// transaction.
+    ///
+    /// For every key in `updates` all existing rows with that key are deleted, and the new value,
+    /// if any, is inserted.
+    async fn commit(
+        &mut self,
+        updates: &[(Option<Row>, Option<Row>)],
+        upper: Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        let mut keys = vec![];
+        let mut values = vec![];
+        let mut staged_messages = 0;
+        for (key, value) in updates {
+            let key = key.as_ref().expect("postgres sinks are keyed");
+            mz_pgcopy::encode_copy_row_text(key.clone(), self.key_desc.typ(), &mut keys)?;
+            if let Some(value) = value {
+                mz_pgcopy::encode_copy_row_text(value.clone(), self.value_desc.typ(), &mut values)?;
+            }
+            staged_messages += 1;
+        }
+        let staged_bytes = u64::cast_from(keys.len() + values.len());
+        self.statistics.inc_messages_staged_by(staged_messages);
+        self.statistics.inc_bytes_staged_by(staged_bytes);
+
+        let progress = ProgressRecord {
+            frontier: upper.into(),
+        };
+        let progress = serde_json::to_string(&progress).expect("infallible");
+
+        let txn = self
+            .client
+            .transaction()
+            .await
+            .map_err(PostgresError::from)?;
+
+        let row = txn
+            .query_opt(&self.lock_progress, &[&self.sink_id])
+            .await
+            .map_err(PostgresError::from)?;
+        match row.map(|row| row.get::<_, i64>("epoch")) {
+            Some(epoch) if epoch == self.epoch => (),
+            Some(epoch) => bail!(
+                "sink fenced out by a newer instance: expected epoch {}, found {epoch}",
+                self.epoch
+            ),
+            None => bail!("sink progress record missing from progress table"),
+        }
+
+        if !keys.is_empty() {
+            let sink = txn
+                .copy_in::<_, Bytes>(self.copy_keys.as_str())
+                .await
+                .map_err(PostgresError::from)?;
+            pin_mut!(sink);
+            sink.send(Bytes::from(keys))
+                .await
+                .map_err(PostgresError::from)?;
+            sink.finish().await.map_err(PostgresError::from)?;
+
+            txn.execute(&self.delete_staged, &[])
+                .await
+                .map_err(PostgresError::from)?;
+        }
+
+        if !values.is_empty() {
+            let sink = txn
+                .copy_in::<_, Bytes>(self.copy_values.as_str())
+                .await
+                .map_err(PostgresError::from)?;
+            pin_mut!(sink);
+            sink.send(Bytes::from(values))
+                .await
+                .map_err(PostgresError::from)?;
+            sink.finish().await.map_err(PostgresError::from)?;
+        }
+
+        txn.execute(&self.update_progress, &[&self.sink_id, &progress])
+            .await
+            .map_err(PostgresError::from)?;
+        txn.commit().await.map_err(PostgresError::from)?;
+
+        self.statistics.inc_messages_committed_by(staged_messages);
+        self.statistics.inc_bytes_committed_by(staged_bytes);
+        Ok(())
+    }
+}
+
+/// Sinks a collection of keyed upserts to a PostgreSQL table.
+///
+/// This operator exchanges all updates to a single worker by hashing on the given sink `id`.
+///
+/// Updates are applied in ascending timestamp order, one transaction per timestamp.
+fn sink_collection<G: Scope<Timestamp = Timestamp>>(
+    name: String,
+    input: &Collection<G, (Option<Row>, Option<Row>), Diff>,
+    sink_id: GlobalId,
+    connection: PostgresSinkConnection,
+    storage_configuration: StorageConfiguration,
+    as_of: Antichain<Timestamp>,
+    statistics: SinkStatistics,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+) -> (Stream<G, HealthStatusMessage>, PressOnDropButton) {
+    let scope = input.scope();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to write all the data to the upstream table.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(&input.inner, Exchange::new(move |_| hashed_id));
+
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            // Instantiating the writer fences out all previous ones, making it safe to use the
+            // resume upper it returns.
+            let (mut writer, resume_upper) =
+                TransactionalWriter::new(sink_id, &connection, &storage_configuration, statistics)
+                    .await?;
+
+            super::check_resume_upper(&name, &as_of, &resume_upper)?;
+
+            // The section below relies on TotalOrder for correctness so we'll work with timestamps
+            // directly to make sure this doesn't compile if someone attempts to make this operator
+            // generic over partial orders in the future.
+            let Some(upper) = resume_upper.as_option().copied() else {
+                return Ok(());
+            };
+            let mut deferred_updates = vec![];
+            let mut ready_updates = vec![];
+            // A frontier that has not been recorded in the progress table yet because no data
+            // was committed with it, and the time at which it is due to be recorded.
+            let mut pending_progress: Option<(Antichain<Timestamp>, Instant)> = None;
+            loop {
+                let event = tokio::select! {
+                    event = input.next() => match event {
+                        Some(event) => event,
+                        None => break,
+                    },
+                    _ = sleep_until_due(&pending_progress), if pending_progress.is_some() => {
+                        let (progress, _) = pending_progress.take().expect("checked above");
+                        info!("{name}: committing progress for {}", progress.pretty());
+                        writer.commit(&[], progress.clone()).await?;
+                        *write_frontier.borrow_mut() = progress;
+                        continue;
+                    }
+                };
+                match event {
+                    Event::Data(_cap, batch) => {
+                        for (update, time, diff) in batch {
+                            // Updates not beyond `upper` have already been committed.
+                            if time < upper {
+                                continue;
+                            }
+                            if diff != 1 {
+                                bail!("{name}: invalid sink update with diff {diff}");
+                            }
+                            deferred_updates.push((update, time));
+                        }
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier.
+                        if !super::is_beyond_as_of(&as_of, &progress) {
+                            continue;
+                        }
+
+                        ready_updates.extend(
+                            deferred_updates
+                                .drain_filter_swapping(|(_, time)| !progress.less_equal(time)),
+                        );
+                        ready_updates.sort_unstable_by(|a, b| a.1.cmp(&b.1));
+
+                        // Commit each timestamp in its own transaction, recording the successor of
+                        // the timestamp as the new upper so that a restart resumes right after it.
+                        // No updates are pending before `progress`, so the last transaction
+                        // records `progress` itself.
+                        let mut ready = ready_updates.drain(..).peekable();
+                        let mut batch = vec![];
+                        let mut committed = false;
+                        while let Some((update, time)) = ready.next() {
+                            batch.push(update);
+                            match ready.peek() {
+                                Some((_, next)) if *next == time => continue,
+                                Some(_) => {
+                                    let batch_upper = Antichain::from_elem(time.step_forward());
+                                    writer.commit(&batch, batch_upper.clone()).await?;
+                                    *write_frontier.borrow_mut() = batch_upper;
+                                }
+                                None => {
+                                    writer.commit(&batch, progress.clone()).await?;
+                                    *write_frontier.borrow_mut() = progress.clone();
+                                    committed = true;
+                                }
+                            }
+                            batch.clear();
+                        }
+                        drop(ready);
+
+                        if committed {
+                            pending_progress = None;
+                        } else if progress.is_empty() {
+                            info!("{name}: committing progress for {}", progress.pretty());
+                            writer.commit(&[], progress.clone()).await?;
+                            *write_frontier.borrow_mut() = progress.clone();
+                        } else {
+                            // Frontier-only progress is recorded at most once per interval, rather
+                            // than in an upstream transaction for every tick of the frontier.
+                            let due = match pending_progress {
+                                Some((_, due)) => due,
+                                None => Instant::now() + PROGRESS_INTERVAL,
+                            };
+                            pending_progress = Some((progress.clone(), due));
+                        }
+                        if progress.is_empty() {
+                            break;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(|error: Rc<anyhow::Error>| {
+        let namespace = match error.downcast_ref::<PostgresError>() {
+            Some(PostgresError::Ssh(_)) => StatusNamespace::Ssh,
+            _ => StatusNamespace::Postgres,
+        };
+        HealthStatusMessage {
+            index: 0,
+            update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+            namespace,
+        }
+    });
+
+    (statuses, button.press_on_drop())
+}
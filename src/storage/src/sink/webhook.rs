@@ -0,0 +1,484 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`WebhookSinkConnection`]. The dataflow consists of a
+//! single operator that POSTs the updates of every closed timestamp to an HTTP endpoint, and
+//! records its progress in a persist shard.
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ keyed updates, the input to this module
+//!               │
+//!        ┏━━━━━━v━━━━━━┓        ╭──────────────╮
+//!        ┃   webhook   ┃ upper  │   progress   │
+//!        ┃    sink     ┃───────>│    shard     │
+//!        ┗━━━━━━┯━━━━━━┛        ╰──────────────╯
+//!               │ one POST per closed timestamp
+//!        ╭──────v──────╮
+//!        │  receiver   │
+//!        ╰─────────────╯
+//! ```
+//!
+//! # Requests
+//!
+//! Updates are buffered until the input frontier advances past their timestamp. The updates of
+//! each timestamp are then POSTed in a single request, in ascending timestamp order, with a JSON
+//! body of the form:
+//!
+//! ```json
+//! {
+//!   "timestamp": 1700000000000,
+//!   "frontier": [1700000000001],
+//!   "updates": [{"key": {"id": 1}, "value": {"id": 1, "name": "a"}}]
+//! }
+//! ```
+//!
+//! Keys and values are encoded with the same JSON encoding as `FORMAT JSON` Kafka sinks, so
+//! with `ENVELOPE DEBEZIUM` every value has a `before` and an `after` field, and with `ENVELOPE
+//! UPSERT` a `null` value denotes a deletion. Timestamps without any updates are not sent.
+//!
+//! If the sink has a signing secret, every request carries a hex encoded HMAC-SHA256 of its body,
+//! keyed by the secret, in the [`SIGNATURE_HEADER`] header.
+//!
+//! # Progress
+//!
+//! The sink records its progress as the upper of a dedicated persist shard, which the storage
+//! controller allocates for it, much like the Kafka sink records its progress in a progress topic.
+//! After the receiver accepts the request of a timestamp, the upper of the shard is advanced past
+//! that timestamp, and when the frontier of the input advances without any updates the upper is
+//! advanced without sending a request. When the sink starts up, it resumes at the upper of the
+//! shard, so that no timestamp is skipped across restarts.
+//!
+//! An HTTP endpoint cannot take part in a transaction with the progress shard, so if the sink
+//! restarts after a request was accepted but before its progress was recorded, the request is
+//! delivered again. Delivery is thus at-least-once, and receivers that need exactly-once
+//! semantics should ignore requests whose `timestamp` is not beyond the last one they accepted.
+//!
+//! Advancing the upper is a compare-and-append against the upper the sink last observed, so if
+//! another instance of the sink has advanced it in the meantime, this instance has been fenced
+//! out and stops with an error.
+//!
+//! # Error handling
+//!
+//! Requests that fail with a network error or a retryable status code (`408`, `429` or `5xx`) are
+//! retried with exponential backoff. Any other failure, or a request that does not succeed within
+//! the retry budget, is a transient error that is wired up with our health monitoring and will
+//! trigger a restart of the sink dataflow.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use differential_dataflow::{Collection, Hashable};
+use hmac::{Hmac, Mac};
+use mz_interchange::json::JsonEncoder;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::retry::{Retry, RetryResult};
+use mz_ore::vec::VecExt;
+use mz_persist_client::cache::PersistClientCache;
+use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    MetadataFilled, SinkEnvelope, StorageSinkDesc, WebhookSinkConnection,
+};
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use reqwest::StatusCode;
+use serde_json::json;
+use sha2::Sha256;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::{Concatenate, Map, ToStream};
+use timely::dataflow::{Scope, Stream};
+use timely::progress::{Antichain, Timestamp as _};
+use timely::PartialOrder;
+use tracing::warn;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::sink::ProgressRecorder;
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The header that carries the HMAC signature of each request body.
+const SIGNATURE_HEADER: &str = "x-materialize-signature";
+
+/// The timeout of a single request to the receiver.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The maximum amount of time spent retrying a single request before the sink is restarted.
+const MAX_RETRY_DURATION: Duration = Duration::from_secs(300);
+
+impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for WebhookSinkConnection {
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        input: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        // TODO(benesch): errors should stream out through the sink,
+        // if we figure out a protocol for that.
+        _err_collection: Collection<G, DataflowError, Diff>,
+    ) -> (Stream<G, HealthStatusMessage>, Vec<PressOnDropButton>) {
+        let mut scope = input.scope();
+
+        let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, Rc::clone(&write_frontier));
+
+        let (sink_status, sink_token) = sink_collection(
+            format!("webhook-{sink_id}-sink"),
+            &input,
+            sink_id,
+            self.clone(),
+            sink.envelope,
+            storage_state.storage_configuration.clone(),
+            Arc::clone(&storage_state.persist_clients),
+            sink.from_storage_metadata.clone(),
+            sink.as_of.clone(),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            write_frontier,
+        );
+
+        let running_status = Some(HealthStatusMessage {
+            index: 0,
+            update: HealthStatusUpdate::Running,
+            namespace: StatusNamespace::Webhook,
+        })
+        .to_stream(&mut scope);
+
+        let status = scope.concatenate([running_status, sink_status]);
+
+        (status, vec![sink_token])
+    }
+}
+
+/// A client that delivers the batches of a [`WebhookSinkConnection`] to its receiver.
+struct WebhookClient {
+    /// The HTTP client used for all requests.
+    client: reqwest::Client,
+    /// The endpoint of the receiver.
+    url: String,
+    /// The key used to sign request bodies, if any.
+    signing_key: Option<Vec<u8>>,
+    /// Encodes the keys and values of updates.
+    encoder: JsonEncoder,
+    /// A handle to the metrics associated with this sink.
+    statistics: SinkStatistics,
+}
+
+impl WebhookClient {
+    async fn new(
+        connection: &WebhookSinkConnection,
+        envelope: SinkEnvelope,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+    ) -> Result<Self, anyhow::Error> {
+        let signing_key = match connection.signing_secret {
+            Some(id) => Some(
+                storage_configuration
+                    .connection_context
+                    .secrets_reader
+                    .read(id)
+                    .await
+                    .context("reading webhook sink signing secret")?,
+            ),
+            None => None,
+        };
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("building webhook sink HTTP client")?;
+        let encoder = JsonEncoder::new(
+            connection
+                .key_desc_and_indices
+                .as_ref()
+                .map(|(desc, _indices)| desc.clone()),
+            connection.value_desc.clone(),
+            matches!(envelope, SinkEnvelope::Debezium),
+        );
+        Ok(WebhookClient {
+            client,
+            url: connection.url.clone(),
+            signing_key,
+            encoder,
+            statistics,
+        })
+    }
+
+    /// Sends a single `POST` request with the given `body` and `signature`.
+    async fn post(&self, body: &[u8], signature: Option<&str>) -> Result<(), RequestError> {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_vec());
+        if let Some(signature) = signature {
+            request = request.header(SIGNATURE_HEADER, signature);
+        }
+        let response = request.send().await?;
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            status => Err(RequestError::Status(status)),
+        }
+    }
+
+    /// POSTs the `updates` of `time`, together with the `upper` up to which the sink has
+    /// delivered all updates once this request has been accepted.
+    async fn deliver(
+        &self,
+        time: Timestamp,
+        updates: Vec<(Option<Row>, Option<Row>)>,
+        upper: &Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        let messages = u64::cast_from(updates.len());
+        let updates: Vec<_> = updates
+            .into_iter()
+            .map(|(key, value)| {
+                json!({
+                    "key": key.map(|key| self.encoder.key_to_json(&key)),
+                    "value": value.map(|value| self.encoder.value_to_json(&value)),
+                })
+            })
+            .collect();
+        let body = json!({
+            "timestamp": time,
+            "frontier": upper.elements(),
+            "updates": updates,
+        });
+        let body = serde_json::to_vec(&body).expect("infallible");
+
+        let signature = self.signing_key.as_ref().map(|key| {
+            let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key size");
+            mac.update(&body);
+            hex::encode(mac.finalize().into_bytes())
+        });
+
+        let bytes = u64::cast_from(body.len());
+        self.statistics.inc_messages_staged_by(messages);
+        self.statistics.inc_bytes_staged_by(bytes);
+
+        retry(|| self.post(&body, signature.as_deref()))
+            .await
+            .with_context(|| format!("delivering batch for frontier {}", upper.pretty()))?;
+
+        self.statistics.inc_messages_committed_by(messages);
+        self.statistics.inc_bytes_committed_by(bytes);
+        Ok(())
+    }
+}
+
+/// An error talking to the receiver of a webhook sink.
+#[derive(Debug, thiserror::Error)]
+enum RequestError {
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error("receiver responded with {0}")]
+    Status(StatusCode),
+}
+
+impl RequestError {
+    /// Reports whether the request that caused this error should be retried.
+    fn is_retryable(&self) -> bool {
+        match self {
+            RequestError::Http(_) => true,
+            RequestError::Status(status) => {
+                status.is_server_error()
+                    || *status == StatusCode::REQUEST_TIMEOUT
+                    || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+        }
+    }
+}
+
+/// Runs `f` until it succeeds, backing off exponentially after every retryable error.
+async fn retry<F, U, T>(mut f: F) -> Result<T, RequestError>
+where
+    F: FnMut() -> U,
+    U: std::future::Future<Output = Result<T, RequestError>>,
+{
+    Retry::default()
+        .clamp_backoff(Duration::from_secs(30))
+        .max_duration(MAX_RETRY_DURATION)
+        .retry_async(|state| {
+            let result = f();
+            async move {
+                match result.await {
+                    Ok(value) => RetryResult::Ok(value),
+                    Err(e) if e.is_retryable() => {
+                        if let Some(backoff) = state.next_backoff {
+                            warn!(
+                                "webhook sink request failed, retrying in {backoff:?}: {}",
+                                e.display_with_causes()
+                            );
+                        }
+                        RetryResult::RetryableErr(e)
+                    }
+                    Err(e) => RetryResult::FatalErr(e),
+                }
+            }
+        })
+        .await
+}
+
+/// Sinks a collection of keyed updates to an HTTP endpoint.
+///
+/// This operator exchanges all updates to a single worker by hashing on the given sink `id`.
+///
+/// Updates are delivered in ascending timestamp order, one request per timestamp, and the
+/// progress of the sink is recorded in its progress shard after every request.
+fn sink_collection<G: Scope<Timestamp = Timestamp>>(
+    name: String,
+    input: &Collection<G, (Option<Row>, Option<Row>), Diff>,
+    sink_id: GlobalId,
+    connection: WebhookSinkConnection,
+    envelope: SinkEnvelope,
+    storage_configuration: StorageConfiguration,
+    persist_clients: Arc<PersistClientCache>,
+    metadata: CollectionMetadata,
+    as_of: Antichain<Timestamp>,
+    statistics: SinkStatistics,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+) -> (Stream<G, HealthStatusMessage>, PressOnDropButton) {
+    let scope = input.scope();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to deliver all the data, in order.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(&input.inner, Exchange::new(move |_| hashed_id));
+
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            let client =
+                WebhookClient::new(&connection, envelope, &storage_configuration, statistics)
+                    .await?;
+            let mut recorder = ProgressRecorder::new(
+                sink_id,
+                connection.progress_shard,
+                &persist_clients,
+                &metadata,
+            )
+            .await?;
+            let resume_upper = recorder.upper().clone();
+            *write_frontier.borrow_mut() = resume_upper.clone();
+
+            super::check_resume_upper(&name, &as_of, &resume_upper)?;
+
+            // The section below relies on TotalOrder for correctness so we'll work with timestamps
+            // directly to make sure this doesn't compile if someone attempts to make this operator
+            // generic over partial orders in the future.
+            let Some(upper) = resume_upper.as_option().copied() else {
+                return Ok(());
+            };
+            let mut deferred_updates = vec![];
+            let mut ready_updates = vec![];
+            while let Some(event) = input.next().await {
+                match event {
+                    Event::Data(_cap, batch) => {
+                        for (update, time, diff) in batch {
+                            // Updates not beyond `upper` have already been delivered.
+                            if time < upper {
+                                continue;
+                            }
+                            if diff != 1 {
+                                return Err(anyhow!("invalid sink update with diff {diff}"));
+                            }
+                            deferred_updates.push((update, time));
+                        }
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier.
+                        if !super::is_beyond_as_of(&as_of, &progress) {
+                            continue;
+                        }
+
+                        ready_updates.extend(
+                            deferred_updates
+                                .drain_filter_swapping(|(_, time)| !progress.less_equal(time)),
+                        );
+                        ready_updates.sort_unstable_by(|a, b| a.1.cmp(&b.1));
+
+                        // Deliver each timestamp in its own request, recording the successor of
+                        // the timestamp as the new upper so that a restart resumes right after it.
+                        let mut ready = ready_updates.drain(..).peekable();
+                        let mut batch = vec![];
+                        while let Some((update, time)) = ready.next() {
+                            batch.push(update);
+                            if ready.peek().map(|(_, next)| *next) != Some(time) {
+                                let batch_upper = Antichain::from_elem(time.step_forward());
+                                client
+                                    .deliver(time, std::mem::take(&mut batch), &batch_upper)
+                                    .await?;
+                                recorder.record(&batch_upper).await?;
+                                *write_frontier.borrow_mut() = batch_upper;
+                            }
+                        }
+                        drop(ready);
+
+                        // The remaining timestamps up to the frontier have no updates, so their
+                        // progress is only recorded in the progress shard.
+                        recorder.record(&progress).await?;
+                        *write_frontier.borrow_mut() = progress.clone();
+                        if progress.is_empty() {
+                            break;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(|error: Rc<anyhow::Error>| HealthStatusMessage {
+        index: 0,
+        update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+        namespace: StatusNamespace::Webhook,
+    });
+
+    (statuses, button.press_on_drop())
+}
@@ -236,6 +236,8 @@ impl<T: Timestamp + Lattice + Codec64 + Display> AsyncStorageWorker<T> {
                                 status_shard: _,
                                 relation_desc,
                                 txns_shard,
+                                // Only tables have a layout
+                                table_layout: _,
                             } = &export.storage_metadata;
                             assert_eq!(
                                 txns_shard, &None,
@@ -65,6 +65,7 @@ pub async fn run_sql(mut cmd: SqlCommand, state: &State) -> Result<ControlFlow,
         | CreateTable(_)
         | CreateIndex(_)
         | CreateType(_)
+        | CreateFunction(_)
         | CreateRole(_)
         | AlterObjectRename(_)
         | AlterIndex(_)
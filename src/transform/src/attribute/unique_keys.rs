@@ -17,6 +17,10 @@ use crate::attribute::{Attribute, DerivedAttributes, DerivedAttributesBuilder};
 
 /// Compute the unique keys of each subtree of a [MirRelationExpr] from the
 /// bottom-up.
+///
+/// The keys of a `Get` of a table are the keys of the table's relation type,
+/// i.e., the table's `PRIMARY KEY` and `UNIQUE` constraints that consider
+/// `NULL`s equal, which writes to the table are checked against.
 #[derive(Default)]
 #[allow(missing_debug_implementations)]
 pub struct UniqueKeys {
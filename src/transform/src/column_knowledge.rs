@@ -408,6 +408,7 @@ impl ColumnKnowledge {
                             | AggregateFunc::MaxFloat64
                             | AggregateFunc::MaxBool
                             | AggregateFunc::MaxString
+                            | AggregateFunc::MaxStringCollated { .. }
                             | AggregateFunc::MaxDate
                             | AggregateFunc::MaxTimestamp
                             | AggregateFunc::MaxTimestampTz
@@ -422,15 +423,23 @@ impl ColumnKnowledge {
                             | AggregateFunc::MinFloat64
                             | AggregateFunc::MinBool
                             | AggregateFunc::MinString
+                            | AggregateFunc::MinStringCollated { .. }
                             | AggregateFunc::MinDate
                             | AggregateFunc::MinTimestamp
                             | AggregateFunc::MinTimestampTz
                             | AggregateFunc::Any
-                            | AggregateFunc::All => {
+                            | AggregateFunc::All
+                            | AggregateFunc::HllMerge => {
                                 // These methods propagate constant values exactly.
                                 knowledge
                             }
                             AggregateFunc::Count => DatumKnowledge::any(false),
+                            // Statistical aggregates can be null even if
+                            // their inputs are not, e.g., `var_samp` over a
+                            // single value.
+                            AggregateFunc::VarianceNumeric { .. }
+                            | AggregateFunc::VarianceFloat64 { .. }
+                            | AggregateFunc::Regression { .. } => DatumKnowledge::any(true),
                             _ => {
                                 // The remaining aggregates are non-null if
                                 // their inputs are non-null. This is correct
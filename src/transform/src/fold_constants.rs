@@ -583,6 +583,7 @@ impl FoldConstants {
                     // groups doesn't matter.
                     desc: false,
                     nulls_last: false,
+                    collation: None,
                 })
                 .collect::<Vec<ColumnOrder>>();
             let mut lhs_datum_vec = mz_repr::DatumVec::new();
@@ -15,7 +15,7 @@
 
 use itertools::Itertools;
 use mz_expr::visit::Visit;
-use mz_expr::MirRelationExpr;
+use mz_expr::{AggregateFunc, MirRelationExpr};
 
 use crate::TransformCtx;
 
@@ -53,10 +53,25 @@ impl ReduceElision {
         } = relation
         {
             let input_type = input.typ();
-            if input_type.keys.iter().any(|keys| {
-                keys.iter()
-                    .all(|k| group_key.contains(&mz_expr::MirScalarExpr::Column(*k)))
-            }) {
+            // Ordered-set aggregates over arrays of fractions and statistical
+            // aggregates cannot be expressed as scalar expressions over a
+            // single row.
+            let elidable = aggregates.iter().all(|a| {
+                !matches!(
+                    a.func,
+                    AggregateFunc::PercentileContMulti { .. }
+                        | AggregateFunc::PercentileDiscMulti { .. }
+                        | AggregateFunc::VarianceNumeric { .. }
+                        | AggregateFunc::VarianceFloat64 { .. }
+                        | AggregateFunc::Regression { .. }
+                )
+            });
+            if elidable
+                && input_type.keys.iter().any(|keys| {
+                    keys.iter()
+                        .all(|k| group_key.contains(&mz_expr::MirScalarExpr::Column(*k)))
+                })
+            {
                 let map_scalars = aggregates
                     .iter()
                     .map(|a| a.on_unique(&input_type.column_types))
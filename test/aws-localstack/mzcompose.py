@@ -9,6 +9,8 @@
 
 """Tests of AWS functionality that run against localstack."""
 
+import csv
+import io
 from typing import Any, cast
 
 import boto3
@@ -63,7 +65,7 @@ SERVICES = [
 
 
 def workflow_default(c: Composition) -> None:
-    for name in ["secrets-manager", "aws-connection"]:
+    for name in ["secrets-manager", "aws-connection", "copy-to-s3"]:
         with c.test_case(name):
             c.workflow(name)
 
@@ -208,3 +210,50 @@ def workflow_secrets_manager(c: Composition) -> None:
 def workflow_aws_connection(c: Composition) -> None:
     c.up("localstack", "materialized")
     c.run("testdrive", "aws-connection/aws-connection.td")
+
+
+def workflow_copy_to_s3(c: Composition) -> None:
+    c.up("localstack", "materialized")
+
+    aws_endpoint_url = f"http://localhost:{c.port('localstack', 4566)}"
+    s3_client = boto3.client(
+        "s3",
+        endpoint_url=aws_endpoint_url,
+        region_name=DEFAULT_CLOUD_REGION,
+        aws_access_key_id=AWS_ACCESS_KEY_ID,
+        aws_secret_access_key=AWS_SECRET_ACCESS_KEY,
+    )
+    bucket = "copy-to-test"
+    s3_client.create_bucket(Bucket=bucket)
+
+    c.run(
+        "testdrive",
+        "--var=aws-endpoint=http://localstack:4566",
+        f"--var=aws-region={DEFAULT_CLOUD_REGION}",
+        f"--var=aws-access-key-id={AWS_ACCESS_KEY_ID}",
+        f"--var=aws-secret-access-key={AWS_SECRET_ACCESS_KEY}",
+        "copy-to-s3/copy-to-s3.td",
+    )
+
+    def list_keys(prefix: str) -> list[str]:
+        objects = s3_client.list_objects_v2(Bucket=bucket, Prefix=prefix)
+        return [o["Key"] for o in objects.get("Contents", [])]
+
+    # Every file starts with the header, followed by a subset of the rows.
+    csv_keys = list_keys("csv/")
+    assert csv_keys, "no CSV files were uploaded"
+    rows = []
+    for key in csv_keys:
+        assert key.endswith(".csv"), key
+        body = s3_client.get_object(Bucket=bucket, Key=key)["Body"].read().decode()
+        reader = csv.reader(io.StringIO(body))
+        assert next(reader) == ["a", "b"]
+        rows.extend(reader)
+    assert sorted(rows) == [["1", "one"], ["2", "two, with a comma"], ["3", ""]], rows
+
+    parquet_keys = list_keys("parquet/")
+    assert parquet_keys, "no Parquet files were uploaded"
+    for key in parquet_keys:
+        assert key.endswith(".parquet"), key
+        body = s3_client.get_object(Bucket=bucket, Key=key)["Body"].read()
+        assert body[:4] == b"PAR1" and body[-4:] == b"PAR1", key
@@ -0,0 +1,147 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""Tests of ICEBERG sinks writing to a table in S3, as emulated by localstack."""
+
+import json
+import time
+from typing import Any
+
+import boto3
+
+from materialize.mzcompose import DEFAULT_CLOUD_REGION
+from materialize.mzcompose.composition import Composition, Service
+from materialize.mzcompose.services.localstack import Localstack
+from materialize.mzcompose.services.materialized import Materialized
+from materialize.mzcompose.services.testdrive import Testdrive
+
+AWS_ACCESS_KEY_ID = "LSIAQAAAAAAVNCBMPNSG"
+AWS_SECRET_ACCESS_KEY = "secret"
+
+BUCKET = "iceberg"
+TABLE = f"s3://{BUCKET}/src"
+
+SERVICES = [
+    Localstack(),
+    Materialized(depends_on=["localstack"]),
+    Testdrive(no_reset=True),
+    Service(
+        name="iceberg-reader",
+        config={
+            "image": "python:3.11-slim",
+            "command": [
+                "sh",
+                "-c",
+                "pip install -q pyarrow==14.0.1 fastavro==1.8.2 boto3==1.34.14 && sleep infinity",
+            ],
+            "environment": [
+                "AWS_ENDPOINT_URL=http://localstack:4566",
+                f"AWS_DEFAULT_REGION={DEFAULT_CLOUD_REGION}",
+                f"AWS_ACCESS_KEY_ID={AWS_ACCESS_KEY_ID}",
+                f"AWS_SECRET_ACCESS_KEY={AWS_SECRET_ACCESS_KEY}",
+            ],
+            "volumes": [".:/workdir"],
+        },
+    ),
+]
+
+
+def run_testdrive(c: Composition, file: str) -> None:
+    c.run(
+        "testdrive",
+        "--var=aws-endpoint=http://localstack:4566",
+        f"--var=aws-region={DEFAULT_CLOUD_REGION}",
+        f"--var=aws-access-key-id={AWS_ACCESS_KEY_ID}",
+        f"--var=aws-secret-access-key={AWS_SECRET_ACCESS_KEY}",
+        file,
+    )
+
+
+def workflow_default(c: Composition) -> None:
+    c.up("localstack", "materialized", "iceberg-reader")
+
+    s3_client = boto3.client(
+        "s3",
+        endpoint_url=f"http://localhost:{c.port('localstack', 4566)}",
+        region_name=DEFAULT_CLOUD_REGION,
+        aws_access_key_id=AWS_ACCESS_KEY_ID,
+        aws_secret_access_key=AWS_SECRET_ACCESS_KEY,
+    )
+    s3_client.create_bucket(Bucket=BUCKET)
+
+    run_testdrive(c, "iceberg-sink.td")
+    table = wait_for_rows(
+        c,
+        [
+            {"id": 1, "v": "a", "n": "1.5", "ts": "2024-01-01 00:00:00+00:00"},
+            {"id": 2, "v": "b", "n": None, "ts": None},
+        ],
+    )
+    assert [f["type"] for f in table["schema"]["fields"]] == [
+        "int",
+        "string",
+        "string",
+        "timestamptz",
+    ], table["schema"]
+    assert table["schema"]["identifier-field-ids"] == [1], table["schema"]
+    # Readers resolve the columns of the data files by their field ids.
+    assert table["field_ids"] == ["1", "2", "3", "4"], table["field_ids"]
+
+    run_testdrive(c, "iceberg-sink-update.td")
+    wait_for_rows(c, [{"id": 2, "v": "c", "n": None, "ts": None}])
+
+    # The sink must resume right after the last frontier it committed.
+    c.kill("materialized")
+    c.up("materialized")
+    run_testdrive(c, "iceberg-sink-restart.td")
+    wait_for_rows(
+        c,
+        [
+            {"id": 2, "v": "c", "n": None, "ts": None},
+            {"id": 3, "v": "d", "n": "2", "ts": None},
+        ],
+    )
+
+    # The sink must fail instead of recreating a table whose metadata was lost.
+    c.kill("materialized")
+    metadata = s3_client.list_objects_v2(Bucket=BUCKET, Prefix="src/metadata/")
+    for o in metadata.get("Contents", []):
+        s3_client.delete_object(Bucket=BUCKET, Key=o["Key"])
+    c.up("materialized")
+    run_testdrive(c, "iceberg-sink-lost.td")
+    listing = s3_client.list_objects_v2(Bucket=BUCKET, Prefix="src/metadata/")
+    assert "Contents" not in listing, listing
+
+
+def read_table(c: Composition) -> Any:
+    # Fails until the reader has installed its dependencies and the sink has created the table.
+    result = c.exec(
+        "iceberg-reader",
+        "python3",
+        "/workdir/read_table.py",
+        TABLE,
+        capture=True,
+        check=False,
+    )
+    if result.returncode != 0:
+        return None
+    return json.loads(result.stdout)
+
+
+def wait_for_rows(c: Composition, expected: list[dict[str, Any]]) -> Any:
+    """Waits until the live rows of the table are exactly `expected`."""
+    table = None
+    for _ in range(120):
+        table = read_table(c)
+        if table is not None:
+            rows = sorted(table["rows"], key=lambda row: row["id"])
+            if rows == expected:
+                return table
+        time.sleep(1)
+    raise AssertionError(f"table did not converge to {expected}: {table}")
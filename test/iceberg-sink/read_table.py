@@ -0,0 +1,123 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""Prints the contents of the Iceberg table at the given s3:// URI as JSON.
+
+The table is read by following the Iceberg format version 2 spec directly:
+the latest metadata file is located through the version hint, the current
+snapshot's manifests are read with fastavro and the data and delete files they
+list with pyarrow. The S3 endpoint and credentials are taken from the
+environment. A data row is live unless an equality delete file with a
+higher sequence number contains a row with the same values in its equality
+columns.
+"""
+
+import io
+import json
+import sys
+from typing import Any
+from urllib.parse import urlparse
+
+import boto3
+import fastavro
+import pyarrow.parquet as pq
+from botocore.exceptions import ClientError
+
+s3 = boto3.client("s3")
+
+
+def read(uri: str) -> bytes:
+    url = urlparse(uri)
+    return s3.get_object(Bucket=url.netloc, Key=url.path.lstrip("/"))["Body"].read()
+
+
+def exists(uri: str) -> bool:
+    try:
+        read(uri)
+        return True
+    except ClientError:
+        return False
+
+
+def read_avro(uri: str) -> list[dict[str, Any]]:
+    return list(fastavro.reader(io.BytesIO(read(uri))))
+
+
+def main() -> None:
+    location = sys.argv[1]
+    metadata_dir = f"{location}/metadata"
+    version = int(read(f"{metadata_dir}/version-hint.text").decode().strip())
+    while exists(f"{metadata_dir}/v{version + 1}.metadata.json"):
+        version += 1
+    metadata = json.loads(read(f"{metadata_dir}/v{version}.metadata.json"))
+
+    schema = next(
+        s for s in metadata["schemas"] if s["schema-id"] == metadata["current-schema-id"]
+    )
+    names = {field["id"]: field["name"] for field in schema["fields"]}
+
+    snapshot = next(
+        (
+            s
+            for s in metadata["snapshots"]
+            if s["snapshot-id"] == metadata.get("current-snapshot-id")
+        ),
+        None,
+    )
+    data: list[tuple[int, dict[str, Any]]] = []
+    deletes: list[tuple[int, list[str], dict[str, Any]]] = []
+    field_ids: set[str] = set()
+    if snapshot is not None:
+        for manifest in read_avro(snapshot["manifest-list"]):
+            for entry in read_avro(manifest["manifest_path"]):
+                sequence_number = entry["sequence_number"]
+                if sequence_number is None:
+                    sequence_number = manifest["sequence_number"]
+                data_file = entry["data_file"]
+                file = pq.read_table(io.BytesIO(read(data_file["file_path"])))
+                if data_file["content"] == 0:
+                    field_ids.update(
+                        field.metadata[b"PARQUET:field_id"].decode()
+                        for field in file.schema
+                    )
+                rows = file.to_pylist()
+                assert len(rows) == data_file["record_count"], data_file
+                if data_file["content"] == 0:
+                    data.extend((sequence_number, row) for row in rows)
+                elif data_file["content"] == 2:
+                    columns = [names[i] for i in data_file["equality_ids"]]
+                    deletes.extend((sequence_number, columns, row) for row in rows)
+                else:
+                    raise AssertionError(f"unexpected data file {data_file}")
+
+    live = [
+        row
+        for sequence_number, row in data
+        if not any(
+            delete_sequence_number > sequence_number
+            and all(row[c] == delete[c] for c in columns)
+            for delete_sequence_number, columns, delete in deletes
+        )
+    ]
+    print(
+        json.dumps(
+            {
+                "rows": live,
+                "snapshots": len(metadata["snapshots"]),
+                "properties": metadata["properties"],
+                "schema": schema,
+                "field_ids": sorted(field_ids),
+            },
+            default=str,
+        )
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,80 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""Tests of WEBHOOK sinks against a local HTTP receiver."""
+
+import json
+import time
+import urllib.request
+from typing import Any
+
+from materialize.mzcompose.composition import Composition, Service
+from materialize.mzcompose.services.materialized import Materialized
+from materialize.mzcompose.services.testdrive import Testdrive
+
+SERVICES = [
+    Materialized(),
+    Testdrive(no_reset=True),
+    Service(
+        name="webhook-receiver",
+        config={
+            "image": "python:3.11-slim",
+            "command": ["python3", "/workdir/receiver.py"],
+            "ports": [8080],
+            "volumes": [".:/workdir"],
+            "environment": ["WEBHOOK_SECRET=sekrit"],
+        },
+    ),
+]
+
+
+def workflow_default(c: Composition) -> None:
+    c.up("materialized", "webhook-receiver")
+
+    c.run("testdrive", "webhook-sink.td")
+    wait_for_updates(c, 4)
+
+    # The sink must resume right after the last batch whose progress it
+    # recorded. A batch that was accepted right before the kill may be delivered
+    # again, in which case the receiver ignores it.
+    c.kill("materialized")
+    c.up("materialized")
+    c.run("testdrive", "webhook-sink-restart.td")
+    received = wait_for_updates(c, 5)
+
+    assert received["duplicates"] <= 1, received
+    timestamps = [batch["timestamp"] for batch in received["batches"]]
+    assert timestamps == sorted(set(timestamps)), timestamps
+    for batch in received["batches"]:
+        assert batch["frontier"] == [batch["timestamp"] + 1], batch
+
+    updates = [
+        (update["key"], update["value"])
+        for batch in received["batches"]
+        for update in sorted(batch["updates"], key=lambda u: u["key"]["id"])
+    ]
+    assert updates == [
+        ({"id": 1}, {"id": 1, "v": "a"}),
+        ({"id": 2}, {"id": 2, "v": "b"}),
+        ({"id": 2}, {"id": 2, "v": "c"}),
+        ({"id": 1}, None),
+        ({"id": 3}, {"id": 3, "v": "d"}),
+    ], updates
+
+
+def wait_for_updates(c: Composition, count: int) -> Any:
+    """Waits until the receiver has accepted `count` updates."""
+    url = f"http://localhost:{c.port('webhook-receiver', 8080)}/batches"
+    for _ in range(120):
+        with urllib.request.urlopen(url) as response:
+            received = json.load(response)
+        if sum(len(batch["updates"]) for batch in received["batches"]) >= count:
+            return received
+        time.sleep(1)
+    raise AssertionError(f"receiver did not accept {count} updates: {received}")
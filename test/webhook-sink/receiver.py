@@ -0,0 +1,87 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""A receiver for WEBHOOK sinks that records the batches it accepts.
+
+* `POST /sink` accepts a batch, after verifying its signature if the
+  `WEBHOOK_SECRET` environment variable is set.
+* `POST /fail/<n>` makes the next `n` batches fail with a 503.
+* `GET /batches` returns all accepted batches, along with the number of
+  batches that were ignored because their timestamp had been accepted before.
+"""
+
+import hashlib
+import hmac
+import json
+import os
+import threading
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import Any
+
+SECRET = os.environ.get("WEBHOOK_SECRET", "").encode()
+
+lock = threading.Lock()
+last_timestamp: int | None = None
+batches: list[Any] = []
+duplicates = 0
+failures = 0
+
+
+class Handler(BaseHTTPRequestHandler):
+    def reply(self, status: int, body: Any = None) -> None:
+        payload = json.dumps(body).encode() if body is not None else b""
+        self.send_response(status)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(payload)))
+        self.end_headers()
+        self.wfile.write(payload)
+
+    def do_GET(self) -> None:
+        with lock:
+            if self.path == "/batches":
+                self.reply(200, {"batches": batches, "duplicates": duplicates})
+            else:
+                self.reply(404)
+
+    def do_POST(self) -> None:
+        global last_timestamp, duplicates, failures
+        body = self.rfile.read(int(self.headers.get("Content-Length", 0)))
+
+        if self.path.startswith("/fail/"):
+            with lock:
+                failures = int(self.path[len("/fail/") :])
+            self.reply(200)
+            return
+        if self.path != "/sink":
+            self.reply(404)
+            return
+
+        if SECRET:
+            expected = hmac.new(SECRET, body, hashlib.sha256).hexdigest()
+            signature = self.headers.get("x-materialize-signature", "")
+            if not hmac.compare_digest(expected, signature):
+                self.reply(401)
+                return
+
+        batch = json.loads(body)
+        with lock:
+            if failures > 0:
+                failures -= 1
+                self.reply(503)
+                return
+            if last_timestamp is not None and batch["timestamp"] <= last_timestamp:
+                duplicates += 1
+            else:
+                last_timestamp = batch["timestamp"]
+                batches.append(batch)
+        self.reply(200)
+
+
+if __name__ == "__main__":
+    ThreadingHTTPServer(("0.0.0.0", 8080), Handler).serve_forever()